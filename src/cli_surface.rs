@@ -1,6 +1,9 @@
 use clap::{Command, CommandFactory, Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::core::config::DEFAULT_LOCK_TIMEOUT_SECS;
+use crate::core::output::color::ColorChoice;
+
 use crate::commands::{
     api, audit, auth, bench, build, changelog, changes, component, config, daemon, db, deploy,
     deps, doctor, extension, file, fleet, git, http, issues, lint, logs, observe, project,
@@ -20,6 +23,47 @@ pub struct Cli {
     #[arg(long, global = true, value_name = "PATH")]
     pub output: Option<PathBuf>,
 
+    /// Print only a rendered template instead of the JSON envelope, e.g.
+    /// `--output-template '{{next}}'`. Placeholders name fields of the
+    /// command's `data` object; `{{a.b}}` reaches nested fields.
+    #[arg(long, global = true, value_name = "TEMPLATE")]
+    pub output_template: Option<String>,
+
+    /// Print a single-value result as bare text instead of the JSON
+    /// envelope. Combine with --select to narrow the result to one field.
+    #[arg(long, global = true, conflicts_with = "output_template")]
+    pub output_raw: bool,
+
+    /// Print an object result as shell-quoted `KEY=value` lines for `eval`,
+    /// e.g. `COMPONENT_VERSION=1.2.3`. Nested fields join with `_`.
+    #[arg(
+        long,
+        global = true,
+        conflicts_with_all = ["output_template", "output_raw"]
+    )]
+    pub output_env: bool,
+
+    /// Reduce the JSON result to these fields (comma-separated; `a.b` reaches
+    /// nested fields, and arrays are projected per element).
+    #[arg(
+        long,
+        global = true,
+        value_name = "FIELDS",
+        value_delimiter = ',',
+        conflicts_with = "output_template"
+    )]
+    pub select: Vec<String>,
+
+    /// With --select, fail when a selected field is missing instead of
+    /// omitting it.
+    #[arg(long, global = true, requires = "select")]
+    pub strict_select: bool,
+
+    /// Mask secrets (tokens, passwords, credentials, identity file paths) in
+    /// the JSON result with `***` before it is printed or written.
+    #[arg(long, global = true)]
+    pub redact: bool,
+
     /// Suppress resource policy warnings for intentionally hot commands.
     #[arg(long, global = true)]
     pub force_hot: bool,
@@ -33,6 +77,46 @@ pub struct Cli {
     #[arg(long, global = true, value_name = "RUNNER_ID")]
     pub runner: Option<String>,
 
+    /// Seconds to wait for another homeboy process to release the config
+    /// lock before failing. 0 fails immediately.
+    #[arg(long, global = true, value_name = "SECS", default_value_t = DEFAULT_LOCK_TIMEOUT_SECS)]
+    pub lock_timeout: u64,
+
+    /// Maximum concurrent SSH processes (remote commands, scp, rsync) for this
+    /// invocation. Overrides HOMEBOY_JOBS; defaults to the CPU count.
+    #[arg(long, global = true, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Print a per-phase timing table (config load, SSH, git, ...) to stderr
+    /// when the command finishes. Named to stay clear of `bench --profile`.
+    #[arg(long, global = true)]
+    pub profile_phases: bool,
+
+    /// Suppress `[prefix]` status lines on stderr, even on a terminal.
+    /// Errors and command output still print. Also enabled by HOMEBOY_QUIET=1.
+    #[arg(long, global = true, conflicts_with = "status_always")]
+    pub quiet: bool,
+
+    /// Print `[prefix]` status lines on stderr even when it is not a terminal
+    /// (for log capture). Overrides HOMEBOY_QUIET.
+    #[arg(long, global = true)]
+    pub status_always: bool,
+
+    /// When to use ANSI color in rendered output and status lines: auto
+    /// (terminals only), always, or never. Overrides NO_COLOR, CLICOLOR, and
+    /// CLICOLOR_FORCE.
+    #[arg(long, global = true, value_name = "WHEN", value_parser = parse_color_choice)]
+    pub color: Option<ColorChoice>,
+
+    /// Disable ANSI color; same as `--color never`.
+    #[arg(long, global = true, conflicts_with = "color")]
+    pub no_color: bool,
+
+    /// Print the JSON Schema of a command's output envelope and exit, e.g.
+    /// `--print-schema component`. Consumers can pin `schema_version`.
+    #[arg(long, value_name = "COMMAND", exclusive = true)]
+    pub print_schema: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -162,7 +246,7 @@ pub enum CommandOutputArtifactPolicy {
 impl Commands {
     pub fn supports_lab_runner(&self) -> bool {
         match self {
-            Commands::Audit(_) => true,
+            Commands::Audit(args) => args.command.is_none(),
             Commands::Bench(args) if args.is_run_command() => true,
             Commands::Lint(_) => true,
             Commands::Test(_) => true,
@@ -171,6 +255,51 @@ impl Commands {
         }
     }
 
+    /// The component ID slot of a command invoked without any target, and
+    /// whether that command falls back to discovering the component from the
+    /// working directory.
+    pub fn omitted_component_id(&mut self) -> Option<(&mut Option<String>, bool)> {
+        match self {
+            Commands::Build(args)
+                if args.target_id.is_none()
+                    && args.component_ids.is_empty()
+                    && !args.all
+                    && args.json.is_none()
+                    && args.path.is_none() =>
+            {
+                Some((&mut args.target_id, true))
+            }
+            Commands::Deploy(args)
+                if args.target_id.is_none()
+                    && args.component_ids.is_empty()
+                    && args.project.is_none()
+                    && args.component.is_none()
+                    && args.projects.is_none()
+                    && args.fleet.is_none()
+                    && args.project_tag.is_empty()
+                    && args.json.is_none()
+                    && !args.shared =>
+            {
+                Some((&mut args.target_id, false))
+            }
+            Commands::Audit(args)
+                if args.command.is_none()
+                    && !args.all
+                    && args.tag.is_empty()
+                    && args.comp.path.is_none() =>
+            {
+                Some((&mut args.comp.component, true))
+            }
+            Commands::Test(args) if args.comp.path.is_none() => {
+                Some((&mut args.comp.component, true))
+            }
+            Commands::Lint(args) if args.comp.path.is_none() => {
+                Some((&mut args.comp.component, true))
+            }
+            _ => None,
+        }
+    }
+
     pub fn lab_offload_mutation_flag(&self) -> Option<&'static str> {
         match self {
             Commands::Bench(args) if args.lab_offload_writes_local_state() => {
@@ -194,6 +323,33 @@ impl Commands {
             Commands::File(args) if file::is_raw_read(args) => {
                 CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
             }
+            Commands::Audit(args) if crate::commands::audit::is_plain_text_mode(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Test(args) if crate::commands::test::is_plain_text_mode(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Lint(args) if crate::commands::lint::is_plain_text_mode(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Component(args) if crate::commands::component::is_dot_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Component(args) if crate::commands::component::is_tabular_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Server(args) if crate::commands::server::is_tabular_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Project(args) if crate::commands::project::is_tabular_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Fleet(args) if crate::commands::fleet::is_tabular_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
+            Commands::Api(args) if crate::commands::api::is_raw_output(args) => {
+                CommandResponseMode::Raw(CommandRawOutputMode::PlainText)
+            }
             Commands::Docs(args) if crate::commands::docs::is_json_mode(args) => {
                 CommandResponseMode::Json
             }
@@ -265,6 +421,11 @@ impl CommandSurfaceEntry {
     }
 }
 
+fn parse_color_choice(value: &str) -> Result<ColorChoice, String> {
+    ColorChoice::parse(value)
+        .ok_or_else(|| format!("'{}' is not one of: auto, always, never", value))
+}
+
 pub fn current_command_surface() -> CommandSurface {
     command_surface_from(Cli::command())
 }
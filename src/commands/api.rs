@@ -8,6 +8,14 @@ pub struct ApiArgs {
     /// Project ID
     pub project_id: String,
 
+    /// Output only the value at this JSON pointer (RFC 6901), e.g. /data/0/id
+    #[arg(long, global = true, value_name = "POINTER")]
+    pub extract: Option<String>,
+
+    /// Print the extracted value as plain text instead of the JSON envelope
+    #[arg(long, global = true, requires = "extract")]
+    pub raw: bool,
+
     #[command(subcommand)]
     command: ApiCommand,
 }
@@ -64,6 +72,20 @@ pub fn run(args: ApiArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<ap
     api::run(&input)
 }
 
+pub fn is_raw_output(args: &ApiArgs) -> bool {
+    args.raw
+}
+
+/// Print the extracted value alone: strings as-is, anything else as JSON.
+pub fn run_raw(args: ApiArgs) -> CmdResult<String> {
+    let (output, exit_code) = api::run(&build_api_json(&args))?;
+    let text = match output.response {
+        serde_json::Value::String(text) => text,
+        value => serde_json::to_string_pretty(&value).unwrap_or_default(),
+    };
+    Ok((text, exit_code))
+}
+
 fn build_api_json(args: &ApiArgs) -> String {
     let (method, endpoint, body, body_format) = match &args.command {
         ApiCommand::Get { endpoint } => ("GET", endpoint.clone(), None, "json"),
@@ -106,6 +128,7 @@ fn build_api_json(args: &ApiArgs) -> String {
         "endpoint": endpoint,
         "body": body,
         "bodyFormat": body_format,
+        "extract": args.extract,
     })
     .to_string()
 }
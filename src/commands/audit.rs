@@ -1,4 +1,4 @@
-use clap::Args;
+use clap::{Args, Subcommand, ValueEnum};
 use std::path::Path;
 
 use homeboy::code_audit::{
@@ -10,11 +10,20 @@ use homeboy::observation::{
     finding_records_from_audit, NewRunRecord, ObservationStore, RunRecord, RunStatus,
 };
 
-use super::utils::args::{BaselineArgs, ExtensionOverrideArgs, PositionalComponentArgs};
+use super::utils::args::{
+    BaselineArgs, ExtensionOverrideArgs, FailOnArgs, PositionalComponentArgs,
+};
 use super::{CmdResult, GlobalArgs};
 
-#[derive(Args)]
+mod batch;
+
+pub use batch::{run_all, AuditBatchOutput};
+
+#[derive(Args, Clone)]
 pub struct AuditArgs {
+    #[command(subcommand)]
+    pub command: Option<AuditCommand>,
+
     #[command(flatten)]
     pub comp: PositionalComponentArgs,
 
@@ -25,6 +34,11 @@ pub struct AuditArgs {
     #[arg(long)]
     pub conventions: bool,
 
+    /// Share of a group's files that must carry a pattern for it to become a
+    /// convention, 0.5-1.0 (default: component config, else 0.6)
+    #[arg(long, value_name = "ratio")]
+    pub threshold: Option<f32>,
+
     /// Restrict findings to these kinds (repeatable)
     #[arg(long = "only", value_name = "kind")]
     pub only: Vec<String>,
@@ -33,13 +47,46 @@ pub struct AuditArgs {
     #[arg(long = "exclude", value_name = "kind")]
     pub exclude: Vec<String>,
 
+    /// Only scan doc files matching this path or glob for docs drift (repeatable)
+    #[arg(long = "docs-include", value_name = "glob")]
+    pub docs_include: Vec<String>,
+
+    /// Skip doc files matching this path or glob for docs drift (repeatable)
+    #[arg(long = "docs-exclude", value_name = "glob")]
+    pub docs_exclude: Vec<String>,
+
+    /// Lowest doc claim confidence to report: real, unclear (default), or example
+    #[arg(long = "docs-min-confidence", value_name = "level")]
+    pub docs_min_confidence: Option<String>,
+
+    /// Lines of context to show around each broken doc reference (default: 1)
+    #[arg(long = "docs-context-lines", value_name = "N")]
+    pub docs_context_lines: Option<usize>,
+
     #[command(flatten)]
     pub baseline_args: BaselineArgs,
 
+    #[command(flatten)]
+    pub fail_on_args: FailOnArgs,
+
     /// Only audit files changed since a git ref (branch, tag, or SHA).
     #[arg(long)]
     pub changed_since: Option<String>,
 
+    /// Report findings only in files with uncommitted changes; conventions
+    /// are still discovered from every file
+    #[arg(long, conflicts_with_all = ["changed_since", "baseline"])]
+    pub only_changed: bool,
+
+    /// Re-fingerprint every file instead of reusing the fingerprint cache
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Append a one-line JSON summary of this run (score, outliers, broken
+    /// doc references) to this file; read it back with `audit history`
+    #[arg(long, value_name = "path")]
+    pub history_file: Option<String>,
+
     /// Include compact machine-readable summary for CI wrappers
     #[arg(long)]
     pub json_summary: bool,
@@ -48,6 +95,102 @@ pub struct AuditArgs {
     /// runs the refactor planner after audit completes.
     #[arg(long)]
     pub fixability: bool,
+
+    /// Emit one NDJSON record per finding instead of the aggregate JSON envelope
+    #[arg(long)]
+    pub json_lines: bool,
+
+    /// Output format: json (default envelope) or sarif (SARIF 2.1.0 log)
+    #[arg(long, value_enum, default_value_t = AuditFormat::Json, conflicts_with_all = ["json_lines", "conventions", "baseline", "json_summary"])]
+    pub format: AuditFormat,
+
+    /// Audit every registered component except archived ones
+    #[arg(long, group = "batch", conflicts_with_all = ["component", "path", "json_lines", "format"])]
+    pub all: bool,
+
+    /// Audit the components of projects carrying this tag (repeatable; a
+    /// project must carry every tag)
+    #[arg(long, value_name = "tag", group = "batch", conflicts_with_all = ["component", "path", "json_lines", "format"])]
+    pub tag: Vec<String>,
+
+    /// With --all or --tag, how many components to audit at once
+    /// (default: CPU count, at most 4)
+    #[arg(long, value_name = "N", requires = "batch")]
+    pub parallel: Option<usize>,
+
+    /// With --all or --tag, write one report per component plus index.json here
+    #[arg(long, value_name = "dir", requires = "batch")]
+    pub output_dir: Option<String>,
+
+    /// Report file format for --output-dir: json (default) or md
+    #[arg(long, value_enum, default_value_t = ReportFormat::Json, requires = "output_dir")]
+    pub report_format: ReportFormat,
+
+    /// Overwrite existing reports in --output-dir
+    #[arg(long, requires = "output_dir")]
+    pub force: bool,
+}
+
+#[derive(Subcommand, Clone)]
+pub enum AuditCommand {
+    /// Show the score trend recorded by `--history-file` for a component
+    History(AuditHistoryArgs),
+}
+
+#[derive(Args, Clone)]
+pub struct AuditHistoryArgs {
+    /// Component ID as recorded in the history file
+    pub component: String,
+
+    /// History file written by `audit --history-file`
+    #[arg(long, value_name = "path")]
+    pub history_file: String,
+
+    /// Most recent runs to show
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AuditFormat {
+    Json,
+    Sarif,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Md,
+}
+
+/// Check if this invocation should print NDJSON or SARIF instead of the JSON
+/// envelope.
+pub fn is_plain_text_mode(args: &AuditArgs) -> bool {
+    args.json_lines || args.format == AuditFormat::Sarif
+}
+
+/// Plain-text output modes: NDJSON (one line per finding) or a SARIF log.
+pub fn run_plain_text(args: AuditArgs, global: &GlobalArgs) -> CmdResult<String> {
+    let format = args.format;
+    let (output, exit_code) = run(args, global)?;
+    let rendered = match format {
+        AuditFormat::Sarif => code_audit::sarif::audit_output_sarif(&output)?,
+        AuditFormat::Json => code_audit::json_lines::audit_output_json_lines(&output)?,
+    };
+    Ok((rendered, exit_code))
+}
+
+/// `audit history`: recorded runs for one component and their trend.
+pub fn run_history(
+    args: AuditArgs,
+    _global: &GlobalArgs,
+) -> CmdResult<code_audit::history::AuditHistoryOutput> {
+    let Some(AuditCommand::History(args)) = args.command else {
+        unreachable!("run_history is only dispatched for `audit history`");
+    };
+    let output =
+        code_audit::history::history(Path::new(&args.history_file), &args.component, args.limit)?;
+    Ok((output, 0))
 }
 
 fn parse_finding_kinds(
@@ -67,10 +210,23 @@ fn parse_finding_kinds(
 pub fn run(args: AuditArgs, _global: &GlobalArgs) -> CmdResult<AuditCommandOutput> {
     let only_kinds = parse_finding_kinds(&args.only, "only")?;
     let exclude_kinds = parse_finding_kinds(&args.exclude, "exclude")?;
+    let exit_policy = args.fail_on_args.policy()?;
+    let docs_min_confidence = args
+        .docs_min_confidence
+        .as_deref()
+        .map(|value| {
+            value.parse().map_err(|msg| {
+                homeboy::Error::validation_invalid_argument("docs-min-confidence", msg, None, None)
+            })
+        })
+        .transpose()?;
 
     // Run extension audit reference setup if configured.
     // This resolves framework dependencies (e.g. WordPress core) so their
     // fingerprints are included in cross-reference analysis (dead code detection).
+    // Cleared first so a previous component's references on this thread
+    // never leak into this run.
+    code_audit::set_reference_paths(None);
     if let Some(ref component_id) = args.comp.component {
         run_audit_reference_setup(component_id);
     }
@@ -113,19 +269,30 @@ pub fn run(args: AuditArgs, _global: &GlobalArgs) -> CmdResult<AuditCommandOutpu
         exclude_kinds,
         only_labels: args.only,
         exclude_labels: args.exclude,
+        docs_include: args.docs_include,
+        docs_exclude: args.docs_exclude,
+        docs_min_confidence,
+        docs_context_lines: args.docs_context_lines,
+        convention_threshold: args.threshold,
         baseline_flags: homeboy::engine::baseline::BaselineFlags {
             baseline: args.baseline_args.baseline,
             ignore_baseline: args.baseline_args.ignore_baseline,
             ratchet: args.baseline_args.ratchet,
         },
         changed_since: args.changed_since,
+        only_changed: args.only_changed,
         json_summary: args.json_summary,
         include_fixability: args.fixability,
+        exit_policy,
+        no_cache: args.no_cache,
     });
 
     let workflow = match workflow {
         Ok(workflow) => {
             finish_audit_observation(observation, &workflow);
+            if let Some(history_file) = args.history_file.as_deref() {
+                append_audit_history(history_file, &resolved_id, &resolved_path, &workflow)?;
+            }
             workflow
         }
         Err(error) => {
@@ -137,6 +304,19 @@ pub fn run(args: AuditArgs, _global: &GlobalArgs) -> CmdResult<AuditCommandOutpu
     Ok(report::from_main_workflow(workflow))
 }
 
+fn append_audit_history(
+    history_file: &str,
+    component_id: &str,
+    source_path: &str,
+    workflow: &code_audit::AuditRunWorkflowResult,
+) -> homeboy::Result<()> {
+    let git_sha = short_head_revision_at(Path::new(source_path));
+    match code_audit::history::AuditHistoryRecord::from_workflow(component_id, git_sha, workflow) {
+        Some(record) => code_audit::history::append(Path::new(history_file), &record),
+        None => Ok(()),
+    }
+}
+
 struct AuditObservation {
     store: ObservationStore,
     audit_run: RunRecord,
@@ -232,18 +412,49 @@ fn audit_observation_command(component_id: &str, args: &AuditArgs) -> String {
     for kind in &args.exclude {
         parts.push(format!("--exclude={kind}"));
     }
+    for pattern in &args.docs_include {
+        parts.push(format!("--docs-include={pattern}"));
+    }
+    for pattern in &args.docs_exclude {
+        parts.push(format!("--docs-exclude={pattern}"));
+    }
+    if let Some(level) = &args.docs_min_confidence {
+        parts.push(format!("--docs-min-confidence={level}"));
+    }
+    if let Some(lines) = args.docs_context_lines {
+        parts.push(format!("--docs-context-lines={lines}"));
+    }
+    if let Some(threshold) = args.threshold {
+        parts.push(format!("--threshold={threshold}"));
+    }
+    if let Some(level) = &args.fail_on_args.fail_on {
+        parts.push(format!("--fail-on={level}"));
+        parts.push(format!(
+            "--fail-exit-code={}",
+            args.fail_on_args.fail_exit_code
+        ));
+    }
     for extension in &args.extension_override.extensions {
         parts.push(format!("--extension={extension}"));
     }
     if let Some(changed_since) = &args.changed_since {
         parts.push(format!("--changed-since={changed_since}"));
     }
+    if args.only_changed {
+        parts.push("--only-changed".to_string());
+    }
     if args.json_summary {
         parts.push("--json-summary".to_string());
     }
     if args.fixability {
         parts.push("--fixability".to_string());
     }
+    if args.no_cache {
+        parts.push("--no-cache".to_string());
+    }
+    if args.format == AuditFormat::Sarif {
+        parts.push("--format=sarif".to_string());
+    }
     parts.join(" ")
 }
 
@@ -253,6 +464,11 @@ fn audit_observation_initial_metadata(source_path: &str, args: &AuditArgs) -> se
         "mode": if args.conventions { "conventions" } else { "audit" },
         "only": args.only,
         "exclude": args.exclude,
+        "docs_include": args.docs_include,
+        "docs_exclude": args.docs_exclude,
+        "docs_min_confidence": args.docs_min_confidence,
+        "docs_context_lines": args.docs_context_lines,
+        "threshold": args.threshold,
         "extensions": args.extension_override.extensions,
         "baseline": {
             "baseline": args.baseline_args.baseline,
@@ -260,6 +476,8 @@ fn audit_observation_initial_metadata(source_path: &str, args: &AuditArgs) -> se
             "ratchet": args.baseline_args.ratchet,
         },
         "changed_since": args.changed_since,
+        "only_changed": args.only_changed,
+        "no_cache": args.no_cache,
         "json_summary": args.json_summary,
         "fixability": args.fixability,
     })
@@ -345,8 +563,9 @@ fn merge_audit_observation_metadata(
 /// Run the extension's audit reference setup script if configured.
 ///
 /// Looks up the component's extension, checks for `audit.setup_references`, and runs it.
-/// The script exports `HOMEBOY_AUDIT_REFERENCE_PATHS` which the audit core reads
-/// to include framework dependencies in cross-reference analysis.
+/// The script exports `HOMEBOY_AUDIT_REFERENCE_PATHS`; its value is handed to
+/// the audit core for this thread so framework dependencies are included in
+/// cross-reference analysis.
 fn run_audit_reference_setup(component_id_or_path: &str) {
     // Skip for bare directory paths — no extension to look up
     if Path::new(component_id_or_path).is_dir() {
@@ -409,7 +628,7 @@ fn run_audit_reference_setup(component_id_or_path: &str) {
                         .trim_start_matches("$'")
                         .trim_start_matches('\'')
                         .trim_end_matches('\'');
-                    std::env::set_var("HOMEBOY_AUDIT_REFERENCE_PATHS", clean);
+                    code_audit::set_reference_paths(Some(clean.to_string()));
                     break;
                 }
             }
@@ -433,7 +652,7 @@ fn run_audit_reference_setup(component_id_or_path: &str) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commands::utils::args::{BaselineArgs, ExtensionOverrideArgs};
+    use crate::commands::utils::args::{BaselineArgs, ExtensionOverrideArgs, FailOnArgs};
     use crate::test_support::with_isolated_home;
     use clap::Parser;
     use std::fs;
@@ -477,6 +696,7 @@ mod tests {
 
     fn sample_args() -> AuditArgs {
         AuditArgs {
+            command: None,
             comp: PositionalComponentArgs {
                 component: Some("homeboy".to_string()),
                 path: None,
@@ -485,14 +705,31 @@ mod tests {
             conventions: false,
             only: vec![],
             exclude: vec![],
+            docs_include: vec![],
+            docs_exclude: vec![],
+            docs_min_confidence: None,
+            docs_context_lines: None,
+            threshold: None,
             baseline_args: BaselineArgs {
                 baseline: false,
                 ignore_baseline: false,
                 ratchet: false,
             },
+            fail_on_args: FailOnArgs::default(),
             changed_since: Some("origin/main".to_string()),
+            only_changed: false,
+            no_cache: true,
+            history_file: None,
             json_summary: true,
             fixability: false,
+            json_lines: false,
+            format: AuditFormat::Json,
+            all: false,
+            tag: Vec::new(),
+            parallel: None,
+            output_dir: None,
+            report_format: ReportFormat::Json,
+            force: false,
         }
     }
 
@@ -519,6 +756,45 @@ mod tests {
         assert_eq!(cli.audit.changed_since.as_deref(), Some("origin/main"));
     }
 
+    #[test]
+    fn parses_sarif_format_and_rejects_json_lines() {
+        let cli = TestCli::try_parse_from(["audit", "--path", "/tmp/repo", "--format", "sarif"])
+            .expect("audit should parse --format sarif");
+        assert_eq!(cli.audit.format, AuditFormat::Sarif);
+        assert!(is_plain_text_mode(&cli.audit));
+
+        assert!(TestCli::try_parse_from([
+            "audit",
+            "--path",
+            "/tmp/repo",
+            "--format",
+            "sarif",
+            "--json-lines",
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn parses_batch_output_dir_and_requires_all() {
+        let cli = TestCli::try_parse_from([
+            "audit",
+            "--all",
+            "--output-dir",
+            "reports",
+            "--report-format",
+            "md",
+            "--force",
+        ])
+        .expect("audit should parse --all --output-dir");
+        assert!(cli.audit.all);
+        assert_eq!(cli.audit.output_dir.as_deref(), Some("reports"));
+        assert_eq!(cli.audit.report_format, ReportFormat::Md);
+
+        assert!(TestCli::try_parse_from(["audit", "--output-dir", "reports"]).is_err());
+        assert!(TestCli::try_parse_from(["audit", "homeboy", "--all"]).is_err());
+        assert!(TestCli::try_parse_from(["audit", "--all", "--json-lines"]).is_err());
+    }
+
     #[test]
     fn audit_observation_start_persists_run_record() {
         with_isolated_home(|home| {
@@ -591,6 +867,7 @@ mod tests {
                         duplicate_groups: vec![],
                     },
                     fixability: None,
+                    broken_references: Vec::new(),
                 },
                 exit_code: 1,
                 findings: vec![finding],
@@ -660,6 +937,7 @@ mod tests {
         fs::write(root.join("commands/bad.rs"), "pub fn run() {}\n").unwrap();
 
         let args = AuditArgs {
+            command: None,
             comp: PositionalComponentArgs {
                 component: Some(root.to_string_lossy().to_string()),
                 path: None,
@@ -668,14 +946,31 @@ mod tests {
             conventions: false,
             only: vec![],
             exclude: vec![],
+            docs_include: vec![],
+            docs_exclude: vec![],
+            docs_min_confidence: None,
+            docs_context_lines: None,
+            threshold: None,
             baseline_args: BaselineArgs {
                 baseline: false,
                 ignore_baseline: true,
                 ratchet: false,
             },
+            fail_on_args: FailOnArgs::default(),
             changed_since: None,
+            only_changed: false,
+            no_cache: true,
+            history_file: None,
             json_summary: false,
             fixability: false,
+            json_lines: false,
+            format: AuditFormat::Json,
+            all: false,
+            tag: Vec::new(),
+            parallel: None,
+            output_dir: None,
+            report_format: ReportFormat::Json,
+            force: false,
         };
 
         let (output, code) = run(args, &crate::commands::GlobalArgs {}).expect("audit should run");
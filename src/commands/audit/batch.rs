@@ -0,0 +1,574 @@
+//! `audit --all` / `audit --tag`: audit many components in one run.
+//!
+//! `--all` selects every registered component, `--tag` the components of
+//! projects carrying the tags. Components are audited on a bounded pool of
+//! worker threads (`--parallel`) and reported in selection order, with the
+//! lowest alignment scores rolled up into the summary.
+//!
+//! With `--output-dir`, each component's report is written to
+//! `<dir>/<component>.json` (or `.md`) and an `index.json` records pass/fail
+//! for the whole batch, so audit snapshots can be committed and diffed.
+//! Without it, the results come back inline in the batch envelope.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use serde::Serialize;
+
+use homeboy::code_audit::{AuditCommandOutput, CodeAuditResult, Severity};
+use homeboy::{component, project};
+
+use super::{run, AuditArgs, ReportFormat};
+use crate::commands::{CmdResult, GlobalArgs};
+
+const INDEX_FILE: &str = "index.json";
+/// Upper bound on the default worker count; audits are CPU- and IO-heavy.
+const DEFAULT_PARALLEL_CAP: usize = 4;
+/// How many of the lowest alignment scores the summary highlights.
+const LOWEST_ALIGNMENT_COUNT: usize = 5;
+
+#[derive(Serialize)]
+pub struct AuditBatchOutput {
+    pub command: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_dir: Option<PathBuf>,
+    pub summary: BatchSummary,
+    pub components: Vec<ComponentAudit>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct BatchSummary {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    /// Components whose audit could not run at all.
+    pub errored: usize,
+    /// The lowest alignment scores, worst first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub lowest_alignment: Vec<ComponentScore>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentScore {
+    pub component_id: String,
+    pub alignment_score: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    Pass,
+    Fail,
+    Error,
+}
+
+#[derive(Serialize)]
+pub struct ComponentAudit {
+    pub component_id: String,
+    pub status: ComponentStatus,
+    pub exit_code: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub findings: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alignment_score: Option<f32>,
+    /// Report file name inside the output directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Full audit output, inline only when no output directory is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AuditCommandOutput>,
+}
+
+/// Audit every registered component. Exits with the highest component exit
+/// code, or 1 when any component errored.
+pub fn run_all(args: AuditArgs, global: &GlobalArgs) -> CmdResult<AuditBatchOutput> {
+    let output_dir = args.output_dir.as_deref().map(PathBuf::from);
+    let format = args.report_format;
+    if format == ReportFormat::Md && (args.conventions || args.baseline_args.baseline) {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "report-format",
+            "Markdown reports need a findings run",
+            None,
+            None,
+        )
+        .with_hint("Drop --conventions and --baseline with --report-format md"));
+    }
+
+    let ids = select_components(&args.tag)?;
+    if let Some(dir) = &output_dir {
+        prepare_output_dir(dir, &ids, format, args.force)?;
+    }
+
+    let parallel = args.parallel.unwrap_or_else(default_parallel).max(1);
+    let components = run_bounded(&ids, parallel, |id| {
+        homeboy::log_status!("audit", "Auditing {}", id);
+        let mut component_args = args.clone();
+        component_args.comp.component = Some(id.clone());
+        component_args.comp.path = None;
+
+        Ok(match run(component_args, global) {
+            Ok((output, exit_code)) => {
+                let report = match &output_dir {
+                    Some(dir) => Some(write_report(dir, id, &output, format)?),
+                    None => None,
+                };
+                ComponentAudit {
+                    findings: finding_count(&output),
+                    alignment_score: alignment_score(&output),
+                    status: if exit_code == 0 {
+                        ComponentStatus::Pass
+                    } else {
+                        ComponentStatus::Fail
+                    },
+                    exit_code,
+                    report,
+                    error: None,
+                    result: output_dir.is_none().then_some(output),
+                    component_id: id.clone(),
+                }
+            }
+            Err(err) => ComponentAudit {
+                component_id: id.clone(),
+                status: ComponentStatus::Error,
+                exit_code: 1,
+                findings: None,
+                alignment_score: None,
+                report: None,
+                error: Some(err.message),
+                result: None,
+            },
+        })
+    })
+    .into_iter()
+    .collect::<homeboy::Result<Vec<_>>>()?;
+
+    let exit_code = components
+        .iter()
+        .map(|component| component.exit_code)
+        .max()
+        .unwrap_or(0);
+    let output = AuditBatchOutput {
+        command: "audit.batch",
+        summary: summarize(&components),
+        output_dir,
+        components,
+    };
+    if let Some(dir) = &output.output_dir {
+        write_file(&dir.join(INDEX_FILE), &to_json(&output)?)?;
+    }
+    Ok((output, exit_code))
+}
+
+/// Component IDs to audit, skipping archived components: every registered
+/// component, or with `tags`, those attached to projects carrying every tag
+/// (in project order, each once).
+fn select_components(tags: &[String]) -> homeboy::Result<Vec<String>> {
+    let components = component::list()?;
+    if tags.is_empty() {
+        return Ok(components
+            .into_iter()
+            .filter(|component| !component.archived)
+            .map(|component| component.id)
+            .collect());
+    }
+
+    let archived: Vec<String> = components
+        .into_iter()
+        .filter(|component| component.archived)
+        .map(|component| component.id)
+        .collect();
+    let mut ids: Vec<String> = Vec::new();
+    for project in project::list_tagged(tags)? {
+        for id in project::project_component_ids(&project) {
+            if !ids.contains(&id) && !archived.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    if ids.is_empty() {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "tag",
+            format!(
+                "No components are attached to projects tagged {}",
+                tags.join(", ")
+            ),
+            None,
+            None,
+        )
+        .with_hint("Run `homeboy project list --tag <tag>` to see tagged projects"));
+    }
+    Ok(ids)
+}
+
+fn default_parallel() -> usize {
+    thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(DEFAULT_PARALLEL_CAP)
+}
+
+/// Run `work` over `items` on at most `limit` worker threads. Results keep
+/// input order.
+fn run_bounded<T, R, F>(items: &[T], limit: usize, work: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let mut done: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..limit.clamp(1, items.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        done.push((index, work(item)));
+                    }
+                    done
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect()
+    });
+    done.sort_by_key(|(index, _)| *index);
+    done.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Create `dir` and refuse to overwrite existing reports unless `force`.
+fn prepare_output_dir(
+    dir: &Path,
+    ids: &[String],
+    format: ReportFormat,
+    force: bool,
+) -> homeboy::Result<()> {
+    std::fs::create_dir_all(dir).map_err(|e| io_error(e, dir, "create output directory"))?;
+    if force {
+        return Ok(());
+    }
+
+    let existing: Vec<String> = ids
+        .iter()
+        .map(|id| report_file_name(id, format))
+        .chain(std::iter::once(INDEX_FILE.to_string()))
+        .filter(|name| dir.join(name).exists())
+        .collect();
+    if existing.is_empty() {
+        return Ok(());
+    }
+    Err(homeboy::Error::validation_invalid_argument(
+        "output-dir",
+        format!(
+            "{} already holds audit reports: {}",
+            dir.display(),
+            existing.join(", ")
+        ),
+        Some(dir.to_string_lossy().to_string()),
+        None,
+    )
+    .with_hint("Pass --force to overwrite them"))
+}
+
+fn report_file_name(id: &str, format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Json => format!("{}.json", id),
+        ReportFormat::Md => format!("{}.md", id),
+    }
+}
+
+fn write_report(
+    dir: &Path,
+    id: &str,
+    output: &AuditCommandOutput,
+    format: ReportFormat,
+) -> homeboy::Result<String> {
+    let name = report_file_name(id, format);
+    let content = match format {
+        ReportFormat::Json => to_json(output)?,
+        ReportFormat::Md => render_markdown(id, output),
+    };
+    write_file(&dir.join(&name), &content)?;
+    Ok(name)
+}
+
+fn write_file(path: &Path, content: &str) -> homeboy::Result<()> {
+    std::fs::write(path, content).map_err(|e| io_error(e, path, "write audit report"))
+}
+
+fn io_error(error: std::io::Error, path: &Path, action: &str) -> homeboy::Error {
+    homeboy::Error::internal_io(
+        error.to_string(),
+        Some(format!("{} {}", action, path.display())),
+    )
+}
+
+fn to_json(value: &impl Serialize) -> homeboy::Result<String> {
+    let mut json = serde_json::to_string_pretty(value).map_err(|e| {
+        homeboy::Error::internal_json(e.to_string(), Some("serialize audit report".to_string()))
+    })?;
+    json.push('\n');
+    Ok(json)
+}
+
+fn summarize(components: &[ComponentAudit]) -> BatchSummary {
+    let count = |status| {
+        components
+            .iter()
+            .filter(|component| component.status == status)
+            .count()
+    };
+    BatchSummary {
+        total: components.len(),
+        passed: count(ComponentStatus::Pass),
+        failed: count(ComponentStatus::Fail),
+        errored: count(ComponentStatus::Error),
+        lowest_alignment: lowest_alignment(components),
+    }
+}
+
+fn lowest_alignment(components: &[ComponentAudit]) -> Vec<ComponentScore> {
+    let mut scores: Vec<ComponentScore> = components
+        .iter()
+        .filter_map(|component| {
+            Some(ComponentScore {
+                component_id: component.component_id.clone(),
+                alignment_score: component.alignment_score?,
+            })
+        })
+        .collect();
+    scores.sort_by(|a, b| a.alignment_score.total_cmp(&b.alignment_score));
+    scores.truncate(LOWEST_ALIGNMENT_COUNT);
+    scores
+}
+
+fn alignment_score(output: &AuditCommandOutput) -> Option<f32> {
+    match output {
+        AuditCommandOutput::Full { result, .. } | AuditCommandOutput::Compared { result, .. } => {
+            result.summary.alignment_score
+        }
+        _ => None,
+    }
+}
+
+fn finding_count(output: &AuditCommandOutput) -> Option<usize> {
+    match output {
+        AuditCommandOutput::Full { result, .. } | AuditCommandOutput::Compared { result, .. } => {
+            Some(result.findings.len())
+        }
+        AuditCommandOutput::Summary(summary) => Some(summary.total_findings),
+        AuditCommandOutput::BaselineSaved { findings_count, .. } => Some(*findings_count),
+        AuditCommandOutput::Conventions { .. } => None,
+    }
+}
+
+fn render_markdown(id: &str, output: &AuditCommandOutput) -> String {
+    let mut out = format!("# Audit: {}\n\n", id);
+    match output {
+        AuditCommandOutput::Full { passed, result, .. }
+        | AuditCommandOutput::Compared { passed, result, .. } => {
+            render_result(&mut out, *passed, result)
+        }
+        AuditCommandOutput::Summary(summary) => {
+            let _ = writeln!(out, "- Findings: {}", summary.total_findings);
+            let _ = writeln!(out, "- Warnings: {}", summary.warnings);
+            let _ = writeln!(out, "- Info: {}", summary.info);
+        }
+        AuditCommandOutput::Conventions { .. } | AuditCommandOutput::BaselineSaved { .. } => {}
+    }
+    out
+}
+
+fn render_result(out: &mut String, passed: bool, result: &CodeAuditResult) {
+    let summary = &result.summary;
+    let _ = writeln!(out, "- Status: {}", if passed { "pass" } else { "fail" });
+    let _ = writeln!(out, "- Files scanned: {}", summary.files_scanned);
+    let _ = writeln!(out, "- Findings: {}", result.findings.len());
+    if let Some(score) = summary.alignment_score {
+        let _ = writeln!(out, "- Alignment score: {:.2}", score);
+    }
+
+    if result.findings.is_empty() {
+        out.push_str("\nNo findings.\n");
+        return;
+    }
+    out.push_str(
+        "\n## Findings\n\n| Severity | Kind | File | Description |\n| --- | --- | --- | --- |\n",
+    );
+    for finding in &result.findings {
+        let kind = serde_json::to_value(&finding.kind)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default();
+        let severity = match finding.severity {
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        let _ = writeln!(
+            out,
+            "| {} | {} | `{}` | {} |",
+            severity,
+            kind,
+            finding.file,
+            table_cell(&finding.description)
+        );
+    }
+}
+
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use homeboy::code_audit::{AuditFinding, AuditSummary, Finding};
+
+    fn full_output(findings: Vec<Finding>) -> AuditCommandOutput {
+        AuditCommandOutput::Full {
+            passed: findings.is_empty(),
+            result: CodeAuditResult {
+                component_id: "widget".to_string(),
+                source_path: "/tmp/widget".to_string(),
+                summary: AuditSummary {
+                    files_scanned: 4,
+                    conventions_detected: 1,
+                    outliers_found: findings.len(),
+                    alignment_score: Some(0.75),
+                    files_skipped: 0,
+                    warnings: Vec::new(),
+                },
+                conventions: Vec::new(),
+                directory_conventions: Vec::new(),
+                findings,
+                duplicate_groups: Vec::new(),
+            },
+            fixability: None,
+            broken_references: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn markdown_report_lists_findings() {
+        let output = full_output(vec![Finding {
+            convention: "Handlers".to_string(),
+            severity: Severity::Warning,
+            file: "src/handlers/b.rs".to_string(),
+            description: "Missing method: a|b".to_string(),
+            suggestion: String::new(),
+            kind: AuditFinding::MissingMethod,
+        }]);
+
+        let markdown = render_markdown("widget", &output);
+        assert!(markdown.starts_with("# Audit: widget\n"));
+        assert!(markdown.contains("- Status: fail"));
+        assert!(markdown.contains("- Alignment score: 0.75"));
+        assert!(markdown.contains(
+            "| warning | missing_method | `src/handlers/b.rs` | Missing method: a\\|b |"
+        ));
+    }
+
+    #[test]
+    fn output_dir_refuses_existing_reports_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let ids = vec!["alpha".to_string(), "beta".to_string()];
+        prepare_output_dir(dir.path(), &ids, ReportFormat::Json, false).unwrap();
+
+        std::fs::write(dir.path().join("beta.json"), "{}").unwrap();
+        std::fs::write(dir.path().join(INDEX_FILE), "{}").unwrap();
+        let err = prepare_output_dir(dir.path(), &ids, ReportFormat::Json, false).unwrap_err();
+        assert!(err.message.contains("beta.json, index.json"));
+
+        prepare_output_dir(dir.path(), &ids, ReportFormat::Md, false).unwrap_err();
+        prepare_output_dir(dir.path(), &ids, ReportFormat::Json, true).unwrap();
+    }
+
+    #[test]
+    fn writes_report_and_counts_statuses() {
+        let dir = tempfile::tempdir().unwrap();
+        let name = write_report(
+            dir.path(),
+            "widget",
+            &full_output(Vec::new()),
+            ReportFormat::Json,
+        )
+        .unwrap();
+        assert_eq!(name, "widget.json");
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(dir.path().join(name)).unwrap()).unwrap();
+        assert_eq!(written["command"], "audit");
+        assert_eq!(written["passed"], true);
+
+        let entry = |status| ComponentAudit {
+            component_id: "widget".to_string(),
+            status,
+            exit_code: 0,
+            findings: None,
+            alignment_score: None,
+            report: None,
+            error: None,
+            result: None,
+        };
+        let summary = summarize(&[
+            entry(ComponentStatus::Pass),
+            entry(ComponentStatus::Fail),
+            entry(ComponentStatus::Error),
+            entry(ComponentStatus::Pass),
+        ]);
+        assert_eq!(
+            (
+                summary.total,
+                summary.passed,
+                summary.failed,
+                summary.errored
+            ),
+            (4, 2, 1, 1)
+        );
+    }
+
+    #[test]
+    fn run_bounded_keeps_input_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = run_bounded(&items, 3, |n| {
+            std::thread::sleep(std::time::Duration::from_millis(20 - n));
+            n * 2
+        });
+        assert_eq!(results, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+        assert!(run_bounded(&[] as &[u64], 4, |n| *n).is_empty());
+    }
+
+    #[test]
+    fn lowest_alignment_lists_worst_scores_first() {
+        let scored = |id: &str, score| ComponentAudit {
+            component_id: id.to_string(),
+            status: ComponentStatus::Pass,
+            exit_code: 0,
+            findings: None,
+            alignment_score: score,
+            report: None,
+            error: None,
+            result: None,
+        };
+        let lowest = lowest_alignment(&[
+            scored("a", Some(0.9)),
+            scored("b", None),
+            scored("c", Some(0.4)),
+            scored("d", Some(0.7)),
+        ]);
+        let ids: Vec<&str> = lowest.iter().map(|s| s.component_id.as_str()).collect();
+        assert_eq!(ids, ["c", "d", "a"]);
+    }
+}
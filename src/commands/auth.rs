@@ -3,7 +3,8 @@ use serde::Serialize;
 use std::collections::HashMap;
 
 use homeboy::server::auth::{
-    self, AuthStatus, GetResult, LoginResult, LogoutResult, RemoveResult, SetResult,
+    self, AuthStatus, AuthStatusAll, GetResult, LoginResult, LogoutAllResult, LogoutResult,
+    RemoveResult, SetResult,
 };
 use homeboy::server::auth_profiles::{
     self, ProfileRemoveResult, ProfileSetResult, ProfileStatusResult,
@@ -72,18 +73,16 @@ enum AuthCommand {
         variable: String,
     },
 
-    /// Clear stored authentication for a project
+    /// Clear stored authentication for a project (or every project with --all)
     Logout {
-        /// Project ID
-        #[arg(long)]
-        project: String,
+        #[command(flatten)]
+        target: ProjectTarget,
     },
 
-    /// Show authentication status for a project
+    /// Show authentication status for a project (or every project with --all)
     Status {
-        /// Project ID
-        #[arg(long)]
-        project: String,
+        #[command(flatten)]
+        target: ProjectTarget,
     },
 
     /// Manage reusable auth profiles for generic HTTP requests
@@ -93,6 +92,29 @@ enum AuthCommand {
     },
 }
 
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct ProjectTarget {
+    /// Project ID
+    #[arg(value_name = "PROJECT")]
+    project_id: Option<String>,
+
+    /// Project ID (same as the positional argument)
+    #[arg(long = "project", value_name = "PROJECT")]
+    project: Option<String>,
+
+    /// Every project with a stored credential
+    #[arg(long)]
+    all: bool,
+}
+
+impl ProjectTarget {
+    /// The selected project, or `None` for `--all`.
+    fn project(&self) -> Option<&str> {
+        self.project_id.as_deref().or(self.project.as_deref())
+    }
+}
+
 #[derive(Subcommand)]
 enum ProfileCommand {
     /// Store a Basic auth profile in the OS keychain
@@ -140,7 +162,9 @@ pub enum AuthOutput {
     Get(GetResult),
     Remove(RemoveResult),
     Logout(LogoutResult),
+    LogoutAll(LogoutAllResult),
     Status(AuthStatus),
+    StatusAll(AuthStatusAll),
     ProfileSet(ProfileSetResult),
     ProfileStatus(ProfileStatusResult),
     ProfileRemove(ProfileRemoveResult),
@@ -164,8 +188,8 @@ pub fn run(args: AuthArgs, _global: &GlobalArgs) -> CmdResult<AuthOutput> {
             redacted,
         } => run_get(&project, &variable, redacted),
         AuthCommand::Remove { project, variable } => run_remove(&project, &variable),
-        AuthCommand::Logout { project } => run_logout(&project),
-        AuthCommand::Status { project } => run_status(&project),
+        AuthCommand::Logout { target } => run_logout(target.project()),
+        AuthCommand::Status { target } => run_status(target.project()),
         AuthCommand::Profile { command } => run_profile(command),
     }
 }
@@ -256,12 +280,16 @@ fn run_remove(project_id: &str, variable: &str) -> CmdResult<AuthOutput> {
     Ok((AuthOutput::Remove(result), 0))
 }
 
-fn run_logout(project_id: &str) -> CmdResult<AuthOutput> {
-    let result = auth::logout(project_id)?;
-    Ok((AuthOutput::Logout(result), 0))
+fn run_logout(project_id: Option<&str>) -> CmdResult<AuthOutput> {
+    match project_id {
+        Some(project_id) => Ok((AuthOutput::Logout(auth::logout(project_id)?), 0)),
+        None => Ok((AuthOutput::LogoutAll(auth::logout_all()?), 0)),
+    }
 }
 
-fn run_status(project_id: &str) -> CmdResult<AuthOutput> {
-    let result = auth::status(project_id)?;
-    Ok((AuthOutput::Status(result), 0))
+fn run_status(project_id: Option<&str>) -> CmdResult<AuthOutput> {
+    match project_id {
+        Some(project_id) => Ok((AuthOutput::Status(auth::status(project_id)?), 0)),
+        None => Ok((AuthOutput::StatusAll(auth::status_all()?), 0)),
+    }
 }
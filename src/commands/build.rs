@@ -1,6 +1,8 @@
+use std::path::PathBuf;
+
 use clap::Args;
 use homeboy::build;
-use homeboy::component;
+use homeboy::component::{self, Component};
 use homeboy::engine::execution_context::{self, ResolveOptions};
 use homeboy::extension::ExtensionCapability;
 use homeboy::project;
@@ -27,12 +29,64 @@ pub struct BuildArgs {
     /// Override local_path for this build (use a workspace clone or temp checkout)
     #[arg(long)]
     pub path: Option<String>,
+
+    /// Load variables from a .env file for this run (shell env takes precedence)
+    #[arg(long, value_name = "PATH")]
+    pub env_file: Option<String>,
+
+    /// Build archived components too
+    #[arg(long)]
+    pub force: bool,
+
+    /// Archive the publishable files after a successful build (zip or tar.gz)
+    #[arg(long, value_name = "FORMAT", value_parser = parse_package_format, conflicts_with = "json")]
+    pub package: Option<build::PackageFormat>,
+
+    /// Directory for the package archive (default: <artifact_root>/packages/<component>)
+    #[arg(long, value_name = "DIR", requires = "package")]
+    pub output_dir: Option<String>,
+
+    /// Extra gitignore-style glob to leave out of the package (repeatable)
+    #[arg(long, value_name = "GLOB", requires = "package")]
+    pub exclude: Vec<String>,
+}
+
+fn parse_package_format(value: &str) -> Result<build::PackageFormat, String> {
+    build::PackageFormat::parse(value).map_err(|e| e.message)
+}
+
+impl BuildArgs {
+    fn package_options(&self) -> Option<build::PackageOptions> {
+        self.package.map(|format| build::PackageOptions {
+            format,
+            output_dir: self.output_dir.as_ref().map(PathBuf::from),
+            exclude: self.exclude.clone(),
+        })
+    }
+}
+
+/// Package the built components when `--package` was given.
+fn finish(
+    result: homeboy::Result<(build::BuildResult, i32)>,
+    components: &[Component],
+    package: Option<&build::PackageOptions>,
+) -> CmdResult<build::BuildResult> {
+    match package {
+        Some(options) => build::package_build_result(result?, components, options),
+        None => result,
+    }
 }
 
 pub fn run(
     args: BuildArgs,
     _global: &crate::commands::GlobalArgs,
 ) -> CmdResult<build::BuildResult> {
+    if let Some(ref path) = args.env_file {
+        crate::commands::utils::dotenv::load(path)?;
+    }
+
+    let package = args.package_options();
+
     // Priority: --json > --all with project > positional args
 
     // JSON takes precedence
@@ -49,7 +103,9 @@ pub fn run(
             ExtensionCapability::Build,
             Vec::new(),
         ))?;
-        return build::run_component(&ctx.component);
+        ctx.component.ensure_active("build", args.force)?;
+        let result = build::run_component(&ctx.component);
+        return finish(result, &[ctx.component], package.as_ref());
     }
 
     let target_id = args.target_id.as_ref().ok_or_else(|| {
@@ -92,8 +148,22 @@ pub fn run(
             ));
         }
 
-        let components = project::resolve_project_components(&proj)?;
-        return build::run_components(&components);
+        let components: Vec<_> = project::resolve_project_components(&proj)?
+            .into_iter()
+            .filter(|component| {
+                let skip = component.archived && !args.force;
+                if skip {
+                    homeboy::log_status!(
+                        "build",
+                        "Skipping '{}': component is archived",
+                        component.id
+                    );
+                }
+                !skip
+            })
+            .collect();
+        let result = build::run_components(&components);
+        return finish(result, &components, package.as_ref());
     }
 
     // Multiple positional args: use shared resolver
@@ -132,14 +202,22 @@ pub fn run(
             .iter()
             .map(|id| project::resolve_project_component(&proj, id))
             .collect();
+        let components = components?;
+        for component in &components {
+            component.ensure_active("build", args.force)?;
+        }
 
-        return build::run_components(&components?);
+        let result = build::run_components(&components);
+        return finish(result, &components, package.as_ref());
     }
 
     // Single target_id: treat as component ID
-    if let Some(ref path) = args.path {
+    let component = component::resolve_effective(Some(target_id), args.path.as_deref(), None)?;
+    component.ensure_active("build", args.force)?;
+    let result = if let Some(ref path) = args.path {
         build::run_with_path(target_id, path)
     } else {
         build::run(target_id)
-    }
+    };
+    finish(result, &[component], package.as_ref())
 }
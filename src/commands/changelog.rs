@@ -2,7 +2,7 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use super::CmdResult;
-use homeboy::changelog::{self, ShowOutput};
+use homeboy::changelog::{self, ChangelogLintOutput, ConvertOutput, ReleaseRange, ShowOutput};
 
 #[derive(Args)]
 pub struct ChangelogArgs {
@@ -20,7 +20,57 @@ pub enum ChangelogCommand {
     Show {
         /// Component ID to show changelog for
         component_id: Option<String>,
+
+        #[command(flatten)]
+        range: ReleaseRangeArgs,
+    },
+    /// Check a component's changelog against Keep a Changelog conventions
+    Lint {
+        /// Component ID whose changelog to lint
+        component_id: String,
     },
+    /// Convert a component's changelog to another format (markdown, json, conventional)
+    Convert {
+        /// Component ID whose changelog to convert
+        component_id: String,
+
+        /// Target format
+        #[arg(long, value_name = "FORMAT")]
+        to: String,
+
+        /// Output path relative to the component (default: current path with the format's extension)
+        #[arg(long, value_name = "PATH")]
+        output: Option<String>,
+
+        /// Print the converted changelog without writing it or updating config
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Args, Default)]
+pub struct ReleaseRangeArgs {
+    /// Oldest release to include (inclusive)
+    #[arg(long, value_name = "VERSION")]
+    pub from: Option<String>,
+
+    /// Newest release to include (inclusive)
+    #[arg(long, value_name = "VERSION")]
+    pub to: Option<String>,
+
+    /// Show only the pending (Unreleased) section
+    #[arg(long, conflicts_with_all = ["from", "to"])]
+    pub unreleased_only: bool,
+}
+
+impl ReleaseRangeArgs {
+    fn to_range(&self) -> ReleaseRange {
+        ReleaseRange {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            unreleased_only: self.unreleased_only,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -36,21 +86,48 @@ pub enum ChangelogOutput {
     Show(ChangelogShowOutput),
 
     ShowComponent(ShowOutput),
+
+    Lint(ChangelogLintOutput),
+
+    Convert(ConvertOutput),
 }
 
 pub fn run_markdown(args: ChangelogArgs) -> CmdResult<String> {
     match (&args.command, args.show_self) {
-        (None, true) => show_homeboy_markdown(),
-        (Some(ChangelogCommand::Show { component_id: None }), _) => show_homeboy_markdown(),
+        (None, true) => show_homeboy_markdown(&ReleaseRange::default()),
+        (
+            Some(ChangelogCommand::Show {
+                component_id: None,
+                range,
+            }),
+            _,
+        ) => show_homeboy_markdown(&range.to_range()),
         (
             Some(ChangelogCommand::Show {
                 component_id: Some(id),
+                range,
             }),
             _,
         ) => {
-            let output = changelog::show(id)?;
+            let output = changelog::show_range(id, &range.to_range())?;
             Ok((output.content, 0))
         }
+        (Some(ChangelogCommand::Lint { .. }), _) => {
+            Err(homeboy::Error::validation_invalid_argument(
+                "output_mode",
+                "changelog lint does not support markdown output",
+                None,
+                None,
+            ))
+        }
+        (Some(ChangelogCommand::Convert { .. }), _) => {
+            Err(homeboy::Error::validation_invalid_argument(
+                "output_mode",
+                "changelog convert does not support markdown output",
+                None,
+                None,
+            ))
+        }
         (None, false) => Err(homeboy::Error::validation_invalid_argument(
             "command",
             "No subcommand provided. Use 'show' or --self to view Homeboy's changelog",
@@ -74,22 +151,46 @@ pub fn run(
 ) -> CmdResult<ChangelogOutput> {
     match (&args.command, args.show_self) {
         (None, true) => {
-            let (out, code) = show_homeboy_json()?;
+            let (out, code) = show_homeboy_json(&ReleaseRange::default())?;
             Ok((ChangelogOutput::Show(out), code))
         }
-        (Some(ChangelogCommand::Show { component_id: None }), _) => {
-            let (out, code) = show_homeboy_json()?;
+        (
+            Some(ChangelogCommand::Show {
+                component_id: None,
+                range,
+            }),
+            _,
+        ) => {
+            let (out, code) = show_homeboy_json(&range.to_range())?;
             Ok((ChangelogOutput::Show(out), code))
         }
         (
             Some(ChangelogCommand::Show {
                 component_id: Some(id),
+                range,
             }),
             _,
         ) => {
-            let output = changelog::show(id)?;
+            let output = changelog::show_range(id, &range.to_range())?;
             Ok((ChangelogOutput::ShowComponent(output), 0))
         }
+        (Some(ChangelogCommand::Lint { component_id }), _) => {
+            let output = changelog::lint(component_id)?;
+            let exit_code = if output.passed { 0 } else { 1 };
+            Ok((ChangelogOutput::Lint(output), exit_code))
+        }
+        (
+            Some(ChangelogCommand::Convert {
+                component_id,
+                to,
+                output,
+                dry_run,
+            }),
+            _,
+        ) => {
+            let output = changelog::convert(component_id, to, output.as_deref(), *dry_run)?;
+            Ok((ChangelogOutput::Convert(output), 0))
+        }
         (None, false) => Err(homeboy::Error::validation_invalid_argument(
             "command",
             "No subcommand provided. Use 'show' or --self to view Homeboy's changelog",
@@ -97,6 +198,8 @@ pub fn run(
             Some(vec![
                 "homeboy changelog show".to_string(),
                 "homeboy changelog show <component_id>".to_string(),
+                "homeboy changelog lint <component_id>".to_string(),
+                "homeboy changelog convert <component_id> --to json".to_string(),
             ]),
         )),
     }
@@ -106,15 +209,23 @@ pub fn run(
 // to avoid collision with docs/commands/changelog.md command docs.
 const HOMEBOY_CHANGELOG: &str = include_str!("../../docs/changelog.md");
 
-fn show_homeboy_markdown() -> CmdResult<String> {
-    Ok((HOMEBOY_CHANGELOG.to_string(), 0))
+fn homeboy_changelog(range: &ReleaseRange) -> homeboy::Result<String> {
+    if range.is_full() {
+        return Ok(HOMEBOY_CHANGELOG.to_string());
+    }
+    let settings = changelog::resolve_effective_settings(None);
+    changelog::slice_releases(HOMEBOY_CHANGELOG, range, &settings.next_section_aliases)
+}
+
+fn show_homeboy_markdown(range: &ReleaseRange) -> CmdResult<String> {
+    Ok((homeboy_changelog(range)?, 0))
 }
 
-fn show_homeboy_json() -> CmdResult<ChangelogShowOutput> {
+fn show_homeboy_json(range: &ReleaseRange) -> CmdResult<ChangelogShowOutput> {
     Ok((
         ChangelogShowOutput {
             topic_label: "changelog".to_string(),
-            content: HOMEBOY_CHANGELOG.to_string(),
+            content: homeboy_changelog(range)?,
         },
         0,
     ))
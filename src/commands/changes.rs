@@ -31,9 +31,25 @@ pub struct ChangesArgs {
     pub json: Option<String>,
 
     /// Compare against specific tag instead of latest
-    #[arg(long)]
+    #[arg(long, value_name = "TAG")]
+    pub since_tag: Option<String>,
+
+    /// Find the last release among tags of this shape, with `{{version}}`
+    /// marking the version (e.g. `api-v{{version}}`). Overrides the
+    /// component's `tag_pattern`
+    #[arg(long, value_name = "PATTERN", conflicts_with_all = ["since_tag", "since", "until"])]
+    pub since_tag_pattern: Option<String>,
+
+    /// Only commits on or after this date or duration back from today
+    /// (2025-03-01, 3d, 2w, 6m, 1y). Replaces the tag baseline. A value that
+    /// is not a date or duration is treated as a tag, as in older releases.
+    #[arg(long, value_name = "DATE|DURATION")]
     pub since: Option<String>,
 
+    /// Only commits on or before this date or duration back from today
+    #[arg(long, value_name = "DATE|DURATION")]
+    pub until: Option<String>,
+
     /// Include commit range diff in output (uncommitted diff is always included)
     #[arg(long)]
     pub git_diffs: bool,
@@ -50,24 +66,35 @@ pub fn run(
     args: ChangesArgs,
     _global: &crate::commands::GlobalArgs,
 ) -> CmdResult<ChangesCommandOutput> {
+    let (since_tag, window) = git::resolve_changes_baseline(
+        args.since_tag.as_deref(),
+        args.since.as_deref(),
+        args.until.as_deref(),
+    )?;
+    let window = window.as_ref();
+
     // Priority: --json > --project flag > positional args
     if let Some(json) = &args.json {
-        reject_path_for_bulk(args.path.as_deref(), "--json")?;
-        let output = git::changes_bulk(json, args.git_diffs)?;
+        reject_single_options_for_bulk(&args, "--json")?;
+        let output = git::changes_bulk(json, window, args.git_diffs)?;
         let exit_code = if output.summary.failed > 0 { 1 } else { 0 };
         return Ok((ChangesCommandOutput::Bulk(output), exit_code));
     }
 
     // --project flag mode (with optional component filter from positional args)
     if let Some(project_id) = &args.project {
-        reject_path_for_bulk(args.path.as_deref(), "--project")?;
+        reject_single_options_for_bulk(&args, "--project")?;
         if args.component_ids.is_empty() {
-            let output = git::changes_project(project_id, args.git_diffs)?;
+            let output = git::changes_project(project_id, window, args.git_diffs)?;
             let exit_code = if output.summary.failed > 0 { 1 } else { 0 };
             return Ok((ChangesCommandOutput::Bulk(output), exit_code));
         } else {
-            let output =
-                git::changes_project_filtered(project_id, &args.component_ids, args.git_diffs)?;
+            let output = git::changes_project_filtered(
+                project_id,
+                &args.component_ids,
+                window,
+                args.git_diffs,
+            )?;
             let exit_code = if output.summary.failed > 0 { 1 } else { 0 };
             return Ok((ChangesCommandOutput::Bulk(output), exit_code));
         }
@@ -77,11 +104,11 @@ pub fn run(
     if let Some(target_id) = &args.target_id {
         // Multiple args: use shared resolver to detect order
         if !args.component_ids.is_empty() {
-            reject_path_for_bulk(args.path.as_deref(), "project positional mode")?;
+            reject_single_options_for_bulk(&args, "project positional mode")?;
             let (project_id, component_ids) =
                 resolve_project_components(target_id, &args.component_ids)?;
             let output =
-                git::changes_project_filtered(&project_id, &component_ids, args.git_diffs)?;
+                git::changes_project_filtered(&project_id, &component_ids, window, args.git_diffs)?;
             let exit_code = if output.summary.failed > 0 { 1 } else { 0 };
             return Ok((ChangesCommandOutput::Bulk(output), exit_code));
         }
@@ -89,7 +116,9 @@ pub fn run(
         if let Some(path) = args.path.as_deref() {
             let output = git::changes_at(
                 Some(target_id),
-                args.since.as_deref(),
+                since_tag.as_deref(),
+                window,
+                args.since_tag_pattern.as_deref(),
                 args.git_diffs,
                 Some(path),
             )?;
@@ -97,11 +126,19 @@ pub fn run(
         }
 
         // Single target_id: try as component first, fall back to project
-        match git::changes(Some(target_id), args.since.as_deref(), args.git_diffs) {
+        match git::changes_at(
+            Some(target_id),
+            since_tag.as_deref(),
+            window,
+            args.since_tag_pattern.as_deref(),
+            args.git_diffs,
+            None,
+        ) {
             Ok(output) => return Ok((ChangesCommandOutput::Single(Box::new(output)), 0)),
             Err(e) => {
                 if project::exists(target_id) {
-                    let output = git::changes_project(target_id, args.git_diffs)?;
+                    reject_single_options_for_bulk(&args, "project mode")?;
+                    let output = git::changes_project(target_id, window, args.git_diffs)?;
                     let exit_code = if output.summary.failed > 0 { 1 } else { 0 };
                     return Ok((ChangesCommandOutput::Bulk(output), exit_code));
                 }
@@ -111,7 +148,14 @@ pub fn run(
     }
 
     if let Some(path) = args.path.as_deref() {
-        let output = git::changes_at(None, args.since.as_deref(), args.git_diffs, Some(path))?;
+        let output = git::changes_at(
+            None,
+            since_tag.as_deref(),
+            window,
+            args.since_tag_pattern.as_deref(),
+            args.git_diffs,
+            Some(path),
+        )?;
         return Ok((ChangesCommandOutput::Single(Box::new(output)), 0));
     }
 
@@ -120,7 +164,14 @@ pub fn run(
     // Auto-use when exactly one component is matched
     if ctx.managed && ctx.matched_components.len() == 1 {
         let component_id = &ctx.matched_components[0];
-        let output = git::changes(Some(component_id), args.since.as_deref(), args.git_diffs)?;
+        let output = git::changes_at(
+            Some(component_id),
+            since_tag.as_deref(),
+            window,
+            args.since_tag_pattern.as_deref(),
+            args.git_diffs,
+            None,
+        )?;
         return Ok((ChangesCommandOutput::Single(Box::new(output)), 0));
     }
 
@@ -148,8 +199,21 @@ pub fn run(
     Err(err)
 }
 
-fn reject_path_for_bulk(path: Option<&str>, mode: &str) -> homeboy::Result<()> {
-    if path.is_some() {
+fn reject_single_options_for_bulk(args: &ChangesArgs, mode: &str) -> homeboy::Result<()> {
+    if args.since_tag_pattern.is_some() {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "since_tag_pattern",
+            format!(
+                "--since-tag-pattern is only supported for single-component changes, not {}",
+                mode
+            ),
+            None,
+            Some(vec![
+                "Set tag_pattern on each component for bulk changes: homeboy component set <id> tag_pattern=<pattern>".to_string(),
+            ]),
+        ));
+    }
+    if args.path.is_some() {
         return Err(homeboy::Error::validation_invalid_argument(
             "path",
             format!(
@@ -1,4 +1,4 @@
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use serde::Serialize;
 use serde_json::Value;
 use std::collections::BTreeMap;
@@ -6,8 +6,10 @@ use std::path::Path;
 
 use homeboy::component::{self, Component};
 use homeboy::project::{self, Project};
-use homeboy::EntityCrudOutput;
+use homeboy::{CsvRows, EntityCrudOutput};
 
+use super::utils::args::ListFormat;
+use super::utils::entity_suggest::{self, EntityType};
 use super::{CmdResult, DynamicSetArgs};
 
 #[derive(Args)]
@@ -59,6 +61,21 @@ enum ComponentCommand {
         /// Attach component to a project after creation
         #[arg(long)]
         project: Option<String>,
+        /// Create even when the ID looks like an existing component's
+        #[arg(long)]
+        force: bool,
+    },
+    /// Propose a starter component config by inspecting a directory
+    ///
+    /// Matches installed extensions by their discovery markers and detects docs
+    /// dirs and the changelog location. Prints the proposal without writing it
+    /// unless --save is passed.
+    Scaffold {
+        /// Directory to inspect
+        path: String,
+        /// Write the proposal to <path>/homeboy.json and register the component
+        #[arg(long)]
+        save: bool,
     },
     /// Display component configuration
     Show {
@@ -68,6 +85,18 @@ enum ComponentCommand {
         #[arg(long)]
         path: Option<String>,
     },
+    /// Show the effective configuration, including extension contributions
+    ///
+    /// Resolves build, deploy, and version fields the way those commands do and
+    /// reports where each value came from: the component's own config, a
+    /// built-in default, or a specific extension.
+    Inspect {
+        /// Component ID (optional when --path is provided)
+        id: Option<String>,
+        /// Discover component from a directory's homeboy.json instead of the registry
+        #[arg(long)]
+        path: Option<String>,
+    },
     /// Update component configuration fields
     ///
     /// Supports dedicated flags for common fields (e.g., --local-path, --changelog-target)
@@ -115,8 +144,38 @@ enum ComponentCommand {
         /// New component ID (should match repository directory name)
         new_id: String,
     },
+    /// Archive a component: keep its config but hide it from lists and
+    /// refuse build/deploy without --force
+    Archive {
+        /// Component ID
+        id: String,
+    },
+    /// Restore an archived component
+    Unarchive {
+        /// Component ID
+        id: String,
+    },
     /// List all available components
-    List,
+    List {
+        /// Only components attached to a project on this server
+        #[arg(long, value_name = "SERVER_ID")]
+        server: Option<String>,
+        /// Only components linking this extension
+        #[arg(long, value_name = "EXTENSION_ID", alias = "has-module")]
+        extension: Option<String>,
+        /// Only components whose ID or alias contains this text (case-insensitive)
+        #[arg(long, value_name = "TEXT")]
+        name: Option<String>,
+        /// Include archived components
+        #[arg(long)]
+        all: bool,
+        /// Output format: json (default), csv, or table
+        #[arg(long, value_enum, default_value_t = ListFormat::Json)]
+        format: ListFormat,
+        /// Table columns as comma-separated field paths (implies --format table)
+        #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+        columns: Vec<String>,
+    },
     /// List projects using this component
     Projects {
         /// Component ID
@@ -156,6 +215,24 @@ enum ComponentCommand {
         #[arg(long)]
         apply: bool,
     },
+    /// Graph components → required extensions → version constraints
+    ///
+    /// Missing or version-conflicting requirements are marked as problem
+    /// edges (red in DOT output).
+    Deps {
+        /// Only this component (all registered components if omitted)
+        #[arg(long)]
+        component: Option<String>,
+        /// Output format: JSON adjacency structure or Graphviz DOT
+        #[arg(long, value_enum, default_value = "json")]
+        format: DepsFormat,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum DepsFormat {
+    Json,
+    Dot,
 }
 
 /// Entity-specific fields for component commands.
@@ -167,6 +244,12 @@ pub struct ComponentExtra {
     pub projects: Option<Vec<Project>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shared: Option<std::collections::HashMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected: Option<component::scaffold::ScaffoldDetection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graph: Option<component::DependencyGraph>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inspection: Option<component::ComponentInspection>,
 }
 
 pub type ComponentOutput = EntityCrudOutput<Value, ComponentExtra>;
@@ -188,6 +271,7 @@ pub fn run(
             changelog_target,
             extensions,
             project,
+            force,
         } => {
             if json.is_some() || skip_existing {
                 return Err(homeboy::Error::validation_invalid_argument(
@@ -216,7 +300,13 @@ pub fn run(
 
             let remote_path = remote_path.unwrap_or_default();
             let repo_path = Path::new(&local_path);
-            let id = derive_component_id_for_create(repo_path, &local_path)?;
+            let id = component::scaffold::derive_component_id(repo_path)?;
+            entity_suggest::guard_near_duplicate(
+                EntityType::Component,
+                &id,
+                &component::list_ids().unwrap_or_default(),
+                force,
+            )?;
             let mut new_component =
                 Component::new(id.clone(), local_path.clone(), remote_path, build_artifact);
 
@@ -304,7 +394,9 @@ pub fn run(
                 0,
             ))
         }
+        ComponentCommand::Scaffold { path, save } => scaffold(&path, save),
         ComponentCommand::Show { id, path } => show(id.as_deref(), path.as_deref()),
+        ComponentCommand::Inspect { id, path } => inspect(id.as_deref(), path.as_deref()),
         ComponentCommand::Set {
             args,
             local_path,
@@ -328,7 +420,20 @@ pub fn run(
         ),
         ComponentCommand::Delete { id } => delete(&id),
         ComponentCommand::Rename { id, new_id } => rename(&id, &new_id),
-        ComponentCommand::List => list(),
+        ComponentCommand::Archive { id } => set_archived(&id, true),
+        ComponentCommand::Unarchive { id } => set_archived(&id, false),
+        ComponentCommand::List {
+            server,
+            extension,
+            name,
+            all,
+            ..
+        } => list(&component::ComponentFilter {
+            server,
+            extension,
+            name,
+            include_archived: all,
+        }),
         ComponentCommand::Projects { id } => projects(&id),
         ComponentCommand::Shared { id } => shared(id.as_deref()),
         ComponentCommand::Env { id, path } => env(id.as_deref(), path.as_deref()),
@@ -336,6 +441,7 @@ pub fn run(
             add_version_target(&id, &file, &pattern)
         }
         ComponentCommand::Reconcile { id, apply } => reconcile(&id, apply),
+        ComponentCommand::Deps { component, .. } => deps(component.as_deref()),
     }
 }
 
@@ -406,28 +512,58 @@ fn suggest_project_for_path(local_path: &str) -> Option<String> {
     None
 }
 
-fn derive_component_id_for_create(repo_path: &Path, local_path: &str) -> homeboy::Result<String> {
-    if repo_path.join("homeboy.json").exists() {
-        return component::infer_portable_component_id(repo_path);
-    }
+fn scaffold(path: &str, save: bool) -> CmdResult<ComponentOutput> {
+    let dir = std::fs::canonicalize(shellexpand::tilde(path).as_ref()).map_err(|e| {
+        homeboy::Error::validation_invalid_argument(
+            "path",
+            format!("Cannot resolve directory {}: {}", path, e),
+            None,
+            None,
+        )
+    })?;
 
-    let dir_name = repo_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .ok_or_else(|| {
-            homeboy::Error::validation_invalid_argument(
-                "local_path",
-                "Could not derive component ID from local path",
-                Some(local_path.to_string()),
-                None,
-            )
-        })?;
+    let proposal = component::scaffold::scaffold(&dir)?;
+    let id = proposal.component.id.clone();
+
+    let hint = if save {
+        component::write_portable_config(&dir, &proposal.component)?;
+        if let Err(e) =
+            homeboy::component::inventory::write_standalone_registration(&proposal.component)
+        {
+            eprintln!("Warning: could not write standalone registration: {}", e);
+        }
+        format!("Saved {}", dir.join("homeboy.json").display())
+    } else {
+        format!(
+            "Review the proposal, then save it with:\n  homeboy component scaffold {} --save",
+            dir.display()
+        )
+    };
 
-    homeboy::engine::identifier::slugify_id(dir_name, "component_id")
+    Ok((
+        ComponentOutput {
+            command: "component.scaffold".to_string(),
+            id: Some(id),
+            entity: Some(component::portable_json(&proposal.component)?),
+            updated_fields: if save {
+                vec!["homeboy.json".to_string()]
+            } else {
+                Vec::new()
+            },
+            hint: Some(hint),
+            extra: ComponentExtra {
+                detected: Some(proposal.detected),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
 }
 
-fn show(id: Option<&str>, path: Option<&str>) -> CmdResult<ComponentOutput> {
-    let component = match (id, path) {
+/// Resolve a component by ID, by `--path`, or from the current directory.
+fn load_for_display(id: Option<&str>, path: Option<&str>) -> homeboy::Result<Component> {
+    Ok(match (id, path) {
         // --path: discover from directory's homeboy.json
         (_, Some(dir)) => {
             let dir_path = std::path::Path::new(dir);
@@ -452,7 +588,29 @@ fn show(id: Option<&str>, path: Option<&str>) -> CmdResult<ComponentOutput> {
         (None, None) => component::resolve_effective(None, None, None).map_err(|_| {
             homeboy::Error::validation_missing_argument(vec!["id or --path".to_string()])
         })?,
-    };
+    })
+}
+
+fn inspect(id: Option<&str>, path: Option<&str>) -> CmdResult<ComponentOutput> {
+    let component = load_for_display(id, path)?;
+    let inspection = component::inspect(&component);
+
+    Ok((
+        ComponentOutput {
+            command: "component.inspect".to_string(),
+            id: Some(component.id),
+            extra: ComponentExtra {
+                inspection: Some(inspection),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
+fn show(id: Option<&str>, path: Option<&str>) -> CmdResult<ComponentOutput> {
+    let component = load_for_display(id, path)?;
 
     let resolved_id = component.id.clone();
     let drift_files = homeboy::component::drift::drift_file_paths(&component);
@@ -760,10 +918,15 @@ fn set(
 ) -> CmdResult<ComponentOutput> {
     // Check if there's any input at all
     let has_dynamic = args.json_spec()?.is_some() || !args.effective_extra().is_empty();
-    if !has_dynamic && !flags.has_any() && version_targets.is_empty() && extensions.is_empty() {
+    if !has_dynamic
+        && !flags.has_any()
+        && version_targets.is_empty()
+        && extensions.is_empty()
+        && args.unset.is_empty()
+    {
         return Err(homeboy::Error::validation_invalid_argument(
             "spec",
-            "Provide a flag (e.g., --local-path), --json spec, --base64, --key value, --version-target, or --extension",
+            "Provide a flag (e.g., --local-path), --json spec, --base64, --key value, --version-target, --extension, or --unset",
             None,
             None,
         ));
@@ -808,7 +971,12 @@ fn set(
 
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
-    match component::merge(args.id.as_deref(), &json_string, &replace_fields)? {
+    match component::merge(
+        args.id.as_deref(),
+        &json_string,
+        &replace_fields,
+        &args.unset,
+    )? {
         homeboy::MergeOutput::Single(result) => {
             let comp = component::load(&result.id)?;
             Ok((
@@ -870,7 +1038,7 @@ fn add_version_target(id: &str, file: &str, pattern: &str) -> CmdResult<Componen
 
     let json_string = homeboy::config::to_json_string(&version_target)?;
 
-    match component::merge(Some(id), &json_string, &[])? {
+    match component::merge(Some(id), &json_string, &[], &[])? {
         homeboy::MergeOutput::Single(result) => {
             let comp = component::load(&result.id)?;
             Ok((
@@ -945,8 +1113,28 @@ fn rename(id: &str, new_id: &str) -> CmdResult<ComponentOutput> {
     ))
 }
 
-fn list() -> CmdResult<ComponentOutput> {
-    let components: Vec<Value> = component::inventory()?
+fn set_archived(id: &str, archived: bool) -> CmdResult<ComponentOutput> {
+    component::set_archived(id, archived)?;
+
+    Ok((
+        ComponentOutput {
+            command: if archived {
+                "component.archive"
+            } else {
+                "component.unarchive"
+            }
+            .to_string(),
+            id: Some(id.to_string()),
+            updated_fields: vec!["archived".to_string()],
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
+fn list(filter: &component::ComponentFilter) -> CmdResult<ComponentOutput> {
+    let components: Vec<Value> = filter
+        .apply(component::inventory()?)
         .into_iter()
         .map(|component| {
             let mut value = serde_json::to_value(&component).map_err(|error| {
@@ -1003,6 +1191,108 @@ fn projects(id: &str) -> CmdResult<ComponentOutput> {
     ))
 }
 
+/// `component deps --format dot` prints raw DOT instead of the JSON envelope.
+pub fn is_dot_output(args: &ComponentArgs) -> bool {
+    matches!(
+        args.command,
+        ComponentCommand::Deps {
+            format: DepsFormat::Dot,
+            ..
+        }
+    )
+}
+
+pub fn run_dot(args: ComponentArgs) -> CmdResult<String> {
+    let ComponentCommand::Deps { component, .. } = args.command else {
+        return Err(homeboy::Error::internal_unexpected(
+            "Unexpected component command for DOT output",
+        ));
+    };
+    let graph = component::dependency_graph(component.as_deref())?;
+    Ok((component::render_dot(&graph), 0))
+}
+
+pub fn is_tabular_output(args: &ComponentArgs) -> bool {
+    matches!(
+        &args.command,
+        ComponentCommand::List { format, columns, .. } if format.resolve(columns) != ListFormat::Json
+    )
+}
+
+pub fn run_tabular(args: ComponentArgs) -> CmdResult<String> {
+    let ComponentCommand::List {
+        server,
+        extension,
+        name,
+        all,
+        format,
+        columns,
+    } = args.command
+    else {
+        return Err(homeboy::Error::internal_unexpected(
+            "Unexpected component command for tabular output",
+        ));
+    };
+    let (output, exit_code) = list(&component::ComponentFilter {
+        server,
+        extension,
+        name,
+        include_archived: all,
+    })?;
+    Ok((format.render(&output, &columns)?, exit_code))
+}
+
+impl CsvRows for ComponentOutput {
+    fn csv_header(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "aliases",
+            "local_path",
+            "remote_path",
+            "remote_owner",
+            "build_artifact",
+            "extract_command",
+            "changelog_target",
+            "extensions",
+        ]
+    }
+
+    /// `extensions` is an object keyed by extension ID; the cell lists the IDs.
+    fn to_csv_rows(&self) -> Vec<Value> {
+        self.entities
+            .iter()
+            .map(|entity| {
+                let mut row = entity.clone();
+                if let Some(Value::Object(extensions)) = row.get("extensions") {
+                    let mut ids: Vec<Value> = extensions
+                        .keys()
+                        .map(|id| Value::String(id.clone()))
+                        .collect();
+                    ids.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+                    row["extensions"] = Value::Array(ids);
+                }
+                row
+            })
+            .collect()
+    }
+}
+
+fn deps(component_id: Option<&str>) -> CmdResult<ComponentOutput> {
+    let graph = component::dependency_graph(component_id)?;
+    Ok((
+        ComponentOutput {
+            command: "component.deps".to_string(),
+            id: component_id.map(str::to_string),
+            extra: ComponentExtra {
+                graph: Some(graph),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
 fn shared(id: Option<&str>) -> CmdResult<ComponentOutput> {
     if let Some(component_id) = id {
         // Show projects for a specific component
@@ -1120,8 +1410,8 @@ mod tests {
         fs::create_dir_all(&repo).expect("repo dir");
         fs::write(repo.join("homeboy.json"), r#"{"id":"homeboy"}"#).expect("homeboy.json");
 
-        let id = derive_component_id_for_create(&repo, &repo.to_string_lossy())
-            .expect("portable id should be used");
+        let id =
+            component::scaffold::derive_component_id(&repo).expect("portable id should be used");
 
         assert_eq!(id, "homeboy");
     }
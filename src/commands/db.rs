@@ -1,7 +1,7 @@
 use clap::{Args, Subcommand};
 use serde::Serialize;
 
-use homeboy::db::{self, DbResult, DbTunnelResult};
+use homeboy::db::{self, DbMigrateResult, DbQueryOptions, DbResult, DbTunnelResult, MigrateAction};
 use homeboy::engine::text;
 use homeboy::observation::store::{self, ObservationDbStatus};
 use homeboy::project;
@@ -34,10 +34,16 @@ enum DbCommand {
         #[arg(trailing_var_arg = true)]
         args: Vec<String>,
     },
-    /// Execute SELECT query
+    /// Execute a read-only SQL query and return rows as JSON objects
     Query {
         /// Project ID
         project_id: String,
+        /// Allow statements that modify data or schema
+        #[arg(long)]
+        write: bool,
+        /// Maximum rows to return (default 1000)
+        #[arg(long)]
+        limit: Option<usize>,
         /// Optional subtarget and SQL query
         #[arg(trailing_var_arg = true)]
         args: Vec<String>,
@@ -88,6 +94,32 @@ enum DbCommand {
         #[arg(long)]
         local_port: Option<u16>,
     },
+    /// Run framework migrations declared by the database extension
+    Migrate {
+        #[command(subcommand)]
+        command: MigrateCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrateCommand {
+    /// List applied and pending migrations
+    Status(MigrateArgs),
+    /// Apply pending migrations
+    Up(MigrateArgs),
+    /// Roll back migrations
+    Down(MigrateArgs),
+}
+
+#[derive(Args)]
+struct MigrateArgs {
+    /// Project ID
+    project_id: String,
+    /// Optional subtarget
+    subtarget: Option<String>,
+    /// Print the migration command without running it
+    #[arg(long)]
+    dry_run: bool,
 }
 
 #[derive(Serialize)]
@@ -104,6 +136,7 @@ pub enum DbResultVariant {
     Status(ObservationDbStatus),
     Query(DbResult),
     Tunnel(DbTunnelResult),
+    Migrate(DbMigrateResult),
 }
 
 pub fn run(args: DbArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<DbOutput> {
@@ -111,7 +144,19 @@ pub fn run(args: DbArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<DbO
         DbCommand::Status => status(),
         DbCommand::Tables { project_id, args } => tables(&project_id, &args),
         DbCommand::Describe { project_id, args } => describe(&project_id, &args),
-        DbCommand::Query { project_id, args } => query(&project_id, &args),
+        DbCommand::Query {
+            project_id,
+            write,
+            limit,
+            args,
+        } => query(
+            &project_id,
+            &args,
+            &DbQueryOptions {
+                allow_write: write,
+                limit,
+            },
+        ),
         DbCommand::Search {
             project_id,
             table,
@@ -135,6 +180,11 @@ pub fn run(args: DbArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<DbO
             project_id,
             local_port,
         } => tunnel(&project_id, local_port),
+        DbCommand::Migrate { command } => match command {
+            MigrateCommand::Status(args) => migrate(MigrateAction::Status, args),
+            MigrateCommand::Up(args) => migrate(MigrateAction::Up, args),
+            MigrateCommand::Down(args) => migrate(MigrateAction::Down, args),
+        },
     }
 }
 
@@ -201,11 +251,11 @@ fn describe(project_id: &str, args: &[String]) -> CmdResult<DbOutput> {
     ))
 }
 
-fn query(project_id: &str, args: &[String]) -> CmdResult<DbOutput> {
+fn query(project_id: &str, args: &[String], options: &DbQueryOptions) -> CmdResult<DbOutput> {
     let (subtarget, remaining) = parse_subtarget(project_id, args)?;
     let sql = remaining.join(" ");
 
-    let result = db::query(project_id, &sql, subtarget.as_deref())?;
+    let result = db::query(project_id, &sql, options, subtarget.as_deref())?;
     let exit_code = result.exit_code;
 
     Ok((
@@ -285,3 +335,17 @@ fn tunnel(project_id: &str, local_port: Option<u16>) -> CmdResult<DbOutput> {
         exit_code,
     ))
 }
+
+fn migrate(action: MigrateAction, args: MigrateArgs) -> CmdResult<DbOutput> {
+    let (subtarget, _) = parse_subtarget(&args.project_id, args.subtarget.as_slice())?;
+    let result = db::migrate(&args.project_id, action, args.dry_run, subtarget.as_deref())?;
+    let exit_code = result.exit_code;
+
+    Ok((
+        DbOutput {
+            command: format!("db.migrate.{}", action.as_str()),
+            result: DbResultVariant::Migrate(result),
+        },
+        exit_code,
+    ))
+}
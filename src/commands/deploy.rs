@@ -1,12 +1,17 @@
+use std::time::Duration;
+
 use clap::Args;
 use serde::Serialize;
 
 use homeboy::deploy::{
-    self, ComponentDeployResult, DeployConfig, DeploySummary, MultiDeploySummary,
-    ProjectDeployResult,
+    self, CanaryConfig, CanaryOutcome, CanarySize, ComponentDeployResult, DeployConfig, DeployPlan,
+    DeploySummary, DeployVerifyResult, MultiDeploySummary, ProjectDeployResult,
 };
 
+use homeboy::plan::PlanStepFilter;
+
 use super::utils::resolve::{infer_project_for_components, resolve_project_components};
+use super::utils::tty;
 use super::CmdResult;
 
 const DEPLOY_RECIPES: &[&str] = &[
@@ -44,10 +49,21 @@ pub struct DeployArgs {
     /// Preview what would be deployed without executing
     #[arg(long)]
     pub dry_run: bool,
+    /// Print the fully-resolved deploy plan (build command, destination,
+    /// verification, hooks, contributing extensions) without executing
+    #[arg(long, conflicts_with_all = ["dry_run", "check", "behind_upstream"])]
+    pub plan: bool,
+    /// Run only the post-deploy verification probes against what is already
+    /// deployed, reporting pass/fail per check
+    #[arg(long, conflicts_with_all = ["dry_run", "check", "plan", "no_verify"])]
+    pub verify_only: bool,
+    /// Skip post-deploy verification probes
+    #[arg(long)]
+    pub no_verify: bool,
     /// Check component status without building or deploying
     #[arg(long, visible_alias = "status")]
     pub check: bool,
-    /// Deploy even with uncommitted changes
+    /// Deploy even with uncommitted changes, and include archived components
     #[arg(long)]
     pub force: bool,
     /// Deploy to multiple projects (comma-separated or repeated)
@@ -56,9 +72,25 @@ pub struct DeployArgs {
     /// Deploy to all projects in a fleet
     #[arg(long, short = 'f')]
     pub fleet: Option<String>,
+    /// Deploy to all projects carrying this project tag (repeatable; all must match)
+    #[arg(long, value_name = "TAG", conflicts_with_all = ["fleet", "projects", "shared"])]
+    pub project_tag: Vec<String>,
     /// Deploy to all projects using the specified component(s)
     #[arg(long, short = 's')]
     pub shared: bool,
+    /// With a multi-project deploy, deploy to N projects (or N% of them)
+    /// first, verify them, and roll out to the rest only if they pass
+    #[arg(
+        long,
+        value_name = "N|N%",
+        value_parser = parse_canary,
+        conflicts_with_all = ["plan", "verify_only", "dry_run", "check"]
+    )]
+    pub canary: Option<CanarySize>,
+    /// With --canary, wait this long (e.g. 30s, 5m) after the canary deploy
+    /// before verifying it
+    #[arg(long, value_name = "DURATION", requires = "canary", value_parser = super::observe::parse_duration)]
+    pub canary_wait: Option<Duration>,
     /// Keep build dependencies (skip post-deploy cleanup)
     #[arg(long)]
     pub keep_deps: bool,
@@ -74,6 +106,16 @@ pub struct DeployArgs {
     /// Force tag-based deploy, ignoring any reusable build artifacts
     #[arg(long)]
     pub tagged: bool,
+    /// Run only these deploy steps (comma-separated: pull, build, upload, cleanup, post_deploy)
+    #[arg(long, value_delimiter = ',', value_name = "STEP")]
+    pub only: Vec<String>,
+    /// Skip these deploy steps (comma-separated: pull, build, upload, cleanup, post_deploy)
+    #[arg(long, value_delimiter = ',', value_name = "STEP")]
+    pub skip: Vec<String>,
+
+    /// Load variables from a .env file for this run (shell env takes precedence)
+    #[arg(long, value_name = "PATH")]
+    pub env_file: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -96,22 +138,42 @@ pub struct MultiProjectDeployOutput {
     pub component_ids: Vec<String>,
     pub projects: Vec<ProjectDeployResult>,
     pub summary: MultiDeploySummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary: Option<CanaryOutcome>,
     pub dry_run: bool,
     pub check: bool,
     pub force: bool,
 }
 
+#[derive(Serialize)]
+pub struct DeployPlanOutput {
+    pub command: String,
+    pub plans: Vec<DeployPlan>,
+}
+
+#[derive(Serialize)]
+pub struct DeployVerifyOutput {
+    pub command: String,
+    pub projects: Vec<DeployVerifyResult>,
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum DeployCommandOutput {
     Single(DeployOutput),
     Multi(MultiProjectDeployOutput),
+    Plan(DeployPlanOutput),
+    Verify(DeployVerifyOutput),
 }
 
 pub fn run(
     mut args: DeployArgs,
     _global: &crate::commands::GlobalArgs,
 ) -> CmdResult<DeployCommandOutput> {
+    if let Some(ref path) = args.env_file {
+        crate::commands::utils::dotenv::load(path)?;
+    }
+
     // Fleet deploy
     if let Some(ref fleet_id) = args.fleet {
         let fl = homeboy::fleet::load(fleet_id)?;
@@ -119,6 +181,13 @@ pub fn run(
         return run_multi_output(&fl.project_ids, &component_ids, &config, &args);
     }
 
+    // Tagged deploy (every project carrying the tags)
+    if !args.project_tag.is_empty() {
+        let project_ids = deploy::resolve_tagged_targets(&args.project_tag)?;
+        let (component_ids, config) = resolve_multi_args(&args)?;
+        return run_multi_output(&project_ids, &component_ids, &config, &args);
+    }
+
     // Shared component deploy (find all projects using the component)
     if args.shared {
         let component_ids = resolve_shared_component_ids(&args)?;
@@ -135,6 +204,20 @@ pub fn run(
         return run_multi_output(project_ids, &component_ids, &config, &args);
     }
 
+    if args.canary.is_some() {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "canary",
+            "--canary needs several target projects",
+            None,
+            Some(vec![
+                "Canary a fleet: homeboy deploy <component-id> --fleet <fleet> --canary 1"
+                    .to_string(),
+                "Or list projects: homeboy deploy <component-id> --projects a,b,c --canary 1"
+                    .to_string(),
+            ]),
+        ));
+    }
+
     // Single-project deploy: resolve project and component IDs
     let (project_id, component_ids) = resolve_single_deploy_target(&args)?;
     args.target_id = Some(project_id.clone());
@@ -147,6 +230,14 @@ pub fn run(
 
     let config = build_config(&args, false);
 
+    if args.plan {
+        return run_plan_output(std::slice::from_ref(&project_id), &config);
+    }
+
+    if args.verify_only {
+        return run_verify_output(std::slice::from_ref(&project_id), &config);
+    }
+
     let result = deploy::run(&project_id, &config).map_err(|e| {
         if e.message.contains("No components configured for project")
             || e.message.contains("No deployable components found")
@@ -286,6 +377,8 @@ fn build_config(args: &DeployArgs, skip_build: bool) -> DeployConfig {
         no_pull: args.no_pull,
         head: args.head,
         tagged: args.tagged,
+        no_verify: args.no_verify,
+        step_filter: PlanStepFilter::new(args.only.clone(), args.skip.clone()),
     }
 }
 
@@ -295,8 +388,40 @@ fn run_multi_output(
     config: &DeployConfig,
     args: &DeployArgs,
 ) -> CmdResult<DeployCommandOutput> {
-    let result = deploy::run_multi(project_ids, component_ids, config)?;
-    let exit_code = if result.summary.failed > 0 { 1 } else { 0 };
+    if args.plan {
+        let mut config = build_config(args, false);
+        config.component_ids = component_ids.to_vec();
+        return run_plan_output(project_ids, &config);
+    }
+
+    if args.verify_only {
+        let mut config = build_config(args, false);
+        config.component_ids = component_ids.to_vec();
+        return run_verify_output(project_ids, &config);
+    }
+
+    let result = match args.canary {
+        Some(size) => deploy::run_multi_canary(
+            project_ids,
+            component_ids,
+            config,
+            &CanaryConfig {
+                size,
+                wait: args.canary_wait,
+            },
+            &mut confirm_rollout,
+        )?,
+        None => deploy::run_multi(project_ids, component_ids, config)?,
+    };
+    let canary_stopped = result
+        .canary
+        .as_ref()
+        .is_some_and(|canary| canary.status != "passed");
+    let exit_code = if result.summary.failed > 0 || canary_stopped {
+        1
+    } else {
+        0
+    };
 
     Ok((
         DeployCommandOutput::Multi(MultiProjectDeployOutput {
@@ -304,6 +429,7 @@ fn run_multi_output(
             component_ids: result.component_ids,
             projects: result.projects,
             summary: result.summary,
+            canary: result.canary,
             dry_run: args.dry_run,
             check: args.check,
             force: args.force,
@@ -311,3 +437,64 @@ fn run_multi_output(
         exit_code,
     ))
 }
+
+fn parse_canary(raw: &str) -> Result<CanarySize, String> {
+    CanarySize::parse(raw).map_err(|err| err.message)
+}
+
+/// Ask before rolling out past a passed canary on a terminal; scripted runs
+/// roll out automatically.
+fn confirm_rollout(canary: &[ProjectDeployResult]) -> bool {
+    if !tty::require_tty_for_interactive() {
+        return true;
+    }
+    let projects: Vec<&str> = canary.iter().map(|p| p.project_id.as_str()).collect();
+    let question = format!(
+        "Canary passed on {}. Roll out to the remaining projects? [y/N] ",
+        projects.join(", ")
+    );
+    tty::prompt(&question)
+        .map(|answer| matches!(answer.to_lowercase().as_str(), "y" | "yes"))
+        .unwrap_or(false)
+}
+
+fn run_plan_output(
+    project_ids: &[String],
+    config: &DeployConfig,
+) -> CmdResult<DeployCommandOutput> {
+    let plans = project_ids
+        .iter()
+        .map(|project_id| deploy::plan(project_id, config))
+        .collect::<homeboy::Result<Vec<_>>>()?;
+
+    Ok((
+        DeployCommandOutput::Plan(DeployPlanOutput {
+            command: "deploy.plan".to_string(),
+            plans,
+        }),
+        0,
+    ))
+}
+
+fn run_verify_output(
+    project_ids: &[String],
+    config: &DeployConfig,
+) -> CmdResult<DeployCommandOutput> {
+    let projects = project_ids
+        .iter()
+        .map(|project_id| deploy::verify(project_id, config))
+        .collect::<homeboy::Result<Vec<_>>>()?;
+    let exit_code = if projects.iter().any(|p| p.summary.failed > 0) {
+        1
+    } else {
+        0
+    };
+
+    Ok((
+        DeployCommandOutput::Verify(DeployVerifyOutput {
+            command: "deploy.verify".to_string(),
+            projects,
+        }),
+        exit_code,
+    ))
+}
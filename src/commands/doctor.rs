@@ -1,13 +1,30 @@
 use clap::{Args, Subcommand};
+use serde::Serialize;
 
+use super::utils::args::{FailOnArgs, HiddenJsonArgs};
 use super::CmdResult;
 
+pub mod checks;
 pub mod resources;
 
 #[derive(Args)]
 pub struct DoctorArgs {
     #[command(subcommand)]
-    pub command: DoctorCommand,
+    pub command: Option<DoctorCommand>,
+
+    /// Run only this check (repeatable; see --list-checks)
+    #[arg(long = "check", value_name = "NAME")]
+    pub checks: Vec<String>,
+
+    /// Describe the available checks instead of running them
+    #[arg(long, conflicts_with = "checks")]
+    pub list_checks: bool,
+
+    #[command(flatten)]
+    pub fail_on_args: FailOnArgs,
+
+    #[command(flatten)]
+    pub _json: HiddenJsonArgs,
 }
 
 #[derive(Subcommand)]
@@ -16,8 +33,42 @@ pub enum DoctorCommand {
     Resources(resources::ResourcesArgs),
 }
 
-pub fn run(args: DoctorArgs, _global: &super::GlobalArgs) -> CmdResult<resources::DoctorOutput> {
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum DoctorCommandOutput {
+    Resources(Box<resources::DoctorOutput>),
+    Report(checks::DoctorReport),
+    CheckList(checks::CheckList),
+}
+
+pub fn run(args: DoctorArgs, _global: &super::GlobalArgs) -> CmdResult<DoctorCommandOutput> {
     match args.command {
-        DoctorCommand::Resources(args) => resources::run(args),
+        Some(DoctorCommand::Resources(args)) => resources::run(args)
+            .map(|(output, code)| (DoctorCommandOutput::Resources(Box::new(output)), code)),
+        None if args.list_checks => Ok((DoctorCommandOutput::CheckList(checks::list()), 0)),
+        None => checks::run(&args.checks, args.fail_on_args.policy()?)
+            .map(|(report, code)| (DoctorCommandOutput::Report(report), code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli_surface::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn parses_repeated_checks_and_list_checks() {
+        let cli = Cli::try_parse_from(["homeboy", "doctor", "--check", "ssh", "--check", "config"])
+            .expect("doctor --check parses");
+        match cli.command {
+            Commands::Doctor(args) => {
+                assert!(args.command.is_none());
+                assert_eq!(args.checks, vec!["ssh", "config"]);
+            }
+            _ => panic!("expected doctor command"),
+        }
+
+        assert!(Cli::try_parse_from(["homeboy", "doctor", "--list-checks"]).is_ok());
+        assert!(Cli::try_parse_from(["homeboy", "doctor", "resources"]).is_ok());
     }
 }
@@ -0,0 +1,412 @@
+//! Named doctor checks.
+//!
+//! `homeboy doctor` runs every entry in [`CHECKS`]; `--check <name>` runs a
+//! subset and `--list-checks` describes them. Each check returns findings
+//! rather than printing, so checks can be run and tested in isolation.
+//!
+//! The report rolls the checks up into `healthy` (no check errored) and a
+//! 0–100 `score`, so CI can gate on the config environment itself.
+
+use serde::Serialize;
+
+use homeboy::output::{ExitPolicy, IssueCounts};
+use homeboy::server;
+use homeboy::{config, defaults};
+
+use super::resources::{self, ResourceRecommendation};
+use crate::commands::CmdResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingLevel {
+    Ok,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorFinding {
+    pub level: FindingLevel,
+    /// What the finding is about (a server ID, a config file), when not the
+    /// whole check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    pub message: String,
+}
+
+impl DoctorFinding {
+    fn new(level: FindingLevel, subject: Option<String>, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            subject,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DoctorCheck {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Whether the check can repair what it finds (`--fix`).
+    pub fixable: bool,
+    pub run: fn() -> Vec<DoctorFinding>,
+}
+
+pub const CHECKS: &[DoctorCheck] = &[
+    DoctorCheck {
+        name: "config",
+        description:
+            "homeboy.json and every project, server, runner, extension, and fleet config parse",
+        fixable: false,
+        run: check_config,
+    },
+    DoctorCheck {
+        name: "ssh",
+        description: "Every configured server accepts a non-interactive SSH connection",
+        fixable: false,
+        run: check_ssh,
+    },
+    DoctorCheck {
+        name: "resources",
+        description: "Machine load, memory, and hot processes leave room for heavy commands",
+        fixable: false,
+        run: check_resources,
+    },
+];
+
+#[derive(Debug, Serialize)]
+pub struct DoctorReport {
+    pub command: &'static str,
+    /// Worst level across all checks.
+    pub status: FindingLevel,
+    /// No check reported an error.
+    pub healthy: bool,
+    /// Percentage of the available points: 2 per `ok` check, 1 per
+    /// `warning`, 0 per `error`. 100 when no checks ran.
+    pub score: u8,
+    pub checks: Vec<CheckReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckReport {
+    pub name: &'static str,
+    pub status: FindingLevel,
+    pub findings: Vec<DoctorFinding>,
+    pub fixable: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckList {
+    pub command: &'static str,
+    pub checks: Vec<CheckInfo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckInfo {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub fixable: bool,
+}
+
+pub fn list() -> CheckList {
+    CheckList {
+        command: "doctor.checks",
+        checks: CHECKS
+            .iter()
+            .map(|check| CheckInfo {
+                name: check.name,
+                description: check.description,
+                fixable: check.fixable,
+            })
+            .collect(),
+    }
+}
+
+/// Run the named checks (all of them when `names` is empty), in registry
+/// order. Exits 1 when any check errored, unless `policy` (`--fail-on`)
+/// sets the threshold.
+pub fn run(names: &[String], policy: Option<ExitPolicy>) -> CmdResult<DoctorReport> {
+    let selected = select(names)?;
+    let checks: Vec<CheckReport> = selected
+        .into_iter()
+        .map(|check| {
+            let findings = (check.run)();
+            CheckReport {
+                name: check.name,
+                status: worst(&findings),
+                findings,
+                fixable: check.fixable,
+            }
+        })
+        .collect();
+
+    let status = checks
+        .iter()
+        .map(|check| check.status)
+        .max()
+        .unwrap_or(FindingLevel::Ok);
+    let counts = IssueCounts {
+        errors: count(&checks, FindingLevel::Error),
+        warnings: count(&checks, FindingLevel::Warning),
+        info: 0,
+    };
+    let exit_code = if status == FindingLevel::Error { 1 } else { 0 };
+    let exit_code = policy.map_or(exit_code, |policy| policy.apply(exit_code, counts));
+    Ok((
+        DoctorReport {
+            command: "doctor",
+            status,
+            healthy: status != FindingLevel::Error,
+            score: score(&checks),
+            checks,
+        },
+        exit_code,
+    ))
+}
+
+fn count(checks: &[CheckReport], level: FindingLevel) -> usize {
+    checks.iter().filter(|check| check.status == level).count()
+}
+
+fn score(checks: &[CheckReport]) -> u8 {
+    if checks.is_empty() {
+        return 100;
+    }
+    let points: usize = checks
+        .iter()
+        .map(|check| match check.status {
+            FindingLevel::Ok => 2,
+            FindingLevel::Warning => 1,
+            FindingLevel::Error => 0,
+        })
+        .sum();
+    (points * 100 / (checks.len() * 2)) as u8
+}
+
+fn select(names: &[String]) -> homeboy::Result<Vec<&'static DoctorCheck>> {
+    if names.is_empty() {
+        return Ok(CHECKS.iter().collect());
+    }
+
+    for name in names {
+        if !CHECKS.iter().any(|check| check.name == name) {
+            return Err(homeboy::Error::validation_invalid_argument(
+                "check",
+                format!("Unknown doctor check '{}'", name),
+                Some(name.clone()),
+                Some(CHECKS.iter().map(|check| check.name.to_string()).collect()),
+            )
+            .with_hint("Run `homeboy doctor --list-checks` to see available checks"));
+        }
+    }
+    Ok(CHECKS
+        .iter()
+        .filter(|check| names.iter().any(|name| name == check.name))
+        .collect())
+}
+
+fn worst(findings: &[DoctorFinding]) -> FindingLevel {
+    findings
+        .iter()
+        .map(|finding| finding.level)
+        .max()
+        .unwrap_or(FindingLevel::Ok)
+}
+
+fn check_config() -> Vec<DoctorFinding> {
+    let mut findings = Vec::new();
+
+    if let Some(err) = defaults::config_file_error() {
+        let subject = defaults::config_path().ok();
+        findings.push(DoctorFinding::new(
+            FindingLevel::Error,
+            subject,
+            format!("homeboy.json does not load, defaults are used: {}", err),
+        ));
+    }
+
+    for invalid in config::invalid_entities() {
+        findings.push(DoctorFinding::new(
+            FindingLevel::Error,
+            Some(format!("{}:{}", invalid.entity_type, invalid.id)),
+            format!("Config does not load: {}", invalid.error),
+        ));
+    }
+
+    if findings.is_empty() {
+        findings.push(DoctorFinding::new(
+            FindingLevel::Ok,
+            None,
+            "All config files load",
+        ));
+    }
+    findings
+}
+
+fn check_ssh() -> Vec<DoctorFinding> {
+    let servers = match server::list() {
+        Ok(servers) => servers,
+        Err(err) => {
+            return vec![DoctorFinding::new(
+                FindingLevel::Error,
+                None,
+                format!("Could not list servers: {}", err),
+            )]
+        }
+    };
+    if servers.is_empty() {
+        return vec![DoctorFinding::new(
+            FindingLevel::Ok,
+            None,
+            "No servers configured",
+        )];
+    }
+
+    server::exec_on(&servers, "true")
+        .results
+        .into_iter()
+        .map(|outcome| match outcome.error {
+            Some(error) => DoctorFinding::new(FindingLevel::Error, Some(outcome.id), error),
+            None => DoctorFinding::new(FindingLevel::Ok, Some(outcome.id), "Reachable over SSH"),
+        })
+        .collect()
+}
+
+fn check_resources() -> Vec<DoctorFinding> {
+    let report = match resources::run(resources::ResourcesArgs {}) {
+        Ok((report, _)) => report,
+        Err(err) => {
+            return vec![DoctorFinding::new(
+                FindingLevel::Warning,
+                None,
+                format!("Could not read machine resources: {}", err),
+            )]
+        }
+    };
+
+    let level = match report.recommendation {
+        ResourceRecommendation::Ok => FindingLevel::Ok,
+        ResourceRecommendation::Warm | ResourceRecommendation::Hot => FindingLevel::Warning,
+    };
+    let mut findings = vec![DoctorFinding::new(
+        level,
+        None,
+        format!(
+            "Machine pressure is {}; see `homeboy doctor resources` for details",
+            recommendation_label(report.recommendation)
+        ),
+    )];
+    findings.extend(
+        report
+            .notes
+            .into_iter()
+            .map(|note| DoctorFinding::new(FindingLevel::Warning, None, note)),
+    );
+    findings
+}
+
+fn recommendation_label(recommendation: ResourceRecommendation) -> &'static str {
+    match recommendation {
+        ResourceRecommendation::Ok => "ok",
+        ResourceRecommendation::Warm => "warm",
+        ResourceRecommendation::Hot => "hot",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+
+    #[test]
+    fn check_names_are_unique() {
+        let mut names: Vec<&str> = CHECKS.iter().map(|check| check.name).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), CHECKS.len());
+    }
+
+    #[test]
+    fn select_rejects_unknown_check() {
+        let err = select(&["dns".to_string()]).unwrap_err();
+        assert!(err.message.contains("Unknown doctor check 'dns'"));
+
+        let selected = select(&["ssh".to_string(), "config".to_string()]).unwrap();
+        assert_eq!(
+            selected.iter().map(|check| check.name).collect::<Vec<_>>(),
+            vec!["config", "ssh"]
+        );
+    }
+
+    fn report(name: &'static str, status: FindingLevel) -> CheckReport {
+        CheckReport {
+            name,
+            status,
+            findings: Vec::new(),
+            fixable: false,
+        }
+    }
+
+    #[test]
+    fn score_weights_warnings_half() {
+        assert_eq!(score(&[]), 100);
+        assert_eq!(
+            score(&[
+                report("config", FindingLevel::Ok),
+                report("ssh", FindingLevel::Error),
+                report("resources", FindingLevel::Warning),
+            ]),
+            50
+        );
+    }
+
+    #[test]
+    fn fail_on_sets_exit_code() {
+        with_isolated_home(|_| {
+            let names = ["config".to_string()];
+            let (report, code) = run(&names, None).unwrap();
+            assert!(report.healthy);
+            assert_eq!(report.score, 100);
+            assert_eq!(code, 0);
+
+            let projects = homeboy::paths::projects().unwrap();
+            std::fs::create_dir_all(&projects).unwrap();
+            std::fs::write(projects.join("broken.json"), "{ not json").unwrap();
+
+            let (report, code) = run(&names, None).unwrap();
+            assert!(!report.healthy);
+            assert_eq!(report.score, 0);
+            assert_eq!(code, 1);
+
+            let never = ExitPolicy {
+                fail_on: homeboy::output::FailOn::None,
+                exit_code: 3,
+            };
+            assert_eq!(run(&names, Some(never)).unwrap().1, 0);
+        });
+    }
+
+    #[test]
+    fn config_check_reports_unparseable_project() {
+        with_isolated_home(|_| {
+            let projects = homeboy::paths::projects().unwrap();
+            std::fs::create_dir_all(&projects).unwrap();
+            std::fs::write(projects.join("broken.json"), "{ not json").unwrap();
+
+            let findings = check_config();
+            assert_eq!(worst(&findings), FindingLevel::Error);
+            assert!(findings
+                .iter()
+                .any(|finding| finding.subject.as_deref() == Some("project:broken")));
+        });
+    }
+
+    #[test]
+    fn ssh_check_passes_without_servers() {
+        with_isolated_home(|_| {
+            let findings = check_ssh();
+            assert_eq!(worst(&findings), FindingLevel::Ok);
+        });
+    }
+}
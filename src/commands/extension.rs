@@ -29,6 +29,11 @@ enum ExtensionCommand {
         /// Extension ID
         extension_id: String,
     },
+    /// Show the inputs `extension run` accepts, as fields and a JSON Schema
+    Describe {
+        /// Extension ID
+        extension_id: String,
+    },
     /// Execute a extension
     Run {
         /// Extension ID
@@ -42,6 +47,9 @@ enum ExtensionCommand {
         /// Input values as key=value pairs
         #[arg(short, long, value_parser = super::parse_key_val)]
         input: Vec<(String, String)>,
+        /// JSON object of input values (path, or `-` for stdin); `--input` overrides it
+        #[arg(long, value_name = "PATH")]
+        input_file: Option<String>,
         /// Run only specific steps (comma-separated, e.g. --step phpunit,phpcs)
         #[arg(long)]
         step: Option<String>,
@@ -76,6 +84,14 @@ enum ExtensionCommand {
         /// Replace an existing extension install/link
         #[arg(long)]
         replace: bool,
+        /// Keep the install even if its requires.extensions form a cycle
+        /// with installed extensions (warns instead of refusing)
+        #[arg(long)]
+        force: bool,
+        /// Report the extension ID, target path, and manifest without
+        /// cloning, linking, or overwriting anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Relink an installed symlinked extension to a new local source path
     Relink {
@@ -100,6 +116,15 @@ enum ExtensionCommand {
         /// Update all installed extensions
         #[arg(long)]
         all: bool,
+        /// Report installed extensions that are behind their origin
+        #[arg(long, conflicts_with_all = ["extension_id", "all"])]
+        check_all: bool,
+        /// With --check-all, update every outdated extension
+        #[arg(long, requires = "check_all")]
+        apply: bool,
+        /// With --apply, keep updating after a failure instead of stopping
+        #[arg(long, requires = "apply")]
+        continue_on_error: bool,
         /// Force update even with uncommitted changes
         #[arg(long)]
         force: bool,
@@ -108,6 +133,12 @@ enum ExtensionCommand {
     Uninstall {
         /// Extension ID
         extension_id: String,
+        /// Uninstall even if components still use the extension
+        #[arg(long)]
+        force: bool,
+        /// Report what would be removed without removing it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Execute a extension action (API call or builtin)
     Action {
@@ -133,6 +164,16 @@ enum ExtensionCommand {
         #[arg(trailing_var_arg = true, required = true)]
         args: Vec<String>,
     },
+    /// Run a capability script directly and return its raw output
+    ExecRaw {
+        /// Extension ID
+        extension_id: String,
+        /// Capability whose script to run (fingerprint, refactor, lint, ...)
+        capability: String,
+        /// Script stdin: inline text, @file, or - for stdin (default: empty)
+        #[arg(long, value_name = "INPUT")]
+        stdin: Option<String>,
+    },
     /// Update extension manifest fields
     #[command(visible_aliases = ["edit", "merge"])]
     Set {
@@ -154,11 +195,13 @@ pub fn run(
     match args.command {
         ExtensionCommand::List { project } => list(project),
         ExtensionCommand::Show { extension_id } => show_extension(&extension_id),
+        ExtensionCommand::Describe { extension_id } => describe_extension(&extension_id),
         ExtensionCommand::Run {
             extension_id,
             project,
             component,
             input,
+            input_file,
             step,
             skip,
             args,
@@ -169,6 +212,7 @@ pub fn run(
             project,
             component,
             input,
+            input_file,
             args,
             stream,
             no_stream,
@@ -181,7 +225,15 @@ pub fn run(
             id,
             revision,
             replace,
-        } => install_extension(&source, id, revision, replace),
+            force,
+            dry_run,
+        } => {
+            if dry_run {
+                plan_install_extension(&source, id.as_deref(), replace)
+            } else {
+                install_extension(&source, id, revision, replace, force)
+            }
+        }
         ExtensionCommand::Relink {
             extension_id,
             source,
@@ -189,12 +241,30 @@ pub fn run(
         ExtensionCommand::InstallForComponent { source, path } => {
             install_for_component(&source, path.as_deref())
         }
+        ExtensionCommand::Update {
+            check_all: true,
+            apply,
+            continue_on_error,
+            force,
+            ..
+        } => check_extension_updates(apply, force, continue_on_error),
         ExtensionCommand::Update {
             extension_id,
             all,
             force,
+            ..
         } => update_extension(extension_id.as_deref(), all, force),
-        ExtensionCommand::Uninstall { extension_id } => uninstall_extension(&extension_id),
+        ExtensionCommand::Uninstall {
+            extension_id,
+            force,
+            dry_run,
+        } => {
+            if dry_run {
+                plan_uninstall_extension(&extension_id, force)
+            } else {
+                uninstall_extension(&extension_id, force)
+            }
+        }
         ExtensionCommand::Action {
             extension_id,
             action_id,
@@ -206,6 +276,11 @@ pub fn run(
             component,
             args,
         } => exec_extension_tool(&extension_id, component, args),
+        ExtensionCommand::ExecRaw {
+            extension_id,
+            capability,
+            stdin,
+        } => exec_raw_script(&extension_id, &capability, stdin.as_deref()),
         ExtensionCommand::Set {
             extension_id,
             json,
@@ -226,6 +301,12 @@ pub enum ExtensionOutput {
     },
     #[serde(rename = "extension.show")]
     Show { extension: ExtensionDetail },
+    #[serde(rename = "extension.describe")]
+    Describe {
+        extension_id: String,
+        inputs: Vec<homeboy::extension::inputs::InputField>,
+        schema: serde_json::Value,
+    },
     #[serde(rename = "extension.run")]
     Run {
         extension_id: String,
@@ -244,7 +325,11 @@ pub enum ExtensionOutput {
         linked: bool,
         #[serde(skip_serializing_if = "Option::is_none")]
         source_revision: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        requirement_cycle: Option<Vec<String>>,
     },
+    #[serde(rename = "extension.install_plan")]
+    InstallPlan(homeboy::extension::InstallPlan),
     #[serde(rename = "extension.replace")]
     Replace {
         extension_id: String,
@@ -286,12 +371,16 @@ pub enum ExtensionOutput {
         updated: Vec<UpdateEntry>,
         skipped: Vec<String>,
     },
+    #[serde(rename = "extension.update_check")]
+    UpdateCheck(homeboy::extension::UpdateCheckResult),
     #[serde(rename = "extension.uninstall")]
     Uninstall {
         extension_id: String,
         path: String,
         was_linked: bool,
     },
+    #[serde(rename = "extension.uninstall_plan")]
+    UninstallPlan(homeboy::extension::UninstallPlan),
     #[serde(rename = "extension.action")]
     Action {
         extension_id: String,
@@ -311,6 +400,11 @@ pub enum ExtensionOutput {
         #[serde(skip_serializing_if = "Option::is_none", flatten)]
         output: Option<homeboy::engine::command::CapturedOutput>,
     },
+    #[serde(rename = "extension.exec_raw")]
+    ExecRaw {
+        #[serde(flatten)]
+        output: homeboy::extension::RawScriptOutput,
+    },
     #[serde(rename = "extension.set")]
     SetBatch { batch: homeboy::BatchResult },
 }
@@ -485,19 +579,41 @@ fn show_extension(extension_id: &str) -> CmdResult<ExtensionOutput> {
     Ok((ExtensionOutput::Show { extension: detail }, 0))
 }
 
+fn describe_extension(extension_id: &str) -> CmdResult<ExtensionOutput> {
+    use homeboy::extension::inputs;
+
+    let extension = load_extension(extension_id)?;
+
+    Ok((
+        ExtensionOutput::Describe {
+            extension_id: extension.id.clone(),
+            inputs: inputs::describe_inputs(&extension),
+            schema: inputs::input_json_schema(&extension),
+        },
+        0,
+    ))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn run_extension(
     extension_id: &str,
     project: Option<String>,
     component: Option<String>,
     inputs: Vec<(String, String)>,
+    input_file: Option<String>,
     args: Vec<String>,
     stream: bool,
     no_stream: bool,
     step: Option<String>,
     skip: Option<String>,
 ) -> CmdResult<ExtensionOutput> {
-    use homeboy::extension::{ExtensionExecutionMode, ExtensionStepFilter};
+    use homeboy::extension::{inputs, ExtensionExecutionMode, ExtensionStepFilter};
+
+    let document = input_file
+        .as_deref()
+        .map(inputs::read_input_document)
+        .transpose()?;
+    let inputs = inputs::merge_inputs(document.as_ref(), inputs);
 
     let mode = if no_stream {
         ExtensionExecutionMode::Captured
@@ -534,6 +650,7 @@ fn install_extension(
     id: Option<String>,
     revision: Option<String>,
     replace: bool,
+    force: bool,
 ) -> CmdResult<ExtensionOutput> {
     if replace {
         let result =
@@ -551,8 +668,12 @@ fn install_extension(
         ));
     }
 
-    let result =
-        homeboy::extension::install_with_revision(source, id.as_deref(), revision.as_deref())?;
+    let result = homeboy::extension::install_with_revision(
+        source,
+        id.as_deref(),
+        revision.as_deref(),
+        force,
+    )?;
     let linked = is_extension_linked(&result.extension_id);
 
     Ok((
@@ -562,11 +683,28 @@ fn install_extension(
             path: result.path.to_string_lossy().to_string(),
             linked,
             source_revision: result.source_revision,
+            requirement_cycle: result.requirement_cycle,
         },
         0,
     ))
 }
 
+fn plan_install_extension(
+    source: &str,
+    id: Option<&str>,
+    replace: bool,
+) -> CmdResult<ExtensionOutput> {
+    let plan = homeboy::extension::plan_install(source, id, replace)?;
+    let exit_code = if plan.warnings.is_empty() { 0 } else { 1 };
+    Ok((ExtensionOutput::InstallPlan(plan), exit_code))
+}
+
+fn plan_uninstall_extension(extension_id: &str, force: bool) -> CmdResult<ExtensionOutput> {
+    let plan = homeboy::extension::plan_uninstall(extension_id, force)?;
+    let exit_code = if plan.blocked { 1 } else { 0 };
+    Ok((ExtensionOutput::UninstallPlan(plan), exit_code))
+}
+
 fn relink_extension(extension_id: &str, source: &str) -> CmdResult<ExtensionOutput> {
     let result = homeboy::extension::relink(extension_id, source)?;
 
@@ -685,9 +823,23 @@ fn update_all_extensions(force: bool) -> CmdResult<ExtensionOutput> {
     ))
 }
 
-fn uninstall_extension(extension_id: &str) -> CmdResult<ExtensionOutput> {
+fn check_extension_updates(
+    apply: bool,
+    force: bool,
+    continue_on_error: bool,
+) -> CmdResult<ExtensionOutput> {
+    let mut result = extension::check_all_updates();
+    if apply {
+        result = extension::apply_updates(result, force, continue_on_error);
+    }
+    let exit_code = if result.failed.is_empty() { 0 } else { 1 };
+
+    Ok((ExtensionOutput::UpdateCheck(result), exit_code))
+}
+
+fn uninstall_extension(extension_id: &str, force: bool) -> CmdResult<ExtensionOutput> {
     let was_linked = is_extension_linked(extension_id);
-    let path = homeboy::extension::uninstall(extension_id)?;
+    let path = homeboy::extension::uninstall_checked(extension_id, force)?;
 
     Ok((
         ExtensionOutput::Uninstall {
@@ -754,6 +906,21 @@ fn set_extension(
     }
 }
 
+fn exec_raw_script(
+    extension_id: &str,
+    capability: &str,
+    stdin: Option<&str>,
+) -> CmdResult<ExtensionOutput> {
+    let input = stdin
+        .map(homeboy::config::read_json_spec_to_string)
+        .transpose()?
+        .unwrap_or_default();
+    let output = extension::exec_raw(extension_id, capability, input.as_bytes())?;
+    let exit_code = output.exit_code.unwrap_or(1);
+
+    Ok((ExtensionOutput::ExecRaw { output }, exit_code))
+}
+
 fn exec_extension_tool(
     extension_id: &str,
     component: Option<String>,
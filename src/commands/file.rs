@@ -7,6 +7,7 @@ use homeboy::project::files::{self, FileEntry, GrepMatch, LineChange};
 use homeboy::server::transfer::{self, TransferConfig, TransferOutput};
 use homeboy::{join_remote_path, project};
 
+use super::utils::tty;
 use super::CmdResult;
 
 #[derive(Args)]
@@ -132,12 +133,18 @@ enum FileCommand {
         /// Show what would be uploaded without doing it
         #[arg(long)]
         dry_run: bool,
+        #[command(flatten)]
+        verify: VerifyFlags,
+        /// Continue an interrupted upload from the bytes already on the server
+        #[arg(long)]
+        resume: bool,
     },
     /// Copy a file or path between local and remote targets
     Copy(TransferArgs),
     /// Sync a directory between local and remote targets without deleting extras
     Sync(SyncArgs),
-    /// Edit file with line-based or pattern-based operations
+    /// Edit file with line-based or pattern-based operations, or in $EDITOR
+    /// when no operation is given
     Edit(EditArgs),
 }
 
@@ -175,6 +182,31 @@ struct TransferFlags {
     /// Exclude patterns for recursive server-to-server copies
     #[arg(long)]
     exclude: Vec<String>,
+    #[command(flatten)]
+    verify: VerifyFlags,
+    /// Continue an interrupted transfer: skip complete files, append to partial ones
+    #[arg(long)]
+    resume: bool,
+}
+
+#[derive(Args)]
+struct VerifyFlags {
+    /// Compare sha256 checksums on both ends after the transfer
+    #[arg(long, conflicts_with = "no_verify")]
+    verify: bool,
+    /// Skip the automatic checksum check for large single-file transfers
+    #[arg(long)]
+    no_verify: bool,
+}
+
+impl VerifyFlags {
+    fn into_option(self) -> Option<bool> {
+        match (self.verify, self.no_verify) {
+            (true, _) => Some(true),
+            (_, true) => Some(false),
+            _ => None,
+        }
+    }
 }
 
 impl TransferArgs {
@@ -202,6 +234,8 @@ fn transfer_config(
         compress: flags.compress,
         dry_run: flags.dry_run,
         exclude: flags.exclude,
+        verify: flags.verify.into_option(),
+        resume: flags.resume,
     }
 }
 
@@ -225,6 +259,35 @@ struct EditArgs {
     file_mods: FileModifications,
 }
 
+impl EditArgs {
+    fn has_no_operation(&self) -> bool {
+        let LineOperations {
+            replace_line,
+            insert_after,
+            insert_before,
+            delete_line,
+            delete_lines,
+            ..
+        } = &self.line_ops;
+        let PatternOperations {
+            replace_pattern,
+            replace_all_pattern,
+            delete_pattern,
+            ..
+        } = &self.pattern_ops;
+        replace_line.is_none()
+            && insert_after.is_none()
+            && insert_before.is_none()
+            && delete_line.is_none()
+            && delete_lines.is_none()
+            && replace_pattern.is_none()
+            && replace_all_pattern.is_none()
+            && delete_pattern.is_none()
+            && self.file_mods.append.is_none()
+            && self.file_mods.prepend.is_none()
+    }
+}
+
 #[derive(Args, Default)]
 struct LineOperations {
     #[arg(long)]
@@ -324,6 +387,16 @@ pub struct FileEditOutput {
     error: Option<String>,
 }
 
+#[derive(Serialize)]
+pub struct FileEditorOutput {
+    command: String,
+    project_id: String,
+    path: String,
+    /// `uploaded`, `unchanged`, `declined`, `dry_run`, or `editor_failed`.
+    outcome: String,
+    bytes_written: Option<usize>,
+}
+
 #[derive(Serialize)]
 pub struct FileDownloadOutput {
     command: String,
@@ -343,6 +416,7 @@ pub enum FileCommandOutput {
     Find(FileFindOutput),
     Grep(FileGrepOutput),
     Edit(FileEditOutput),
+    Editor(FileEditorOutput),
     Download(FileDownloadOutput),
     Transfer(TransferOutput),
     Raw(String),
@@ -483,6 +557,8 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
             remote_path,
             compress,
             dry_run,
+            verify,
+            resume,
         } => transfer_command(TransferConfig {
             source: local_path,
             destination: format!("{}:{}", server, remote_path),
@@ -490,9 +566,15 @@ pub fn run(args: FileArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<F
             compress,
             dry_run,
             exclude: Vec::new(),
+            verify: verify.into_option(),
+            resume,
         }),
         FileCommand::Copy(args) => transfer_command(args.into_config()),
         FileCommand::Sync(args) => transfer_command(args.into_config()),
+        FileCommand::Edit(args) if args.has_no_operation() => {
+            let (out, code) = edit_in_editor(&args.project_id, &args.file_path, args.dry_run)?;
+            Ok((FileCommandOutput::Editor(out), code))
+        }
         FileCommand::Edit(args) => {
             let (out, code) = edit(args)?;
             Ok((FileCommandOutput::Edit(out), code))
@@ -796,6 +878,115 @@ fn edit(args: EditArgs) -> CmdResult<FileEditOutput> {
     ))
 }
 
+/// Pull a remote file, open it in `$VISUAL`/`$EDITOR`, show a diff, and push
+/// it back after confirmation. The original is kept next to the edited copy
+/// until the upload succeeds, so a failed upload loses nothing.
+fn edit_in_editor(project_id: &str, path: &str, dry_run: bool) -> CmdResult<FileEditorOutput> {
+    if !tty::require_tty_for_interactive() {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "tty",
+            "file edit without an operation opens $EDITOR and needs an interactive TTY",
+            None,
+            Some(vec![
+                "Use --replace-pattern, --append, etc. for non-interactive edits".to_string(),
+                "Or: homeboy file read <project> <path> --raw, then homeboy file write".to_string(),
+            ]),
+        ));
+    }
+
+    let original = files::read(project_id, path)?;
+    let workdir = std::env::temp_dir().join(format!("homeboy-edit-{}", uuid::Uuid::new_v4()));
+    let file_name = std::path::Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "file".to_string());
+    let edited_path = workdir.join(&file_name);
+    let backup_path = workdir.join(format!("{}.orig", file_name));
+    let io_err = |e: std::io::Error, context: &str| {
+        homeboy::Error::internal_io(e.to_string(), Some(context.to_string()))
+    };
+    std::fs::create_dir_all(&workdir).map_err(|e| io_err(e, "create edit directory"))?;
+    std::fs::write(&backup_path, &original.content).map_err(|e| io_err(e, "write backup"))?;
+    std::fs::write(&edited_path, &original.content).map_err(|e| io_err(e, "write edit copy"))?;
+
+    let output = |outcome: &str, bytes_written: Option<usize>| FileEditorOutput {
+        command: "file.edit".to_string(),
+        project_id: project_id.to_string(),
+        path: original.path.clone(),
+        outcome: outcome.to_string(),
+        bytes_written,
+    };
+    let finish = |outcome: &str, exit_code: i32| {
+        let _ = std::fs::remove_dir_all(&workdir);
+        Ok((output(outcome, None), exit_code))
+    };
+
+    let editor = resolve_editor(
+        std::env::var("VISUAL").ok().as_deref(),
+        std::env::var("EDITOR").ok().as_deref(),
+    );
+    let status = homeboy::server::execute_local_command_interactive(
+        &format!(
+            "{} {}",
+            editor,
+            shell::quote_path(&edited_path.to_string_lossy())
+        ),
+        None,
+        None,
+    );
+    if status != 0 {
+        log_status!("file", "{} exited with {}; nothing written", editor, status);
+        return finish("editor_failed", 1);
+    }
+
+    let edited =
+        std::fs::read_to_string(&edited_path).map_err(|e| io_err(e, "read edited file"))?;
+    if edited == original.content {
+        return finish("unchanged", 0);
+    }
+
+    let diff = homeboy::server::execute_local_command(&format!(
+        "diff -u --label {} --label edited {} {}",
+        shell::quote_arg(&original.path),
+        shell::quote_path(&backup_path.to_string_lossy()),
+        shell::quote_path(&edited_path.to_string_lossy()),
+    ));
+    eprint!("{}", diff.stdout);
+
+    if dry_run {
+        return finish("dry_run", 0);
+    }
+    let answer = tty::prompt(&format!("Upload changes to {}? [y/N] ", original.path))?;
+    if !matches!(answer.to_lowercase().as_str(), "y" | "yes") {
+        return finish("declined", 0);
+    }
+
+    // `files::write` re-adds the final newline, same as `file write` from stdin.
+    let upload = edited.strip_suffix('\n').unwrap_or(&edited);
+    let result = files::write(project_id, path, upload).map_err(|e| {
+        e.with_hint(format!(
+            "Original saved at {}; your edits at {}",
+            backup_path.display(),
+            edited_path.display()
+        ))
+    })?;
+    let _ = std::fs::remove_dir_all(&workdir);
+
+    Ok((output("uploaded", Some(result.bytes_written)), 0))
+}
+
+/// `$VISUAL`, then `$EDITOR`, then `vi`. Values may carry arguments
+/// (`code --wait`), so they are passed to the shell unquoted.
+fn resolve_editor(visual: Option<&str>, editor: Option<&str>) -> String {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .find(|value| !value.is_empty())
+        .unwrap_or("vi")
+        .to_string()
+}
+
 #[cfg(test)]
 #[path = "../../tests/commands/file_test.rs"]
 mod file_test;
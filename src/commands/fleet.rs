@@ -5,6 +5,7 @@ use homeboy::fleet::{self, Fleet, FleetComponentDrift, FleetStatusResult};
 use homeboy::project::Project;
 use homeboy::EntityCrudOutput;
 
+use super::utils::args::ListFormat;
 use super::{CmdResult, DynamicSetArgs};
 
 #[derive(Args)]
@@ -30,6 +31,10 @@ enum FleetCommand {
         #[arg(long, short = 'p', value_delimiter = ',')]
         projects: Option<Vec<String>>,
 
+        /// Also include every project carrying this tag (repeatable; all must match)
+        #[arg(long)]
+        tag: Vec<String>,
+
         /// Description of the fleet
         #[arg(long, short = 'd')]
         description: Option<String>,
@@ -52,25 +57,34 @@ enum FleetCommand {
     },
     /// List all fleets
     List,
-    /// Add a project to a fleet
+    /// Add projects to a fleet
     Add {
         /// Fleet ID
         id: String,
 
-        /// Project ID to add
+        /// Project IDs to add
+        #[arg(value_name = "PROJECT", required_unless_present = "project")]
+        projects: Vec<String>,
+
+        /// Project ID to add (repeatable)
         #[arg(long, short = 'p')]
-        project: String,
+        project: Vec<String>,
     },
-    /// Remove a project from a fleet
+    /// Remove projects from a fleet
     Remove {
         /// Fleet ID
         id: String,
 
-        /// Project ID to remove
+        /// Project IDs to remove
+        #[arg(value_name = "PROJECT", required_unless_present = "project")]
+        projects: Vec<String>,
+
+        /// Project ID to remove (repeatable)
         #[arg(long, short = 'p')]
-        project: String,
+        project: Vec<String>,
     },
     /// Show projects in a fleet
+    #[command(visible_alias = "members")]
     Projects {
         /// Fleet ID
         id: String,
@@ -92,6 +106,13 @@ enum FleetCommand {
         /// Show only server health metrics, skip component versions
         #[arg(long)]
         health_only: bool,
+
+        /// Output format: json (default), csv, or table
+        #[arg(long, value_enum, default_value_t = ListFormat::Json)]
+        format: ListFormat,
+        /// Table columns as comma-separated field paths (implies --format table)
+        #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+        columns: Vec<String>,
     },
     /// Check component drift across a fleet (compares local vs remote)
     Check {
@@ -130,6 +151,16 @@ enum FleetCommand {
 pub struct FleetExtra {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub projects: Option<Vec<Project>>,
+    /// Member IDs with no matching project config.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub removed: Option<Vec<String>>,
+    /// Requested projects that were already members (add) or not members (remove).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unchanged: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub components: Option<std::collections::HashMap<String, Vec<String>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -151,20 +182,36 @@ pub fn run(args: FleetArgs, _global: &super::GlobalArgs) -> CmdResult<FleetOutpu
         FleetCommand::Create {
             id,
             projects,
+            tag,
             description,
-        } => create(&id, projects.unwrap_or_default(), description),
+        } => create(&id, projects.unwrap_or_default(), &tag, description),
         FleetCommand::Show { id } => show(&id),
         FleetCommand::Set { args } => set(args),
         FleetCommand::Delete { id } => delete(&id),
         FleetCommand::List => list(),
-        FleetCommand::Add { id, project } => add(&id, &project),
-        FleetCommand::Remove { id, project } => remove(&id, &project),
+        FleetCommand::Add {
+            id,
+            mut projects,
+            project,
+        } => {
+            projects.extend(project);
+            add(&id, &projects)
+        }
+        FleetCommand::Remove {
+            id,
+            mut projects,
+            project,
+        } => {
+            projects.extend(project);
+            remove(&id, &projects)
+        }
         FleetCommand::Projects { id } => projects(&id),
         FleetCommand::Components { id } => components(&id),
         FleetCommand::Status {
             id,
             cached,
             health_only,
+            ..
         } => status(&id, cached, health_only),
         FleetCommand::Check { id, outdated } => check(&id, outdated),
         FleetCommand::Exec {
@@ -179,16 +226,22 @@ pub fn run(args: FleetArgs, _global: &super::GlobalArgs) -> CmdResult<FleetOutpu
 
 fn create(
     id: &str,
-    project_ids: Vec<String>,
+    mut project_ids: Vec<String>,
+    tags: &[String],
     description: Option<String>,
 ) -> CmdResult<FleetOutput> {
-    // Validate projects exist
-    for pid in &project_ids {
-        if !homeboy::project::exists(pid) {
-            return Err(homeboy::Error::project_not_found(pid, vec![]));
+    if !tags.is_empty() {
+        for pid in homeboy::deploy::resolve_tagged_targets(tags)? {
+            if !project_ids.contains(&pid) {
+                project_ids.push(pid);
+            }
         }
     }
 
+    fleet::validate_project_ids(&project_ids)?;
+    let mut seen = std::collections::HashSet::new();
+    project_ids.retain(|pid| seen.insert(pid.clone()));
+
     let mut new_fleet = Fleet::new(id.to_string(), project_ids);
     new_fleet.description = description;
 
@@ -225,17 +278,15 @@ fn show(id: &str) -> CmdResult<FleetOutput> {
 }
 
 fn set(args: DynamicSetArgs) -> CmdResult<FleetOutput> {
-    let merged = super::merge_dynamic_args(&args)?.ok_or_else(|| {
-        homeboy::Error::validation_invalid_argument(
-            "spec",
-            "Provide JSON spec, --json flag, --base64 flag, or --key value flags",
-            None,
-            None,
-        )
-    })?;
+    let merged = super::set_patch(&args)?;
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
-    match fleet::merge(args.id.as_deref(), &json_string, &replace_fields)? {
+    match fleet::merge(
+        args.id.as_deref(),
+        &json_string,
+        &replace_fields,
+        &args.unset,
+    )? {
         homeboy::MergeOutput::Single(result) => {
             let fl = fleet::load(&result.id)?;
             Ok((
@@ -282,38 +333,76 @@ fn list() -> CmdResult<FleetOutput> {
     ))
 }
 
-fn add(fleet_id: &str, project_id: &str) -> CmdResult<FleetOutput> {
-    let fl = fleet::add_project(fleet_id, project_id)?;
+fn add(fleet_id: &str, project_ids: &[String]) -> CmdResult<FleetOutput> {
+    let change = fleet::add_projects(fleet_id, project_ids)?;
 
     Ok((
         FleetOutput {
             command: "fleet.add".to_string(),
             id: Some(fleet_id.to_string()),
-            entity: Some(fl),
-            updated_fields: vec!["project_ids".to_string()],
+            updated_fields: updated_fields(&change.changed),
+            entity: Some(change.fleet),
+            extra: FleetExtra {
+                added: Some(change.changed),
+                unchanged: Some(change.unchanged),
+                ..Default::default()
+            },
             ..Default::default()
         },
         0,
     ))
 }
 
-fn remove(fleet_id: &str, project_id: &str) -> CmdResult<FleetOutput> {
-    let fl = fleet::remove_project(fleet_id, project_id)?;
+fn remove(fleet_id: &str, project_ids: &[String]) -> CmdResult<FleetOutput> {
+    let change = fleet::remove_projects(fleet_id, project_ids)?;
+
+    let hint = (!change.changed.is_empty() && change.fleet.project_ids.is_empty()).then(|| {
+        homeboy::log_status!(
+            "warning",
+            "Fleet '{}' has no projects left; fleet commands will have nothing to act on",
+            fleet_id
+        );
+        format!(
+            "Add projects with: homeboy fleet add {} <project>, or delete it with: homeboy fleet delete {}",
+            fleet_id, fleet_id
+        )
+    });
 
     Ok((
         FleetOutput {
             command: "fleet.remove".to_string(),
             id: Some(fleet_id.to_string()),
-            entity: Some(fl),
-            updated_fields: vec!["project_ids".to_string()],
+            updated_fields: updated_fields(&change.changed),
+            entity: Some(change.fleet),
+            hint,
+            extra: FleetExtra {
+                removed: Some(change.changed),
+                unchanged: Some(change.unchanged),
+                ..Default::default()
+            },
             ..Default::default()
         },
         0,
     ))
 }
 
+fn updated_fields(changed: &[String]) -> Vec<String> {
+    if changed.is_empty() {
+        Vec::new()
+    } else {
+        vec!["project_ids".to_string()]
+    }
+}
+
 fn projects(id: &str) -> CmdResult<FleetOutput> {
+    let fl = fleet::load(id)?;
     let projects = fleet::get_projects(id)?;
+    let missing: Vec<String> = fl
+        .project_ids
+        .iter()
+        .filter(|pid| !projects.iter().any(|project| &project.id == *pid))
+        .cloned()
+        .collect();
 
     Ok((
         FleetOutput {
@@ -321,6 +410,7 @@ fn projects(id: &str) -> CmdResult<FleetOutput> {
             id: Some(id.to_string()),
             extra: FleetExtra {
                 projects: Some(projects),
+                missing: (!missing.is_empty()).then_some(missing),
                 ..Default::default()
             },
             ..Default::default()
@@ -346,6 +436,36 @@ fn components(id: &str) -> CmdResult<FleetOutput> {
     ))
 }
 
+pub fn is_tabular_output(args: &FleetArgs) -> bool {
+    matches!(
+        &args.command,
+        FleetCommand::Status { format, columns, .. } if format.resolve(columns) != ListFormat::Json
+    )
+}
+
+/// `fleet status --format csv|table`: one row per project component. The exit code
+/// matches the JSON output.
+pub fn run_tabular(args: FleetArgs) -> CmdResult<String> {
+    let FleetCommand::Status {
+        id,
+        cached,
+        health_only,
+        format,
+        columns,
+    } = args.command
+    else {
+        return Err(homeboy::Error::internal_unexpected(
+            "Unexpected fleet command for tabular output",
+        ));
+    };
+    let (output, exit_code) = status(&id, cached, health_only)?;
+    let text = match output.extra.status.as_ref() {
+        Some(status) => format.render(status, &columns)?,
+        None => String::new(),
+    };
+    Ok((text, exit_code))
+}
+
 fn status(id: &str, cached: bool, health_only: bool) -> CmdResult<FleetOutput> {
     let result = fleet::collect_status(id, cached, health_only)?;
 
@@ -2,11 +2,11 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use homeboy::git::{
-    self, CherryPickOptions, GitOutput, GithubFindOutput, GithubIssueOutput, GithubPrOutput,
-    IssueCloseOptions, IssueCloseReason, IssueCommentOptions, IssueCreateOptions, IssueEditOptions,
-    IssueFindOptions, IssueState, PrCommentMode, PrCommentOptions, PrCreateOptions, PrEditOptions,
-    PrFindOptions, PrPolicyDecision, PrPolicyMergeOptions, PrPolicyOpenOptions, PrState,
-    PushOptions, RebaseOptions,
+    self, CherryPickOptions, GitBranchOutput, GitOutput, GitStaleBranchesOutput, GithubFindOutput,
+    GithubIssueOutput, GithubPrOutput, IssueCloseOptions, IssueCloseReason, IssueCommentOptions,
+    IssueCreateOptions, IssueEditOptions, IssueFindOptions, IssueState, PrCommentMode,
+    PrCommentOptions, PrCreateOptions, PrEditOptions, PrFindOptions, PrPolicyDecision,
+    PrPolicyMergeOptions, PrPolicyOpenOptions, PrState, PushOptions, RebaseOptions,
 };
 use homeboy::BulkResult;
 
@@ -229,11 +229,74 @@ enum GitCommand {
         #[arg(short, long)]
         message: Option<String>,
 
+        /// Sign the tag (GPG, SSH, or X.509 per `release.signing_format`).
+        /// Also on by default when `release.sign_tags` is set.
+        #[arg(long)]
+        sign: bool,
+
         /// Workspace path to operate on directly. Useful for unregistered
         /// checkouts (CI runners, ad-hoc clones, worktrees).
         #[arg(long, value_name = "PATH")]
         path: Option<String>,
     },
+    /// Report local branches that are merged or whose upstream is gone.
+    ///
+    /// Lists branches fully merged into the default branch or tracking a
+    /// deleted upstream, each with the `git branch -d`/`-D` command that
+    /// would remove it. Nothing is deleted.
+    StaleBranches {
+        /// Component ID. When omitted, auto-detected from CWD.
+        #[arg(long, short)]
+        component_id: Option<String>,
+
+        /// Workspace path to operate on directly.
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+
+        /// Check every registered component with a local git checkout.
+        /// Runs git once per component.
+        #[arg(long, conflicts_with_all = ["component_id", "path"])]
+        all: bool,
+    },
+    /// List local branches, or create one.
+    ///
+    /// With a name, creates the branch (at `--from`, default `HEAD`)
+    /// without switching to it. Reports the current branch either way.
+    Branch {
+        /// Branch to create. When omitted, lists local branches.
+        #[arg(value_name = "NAME")]
+        name: Option<String>,
+
+        /// Start point for the new branch (branch, tag, or commit).
+        #[arg(long, value_name = "REF", requires = "name")]
+        from: Option<String>,
+
+        /// Component ID. When omitted, auto-detected from CWD.
+        #[arg(long, short)]
+        component_id: Option<String>,
+
+        /// Workspace path to operate on directly.
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
+    /// Switch to a branch, tag, or commit.
+    ///
+    /// Fails without touching the worktree when local changes would be
+    /// overwritten, listing the blocking files. Reports the branch checked
+    /// out afterwards (null for a detached HEAD).
+    Checkout {
+        /// Branch, tag, or commit to check out.
+        #[arg(value_name = "REF")]
+        reference: String,
+
+        /// Component ID. When omitted, auto-detected from CWD.
+        #[arg(long, short)]
+        component_id: Option<String>,
+
+        /// Workspace path to operate on directly.
+        #[arg(long, value_name = "PATH")]
+        path: Option<String>,
+    },
     /// Manage GitHub issues for a component
     Issue(IssueArgs),
     /// Manage GitHub pull requests for a component
@@ -670,6 +733,8 @@ enum PrPolicyCommand {
 pub enum GitCommandOutput {
     Single(GitOutput),
     Bulk(BulkResult<GitOutput>),
+    Branch(GitBranchOutput),
+    StaleBranches(GitStaleBranchesOutput),
     Issue(GithubIssueOutput),
     Pr(GithubPrOutput),
     Find(GithubFindOutput),
@@ -826,6 +891,7 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
             component_id,
             tag_name,
             message,
+            sign,
             path,
         } => {
             // Derive tag from version if not provided
@@ -855,6 +921,7 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
                 component_id.as_deref(),
                 Some(&final_tag),
                 message.as_deref(),
+                git::TagSigning::resolve(sign).as_ref(),
                 path.as_deref(),
             )?;
             let exit_code = output.exit_code;
@@ -900,6 +967,43 @@ pub fn run(args: GitArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Gi
             let exit_code = output.exit_code;
             Ok((GitCommandOutput::Single(output), exit_code))
         }
+        GitCommand::StaleBranches {
+            component_id,
+            path,
+            all,
+        } => {
+            let output = if all {
+                git::stale_branches_all()?
+            } else {
+                git::stale_branches_at(component_id.as_deref(), path.as_deref())?
+            };
+            Ok((GitCommandOutput::StaleBranches(output), 0))
+        }
+        GitCommand::Branch {
+            name,
+            from,
+            component_id,
+            path,
+        } => {
+            let output = match name {
+                Some(name) => git::branch_create_at(
+                    component_id.as_deref(),
+                    &name,
+                    from.as_deref(),
+                    path.as_deref(),
+                )?,
+                None => git::branch_list_at(component_id.as_deref(), path.as_deref())?,
+            };
+            Ok((GitCommandOutput::Branch(output), 0))
+        }
+        GitCommand::Checkout {
+            reference,
+            component_id,
+            path,
+        } => {
+            let output = git::checkout_at(component_id.as_deref(), &reference, path.as_deref())?;
+            Ok((GitCommandOutput::Branch(output), 0))
+        }
         GitCommand::Issue(args) => run_issue(args),
         GitCommand::Pr(args) => run_pr(args),
     }
@@ -11,8 +11,11 @@ use homeboy::git;
 use homeboy::observation::{finding_records_from_lint, ActiveObservation, NewRunRecord, RunStatus};
 use homeboy::refactor::plan::{collect_refactor_sources, lint_refactor_request, LintSourceOptions};
 
+use std::time::Instant;
+
 use super::utils::args::{
-    BaselineArgs, ExtensionOverrideArgs, HiddenJsonArgs, PositionalComponentArgs, SettingArgs,
+    BaselineArgs, ExtensionOverrideArgs, FailOnArgs, HiddenJsonArgs, PositionalComponentArgs,
+    ResultFormat, SettingArgs,
 };
 use super::{CmdResult, GlobalArgs};
 
@@ -48,6 +51,10 @@ pub struct LintArgs {
     #[arg(long)]
     pub errors_only: bool,
 
+    /// Exit non-zero on warnings too, not just errors
+    #[arg(long)]
+    pub fail_on_warnings: bool,
+
     /// Only check specific sniffs (comma-separated codes)
     #[arg(long)]
     pub sniffs: Option<String>,
@@ -78,12 +85,35 @@ pub struct LintArgs {
     #[command(flatten)]
     pub baseline_args: BaselineArgs,
 
+    #[command(flatten)]
+    pub fail_on_args: FailOnArgs,
+
     #[command(flatten)]
     pub _json: HiddenJsonArgs,
 
     /// Print compact machine-readable summary (for CI wrappers)
     #[arg(long)]
     pub json_summary: bool,
+
+    /// Output format: json (default envelope) or junit (JUnit XML for CI test reporters)
+    #[arg(long, value_enum, default_value_t = ResultFormat::Json, conflicts_with_all = ["json_summary", "fix"])]
+    pub format: ResultFormat,
+}
+
+/// Check if this invocation should print JUnit XML instead of the JSON envelope.
+pub fn is_plain_text_mode(args: &LintArgs) -> bool {
+    args.format == ResultFormat::Junit
+}
+
+/// `--format junit`: lint and render the findings as JUnit XML.
+pub fn run_plain_text(args: LintArgs, global: &GlobalArgs) -> CmdResult<String> {
+    let started = Instant::now();
+    let (output, exit_code) = run(args, global)?;
+    let suite = report::junit_suite(&output, Some(started.elapsed().as_secs_f64()));
+    Ok((
+        homeboy::engine::junit::render("homeboy lint", &[suite]),
+        exit_code,
+    ))
 }
 
 impl LintArgs {
@@ -178,6 +208,8 @@ pub fn run(args: LintArgs, _global: &GlobalArgs) -> CmdResult<LintCommandOutput>
                 ratchet: args.baseline_args.ratchet,
             },
             json_summary: args.json_summary,
+            fail_on_warnings: args.fail_on_warnings,
+            exit_policy: args.fail_on_args.policy()?,
         },
         &run_dir,
     );
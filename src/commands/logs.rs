@@ -30,6 +30,9 @@ pub enum LogsCommand {
         /// Follow log output (like tail -f)
         #[arg(short, long)]
         follow: bool,
+        /// With --follow over SSH, how many times to reconnect after a dropped connection (0 disables)
+        #[arg(long, default_value_t = logs::DEFAULT_FOLLOW_RECONNECTS, requires = "follow")]
+        max_reconnects: u32,
         /// Execute locally instead of via SSH (for when running on the target server)
         #[arg(long)]
         local: bool,
@@ -79,14 +82,16 @@ pub fn run(args: LogsArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<L
             path: Some(path),
             lines,
             follow,
+            max_reconnects,
             local,
-        } => show(&project_id, &path, lines, follow, local),
+        } => show(&project_id, &path, lines, follow, max_reconnects, local),
         LogsCommand::Show {
             project_id,
             path: None,
             lines,
             follow,
             local,
+            ..
         } => show_pinned(&project_id, lines, follow, local),
         LogsCommand::Clear {
             project_id,
@@ -152,10 +157,11 @@ fn show(
     path: &str,
     lines: u32,
     follow: bool,
+    max_reconnects: u32,
     local: bool,
 ) -> CmdResult<LogsOutput> {
     if follow {
-        let code = logs::follow(project_id, path, local)?;
+        let code = logs::follow(project_id, path, local, max_reconnects)?;
 
         Ok((
             LogsOutput {
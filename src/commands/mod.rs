@@ -89,6 +89,11 @@ pub struct DynamicSetArgs {
     #[arg(long, value_name = "FIELD")]
     pub replace: Vec<String>,
 
+    /// Remove this field from the config (repeatable; dotted paths like
+    /// `auth.mode` reach nested keys). Applied after the merge
+    #[arg(long, value_name = "FIELD")]
+    pub unset: Vec<String>,
+
     /// Dynamic key=value flags (e.g., --remote_path /var/www).
     /// When combined with --json, add '--' separator first:
     /// `homeboy component set ID --json '{}' -- --key value`
@@ -157,6 +162,33 @@ fn is_dynamic_set_arg(arg: &str) -> bool {
     arg.starts_with("--") || parse_key_value_arg(arg).is_some()
 }
 
+/// Shared `tag add|remove` subcommand for taggable entities (servers, projects).
+#[derive(Args)]
+pub struct TagArgs {
+    #[command(subcommand)]
+    pub command: TagCommand,
+}
+
+#[derive(clap::Subcommand)]
+pub enum TagCommand {
+    /// Add tags
+    Add {
+        /// Entity ID
+        id: String,
+        /// Tags to add
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+    /// Remove tags
+    Remove {
+        /// Entity ID
+        id: String,
+        /// Tags to remove
+        #[arg(required = true)]
+        tags: Vec<String>,
+    },
+}
+
 // ============================================================================
 // JSON Input Parsing (CLI layer)
 // ============================================================================
@@ -310,6 +342,21 @@ pub fn merge_dynamic_args(args: &DynamicSetArgs) -> homeboy::Result<Option<Value
     Ok(Some(merge_json_sources(spec.as_deref(), &extra)?))
 }
 
+/// The patch for a `set` command: the merged JSON sources, or an empty
+/// object when only `--unset` was given.
+pub fn set_patch(args: &DynamicSetArgs) -> homeboy::Result<Value> {
+    match merge_dynamic_args(args)? {
+        Some(merged) => Ok(merged),
+        None if !args.unset.is_empty() => Ok(Value::Object(serde_json::Map::new())),
+        None => Err(homeboy::Error::validation_invalid_argument(
+            "spec",
+            "Provide JSON spec, --json flag, --base64 flag, --key value flags, or --unset",
+            None,
+            None,
+        )),
+    }
+}
+
 /// Serialize a merged JSON value to a string and compute the full replace
 /// fields list (explicit `--replace` flags + auto-detected array fields).
 pub fn finalize_set_spec(
@@ -405,6 +452,17 @@ pub fn run_plain_text(
                 "Unexpected output type for raw mode",
             )),
         },
+        crate::cli_surface::Commands::Audit(args) => audit::run_plain_text(args, global),
+        crate::cli_surface::Commands::Test(args) => test::run_plain_text(args, global),
+        crate::cli_surface::Commands::Lint(args) => lint::run_plain_text(args, global),
+        crate::cli_surface::Commands::Component(args) if component::is_tabular_output(&args) => {
+            component::run_tabular(args)
+        }
+        crate::cli_surface::Commands::Component(args) => component::run_dot(args),
+        crate::cli_surface::Commands::Server(args) => server::run_tabular(args),
+        crate::cli_surface::Commands::Project(args) => project::run_tabular(args),
+        crate::cli_surface::Commands::Fleet(args) => fleet::run_tabular(args),
+        crate::cli_surface::Commands::Api(args) => api::run_raw(args),
         _ => Err(homeboy::Error::validation_invalid_argument(
             "output_mode",
             "Command does not support plain text output",
@@ -460,6 +518,14 @@ pub fn run_json(
         crate::cli_surface::Commands::Release(args) => dispatch!(args, global, release),
         crate::cli_surface::Commands::Report(args) => dispatch!(args, global, report),
         crate::cli_surface::Commands::Review(args) => dispatch!(args, global, review),
+        crate::cli_surface::Commands::Audit(args) if args.command.is_some() => {
+            crate::commands::utils::response::map_cmd_result_to_json(audit::run_history(
+                args, global,
+            ))
+        }
+        crate::cli_surface::Commands::Audit(args) if args.all || !args.tag.is_empty() => {
+            crate::commands::utils::response::map_cmd_result_to_json(audit::run_all(args, global))
+        }
         crate::cli_surface::Commands::Audit(args) => dispatch!(args, global, audit),
         crate::cli_surface::Commands::Refactor(args) => dispatch!(args, global, refactor),
         crate::cli_surface::Commands::Rig(args) => dispatch!(args, global, rig),
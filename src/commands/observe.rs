@@ -550,7 +550,7 @@ fn empty_event(t_ms: u64, source: &str, name: &str) -> TraceEvent {
     }
 }
 
-fn parse_duration(raw: &str) -> Result<Duration, String> {
+pub(crate) fn parse_duration(raw: &str) -> Result<Duration, String> {
     let raw = raw.trim();
     let split = raw
         .find(|c: char| !c.is_ascii_digit())
@@ -1,7 +1,9 @@
 use clap::{Args, Subcommand, ValueEnum};
 use std::path::Path;
 
-use super::CmdResult;
+use super::utils::args::ListFormat;
+use super::utils::tty;
+use super::{CmdResult, TagArgs, TagCommand};
 use homeboy::project::{self};
 
 #[derive(Args)]
@@ -13,7 +15,17 @@ pub struct ProjectArgs {
 #[derive(Subcommand)]
 enum ProjectCommand {
     /// List all configured projects
-    List,
+    List {
+        /// Only projects carrying this tag (repeatable; all must match)
+        #[arg(long)]
+        tag: Vec<String>,
+        /// Output format: json (default), csv, or table
+        #[arg(long, value_enum, default_value_t = ListFormat::Json)]
+        format: ListFormat,
+        /// Table columns as comma-separated field paths (implies --format table)
+        #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+        columns: Vec<String>,
+    },
     /// Show project configuration
     Show {
         /// Project ID
@@ -48,6 +60,18 @@ enum ProjectCommand {
     Set {
         #[command(flatten)]
         args: super::DynamicSetArgs,
+
+        /// Derive the project and its first component from a git URL or local checkout
+        #[arg(long, value_name = "URL")]
+        from_url: Option<String>,
+
+        /// Clone --from-url into this directory (default: ./<repo-name>)
+        #[arg(long, value_name = "DIR", requires = "from_url")]
+        into: Option<String>,
+
+        /// Save the derived config without asking
+        #[arg(long, short = 'y', requires = "from_url")]
+        yes: bool,
     },
     /// Remove items from project configuration arrays
     Remove {
@@ -66,6 +90,16 @@ enum ProjectCommand {
         /// New project ID
         new_id: String,
     },
+    /// Copy a project's configuration to a new project
+    Clone {
+        /// Source project ID (left unchanged)
+        source_id: String,
+        /// Name for the new project (slugified into its ID)
+        new_name: String,
+        /// Override a field on the copy (repeatable, e.g. --set server_id=staging)
+        #[arg(long = "set", value_name = "KEY=VALUE")]
+        set: Vec<String>,
+    },
     /// Manage project components
     Components {
         #[command(subcommand)]
@@ -76,6 +110,8 @@ enum ProjectCommand {
         #[command(subcommand)]
         command: ProjectPinCommand,
     },
+    /// Add or remove grouping tags
+    Tag(TagArgs),
     /// Delete a project configuration
     Delete {
         /// Project ID
@@ -86,6 +122,19 @@ enum ProjectCommand {
         /// Project ID
         project_id: String,
     },
+    /// Fix references to servers and components that no longer exist
+    Repair {
+        /// Project ID
+        project_id: String,
+
+        /// Apply every fix without asking (a missing server still needs --server)
+        #[arg(long, short = 'y')]
+        yes: bool,
+
+        /// Server to use when the project's server is missing
+        #[arg(long, value_name = "SERVER_ID")]
+        server: Option<String>,
+    },
     /// Show live server health and component versions for a project
     Status {
         /// Project ID
@@ -209,7 +258,7 @@ pub type ProjectOutput = homeboy::project::ProjectReportOutput;
 
 pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<ProjectOutput> {
     match args.command {
-        ProjectCommand::List => list(),
+        ProjectCommand::List { tag, .. } => list(&tag),
         ProjectCommand::Show { project_id } => show(&project_id),
         ProjectCommand::Create {
             json,
@@ -249,7 +298,13 @@ pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResul
                 skip_existing,
             )?))
         }
-        ProjectCommand::Set { args } => set(args),
+        ProjectCommand::Set {
+            args,
+            from_url: Some(url),
+            into,
+            yes,
+        } => set_from_url(args, &url, into.as_deref(), yes),
+        ProjectCommand::Set { args, .. } => set(args),
         ProjectCommand::Remove {
             project_id,
             spec,
@@ -266,10 +321,21 @@ pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResul
             remove(project_id.as_deref(), &json_spec)
         }
         ProjectCommand::Rename { project_id, new_id } => rename(&project_id, &new_id),
+        ProjectCommand::Clone {
+            source_id,
+            new_name,
+            set,
+        } => clone(&source_id, &new_name, &set),
         ProjectCommand::Components { command } => components(command),
         ProjectCommand::Pin { command } => pin(command),
+        ProjectCommand::Tag(tag_args) => tag(tag_args),
         ProjectCommand::Delete { project_id } => delete(&project_id),
         ProjectCommand::Init { project_id } => init(&project_id),
+        ProjectCommand::Repair {
+            project_id,
+            yes,
+            server,
+        } => repair(&project_id, yes, server.as_deref()),
         ProjectCommand::Status {
             project_id,
             health_only,
@@ -277,8 +343,42 @@ pub fn run(args: ProjectArgs, _global: &crate::commands::GlobalArgs) -> CmdResul
     }
 }
 
-fn list() -> CmdResult<ProjectOutput> {
-    Ok((project::build_list_output(project::list_report()?), 0))
+pub fn is_tabular_output(args: &ProjectArgs) -> bool {
+    matches!(
+        &args.command,
+        ProjectCommand::List { format, columns, .. } if format.resolve(columns) != ListFormat::Json
+    )
+}
+
+pub fn run_tabular(args: ProjectArgs) -> CmdResult<String> {
+    let ProjectCommand::List {
+        tag,
+        format,
+        columns,
+    } = args.command
+    else {
+        return Err(homeboy::Error::internal_unexpected(
+            "Unexpected project command for tabular output",
+        ));
+    };
+    let report = project::list_report(&tag)?;
+    Ok((format.render(&report, &columns)?, 0))
+}
+
+fn list(tags: &[String]) -> CmdResult<ProjectOutput> {
+    Ok((project::build_list_output(project::list_report(tags)?), 0))
+}
+
+fn tag(args: TagArgs) -> CmdResult<ProjectOutput> {
+    let output = match args.command {
+        TagCommand::Add { id, tags } => {
+            project::build_tag_output("add", project::add_tags(&id, &tags)?)
+        }
+        TagCommand::Remove { id, tags } => {
+            project::build_tag_output("remove", project::remove_tags(&id, &tags)?)
+        }
+    };
+    Ok((output, 0))
 }
 
 fn show(project_id: &str) -> CmdResult<ProjectOutput> {
@@ -289,23 +389,110 @@ fn show(project_id: &str) -> CmdResult<ProjectOutput> {
 }
 
 fn set(args: super::DynamicSetArgs) -> CmdResult<ProjectOutput> {
-    let merged = super::merge_dynamic_args(&args)?.ok_or_else(|| {
-        homeboy::Error::validation_invalid_argument(
-            "spec",
-            "Provide JSON spec, --json flag, --base64 flag, or --key value flags",
-            None,
-            None,
-        )
-    })?;
+    let merged = super::set_patch(&args)?;
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
     project::build_set_output(project::merge(
         args.id.as_deref(),
         &json_string,
         &replace_fields,
+        &args.unset,
     )?)
 }
 
+fn set_from_url(
+    args: super::DynamicSetArgs,
+    url: &str,
+    into: Option<&str>,
+    yes: bool,
+) -> CmdResult<ProjectOutput> {
+    let name = args.id.as_deref().ok_or_else(|| {
+        homeboy::Error::validation_invalid_argument(
+            "id",
+            "--from-url needs a project name: homeboy project set <name> --from-url <url>",
+            None,
+            None,
+        )
+    })?;
+    if args.spec.is_some()
+        || args.json.is_some()
+        || args.base64.is_some()
+        || !args.extra.is_empty()
+        || !args.unset.is_empty()
+    {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "from_url",
+            "--from-url cannot be combined with a JSON spec, --key value flags, or --unset",
+            None,
+            None,
+        )
+        .with_hint("Run `homeboy project set` again afterwards to adjust fields"));
+    }
+
+    let mut plan = project::from_url::plan(name, url, into.map(Path::new))?;
+    let confirmed = yes
+        || (tty::require_tty_for_interactive() && {
+            print_from_url_plan(&plan);
+            let answer = tty::prompt(&format!("Save project '{}'? [y/N] ", plan.project_id))?;
+            matches!(answer.to_lowercase().as_str(), "y" | "yes")
+        });
+
+    let (entity, updated_fields, hint) = if confirmed {
+        let project = project::from_url::apply(&mut plan)?;
+        (Some(project), vec!["components".to_string()], None)
+    } else {
+        let into = if homeboy::extension::is_git_url(&plan.source) {
+            format!(" --into {}", plan.local_path)
+        } else {
+            String::new()
+        };
+        let hint = format!(
+            "Nothing saved. Review the derived config, then run:\n  homeboy project set {} --from-url {}{} --yes",
+            plan.project_id, plan.source, into
+        );
+        (None, Vec::new(), Some(hint))
+    };
+
+    Ok((
+        ProjectOutput {
+            command: "project.set".to_string(),
+            id: Some(plan.project_id.clone()),
+            entity,
+            updated_fields,
+            hint,
+            extra: project::ProjectReportExtra {
+                from_url: Some(plan),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
+fn print_from_url_plan(plan: &project::from_url::FromUrlPlan) {
+    let action = if plan.project_exists {
+        "attach to existing project"
+    } else {
+        "create project"
+    };
+    eprintln!("Project:   {} ({})", plan.project_id, action);
+    eprintln!("Component: {}", plan.component_id);
+    eprintln!("Checkout:  {}", plan.local_path);
+    if !plan.detected.extensions.is_empty() {
+        eprintln!("Extensions: {}", plan.detected.extensions.join(", "));
+    }
+    if !plan.detected.build_extensions.is_empty() {
+        eprintln!("Build via: {}", plan.detected.build_extensions.join(", "));
+    }
+    if !plan.detected.docs_dirs.is_empty() {
+        eprintln!("Docs:      {}", plan.detected.docs_dirs.join(", "));
+    }
+    if let Some(changelog) = &plan.detected.changelog_target {
+        eprintln!("Changelog: {}", changelog);
+    }
+}
+
 fn remove(project_id: Option<&str>, json: &str) -> CmdResult<ProjectOutput> {
     Ok((
         project::build_remove_output(project::remove_from_json(project_id, json)?)?,
@@ -320,6 +507,27 @@ fn rename(project_id: &str, new_id: &str) -> CmdResult<ProjectOutput> {
     ))
 }
 
+fn clone(source_id: &str, new_name: &str, set: &[String]) -> CmdResult<ProjectOutput> {
+    if let Some(entry) = set.iter().find(|entry| !entry.contains('=')) {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "set",
+            format!("Expected KEY=VALUE, got '{}'", entry),
+            None,
+            None,
+        ));
+    }
+    let overrides = if set.is_empty() {
+        None
+    } else {
+        Some(super::merge_json_sources(None, set)?)
+    };
+
+    Ok((
+        project::build_clone_output(source_id, project::clone(source_id, new_name, overrides)?),
+        0,
+    ))
+}
+
 fn delete(project_id: &str) -> CmdResult<ProjectOutput> {
     project::delete(project_id)?;
 
@@ -332,6 +540,67 @@ fn init(project_id: &str) -> CmdResult<ProjectOutput> {
     Ok((project::build_init_output(project_id, &dir), 0))
 }
 
+/// Fix each detected issue: prompt in a terminal, apply everything under
+/// `--yes`, and only report when neither applies.
+fn repair(project_id: &str, yes: bool, server: Option<&str>) -> CmdResult<ProjectOutput> {
+    if let Some(server_id) = server {
+        if !homeboy::server::exists(server_id) {
+            return Err(homeboy::Error::server_not_found(server_id, vec![]));
+        }
+    }
+    let interactive = !yes && tty::require_tty_for_interactive();
+
+    let (project, report) = project::repair(project_id, |issue| {
+        let Some(fix) = issue.fix.clone() else {
+            return choose_server(issue, interactive, server);
+        };
+        if yes {
+            return Ok(Some(fix));
+        }
+        if !interactive {
+            return Ok(None);
+        }
+        let answer = tty::prompt(&format!("{}. Fix it? [y/N] ", issue.message))?;
+        Ok(matches!(answer.to_lowercase().as_str(), "y" | "yes").then_some(fix))
+    })?;
+
+    for outcome in &report.repairs {
+        if outcome.status == project::RepairStatus::Applied {
+            homeboy::log_status!("repair", "Fixed: {}", outcome.issue.message);
+        }
+    }
+    let exit_code = if report.unresolved() > 0 { 1 } else { 0 };
+    Ok((project::build_repair_output(project, report), exit_code))
+}
+
+/// Pick a replacement for a missing server: `--server`, or a prompt listing
+/// the configured servers. `--yes` never picks one on its own, so automation
+/// can't repoint a project at an unintended host.
+fn choose_server(
+    issue: &project::RepairIssue,
+    interactive: bool,
+    server: Option<&str>,
+) -> homeboy::Result<Option<project::RepairFix>> {
+    let set = |server_id: &str| {
+        Some(project::RepairFix::SetServer {
+            server_id: server_id.to_string(),
+        })
+    };
+    if let Some(server_id) = server {
+        return Ok(set(server_id));
+    }
+    if !interactive || issue.choices.is_empty() {
+        return Ok(None);
+    }
+
+    let chosen = tty::select(
+        &format!("{}. Configured servers:", issue.message),
+        &issue.choices,
+        "Use which server? [number or ID, blank to skip] ",
+    )?;
+    Ok(chosen.and_then(|index| set(&issue.choices[index])))
+}
+
 fn components(command: ProjectComponentsCommand) -> CmdResult<ProjectOutput> {
     match command {
         ProjectComponentsCommand::List { project_id } => components_list(&project_id),
@@ -522,3 +791,51 @@ fn status(project_id: &str, health_only: bool) -> CmdResult<ProjectOutput> {
         0,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli_surface::{Cli, Commands};
+    use clap::Parser;
+
+    fn parse_set(args: &[&str]) -> Option<ProjectCommand> {
+        let cli = Cli::try_parse_from(args).ok()?;
+        match cli.command {
+            Commands::Project(args) => Some(args.command),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn set_parses_from_url_flags() {
+        let Some(ProjectCommand::Set {
+            args,
+            from_url,
+            into,
+            yes,
+        }) = parse_set(&[
+            "homeboy",
+            "project",
+            "set",
+            "blog",
+            "--from-url",
+            "https://github.com/acme/blog.git",
+            "--into",
+            "/src/blog",
+            "--yes",
+        ])
+        else {
+            panic!("project set should parse --from-url");
+        };
+        assert_eq!(args.id.as_deref(), Some("blog"));
+        assert!(args.extra.is_empty());
+        assert_eq!(
+            from_url.as_deref(),
+            Some("https://github.com/acme/blog.git")
+        );
+        assert_eq!(into.as_deref(), Some("/src/blog"));
+        assert!(yes);
+
+        assert!(parse_set(&["homeboy", "project", "set", "blog", "--yes"]).is_none());
+    }
+}
@@ -1,15 +1,20 @@
-use clap::Args;
+use clap::{Args, Subcommand};
 use serde::Serialize;
 
+use homeboy::build;
 use homeboy::component;
 use homeboy::deploy::{self, ReleaseStateStatus};
+use homeboy::plan::PlanStepFilter;
 use homeboy::project;
-use homeboy::release::{self, BatchReleaseResult, ReleaseCommandInput, ReleaseCommandResult};
+use homeboy::release::{
+    self, ArtifactPin, BatchReleaseResult, ReleaseCommandInput, ReleaseCommandResult,
+};
 
 use super::utils::args::{DryRunArgs, HiddenJsonArgs};
 use super::CmdResult;
 
 #[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct ReleaseArgs {
     /// Component ID(s) to release
     pub components: Vec<String>,
@@ -68,6 +73,42 @@ pub struct ReleaseArgs {
     /// When set, configures git user.name and user.email before committing.
     #[arg(long)]
     git_identity: Option<String>,
+
+    /// Sign the release tag. Uses `release.signing_format` / `release.signing_key`
+    /// from `homeboy config`; on by default when `release.sign_tags` is set.
+    #[arg(long)]
+    sign: bool,
+
+    /// Run only these release steps (comma-separated step ids or groups, e.g. `git.tag,publish`)
+    #[arg(long, value_delimiter = ',', value_name = "STEP")]
+    only: Vec<String>,
+
+    /// Skip these release steps (comma-separated step ids or groups, e.g. `changelog`)
+    #[arg(long, value_delimiter = ',', value_name = "STEP")]
+    skip: Vec<String>,
+
+    /// Copy packaged artifacts to this directory and pin them to the released version
+    #[arg(long, value_name = "DIR")]
+    output: Option<String>,
+
+    /// Package the release with the built-in archiver (zip or tar.gz) instead of
+    /// an extension's `release.package` action
+    #[arg(long, value_name = "FORMAT", value_parser = parse_package_format)]
+    package: Option<build::PackageFormat>,
+
+    #[command(subcommand)]
+    command: Option<ReleaseCommand>,
+}
+
+#[derive(Subcommand)]
+enum ReleaseCommand {
+    /// List artifacts pinned by `release --output`
+    Artifacts {
+        /// Component ID
+        component_id: String,
+        /// Only this version
+        version: Option<String>,
+    },
 }
 
 #[derive(Serialize)]
@@ -82,11 +123,19 @@ pub struct BatchReleaseOutput {
     pub result: BatchReleaseResult,
 }
 
+#[derive(Serialize)]
+#[serde(tag = "command", rename = "release.artifacts")]
+pub struct ReleaseArtifactsOutput {
+    pub component_id: String,
+    pub pins: Vec<ArtifactPin>,
+}
+
 #[derive(Serialize)]
 #[serde(untagged)]
 pub enum ReleaseCommandOutput {
     Single(ReleaseOutput),
     Batch(BatchReleaseOutput),
+    Artifacts(ReleaseArtifactsOutput),
 }
 
 #[cfg(test)]
@@ -119,18 +168,44 @@ impl ReleaseArgs {
             skip_publish,
             no_github_release: false,
             git_identity: None,
+            sign: false,
+            only: Vec::new(),
+            skip: Vec::new(),
+            output: None,
+            package: None,
+            command: None,
         }
     }
 }
 
+fn parse_package_format(value: &str) -> Result<build::PackageFormat, String> {
+    build::PackageFormat::parse(value).map_err(|e| e.message)
+}
+
 pub fn run(
     args: ReleaseArgs,
     _global: &crate::commands::GlobalArgs,
 ) -> CmdResult<ReleaseCommandOutput> {
+    if let Some(ReleaseCommand::Artifacts {
+        component_id,
+        version,
+    }) = &args.command
+    {
+        let pins = release::list_pins(component_id, version.as_deref())?;
+        return Ok((
+            ReleaseCommandOutput::Artifacts(ReleaseArtifactsOutput {
+                component_id: component_id.clone(),
+                pins,
+            }),
+            0,
+        ));
+    }
+
     let positional = resolve_positional_args(&args)?;
     let component_ids = resolve_component_ids(&args, &positional.components)?;
 
     let bump_override = resolve_bump_override(&args, positional.bump);
+    let step_filter = PlanStepFilter::new(args.only.clone(), args.skip.clone());
 
     // Single component: use the original single-release flow
     if component_ids.len() == 1 {
@@ -147,6 +222,10 @@ pub fn run(
             skip_publish: args.skip_publish,
             skip_github_release: args.no_github_release,
             git_identity: args.git_identity.clone(),
+            step_filter: step_filter.clone(),
+            artifact_output: args.output.clone(),
+            sign_tag: args.sign,
+            package_format: args.package,
         })?;
 
         return Ok((
@@ -185,6 +264,10 @@ pub fn run(
         skip_publish: args.skip_publish,
         skip_github_release: args.no_github_release,
         git_identity: args.git_identity.clone(),
+        step_filter,
+        artifact_output: args.output.clone(),
+        sign_tag: args.sign,
+        package_format: args.package,
     };
 
     let batch_result = release::run_batch(&component_ids, &input_template);
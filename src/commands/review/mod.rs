@@ -28,7 +28,9 @@ use homeboy::quality::{build_quality_plan, QualityPlanOptions};
 use homeboy::ObservationOutputMetadata;
 
 use super::parse_key_val;
-use super::utils::args::{BaselineArgs, ExtensionOverrideArgs, PositionalComponentArgs};
+use super::utils::args::{
+    BaselineArgs, ExtensionOverrideArgs, PositionalComponentArgs, ResultFormat,
+};
 use super::{audit, lint, test, CmdResult, GlobalArgs};
 
 mod observation;
@@ -631,15 +633,33 @@ fn git_ref(path: &str, git_ref: &str) -> Option<String> {
 
 fn build_audit_args(args: &ReviewArgs) -> audit::AuditArgs {
     audit::AuditArgs {
+        command: None,
         comp: args.comp.clone(),
         extension_override: args.extension_override.clone(),
         conventions: false,
         only: Vec::new(),
         exclude: Vec::new(),
+        docs_include: Vec::new(),
+        docs_exclude: Vec::new(),
+        docs_min_confidence: None,
+        docs_context_lines: None,
+        threshold: None,
         baseline_args: args.baseline_args.clone(),
+        fail_on_args: Default::default(),
         changed_since: args.changed_since.clone(),
+        only_changed: false,
+        no_cache: false,
+        history_file: None,
         json_summary: args.summary,
         fixability: false,
+        json_lines: false,
+        format: audit::AuditFormat::Json,
+        all: false,
+        tag: Vec::new(),
+        parallel: None,
+        output_dir: None,
+        report_format: audit::ReportFormat::Json,
+        force: false,
     }
 }
 
@@ -652,6 +672,7 @@ fn build_lint_args(args: &ReviewArgs) -> lint::LintArgs {
         changed_only: args.changed_only,
         changed_since: args.changed_since.clone(),
         errors_only: false,
+        fail_on_warnings: false,
         sniffs: None,
         exclude_sniffs: None,
         category: None,
@@ -660,8 +681,10 @@ fn build_lint_args(args: &ReviewArgs) -> lint::LintArgs {
         extension_override: args.extension_override.clone(),
         setting_args: Default::default(),
         baseline_args: args.baseline_args.clone(),
+        fail_on_args: Default::default(),
         _json: Default::default(),
         json_summary: args.summary,
+        format: ResultFormat::Json,
     }
 }
 
@@ -682,6 +705,7 @@ fn build_test_args(args: &ReviewArgs) -> test::TestArgs {
         args: Vec::new(),
         _json: Default::default(),
         json_summary: args.summary,
+        format: ResultFormat::Json,
     }
 }
 
@@ -456,6 +456,7 @@ mod tests {
             passed: result.findings.is_empty(),
             result,
             fixability: None,
+            broken_references: Vec::new(),
         }
     }
 
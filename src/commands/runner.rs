@@ -419,17 +419,15 @@ fn show(id: &str) -> CmdResult<RunnerOutput> {
 }
 
 fn set(args: DynamicSetArgs) -> CmdResult<RunnerOutput> {
-    let merged = super::merge_dynamic_args(&args)?.ok_or_else(|| {
-        homeboy::Error::validation_invalid_argument(
-            "spec",
-            "Provide JSON spec, --json flag, --base64 flag, or --key value flags",
-            None,
-            None,
-        )
-    })?;
+    let merged = super::set_patch(&args)?;
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
-    match runner::merge(args.id.as_deref(), &json_string, &replace_fields)? {
+    match runner::merge(
+        args.id.as_deref(),
+        &json_string,
+        &replace_fields,
+        &args.unset,
+    )? {
         MergeOutput::Single(result) => {
             let entity = runner::load(&result.id)?;
             Ok((
@@ -1,10 +1,17 @@
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 use serde::Serialize;
 
-use homeboy::server::{self, Server, ServerSessionConfig, SshClient};
-use homeboy::{EntityCrudOutput, MergeOutput};
+use homeboy::engine::shell;
+use homeboy::server::{
+    self, Server, ServerExecResult, ServerSessionConfig, ServiceAction, ServiceControlResult,
+    SshClient,
+};
+use homeboy::{BulkResult, CsvRows, EntityCrudOutput, MergeOutput};
 
-use super::{CmdResult, DynamicSetArgs};
+use super::utils::args::ListFormat;
+use super::utils::entity_suggest::{self, EntityType};
+use super::utils::tty;
+use super::{CmdResult, DynamicSetArgs, TagArgs, TagCommand};
 
 /// Entity-specific fields for server commands.
 #[derive(Debug, Default, Serialize)]
@@ -13,6 +20,12 @@ pub struct ServerExtra {
     pub key: Option<ServerKeyOutput>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session: Option<ServerSessionOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rename: Option<ServerRenameOutput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<BulkResult<ServerExecResult>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<ServiceControlResult>,
 }
 
 pub type ServerOutput = EntityCrudOutput<Server, ServerExtra>;
@@ -27,6 +40,13 @@ pub struct ServerKeyOutput {
     imported: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ServerRenameOutput {
+    old_id: String,
+    dry_run: bool,
+    updated_projects: Vec<homeboy::project::ProjectReferenceUpdate>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ServerSessionOutput {
     action: String,
@@ -67,6 +87,12 @@ enum ServerCommand {
         /// SSH port (default: 22)
         #[arg(long)]
         port: Option<u16>,
+        /// Jump host: a registered server ID or user@host[:port]
+        #[arg(long)]
+        proxy_jump: Option<String>,
+        /// Create even when the ID looks like an existing server's
+        #[arg(long)]
+        force: bool,
     },
     /// Display server configuration
     Show {
@@ -79,13 +105,33 @@ enum ServerCommand {
         #[command(flatten)]
         args: DynamicSetArgs,
     },
+    /// Rename a server and update projects that reference it
+    Rename {
+        /// Current server ID
+        server_id: String,
+        /// New server ID
+        new_id: String,
+        /// Show which projects would be updated without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Remove a server configuration
     Delete {
         /// Server ID
         server_id: String,
     },
     /// List all configured servers
-    List,
+    List {
+        /// Only servers carrying this tag (repeatable; all must match)
+        #[arg(long)]
+        tag: Vec<String>,
+        /// Output format: json (default), csv, or table
+        #[arg(long, value_enum, default_value_t = ListFormat::Json)]
+        format: ListFormat,
+        /// Table columns as comma-separated field paths (implies --format table)
+        #[arg(long, value_delimiter = ',', value_name = "FIELDS")]
+        columns: Vec<String>,
+    },
     /// Open a managed SSH control-master session for this server
     Connect {
         /// Server ID
@@ -101,8 +147,62 @@ enum ServerCommand {
         /// Server ID
         server_id: String,
     },
+    /// Run a command over SSH on one server, or on many in parallel
+    Exec {
+        /// Server ID (omit with --all)
+        server_id: Option<String>,
+        /// Run on every configured server
+        #[arg(long, conflicts_with = "server_id")]
+        all: bool,
+        /// Only servers whose ID, alias, or kind matches this glob (repeatable; implies --all)
+        #[arg(long, conflicts_with = "server_id")]
+        filter: Vec<String>,
+        /// Only servers carrying this tag (repeatable; all must match; implies --all)
+        #[arg(long, conflicts_with = "server_id")]
+        tag: Vec<String>,
+        /// Run the command through sudo, prompting for the password if sudo asks
+        #[arg(long)]
+        sudo: bool,
+        /// Run as this user (`sudo -u`; implies --sudo)
+        #[arg(long, value_name = "USER")]
+        sudo_user: Option<String>,
+        /// Command to run, after `--`
+        #[arg(last = true, required = true)]
+        command: Vec<String>,
+    },
+    /// Start, stop, restart, or check a service (systemd, PM2, or Supervisor)
+    Service {
+        /// Server ID
+        server_id: String,
+        /// Action to run
+        #[arg(value_enum)]
+        action: ServiceActionArg,
+        /// Service name (systemd unit, PM2 app, or Supervisor program)
+        service: String,
+    },
     /// Manage SSH keys
     Key(KeyArgs),
+    /// Add or remove grouping tags
+    Tag(TagArgs),
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ServiceActionArg {
+    Start,
+    Stop,
+    Restart,
+    Status,
+}
+
+impl From<ServiceActionArg> for ServiceAction {
+    fn from(value: ServiceActionArg) -> Self {
+        match value {
+            ServiceActionArg::Start => ServiceAction::Start,
+            ServiceActionArg::Stop => ServiceAction::Stop,
+            ServiceActionArg::Restart => ServiceAction::Restart,
+            ServiceActionArg::Status => ServiceAction::Status,
+        }
+    }
 }
 
 #[derive(Args)]
@@ -153,6 +253,8 @@ pub fn run(args: ServerArgs, _global: &crate::commands::GlobalArgs) -> CmdResult
             host,
             user,
             port,
+            proxy_jump,
+            force,
         } => {
             let json_spec = if let Some(spec) = json {
                 spec
@@ -192,14 +294,27 @@ pub fn run(args: ServerArgs, _global: &crate::commands::GlobalArgs) -> CmdResult
                     port: port.unwrap_or(22),
                     identity_file: None,
                     kind: None,
+                    tags: Vec::new(),
                     auth: None,
                     env: std::collections::HashMap::new(),
+                    proxy_jump,
+                    service_manager: None,
                 };
 
                 homeboy::config::to_json_string(&new_server)?
             };
 
-            match server::create(&json_spec, skip_existing)? {
+            let raw = homeboy::config::read_json_spec_to_string(&json_spec)?;
+            let existing: Vec<String> = server::list()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|s| s.id)
+                .collect();
+            for id in spec_ids(&raw) {
+                entity_suggest::guard_near_duplicate(EntityType::Server, &id, &existing, force)?;
+            }
+
+            match server::create(&raw, skip_existing)? {
                 homeboy::CreateOutput::Single(result) => Ok((
                     ServerOutput {
                         command: "server.create".to_string(),
@@ -225,13 +340,121 @@ pub fn run(args: ServerArgs, _global: &crate::commands::GlobalArgs) -> CmdResult
         }
         ServerCommand::Show { server_id } => show(&server_id),
         ServerCommand::Set { args } => set(args),
+        ServerCommand::Rename {
+            server_id,
+            new_id,
+            dry_run,
+        } => rename(&server_id, &new_id, dry_run),
         ServerCommand::Delete { server_id } => delete(&server_id),
-        ServerCommand::List => list(),
+        ServerCommand::List { tag, .. } => list(&tag),
         ServerCommand::Connect { server_id } => session_connect(&server_id),
         ServerCommand::Status { server_id } => session_status(&server_id),
         ServerCommand::Disconnect { server_id } => session_disconnect(&server_id),
+        ServerCommand::Exec {
+            server_id,
+            all,
+            filter,
+            tag,
+            sudo,
+            sudo_user,
+            command,
+        } => {
+            let sudo = (sudo || sudo_user.is_some()).then_some(server::Sudo {
+                user: sudo_user,
+                password: None,
+            });
+            exec(server_id.as_deref(), all, &filter, &tag, sudo, &command)
+        }
+        ServerCommand::Service {
+            server_id,
+            action,
+            service,
+        } => control_service(&server_id, action.into(), &service),
         ServerCommand::Key(key_args) => run_key(key_args),
+        ServerCommand::Tag(tag_args) => run_tag(tag_args),
+    }
+}
+
+fn exec(
+    server_id: Option<&str>,
+    all: bool,
+    filter: &[String],
+    tags: &[String],
+    sudo: Option<server::Sudo>,
+    command: &[String],
+) -> CmdResult<ServerOutput> {
+    // Same rule as `homeboy ssh`: one argument is a raw shell string,
+    // several are quoted individually.
+    let command = match command {
+        [single] => single.clone(),
+        args => shell::quote_args(args),
+    };
+
+    let servers = match server_id {
+        Some(server_id) => vec![server::load(server_id)?],
+        None if all || !filter.is_empty() || !tags.is_empty() => {
+            server::select_servers(filter, tags)?
+        }
+        None => {
+            return Err(homeboy::Error::validation_invalid_argument(
+                "server_id",
+                "Pass a server ID, --all, --filter, or --tag",
+                None,
+                None,
+            ))
+        }
+    };
+
+    let mut result = server::exec_on_with(&servers, &command, sudo.as_ref());
+    // Ask once, and only when some server's sudo wanted a password.
+    if let Some(sudo) = sudo {
+        let pending = server::sudo_password_required(&result);
+        if !pending.is_empty() && tty::is_stdin_tty() {
+            let password =
+                tty::prompt_password(&format!("[sudo] password on {}: ", pending.join(", ")))?;
+            let sudo = server::Sudo {
+                password: Some(password),
+                ..sudo
+            };
+            result = server::rerun_with_sudo_password(result, &servers, &command, &sudo);
+        }
     }
+
+    let exit_code = if result.summary.failed > 0 { 1 } else { 0 };
+    Ok((
+        ServerOutput {
+            command: "server.exec".to_string(),
+            id: server_id.map(str::to_string),
+            extra: ServerExtra {
+                exec: Some(result),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        exit_code,
+    ))
+}
+
+fn control_service(server_id: &str, action: ServiceAction, name: &str) -> CmdResult<ServerOutput> {
+    let result = server::control(server_id, action, name)?;
+    // `status` reports a stopped service as a non-zero exit, like `systemctl status`.
+    let exit_code = if result.active || action == ServiceAction::Stop {
+        0
+    } else {
+        1
+    };
+    Ok((
+        ServerOutput {
+            command: "server.service".to_string(),
+            id: Some(server_id.to_string()),
+            extra: ServerExtra {
+                service: Some(result),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        exit_code,
+    ))
 }
 
 fn session_connect(server_id: &str) -> CmdResult<ServerOutput> {
@@ -312,17 +535,15 @@ fn show(server_id: &str) -> CmdResult<ServerOutput> {
 }
 
 fn set(args: DynamicSetArgs) -> CmdResult<ServerOutput> {
-    let merged = super::merge_dynamic_args(&args)?.ok_or_else(|| {
-        homeboy::Error::validation_invalid_argument(
-            "spec",
-            "Provide JSON spec, --json flag, --base64 flag, or --key value flags",
-            None,
-            None,
-        )
-    })?;
+    let merged = super::set_patch(&args)?;
     let (json_string, replace_fields) = super::finalize_set_spec(&merged, &args.replace)?;
 
-    match server::merge(args.id.as_deref(), &json_string, &replace_fields)? {
+    match server::merge(
+        args.id.as_deref(),
+        &json_string,
+        &replace_fields,
+        &args.unset,
+    )? {
         MergeOutput::Single(result) => {
             let svr = server::load(&result.id)?;
             Ok((
@@ -350,6 +571,29 @@ fn set(args: DynamicSetArgs) -> CmdResult<ServerOutput> {
     }
 }
 
+fn rename(server_id: &str, new_id: &str, dry_run: bool) -> CmdResult<ServerOutput> {
+    let result = server::rename_with_references(server_id, new_id, dry_run)?;
+
+    Ok((
+        ServerOutput {
+            command: "server.rename".to_string(),
+            id: Some(result.server.id.clone()),
+            entity: Some(result.server),
+            updated_fields: vec!["id".to_string()],
+            extra: ServerExtra {
+                rename: Some(ServerRenameOutput {
+                    old_id: result.old_id,
+                    dry_run: result.dry_run,
+                    updated_projects: result.updated_projects,
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
 fn delete(server_id: &str) -> CmdResult<ServerOutput> {
     server::delete_safe(server_id)?;
 
@@ -364,8 +608,8 @@ fn delete(server_id: &str) -> CmdResult<ServerOutput> {
     ))
 }
 
-fn list() -> CmdResult<ServerOutput> {
-    let servers = server::list()?;
+fn list(tags: &[String]) -> CmdResult<ServerOutput> {
+    let servers = server::list_tagged(tags)?;
 
     Ok((
         ServerOutput {
@@ -377,6 +621,70 @@ fn list() -> CmdResult<ServerOutput> {
     ))
 }
 
+pub fn is_tabular_output(args: &ServerArgs) -> bool {
+    matches!(
+        &args.command,
+        ServerCommand::List { format, columns, .. } if format.resolve(columns) != ListFormat::Json
+    )
+}
+
+pub fn run_tabular(args: ServerArgs) -> CmdResult<String> {
+    let ServerCommand::List {
+        tag,
+        format,
+        columns,
+    } = args.command
+    else {
+        return Err(homeboy::Error::internal_unexpected(
+            "Unexpected server command for tabular output",
+        ));
+    };
+    let (output, exit_code) = list(&tag)?;
+    Ok((format.render(&output, &columns)?, exit_code))
+}
+
+impl CsvRows for ServerOutput {
+    fn csv_header(&self) -> &'static [&'static str] {
+        &[
+            "id",
+            "aliases",
+            "host",
+            "user",
+            "port",
+            "identity_file",
+            "kind",
+            "tags",
+            "auth.mode",
+            "proxy_jump",
+        ]
+    }
+
+    fn to_csv_rows(&self) -> Vec<serde_json::Value> {
+        self.entities
+            .iter()
+            .filter_map(|server| serde_json::to_value(server).ok())
+            .collect()
+    }
+}
+
+fn run_tag(args: TagArgs) -> CmdResult<ServerOutput> {
+    let (action, svr) = match args.command {
+        TagCommand::Add { id, tags } => ("add", server::add_tags(&id, &tags)?),
+        TagCommand::Remove { id, tags } => ("remove", server::remove_tags(&id, &tags)?),
+    };
+
+    Ok((
+        ServerOutput {
+            command: format!("server.tag.{}", action),
+            id: Some(svr.id.clone()),
+            entity: Some(svr),
+            updated_fields: vec!["tags".to_string()],
+            ..Default::default()
+        },
+        0,
+    ))
+}
+
 fn key_generate(server_id: &str) -> CmdResult<ServerOutput> {
     let result = server::generate_key(server_id)?;
 
@@ -500,3 +808,13 @@ fn key_import(server_id: &str, private_key_path: &str) -> CmdResult<ServerOutput
         0,
     ))
 }
+
+/// IDs a create spec would register: the object's `id`, or each array entry's.
+fn spec_ids(raw: &str) -> Vec<String> {
+    let id_of = |value: &serde_json::Value| value.get("id")?.as_str().map(str::to_string);
+    match serde_json::from_str::<serde_json::Value>(raw) {
+        Ok(serde_json::Value::Array(items)) => items.iter().filter_map(id_of).collect(),
+        Ok(value) => id_of(&value).into_iter().collect(),
+        Err(_) => Vec::new(),
+    }
+}
@@ -1,6 +1,6 @@
 use clap::{Args, Subcommand};
 use homeboy::engine::shell;
-use homeboy::server::{self, Server};
+use homeboy::server::{self, KeyCopyResult, KeyCopyStatus, Server};
 use homeboy::server::{resolve_context, SshClient, SshResolveArgs};
 use serde::Serialize;
 
@@ -38,6 +38,15 @@ pub struct SshArgs {
 pub enum SshSubcommand {
     /// List configured SSH server targets
     List,
+    /// Install a public key in a server's authorized_keys (like ssh-copy-id)
+    CopyId {
+        /// Server ID
+        server_id: String,
+
+        /// Public key to install (default: ~/.ssh/id_ed25519.pub, id_ecdsa.pub, or id_rsa.pub)
+        #[arg(long, value_name = "path")]
+        key: Option<String>,
+    },
 }
 
 #[derive(Debug, Serialize)]
@@ -45,6 +54,7 @@ pub enum SshSubcommand {
 pub enum SshOutput {
     Connect(SshConnectOutput),
     List(SshListOutput),
+    CopyId(KeyCopyResult),
 }
 
 #[derive(Debug, Serialize)]
@@ -72,6 +82,19 @@ pub fn run(args: SshArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<Ss
             let servers = server::list()?;
             Ok((SshOutput::List(SshListOutput { servers }), 0))
         }
+        Some(SshSubcommand::CopyId { server_id, key }) => {
+            homeboy::log_status!("ssh", "Installing public key on {}...", server_id);
+            let result = server::copy_id(&server_id, key.as_deref())?;
+            match result.status {
+                KeyCopyStatus::Added => {
+                    homeboy::log_status!("ssh", "Added {} to authorized_keys", result.key_path)
+                }
+                KeyCopyStatus::AlreadyPresent => {
+                    homeboy::log_status!("ssh", "{} is already in authorized_keys", result.key_path)
+                }
+            }
+            Ok((SshOutput::CopyId(result), 0))
+        }
         None => {
             // Build resolve args based on simplified CLI args
             let resolve_args = if args.as_server {
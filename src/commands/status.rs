@@ -18,6 +18,10 @@ pub struct StatusArgs {
     #[arg(long, value_name = "PATH")]
     pub path: Option<String>,
 
+    /// Compute the repo context for this directory instead of the current one
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["path", "project"])]
+    pub cwd: Option<String>,
+
     /// Show the full workspace/context report (the old init behavior)
     #[arg(long)]
     pub full: bool,
@@ -38,13 +42,17 @@ pub struct StatusArgs {
     #[arg(long)]
     pub docs_only: bool,
 
-    /// Show all components regardless of current directory context
+    /// Show all components regardless of current directory context, including archived ones
     #[arg(long, short = 'a')]
     pub all: bool,
 
     /// Show only outdated components (local != remote)
     #[arg(long)]
     pub outdated: bool,
+
+    /// Compare repo context between two git refs (components, extensions, versions)
+    #[arg(long, num_args = 2, value_names = ["FROM", "TO"], conflicts_with = "project")]
+    pub diff: Option<Vec<String>>,
 }
 
 /// Per-component upstream drift info.
@@ -84,6 +92,9 @@ pub struct StatusOutput {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub upstream_drift: Vec<UpstreamDrift>,
     pub clean: usize,
+    /// Repo context of the `--cwd` directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<context::ContextOutput>,
 }
 
 /// A single row in the project status dashboard.
@@ -150,6 +161,7 @@ pub enum StatusResult {
     Summary(StatusOutput),
     Full(homeboy::context::report::ContextReport),
     Dashboard(ProjectDashboardOutput),
+    Diff(context::RepoContextDiff),
 }
 
 impl serde::Serialize for StatusResult {
@@ -161,11 +173,16 @@ impl serde::Serialize for StatusResult {
             StatusResult::Summary(output) => output.serialize(serializer),
             StatusResult::Full(output) => output.serialize(serializer),
             StatusResult::Dashboard(output) => output.serialize(serializer),
+            StatusResult::Diff(output) => output.serialize(serializer),
         }
     }
 }
 
 pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusResult> {
+    if let Some([from, to]) = args.diff.as_deref() {
+        return run_ref_diff(args.path.as_deref(), from, to);
+    }
+
     if args.path.is_some() {
         return run_path_status(&args);
     }
@@ -176,12 +193,15 @@ pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusRes
     }
 
     if args.full {
-        let mut report = context::build_report(args.all, "status")?;
+        let mut report = match args.cwd.as_deref() {
+            Some(cwd) => context::build_report_in(args.all, "status", cwd)?,
+            None => context::build_report(args.all, "status")?,
+        };
         report.command = "status".to_string();
         return Ok((StatusResult::Full(report), 0));
     }
 
-    let (context_output, _) = context::run(None)?;
+    let (context_output, _) = context::run(args.cwd.as_deref())?;
 
     let relevant_ids: std::collections::HashSet<String> = context_output
         .matched_components
@@ -190,7 +210,11 @@ pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusRes
         .cloned()
         .collect();
 
-    let all_components = component::inventory().unwrap_or_default();
+    let all_components: Vec<component::Component> = component::inventory()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| args.all || !c.archived)
+        .collect();
 
     let show_all = args.all || relevant_ids.is_empty();
 
@@ -203,7 +227,11 @@ pub fn run(args: StatusArgs, _global: &super::GlobalArgs) -> CmdResult<StatusRes
             .collect()
     };
 
-    summarize_components(components, &args)
+    let (mut result, exit_code) = summarize_components(components, &args)?;
+    if let (Some(_), StatusResult::Summary(output)) = (&args.cwd, &mut result) {
+        output.context = Some(context_output);
+    }
+    Ok((result, exit_code))
 }
 
 fn summarize_components(
@@ -273,6 +301,7 @@ fn summarize_components(
             behind_upstream,
             upstream_drift,
             clean,
+            context: None,
         }),
         0,
     ))
@@ -292,6 +321,20 @@ fn run_path_status(args: &StatusArgs) -> CmdResult<StatusResult> {
     summarize_components(vec![component], args)
 }
 
+/// Repo context diff between two refs of the checkout at `path` (or cwd).
+fn run_ref_diff(path: Option<&str>, from: &str, to: &str) -> CmdResult<StatusResult> {
+    let start = match path {
+        Some(path) => std::path::PathBuf::from(path),
+        None => {
+            std::env::current_dir().map_err(|e| homeboy::Error::internal_io(e.to_string(), None))?
+        }
+    };
+    let root = git::run_git(&start, &["rev-parse", "--show-toplevel"], "find git root")?;
+
+    let diff = context::diff_refs(std::path::Path::new(root.trim()), from, to)?;
+    Ok((StatusResult::Diff(diff), 0))
+}
+
 /// Project dashboard: show version drift across all components in a project.
 ///
 /// Combines local version, remote (deployed) version, release state, upstream
@@ -511,6 +554,8 @@ fn fetch_project_remote_versions(project_id: &str) -> std::collections::HashMap<
         no_pull: true,
         head: true,
         tagged: false,
+        no_verify: false,
+        step_filter: Default::default(),
     };
 
     match deploy::run(project_id, &config) {
@@ -650,6 +695,7 @@ mod tests {
         StatusArgs {
             project,
             path: Some(path),
+            cwd: None,
             full,
             uncommitted: false,
             needs_release: false,
@@ -657,6 +703,7 @@ mod tests {
             docs_only: false,
             all: false,
             outdated: false,
+            diff: None,
         }
     }
 
@@ -13,10 +13,11 @@ use homeboy::observation::{
     merge_metadata, ActiveObservation, NewFindingRecord, NewRunRecord, RunStatus,
 };
 use std::path::Path;
+use std::time::Instant;
 
 use super::utils::args::{
     filter_passthrough_args, BaselineArgs, ExtensionOverrideArgs, HiddenJsonArgs,
-    PassthroughCommand, PositionalComponentArgs, SettingArgs,
+    PassthroughCommand, PositionalComponentArgs, ResultFormat, SettingArgs,
 };
 use super::{CmdResult, GlobalArgs};
 
@@ -76,6 +77,26 @@ pub struct TestArgs {
     /// Print compact machine-readable summary (for CI wrappers)
     #[arg(long)]
     pub json_summary: bool,
+
+    /// Output format: json (default envelope) or junit (JUnit XML for CI test reporters)
+    #[arg(long, value_enum, default_value_t = ResultFormat::Json, conflicts_with_all = ["json_summary", "drift"])]
+    pub format: ResultFormat,
+}
+
+/// Check if this invocation should print JUnit XML instead of the JSON envelope.
+pub fn is_plain_text_mode(args: &TestArgs) -> bool {
+    args.format == ResultFormat::Junit
+}
+
+/// `--format junit`: run the tests and render the result as JUnit XML.
+pub fn run_plain_text(args: TestArgs, global: &GlobalArgs) -> CmdResult<String> {
+    let started = Instant::now();
+    let (output, exit_code) = run(args, global)?;
+    let suite = report::junit_suite(&output, Some(started.elapsed().as_secs_f64()));
+    Ok((
+        homeboy::engine::junit::render("homeboy test", &[suite]),
+        exit_code,
+    ))
 }
 
 /// Filter out homeboy-owned flags from trailing args before passing to extension scripts.
@@ -1,4 +1,4 @@
-use clap::Args;
+use clap::{Args, ValueEnum};
 use homeboy::upgrade;
 use serde_json::Value;
 use std::path::PathBuf;
@@ -32,13 +32,43 @@ pub struct UpgradeArgs {
     #[arg(long, value_name = "PATH")]
     pub source_path: Option<PathBuf>,
 
+    /// Release channel (default: `upgrade.channel` from config, else stable)
+    #[arg(long, value_enum)]
+    pub channel: Option<UpgradeChannel>,
+
+    /// Install this exact version instead of the channel's newest
+    #[arg(long, value_name = "X.Y.Z")]
+    pub version: Option<String>,
+
+    /// Allow installing a version older than the current one
+    #[arg(long)]
+    pub allow_downgrade: bool,
+
     #[command(flatten)]
     _json: HiddenJsonArgs,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpgradeChannel {
+    Stable,
+    Beta,
+}
+
+impl From<UpgradeChannel> for upgrade::ReleaseChannel {
+    fn from(channel: UpgradeChannel) -> Self {
+        match channel {
+            UpgradeChannel::Stable => upgrade::ReleaseChannel::Stable,
+            UpgradeChannel::Beta => upgrade::ReleaseChannel::Beta,
+        }
+    }
+}
+
 pub fn run(args: UpgradeArgs, _global: &GlobalArgs) -> CmdResult<Value> {
+    let channel = args.channel.map(upgrade::ReleaseChannel::from);
+
     if args.check {
-        let result = upgrade::check_for_updates()?;
+        let channel = channel.unwrap_or_else(upgrade::configured_channel);
+        let result = upgrade::check_for_updates(channel)?;
         let json = serde_json::to_value(result)
             .map_err(|e| homeboy::Error::internal_json(e.to_string(), None))?;
         return Ok((json, 0));
@@ -66,6 +96,11 @@ pub fn run(args: UpgradeArgs, _global: &GlobalArgs) -> CmdResult<Value> {
         method_override,
         args.skip_extensions,
         args.source_path.as_deref(),
+        &upgrade::ReleaseSelection {
+            channel,
+            version: args.version,
+            allow_downgrade: args.allow_downgrade,
+        },
     )?;
     let json = serde_json::to_value(&result)
         .map_err(|e| homeboy::Error::internal_json(e.to_string(), None))?;
@@ -92,3 +127,33 @@ pub fn run(args: UpgradeArgs, _global: &GlobalArgs) -> CmdResult<Value> {
 
     Ok((json, 0))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli_surface::{Cli, Commands};
+    use clap::Parser;
+
+    #[test]
+    fn parses_channel_version_and_allow_downgrade() {
+        let cli = Cli::try_parse_from([
+            "homeboy",
+            "upgrade",
+            "--channel",
+            "beta",
+            "--version",
+            "0.150.0",
+            "--allow-downgrade",
+        ])
+        .expect("upgrade options parse");
+
+        match cli.command {
+            Commands::Upgrade(args) => {
+                assert_eq!(args.channel, Some(UpgradeChannel::Beta));
+                assert_eq!(args.version.as_deref(), Some("0.150.0"));
+                assert!(args.allow_downgrade);
+            }
+            _ => panic!("expected upgrade command"),
+        }
+    }
+}
@@ -6,7 +6,7 @@
 //!
 //! See: https://github.com/Extra-Chill/homeboy/issues/436
 
-use clap::{Arg, ArgAction, Args, Command, CommandFactory};
+use clap::{Arg, ArgAction, Args, Command, CommandFactory, ValueEnum};
 use std::path::PathBuf;
 
 use crate::cli_surface::Cli;
@@ -23,7 +23,7 @@ pub(crate) fn normalize_version_show(args: Vec<String>) -> Vec<String> {
         return args;
     }
 
-    let known_subcommands = ["show", "bump", "--help", "-h", "help"];
+    let known_subcommands = ["show", "list", "sync", "bump", "--help", "-h", "help"];
     let second_arg = args.get(2).map(|s| s.as_str()).unwrap_or("");
 
     if known_subcommands.contains(&second_arg) || second_arg.starts_with('-') {
@@ -411,6 +411,17 @@ mod normalize_tests {
         parts.iter().map(|s| s.to_string()).collect()
     }
 
+    #[test]
+    fn version_subcommands_are_not_treated_as_component_ids() {
+        let sync = argv(&["homeboy", "version", "sync", "widget", "1.2.0"]);
+        assert_eq!(normalize(sync.clone()), sync);
+
+        assert_eq!(
+            normalize(argv(&["homeboy", "version", "widget"])),
+            argv(&["homeboy", "version", "show", "widget"])
+        );
+    }
+
     /// `--output` placed AFTER a `last = true` subcommand must NOT trigger
     /// the `--` separator insertion — it's a `global = true` flag on the
     /// top-level Cli struct and clap routes it there directly. Inserting
@@ -781,6 +792,99 @@ pub struct BaselineArgs {
     pub ratchet: bool,
 }
 
+// ============================================================================
+// ListFormat: --format for list-style commands
+// ============================================================================
+
+/// `--format` for list-style commands: the JSON envelope, CSV for
+/// spreadsheets (see `homeboy::output::CsvRows`), or an aligned table for
+/// scanning in a terminal.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ListFormat {
+    #[default]
+    Json,
+    Csv,
+    Table,
+}
+
+impl ListFormat {
+    /// The format to print: `--columns` on its own asks for a table.
+    pub fn resolve(self, columns: &[String]) -> Self {
+        if self == Self::Json && !columns.is_empty() {
+            Self::Table
+        } else {
+            self
+        }
+    }
+
+    /// Render a list result as CSV or a table, sized to the terminal.
+    pub fn render(
+        self,
+        result: &impl homeboy::CsvRows,
+        columns: &[String],
+    ) -> homeboy::Result<String> {
+        match self.resolve(columns) {
+            Self::Table => {
+                homeboy::output::render_table(result, columns, super::tty::stdout_width())
+            }
+            _ if !columns.is_empty() => Err(homeboy::Error::validation_invalid_argument(
+                "columns",
+                "--columns applies to --format table",
+                None,
+                None,
+            )),
+            _ => Ok(homeboy::output::render_csv(result)),
+        }
+    }
+}
+
+// ============================================================================
+// ResultFormat: --format for runner commands
+// ============================================================================
+
+/// `--format` for runner commands (test, lint): the JSON envelope, or JUnit
+/// XML for CI test reporters (see `homeboy::engine::junit`).
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResultFormat {
+    #[default]
+    Json,
+    Junit,
+}
+
+// ============================================================================
+// FailOnArgs: --fail-on + --fail-exit-code
+// ============================================================================
+
+/// Issue-based exit code flags shared by finding-reporting commands (audit,
+/// lint). Without `--fail-on` each command keeps its own exit code rules.
+#[derive(Args, Debug, Clone, Default)]
+pub struct FailOnArgs {
+    /// Exit non-zero when issues at this level exist: findings, warnings, errors, or none
+    #[arg(long, value_name = "level")]
+    pub fail_on: Option<String>,
+
+    /// Exit code used when --fail-on matches
+    #[arg(long, value_name = "code", default_value_t = 1, requires = "fail_on")]
+    pub fail_exit_code: i32,
+}
+
+impl FailOnArgs {
+    pub fn policy(&self) -> homeboy::Result<Option<homeboy::output::ExitPolicy>> {
+        self.fail_on
+            .as_deref()
+            .map(|value| {
+                let fail_on = value.parse().map_err(|msg| {
+                    homeboy::Error::validation_invalid_argument("fail-on", msg, None, None)
+                })?;
+                Ok(homeboy::output::ExitPolicy {
+                    fail_on,
+                    exit_code: self.fail_exit_code,
+                })
+            })
+            .transpose()
+    }
+}
+
 // ============================================================================
 // WriteModeArgs: --write (dry-run by default)
 // ============================================================================
@@ -0,0 +1,37 @@
+//! Interactive component selection for commands run without a component ID.
+
+use homeboy::component;
+
+use super::tty;
+
+/// Fill an omitted component ID by asking the user to pick a registered
+/// component.
+///
+/// Does nothing when the ID is already set, off a TTY, or when
+/// `discovers_from_cwd` and the working directory resolves to a component on
+/// its own, so non-interactive runs keep their missing-argument error.
+pub fn pick_if_omitted(
+    component_id: &mut Option<String>,
+    discovers_from_cwd: bool,
+) -> homeboy::Result<()> {
+    if component_id.is_some() || !tty::require_tty_for_interactive() {
+        return Ok(());
+    }
+    if discovers_from_cwd && component::resolve(None).is_ok() {
+        return Ok(());
+    }
+
+    let mut ids = component::list_ids().unwrap_or_default();
+    if ids.is_empty() {
+        return Ok(());
+    }
+    ids.sort();
+
+    let chosen = tty::select(
+        "No component ID given. Registered components:",
+        &ids,
+        "Use which component? [number or ID, blank to cancel] ",
+    )?;
+    *component_id = chosen.map(|index| ids.swap_remove(index));
+    Ok(())
+}
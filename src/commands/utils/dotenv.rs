@@ -0,0 +1,142 @@
+//! `--env-file` support: parse a `.env` file and load it into the process
+//! environment so build and deploy child processes inherit it.
+//!
+//! Supports `KEY=value`, an optional `export ` prefix, `#` comments, and
+//! single- or double-quoted values. Variables already set in the shell win.
+
+use std::path::Path;
+
+/// Parse `.env` content into `(key, value)` pairs in file order.
+pub fn parse(content: &str) -> homeboy::Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(invalid_line(index + 1, "expected KEY=value"));
+        };
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(invalid_line(index + 1, "invalid variable name"));
+        }
+        vars.push((key.to_string(), parse_value(value.trim(), index + 1)?));
+    }
+    Ok(vars)
+}
+
+fn parse_value(value: &str, line: usize) -> homeboy::Result<String> {
+    let Some(quote) = value.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+        // Unquoted: a ` #` starts a trailing comment.
+        let value = value.split(" #").next().unwrap_or_default();
+        return Ok(value.trim_end().to_string());
+    };
+    let mut parsed = String::new();
+    let mut chars = value[1..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c == quote => return Ok(parsed),
+            '\\' if quote == '"' => match chars.next() {
+                Some('n') => parsed.push('\n'),
+                Some(escaped @ ('"' | '\\')) => parsed.push(escaped),
+                Some(other) => {
+                    parsed.push('\\');
+                    parsed.push(other);
+                }
+                None => break,
+            },
+            c => parsed.push(c),
+        }
+    }
+    Err(invalid_line(line, "unterminated quote"))
+}
+
+fn invalid_line(line: usize, problem: &str) -> homeboy::Error {
+    homeboy::Error::validation_invalid_argument(
+        "env-file",
+        format!("Line {}: {}", line, problem),
+        None,
+        None,
+    )
+}
+
+/// Load `path` into the process environment, skipping keys the shell already
+/// sets. Returns the keys that were loaded.
+pub fn load(path: &str) -> homeboy::Result<Vec<String>> {
+    if !Path::new(path).is_file() {
+        return Err(homeboy::Error::validation_invalid_argument(
+            "env-file",
+            format!("Env file not found: {}", path),
+            None,
+            None,
+        ));
+    }
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| homeboy::Error::internal_io(e.to_string(), Some(format!("read {}", path))))?;
+
+    let mut loaded = Vec::new();
+    for (key, value) in parse(&content)? {
+        if std::env::var_os(&key).is_some() {
+            continue;
+        }
+        std::env::set_var(&key, value);
+        loaded.push(key);
+    }
+
+    homeboy::log_status!(
+        "env",
+        "Loaded {} variable(s) from {} (values redacted): {}",
+        loaded.len(),
+        path,
+        loaded.join(", ")
+    );
+    Ok(loaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let content = r#"
+# comment
+export API_URL=https://example.test
+PLAIN = value # trailing comment
+DOUBLE="a \"quoted\" value"
+SINGLE='keep $HOME literal'
+EMPTY=
+"#;
+
+        let vars = parse(content).expect("parse");
+
+        assert_eq!(
+            vars,
+            [
+                ("API_URL", "https://example.test"),
+                ("PLAIN", "value"),
+                ("DOUBLE", "a \"quoted\" value"),
+                ("SINGLE", "keep $HOME literal"),
+                ("EMPTY", ""),
+            ]
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_rejects_malformed_lines() {
+        let err = parse("OK=1\nnot a pair\n").unwrap_err();
+        assert!(err.message.contains("Line 2"));
+
+        let err = parse("QUOTED=\"open\n").unwrap_err();
+        assert!(err.message.contains("unterminated quote"));
+    }
+
+    #[test]
+    fn load_errors_on_missing_file() {
+        let err = load("/nonexistent/.env").unwrap_err();
+        assert!(err.message.contains("Env file not found"));
+    }
+}
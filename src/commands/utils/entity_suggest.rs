@@ -1,8 +1,10 @@
 //! Entity suggestion utilities for unrecognized CLI subcommands.
 
-use homeboy::engine::text::levenshtein;
+use homeboy::engine::text::{is_near_duplicate, levenshtein};
 use homeboy::{component, extension, project, server};
 
+use super::tty;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EntityType {
     Component,
@@ -146,3 +148,45 @@ pub fn generate_entity_hints(
 
     hints
 }
+
+/// Refuse to create `id` when it nearly duplicates an existing ID (see
+/// [`is_near_duplicate`]), unless `force` is set or the user confirms in a
+/// terminal. Guards against registering `prod_server` next to `prod-server`.
+pub fn guard_near_duplicate(
+    entity_type: EntityType,
+    id: &str,
+    existing: &[String],
+    force: bool,
+) -> homeboy::Result<()> {
+    if force {
+        return Ok(());
+    }
+    let similar: Vec<&str> = existing
+        .iter()
+        .map(String::as_str)
+        .filter(|other| !other.eq_ignore_ascii_case(id) && is_near_duplicate(id, other))
+        .collect();
+    let Some(first) = similar.first() else {
+        return Ok(());
+    };
+
+    let label = entity_type.label();
+    let message = format!(
+        "{} '{}' looks like existing {} {}",
+        label,
+        id,
+        label,
+        similar.join(", ")
+    );
+    if tty::require_tty_for_interactive() {
+        let answer = tty::prompt(&format!("{}. Create it anyway? [y/N] ", message))?;
+        if matches!(answer.to_lowercase().as_str(), "y" | "yes") {
+            return Ok(());
+        }
+    }
+    Err(
+        homeboy::Error::validation_invalid_argument("id", message, Some(id.to_string()), None)
+            .with_hint(format!("Reuse it: homeboy {} show {}", label, first))
+            .with_hint("Pass --force to create it anyway"),
+    )
+}
@@ -1,5 +1,8 @@
 pub mod args;
+pub mod component_picker;
+pub mod dotenv;
 pub mod entity_suggest;
+pub mod output_schema;
 pub mod resolve;
 pub mod resource_policy;
 pub mod response;
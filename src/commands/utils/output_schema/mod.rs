@@ -0,0 +1,271 @@
+//! Versioned JSON output contract.
+//!
+//! Every JSON envelope carries `api_version`, the version of the envelope
+//! itself, and for built-in commands `schema_version`, the version of that
+//! command's `data` shape. Bump a command's version here when its result
+//! changes incompatibly (a field removed, renamed, or retyped); new optional
+//! fields do not need a bump. `homeboy --print-schema <command>` prints the
+//! schema consumers can pin against, with the `data` shapes from [`shapes`].
+
+mod shapes;
+
+use homeboy::{Error, Result};
+use serde_json::{json, Map, Value};
+
+/// Version of the `success`/`data`/`error` envelope.
+pub const API_VERSION: u32 = 1;
+
+/// Result contract of one top-level command.
+pub struct CommandSchema {
+    pub command: &'static str,
+    pub version: u32,
+    data: DataShape,
+}
+
+enum DataShape {
+    /// One of the listed result variants, gathered from one or more tables
+    /// in [`shapes`].
+    Variants(&'static [&'static [Shape]]),
+    /// The shared `EntityCrudOutput` shape.
+    EntityCrud,
+}
+
+/// One result variant: the fields it always prints, with their types.
+type Shape = &'static [(&'static str, Ty)];
+
+/// JSON type of one result field.
+#[derive(Clone, Copy)]
+enum Ty {
+    Is(&'static str),
+    /// The type, `null`, or absent.
+    Optional(&'static str),
+    /// A fixed string, used by tagged results (`"command": "undo.list"`).
+    Const(&'static str),
+    Any,
+}
+
+const STRING: Ty = Ty::Is("string");
+const INTEGER: Ty = Ty::Is("integer");
+const NUMBER: Ty = Ty::Is("number");
+const BOOLEAN: Ty = Ty::Is("boolean");
+const ARRAY: Ty = Ty::Is("array");
+const OBJECT: Ty = Ty::Is("object");
+const ANY: Ty = Ty::Any;
+const OPT_STRING: Ty = Ty::Optional("string");
+const OPT_INTEGER: Ty = Ty::Optional("integer");
+const OPT_BOOLEAN: Ty = Ty::Optional("boolean");
+const OPT_ARRAY: Ty = Ty::Optional("array");
+const OPT_OBJECT: Ty = Ty::Optional("object");
+
+const fn schema(command: &'static str, version: u32, data: DataShape) -> CommandSchema {
+    CommandSchema {
+        command,
+        version,
+        data,
+    }
+}
+
+const COMMAND_SCHEMAS: &[CommandSchema] = &[
+    schema("api", 1, DataShape::Variants(&[shapes::API])),
+    schema("audit", 1, DataShape::Variants(&[shapes::AUDIT])),
+    schema("auth", 1, DataShape::Variants(&[shapes::AUTH])),
+    schema(
+        "bench",
+        1,
+        DataShape::Variants(&[shapes::BENCH, shapes::RUNS]),
+    ),
+    schema("build", 1, DataShape::Variants(&[shapes::BUILD])),
+    schema("changelog", 1, DataShape::Variants(&[shapes::CHANGELOG])),
+    schema("changes", 1, DataShape::Variants(&[shapes::CHANGES])),
+    schema("component", 1, DataShape::EntityCrud),
+    schema("config", 1, DataShape::Variants(&[shapes::CONFIG])),
+    schema("daemon", 1, DataShape::Variants(&[shapes::DAEMON])),
+    schema("db", 1, DataShape::Variants(&[shapes::DB])),
+    schema("deploy", 1, DataShape::Variants(&[shapes::DEPLOY])),
+    schema("deps", 1, DataShape::Variants(&[shapes::DEPS])),
+    schema("docs", 1, DataShape::Variants(&[shapes::DOCS])),
+    schema("doctor", 1, DataShape::Variants(&[shapes::DOCTOR])),
+    schema("extension", 1, DataShape::Variants(&[shapes::EXTENSION])),
+    schema("file", 1, DataShape::Variants(&[shapes::FILE])),
+    schema("fleet", 1, DataShape::EntityCrud),
+    schema("git", 1, DataShape::Variants(&[shapes::GIT])),
+    schema("http", 1, DataShape::Variants(&[shapes::HTTP])),
+    schema("issues", 1, DataShape::Variants(&[shapes::ISSUES])),
+    schema("lint", 1, DataShape::Variants(&[shapes::LINT])),
+    schema("logs", 1, DataShape::Variants(&[shapes::LOGS])),
+    schema("observe", 1, DataShape::Variants(&[shapes::OBSERVE])),
+    schema("project", 1, DataShape::EntityCrud),
+    schema("refactor", 1, DataShape::Variants(&[shapes::REFACTOR])),
+    schema("release", 1, DataShape::Variants(&[shapes::RELEASE])),
+    schema("report", 1, DataShape::Variants(&[shapes::REPORT])),
+    schema("review", 1, DataShape::Variants(&[shapes::REVIEW])),
+    schema("rig", 1, DataShape::Variants(&[shapes::RIG, shapes::RUNS])),
+    schema("runner", 1, DataShape::EntityCrud),
+    schema("runs", 1, DataShape::Variants(&[shapes::RUNS])),
+    schema("self", 1, DataShape::Variants(&[shapes::SELF])),
+    schema("server", 1, DataShape::EntityCrud),
+    schema("ssh", 1, DataShape::Variants(&[shapes::SSH])),
+    schema("stack", 1, DataShape::Variants(&[shapes::STACK])),
+    schema("status", 1, DataShape::Variants(&[shapes::STATUS])),
+    schema("test", 1, DataShape::Variants(&[shapes::TEST])),
+    schema("trace", 1, DataShape::Variants(&[shapes::TRACE])),
+    schema("triage", 1, DataShape::Variants(&[shapes::TRIAGE])),
+    schema("undo", 1, DataShape::Variants(&[shapes::UNDO])),
+    schema("upgrade", 1, DataShape::Variants(&[shapes::UPGRADE])),
+    schema("version", 1, DataShape::Variants(&[shapes::VERSION])),
+];
+
+pub fn find(command: &str) -> Option<&'static CommandSchema> {
+    COMMAND_SCHEMAS
+        .iter()
+        .find(|schema| schema.command == command)
+}
+
+/// `schema_version` for a top-level command, or `None` for commands outside
+/// the registry (extension-provided CLI tools).
+pub fn schema_version(command: &str) -> Option<u32> {
+    find(command).map(|schema| schema.version)
+}
+
+/// JSON Schema for the full envelope printed by `command`.
+pub fn print_schema(command: &str) -> Result<Value> {
+    let schema = find(command).ok_or_else(|| {
+        let known: Vec<&str> = COMMAND_SCHEMAS.iter().map(|s| s.command).collect();
+        Error::validation_invalid_argument(
+            "print_schema",
+            format!("No output schema for command '{}'", command),
+            Some(command.to_string()),
+            None,
+        )
+        .with_hint(format!("Known commands: {}", known.join(", ")))
+    })?;
+
+    Ok(json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": format!("homeboy:output:{}:v{}", schema.command, schema.version),
+        "title": format!("homeboy {} output", schema.command),
+        "type": "object",
+        "required": ["success", "api_version", "schema_version"],
+        "properties": {
+            "success": { "type": "boolean" },
+            "api_version": { "const": API_VERSION },
+            "schema_version": { "const": schema.version },
+            "data": data_schema(&schema.data),
+            "error": error_schema(),
+        },
+    }))
+}
+
+fn data_schema(shape: &DataShape) -> Value {
+    match shape {
+        DataShape::Variants(tables) => {
+            let variants: Vec<Value> = tables
+                .iter()
+                .flat_map(|table| table.iter())
+                .map(|shape| variant_schema(shape))
+                .collect();
+            match <[Value; 1]>::try_from(variants) {
+                Ok([single]) => single,
+                Err(variants) => json!({ "anyOf": variants }),
+            }
+        }
+        DataShape::EntityCrud => json!({
+            "type": "object",
+            "required": ["command"],
+            "properties": {
+                "command": { "type": "string" },
+                "id": { "type": "string" },
+                "entity": { "type": "object" },
+                "entities": { "type": "array", "items": { "type": "object" } },
+                "updated_fields": { "type": "array", "items": { "type": "string" } },
+                "deleted": { "type": "array", "items": { "type": "string" } },
+                "import": { "type": "object" },
+                "batch": { "type": "object" },
+                "hint": { "type": "string" },
+            },
+        }),
+    }
+}
+
+fn variant_schema(shape: Shape) -> Value {
+    let mut required = Vec::new();
+    let mut properties = Map::new();
+    for (name, ty) in shape {
+        let field = match ty {
+            Ty::Is(ty) => {
+                required.push(*name);
+                json!({ "type": ty })
+            }
+            Ty::Optional(ty) => json!({ "type": [ty, "null"] }),
+            Ty::Const(value) => {
+                required.push(*name);
+                json!({ "const": value })
+            }
+            Ty::Any => {
+                required.push(*name);
+                json!({})
+            }
+        };
+        properties.insert(name.to_string(), field);
+    }
+    json!({
+        "type": "object",
+        "required": required,
+        "properties": properties,
+    })
+}
+
+fn error_schema() -> Value {
+    json!({
+        "type": "object",
+        "required": ["code", "message", "details"],
+        "properties": {
+            "code": { "type": "string" },
+            "message": { "type": "string" },
+            "details": {},
+            "hints": { "type": "array", "items": { "type": "object" } },
+            "retryable": { "type": "boolean" },
+        },
+    })
+}
+
+#[cfg(test)]
+#[path = "../../../../tests/commands/output_schema_test.rs"]
+mod output_schema_test;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn every_json_command_has_a_schema() {
+        let missing: Vec<String> = crate::cli_surface::Cli::command()
+            .get_subcommands()
+            .map(|command| command.get_name().to_string())
+            .filter(|name| name != "list" && find(name).is_none())
+            .collect();
+        assert!(missing.is_empty(), "no output schema for {:?}", missing);
+    }
+
+    #[test]
+    fn test_print_schema() {
+        let schema = print_schema("component").expect("schema");
+        assert_eq!(schema["properties"]["api_version"]["const"], API_VERSION);
+        assert_eq!(schema["properties"]["schema_version"]["const"], 1);
+        assert_eq!(
+            schema["properties"]["data"]["properties"]["command"]["type"],
+            "string"
+        );
+
+        let err = print_schema("nope").expect_err("unknown command");
+        assert!(err.message.contains("nope"));
+    }
+
+    #[test]
+    fn test_print_schema_is_versioned() {
+        let schema = print_schema("undo").expect("schema");
+        assert_eq!(schema["$id"], "homeboy:output:undo:v1");
+        assert_eq!(schema["properties"]["schema_version"]["const"], 1);
+    }
+}
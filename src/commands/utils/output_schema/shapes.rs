@@ -0,0 +1,1210 @@
+//! Per-command `data` shapes.
+//!
+//! Each entry lists one result variant a command can print: the fields that
+//! are always present, with their JSON type. `OPT_*` fields may also be
+//! `null` or omitted. Variants are matched with `anyOf`, so extra fields a
+//! command adds later never break a consumer pinned to the current version.
+
+use super::{
+    Shape, Ty, ANY, ARRAY, BOOLEAN, INTEGER, NUMBER, OBJECT, OPT_ARRAY, OPT_BOOLEAN, OPT_INTEGER,
+    OPT_OBJECT, OPT_STRING, STRING,
+};
+
+pub(super) const API: &[Shape] = &[&[
+    ("project_id", STRING),
+    ("method", STRING),
+    ("endpoint", STRING),
+    ("response", ANY),
+]];
+
+pub(super) const AUDIT: &[Shape] = &[
+    &[
+        ("command", Ty::Const("audit")),
+        ("passed", BOOLEAN),
+        ("component_id", STRING),
+        ("source_path", STRING),
+        ("summary", OBJECT),
+        ("conventions", ARRAY),
+        ("findings", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("audit.conventions")),
+        ("component_id", STRING),
+        ("conventions", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("audit.baseline")),
+        ("component_id", STRING),
+        ("path", STRING),
+        ("findings_count", INTEGER),
+        ("outliers_count", INTEGER),
+    ],
+    &[
+        ("command", Ty::Const("audit.compared")),
+        ("passed", BOOLEAN),
+        ("component_id", STRING),
+        ("source_path", STRING),
+        ("summary", OBJECT),
+        ("conventions", ARRAY),
+        ("findings", ARRAY),
+        ("baseline_comparison", OBJECT),
+    ],
+    &[
+        ("command", Ty::Const("audit.summary")),
+        ("total_findings", INTEGER),
+        ("warnings", INTEGER),
+        ("info", INTEGER),
+        ("exit_code", INTEGER),
+    ],
+];
+
+pub(super) const AUTH: &[Shape] = &[
+    &[("project_id", STRING), ("success", BOOLEAN)],
+    &[
+        ("project_id", STRING),
+        ("variable", STRING),
+        ("stored", BOOLEAN),
+    ],
+    &[
+        ("project_id", STRING),
+        ("variable", STRING),
+        ("value", OPT_STRING),
+        ("redacted", BOOLEAN),
+    ],
+    &[
+        ("project_id", STRING),
+        ("variable", STRING),
+        ("removed", BOOLEAN),
+    ],
+    &[("project_id", STRING), ("removed", INTEGER)],
+    &[("projects", ARRAY), ("removed", INTEGER)],
+    &[
+        ("project_id", STRING),
+        ("authenticated", BOOLEAN),
+        ("stored", BOOLEAN),
+        ("variables", ARRAY),
+    ],
+    &[("projects", ARRAY)],
+    &[("profile", STRING), ("kind", STRING), ("stored", BOOLEAN)],
+    &[
+        ("profile", STRING),
+        ("kind", OPT_STRING),
+        ("available", BOOLEAN),
+    ],
+    &[("profile", STRING), ("removed", INTEGER)],
+];
+
+pub(super) const BENCH: &[Shape] = &[
+    &[
+        ("passed", BOOLEAN),
+        ("status", STRING),
+        ("component", STRING),
+        ("exit_code", INTEGER),
+        ("iterations", INTEGER),
+    ],
+    &[
+        ("comparison", STRING),
+        ("passed", BOOLEAN),
+        ("component", STRING),
+        ("exit_code", INTEGER),
+        ("iterations", INTEGER),
+        ("rigs", ARRAY),
+        ("diff", OBJECT),
+        ("reports", OBJECT),
+    ],
+    &[
+        ("comparison", STRING),
+        ("summary_only", BOOLEAN),
+        ("passed", BOOLEAN),
+        ("component", STRING),
+        ("exit_code", INTEGER),
+        ("iterations", INTEGER),
+        ("rigs", ARRAY),
+    ],
+    &[
+        ("component", STRING),
+        ("component_id", STRING),
+        ("scenarios", ARRAY),
+        ("count", INTEGER),
+    ],
+];
+
+pub(super) const BUILD: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("build_command", STRING),
+        ("success", BOOLEAN),
+    ],
+    &[("action", STRING), ("results", ARRAY), ("summary", OBJECT)],
+];
+
+pub(super) const CHANGELOG: &[Shape] = &[
+    &[
+        ("command", Ty::Const("Show")),
+        ("topic_label", STRING),
+        ("content", STRING),
+    ],
+    &[
+        ("command", Ty::Const("ShowComponent")),
+        ("component_id", STRING),
+        ("changelog_path", STRING),
+        ("content", STRING),
+    ],
+    &[
+        ("command", Ty::Const("Lint")),
+        ("component_id", STRING),
+        ("changelog_path", STRING),
+        ("passed", BOOLEAN),
+        ("errors", INTEGER),
+        ("warnings", INTEGER),
+        ("issues", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("Convert")),
+        ("component_id", STRING),
+        ("from", STRING),
+        ("to", STRING),
+        ("source_path", STRING),
+        ("target_path", STRING),
+        ("releases", INTEGER),
+        ("entries", INTEGER),
+        ("dry_run", BOOLEAN),
+        ("config_updated", BOOLEAN),
+    ],
+];
+
+pub(super) const CHANGES: &[Shape] = &[
+    &[
+        ("component_id", STRING),
+        ("path", STRING),
+        ("success", BOOLEAN),
+        ("latest_tag", OPT_STRING),
+        ("commits", ARRAY),
+        ("uncommitted", OBJECT),
+    ],
+    &[("action", STRING), ("results", ARRAY), ("summary", OBJECT)],
+];
+
+pub(super) const CONFIG: &[Shape] = &[&[("command", STRING)]];
+
+pub(super) const DAEMON: &[Shape] = &[
+    &[
+        ("action", Ty::Const("start")),
+        ("pid", INTEGER),
+        ("address", STRING),
+        ("state_path", STRING),
+    ],
+    &[
+        ("action", Ty::Const("serve")),
+        ("pid", INTEGER),
+        ("address", STRING),
+        ("state_path", STRING),
+    ],
+    &[
+        ("action", Ty::Const("stop")),
+        ("stopped", BOOLEAN),
+        ("state_path", STRING),
+    ],
+    &[
+        ("action", Ty::Const("status")),
+        ("running", BOOLEAN),
+        ("state_path", STRING),
+    ],
+];
+
+pub(super) const DB: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("path", STRING),
+        ("exists", BOOLEAN),
+        ("schema_version", INTEGER),
+        ("migration_count", INTEGER),
+        ("table_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("domain", OPT_STRING),
+        ("cli_path", OPT_STRING),
+        ("stdout", OPT_STRING),
+        ("stderr", OPT_STRING),
+        ("exit_code", INTEGER),
+        ("success", BOOLEAN),
+        ("tables", OPT_ARRAY),
+        ("table", OPT_STRING),
+        ("sql", OPT_STRING),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("domain", OPT_STRING),
+        ("exit_code", INTEGER),
+        ("success", BOOLEAN),
+        ("tunnel", OBJECT),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("action", STRING),
+        ("migration_command", STRING),
+        ("dry_run", BOOLEAN),
+        ("exit_code", INTEGER),
+        ("success", BOOLEAN),
+    ],
+];
+
+pub(super) const DEPLOY: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("all", BOOLEAN),
+        ("outdated", BOOLEAN),
+        ("behind_upstream", BOOLEAN),
+        ("dry_run", BOOLEAN),
+        ("check", BOOLEAN),
+        ("force", BOOLEAN),
+        ("results", ARRAY),
+        ("summary", OBJECT),
+    ],
+    &[
+        ("command", STRING),
+        ("component_ids", ARRAY),
+        ("projects", ARRAY),
+        ("summary", OBJECT),
+        ("dry_run", BOOLEAN),
+        ("check", BOOLEAN),
+        ("force", BOOLEAN),
+    ],
+    &[("command", STRING), ("plans", ARRAY)],
+    &[("command", STRING), ("projects", ARRAY)],
+];
+
+pub(super) const DEPS: &[Shape] = &[
+    &[
+        ("component_id", STRING),
+        ("component_path", STRING),
+        ("package_manager", STRING),
+        ("packages", ARRAY),
+    ],
+    &[
+        ("component_id", STRING),
+        ("component_path", STRING),
+        ("package_manager", STRING),
+        ("package", STRING),
+        ("command", ARRAY),
+        ("stdout", STRING),
+        ("stderr", STRING),
+    ],
+    &[("edge_count", INTEGER), ("edges", ARRAY)],
+    &[
+        ("id", STRING),
+        ("kind", STRING),
+        ("subject", OBJECT),
+        ("upstream", STRING),
+        ("step_count", INTEGER),
+        ("steps", ARRAY),
+    ],
+    &[
+        ("upstream", STRING),
+        ("dry_run", BOOLEAN),
+        ("step_count", INTEGER),
+        ("steps", ARRAY),
+    ],
+];
+
+pub(super) const DOCS: &[Shape] = &[
+    &[
+        ("command", Ty::Const("docs.map")),
+        ("component", STRING),
+        ("modules", ARRAY),
+        ("class_hierarchy", ARRAY),
+        ("hook_summary", OBJECT),
+        ("total_files", INTEGER),
+        ("total_classes", INTEGER),
+    ],
+    &[
+        ("command", Ty::Const("docs.map.write")),
+        ("files_created", ARRAY),
+        ("files_updated", ARRAY),
+        ("hints", ARRAY),
+    ],
+];
+
+pub(super) const DOCTOR: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("recommendation", STRING),
+        ("load", OBJECT),
+        ("processes", OBJECT),
+        ("rig_leases", OBJECT),
+    ],
+    &[
+        ("command", STRING),
+        ("status", STRING),
+        ("healthy", BOOLEAN),
+        ("score", INTEGER),
+        ("checks", ARRAY),
+    ],
+    &[("command", STRING), ("checks", ARRAY)],
+];
+
+pub(super) const EXTENSION: &[Shape] = &[
+    &[
+        ("command", Ty::Const("extension.list")),
+        ("extensions", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("extension.show")),
+        ("extension", OBJECT),
+    ],
+    &[
+        ("command", Ty::Const("extension.describe")),
+        ("extension_id", STRING),
+        ("inputs", ARRAY),
+        ("schema", ANY),
+    ],
+    &[
+        ("command", Ty::Const("extension.run")),
+        ("extension_id", STRING),
+    ],
+    &[
+        ("command", Ty::Const("extension.setup")),
+        ("extension_id", STRING),
+    ],
+    &[
+        ("command", Ty::Const("extension.install")),
+        ("extension_id", STRING),
+        ("source", STRING),
+        ("path", STRING),
+        ("linked", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.install_plan")),
+        ("extension_id", STRING),
+        ("source", STRING),
+        ("linked", BOOLEAN),
+        ("path", STRING),
+        ("exists", BOOLEAN),
+        ("replace", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.replace")),
+        ("extension_id", STRING),
+        ("old_path", STRING),
+        ("new_path", STRING),
+        ("source", STRING),
+        ("linked", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.install_for_component")),
+        ("component_id", STRING),
+        ("source", STRING),
+        ("installed", ARRAY),
+        ("skipped", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("extension.update")),
+        ("extension_id", STRING),
+        ("url", STRING),
+        ("path", STRING),
+        ("linked", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.update_all")),
+        ("updated", ARRAY),
+        ("skipped", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("extension.update_check")),
+        ("checked", INTEGER),
+        ("outdated", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("extension.uninstall")),
+        ("extension_id", STRING),
+        ("path", STRING),
+        ("was_linked", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.uninstall_plan")),
+        ("extension_id", STRING),
+        ("path", STRING),
+        ("linked", BOOLEAN),
+        ("required_by", OBJECT),
+        ("blocked", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("extension.action")),
+        ("extension_id", STRING),
+        ("action_id", STRING),
+        ("response", ANY),
+    ],
+    &[
+        ("command", Ty::Const("extension.set")),
+        ("extension_id", STRING),
+        ("updated_fields", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("extension.exec")),
+        ("extension_id", STRING),
+    ],
+    &[
+        ("command", Ty::Const("extension.exec_raw")),
+        ("extension_id", STRING),
+        ("capability", STRING),
+        ("script_path", STRING),
+        ("exit_code", OPT_INTEGER),
+        ("success", BOOLEAN),
+        ("stdout", STRING),
+        ("stderr", STRING),
+    ],
+    &[("command", Ty::Const("extension.set")), ("batch", OBJECT)],
+];
+
+pub(super) const FILE: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("path", OPT_STRING),
+        ("old_path", OPT_STRING),
+        ("new_path", OPT_STRING),
+        ("recursive", OPT_BOOLEAN),
+        ("entries", OPT_ARRAY),
+        ("content", OPT_STRING),
+        ("size", OPT_INTEGER),
+        ("bytes_written", OPT_INTEGER),
+        ("stdout", OPT_STRING),
+        ("stderr", OPT_STRING),
+        ("exit_code", INTEGER),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("path", STRING),
+        ("pattern", OPT_STRING),
+        ("matches", ARRAY),
+        ("match_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("path", STRING),
+        ("pattern", STRING),
+        ("matches", ARRAY),
+        ("match_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("base_path", OPT_STRING),
+        ("path", STRING),
+        ("changes_made", ARRAY),
+        ("change_count", INTEGER),
+        ("success", BOOLEAN),
+        ("error", OPT_STRING),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("path", STRING),
+        ("outcome", STRING),
+        ("bytes_written", OPT_INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("remote_path", STRING),
+        ("local_path", STRING),
+        ("recursive", BOOLEAN),
+        ("success", BOOLEAN),
+        ("exit_code", INTEGER),
+        ("error", OPT_STRING),
+    ],
+    &[
+        ("source", STRING),
+        ("destination", STRING),
+        ("method", STRING),
+        ("direction", STRING),
+        ("recursive", BOOLEAN),
+        ("compress", BOOLEAN),
+        ("success", BOOLEAN),
+        ("dry_run", BOOLEAN),
+    ],
+];
+
+pub(super) const GIT: &[Shape] = &[
+    &[
+        ("component_id", STRING),
+        ("path", STRING),
+        ("action", STRING),
+        ("success", BOOLEAN),
+        ("exit_code", INTEGER),
+        ("stdout", STRING),
+        ("stderr", STRING),
+    ],
+    &[("action", STRING), ("results", ARRAY), ("summary", OBJECT)],
+    &[
+        ("component_id", STRING),
+        ("path", STRING),
+        ("action", STRING),
+        ("current_branch", OPT_STRING),
+    ],
+    &[
+        ("action", STRING),
+        ("components", ARRAY),
+        ("stale_count", INTEGER),
+    ],
+    &[
+        ("component_id", STRING),
+        ("owner", STRING),
+        ("repo", STRING),
+        ("action", STRING),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("component_id", STRING),
+        ("owner", STRING),
+        ("repo", STRING),
+        ("action", STRING),
+        ("success", BOOLEAN),
+        ("items", ARRAY),
+    ],
+    &[
+        ("mode", STRING),
+        ("allowed", BOOLEAN),
+        ("safe", BOOLEAN),
+        ("reason", STRING),
+        ("report", STRING),
+        ("changed_file_count", INTEGER),
+        ("files", ARRAY),
+    ],
+];
+
+pub(super) const HTTP: &[Shape] = &[&[
+    ("method", STRING),
+    ("url", STRING),
+    ("status", INTEGER),
+    ("headers", OBJECT),
+    ("body", ANY),
+]];
+
+pub(super) const ISSUES: &[Shape] = &[
+    &[
+        ("component_id", STRING),
+        ("command", STRING),
+        ("applied", BOOLEAN),
+        ("plan_summary", OBJECT),
+        ("plan_lines", ARRAY),
+    ],
+    &[("command", STRING), ("groups", OBJECT)],
+];
+
+pub(super) const LINT: &[Shape] = &[&[
+    ("passed", BOOLEAN),
+    ("status", STRING),
+    ("component", STRING),
+    ("exit_code", INTEGER),
+    ("phase", OBJECT),
+]];
+
+pub(super) const LOGS: &[Shape] = &[&[("command", STRING), ("project_id", STRING)]];
+
+pub(super) const OBSERVE: &[Shape] = &[&[
+    ("command", STRING),
+    ("run_id", STRING),
+    ("component_id", STRING),
+    ("status", STRING),
+    ("duration_ms", INTEGER),
+    ("event_count", INTEGER),
+    ("artifact_path", STRING),
+    ("hints", ARRAY),
+]];
+
+pub(super) const REFACTOR: &[Shape] = &[
+    &[
+        ("command", Ty::Const("refactor.sources")),
+        ("component_id", STRING),
+        ("source_path", STRING),
+        ("sources", ARRAY),
+        ("dry_run", BOOLEAN),
+        ("applied", BOOLEAN),
+        ("merge_strategy", STRING),
+        ("collected_edits", ARRAY),
+        ("stages", ARRAY),
+        ("source_totals", OBJECT),
+        ("overlaps", ARRAY),
+        ("files_modified", INTEGER),
+        ("changed_files", ARRAY),
+        ("warnings", ARRAY),
+        ("hints", ARRAY),
+    ],
+    &[
+        ("command", Ty::Const("refactor.rename")),
+        ("from", STRING),
+        ("to", STRING),
+        ("scope", STRING),
+        ("dry_run", BOOLEAN),
+        ("variants", ARRAY),
+        ("total_references", INTEGER),
+        ("total_files", INTEGER),
+        ("edits", ARRAY),
+        ("file_renames", ARRAY),
+        ("warnings", ARRAY),
+        ("applied", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.add.from_audit")),
+        ("source_path", STRING),
+        ("fixes", ARRAY),
+        ("total_insertions", INTEGER),
+        ("files_modified", INTEGER),
+        ("dry_run", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.add.import")),
+        ("import", STRING),
+        ("target", STRING),
+        ("fixes", ARRAY),
+        ("total_insertions", INTEGER),
+        ("files_modified", INTEGER),
+        ("dry_run", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.move")),
+        ("items_moved", ARRAY),
+        ("from_file", STRING),
+        ("to_file", STRING),
+        ("file_created", BOOLEAN),
+        ("imports_updated", INTEGER),
+        ("tests_moved", ARRAY),
+        ("applied", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.move_file")),
+        ("from_file", STRING),
+        ("to_file", STRING),
+        ("imports_updated", INTEGER),
+        ("applied", BOOLEAN),
+        ("mod_declarations_updated", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.propagate")),
+        ("struct_name", STRING),
+        ("definition_file", STRING),
+        ("fields", ARRAY),
+        ("files_scanned", INTEGER),
+        ("instantiations_found", INTEGER),
+        ("instantiations_needing_fix", INTEGER),
+        ("edits", ARRAY),
+        ("applied", BOOLEAN),
+        ("dry_run", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.transform")),
+        ("name", STRING),
+        ("rules", ARRAY),
+        ("total_replacements", INTEGER),
+        ("total_files", INTEGER),
+        ("written", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.decompose")),
+        ("plan", OBJECT),
+        ("move_results", ARRAY),
+        ("dry_run", BOOLEAN),
+        ("applied", BOOLEAN),
+    ],
+    &[
+        ("command", Ty::Const("refactor.bulk")),
+        ("action", STRING),
+        ("results", ARRAY),
+        ("summary", OBJECT),
+    ],
+];
+
+pub(super) const RELEASE: &[Shape] = &[
+    &[("command", Ty::Const("release")), ("result", OBJECT)],
+    &[("command", Ty::Const("release.batch")), ("result", OBJECT)],
+    &[
+        ("command", Ty::Const("release.artifacts")),
+        ("component_id", STRING),
+        ("pins", ARRAY),
+    ],
+];
+
+pub(super) const REPORT: &[Shape] = &[&[("command", STRING), ("markdown", STRING)]];
+
+pub(super) const REVIEW: &[Shape] = &[&[
+    ("command", STRING),
+    ("plan", OBJECT),
+    ("artifact", OBJECT),
+    ("summary", OBJECT),
+    ("audit", OBJECT),
+    ("lint", OBJECT),
+    ("test", OBJECT),
+]];
+
+pub(super) const RIG: &[Shape] = &[
+    &[("command", STRING), ("rigs", ARRAY)],
+    &[("command", STRING), ("rig", OBJECT)],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("pipeline", OBJECT),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("stopped", ARRAY),
+        ("pipeline", OPT_OBJECT),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("resources", ARRAY),
+        ("repaired", INTEGER),
+        ("unchanged", INTEGER),
+        ("blocked", INTEGER),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("stacks", ARRAY),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("description", STRING),
+        ("services", ARRAY),
+        ("symlinks", ARRAY),
+        ("last_up", OPT_STRING),
+        ("last_check", OPT_STRING),
+        ("last_check_result", OPT_STRING),
+        ("materialized", OPT_OBJECT),
+    ],
+    &[
+        ("command", STRING),
+        ("source", STRING),
+        ("package_path", STRING),
+        ("linked", BOOLEAN),
+        ("installed", ARRAY),
+        ("installed_stacks", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("updated", ARRAY),
+        ("updated_stacks", ARRAY),
+        ("skipped", ARRAY),
+    ],
+    &[("command", STRING), ("sources", ARRAY), ("invalid", ARRAY)],
+    &[
+        ("command", STRING),
+        ("selector", STRING),
+        ("source", OBJECT),
+        ("removed", ARRAY),
+        ("removed_stacks", ARRAY),
+        ("skipped", ARRAY),
+        ("skipped_stacks", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("rig_id", STRING),
+        ("action", STRING),
+        ("platform", STRING),
+        ("launcher_path", STRING),
+        ("target_app", STRING),
+        ("dry_run", BOOLEAN),
+        ("files", ARRAY),
+    ],
+];
+
+pub(super) const RUNS: &[Shape] = &[
+    &[("command", STRING), ("runs", ARRAY)],
+    &[("command", STRING), ("filters", OBJECT), ("fields", ARRAY)],
+    &[("command", STRING), ("run", OBJECT)],
+    &[
+        ("command", STRING),
+        ("kind", STRING),
+        ("component_id", OPT_STRING),
+        ("rig_id", OPT_STRING),
+        ("scenario_id", OPT_STRING),
+        ("metrics", ARRAY),
+        ("rows", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("run_id", STRING),
+        ("artifacts", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("run_id", STRING),
+        ("artifact_id", STRING),
+        ("output_path", STRING),
+        ("content_type", OPT_STRING),
+        ("size_bytes", OPT_INTEGER),
+        ("sha256", OPT_STRING),
+    ],
+    &[("command", STRING), ("run_id", STRING), ("findings", ARRAY)],
+    &[("command", STRING), ("finding", OBJECT)],
+    &[("command", STRING), ("run", OBJECT), ("finding", OBJECT)],
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("runs", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("from_run", OBJECT),
+        ("to_run", OBJECT),
+        ("comparisons", ARRAY),
+        ("missing", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("dry_run", BOOLEAN),
+        ("inspected", INTEGER),
+        ("reconciled", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("output", STRING),
+        ("manifest", OBJECT),
+        ("run_count", INTEGER),
+        ("artifact_count", INTEGER),
+        ("trace_span_count", INTEGER),
+        ("finding_count", INTEGER),
+        ("test_failure_count", INTEGER),
+    ],
+    &[("command", STRING), ("input", STRING), ("imported", OBJECT)],
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("repo", STRING),
+        ("workflow", STRING),
+        ("artifact_glob", STRING),
+        ("runs_inspected", INTEGER),
+        ("runs_imported", INTEGER),
+        ("runs_skipped_existing", INTEGER),
+        ("artifacts_imported", INTEGER),
+        ("artifacts_skipped_existing", INTEGER),
+        ("artifacts_skipped_non_json", INTEGER),
+        ("etag_cache_hit", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("filters", OBJECT),
+        ("select", ARRAY),
+        ("matched_artifact_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("filters", OBJECT),
+        ("metric", STRING),
+        ("threshold", NUMBER),
+        ("window_observations", INTEGER),
+        ("window_missing_rows", INTEGER),
+        ("values", ARRAY),
+    ],
+];
+
+pub(super) const SELF: &[Shape] = &[&[
+    ("command", STRING),
+    ("active_binary", STRING),
+    ("active_version", STRING),
+    ("install_method", STRING),
+    ("latest_github_release", OBJECT),
+    ("homebrew", OBJECT),
+    ("version_relation", STRING),
+]];
+
+pub(super) const SSH: &[Shape] = &[
+    &[
+        ("action", Ty::Const("Connect")),
+        ("resolved_type", STRING),
+        ("project_id", OPT_STRING),
+        ("server_id", STRING),
+        ("command", OPT_STRING),
+        ("success", BOOLEAN),
+    ],
+    &[("action", Ty::Const("List")), ("servers", ARRAY)],
+    &[
+        ("action", Ty::Const("CopyId")),
+        ("server_id", STRING),
+        ("key_path", STRING),
+        ("status", STRING),
+    ],
+];
+
+pub(super) const STACK: &[Shape] = &[
+    &[("command", STRING), ("stacks", ARRAY)],
+    &[("command", STRING), ("stack", OBJECT)],
+    &[
+        ("command", STRING),
+        ("stack_id", STRING),
+        ("component_path", STRING),
+        ("branch", STRING),
+        ("base", STRING),
+        ("target", STRING),
+        ("applied", ARRAY),
+        ("picked_count", INTEGER),
+        ("skipped_count", INTEGER),
+        ("conflict_count", INTEGER),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("stack_id", STRING),
+        ("component_path", STRING),
+        ("base", STRING),
+        ("target", STRING),
+        ("prs", ARRAY),
+        ("merged_count", INTEGER),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("id", STRING),
+        ("kind", STRING),
+        ("subject", OBJECT),
+        ("stack_id", STRING),
+        ("component_path", STRING),
+        ("branch", STRING),
+        ("base", STRING),
+        ("target", STRING),
+        ("dropped", ARRAY),
+        ("replayed", ARRAY),
+        ("uncertain", ARRAY),
+        ("target_exists", BOOLEAN),
+        ("dropped_count", INTEGER),
+        ("replayed_count", INTEGER),
+        ("uncertain_count", INTEGER),
+        ("would_mutate", BOOLEAN),
+        ("blocked", BOOLEAN),
+        ("success", BOOLEAN),
+        ("applied", ARRAY),
+        ("dry_run", BOOLEAN),
+        ("picked_count", INTEGER),
+        ("skipped_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("stack_id", STRING),
+        ("component_path", STRING),
+        ("remote", STRING),
+        ("branch", STRING),
+        ("after_ref", STRING),
+        ("status", STRING),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("id", STRING),
+        ("kind", STRING),
+        ("subject", OBJECT),
+        ("stack_id", STRING),
+        ("component_path", STRING),
+        ("branch", STRING),
+        ("base", STRING),
+        ("target", STRING),
+        ("dropped", ARRAY),
+        ("replayed", ARRAY),
+        ("uncertain", ARRAY),
+        ("target_exists", BOOLEAN),
+        ("dropped_count", INTEGER),
+        ("replayed_count", INTEGER),
+        ("uncertain_count", INTEGER),
+        ("would_mutate", BOOLEAN),
+        ("blocked", BOOLEAN),
+        ("success", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("path", STRING),
+        ("branch", STRING),
+        ("base", STRING),
+        ("base_auto_detected", BOOLEAN),
+        ("commits", ARRAY),
+        ("merged_count", INTEGER),
+        ("success", BOOLEAN),
+    ],
+];
+
+pub(super) const STATUS: &[Shape] = &[
+    &[("command", STRING), ("total", INTEGER), ("clean", INTEGER)],
+    &[
+        ("command", STRING),
+        ("status", OBJECT),
+        ("summary", OBJECT),
+        ("context", OBJECT),
+        ("next_steps", ARRAY),
+        ("components", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("project_id", STRING),
+        ("total", INTEGER),
+        ("components", ARRAY),
+        ("summary", OBJECT),
+    ],
+    &[
+        ("command", STRING),
+        ("from", OBJECT),
+        ("to", OBJECT),
+        ("files_changed", INTEGER),
+        ("components", ARRAY),
+    ],
+];
+
+pub(super) const TEST: &[Shape] = &[&[
+    ("passed", BOOLEAN),
+    ("status", STRING),
+    ("component", STRING),
+    ("exit_code", INTEGER),
+]];
+
+pub(super) const TRACE: &[Shape] = &[
+    &[
+        ("passed", BOOLEAN),
+        ("status", STRING),
+        ("component", STRING),
+        ("exit_code", INTEGER),
+    ],
+    &[
+        ("summary_only", BOOLEAN),
+        ("passed", BOOLEAN),
+        ("status", STRING),
+        ("component", STRING),
+        ("exit_code", INTEGER),
+        ("assertion_count", INTEGER),
+        ("artifact_count", INTEGER),
+        ("span_count", INTEGER),
+    ],
+    &[
+        ("command", STRING),
+        ("passed", BOOLEAN),
+        ("status", STRING),
+        ("component", STRING),
+        ("scenario_id", STRING),
+        ("repeat", INTEGER),
+        ("run_count", INTEGER),
+        ("failure_count", INTEGER),
+        ("exit_code", INTEGER),
+        ("runs", ARRAY),
+        ("spans", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("before_path", STRING),
+        ("after_path", STRING),
+        ("span_count", INTEGER),
+        ("spans", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("passed", BOOLEAN),
+        ("status", STRING),
+        ("component", STRING),
+        ("scenario_id", STRING),
+        ("matrix", STRING),
+        ("output_dir", STRING),
+        ("baseline_path", STRING),
+        ("summary_path", STRING),
+        ("run_count", INTEGER),
+        ("failure_count", INTEGER),
+        ("exit_code", INTEGER),
+        ("runs", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("component", STRING),
+        ("component_id", STRING),
+        ("count", INTEGER),
+        ("scenarios", ARRAY),
+    ],
+    &[
+        ("command", STRING),
+        ("count", INTEGER),
+        ("active_count", INTEGER),
+        ("stale_count", INTEGER),
+        ("unknown_count", INTEGER),
+        ("locks", ARRAY),
+    ],
+];
+
+pub(super) const TRIAGE: &[Shape] = &[&[
+    ("command", STRING),
+    ("target", OBJECT),
+    ("summary", OBJECT),
+    ("components", ARRAY),
+]];
+
+pub(super) const UNDO: &[Shape] = &[
+    &[
+        ("command", Ty::Const("undo.restore")),
+        ("snapshot_id", STRING),
+        ("label", STRING),
+        ("files_restored", INTEGER),
+        ("files_removed", INTEGER),
+        ("errors", ARRAY),
+    ],
+    &[("command", Ty::Const("undo.list")), ("snapshots", ARRAY)],
+    &[
+        ("command", Ty::Const("undo.delete")),
+        ("id", STRING),
+        ("deleted", BOOLEAN),
+    ],
+];
+
+pub(super) const UPGRADE: &[Shape] = &[
+    &[
+        ("command", STRING),
+        ("channel", STRING),
+        ("current_version", STRING),
+        ("latest_version", OPT_STRING),
+        ("update_available", BOOLEAN),
+        ("install_method", STRING),
+    ],
+    &[
+        ("command", STRING),
+        ("install_method", STRING),
+        ("channel", STRING),
+        ("previous_version", STRING),
+        ("new_version", OPT_STRING),
+        ("upgraded", BOOLEAN),
+        ("message", STRING),
+        ("restart_required", BOOLEAN),
+    ],
+];
+
+pub(super) const VERSION: &[Shape] = &[
+    &[("command", STRING), ("version", STRING), ("targets", ARRAY)],
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("source", STRING),
+        ("tag", OPT_STRING),
+        ("tag_version", OPT_STRING),
+        ("changelog", OPT_STRING),
+        ("in_sync", BOOLEAN),
+    ],
+    &[
+        ("command", STRING),
+        ("component_id", STRING),
+        ("version", STRING),
+        ("dry_run", BOOLEAN),
+        ("steps", ARRAY),
+    ],
+];
@@ -181,7 +181,9 @@ pub fn hot_command(command: &Commands) -> Option<HotCommand> {
         Commands::Fleet(args) if args.is_hot_resource_command() => Some(HotCommand {
             label: "fleet exec",
         }),
-        Commands::Audit(args) if args.changed_since.is_none() && !args.conventions => {
+        Commands::Audit(args)
+            if args.command.is_none() && args.changed_since.is_none() && !args.conventions =>
+        {
             Some(HotCommand { label: "audit" })
         }
         Commands::Lint(args) if args.is_full_workspace_run() => Some(HotCommand { label: "lint" }),
@@ -2,13 +2,35 @@
 //!
 //! Provides JSON envelope, printing, and exit code mapping.
 
+use std::sync::OnceLock;
+
 use homeboy::error::Hint;
 use homeboy::{Error, ErrorCode, Result};
 use serde::Serialize;
 
+use super::output_schema;
+
+/// Top-level command whose `schema_version` goes on the envelope.
+static SCHEMA_COMMAND: OnceLock<String> = OnceLock::new();
+
+/// Record the top-level command being run, so every envelope printed for it
+/// carries that command's `schema_version`.
+pub fn set_schema_command(command: &str) {
+    let _ = SCHEMA_COMMAND.set(command.to_string());
+}
+
+fn current_schema_version() -> Option<u32> {
+    SCHEMA_COMMAND
+        .get()
+        .and_then(|command| output_schema::schema_version(command))
+}
+
 #[derive(Debug, Serialize)]
 pub struct CliResponse<T: Serialize> {
     pub success: bool,
+    pub api_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub data: Option<T>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -27,14 +49,20 @@ pub struct CliError {
 }
 
 impl<T: Serialize> CliResponse<T> {
-    pub fn success(data: T) -> Self {
+    fn new(success: bool, data: Option<T>, error: Option<CliError>) -> Self {
         Self {
-            success: true,
-            data: Some(data),
-            error: None,
+            success,
+            api_version: output_schema::API_VERSION,
+            schema_version: current_schema_version(),
+            data,
+            error,
         }
     }
 
+    pub fn success(data: T) -> Self {
+        Self::new(true, Some(data), None)
+    }
+
     fn to_json(&self) -> Result<String> {
         serde_json::to_string_pretty(self).map_err(|e| {
             Error::internal_json(e.to_string(), Some("serialize response".to_string()))
@@ -44,10 +72,10 @@ impl<T: Serialize> CliResponse<T> {
 
 impl CliResponse<()> {
     pub fn from_error(err: &Error) -> Self {
-        Self {
-            success: false,
-            data: None,
-            error: Some(CliError {
+        Self::new(
+            false,
+            None,
+            Some(CliError {
                 code: err.code.as_str().to_string(),
                 message: err.message.clone(),
                 details: err.details.clone(),
@@ -58,7 +86,7 @@ impl CliResponse<()> {
                 },
                 retryable: err.retryable,
             }),
-        }
+        )
     }
 }
 
@@ -139,7 +167,8 @@ fn exit_code_for_error(code: ErrorCode) -> i32 {
         ErrorCode::RigPipelineFailed
         | ErrorCode::RigServiceFailed
         | ErrorCode::RigResourceConflict
-        | ErrorCode::StackApplyConflict => 20,
+        | ErrorCode::StackApplyConflict
+        | ErrorCode::ConfigLocked => 20,
 
         ErrorCode::SshServerInvalid
         | ErrorCode::SshIdentityFileNotFound
@@ -148,6 +177,7 @@ fn exit_code_for_error(code: ErrorCode) -> i32 {
 
         ErrorCode::RemoteCommandFailed
         | ErrorCode::RemoteCommandTimeout
+        | ErrorCode::ExtensionScriptTimeout
         | ErrorCode::DeployNoComponentsConfigured
         | ErrorCode::DeployBuildFailed
         | ErrorCode::DeployUploadFailed
@@ -159,17 +189,89 @@ fn exit_code_for_error(code: ErrorCode) -> i32 {
     }
 }
 
-pub fn print_json_result(result: Result<serde_json::Value>, exit_code: i32) -> Result<()> {
+/// Global flags that reshape a command's JSON result.
+#[derive(Debug, Default)]
+pub struct JsonOutputOptions {
+    /// `--output-template`: print the rendered template instead of the envelope.
+    pub template: Option<String>,
+    /// `--output-raw`: print a scalar result as bare text.
+    pub raw: bool,
+    /// `--output-env`: print an object result as `KEY=value` lines.
+    pub env: bool,
+    /// `--redact`: mask secrets in the result.
+    pub redact: bool,
+    /// `--select`: reduce the result to these fields.
+    pub select: Vec<String>,
+}
+
+impl JsonOutputOptions {
+    /// `(field, flag)` for every flag that is set.
+    fn active_flags(&self) -> Vec<(&'static str, &'static str)> {
+        [
+            (
+                "output_template",
+                "--output-template",
+                self.template.is_some(),
+            ),
+            ("output_raw", "--output-raw", self.raw),
+            ("output_env", "--output-env", self.env),
+            ("redact", "--redact", self.redact),
+            ("select", "--select", !self.select.is_empty()),
+        ]
+        .into_iter()
+        .filter(|(_, _, active)| *active)
+        .map(|(field, flag, _)| (field, flag))
+        .collect()
+    }
+
+    /// These flags only make sense when the command prints the JSON envelope.
+    pub fn require_json_output(&self, json_output: bool) -> Result<()> {
+        match self.active_flags().first() {
+            Some((field, flag)) if !json_output => Err(Error::validation_invalid_argument(
+                *field,
+                format!("{} only applies to commands with JSON output", flag),
+                None,
+                None,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Render a result for `--output-template`, `--output-raw`, or
+    /// `--output-env`, or `None` to print the envelope.
+    fn render(&self, data: &serde_json::Value) -> Option<Result<String>> {
+        if let Some(template) = &self.template {
+            Some(homeboy::engine::template::render_json(template, data))
+        } else if self.raw {
+            Some(homeboy::output::raw_scalar(data))
+        } else if self.env {
+            Some(homeboy::output::env_assignments(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// Print a command's JSON result: the envelope, or the text form chosen by
+/// `options`. A result that cannot be rendered in that form is returned as
+/// an error for the caller to print.
+pub fn print_json_result(
+    result: Result<serde_json::Value>,
+    exit_code: i32,
+    options: &JsonOutputOptions,
+) -> Result<()> {
+    if let Ok(data) = &result {
+        if let Some(rendered) = options.render(data) {
+            println!("{}", rendered?);
+            return Ok(());
+        }
+    }
     match result {
         Ok(data) if exit_code == 0 => print_success(data),
         Ok(data) => {
             // Command returned data but with a non-zero exit code (e.g., release
             // succeeded but deploy failed). The envelope should reflect the failure.
-            print_response(&CliResponse {
-                success: false,
-                data: Some(data),
-                error: None,
-            })
+            print_response(&CliResponse::new(false, Some(data), None))
         }
         Err(err) => print_response(&CliResponse::<()>::from_error(&err)),
     }
@@ -179,19 +281,8 @@ pub fn print_json_result(result: Result<serde_json::Value>, exit_code: i32) -> R
 /// logged to stderr but don't affect the command's exit code.
 pub fn write_json_to_file(result: &Result<serde_json::Value>, path: &str, exit_code: i32) {
     let response = match result {
-        Ok(data) => CliResponse {
-            success: exit_code == 0,
-            data: Some(data.clone()),
-            error: None,
-        },
-        Err(err) => {
-            let error_response = CliResponse::<()>::from_error(err);
-            CliResponse {
-                success: false,
-                data: None,
-                error: error_response.error,
-            }
-        }
+        Ok(data) => CliResponse::new(exit_code == 0, Some(data.clone()), None),
+        Err(err) => CliResponse::new(false, None, CliResponse::<()>::from_error(err).error),
     };
 
     let json = match serde_json::to_string_pretty(&response) {
@@ -212,6 +303,33 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn shaping_flags_require_json_output() {
+        let options = JsonOutputOptions {
+            raw: true,
+            select: vec!["version".to_string()],
+            ..Default::default()
+        };
+        assert!(options.require_json_output(true).is_ok());
+        let err = options.require_json_output(false).expect_err("not JSON");
+        assert!(err.message.contains("--output-raw"));
+
+        assert!(JsonOutputOptions::default()
+            .require_json_output(false)
+            .is_ok());
+    }
+
+    #[test]
+    fn print_json_result_reports_unrenderable_results() {
+        let options = JsonOutputOptions {
+            raw: true,
+            ..Default::default()
+        };
+        let err =
+            print_json_result(Ok(json!({ "a": 1, "b": 2 })), 0, &options).expect_err("object");
+        assert_eq!(err.code, ErrorCode::ValidationInvalidArgument);
+    }
+
     #[test]
     fn json_mapping_preserves_success_payload_and_exit_code() {
         let (payload, exit_code) = map_cmd_result_to_json(Ok((json!({ "changed": 2 }), 20)));
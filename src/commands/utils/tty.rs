@@ -12,6 +12,35 @@ pub fn is_stdout_tty() -> bool {
     io::stdout().is_terminal()
 }
 
+/// Width of the terminal on stdout: `COLUMNS` when set, else the terminal's
+/// own size. `None` when stdout is not a terminal.
+pub fn stdout_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|&columns| columns > 0)
+    {
+        return Some(columns);
+    }
+    if !is_stdout_tty() {
+        return None;
+    }
+    terminal_columns()
+}
+
+#[cfg(unix)]
+fn terminal_columns() -> Option<usize> {
+    // SAFETY: TIOCGWINSZ only writes into the zeroed winsize we pass.
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    (ok && size.ws_col > 0).then_some(size.ws_col as usize)
+}
+
+#[cfg(not(unix))]
+fn terminal_columns() -> Option<usize> {
+    None
+}
+
 pub fn require_tty_for_interactive() -> bool {
     is_stdin_tty() && is_stdout_tty()
 }
@@ -33,15 +62,104 @@ pub fn prompt(message: &str) -> homeboy::Result<String> {
     Ok(line.trim().to_string())
 }
 
+/// Print `header` and a numbered list of `choices` to stderr, then ask
+/// `question`. Accepts a number or a choice verbatim; anything else,
+/// including a blank answer, selects nothing.
+pub fn select(header: &str, choices: &[String], question: &str) -> homeboy::Result<Option<usize>> {
+    eprintln!("{}", header);
+    for (index, choice) in choices.iter().enumerate() {
+        eprintln!("  {}) {}", index + 1, choice);
+    }
+    let answer = prompt(question)?;
+    Ok(parse_selection(&answer, choices))
+}
+
+fn parse_selection(answer: &str, choices: &[String]) -> Option<usize> {
+    answer
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_sub(1))
+        .filter(|&index| index < choices.len())
+        .or_else(|| choices.iter().position(|choice| choice == answer))
+}
+
+/// Prompt without echoing the answer when stdin is a terminal.
 pub fn prompt_password(message: &str) -> homeboy::Result<String> {
+    let _echo = EchoOff::new();
     prompt(message)
 }
 
-/// Print status message to stderr if running in a terminal.
+/// Turns terminal echo off (keeping the newline) until dropped.
+#[cfg(unix)]
+struct EchoOff {
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl EchoOff {
+    fn new() -> Option<Self> {
+        if !is_stdin_tty() {
+            return None;
+        }
+        // SAFETY: tcgetattr/tcsetattr only read and write the termios struct
+        // we own, for the stdin file descriptor.
+        unsafe {
+            let mut term: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut term) != 0 {
+                return None;
+            }
+            let original = term;
+            term.c_lflag &= !libc::ECHO;
+            term.c_lflag |= libc::ECHONL;
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &term) != 0 {
+                return None;
+            }
+            Some(Self { original })
+        }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for EchoOff {
+    fn drop(&mut self) {
+        // SAFETY: restores the settings captured in `new`.
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+    }
+}
+
+#[cfg(not(unix))]
+struct EchoOff;
+
+#[cfg(not(unix))]
+impl EchoOff {
+    fn new() -> Option<Self> {
+        None
+    }
+}
+
+/// Print status message to stderr if running in a terminal (subject to
+/// `--quiet` / `--status-always`).
 pub fn status(message: &str) {
-    if io::stderr().is_terminal() {
+    if homeboy::status_log::enabled() {
         eprintln!("{}", message);
     }
 }
 
 // log_status! macro is defined in lib.rs (#[macro_export]) and available crate-wide.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selection() {
+        let choices = vec!["alpha".to_string(), "beta".to_string()];
+        assert_eq!(parse_selection("2", &choices), Some(1));
+        assert_eq!(parse_selection("alpha", &choices), Some(0));
+        assert_eq!(parse_selection("0", &choices), None);
+        assert_eq!(parse_selection("3", &choices), None);
+        assert_eq!(parse_selection("", &choices), None);
+    }
+}
@@ -2,7 +2,10 @@ use clap::{Args, Subcommand};
 use serde::Serialize;
 
 use homeboy::component;
-use homeboy::version::{read_component_version, read_version, VersionTargetInfo};
+use homeboy::version::{
+    list_version_sources, read_component_version, read_version, sync_version_sources,
+    VersionSources, VersionSyncOptions, VersionSyncResult, VersionTargetInfo,
+};
 
 use super::CmdResult;
 
@@ -10,6 +13,8 @@ use super::CmdResult;
 #[serde(untagged)]
 pub enum VersionOutput {
     Show(VersionShowOutput),
+    List(VersionListOutput),
+    Sync(VersionSyncOutput),
 }
 
 #[derive(Args)]
@@ -29,6 +34,39 @@ enum VersionCommand {
         #[arg(long)]
         path: Option<String>,
     },
+    /// Compare the version in source files, the latest tag, and the changelog
+    List {
+        /// Component ID (optional - uses the discovered component)
+        component_id: Option<String>,
+
+        /// Override local_path for version file lookup
+        #[arg(long)]
+        path: Option<String>,
+    },
+    /// Set source files, the changelog, and optionally the tag to one version
+    Sync {
+        /// Component ID
+        component_id: String,
+
+        /// Target version (e.g. 1.4.0)
+        version: String,
+
+        /// Override local_path for version file lookup
+        #[arg(long)]
+        path: Option<String>,
+
+        /// Also create the release tag once source files and changelog are committed
+        #[arg(long)]
+        tag: bool,
+
+        /// Allow a version older than one already recorded
+        #[arg(long)]
+        allow_downgrade: bool,
+
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 struct VersionShowArgs {
@@ -48,9 +86,114 @@ pub struct VersionShowOutput {
     targets: Vec<VersionTargetInfo>,
 }
 
+#[derive(Serialize)]
+pub struct VersionListOutput {
+    command: String,
+    component_id: String,
+    #[serde(flatten)]
+    sources: VersionSources,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    hints: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct VersionSyncOutput {
+    command: String,
+    component_id: String,
+    #[serde(flatten)]
+    result: VersionSyncResult,
+}
+
 pub fn run(args: VersionArgs, _global: &crate::commands::GlobalArgs) -> CmdResult<VersionOutput> {
-    let VersionCommand::Show { component_id, path } = args.command;
-    show(VersionShowArgs { component_id, path })
+    match args.command {
+        VersionCommand::Show { component_id, path } => show(VersionShowArgs { component_id, path }),
+        VersionCommand::List { component_id, path } => list(component_id, path),
+        VersionCommand::Sync {
+            component_id,
+            version,
+            path,
+            tag,
+            allow_downgrade,
+            dry_run,
+        } => sync(
+            &component_id,
+            &version,
+            path,
+            VersionSyncOptions {
+                dry_run,
+                allow_downgrade,
+                tag,
+            },
+        ),
+    }
+}
+
+fn list(component_id: Option<String>, path: Option<String>) -> CmdResult<VersionOutput> {
+    let comp = component::resolve_effective(component_id.as_deref(), path.as_deref(), None)?;
+    let sources = list_version_sources(&comp)?;
+    let hints = reconcile_hints(&comp.id, &sources);
+
+    Ok((
+        VersionOutput::List(VersionListOutput {
+            command: "version.list".to_string(),
+            component_id: comp.id,
+            sources,
+            hints,
+        }),
+        0,
+    ))
+}
+
+fn sync(
+    component_id: &str,
+    version: &str,
+    path: Option<String>,
+    options: VersionSyncOptions,
+) -> CmdResult<VersionOutput> {
+    let comp = component::resolve_effective(Some(component_id), path.as_deref(), None)?;
+    let result = sync_version_sources(&comp, version, &options)?;
+
+    Ok((
+        VersionOutput::Sync(VersionSyncOutput {
+            command: "version.sync".to_string(),
+            component_id: comp.id,
+            result,
+        }),
+        0,
+    ))
+}
+
+fn reconcile_hints(component_id: &str, sources: &VersionSources) -> Vec<String> {
+    if sources.in_sync {
+        return Vec::new();
+    }
+
+    let mut hints = Vec::new();
+    if let Some(tag) = sources
+        .tag_version
+        .as_ref()
+        .filter(|tag| **tag != sources.source)
+    {
+        hints.push(format!(
+            "Source files declare {} but the latest tag is {}",
+            sources.source, tag
+        ));
+    }
+    if let Some(changelog) = sources
+        .changelog
+        .as_ref()
+        .filter(|changelog| **changelog != sources.source)
+    {
+        hints.push(format!(
+            "Source files declare {} but the changelog's latest release is {}",
+            sources.source, changelog
+        ));
+    }
+    hints.push(format!(
+        "Align all three with: homeboy version sync {} <version> --dry-run",
+        component_id
+    ));
+    hints
 }
 
 fn show(args: VersionShowArgs) -> CmdResult<VersionOutput> {
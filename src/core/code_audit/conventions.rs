@@ -332,10 +332,17 @@ impl std::str::FromStr for AuditFinding {
 // Convention Discovery
 // ============================================================================
 
+/// How many of `total` items must share a pattern for it to count at
+/// `threshold`.
+pub(crate) fn min_file_count(total: usize, threshold: f32) -> usize {
+    (total as f32 * threshold).ceil() as usize
+}
+
 /// Discover conventions from a set of fingerprints that share a common grouping.
 ///
 /// The algorithm:
-/// 1. Find methods that appear in ≥ 60% of files (the "convention")
+/// 1. Find methods that appear in at least the convention threshold share of
+///    files (60% by default, see [`AuditConfig::convention_threshold`])
 /// 2. Find files that are missing any of those methods (the "outliers")
 pub fn discover_conventions_with_config(
     group_name: &str,
@@ -348,7 +355,7 @@ pub fn discover_conventions_with_config(
     }
 
     let total = fingerprints.len();
-    let threshold = (total as f32 * 0.6).ceil() as usize;
+    let threshold = min_file_count(total, audit_config.convention_threshold());
     let typed_count = fingerprints
         .iter()
         .filter(|fp| declares_type_subject(fp))
@@ -694,7 +701,11 @@ pub fn check_signature_consistency(
         }
 
         // Detect language from the glob pattern
-        let lang = if conv.glob.ends_with(".php") || conv.glob.ends_with("/*") {
+        let glob_lang = Language::from_path(Path::new(&conv.glob));
+        let lang = if conv.glob.ends_with(".php")
+            || conv.glob.ends_with("/*")
+            || matches!(glob_lang, Language::JavaScript | Language::TypeScript)
+        {
             // Check first conforming file extension
             conv.conforming
                 .first()
@@ -999,6 +1010,43 @@ mod tests {
         assert_eq!(Language::from_extension("txt"), Language::Unknown);
     }
 
+    #[test]
+    fn convention_threshold_changes_expected_methods() {
+        // `execute` is in every file, `validate` in 3 of 5.
+        let fingerprints: Vec<FileFingerprint> = (0..5)
+            .map(|i| {
+                let mut methods = vec!["execute".to_string()];
+                if i < 3 {
+                    methods.push("validate".to_string());
+                }
+                FileFingerprint {
+                    relative_path: format!("steps/step{}.php", i),
+                    language: Language::Php,
+                    methods,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let at = |threshold| {
+            let config = AuditConfig {
+                convention_threshold: Some(threshold),
+                ..Default::default()
+            };
+            let convention =
+                discover_conventions_with_config("Steps", "steps/*.php", &fingerprints, &config)
+                    .unwrap();
+            let mut expected = convention.expected_methods;
+            expected.sort();
+            (expected, convention.outliers.len())
+        };
+
+        assert_eq!(
+            at(0.6),
+            (vec!["execute".to_string(), "validate".to_string()], 2)
+        );
+        assert_eq!(at(0.8), (vec!["execute".to_string()], 0));
+    }
+
     #[test]
     fn utility_like_outlier_is_not_promoted_to_naming_mismatch() {
         let fingerprints = vec![
@@ -1699,6 +1747,57 @@ mod tests {
         assert!(conventions[0].outliers.is_empty());
     }
 
+    #[test]
+    fn signature_check_flags_js_arity_and_async_mismatches() {
+        let _audit_guard = crate::test_support::AuditGuard::new();
+        let tmp = tempfile::TempDir::new().unwrap();
+        let dir = tmp.path().to_path_buf();
+        std::fs::create_dir_all(dir.join("handlers")).unwrap();
+
+        let conforming = "export class Handler {\n    async handle(req, res) {\n        return res.send(req.body);\n    }\n}\n";
+        std::fs::write(dir.join("handlers/a.js"), conforming).unwrap();
+        std::fs::write(
+            dir.join("handlers/b.js"),
+            "export class Other {\n    handle = async (request, response) => {\n        response.end();\n    };\n}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("handlers/c.js"), conforming).unwrap();
+        std::fs::write(
+            dir.join("handlers/d.js"),
+            "export class Legacy {\n    handle(req) {\n        return req;\n    }\n}\n",
+        )
+        .unwrap();
+
+        let files: Vec<String> = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| format!("handlers/{}.js", name))
+            .collect();
+        let mut conventions = vec![Convention {
+            name: "Handlers".to_string(),
+            glob: "handlers/*.js".to_string(),
+            expected_methods: vec!["handle".to_string()],
+            expected_registrations: vec![],
+            expected_interfaces: vec![],
+            expected_namespace: None,
+            expected_imports: vec![],
+            conforming: files.clone(),
+            outliers: vec![],
+            total_files: 4,
+            confidence: 1.0,
+        }];
+
+        check_signature_consistency(&mut conventions, &dir, &AuditConfig::default());
+
+        let conv = &conventions[0];
+        assert_eq!(conv.outliers.len(), 1);
+        assert_eq!(conv.outliers[0].file, "handlers/d.js");
+        assert_eq!(
+            conv.outliers[0].deviations[0].kind,
+            AuditFinding::SignatureMismatch
+        );
+        assert_eq!(conv.conforming.len(), 3);
+    }
+
     #[test]
     fn signature_check_skips_unknown_language() {
         let tmp = tempfile::TempDir::new().unwrap();
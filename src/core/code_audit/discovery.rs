@@ -3,8 +3,11 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+use regex::Regex;
+
 use super::conventions::Language;
-use super::fingerprint::{fingerprint_content, normalize_convention_tags, FileFingerprint};
+use super::fingerprint::{normalize_convention_tags, FileFingerprint};
+use super::fingerprint_cache::FingerprintCache;
 use super::walker::{is_test_path, walk_source_files_snapshot};
 use crate::component::AuditConfig;
 
@@ -18,6 +21,14 @@ pub struct DiscoveryResult {
     pub files_walked: usize,
     /// Files that were successfully fingerprinted by an extension.
     pub files_fingerprinted: usize,
+    /// Configuration problems found while discovering (e.g., invalid regexes).
+    pub warnings: Vec<String>,
+}
+
+/// A component/extension registration regex compiled once per audit run.
+struct CompiledRegistrationPattern {
+    regex: Regex,
+    file_extensions: Vec<String>,
 }
 
 /// Auto-discover file groups by scanning directories for clusters of similar files.
@@ -26,10 +37,14 @@ pub struct DiscoveryResult {
 /// contain 2+ files of the same language, plus counts of walked vs fingerprinted files.
 ///
 /// Walks `root` once via [`walk_source_files_snapshot`] and reads each file
-/// exactly once. Fingerprinting goes through [`fingerprint_content`] so the
-/// snapshot's already-loaded content is reused — no second `read_to_string`.
-/// Slice 2 of #1492.
-pub(crate) fn auto_discover_groups(root: &Path, audit_config: &AuditConfig) -> DiscoveryResult {
+/// exactly once. Fingerprinting goes through `cache` so the snapshot's
+/// already-loaded content is reused — no second `read_to_string` — and
+/// unchanged files skip the extension entirely. Slice 2 of #1492.
+pub(crate) fn auto_discover_groups(
+    root: &Path,
+    audit_config: &AuditConfig,
+    cache: &mut FingerprintCache,
+) -> DiscoveryResult {
     // Walk directories, group files by (parent dir, language, is_test, opaque convention tags).
     // Test files are separated from production files so conventions from
     // production code don't get applied to test files and vice versa.
@@ -38,11 +53,12 @@ pub(crate) fn auto_discover_groups(root: &Path, audit_config: &AuditConfig) -> D
     let mut dir_files: HashMap<DiscoveryGroupKey, Vec<FileFingerprint>> = HashMap::new();
     let mut files_walked: usize = 0;
     let mut files_fingerprinted: usize = 0;
+    let (registration_patterns, warnings) = compile_registration_patterns(audit_config);
 
     let snapshot = walk_source_files_snapshot(root);
     for (path, content) in snapshot.iter() {
         files_walked += 1;
-        if let Some(mut fp) = fingerprint_content(path, root, content) {
+        if let Some(mut fp) = cache.fingerprint(path, root, content) {
             files_fingerprinted += 1;
             let parent = path
                 .parent()
@@ -51,6 +67,7 @@ pub(crate) fn auto_discover_groups(root: &Path, audit_config: &AuditConfig) -> D
                 .unwrap_or_default();
             let file_is_test = is_test_path(&fp.relative_path);
             fp.convention_tags = convention_tags_for(&fp, audit_config);
+            add_pattern_registrations(&mut fp, &registration_patterns);
             let key = (
                 parent,
                 fp.language.clone(),
@@ -65,6 +82,61 @@ pub(crate) fn auto_discover_groups(root: &Path, audit_config: &AuditConfig) -> D
         groups: groups_from_dir_files(dir_files),
         files_walked,
         files_fingerprinted,
+        warnings,
+    }
+}
+
+/// Compile configured registration regexes, reporting invalid ones as warnings.
+fn compile_registration_patterns(
+    audit_config: &AuditConfig,
+) -> (Vec<CompiledRegistrationPattern>, Vec<String>) {
+    let mut compiled = Vec::new();
+    let mut warnings = Vec::new();
+    for rule in &audit_config.registration_patterns {
+        match Regex::new(&rule.pattern) {
+            Ok(regex) => compiled.push(CompiledRegistrationPattern {
+                regex,
+                file_extensions: rule
+                    .file_extensions
+                    .iter()
+                    .map(|ext| ext.trim_start_matches('.').to_string())
+                    .collect(),
+            }),
+            Err(error) => {
+                let warning = format!(
+                    "Ignoring invalid audit registration pattern `{}`: {}",
+                    rule.pattern, error
+                );
+                log_status!("audit", "WARNING: {}", warning);
+                warnings.push(warning);
+            }
+        }
+    }
+    (compiled, warnings)
+}
+
+/// Merge registrations matched by configured patterns into the fingerprint,
+/// so they feed convention discovery exactly like built-in registrations.
+fn add_pattern_registrations(fp: &mut FileFingerprint, patterns: &[CompiledRegistrationPattern]) {
+    let extension = Path::new(&fp.relative_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    for pattern in patterns {
+        if !pattern.file_extensions.is_empty()
+            && !pattern.file_extensions.iter().any(|ext| ext == extension)
+        {
+            continue;
+        }
+        for caps in pattern.regex.captures_iter(&fp.content) {
+            let Some(name) = caps.name("name").or_else(|| caps.get(1)) else {
+                continue;
+            };
+            let name = name.as_str().to_string();
+            if !name.is_empty() && !fp.registrations.contains(&name) {
+                fp.registrations.push(name);
+            }
+        }
     }
 }
 
@@ -143,9 +215,11 @@ fn groups_from_dir_files(
 ///
 /// Example: if `inc/Abilities/Flow/` and `inc/Abilities/Job/` both expect
 /// `execute`, `registerAbility`, `__construct` — that's a cross-directory
-/// convention for `inc/Abilities/`.
+/// convention for `inc/Abilities/`. A pattern counts when at least `threshold`
+/// of the sibling directories expect it.
 pub(crate) fn discover_cross_directory(
     conventions: &[super::ConventionReport],
+    threshold: f32,
 ) -> Vec<super::DirectoryConvention> {
     // Group conventions by their parent directory (one level up from glob)
     let mut parent_groups: HashMap<String, Vec<&super::ConventionReport>> = HashMap::new();
@@ -167,7 +241,7 @@ pub(crate) fn discover_cross_directory(
         }
 
         let total = child_convs.len();
-        let threshold = (total as f32 * 0.6).ceil() as usize;
+        let min_dirs = super::conventions::min_file_count(total, threshold);
 
         // Count method frequency across sibling conventions
         let mut method_counts: HashMap<&str, usize> = HashMap::new();
@@ -179,7 +253,7 @@ pub(crate) fn discover_cross_directory(
 
         let expected_methods: Vec<String> = method_counts
             .iter()
-            .filter(|(_, count)| **count >= threshold)
+            .filter(|(_, count)| **count >= min_dirs)
             .map(|(name, _)| name.to_string())
             .collect();
 
@@ -193,7 +267,7 @@ pub(crate) fn discover_cross_directory(
 
         let expected_registrations: Vec<String> = reg_counts
             .iter()
-            .filter(|(_, count)| **count >= threshold)
+            .filter(|(_, count)| **count >= min_dirs)
             .map(|(name, _)| name.to_string())
             .collect();
 
@@ -252,6 +326,7 @@ pub(crate) fn discover_cross_directory(
 mod tests {
     use super::super::test_helpers::make_convention;
     use super::*;
+    use crate::component::DEFAULT_CONVENTION_THRESHOLD;
 
     fn tagged_fingerprint(path: &str, tags: &[&str]) -> FileFingerprint {
         FileFingerprint {
@@ -331,6 +406,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn registration_patterns_merge_into_fingerprint_registrations() {
+        let mut fp = FileFingerprint {
+            relative_path: "routes/web.route".to_string(),
+            registrations: vec!["builtin".to_string()],
+            content: "route('/a', home)\nroute('/b', about)\nroute('/a', home)\n".to_string(),
+            ..Default::default()
+        };
+        let audit_config = AuditConfig {
+            registration_patterns: vec![
+                crate::component::RegistrationPattern {
+                    pattern: r"route\('[^']+', (?P<name>\w+)\)".to_string(),
+                    file_extensions: vec![".route".to_string()],
+                },
+                crate::component::RegistrationPattern {
+                    pattern: r"route\((\w+)".to_string(),
+                    file_extensions: vec!["other".to_string()],
+                },
+            ],
+            ..Default::default()
+        };
+
+        let (patterns, warnings) = compile_registration_patterns(&audit_config);
+        add_pattern_registrations(&mut fp, &patterns);
+
+        assert!(warnings.is_empty());
+        assert_eq!(fp.registrations, vec!["builtin", "home", "about"]);
+    }
+
+    #[test]
+    fn invalid_registration_patterns_are_reported_and_skipped() {
+        let audit_config = AuditConfig {
+            registration_patterns: vec![
+                crate::component::RegistrationPattern {
+                    pattern: "register(".to_string(),
+                    file_extensions: Vec::new(),
+                },
+                crate::component::RegistrationPattern {
+                    pattern: r"register\((\w+)\)".to_string(),
+                    file_extensions: Vec::new(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let (patterns, warnings) = compile_registration_patterns(&audit_config);
+
+        assert_eq!(patterns.len(), 1);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("`register(`"));
+    }
+
     #[test]
     fn cross_directory_detects_shared_methods() {
         let conventions = vec![
@@ -354,7 +481,7 @@ mod tests {
             ),
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
 
         assert_eq!(results.len(), 1);
         let result = &results[0];
@@ -370,6 +497,27 @@ mod tests {
         assert!((result.confidence - 1.0).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn cross_directory_threshold_controls_shared_methods() {
+        let conventions = vec![
+            make_convention(
+                "Flow",
+                "inc/Abilities/Flow/*",
+                &["execute", "register"],
+                &[],
+            ),
+            make_convention("Job", "inc/Abilities/Job/*", &["execute", "register"], &[]),
+            make_convention("Data", "inc/Abilities/Data/*", &["execute"], &[]),
+        ];
+
+        let default = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
+        assert_eq!(default[0].outlier_dirs.len(), 1);
+
+        let strict = discover_cross_directory(&conventions, 1.0);
+        assert_eq!(strict[0].expected_methods, ["execute"]);
+        assert!(strict[0].outlier_dirs.is_empty());
+    }
+
     #[test]
     fn cross_directory_detects_outlier_missing_method() {
         let conventions = vec![
@@ -393,7 +541,7 @@ mod tests {
             ), // missing registerAbility
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
 
         assert_eq!(results.len(), 1);
         let result = &results[0];
@@ -415,7 +563,7 @@ mod tests {
             &[],
         )];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
         assert!(results.is_empty());
     }
 
@@ -437,7 +585,7 @@ mod tests {
             ),
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
         // No method appears in ≥60% of siblings (each appears in 1 of 2 = 50%)
         assert!(results.is_empty());
     }
@@ -452,7 +600,7 @@ mod tests {
             make_convention("D", "app/Services/D/*", &["process"], &[]), // outlier
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
 
         assert_eq!(results.len(), 1);
         let result = &results[0];
@@ -480,7 +628,7 @@ mod tests {
             ),
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
 
         assert_eq!(results.len(), 1);
         assert!(results[0]
@@ -502,7 +650,7 @@ mod tests {
             make_convention("Cache", "inc/Middleware/Cache/*", &["handle", "boot"], &[]),
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
 
         assert_eq!(results.len(), 2);
         let parents: Vec<&str> = results.iter().map(|r| r.parent.as_str()).collect();
@@ -518,7 +666,7 @@ mod tests {
             make_convention("Jobs", "jobs/*", &["execute"], &[]),
         ];
 
-        let results = discover_cross_directory(&conventions);
+        let results = discover_cross_directory(&conventions, DEFAULT_CONVENTION_THRESHOLD);
         assert!(results.is_empty()); // These aren't siblings under a common parent
     }
 }
@@ -35,6 +35,37 @@ pub enum ClaimConfidence {
     Unclear,
 }
 
+impl ClaimConfidence {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Example => 0,
+            Self::Unclear => 1,
+            Self::Real => 2,
+        }
+    }
+
+    /// Whether this confidence is at or above `threshold`.
+    pub fn meets(&self, threshold: &ClaimConfidence) -> bool {
+        self.rank() >= threshold.rank()
+    }
+}
+
+impl std::str::FromStr for ClaimConfidence {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "real" => Ok(Self::Real),
+            "unclear" => Ok(Self::Unclear),
+            "example" => Ok(Self::Example),
+            _ => Err(format!(
+                "unknown confidence '{}'. Valid levels: real, unclear, example",
+                value
+            )),
+        }
+    }
+}
+
 /// A claim extracted from documentation.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct Claim {
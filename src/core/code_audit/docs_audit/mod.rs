@@ -107,29 +107,91 @@ pub struct AuditResult {
     pub detected_features: Vec<DetectedFeature>,
 }
 
-/// Find all markdown files in the docs directory.
-///
-/// Excludes configured doc targets using file-name matching (case-insensitive).
+/// One NDJSON record of a docs audit, tagged by `record`.
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "record", rename_all = "snake_case")]
+enum DocsAuditRecord<'a> {
+    PriorityDoc {
+        component_id: &'a str,
+        #[serde(flatten)]
+        item: &'a PriorityDoc,
+    },
+    BrokenReference {
+        component_id: &'a str,
+        #[serde(flatten)]
+        item: &'a BrokenReference,
+    },
+    UndocumentedFeature {
+        component_id: &'a str,
+        #[serde(flatten)]
+        item: &'a UndocumentedFeature,
+    },
+}
+
+impl AuditResult {
+    /// Drop broken references below `threshold` and recount the summary.
+    pub fn retain_min_confidence(&mut self, threshold: &ClaimConfidence) {
+        self.broken_references
+            .retain(|item| item.confidence.meets(threshold));
+        self.summary.broken_references = self.broken_references.len();
+    }
+
+    /// Render each priority doc, broken reference, and undocumented feature
+    /// as its own NDJSON line.
+    pub fn to_json_lines(&self) -> crate::Result<String> {
+        let component_id = self.component_id.as_str();
+        let priority = self
+            .priority_docs
+            .iter()
+            .map(|item| DocsAuditRecord::PriorityDoc { component_id, item });
+        let broken = self
+            .broken_references
+            .iter()
+            .map(|item| DocsAuditRecord::BrokenReference { component_id, item });
+        let undocumented = self
+            .undocumented_features
+            .iter()
+            .map(|item| DocsAuditRecord::UndocumentedFeature { component_id, item });
+
+        super::json_lines::to_json_lines(priority.chain(broken).chain(undocumented))
+    }
+}
+
 /// Filenames excluded from docs audit by default.
 /// CHANGELOG files are historically referential by design — they reference
 /// old functions, modules, and paths that no longer exist. Flagging them
 /// as stale/broken doc references is noise, not signal.
 const DEFAULT_DOC_EXCLUDES: &[&str] = &["changelog.md"];
 
-pub(crate) fn find_doc_files(docs_path: &Path, excluded_targets: &[String]) -> Vec<String> {
+/// Find all markdown files in the docs directory.
+///
+/// `excluded` and `included` entries are either plain paths, matched by file
+/// name (case-insensitive), or globs (`*`, `?`, `[`) matched against the path
+/// relative to the docs directory or prefixed with it (`docs/api/*.md`).
+/// A non-empty `included` list restricts the scan; exclusion always wins.
+pub(crate) fn find_doc_files(
+    docs_path: &Path,
+    excluded: &[String],
+    included: &[String],
+) -> Vec<String> {
     use crate::engine::codebase_scan::{self, ExtensionFilter, ScanConfig};
 
     if !docs_path.exists() {
         return Vec::new();
     }
 
-    let mut excluded_filenames: std::collections::HashSet<String> = excluded_targets
-        .iter()
-        .filter_map(|p| Path::new(p).file_name())
-        .filter_map(|n| n.to_str())
-        .map(|s| s.to_lowercase())
-        .collect();
-    excluded_filenames.extend(DEFAULT_DOC_EXCLUDES.iter().map(|s| s.to_string()));
+    let mut excluded: Vec<DocPattern> =
+        excluded.iter().filter_map(|p| DocPattern::new(p)).collect();
+    excluded.extend(
+        DEFAULT_DOC_EXCLUDES
+            .iter()
+            .filter_map(|p| DocPattern::new(p)),
+    );
+    let included: Vec<DocPattern> = included.iter().filter_map(|p| DocPattern::new(p)).collect();
+    let docs_dir_name = docs_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
 
     let config = ScanConfig {
         extensions: ExtensionFilter::Only(vec!["md".to_string()]),
@@ -141,12 +203,20 @@ pub(crate) fn find_doc_files(docs_path: &Path, excluded_targets: &[String]) -> V
     let mut docs: Vec<String> = files
         .into_iter()
         .filter_map(|path| {
-            let name = path.file_name()?.to_str()?;
-            if excluded_filenames.contains(&name.to_lowercase()) {
+            let rel = path
+                .strip_prefix(docs_path)
+                .ok()?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let prefixed = format!("{}/{}", docs_dir_name, rel);
+            let matches = |p: &DocPattern| p.matches(&rel, &prefixed);
+            if excluded.iter().any(matches) {
+                return None;
+            }
+            if !included.is_empty() && !included.iter().any(matches) {
                 return None;
             }
-            let rel = path.strip_prefix(docs_path).ok()?;
-            Some(rel.to_string_lossy().to_string())
+            Some(rel)
         })
         .collect();
 
@@ -154,6 +224,69 @@ pub(crate) fn find_doc_files(docs_path: &Path, excluded_targets: &[String]) -> V
     docs
 }
 
+/// One `find_doc_files` include/exclude entry.
+enum DocPattern {
+    FileName(String),
+    Glob(glob::Pattern),
+}
+
+impl DocPattern {
+    /// Invalid globs are dropped; callers validate user input up front.
+    fn new(raw: &str) -> Option<Self> {
+        if is_glob(raw) {
+            return glob::Pattern::new(raw).ok().map(Self::Glob);
+        }
+        let name = Path::new(raw).file_name()?.to_str()?;
+        Some(Self::FileName(name.to_lowercase()))
+    }
+
+    fn matches(&self, rel: &str, prefixed: &str) -> bool {
+        match self {
+            Self::FileName(name) => rel
+                .rsplit('/')
+                .next()
+                .is_some_and(|file| file.to_lowercase() == *name),
+            Self::Glob(pattern) => pattern.matches(rel) || pattern.matches(prefixed),
+        }
+    }
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?', '['])
+}
+
+/// Check that every doc include/exclude glob parses.
+pub(crate) fn validate_doc_patterns(field: &str, patterns: &[String]) -> crate::Result<()> {
+    for pattern in patterns.iter().filter(|p| is_glob(p)) {
+        glob::Pattern::new(pattern).map_err(|e| {
+            crate::Error::validation_invalid_argument(
+                field,
+                format!("Invalid doc glob '{}': {}", pattern, e),
+                None,
+                None,
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Lines of context shown on each side of a broken reference by default.
+pub(crate) const DEFAULT_DOC_CONTEXT_LINES: usize = 1;
+
+/// Lines around 1-based `line` in `content`, `context_lines` on each side and
+/// clamped to the file bounds, each prefixed with its line number.
+pub(crate) fn extract_doc_context(content: &str, line: usize, context_lines: usize) -> Vec<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    if line == 0 || line > lines.len() {
+        return Vec::new();
+    }
+    let start = line.saturating_sub(context_lines).max(1);
+    let end = (line + context_lines).min(lines.len());
+    (start..=end)
+        .map(|n| format!("{}: {}", n, lines[n - 1]))
+        .collect()
+}
+
 /// Collect audit ignore patterns from all linked extensions.
 pub(crate) fn collect_extension_ignore_patterns(comp: &component::Component) -> Vec<String> {
     let mut patterns = Vec::new();
@@ -173,6 +306,116 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_to_json_lines() {
+        let result = AuditResult {
+            component_id: "widget".to_string(),
+            baseline_ref: None,
+            summary: AlignmentSummary {
+                docs_scanned: 1,
+                priority_docs: 0,
+                broken_references: 1,
+                unchanged_docs: 0,
+                total_features: 1,
+                documented_features: 0,
+                undocumented_features: 1,
+            },
+            changed_files: Vec::new(),
+            priority_docs: Vec::new(),
+            broken_references: vec![BrokenReference {
+                doc: "docs/guide.md".to_string(),
+                line: 12,
+                claim: "src/missing.rs".to_string(),
+                confidence: ClaimConfidence::Real,
+                doc_context: None,
+                action: "Update the path".to_string(),
+            }],
+            undocumented_features: vec![UndocumentedFeature {
+                name: "widget_hook".to_string(),
+                source_file: "src/hooks.rs".to_string(),
+                line: 4,
+                pattern: "hook".to_string(),
+            }],
+            detected_features: Vec::new(),
+        };
+
+        let out = result.to_json_lines().expect("ndjson");
+        let lines: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("valid json line"))
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["record"], "broken_reference");
+        assert_eq!(lines[0]["component_id"], "widget");
+        assert_eq!(lines[0]["line"], 12);
+        assert_eq!(lines[1]["record"], "undocumented_feature");
+        assert_eq!(lines[1]["source_file"], "src/hooks.rs");
+    }
+
+    #[test]
+    fn test_retain_min_confidence_recounts_summary() {
+        let broken = |claim: &str, confidence| BrokenReference {
+            doc: "docs/guide.md".to_string(),
+            line: 1,
+            claim: claim.to_string(),
+            confidence,
+            doc_context: None,
+            action: "Update the path".to_string(),
+        };
+        let mut result = AuditResult {
+            component_id: "widget".to_string(),
+            baseline_ref: None,
+            summary: AlignmentSummary {
+                docs_scanned: 1,
+                priority_docs: 0,
+                broken_references: 2,
+                unchanged_docs: 0,
+                total_features: 0,
+                documented_features: 0,
+                undocumented_features: 0,
+            },
+            changed_files: Vec::new(),
+            priority_docs: Vec::new(),
+            broken_references: vec![
+                broken("src/missing.rs", ClaimConfidence::Real),
+                broken("maybe/thing.rs", ClaimConfidence::Unclear),
+            ],
+            undocumented_features: Vec::new(),
+            detected_features: Vec::new(),
+        };
+
+        result.retain_min_confidence(&ClaimConfidence::Unclear);
+        assert_eq!(result.summary.broken_references, 2);
+
+        result.retain_min_confidence(&ClaimConfidence::Real);
+        assert_eq!(result.summary.broken_references, 1);
+        assert_eq!(result.broken_references[0].claim, "src/missing.rs");
+    }
+
+    #[test]
+    fn test_extract_doc_context_default_window() {
+        let content = "one\ntwo\nthree\nfour\n";
+        assert_eq!(
+            extract_doc_context(content, 2, DEFAULT_DOC_CONTEXT_LINES),
+            vec!["1: one", "2: two", "3: three"]
+        );
+    }
+
+    #[test]
+    fn test_extract_doc_context_clamps_wide_window_to_file_bounds() {
+        let content = "one\ntwo\nthree\nfour\nfive\n";
+        assert_eq!(
+            extract_doc_context(content, 1, 3),
+            vec!["1: one", "2: two", "3: three", "4: four"]
+        );
+        assert_eq!(
+            extract_doc_context(content, 5, 3),
+            vec!["2: two", "3: three", "4: four", "5: five"]
+        );
+        assert!(extract_doc_context(content, 9, 3).is_empty());
+    }
+
     #[test]
     fn test_find_doc_files_excludes_configured_targets() {
         let dir = tempfile::tempdir().unwrap();
@@ -186,7 +429,7 @@ mod tests {
         .unwrap();
         fs::write(docs_path.join("api.md"), "# API\n").unwrap();
 
-        let files = find_doc_files(docs_path, &["CHANGELOG.md".to_string()]);
+        let files = find_doc_files(docs_path, &["CHANGELOG.md".to_string()], &[]);
         assert_eq!(files.len(), 2);
         assert!(files.contains(&"api.md".to_string()));
         assert!(files.contains(&"guide.md".to_string()));
@@ -201,7 +444,7 @@ mod tests {
         fs::write(docs_path.join("guide.md"), "# Guide\n").unwrap();
         fs::write(docs_path.join("changelog.md"), "# Changes\n").unwrap();
 
-        let files = find_doc_files(docs_path, &["CHANGELOG.md".to_string()]);
+        let files = find_doc_files(docs_path, &["CHANGELOG.md".to_string()], &[]);
         assert_eq!(files.len(), 1);
         assert_eq!(files[0], "guide.md");
     }
@@ -215,7 +458,7 @@ mod tests {
         fs::write(docs_path.join("CHANGELOG.md"), "# Changelog\n").unwrap();
 
         // CHANGELOG is excluded by default (historically referential by design)
-        let files = find_doc_files(docs_path, &[]);
+        let files = find_doc_files(docs_path, &[], &[]);
         assert_eq!(files.len(), 1);
         assert_eq!(files[0], "guide.md");
     }
@@ -230,8 +473,33 @@ mod tests {
         fs::write(docs_path.join("CHANGES.md"), "# Changes\n").unwrap();
 
         // CHANGES.md excluded by caller, CHANGELOG.md excluded by default
-        let files = find_doc_files(docs_path, &["CHANGES.md".to_string()]);
+        let files = find_doc_files(docs_path, &["CHANGES.md".to_string()], &[]);
         assert_eq!(files.len(), 1);
         assert_eq!(files[0], "guide.md");
     }
+
+    #[test]
+    fn test_find_doc_files_include_and_exclude_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        let docs_path = dir.path().join("docs");
+        fs::create_dir_all(docs_path.join("api")).unwrap();
+        fs::create_dir_all(docs_path.join("drafts")).unwrap();
+
+        fs::write(docs_path.join("guide.md"), "# Guide\n").unwrap();
+        fs::write(docs_path.join("api/server.md"), "# Server\n").unwrap();
+        fs::write(docs_path.join("api/CHANGELOG.md"), "# Changelog\n").unwrap();
+        fs::write(docs_path.join("drafts/wip.md"), "# WIP\n").unwrap();
+
+        let files = find_doc_files(&docs_path, &["drafts/**".to_string()], &[]);
+        assert_eq!(files, vec!["api/server.md", "guide.md"]);
+
+        let files = find_doc_files(
+            &docs_path,
+            &["docs/api/server.md".to_string()],
+            &["docs/api/*".to_string(), "guide.md".to_string()],
+        );
+        assert_eq!(files, vec!["guide.md"]);
+
+        assert!(validate_doc_patterns("docs-exclude", &["[".to_string()]).is_err());
+    }
 }
@@ -7,7 +7,7 @@ use super::conventions::Language;
 use crate::core::engine::codebase_scan::CodebaseSnapshot;
 
 /// A structural fingerprint extracted from a single source file.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct FileFingerprint {
     /// Path relative to component root.
     pub relative_path: String,
@@ -49,7 +49,9 @@ pub struct FileFingerprint {
     pub namespace: Option<String>,
     /// Import/use statements.
     pub imports: Vec<String>,
-    /// Raw file content (for import usage analysis).
+    /// Raw file content (for import usage analysis). Not cached; restored
+    /// from the file on reuse.
+    #[serde(skip)]
     pub content: String,
     /// Method name → normalized body hash for duplication detection.
     /// Populated by extension scripts that support it; empty otherwise.
@@ -0,0 +1,298 @@
+//! Per-component fingerprint cache for repeated audits.
+//!
+//! Entries are keyed by path relative to the component root and hold the
+//! SHA-256 of the content they were computed from, so an edited file misses
+//! and is re-fingerprinted. Each entry also records which extension produced
+//! it (id, version, and grammar hash), so `extension update` invalidates the
+//! files it fingerprints. The whole cache is dropped when the Homeboy
+//! version changes, since core fingerprint logic may have changed.
+//! Stored at `~/.config/homeboy/fingerprint-cache/<component>.json`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::fingerprint::{fingerprint_content, FileFingerprint};
+use crate::engine::local_files;
+use crate::{extension, paths};
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    entries: HashMap<String, CachedFingerprint>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedFingerprint {
+    hash: String,
+    /// [`fingerprinter_signature`] of the extension that produced it.
+    #[serde(default)]
+    fingerprinter: String,
+    fingerprint: FileFingerprint,
+}
+
+/// Fingerprints reused across audit runs. A disabled cache (`--no-cache`)
+/// fingerprints every file and stores nothing.
+pub(crate) struct FingerprintCache {
+    path: Option<PathBuf>,
+    entries: HashMap<String, CachedFingerprint>,
+    /// Fingerprinter signature per file extension, resolved once per run.
+    fingerprinters: HashMap<String, String>,
+    used: HashSet<String>,
+    hits: usize,
+    misses: usize,
+}
+
+impl FingerprintCache {
+    pub(crate) fn disabled() -> Self {
+        Self::empty(None)
+    }
+
+    /// Load `component_id`'s cache. A missing, unreadable, or stale cache
+    /// starts empty.
+    pub(crate) fn load(component_id: &str) -> Self {
+        match paths::fingerprint_cache(component_id) {
+            Ok(path) => Self::load_from(path),
+            Err(_) => Self::disabled(),
+        }
+    }
+
+    fn load_from(path: PathBuf) -> Self {
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<CacheFile>(&raw).ok())
+            .filter(|file| file.version == env!("CARGO_PKG_VERSION"))
+            .map(|file| file.entries)
+            .unwrap_or_default();
+        Self {
+            entries,
+            ..Self::empty(Some(path))
+        }
+    }
+
+    fn empty(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            entries: HashMap::new(),
+            fingerprinters: HashMap::new(),
+            used: HashSet::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// [`fingerprint_content`], reusing the cached fingerprint when `content`
+    /// is unchanged. Files that cannot be fingerprinted are not cached, so a
+    /// transient extension failure is retried next run.
+    pub(crate) fn fingerprint(
+        &mut self,
+        path: &Path,
+        root: &Path,
+        content: &str,
+    ) -> Option<FileFingerprint> {
+        if !self.is_enabled() {
+            return fingerprint_content(path, root, content);
+        }
+        let ext = path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let fingerprinter = self
+            .fingerprinters
+            .entry(ext)
+            .or_insert_with_key(|ext| fingerprinter_signature(ext))
+            .clone();
+        self.fingerprint_with(path, root, content, &fingerprinter, || {
+            fingerprint_content(path, root, content)
+        })
+    }
+
+    fn fingerprint_with(
+        &mut self,
+        path: &Path,
+        root: &Path,
+        content: &str,
+        fingerprinter: &str,
+        compute: impl FnOnce() -> Option<FileFingerprint>,
+    ) -> Option<FileFingerprint> {
+        if !self.is_enabled() {
+            return compute();
+        }
+
+        let key = path
+            .strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        let hash = format!("{:x}", Sha256::digest(content.as_bytes()));
+        self.used.insert(key.clone());
+
+        if let Some(cached) = self
+            .entries
+            .get(&key)
+            .filter(|c| c.hash == hash && c.fingerprinter == fingerprinter)
+        {
+            self.hits += 1;
+            let mut fingerprint = cached.fingerprint.clone();
+            fingerprint.content = content.to_string();
+            return Some(fingerprint);
+        }
+
+        self.misses += 1;
+        let fingerprint = compute();
+        match &fingerprint {
+            Some(fp) => {
+                self.entries.insert(
+                    key,
+                    CachedFingerprint {
+                        hash,
+                        fingerprinter: fingerprinter.to_string(),
+                        fingerprint: fp.clone(),
+                    },
+                );
+            }
+            None => {
+                self.entries.remove(&key);
+            }
+        }
+        fingerprint
+    }
+
+    /// `(hits, misses)` for this run.
+    pub(crate) fn stats(&self) -> (usize, usize) {
+        (self.hits, self.misses)
+    }
+
+    /// Write the cache, dropping entries for files not seen this run.
+    /// Best effort: a cache that cannot be written only costs speed.
+    pub(crate) fn save(mut self) {
+        let Some(path) = self.path.take() else {
+            return;
+        };
+        if self.misses == 0 && self.used.len() == self.entries.len() {
+            return;
+        }
+
+        let used = self.used;
+        self.entries.retain(|key, _| used.contains(key));
+        let file = CacheFile {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            entries: self.entries,
+        };
+        let Ok(raw) = serde_json::to_string(&file) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = local_files::write_scratch_file_atomic(&path, &raw, "write fingerprint cache");
+    }
+}
+
+/// Identify what fingerprints files with extension `ext`: the matching
+/// extension's id and version plus a hash of its grammar file. Empty when no
+/// extension handles `ext`.
+fn fingerprinter_signature(ext: &str) -> String {
+    let Some(matched) = extension::find_extension_for_file_ext(ext, "fingerprint") else {
+        return String::new();
+    };
+    let grammar = matched.extension_path.as_deref().and_then(|dir| {
+        ["grammar.toml", "grammar.json"]
+            .iter()
+            .find_map(|name| std::fs::read(Path::new(dir).join(name)).ok())
+    });
+    let grammar_hash = grammar
+        .map(|bytes| format!("{:x}", Sha256::digest(&bytes)))
+        .unwrap_or_default();
+    format!("{}@{}:{}", matched.id, matched.version, grammar_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn methods_of(content: &str) -> Option<FileFingerprint> {
+        Some(FileFingerprint {
+            relative_path: "lib.rs".to_string(),
+            methods: content
+                .lines()
+                .filter_map(|line| line.strip_prefix("fn "))
+                .map(str::to_string)
+                .collect(),
+            content: content.to_string(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_cache_hits_unchanged_files_and_misses_edits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("component");
+        let file = root.join("lib.rs");
+        let cache_path = dir.path().join("cache/component.json");
+
+        let original = "fn alpha\n";
+        let mut cache = FingerprintCache::load_from(cache_path.clone());
+        cache.fingerprint_with(&file, &root, original, "rust@1.0.0:a", || {
+            methods_of(original)
+        });
+        assert_eq!(cache.stats(), (0, 1));
+        cache.save();
+
+        let mut cache = FingerprintCache::load_from(cache_path);
+        let cached = cache
+            .fingerprint_with(&file, &root, original, "rust@1.0.0:a", || {
+                panic!("should hit the cache")
+            })
+            .unwrap();
+        assert_eq!(cache.stats(), (1, 0));
+        assert_eq!(cached.methods, vec!["alpha"]);
+        assert_eq!(cached.content, original);
+
+        let edited = "fn alpha\nfn beta\n";
+        let refreshed = cache
+            .fingerprint_with(&file, &root, edited, "rust@1.0.0:a", || methods_of(edited))
+            .unwrap();
+        assert_eq!(cache.stats(), (1, 1));
+        assert_eq!(refreshed.methods, vec!["alpha", "beta"]);
+    }
+
+    #[test]
+    fn test_cache_misses_when_the_fingerprinter_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("component");
+        let file = root.join("lib.rs");
+        let cache_path = dir.path().join("cache/component.json");
+
+        let content = "fn alpha\n";
+        let mut cache = FingerprintCache::load_from(cache_path.clone());
+        cache.fingerprint_with(&file, &root, content, "rust@1.0.0:a", || {
+            methods_of(content)
+        });
+        cache.save();
+
+        let mut cache = FingerprintCache::load_from(cache_path);
+        cache.fingerprint_with(&file, &root, content, "rust@1.1.0:b", || {
+            methods_of(content)
+        });
+        assert_eq!(cache.stats(), (0, 1));
+    }
+
+    #[test]
+    fn test_disabled_cache_always_computes() {
+        let mut cache = FingerprintCache::disabled();
+        let root = Path::new("/tmp/component");
+        let fp = cache.fingerprint_with(&root.join("lib.rs"), root, "fn a\n", "", || {
+            methods_of("fn a\n")
+        });
+        assert!(fp.is_some());
+        assert_eq!(cache.stats(), (0, 0));
+        cache.save();
+    }
+}
@@ -0,0 +1,248 @@
+//! Audit score history (`audit --history-file`, `audit history`).
+//!
+//! Each audit run can append a one-line JSON summary to a history file so
+//! alignment can be tracked across runs without keeping full reports. The
+//! file is plain JSONL: one record per run, oldest first, any number of
+//! components interleaved.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{AuditCommandOutput, AuditFinding, AuditRunWorkflowResult};
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditHistoryRecord {
+    /// RFC 3339 time the run finished.
+    pub timestamp: String,
+    pub component_id: String,
+    /// Short SHA of the audited checkout, when it is a git repo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git_sha: Option<String>,
+    #[serde(default)]
+    pub alignment_score: Option<f32>,
+    #[serde(default)]
+    pub outliers: usize,
+    #[serde(default)]
+    pub broken_refs: usize,
+    #[serde(default)]
+    pub findings: usize,
+}
+
+/// Direction of the latest run against the first one in the window.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditHistoryTrend {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alignment_change: Option<f32>,
+    pub outliers_change: i64,
+    pub broken_refs_change: i64,
+    /// `improving`, `regressing`, or `flat`, judged on alignment first and
+    /// outliers when neither run has a score.
+    pub direction: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditHistoryOutput {
+    pub command: String,
+    pub component_id: String,
+    pub history_file: String,
+    /// Runs in the window, oldest first.
+    pub runs: Vec<AuditHistoryRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trend: Option<AuditHistoryTrend>,
+    /// Lines that could not be parsed and were skipped.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub skipped_lines: usize,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+impl AuditHistoryRecord {
+    /// Summarize a finished audit run. Convention-only runs carry no scores
+    /// and yield `None`.
+    pub fn from_workflow(
+        component_id: &str,
+        git_sha: Option<String>,
+        workflow: &AuditRunWorkflowResult,
+    ) -> Option<Self> {
+        let (alignment_score, outliers) = match &workflow.output {
+            AuditCommandOutput::Full { result, .. }
+            | AuditCommandOutput::Compared { result, .. } => (
+                result.summary.alignment_score,
+                result.summary.outliers_found,
+            ),
+            AuditCommandOutput::BaselineSaved {
+                alignment_score,
+                outliers_count,
+                ..
+            } => (*alignment_score, *outliers_count),
+            AuditCommandOutput::Summary(summary) => {
+                (summary.alignment_score, workflow.findings.len())
+            }
+            AuditCommandOutput::Conventions { .. } => return None,
+        };
+        let broken_refs = workflow
+            .findings
+            .iter()
+            .filter(|finding| finding.kind == AuditFinding::BrokenDocReference)
+            .count();
+
+        Some(Self {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            component_id: component_id.to_string(),
+            git_sha,
+            alignment_score,
+            outliers,
+            broken_refs,
+            findings: workflow.findings.len(),
+        })
+    }
+}
+
+/// Append `record` as one line to the history file at `path`, creating the
+/// file and its parent directory if needed.
+pub fn append(path: &Path, record: &AuditHistoryRecord) -> Result<()> {
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            Error::internal_io(e.to_string(), Some(format!("create {}", parent.display())))
+        })?;
+    }
+    let mut line = serde_json::to_string(record).map_err(|e| {
+        Error::internal_json(e.to_string(), Some("serialize audit history".to_string()))
+    })?;
+    line.push('\n');
+
+    // One write per record keeps concurrent batch appends from interleaving.
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("append {}", path.display()))))
+}
+
+/// Records for `component_id` in the history file, oldest first, plus the
+/// number of unparseable lines skipped.
+pub fn read(path: &Path, component_id: &str) -> Result<(Vec<AuditHistoryRecord>, usize)> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        Error::validation_invalid_argument(
+            "history-file",
+            format!("Cannot read audit history {}: {}", path.display(), e),
+            Some(path.display().to_string()),
+            None,
+        )
+    })?;
+
+    let mut records = Vec::new();
+    let mut skipped = 0;
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<AuditHistoryRecord>(line) {
+            Ok(record) if record.component_id == component_id => records.push(record),
+            Ok(_) => {}
+            Err(_) => skipped += 1,
+        }
+    }
+    Ok((records, skipped))
+}
+
+/// Latest run against the first run in `runs`; `None` with fewer than two.
+pub fn trend(runs: &[AuditHistoryRecord]) -> Option<AuditHistoryTrend> {
+    let (first, latest) = match runs {
+        [first, .., latest] => (first, latest),
+        _ => return None,
+    };
+    let alignment_change = match (first.alignment_score, latest.alignment_score) {
+        (Some(before), Some(after)) => Some(after - before),
+        _ => None,
+    };
+    let outliers_change = latest.outliers as i64 - first.outliers as i64;
+    let direction = match alignment_change {
+        Some(change) if change > 0.0 => "improving",
+        Some(change) if change < 0.0 => "regressing",
+        Some(_) => "flat",
+        None if outliers_change < 0 => "improving",
+        None if outliers_change > 0 => "regressing",
+        None => "flat",
+    };
+
+    Some(AuditHistoryTrend {
+        alignment_change,
+        outliers_change,
+        broken_refs_change: latest.broken_refs as i64 - first.broken_refs as i64,
+        direction: direction.to_string(),
+    })
+}
+
+/// Read the last `limit` runs for `component_id` and summarize the trend.
+pub fn history(path: &Path, component_id: &str, limit: usize) -> Result<AuditHistoryOutput> {
+    let (mut runs, skipped_lines) = read(path, component_id)?;
+    runs.drain(..runs.len().saturating_sub(limit));
+
+    Ok(AuditHistoryOutput {
+        command: "audit.history".to_string(),
+        component_id: component_id.to_string(),
+        history_file: path.display().to_string(),
+        trend: trend(&runs),
+        runs,
+        skipped_lines,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(component_id: &str, score: Option<f32>, outliers: usize) -> AuditHistoryRecord {
+        AuditHistoryRecord {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            component_id: component_id.to_string(),
+            git_sha: None,
+            alignment_score: score,
+            outliers,
+            broken_refs: 0,
+            findings: outliers,
+        }
+    }
+
+    #[test]
+    fn test_history_appends_and_filters_by_component() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("nested/audit-history.jsonl");
+
+        append(&path, &record("alpha", Some(0.5), 8)).unwrap();
+        append(&path, &record("beta", Some(0.9), 1)).unwrap();
+        append(&path, &record("alpha", Some(0.6), 6)).unwrap();
+        append(&path, &record("alpha", Some(0.75), 4)).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"not json\n").unwrap();
+
+        let output = history(&path, "alpha", 2).unwrap();
+
+        assert_eq!(output.skipped_lines, 1);
+        let scores: Vec<Option<f32>> = output.runs.iter().map(|r| r.alignment_score).collect();
+        assert_eq!(scores, vec![Some(0.6), Some(0.75)]);
+        let trend = output.trend.unwrap();
+        assert_eq!(trend.direction, "improving");
+        assert_eq!(trend.outliers_change, -2);
+    }
+
+    #[test]
+    fn test_trend() {
+        assert!(trend(&[record("a", Some(0.5), 1)]).is_none());
+
+        let regressing = trend(&[record("a", None, 1), record("a", None, 3)]).unwrap();
+        assert_eq!(regressing.direction, "regressing");
+        assert_eq!(regressing.alignment_change, None);
+
+        let flat = trend(&[record("a", Some(0.5), 1), record("a", Some(0.5), 3)]).unwrap();
+        assert_eq!(flat.direction, "flat");
+    }
+}
@@ -0,0 +1,155 @@
+//! Newline-delimited JSON (NDJSON) rendering of audit results.
+//!
+//! One self-contained record per line so CI wrappers and review bots can
+//! `jq` or annotate findings individually instead of parsing one large blob.
+//! Every record carries a `record` tag naming its type.
+
+use serde::Serialize;
+
+use super::{CheckStatus, CodeAuditResult, ConventionReport, Finding};
+use crate::code_audit::report::AuditCommandOutput;
+use crate::error::{Error, Result};
+
+/// Convention context attached to a finding record.
+#[derive(Debug, Serialize)]
+pub struct ConventionContext<'a> {
+    pub name: &'a str,
+    pub glob: &'a str,
+    pub status: &'a CheckStatus,
+    pub confidence: f32,
+    pub total_files: usize,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    pub expected_methods: &'a [String],
+}
+
+impl<'a> From<&'a ConventionReport> for ConventionContext<'a> {
+    fn from(report: &'a ConventionReport) -> Self {
+        Self {
+            name: &report.name,
+            glob: &report.glob,
+            status: &report.status,
+            confidence: report.confidence,
+            total_files: report.total_files,
+            expected_methods: &report.expected_methods,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct FindingRecord<'a> {
+    record: &'static str,
+    component_id: &'a str,
+    #[serde(flatten)]
+    finding: &'a Finding,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    convention_context: Option<ConventionContext<'a>>,
+}
+
+/// Render `records` as NDJSON, one compact JSON object per line.
+pub fn to_json_lines<T: Serialize>(records: impl IntoIterator<Item = T>) -> Result<String> {
+    let mut out = String::new();
+    for record in records {
+        let line = serde_json::to_string(&record).map_err(|e| {
+            Error::internal_json(e.to_string(), Some("serialize NDJSON record".to_string()))
+        })?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// One `finding` record per finding, with the matching convention's context.
+pub fn findings_json_lines(result: &CodeAuditResult) -> Result<String> {
+    to_json_lines(result.findings.iter().map(|finding| {
+        FindingRecord {
+            record: "finding",
+            component_id: &result.component_id,
+            finding,
+            convention_context: result
+                .conventions
+                .iter()
+                .find(|report| report.name == finding.convention)
+                .map(ConventionContext::from),
+        }
+    }))
+}
+
+/// NDJSON for an audit command: findings for full and compared runs, and a
+/// single line holding the whole payload for conventions, baseline, and
+/// summary output, which have no per-finding records.
+pub fn audit_output_json_lines(output: &AuditCommandOutput) -> Result<String> {
+    match output {
+        AuditCommandOutput::Full { result, .. } | AuditCommandOutput::Compared { result, .. } => {
+            findings_json_lines(result)
+        }
+        other => to_json_lines([other]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code_audit::{AuditFinding, AuditSummary, Severity};
+
+    fn finding(convention: &str, file: &str) -> Finding {
+        Finding {
+            convention: convention.to_string(),
+            severity: Severity::Warning,
+            file: file.to_string(),
+            description: "Missing method: register".to_string(),
+            suggestion: "Add register()".to_string(),
+            kind: AuditFinding::MissingMethod,
+        }
+    }
+
+    #[test]
+    fn test_findings_json_lines() {
+        let result = CodeAuditResult {
+            component_id: "widget".to_string(),
+            source_path: "/tmp/widget".to_string(),
+            summary: AuditSummary {
+                files_scanned: 3,
+                conventions_detected: 1,
+                outliers_found: 1,
+                alignment_score: Some(0.5),
+                files_skipped: 0,
+                warnings: Vec::new(),
+            },
+            conventions: vec![ConventionReport {
+                name: "Handlers".to_string(),
+                glob: "src/handlers/*".to_string(),
+                status: CheckStatus::Drift,
+                expected_methods: vec!["register".to_string()],
+                expected_registrations: Vec::new(),
+                expected_interfaces: Vec::new(),
+                expected_namespace: None,
+                expected_imports: Vec::new(),
+                conforming: vec!["src/handlers/a.rs".to_string()],
+                outliers: Vec::new(),
+                total_files: 2,
+                confidence: 0.5,
+            }],
+            directory_conventions: Vec::new(),
+            findings: vec![
+                finding("Handlers", "src/handlers/b.rs"),
+                finding("Other", "src/lib.rs"),
+            ],
+            duplicate_groups: Vec::new(),
+        };
+
+        let out = findings_json_lines(&result).expect("ndjson");
+        let lines: Vec<serde_json::Value> = out
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("valid json line"))
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["record"], "finding");
+        assert_eq!(lines[0]["component_id"], "widget");
+        assert_eq!(lines[0]["file"], "src/handlers/b.rs");
+        assert_eq!(lines[0]["severity"], "warning");
+        assert_eq!(lines[0]["suggestion"], "Add register()");
+        assert_eq!(lines[0]["convention_context"]["glob"], "src/handlers/*");
+        assert!(lines[1].get("convention_context").is_none());
+    }
+}
@@ -33,10 +33,13 @@ mod facade_passthrough;
 mod field_patterns;
 mod findings;
 pub mod fingerprint;
+mod fingerprint_cache;
 mod global_env_guard;
+pub mod history;
 mod idiomatic;
 pub(crate) mod impact;
 pub(crate) mod import_matching;
+pub mod json_lines;
 mod layer_ownership;
 pub(crate) mod naming;
 mod parallel_runner_setup;
@@ -46,6 +49,7 @@ mod requested_detectors;
 mod requirements;
 pub mod run;
 mod rust_test_wiring;
+pub mod sarif;
 mod shadow_modules;
 mod shared_scaffolding;
 mod signatures;
@@ -63,6 +67,7 @@ pub(crate) mod test_helpers;
 
 use std::path::Path;
 
+use self::fingerprint_cache::FingerprintCache;
 use self::layer_ownership::run as run_layer_ownership;
 
 pub use checks::{CheckResult, CheckStatus};
@@ -119,6 +124,8 @@ pub struct CodeAuditResult {
 #[derive(Debug, Clone, Default)]
 pub(crate) struct AuditAnalysisContext {
     pub(crate) fingerprints: Vec<fingerprint::FileFingerprint>,
+    /// Broken doc references from the docs pass, with their doc context.
+    pub(crate) broken_references: Vec<docs_audit::BrokenReference>,
 }
 
 #[derive(Debug, Clone)]
@@ -155,6 +162,19 @@ pub(crate) struct AuditExecutionPlan {
     pub(crate) run_parallel_runner_setup: bool,
     pub(crate) run_enum_dispatch_contracts: bool,
     pub(crate) run_aggregate_construction: bool,
+    /// Doc file globs/paths the docs pass scans, on top of component config.
+    pub(crate) doc_include: Vec<String>,
+    pub(crate) doc_exclude: Vec<String>,
+    /// Lowest claim confidence reported by the docs pass. `None` keeps the
+    /// default: real and unclear references, never examples.
+    pub(crate) doc_min_confidence: Option<docs_audit::ClaimConfidence>,
+    /// Lines of context captured on each side of a broken doc reference.
+    /// `None` keeps the default of one line.
+    pub(crate) doc_context_lines: Option<usize>,
+    /// Reuse fingerprints of unchanged files from the component's cache.
+    pub(crate) fingerprint_cache: bool,
+    /// Convention threshold from `--threshold`, overriding component config.
+    pub(crate) convention_threshold: Option<f32>,
 }
 
 impl AuditExecutionPlan {
@@ -188,6 +208,12 @@ impl AuditExecutionPlan {
                 run_parallel_runner_setup: true,
                 run_enum_dispatch_contracts: true,
                 run_aggregate_construction: true,
+                doc_include: Vec::new(),
+                doc_exclude: Vec::new(),
+                doc_min_confidence: None,
+                doc_context_lines: None,
+                fingerprint_cache: false,
+                convention_threshold: None,
             },
         )
     }
@@ -364,10 +390,52 @@ impl AuditExecutionPlan {
                     exclude,
                     &[AuditFinding::DirectAggregateConstruction],
                 ),
+                doc_include: Vec::new(),
+                doc_exclude: Vec::new(),
+                doc_min_confidence: None,
+                doc_context_lines: None,
+                fingerprint_cache: false,
+                convention_threshold: None,
             },
         )
     }
 
+    /// Restrict which doc files the docs pass scans (`--docs-include`,
+    /// `--docs-exclude`).
+    pub(crate) fn with_doc_filters(mut self, include: &[String], exclude: &[String]) -> Self {
+        self.doc_include = include.to_vec();
+        self.doc_exclude = exclude.to_vec();
+        self
+    }
+
+    /// Only report doc claims at or above `threshold` (`--docs-min-confidence`).
+    pub(crate) fn with_doc_min_confidence(
+        mut self,
+        threshold: Option<docs_audit::ClaimConfidence>,
+    ) -> Self {
+        self.doc_min_confidence = threshold;
+        self
+    }
+
+    /// Read and update the per-component fingerprint cache (off with
+    /// `--no-cache`).
+    pub(crate) fn with_fingerprint_cache(mut self, enabled: bool) -> Self {
+        self.fingerprint_cache = enabled;
+        self
+    }
+
+    /// Override the component's convention threshold (`--threshold`).
+    pub(crate) fn with_convention_threshold(mut self, threshold: Option<f32>) -> Self {
+        self.convention_threshold = threshold;
+        self
+    }
+
+    /// Capture `lines` of context around broken doc references (`--docs-context-lines`).
+    pub(crate) fn with_doc_context_lines(mut self, lines: Option<usize>) -> Self {
+        self.doc_context_lines = lines;
+        self
+    }
+
     fn with_generic_plan(mode: &str, mut audit: Self) -> Self {
         audit.plan = HomeboyPlan::builder_for_description(PlanKind::Audit, "audit execution")
             .mode(mode)
@@ -527,7 +595,21 @@ pub fn audit_component(component_id: &str) -> Result<CodeAuditResult> {
     audit_path_with_id(component_id, &comp.local_path)
 }
 
-/// Read reference dependency paths from HOMEBOY_AUDIT_REFERENCE_PATHS env var.
+thread_local! {
+    static REFERENCE_PATHS: std::cell::RefCell<Option<String>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Set the reference dependency paths (newline-separated) for audits run on
+/// this thread, overriding HOMEBOY_AUDIT_REFERENCE_PATHS. Per-thread so
+/// `audit --all` can audit components in parallel, each with its own
+/// extension's references. `None` falls back to the env var.
+pub fn set_reference_paths(paths: Option<String>) {
+    REFERENCE_PATHS.with(|cell| *cell.borrow_mut() = paths);
+}
+
+/// Read reference dependency paths set by [`set_reference_paths`], or from
+/// the HOMEBOY_AUDIT_REFERENCE_PATHS env var.
 ///
 /// Reference dependencies are external codebases (e.g. WordPress core, plugin
 /// dependencies) whose fingerprints are included in cross-reference analysis
@@ -535,7 +617,9 @@ pub fn audit_component(component_id: &str) -> Result<CodeAuditResult> {
 /// detection. This eliminates false positives for functions called via framework
 /// hooks, callbacks, or inherited methods.
 fn read_reference_paths_from_env() -> Vec<String> {
-    std::env::var("HOMEBOY_AUDIT_REFERENCE_PATHS")
+    REFERENCE_PATHS
+        .with(|cell| cell.borrow().clone())
+        .or_else(|| std::env::var("HOMEBOY_AUDIT_REFERENCE_PATHS").ok())
         .unwrap_or_default()
         .lines()
         .map(|s| s.trim().to_string())
@@ -672,7 +756,10 @@ fn audit_internal(
     plan: &AuditExecutionPlan,
 ) -> Result<AuditWithAnalysis> {
     let root = Path::new(source_path);
-    let audit_config = audit_config_for(component_id, root);
+    let mut audit_config = audit_config_for(component_id, root);
+    if plan.convention_threshold.is_some() {
+        audit_config.convention_threshold = plan.convention_threshold;
+    }
 
     if let Some(filter) = file_filter {
         log_status!(
@@ -686,19 +773,22 @@ fn audit_internal(
     }
 
     if !plan.requires_discovery() {
-        return Ok(AuditWithAnalysis {
-            result: audit_root_only(component_id, source_path, root, plan),
-            analysis: AuditAnalysisContext::default(),
-        });
+        return Ok(audit_root_only(component_id, source_path, root, plan));
     }
 
+    let mut cache = if plan.fingerprint_cache {
+        FingerprintCache::load(component_id)
+    } else {
+        FingerprintCache::disabled()
+    };
+
     // Phase 1: Auto-discover file groups (always full codebase for convention detection)
-    let discovery = discovery::auto_discover_groups(root, &audit_config);
+    let discovery = discovery::auto_discover_groups(root, &audit_config, &mut cache);
     let files_skipped = discovery
         .files_walked
         .saturating_sub(discovery.files_fingerprinted);
     if discovery.groups.is_empty() {
-        let mut warnings = Vec::new();
+        let mut warnings = discovery.warnings.clone();
         let unclaimed = walker::count_unclaimed_source_files(root);
         let total_skipped = files_skipped + unclaimed;
         if unclaimed > 0 {
@@ -789,8 +879,9 @@ fn audit_internal(
         .iter()
         .flat_map(|(_, _, fps)| fps.iter())
         .collect();
-    let analysis = AuditAnalysisContext {
+    let mut analysis = AuditAnalysisContext {
         fingerprints: all_fingerprints.iter().map(|fp| (*fp).clone()).collect(),
+        broken_references: Vec::new(),
     };
 
     // Build convention method set ONCE — used by duplication, near-duplicate, and parallel detectors.
@@ -879,10 +970,20 @@ fn audit_internal(
         Vec::new()
     };
     let component_ref_fingerprints = if plan.run_dead_code {
-        fingerprint_component_reference_files(root)
+        fingerprint_component_reference_files(root, &mut cache)
     } else {
         Vec::new()
     };
+    if cache.is_enabled() {
+        let (hits, misses) = cache.stats();
+        log_status!(
+            "audit",
+            "Fingerprint cache: {} hit(s), {} miss(es)",
+            hits,
+            misses
+        );
+    }
+    cache.save();
     let ref_fp_refs: Vec<&fingerprint::FileFingerprint> = ref_fingerprints
         .iter()
         .chain(component_ref_fingerprints.iter())
@@ -993,11 +1094,12 @@ fn audit_internal(
     }
 
     // Phase 4j: Documentation drift detection (broken/stale references in markdown)
-    let doc_findings = if plan.run_docs {
-        detect_doc_drift(root, component_id)
+    let (doc_findings, broken_references) = if plan.run_docs {
+        detect_doc_drift(root, component_id, plan)
     } else {
-        Vec::new()
+        (Vec::new(), Vec::new())
     };
+    analysis.broken_references = broken_references;
     if !doc_findings.is_empty() {
         log_status!(
             "audit",
@@ -1311,7 +1413,7 @@ fn audit_internal(
         None
     };
 
-    let mut warnings = Vec::new();
+    let mut warnings = discovery.warnings.clone();
     if files_skipped > 0 {
         warnings.push(format!(
             "{} source file(s) found but could not be fingerprinted (no extension provides fingerprinting for these file types)",
@@ -1348,7 +1450,10 @@ fn audit_internal(
     );
 
     // Phase 6: Cross-directory convention discovery
-    let directory_conventions = discovery::discover_cross_directory(&convention_reports);
+    let directory_conventions = discovery::discover_cross_directory(
+        &convention_reports,
+        audit_config.convention_threshold(),
+    );
 
     if !directory_conventions.is_empty() {
         let total_dir_outliers: usize = directory_conventions
@@ -1389,8 +1494,9 @@ fn audit_root_only(
     source_path: &str,
     root: &Path,
     plan: &AuditExecutionPlan,
-) -> CodeAuditResult {
+) -> AuditWithAnalysis {
     let mut findings = Vec::new();
+    let mut analysis = AuditAnalysisContext::default();
 
     if plan.run_structural {
         let structural_findings = structural::analyze_structure(root);
@@ -1441,7 +1547,8 @@ fn audit_root_only(
     }
 
     if plan.run_docs {
-        let doc_findings = detect_doc_drift(root, component_id);
+        let (doc_findings, broken_references) = detect_doc_drift(root, component_id, plan);
+        analysis.broken_references = broken_references;
         if !doc_findings.is_empty() {
             log_status!(
                 "audit",
@@ -1483,21 +1590,24 @@ fn audit_root_only(
         outliers_found
     );
 
-    CodeAuditResult {
-        component_id: component_id.to_string(),
-        source_path: source_path.to_string(),
-        summary: AuditSummary {
-            files_scanned: 0,
-            conventions_detected: 0,
-            outliers_found,
-            alignment_score: None,
-            files_skipped: 0,
-            warnings: vec![],
+    AuditWithAnalysis {
+        result: CodeAuditResult {
+            component_id: component_id.to_string(),
+            source_path: source_path.to_string(),
+            summary: AuditSummary {
+                files_scanned: 0,
+                conventions_detected: 0,
+                outliers_found,
+                alignment_score: None,
+                files_skipped: 0,
+                warnings: vec![],
+            },
+            conventions: vec![],
+            directory_conventions: vec![],
+            findings,
+            duplicate_groups: vec![],
         },
-        conventions: vec![],
-        directory_conventions: vec![],
-        findings,
-        duplicate_groups: vec![],
+        analysis,
     }
 }
 
@@ -1509,11 +1619,17 @@ fn audit_root_only(
 ///
 /// Scans all `.md` files in common docs directories, extracts verifiable claims
 /// (file paths, directory paths, class names), and checks each against the
-/// codebase. Broken claims become `Finding` entries in the unified audit pipeline.
-fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
+/// codebase. Broken claims become `Finding` entries in the unified audit pipeline,
+/// paired with `BrokenReference`s that carry the surrounding doc lines.
+fn detect_doc_drift(
+    root: &Path,
+    component_id: &str,
+    plan: &AuditExecutionPlan,
+) -> (Vec<Finding>, Vec<docs_audit::BrokenReference>) {
     use docs_audit::claims::ClaimConfidence;
 
     let mut findings = Vec::new();
+    let mut broken_references = Vec::new();
 
     // Find docs directory
     let docs_dirs = ["docs", "doc", "documentation"];
@@ -1527,22 +1643,28 @@ fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
     });
 
     let Some((docs_path, docs_dir_name)) = docs_entry else {
-        return findings;
+        return (findings, broken_references);
     };
 
-    let doc_excludes = if let Ok(comp) = component::load(component_id) {
-        crate::component::scope::resolve_component_scope(
-            &comp,
-            crate::component::scope::ScopeCommand::Audit,
-        )
-        .exclude
-    } else {
-        Vec::new()
-    };
+    let mut doc_include = plan.doc_include.clone();
+    let mut doc_exclude = plan.doc_exclude.clone();
+    if let Ok(comp) = component::load(component_id) {
+        doc_exclude.extend(
+            crate::component::scope::resolve_component_scope(
+                &comp,
+                crate::component::scope::ScopeCommand::Audit,
+            )
+            .exclude,
+        );
+        if let Some(docs) = comp.scopes.as_ref().and_then(|s| s.docs.as_ref()) {
+            doc_include.extend(docs.include.iter().cloned());
+            doc_exclude.extend(docs.exclude.iter().cloned());
+        }
+    }
 
-    let doc_files = docs_audit::find_doc_files(&docs_path, &doc_excludes);
+    let doc_files = docs_audit::find_doc_files(&docs_path, &doc_exclude, &doc_include);
     if doc_files.is_empty() {
-        return findings;
+        return (findings, broken_references);
     }
 
     // Load extension-configured ignore patterns if component is registered
@@ -1552,6 +1674,14 @@ fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
         Vec::new()
     };
 
+    let threshold = plan
+        .doc_min_confidence
+        .as_ref()
+        .unwrap_or(&ClaimConfidence::Unclear);
+    let context_lines = plan
+        .doc_context_lines
+        .unwrap_or(docs_audit::DEFAULT_DOC_CONTEXT_LINES);
+
     for relative_doc in &doc_files {
         let abs_doc = docs_path.join(relative_doc);
         let content = match std::fs::read_to_string(&abs_doc) {
@@ -1563,8 +1693,9 @@ fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
         let claims = docs_audit::claims::extract_claims(&content, &finding_file, &ignore_patterns);
 
         for claim in claims {
-            // Skip example/placeholder paths — they're illustrative, not real references
-            if claim.confidence == ClaimConfidence::Example {
+            // Skip example/placeholder paths unless asked for — they're
+            // illustrative, not real references
+            if !claim.confidence.meets(threshold) {
                 continue;
             }
 
@@ -1588,9 +1719,21 @@ fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
                         },
                         file: finding_file.clone(),
                         description,
-                        suggestion: suggestion_text,
+                        suggestion: suggestion_text.clone(),
                         kind,
                     });
+                    broken_references.push(docs_audit::BrokenReference {
+                        doc: finding_file.clone(),
+                        line: claim.line,
+                        claim: claim.value.clone(),
+                        confidence: claim.confidence.clone(),
+                        doc_context: Some(docs_audit::extract_doc_context(
+                            &content,
+                            claim.line,
+                            context_lines,
+                        )),
+                        action: suggestion_text,
+                    });
                 }
                 docs_audit::VerifyResult::Verified
                 | docs_audit::VerifyResult::NeedsVerification { .. } => {}
@@ -1603,8 +1746,9 @@ fn detect_doc_drift(root: &Path, component_id: &str) -> Vec<Finding> {
             .cmp(&b.file)
             .then_with(|| a.description.cmp(&b.description))
     });
+    broken_references.sort_by(|a, b| a.doc.cmp(&b.doc).then(a.line.cmp(&b.line)));
 
-    findings
+    (findings, broken_references)
 }
 
 /// Classify a broken reference as stale (moved target) or truly broken.
@@ -1807,12 +1951,15 @@ fn fingerprint_reference_paths(reference_paths: &[String]) -> Vec<fingerprint::F
 /// fingerprints. This reference-only pass keeps calls from singleton files,
 /// index files, and module facades in the graph so exported functions are not
 /// reported just because their consumers live outside a convention group.
-fn fingerprint_component_reference_files(root: &Path) -> Vec<fingerprint::FileFingerprint> {
+fn fingerprint_component_reference_files(
+    root: &Path,
+    cache: &mut FingerprintCache,
+) -> Vec<fingerprint::FileFingerprint> {
     let snapshot = walker::walk_all_source_files_snapshot(root);
     let mut fingerprints = Vec::new();
 
     for (path, content) in snapshot.iter() {
-        if let Some(fp) = fingerprint::fingerprint_content(path, root, content) {
+        if let Some(fp) = cache.fingerprint(path, root, content) {
             fingerprints.push(fp);
         }
     }
@@ -1919,7 +2066,8 @@ mod tests {
             .iter()
             .filter_map(|(path, content)| fingerprint::fingerprint_content(path, &dir, content))
             .collect();
-        let component_ref_fingerprints = fingerprint_component_reference_files(&dir);
+        let component_ref_fingerprints =
+            fingerprint_component_reference_files(&dir, &mut FingerprintCache::disabled());
 
         let owned_refs: Vec<_> = owned_fingerprints.iter().collect();
         let component_refs: Vec<_> = component_ref_fingerprints.iter().collect();
@@ -2019,4 +2167,33 @@ class StepC {
 
         let _ = fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    fn doc_drift_attaches_doc_context_to_broken_references() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("docs")).unwrap();
+        fs::write(
+            dir.path().join("docs/guide.md"),
+            "# Guide\n\nSee `src/missing.rs` for details.\n\nMore text.\n",
+        )
+        .unwrap();
+
+        let plan = AuditExecutionPlan::full().with_doc_context_lines(Some(1));
+        let (findings, broken) = detect_doc_drift(dir.path(), "homeboy-doc-context-test", &plan);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(broken.len(), 1);
+        assert_eq!(broken[0].doc, "docs/guide.md");
+        assert_eq!(broken[0].line, 3);
+        assert_eq!(
+            broken[0].doc_context.as_deref(),
+            Some(
+                &[
+                    "2: ".to_string(),
+                    "3: See `src/missing.rs` for details.".to_string(),
+                    "4: ".to_string(),
+                ][..]
+            )
+        );
+    }
 }
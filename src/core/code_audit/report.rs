@@ -8,7 +8,7 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::code_audit::{
-    baseline, AuditFinding, CodeAuditResult, ConventionReport, DirectoryConvention,
+    baseline, docs_audit, AuditFinding, CodeAuditResult, ConventionReport, DirectoryConvention,
     FindingConfidence, Severity,
 };
 use serde::Serialize;
@@ -96,6 +96,9 @@ pub enum AuditCommandOutput {
         result: CodeAuditResult,
         #[serde(skip_serializing_if = "Option::is_none")]
         fixability: Option<AuditFixability>,
+        /// Broken doc references with the surrounding doc lines.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        broken_references: Vec<docs_audit::BrokenReference>,
     },
 
     #[serde(rename = "audit.conventions")]
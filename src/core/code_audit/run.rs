@@ -5,6 +5,7 @@
 
 use crate::code_audit::{self, baseline, AuditWithAnalysis, CodeAuditResult};
 use crate::git;
+use crate::output::{ExitPolicy, IssueCounts};
 use std::collections::HashSet;
 use std::path::Path;
 
@@ -23,10 +24,28 @@ pub struct AuditRunWorkflowArgs {
     pub exclude_kinds: Vec<code_audit::AuditFinding>,
     pub only_labels: Vec<String>,
     pub exclude_labels: Vec<String>,
+    /// Doc file paths/globs the docs pass is limited to (`--docs-include`).
+    pub docs_include: Vec<String>,
+    /// Doc file paths/globs the docs pass skips (`--docs-exclude`).
+    pub docs_exclude: Vec<String>,
+    /// Lowest doc claim confidence to report (`--docs-min-confidence`).
+    pub docs_min_confidence: Option<code_audit::docs_audit::ClaimConfidence>,
+    /// Lines of context around broken doc references (`--docs-context-lines`).
+    pub docs_context_lines: Option<usize>,
+    /// Convention threshold override (`--threshold`).
+    pub convention_threshold: Option<f32>,
     pub baseline_flags: crate::engine::baseline::BaselineFlags,
     pub changed_since: Option<String>,
+    /// `--only-changed`: audit everything for conventions, but report
+    /// findings only in files with uncommitted changes.
+    pub only_changed: bool,
     pub json_summary: bool,
     pub include_fixability: bool,
+    /// `--fail-on`: derive the exit code from finding severities instead of
+    /// the default/baseline rules.
+    pub exit_policy: Option<ExitPolicy>,
+    /// Re-fingerprint every file instead of reusing the fingerprint cache.
+    pub no_cache: bool,
 }
 
 /// Result of the main audit workflow — ready for report assembly.
@@ -40,8 +59,22 @@ pub struct AuditRunWorkflowResult {
 pub fn run_main_audit_workflow(
     args: AuditRunWorkflowArgs,
 ) -> crate::Result<AuditRunWorkflowResult> {
+    // --only-changed: the full tree is still audited so conventions reflect
+    // every sibling; only the reported findings are narrowed below.
+    let changed_files = if args.only_changed {
+        Some(uncommitted_files(&args.source_path)?)
+    } else {
+        None
+    };
+
     // Run audit — scoped or full
-    let result = run_audit(&args)?;
+    let result = match changed_files.as_deref() {
+        Some([]) => {
+            crate::log_status!("audit", "No uncommitted changes to audit");
+            None
+        }
+        _ => run_audit(&args)?,
+    };
 
     // Early return: no-change shortcut already handled by run_audit returning None
     let audit = match result {
@@ -67,6 +100,7 @@ pub fn run_main_audit_workflow(
                         duplicate_groups: vec![],
                     },
                     fixability: None,
+                    broken_references: Vec::new(),
                 },
                 0,
                 Vec::new(),
@@ -104,7 +138,9 @@ pub fn run_main_audit_workflow(
     // `default_audit_exit_code` reflects the filtered view.
     apply_finding_filters(&mut result, &args.only_kinds, &args.exclude_kinds);
 
-    if args.changed_since.is_some() {
+    if let Some(changed_files) = &changed_files {
+        scope_findings_to_changed_files(&mut result, changed_files);
+    } else if args.changed_since.is_some() {
         scope_convention_outliers_to_findings(&mut result);
     }
 
@@ -188,13 +224,57 @@ fn scope_convention_outliers_to_findings(result: &mut CodeAuditResult) {
     result.summary.outliers_found = result.findings.len();
 }
 
+/// Files with staged, unstaged, or untracked changes, for `--only-changed`.
+///
+/// Renames report their new path. A wholly untracked directory is listed
+/// once, with a trailing `/`.
+fn uncommitted_files(source_path: &str) -> crate::Result<Vec<String>> {
+    let changes = git::get_uncommitted_changes(source_path)?;
+    Ok(changes
+        .staged
+        .into_iter()
+        .chain(changes.unstaged)
+        .chain(changes.untracked)
+        .map(|path| match path.split_once(" -> ") {
+            Some((_, renamed)) => renamed.to_string(),
+            None => path,
+        })
+        .collect())
+}
+
+/// Keep findings (and convention outliers) in `changed_files` only. The
+/// conventions themselves stay, so the report still shows what was expected.
+fn scope_findings_to_changed_files(result: &mut CodeAuditResult, changed_files: &[String]) {
+    result
+        .findings
+        .retain(|finding| in_changed_files(&finding.file, changed_files));
+    scope_convention_outliers_to_findings(result);
+}
+
+fn in_changed_files(file: &str, changed_files: &[String]) -> bool {
+    changed_files
+        .iter()
+        .any(|changed| match changed.strip_suffix('/') {
+            Some(dir) => file.starts_with(dir) && file[dir.len()..].starts_with('/'),
+            None => changed == file,
+        })
+}
+
 /// Run the audit scan (scoped or full). Returns None if changed-since found no files.
 fn run_audit(args: &AuditRunWorkflowArgs) -> crate::Result<Option<AuditWithAnalysis>> {
+    code_audit::docs_audit::validate_doc_patterns("docs-include", &args.docs_include)?;
+    code_audit::docs_audit::validate_doc_patterns("docs-exclude", &args.docs_exclude)?;
+
     let plan = if args.baseline_flags.baseline {
         code_audit::AuditExecutionPlan::full()
     } else {
         code_audit::AuditExecutionPlan::from_filters(&args.only_kinds, &args.exclude_kinds)
-    };
+    }
+    .with_doc_filters(&args.docs_include, &args.docs_exclude)
+    .with_doc_min_confidence(args.docs_min_confidence.clone())
+    .with_doc_context_lines(args.docs_context_lines)
+    .with_convention_threshold(args.convention_threshold)
+    .with_fingerprint_cache(!args.no_cache);
 
     if let Some(ref git_ref) = args.changed_since {
         let changed = git::get_files_changed_since(&args.source_path, git_ref)?;
@@ -282,8 +362,9 @@ fn run_comparison_workflow(
     analysis: &code_audit::AuditAnalysisContext,
     args: &AuditRunWorkflowArgs,
 ) -> crate::Result<AuditRunWorkflowResult> {
-    // Try file-based baseline
-    if !args.baseline_flags.ignore_baseline {
+    // Try file-based baseline. An --only-changed run reports a slice of the
+    // tree, so comparing it would count everything else as resolved.
+    if !args.baseline_flags.ignore_baseline && !args.only_changed {
         if let Some(existing_baseline) = baseline::load_baseline(Path::new(&result.source_path)) {
             return build_comparison_output(result, analysis, existing_baseline, args);
         }
@@ -308,6 +389,7 @@ fn run_comparison_workflow(
     } else {
         default_audit_exit_code(&result, false)
     };
+    let exit_code = apply_exit_policy(args, &result, exit_code);
 
     if args.json_summary {
         let findings = result.findings.clone();
@@ -321,11 +403,13 @@ fn run_comparison_workflow(
     } else {
         let fixability = compute_fixability_if_requested(&result, analysis, args);
         let findings = result.findings.clone();
+        let broken_references = reported_broken_references(&result, analysis);
         Ok(AuditRunWorkflowResult {
             output: AuditCommandOutput::Full {
                 passed: exit_code == 0,
                 result,
                 fixability,
+                broken_references,
             },
             exit_code,
             findings,
@@ -333,6 +417,25 @@ fn run_comparison_workflow(
     }
 }
 
+/// Broken doc references whose doc still has a reported docs finding, so
+/// `--only` / `--exclude` / changed-file scoping narrow them too.
+fn reported_broken_references(
+    result: &CodeAuditResult,
+    analysis: &code_audit::AuditAnalysisContext,
+) -> Vec<code_audit::docs_audit::BrokenReference> {
+    analysis
+        .broken_references
+        .iter()
+        .filter(|reference| {
+            result
+                .findings
+                .iter()
+                .any(|finding| finding.convention == "docs" && finding.file == reference.doc)
+        })
+        .cloned()
+        .collect()
+}
+
 /// Build comparison output from a result and baseline.
 fn build_comparison_output(
     result: CodeAuditResult,
@@ -342,6 +445,7 @@ fn build_comparison_output(
 ) -> crate::Result<AuditRunWorkflowResult> {
     let comparison = baseline::compare(&result, &existing_baseline);
     let exit_code = if comparison.drift_increased { 1 } else { 0 };
+    let exit_code = apply_exit_policy(args, &result, exit_code);
     let changed_since_summary = args
         .changed_since
         .as_ref()
@@ -415,6 +519,22 @@ fn compute_fixability_if_requested(
         .flatten()
 }
 
+/// Apply `--fail-on` to the workflow's exit code, counting every finding in
+/// the (filtered) result. Audit findings are warnings or info, never errors.
+fn apply_exit_policy(args: &AuditRunWorkflowArgs, result: &CodeAuditResult, exit_code: i32) -> i32 {
+    let Some(policy) = args.exit_policy else {
+        return exit_code;
+    };
+    let mut counts = IssueCounts::default();
+    for finding in &result.findings {
+        match finding.severity {
+            code_audit::Severity::Warning => counts.warnings += 1,
+            code_audit::Severity::Info => counts.info += 1,
+        }
+    }
+    policy.apply(exit_code, counts)
+}
+
 /// Determine exit code for audit results.
 pub fn default_audit_exit_code(result: &CodeAuditResult, is_scoped: bool) -> i32 {
     if is_scoped {
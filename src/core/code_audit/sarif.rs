@@ -0,0 +1,207 @@
+//! SARIF 2.1.0 rendering of audit findings.
+//!
+//! Lets audit results feed code-scanning dashboards (GitHub code scanning and
+//! other SARIF consumers). Each finding becomes one result; every
+//! [`AuditFinding`] variant is listed as a rule so rule indexes stay stable
+//! across runs.
+
+use serde_json::{json, Value};
+
+use super::{AuditFinding, CodeAuditResult, Finding, Severity};
+use crate::code_audit::report::AuditCommandOutput;
+use crate::error::{Error, Result};
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// SARIF log with one run holding `result`'s findings.
+pub fn findings_sarif(result: &CodeAuditResult) -> Value {
+    let rule_names = AuditFinding::all_names();
+    let rules: Vec<Value> = rule_names
+        .iter()
+        .map(|name| {
+            json!({
+                "id": name,
+                "name": name,
+                "shortDescription": { "text": rule_title(name) },
+            })
+        })
+        .collect();
+    let results: Vec<Value> = result
+        .findings
+        .iter()
+        .map(|finding| finding_result(finding, rule_names))
+        .collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "homeboy",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                }
+            },
+            "automationDetails": { "id": format!("homeboy-audit/{}/", result.component_id) },
+            "results": results,
+        }]
+    })
+}
+
+/// Pretty SARIF for an audit command. Only full and compared runs carry
+/// findings; conventions, baseline, and summary output are rejected.
+pub fn audit_output_sarif(output: &AuditCommandOutput) -> Result<String> {
+    let result = match output {
+        AuditCommandOutput::Full { result, .. } | AuditCommandOutput::Compared { result, .. } => {
+            result
+        }
+        _ => {
+            return Err(Error::validation_invalid_argument(
+                "format",
+                "SARIF output needs a findings run",
+                None,
+                None,
+            )
+            .with_hint("Drop --conventions, --baseline, and --json-summary with --format sarif"))
+        }
+    };
+    serde_json::to_string_pretty(&findings_sarif(result))
+        .map_err(|e| Error::internal_json(e.to_string(), Some("serialize SARIF log".to_string())))
+}
+
+fn finding_result(finding: &Finding, rule_names: &[&str]) -> Value {
+    let rule_id = serde_json::to_value(&finding.kind)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let rule_index = rule_names.iter().position(|name| *name == rule_id);
+
+    let mut text = finding.description.clone();
+    if !finding.suggestion.is_empty() {
+        text.push_str("\nSuggestion: ");
+        text.push_str(&finding.suggestion);
+    }
+
+    let mut physical = json!({ "artifactLocation": { "uri": finding.file } });
+    if let Some(line) = line_hint(&finding.description) {
+        physical["region"] = json!({ "startLine": line });
+    }
+
+    let mut result = json!({
+        "ruleId": rule_id,
+        "level": match finding.severity {
+            Severity::Warning => "warning",
+            Severity::Info => "note",
+        },
+        "message": { "text": text },
+        "locations": [{ "physicalLocation": physical }],
+        "properties": {
+            "convention": finding.convention,
+            "suggestion": finding.suggestion,
+            "confidence": finding.kind.confidence(),
+        },
+    });
+    if let Some(index) = rule_index {
+        result["ruleIndex"] = json!(index);
+    }
+    result
+}
+
+/// Findings have no line field, but several detectors name the line in their
+/// description ("... on line 12 ..."). Use the first one when present.
+fn line_hint(description: &str) -> Option<usize> {
+    description.match_indices("line ").find_map(|(at, word)| {
+        let digits: String = description[at + word.len()..]
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        digits.parse().ok().filter(|line| *line > 0)
+    })
+}
+
+/// `missing_method` → `Missing method`.
+fn rule_title(name: &str) -> String {
+    let words = name.replace('_', " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::code_audit::AuditSummary;
+
+    fn finding(kind: AuditFinding, severity: Severity, description: &str) -> Finding {
+        Finding {
+            convention: "Handlers".to_string(),
+            severity,
+            file: "src/handlers/b.rs".to_string(),
+            description: description.to_string(),
+            suggestion: "Add register()".to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_findings_sarif() {
+        let result = CodeAuditResult {
+            component_id: "widget".to_string(),
+            source_path: "/tmp/widget".to_string(),
+            summary: AuditSummary {
+                files_scanned: 3,
+                conventions_detected: 1,
+                outliers_found: 2,
+                alignment_score: Some(0.5),
+                files_skipped: 0,
+                warnings: Vec::new(),
+            },
+            conventions: Vec::new(),
+            directory_conventions: Vec::new(),
+            findings: vec![
+                finding(
+                    AuditFinding::MissingMethod,
+                    Severity::Warning,
+                    "Missing method: register",
+                ),
+                finding(
+                    AuditFinding::TodoMarker,
+                    Severity::Info,
+                    "Comment marker 'TODO' found on line 12: tidy",
+                ),
+            ],
+            duplicate_groups: Vec::new(),
+        };
+
+        let sarif = findings_sarif(&result);
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), AuditFinding::all_names().len());
+        assert_eq!(rules[0]["shortDescription"]["text"], "Missing method");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results[0]["ruleId"], "missing_method");
+        assert_eq!(results[0]["ruleIndex"], 0);
+        assert_eq!(results[0]["level"], "warning");
+        assert_eq!(
+            results[0]["message"]["text"],
+            "Missing method: register\nSuggestion: Add register()"
+        );
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/handlers/b.rs");
+        assert!(location.get("region").is_none());
+
+        assert_eq!(results[1]["ruleId"], "todo_marker");
+        assert_eq!(results[1]["level"], "note");
+        assert_eq!(
+            results[1]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            12
+        );
+    }
+}
@@ -186,27 +186,16 @@ fn run_topology_script(
         return Vec::new();
     }
 
-    // Invoke the script directly so its shebang resolves the interpreter.
-    // Wrapping with `sh <script>` bypasses `#!/usr/bin/env bash` and runs
-    // under POSIX sh — which breaks scripts using bash-only features. See #1276.
-    let output = std::process::Command::new(&script_path)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::null())
-        .spawn()
-        .ok()
-        .and_then(|mut child| {
-            use std::io::Write;
-            if let Some(ref mut stdin) = child.stdin {
-                let payload = serde_json::to_vec(input).ok()?;
-                let _ = stdin.write_all(&payload);
-            }
-            child.wait_with_output().ok()
-        });
-
-    let Some(output) = output else {
+    let Ok(payload) = serde_json::to_vec(input) else {
         return Vec::new();
     };
+    let output = match extension::run_json_script(extension, "topology", &script_path, &payload) {
+        Ok(output) => output,
+        Err(err) => {
+            extension::log_script_timeout(&err);
+            return Vec::new();
+        }
+    };
     if !output.status.success() {
         return Vec::new();
     }
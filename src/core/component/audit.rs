@@ -4,8 +4,19 @@ use serde::{Deserialize, Serialize};
 #[path = "../../../tests/core/component/audit_test.rs"]
 mod audit_test;
 
+/// Share of files in a group that must carry a method, registration, or
+/// interface for it to become part of the convention.
+pub const DEFAULT_CONVENTION_THRESHOLD: f32 = 0.6;
+/// Below half, a "convention" would describe a minority of the files.
+pub const MIN_CONVENTION_THRESHOLD: f32 = 0.5;
+pub const MAX_CONVENTION_THRESHOLD: f32 = 1.0;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AuditConfig {
+    /// Convention threshold (0.5–1.0, default 0.6). Higher values require
+    /// near-unanimous agreement before a pattern becomes a convention.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub convention_threshold: Option<f32>,
     /// Class/base names whose public methods are invoked by a runtime dispatcher.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub runtime_entrypoint_extends: Vec<String>,
@@ -28,6 +39,10 @@ pub struct AuditConfig {
     /// Component-owned path rules that attach opaque tags before convention grouping.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub convention_tag_globs: Vec<ConventionTagGlob>,
+    /// Extra registration regexes merged with the grammar/extension-provided
+    /// registrations before convention discovery.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub registration_patterns: Vec<RegistrationPattern>,
     /// Symbols that are known to exist when component metadata proves a runtime
     /// floor, package, or bootstrap file is present.
     #[serde(default, skip_serializing_if = "KnownSymbolsConfig::is_empty")]
@@ -128,6 +143,16 @@ pub struct ConventionTagGlob {
     pub globs: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RegistrationPattern {
+    /// Regex whose first capture group (or `name` group) is the registration name.
+    pub pattern: String,
+    /// File extensions this pattern applies to, without leading dots. Empty
+    /// applies it to every fingerprinted file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_extensions: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
 pub struct KnownSymbolsConfig {
     /// Header-version providers keyed by an extension-owned marker and header.
@@ -269,20 +294,36 @@ fn default_requested_detector_convention() -> String {
 
 impl AuditConfig {
     pub fn is_empty(&self) -> bool {
-        self.runtime_entrypoint_extends.is_empty()
+        self.convention_threshold.is_none()
+            && self.runtime_entrypoint_extends.is_empty()
             && self.runtime_entrypoint_markers.is_empty()
             && self.lifecycle_path_globs.is_empty()
             && self.dead_guard_context_comment_patterns.is_empty()
             && self.utility_suffixes.is_empty()
             && self.convention_exception_globs.is_empty()
             && self.convention_tag_globs.is_empty()
+            && self.registration_patterns.is_empty()
             && self.known_symbols.is_empty()
             && self.requested_detectors.is_empty()
             && self.core_boundary_leaks.is_empty()
             && self.duplication_detector.is_empty()
     }
 
+    /// The configured convention threshold, clamped to
+    /// [`MIN_CONVENTION_THRESHOLD`]..=[`MAX_CONVENTION_THRESHOLD`].
+    pub fn convention_threshold(&self) -> f32 {
+        match self.convention_threshold {
+            Some(threshold) if threshold.is_finite() => {
+                threshold.clamp(MIN_CONVENTION_THRESHOLD, MAX_CONVENTION_THRESHOLD)
+            }
+            _ => DEFAULT_CONVENTION_THRESHOLD,
+        }
+    }
+
     pub fn merge(&mut self, other: &AuditConfig) {
+        if other.convention_threshold.is_some() {
+            self.convention_threshold = other.convention_threshold;
+        }
         extend_unique(
             &mut self.runtime_entrypoint_extends,
             &other.runtime_entrypoint_extends,
@@ -302,6 +343,10 @@ impl AuditConfig {
             &other.convention_exception_globs,
         );
         extend_unique(&mut self.convention_tag_globs, &other.convention_tag_globs);
+        extend_unique(
+            &mut self.registration_patterns,
+            &other.registration_patterns,
+        );
         self.known_symbols.merge(&other.known_symbols);
         self.core_boundary_leaks.merge(&other.core_boundary_leaks);
         self.duplication_detector.merge(&other.duplication_detector);
@@ -0,0 +1,274 @@
+//! Component → extension dependency graph for `component deps`.
+//!
+//! Edges are checked with [`check_extension_requirement`], the same check the
+//! component validators use, so a problem edge here is exactly what would
+//! fail `validate_extension_requirements` at run time.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use serde::Serialize;
+
+use crate::component::{self, Component};
+use crate::error::Result;
+use crate::extension::{self, check_extension_requirement, RequirementStatus};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyNode {
+    pub id: String,
+    /// `component` or `extension`.
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyEdge {
+    pub from: String,
+    pub to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    pub status: RequirementStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub problem: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyGraph {
+    pub nodes: Vec<DependencyNode>,
+    pub edges: Vec<DependencyEdge>,
+    /// Number of edges that are not satisfied.
+    pub problems: usize,
+}
+
+/// Build the graph for one component, or every registered component.
+pub fn dependency_graph(component_id: Option<&str>) -> Result<DependencyGraph> {
+    let components = match component_id {
+        Some(id) => vec![component::resolve_effective(Some(id), None, None)?],
+        None => component::inventory()?,
+    };
+    Ok(build_graph(&components))
+}
+
+fn build_graph(components: &[Component]) -> DependencyGraph {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut queue: VecDeque<String> = VecDeque::new();
+
+    for component in components {
+        nodes.push(DependencyNode {
+            id: component.id.clone(),
+            kind: "component".to_string(),
+            version: None,
+        });
+
+        let mut requirements: Vec<_> = component
+            .extensions
+            .iter()
+            .flatten()
+            .map(|(id, config)| (id.clone(), config.version.clone()))
+            .collect();
+        requirements.sort();
+
+        for (extension_id, constraint) in requirements {
+            let check = check_extension_requirement(&extension_id, constraint.as_deref());
+            edges.push(DependencyEdge {
+                from: component.id.clone(),
+                to: extension_id.clone(),
+                constraint,
+                status: check.status,
+                problem: check.message,
+            });
+            queue.push_back(extension_id);
+        }
+    }
+
+    // Walk extension → extension requirements declared in manifests.
+    let mut visited: BTreeSet<String> = BTreeSet::new();
+    while let Some(extension_id) = queue.pop_front() {
+        if !visited.insert(extension_id.clone()) {
+            continue;
+        }
+
+        let manifest = extension::load_extension(&extension_id).ok();
+        nodes.push(DependencyNode {
+            id: extension_id.clone(),
+            kind: "extension".to_string(),
+            version: manifest.as_ref().map(|m| m.version.clone()),
+        });
+
+        let required = manifest
+            .and_then(|m| m.requires)
+            .map(|requires| requires.extensions)
+            .unwrap_or_default();
+        for required_id in required {
+            let check = check_extension_requirement(&required_id, None);
+            edges.push(DependencyEdge {
+                from: extension_id.clone(),
+                to: required_id.clone(),
+                constraint: None,
+                status: check.status,
+                problem: check.message,
+            });
+            queue.push_back(required_id);
+        }
+    }
+
+    let problems = edges
+        .iter()
+        .filter(|edge| edge.status != RequirementStatus::Satisfied)
+        .count();
+    DependencyGraph {
+        nodes,
+        edges,
+        problems,
+    }
+}
+
+/// Render the graph as Graphviz DOT. Problem edges are drawn red with the
+/// problem as the edge label; missing extensions get a dashed red node.
+pub fn render_dot(graph: &DependencyGraph) -> String {
+    let mut out = String::from("digraph component_deps {\n    rankdir=LR;\n");
+
+    for node in &graph.nodes {
+        let missing = node.kind == "extension" && node.version.is_none();
+        let label = match &node.version {
+            Some(version) => format!("{}\\n{}", node.id, version),
+            None => node.id.clone(),
+        };
+        let shape = if node.kind == "component" {
+            "box"
+        } else {
+            "ellipse"
+        };
+        let style = if missing {
+            ", color=red, style=dashed"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "    {} [label={}, shape={}{}];\n",
+            dot_id(&node.id),
+            dot_id(&label),
+            shape,
+            style
+        ));
+    }
+
+    for edge in &graph.edges {
+        let mut attrs = Vec::new();
+        let label = match (&edge.constraint, &edge.problem) {
+            (_, Some(problem)) => Some(problem.as_str()),
+            (Some(constraint), None) => Some(constraint.as_str()),
+            (None, None) => None,
+        };
+        if let Some(label) = label {
+            attrs.push(format!("label={}", dot_id(label)));
+        }
+        if edge.status != RequirementStatus::Satisfied {
+            attrs.push("color=red".to_string());
+            attrs.push("fontcolor=red".to_string());
+        }
+        let attrs = if attrs.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", attrs.join(", "))
+        };
+        out.push_str(&format!(
+            "    {} -> {}{};\n",
+            dot_id(&edge.from),
+            dot_id(&edge.to),
+            attrs
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+fn dot_id(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::ScopedExtensionConfig;
+    use crate::test_support::with_isolated_home;
+    use std::collections::HashMap;
+
+    fn write_extension(id: &str, version: &str, requires: &[&str]) {
+        let dir = crate::paths::extensions().expect("extensions dir").join(id);
+        std::fs::create_dir_all(&dir).expect("extension dir");
+        let manifest = serde_json::json!({
+            "name": format!("{} extension", id),
+            "version": version,
+            "requires": { "extensions": requires },
+        });
+        std::fs::write(dir.join(format!("{}.json", id)), manifest.to_string())
+            .expect("extension manifest");
+    }
+
+    fn component(extensions: &[(&str, Option<&str>)]) -> Component {
+        let mut component = Component::new(
+            "site".to_string(),
+            "/tmp/site".to_string(),
+            String::new(),
+            None,
+        );
+        component.extensions = Some(
+            extensions
+                .iter()
+                .map(|(id, version)| {
+                    (
+                        id.to_string(),
+                        ScopedExtensionConfig {
+                            version: version.map(str::to_string),
+                            ..Default::default()
+                        },
+                    )
+                })
+                .collect::<HashMap<_, _>>(),
+        );
+        component
+    }
+
+    #[test]
+    fn test_build_graph_marks_problem_edges() {
+        with_isolated_home(|_| {
+            write_extension("wordpress", "1.2.0", &["php"]);
+            let graph =
+                build_graph(&[component(&[("wordpress", Some(">=2.0.0")), ("rust", None)])]);
+
+            let status = |from: &str, to: &str| {
+                graph
+                    .edges
+                    .iter()
+                    .find(|edge| edge.from == from && edge.to == to)
+                    .map(|edge| edge.status)
+            };
+            assert_eq!(status("site", "rust"), Some(RequirementStatus::Missing));
+            assert_eq!(
+                status("site", "wordpress"),
+                Some(RequirementStatus::VersionMismatch)
+            );
+            assert_eq!(status("wordpress", "php"), Some(RequirementStatus::Missing));
+            assert_eq!(graph.problems, 3);
+
+            let dot = render_dot(&graph);
+            assert!(dot.starts_with("digraph component_deps {"));
+            assert!(dot.contains("\"site\" -> \"rust\" [label=\"Extension 'rust' is not installed\", color=red, fontcolor=red];"));
+        });
+    }
+
+    #[test]
+    fn test_build_graph_satisfied_edge_keeps_constraint_label() {
+        with_isolated_home(|_| {
+            write_extension("wordpress", "2.1.0", &[]);
+            let graph = build_graph(&[component(&[("wordpress", Some("^2.0"))])]);
+
+            assert_eq!(graph.problems, 0);
+            assert_eq!(graph.nodes[1].version.as_deref(), Some("2.1.0"));
+            assert!(render_dot(&graph).contains("\"site\" -> \"wordpress\" [label=\"^2.0\"];"));
+        });
+    }
+}
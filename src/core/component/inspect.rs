@@ -0,0 +1,335 @@
+//! Fully-effective component configuration with per-field provenance.
+//!
+//! A component's behavior is its own config merged with what its extensions
+//! contribute: build scripts and artifact patterns, deploy overrides and
+//! verifications, and version patterns. This resolves each of those fields the
+//! same way `build`, `deploy`, and `version` do and records where the value
+//! came from, generalizing the deploy-plan provenance to the whole component.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::Component;
+use crate::deploy::{resolve_effective_deploy_config, DeployFieldConflict};
+use crate::extension::build::{resolve_build_command, ResolvedBuildCommand};
+use crate::extension::{self, ExtensionCapability};
+use crate::version;
+
+/// Resolved fields of a component plus anything that could not be resolved.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ComponentInspection {
+    pub fields: Vec<ResolvedField>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<DeployFieldConflict>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// One effective field and where its value came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedField {
+    /// `component`, `build`, `deploy`, or `version`.
+    pub section: String,
+    pub field: String,
+    pub value: Value,
+    /// `component`, `default`, or `extension:<id>`.
+    pub source: String,
+    /// Precedence rule that selected this source.
+    pub rule: String,
+}
+
+impl ComponentInspection {
+    pub fn field(&self, section: &str, name: &str) -> Option<&ResolvedField> {
+        self.fields
+            .iter()
+            .find(|field| field.section == section && field.field == name)
+    }
+
+    fn push(&mut self, section: &str, field: &str, value: Value, source: String, rule: &str) {
+        self.fields.push(ResolvedField {
+            section: section.to_string(),
+            field: field.to_string(),
+            value,
+            source,
+            rule: rule.to_string(),
+        });
+    }
+}
+
+/// Resolve the effective configuration of `component` against the installed
+/// extensions.
+pub fn inspect(component: &Component) -> ComponentInspection {
+    let mut inspection = ComponentInspection::default();
+    resolve_own_fields(&mut inspection, component);
+    resolve_build(&mut inspection, component);
+    resolve_deploy(&mut inspection, component);
+    resolve_version_targets(&mut inspection, component);
+    inspection
+}
+
+fn resolve_own_fields(inspection: &mut ComponentInspection, component: &Component) {
+    inspection.push(
+        "component",
+        "local_path",
+        Value::String(component.local_path.clone()),
+        "component".to_string(),
+        "component config",
+    );
+    let mut linked: Vec<String> = component
+        .extensions
+        .as_ref()
+        .map(|extensions| extensions.keys().cloned().collect())
+        .unwrap_or_default();
+    linked.sort();
+    inspection.push(
+        "component",
+        "extensions",
+        Value::from(linked),
+        "component".to_string(),
+        "component config",
+    );
+    if let Some(target) = &component.changelog_target {
+        inspection.push(
+            "component",
+            "changelog_target",
+            Value::String(target.clone()),
+            "component".to_string(),
+            "component config",
+        );
+    }
+}
+
+fn resolve_build(inspection: &mut ComponentInspection, component: &Component) {
+    match resolve_build_command(component) {
+        Ok(ResolvedBuildCommand::ComponentScript { command }) => inspection.push(
+            "build",
+            "build_command",
+            Value::String(command),
+            "component".to_string(),
+            "component scripts.build beats extension build scripts",
+        ),
+        Ok(ResolvedBuildCommand::ExtensionProvided {
+            context, command, ..
+        }) => inspection.push(
+            "build",
+            "build_command",
+            Value::String(command),
+            extension_source(&context.extension_id),
+            "bundled script of the linked build extension",
+        ),
+        Ok(ResolvedBuildCommand::LocalScript { command, .. }) => {
+            let source =
+                extension::resolve_extension_for_capability(component, ExtensionCapability::Build)
+                    .map(|extension_id| extension_source(&extension_id))
+                    .unwrap_or_else(|_| "component".to_string());
+            inspection.push(
+                "build",
+                "build_command",
+                Value::String(command),
+                source,
+                "local script matching the extension's script_names",
+            );
+        }
+        // Components without any build configuration simply have no build.
+        Err(error) if extension::extension_provides_build(component) => {
+            inspection.warnings.push(error.message)
+        }
+        Err(_) => {}
+    }
+
+    resolve_build_artifact(inspection, component);
+}
+
+/// Mirrors [`super::resolve_artifact`], keeping track of the source.
+fn resolve_build_artifact(inspection: &mut ComponentInspection, component: &Component) {
+    if let Some(artifact) = &component.build_artifact {
+        inspection.push(
+            "build",
+            "build_artifact",
+            Value::String(artifact.clone()),
+            "component".to_string(),
+            "explicit component config beats extension artifact_pattern",
+        );
+        return;
+    }
+
+    let mut linked: Vec<&String> = component
+        .extensions
+        .as_ref()
+        .map(|extensions| extensions.keys().collect())
+        .unwrap_or_default();
+    linked.sort();
+    for extension_id in linked {
+        let Ok(manifest) = extension::load_extension(extension_id) else {
+            continue;
+        };
+        let Some(pattern) = manifest
+            .build
+            .as_ref()
+            .and_then(|build| build.artifact_pattern.as_ref())
+        else {
+            continue;
+        };
+        let resolved = pattern
+            .replace("{component_id}", &component.id)
+            .replace("{local_path}", &component.local_path);
+        inspection.push(
+            "build",
+            "build_artifact",
+            Value::String(resolved),
+            extension_source(extension_id),
+            "first linked extension by ID with an artifact_pattern",
+        );
+        return;
+    }
+}
+
+fn resolve_deploy(inspection: &mut ComponentInspection, component: &Component) {
+    // Without a project the install directory is unknown; the remote path is
+    // what extension path patterns are matched against once it is joined to
+    // the project base path, so it stands in here.
+    let remote_path = component_remote_path(component);
+    let install_dir = (!remote_path.trim().is_empty())
+        .then(|| format!("/{}", remote_path.trim_start_matches('/')));
+    let effective = resolve_effective_deploy_config(component, install_dir.as_deref());
+
+    inspection.warnings.extend(effective.conflict_warnings());
+    for field in effective.fields {
+        inspection.push(
+            "deploy",
+            &field.field,
+            field.value,
+            field.source,
+            &field.rule,
+        );
+    }
+    inspection.conflicts.extend(effective.conflicts);
+}
+
+fn component_remote_path(component: &Component) -> String {
+    if component.remote_path.trim().is_empty() {
+        component.auto_resolve_remote_path().unwrap_or_default()
+    } else {
+        component.remote_path.clone()
+    }
+}
+
+fn resolve_version_targets(inspection: &mut ComponentInspection, component: &Component) {
+    for target in component.version_targets.iter().flatten() {
+        let field = format!("version_targets.{}", target.file);
+        if let Some(pattern) = &target.pattern {
+            inspection.push(
+                "version",
+                &field,
+                Value::String(pattern.clone()),
+                "component".to_string(),
+                "explicit target pattern beats extension version_patterns",
+            );
+            continue;
+        }
+        match version::default_pattern_source_for_file(&target.file) {
+            Some((extension_id, pattern)) => inspection.push(
+                "version",
+                &field,
+                Value::String(pattern),
+                extension_source(&extension_id),
+                "first installed extension by ID with a matching version_pattern",
+            ),
+            None => inspection.warnings.push(format!(
+                "No version pattern configured for '{}' and no extension provides one",
+                target.file
+            )),
+        }
+    }
+}
+
+fn extension_source(extension_id: &str) -> String {
+    format!("extension:{}", extension_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::VersionTarget;
+    use crate::test_support::with_isolated_home;
+    use std::collections::HashMap;
+
+    fn write_extension(home: &std::path::Path, id: &str, manifest: Value) {
+        let dir = home.join(".config/homeboy/extensions").join(id);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(format!("{}.json", id)), manifest.to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_inspect() {
+        with_isolated_home(|home| {
+            write_extension(
+                home.path(),
+                "wordpress",
+                serde_json::json!({
+                    "name": "WordPress",
+                    "version": "1.0.0",
+                    "build": { "artifact_pattern": "build/{component_id}.zip" },
+                    "deploy": {
+                        "overrides": [{
+                            "path_pattern": "/plugins/",
+                            "staging_path": "/tmp/staging",
+                            "install_command": "wp plugin install {{artifact}}"
+                        }],
+                        "version_patterns": [{ "extension": ".php", "pattern": "Version:\\s*(\\S+)" }]
+                    }
+                }),
+            );
+
+            let mut component = Component::new(
+                "site".to_string(),
+                "/tmp/homeboy-inspect-site".to_string(),
+                "wp-content/plugins/site".to_string(),
+                None,
+            );
+            component.extensions = Some(HashMap::from([(
+                "wordpress".to_string(),
+                Default::default(),
+            )]));
+            component.version_targets = Some(vec![
+                VersionTarget {
+                    file: "site.php".to_string(),
+                    pattern: None,
+                },
+                VersionTarget {
+                    file: "VERSION".to_string(),
+                    pattern: Some("(.*)".to_string()),
+                },
+            ]);
+
+            let inspection = inspect(&component);
+
+            let artifact = inspection.field("build", "build_artifact").unwrap();
+            assert_eq!(artifact.value, "build/site.zip");
+            assert_eq!(artifact.source, "extension:wordpress");
+
+            let install = inspection.field("deploy", "install_command").unwrap();
+            assert_eq!(install.source, "extension:wordpress");
+            assert_eq!(
+                inspection
+                    .field("deploy", "deploy_strategy")
+                    .unwrap()
+                    .source,
+                "default"
+            );
+            assert_eq!(
+                inspection.field("deploy", "remote_path").unwrap().source,
+                "component"
+            );
+
+            let php = inspection
+                .field("version", "version_targets.site.php")
+                .unwrap();
+            assert_eq!(php.source, "extension:wordpress");
+            let plain = inspection
+                .field("version", "version_targets.VERSION")
+                .unwrap();
+            assert_eq!(plain.source, "component");
+        });
+    }
+}
@@ -77,6 +77,83 @@ pub fn inventory() -> Result<Vec<Component>> {
     Ok(components)
 }
 
+/// Predicates for narrowing the component inventory. Unset fields match
+/// everything except archived components; set fields combine with AND.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentFilter {
+    /// Keep components attached to a project deployed on this server.
+    pub server: Option<String>,
+    /// Keep components that link this extension.
+    pub extension: Option<String>,
+    /// Case-insensitive substring matched against the ID and aliases.
+    pub name: Option<String>,
+    /// Keep archived components, which are dropped by default.
+    pub include_archived: bool,
+}
+
+impl ComponentFilter {
+    pub fn is_empty(&self) -> bool {
+        self.server.is_none()
+            && self.extension.is_none()
+            && self.name.is_none()
+            && self.include_archived
+    }
+
+    /// Keep only components matching every set predicate, preserving order.
+    pub fn apply(&self, components: Vec<Component>) -> Vec<Component> {
+        if self.is_empty() {
+            return components;
+        }
+        let projects = if self.server.is_some() {
+            project::list().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        components
+            .into_iter()
+            .filter(|component| self.matches(component, &projects))
+            .collect()
+    }
+
+    fn matches(&self, component: &Component, projects: &[project::Project]) -> bool {
+        if component.archived && !self.include_archived {
+            return false;
+        }
+
+        if let Some(server) = &self.server {
+            let on_server = projects.iter().any(|p| {
+                p.server_id.as_deref() == Some(server.as_str())
+                    && project::has_component(p, &component.id)
+            });
+            if !on_server {
+                return false;
+            }
+        }
+
+        if let Some(extension) = &self.extension {
+            let linked = component
+                .extensions
+                .as_ref()
+                .is_some_and(|extensions| extensions.contains_key(extension));
+            if !linked {
+                return false;
+            }
+        }
+
+        if let Some(name) = &self.name {
+            let needle = name.to_lowercase();
+            let named = std::iter::once(&component.id)
+                .chain(&component.aliases)
+                .any(|candidate| candidate.to_lowercase().contains(&needle));
+            if !named {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 /// Load standalone component registrations from `~/.config/homeboy/components/`.
 ///
 /// Each `<id>.json` file in the components directory is a registered component
@@ -533,6 +610,7 @@ pub fn rename_standalone_registration(old_id: &str, component: &Component) -> Re
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use std::fs;
     use std::sync::MutexGuard;
     use tempfile::TempDir;
@@ -592,6 +670,37 @@ mod tests {
         fs::write(path, serde_json::to_string_pretty(&json).unwrap()).unwrap();
     }
 
+    #[test]
+    fn component_filter_hides_archived_unless_included() {
+        let active = Component::new(
+            "active".to_string(),
+            "/tmp/active".to_string(),
+            String::new(),
+            None,
+        );
+        let mut archived = active.clone();
+        archived.id = "retired".to_string();
+        archived.archived = true;
+        let components = vec![active, archived];
+
+        let ids = |filter: ComponentFilter| -> Vec<String> {
+            filter
+                .apply(components.clone())
+                .into_iter()
+                .map(|component| component.id)
+                .collect()
+        };
+
+        assert_eq!(ids(ComponentFilter::default()), vec!["active"]);
+        assert_eq!(
+            ids(ComponentFilter {
+                include_archived: true,
+                ..Default::default()
+            }),
+            vec!["active", "retired"]
+        );
+    }
+
     #[test]
     fn write_standalone_registration_rejects_blank_id() {
         let component = Component::new(
@@ -1063,4 +1172,68 @@ mod tests {
             "validation.invalid_argument"
         );
     }
+
+    #[test]
+    fn component_filter_combines_predicates_with_and() {
+        let mut blog = Component::new(
+            "blog-theme".to_string(),
+            "/tmp/blog-theme".to_string(),
+            "wp-content/themes/blog-theme".to_string(),
+            None,
+        );
+        blog.extensions = Some(HashMap::from([(
+            "wordpress".to_string(),
+            Default::default(),
+        )]));
+        let mut cli = Component::new(
+            "deploy-cli".to_string(),
+            "/tmp/deploy-cli".to_string(),
+            "bin".to_string(),
+            None,
+        );
+        cli.aliases = vec!["blogctl".to_string()];
+        cli.extensions = Some(HashMap::from([("rust".to_string(), Default::default())]));
+
+        let prod = project::Project {
+            id: "prod-site".to_string(),
+            server_id: Some("prod".to_string()),
+            components: vec![project::ProjectComponentAttachment {
+                id: "blog-theme".to_string(),
+                local_path: "/tmp/blog-theme".to_string(),
+            }],
+            ..Default::default()
+        };
+        let projects = vec![prod];
+
+        let by_name = ComponentFilter {
+            name: Some("BLOG".to_string()),
+            ..Default::default()
+        };
+        assert!(by_name.matches(&blog, &projects));
+        assert!(by_name.matches(&cli, &projects), "aliases match too");
+
+        let wordpress_on_prod = ComponentFilter {
+            server: Some("prod".to_string()),
+            extension: Some("wordpress".to_string()),
+            name: Some("blog".to_string()),
+            ..Default::default()
+        };
+        assert!(wordpress_on_prod.matches(&blog, &projects));
+        assert!(!wordpress_on_prod.matches(&cli, &projects));
+
+        let staging = ComponentFilter {
+            server: Some("staging".to_string()),
+            ..Default::default()
+        };
+        assert!(!staging.matches(&blog, &projects));
+        assert!(ComponentFilter {
+            include_archived: true,
+            ..Default::default()
+        }
+        .is_empty());
+        assert!(
+            !ComponentFilter::default().is_empty(),
+            "default hides archived"
+        );
+    }
 }
@@ -2,30 +2,40 @@ use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::{HashMap, HashSet};
 
 pub mod audit;
+pub mod deps;
 pub mod drift;
+pub mod inspect;
 pub mod inventory;
 pub mod mutations;
 pub mod portable;
 pub mod relationships;
 pub mod resolution;
+pub mod scaffold;
 pub mod scope;
 pub mod versioning;
 
 pub use audit::{
     AuditConfig, ConventionTagGlob, CoreBoundaryLeakConfig, DuplicationDetectorConfig,
     KnownSymbolEntry, KnownSymbolHeaderVersionProvider, KnownSymbolKind, KnownSymbolVersionedEntry,
-    RequestedDetectorRule, RequestedDetectorRuleBody,
+    RegistrationPattern, RequestedDetectorRule, RequestedDetectorRuleBody,
+    DEFAULT_CONVENTION_THRESHOLD, MAX_CONVENTION_THRESHOLD, MIN_CONVENTION_THRESHOLD,
 };
+pub use deps::{dependency_graph, render_dot, DependencyEdge, DependencyGraph, DependencyNode};
+pub use inspect::{inspect, ComponentInspection, ResolvedField};
 pub use inventory::{
     exists, extension_provides_artifact_pattern, inventory, list, list_ids, load,
-    reconcile_standalone_registration, write_standalone_registration, ComponentReconcileReport,
+    reconcile_standalone_registration, write_standalone_registration, ComponentFilter,
+    ComponentReconcileReport,
 };
-pub use mutations::{delete_safe, merge, rename, set_changelog_target};
+pub use mutations::{delete_safe, merge, rename, set_archived, set_changelog_target};
 pub use portable::{
     discover_from_portable, has_portable_config, infer_portable_component_id, mutate_portable,
     portable_json, read_portable_config, write_portable_config,
 };
-pub use relationships::{associated_projects, projects_using, rename_component, shared_components};
+pub use relationships::{
+    associated_projects, components_using_extension, projects_using, rename_component,
+    shared_components,
+};
 pub use resolution::{resolve, resolve_artifact, resolve_effective, validate_local_path};
 pub use scope::{resolve_component_scope, EffectiveScope, ScopeCommand};
 pub use versioning::{
@@ -119,6 +129,9 @@ pub struct ScopeConfig {
     pub release: Option<CommandScopeConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub fleet: Option<CommandScopeConfig>,
+    /// Doc files scanned by the audit docs pass (paths or globs).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docs: Option<CommandScopeConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -166,9 +179,17 @@ pub struct Component {
     pub build_artifact: Option<String>,
     pub extensions: Option<HashMap<String, ScopedExtensionConfig>>,
     pub version_targets: Option<Vec<VersionTarget>>,
+    /// Shape of this component's release tags, with `{{version}}` marking the
+    /// version (e.g. `api-v{{version}}`, `release/{{version}}`). Used to find
+    /// the last release when computing changes; unset means the newest
+    /// `v1.2.3`/`1.2.3` tag.
+    pub tag_pattern: Option<String>,
     pub changelog_target: Option<String>,
     pub changelog_next_section_label: Option<String>,
     pub changelog_next_section_aliases: Option<Vec<String>>,
+    /// Changelog storage format (`markdown`, `json`, `conventional`).
+    /// Defaults to `markdown`.
+    pub changelog_format: Option<String>,
     /// Lifecycle hooks: event name -> list of shell commands.
     /// Events: `pre:version:bump`, `post:version:bump`, `post:release`, `post:deploy`
     pub hooks: HashMap<String, Vec<String>>,
@@ -214,6 +235,10 @@ pub struct Component {
     /// is always drift and does not need to be listed here.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub extra_drift_files: Vec<String>,
+    /// Retired but kept for a possible revival. Archived components are
+    /// hidden from `component list`, `status`, and `audit --all`, and build
+    /// and deploy refuse them without `--force`.
+    pub archived: bool,
 }
 
 /// Raw JSON shape for Component — handles backward-compatible deserialization
@@ -238,12 +263,16 @@ struct RawComponent {
     extensions: Option<HashMap<String, ScopedExtensionConfig>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     version_targets: Option<Vec<VersionTarget>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag_pattern: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", alias = "changelog_targets")]
     changelog_target: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     changelog_next_section_label: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     changelog_next_section_aliases: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    changelog_format: Option<String>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     hooks: HashMap<String, Vec<String>>,
     // Legacy hook fields — read from old JSON, merged into hooks
@@ -287,6 +316,8 @@ struct RawComponent {
     cli_path: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     extra_drift_files: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    archived: bool,
 }
 
 /// Insert legacy commands into hooks map if the event key doesn't already exist.
@@ -319,9 +350,11 @@ impl From<RawComponent> for Component {
             build_artifact: raw.build_artifact,
             extensions: raw.extensions,
             version_targets: raw.version_targets,
+            tag_pattern: raw.tag_pattern,
             changelog_target: raw.changelog_target,
             changelog_next_section_label: raw.changelog_next_section_label,
             changelog_next_section_aliases: raw.changelog_next_section_aliases,
+            changelog_format: raw.changelog_format,
             hooks,
             extract_command: raw.extract_command,
             remote_owner: raw.remote_owner,
@@ -340,6 +373,7 @@ impl From<RawComponent> for Component {
             dependency_stack: raw.dependency_stack,
             cli_path: raw.cli_path,
             extra_drift_files: raw.extra_drift_files,
+            archived: raw.archived,
         }
     }
 }
@@ -354,9 +388,11 @@ impl From<Component> for RawComponent {
             build_artifact: c.build_artifact,
             extensions: c.extensions,
             version_targets: c.version_targets,
+            tag_pattern: c.tag_pattern,
             changelog_target: c.changelog_target,
             changelog_next_section_label: c.changelog_next_section_label,
             changelog_next_section_aliases: c.changelog_next_section_aliases,
+            changelog_format: c.changelog_format,
             hooks: c.hooks,
             pre_version_bump_commands: Vec::new(),
             post_version_bump_commands: Vec::new(),
@@ -378,6 +414,7 @@ impl From<Component> for RawComponent {
             dependency_stack: c.dependency_stack,
             cli_path: c.cli_path,
             extra_drift_files: c.extra_drift_files,
+            archived: c.archived,
         }
     }
 }
@@ -432,9 +469,11 @@ impl Component {
             build_artifact,
             extensions: None,
             version_targets: None,
+            tag_pattern: None,
             changelog_target: None,
             changelog_next_section_label: None,
             changelog_next_section_aliases: None,
+            changelog_format: None,
             hooks: HashMap::new(),
             extract_command: None,
             remote_owner: None,
@@ -453,6 +492,7 @@ impl Component {
             dependency_stack: Vec::new(),
             cli_path: None,
             extra_drift_files: Vec::new(),
+            archived: false,
         }
     }
 
@@ -473,33 +513,53 @@ impl Component {
             return None;
         }
 
+        let matches: HashSet<String> = self
+            .remote_path_inference_matches()
+            .into_iter()
+            .map(|(_, path)| path)
+            .collect();
+
+        if matches.len() == 1 {
+            matches.into_iter().next()
+        } else {
+            None
+        }
+    }
+
+    /// Every remote path the linked extensions' inference rules produce for
+    /// this component, as `(extension_id, remote_path)` in extension-ID order.
+    ///
+    /// [`auto_resolve_remote_path`](Self::auto_resolve_remote_path) only
+    /// accepts a single distinct path; deploy plans use the full list to
+    /// explain why inference was ambiguous.
+    pub fn remote_path_inference_matches(&self) -> Vec<(String, String)> {
         let local = std::path::Path::new(&self.local_path);
+        let Some(dir_name) = local.file_name().and_then(|name| name.to_str()) else {
+            return Vec::new();
+        };
+        let Some(extensions) = self.extensions.as_ref() else {
+            return Vec::new();
+        };
 
-        // Use the directory basename as the remote directory name.
-        let dir_name = local.file_name()?.to_str()?;
+        let mut extension_ids: Vec<&String> = extensions.keys().collect();
+        extension_ids.sort();
 
-        let mut matches = HashSet::new();
-        for extension_id in self.extensions.as_ref()?.keys() {
+        let mut matches = Vec::new();
+        for extension_id in extension_ids {
             let Ok(extension) = crate::extension::load_extension(extension_id) else {
                 continue;
             };
 
             for rule in extension.remote_path_inference_rules() {
                 if self.remote_path_inference_rule_matches(rule, local, dir_name) {
-                    matches.insert(render_remote_path_template(
-                        &rule.remote_path,
-                        &self.id,
-                        dir_name,
+                    matches.push((
+                        extension_id.clone(),
+                        render_remote_path_template(&rule.remote_path, &self.id, dir_name),
                     ));
                 }
             }
         }
-
-        if matches.len() == 1 {
-            matches.into_iter().next()
-        } else {
-            None
-        }
+        matches
     }
 
     fn remote_path_inference_rule_matches(
@@ -577,6 +637,24 @@ impl Component {
         !self.script_commands(capability).is_empty()
     }
 
+    /// Refuse to `action` (build, deploy) an archived component unless `force`.
+    pub fn ensure_active(&self, action: &str, force: bool) -> crate::error::Result<()> {
+        if !self.archived || force {
+            return Ok(());
+        }
+        Err(crate::error::Error::validation_invalid_argument(
+            "component",
+            format!("Component '{}' is archived", self.id),
+            Some(self.id.clone()),
+            None,
+        )
+        .with_hint(format!("Pass --force to {} it anyway", action))
+        .with_hint(format!(
+            "Restore it: homeboy component unarchive {}",
+            self.id
+        )))
+    }
+
     /// Ensure `remote_path` is populated. If empty, attempt auto-resolution.
     ///
     /// This should be called after all config layers (repo portable, project overrides)
@@ -16,7 +16,20 @@ pub fn set_changelog_target(component_id: &str, file_path: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn merge(id: Option<&str>, json_spec: &str, replace_fields: &[String]) -> Result<MergeOutput> {
+/// Archive or restore a component. Its config is kept either way.
+pub fn set_archived(component_id: &str, archived: bool) -> Result<Component> {
+    mutate_portable(component_id, |component| {
+        component.archived = archived;
+        Ok(())
+    })
+}
+
+pub fn merge(
+    id: Option<&str>,
+    json_spec: &str,
+    replace_fields: &[String],
+    unset_fields: &[String],
+) -> Result<MergeOutput> {
     let id = id.ok_or_else(|| {
         Error::validation_invalid_argument(
             "component_id",
@@ -41,7 +54,7 @@ pub fn merge(id: Option<&str>, json_spec: &str, replace_fields: &[String]) -> Re
     if let Some(json_id) = patch.get("id").and_then(|v| v.as_str()) {
         if json_id != id {
             rename(id, json_id)?;
-            return merge(Some(json_id), json_spec, replace_fields);
+            return merge(Some(json_id), json_spec, replace_fields, unset_fields);
         }
     }
 
@@ -52,25 +65,31 @@ pub fn merge(id: Option<&str>, json_spec: &str, replace_fields: &[String]) -> Re
         map.remove("id");
     }
 
-    let component = mutate_portable(id, |component| {
-        let fields = config::merge_config(component, patch.clone(), replace_fields)?;
-        if fields.updated_fields.is_empty() {
-            return Err(Error::validation_invalid_argument(
-                "merge",
-                "Merge patch cannot be empty",
-                None,
-                None,
-            ));
+    let unset_only = !unset_fields.is_empty() && patch.as_object().is_some_and(|p| p.is_empty());
+    let mut unset = Vec::new();
+    mutate_portable(id, |component| {
+        if !unset_only {
+            let fields = config::merge_config(component, patch.clone(), replace_fields)?;
+            if fields.updated_fields.is_empty() {
+                return Err(Error::validation_invalid_argument(
+                    "merge",
+                    "Merge patch cannot be empty",
+                    None,
+                    None,
+                ));
+            }
         }
+        // Applied after the merge so an unset always wins over a patch value.
+        unset = config::unset_config(component, unset_fields)?;
         Ok(())
     })?;
 
-    let updated_fields = match patch {
+    let mut updated_fields: Vec<String> = match patch {
         Value::Object(obj) => obj.keys().cloned().collect(),
         _ => vec![],
     };
+    updated_fields.extend(unset);
 
-    let _ = component;
     Ok(MergeOutput::Single(MergeResult {
         id: id.to_string(),
         updated_fields,
@@ -527,7 +527,7 @@ mod tests {
             .unwrap();
 
             let patch = r#"{"remote_url":"/Users/chubes/Developer/homeboy"}"#;
-            let result = crate::component::mutations::merge(Some("my-comp"), patch, &[]);
+            let result = crate::component::mutations::merge(Some("my-comp"), patch, &[], &[]);
 
             assert!(result.is_err());
             assert_eq!(
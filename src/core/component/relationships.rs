@@ -3,22 +3,23 @@ use crate::error::Result;
 use crate::project;
 
 fn update_project_references(old_id: &str, new_id: &str) -> Result<()> {
-    let projects = project::list().unwrap_or_default();
-    for proj in projects {
-        if project::has_component(&proj, old_id) {
-            let updated_components: Vec<project::ProjectComponentAttachment> = proj
-                .components
-                .into_iter()
-                .map(|mut component| {
-                    if component.id == old_id {
-                        component.id = new_id.to_string();
-                    }
-                    component
-                })
-                .collect();
-            project::set_component_attachments(&proj.id, updated_components)?;
+    project::rewrite_project_references(false, |proj| {
+        let mut changed = false;
+        for component in &mut proj.components {
+            if component.id == old_id {
+                component.id = new_id.to_string();
+                changed = true;
+            }
         }
-    }
+        if changed {
+            let mut seen = std::collections::HashSet::new();
+            proj.components
+                .retain(|component| seen.insert(component.id.clone()));
+            vec!["components".to_string()]
+        } else {
+            Vec::new()
+        }
+    })?;
     Ok(())
 }
 
@@ -41,6 +42,23 @@ pub fn projects_using(component_id: &str) -> Result<Vec<String>> {
         .collect())
 }
 
+/// Components whose `extensions` include `extension_id`, sorted.
+pub fn components_using_extension(extension_id: &str) -> Result<Vec<String>> {
+    let mut ids: Vec<String> = crate::component::list()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|component| {
+            component
+                .extensions
+                .as_ref()
+                .is_some_and(|extensions| extensions.contains_key(extension_id))
+        })
+        .map(|component| component.id)
+        .collect();
+    ids.sort();
+    Ok(ids)
+}
+
 /// Returns a map of component_id -> Vec<project_id> for all components used by projects.
 pub fn shared_components() -> Result<std::collections::HashMap<String, Vec<String>>> {
     let projects = project::list().unwrap_or_default();
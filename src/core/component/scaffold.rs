@@ -0,0 +1,233 @@
+//! Propose a starter component config by inspecting a checkout on disk.
+//!
+//! Ecosystem knowledge stays in extension manifests: a directory is matched
+//! against each installed extension's `discovery_markers`, and matched
+//! extensions are attached so their build/lint/test capabilities apply.
+//! Core only detects generic layout (docs dirs, changelog location).
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{portable, Component, ScopedExtensionConfig};
+use crate::error::{Error, Result};
+use crate::extension::{self, ExtensionManifest};
+
+/// Directory names treated as documentation roots when present.
+const DOCS_DIR_CANDIDATES: &[&str] = &["docs", "doc", "documentation"];
+
+/// What scaffold detected in the directory, reported alongside the proposal.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ScaffoldDetection {
+    /// Extensions whose discovery markers match the directory.
+    pub extensions: Vec<String>,
+    /// Matched extensions that declare a build capability.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub build_extensions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub docs_dirs: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changelog_target: Option<String>,
+    /// True when the directory already has a `homeboy.json`; the proposal
+    /// then only fills fields the existing config leaves unset.
+    pub existing_config: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ScaffoldProposal {
+    pub component: Component,
+    pub detected: ScaffoldDetection,
+}
+
+/// Inspect `dir` against all installed extensions and propose a component config.
+pub fn scaffold(dir: &Path) -> Result<ScaffoldProposal> {
+    let extensions = extension::load_all_extensions().unwrap_or_default();
+    propose(dir, &extensions)
+}
+
+/// Propose a component config for `dir` using the given extension manifests.
+pub fn propose(dir: &Path, extensions: &[ExtensionManifest]) -> Result<ScaffoldProposal> {
+    if !dir.is_dir() {
+        return Err(Error::validation_invalid_argument(
+            "path",
+            format!("Not a directory: {}", dir.display()),
+            None,
+            None,
+        ));
+    }
+
+    let matched = crate::context::extension_suggestions_from_manifests(dir, extensions);
+    let build_extensions: Vec<String> = extensions
+        .iter()
+        .filter(|manifest| manifest.build.is_some() && matched.contains(&manifest.id))
+        .map(|manifest| manifest.id.clone())
+        .collect();
+    let docs_dirs: Vec<String> = DOCS_DIR_CANDIDATES
+        .iter()
+        .filter(|name| dir.join(name).is_dir())
+        .map(|name| name.to_string())
+        .collect();
+    let changelog_target = crate::release::changelog::discover_changelog_relative_path(dir);
+
+    let existing = portable::discover_from_portable(dir);
+    let existing_config = existing.is_some();
+    let mut component = match existing {
+        Some(component) => component,
+        None => Component::new(
+            derive_component_id(dir)?,
+            dir.to_string_lossy().to_string(),
+            String::new(),
+            None,
+        ),
+    };
+
+    if component.extensions.as_ref().is_none_or(HashMap::is_empty) && !matched.is_empty() {
+        component.extensions = Some(
+            matched
+                .iter()
+                .map(|id| (id.clone(), ScopedExtensionConfig::default()))
+                .collect(),
+        );
+    }
+    if component.docs_dirs.is_empty() && component.docs_dir.is_none() {
+        component.docs_dirs = docs_dirs.clone();
+    }
+    if component.changelog_target.is_none() {
+        component.changelog_target = changelog_target.clone();
+    }
+
+    Ok(ScaffoldProposal {
+        component,
+        detected: ScaffoldDetection {
+            extensions: matched,
+            build_extensions,
+            docs_dirs,
+            changelog_target,
+            existing_config,
+        },
+    })
+}
+
+/// Component ID for a checkout: the `homeboy.json` `id` when present,
+/// otherwise the slugified directory name.
+pub fn derive_component_id(dir: &Path) -> Result<String> {
+    if dir.join("homeboy.json").exists() {
+        return portable::infer_portable_component_id(dir);
+    }
+
+    let dir_name = dir.file_name().and_then(|n| n.to_str()).ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "local_path",
+            "Could not derive component ID from local path",
+            Some(dir.display().to_string()),
+            None,
+        )
+    })?;
+
+    crate::engine::identifier::slugify_id(dir_name, "component_id")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(id: &str, markers: serde_json::Value, build: bool) -> ExtensionManifest {
+        let mut value = serde_json::json!({
+            "name": id,
+            "version": "1.0.0",
+            "provides": { "discovery_markers": markers }
+        });
+        if build {
+            value["build"] = serde_json::json!({ "script_names": ["build.sh"] });
+        }
+        let mut manifest: ExtensionManifest =
+            serde_json::from_value(value).expect("manifest parses");
+        manifest.id = id.to_string();
+        manifest
+    }
+
+    fn fixture_extensions() -> Vec<ExtensionManifest> {
+        vec![
+            manifest("marker-a", serde_json::json!([{ "all": ["a.toml"] }]), true),
+            manifest(
+                "marker-b",
+                serde_json::json!([{ "all": ["b.json"] }]),
+                false,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_propose() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let dir = root.path().join("My Widget");
+        std::fs::create_dir_all(dir.join("docs")).expect("docs dir");
+        std::fs::write(dir.join("a.toml"), "").expect("marker");
+        std::fs::write(dir.join("CHANGELOG.md"), "# Changelog\n").expect("changelog");
+
+        let proposal = propose(&dir, &fixture_extensions()).expect("proposal");
+
+        assert_eq!(proposal.component.id, "my-widget");
+        assert_eq!(proposal.component.local_path, dir.to_string_lossy());
+        assert_eq!(
+            proposal.component.changelog_target.as_deref(),
+            Some("CHANGELOG.md")
+        );
+        assert_eq!(proposal.component.docs_dirs, vec!["docs"]);
+        let extensions = proposal.component.extensions.expect("extensions");
+        assert_eq!(extensions.keys().collect::<Vec<_>>(), vec!["marker-a"]);
+        assert_eq!(proposal.detected.build_extensions, vec!["marker-a"]);
+        assert!(!proposal.detected.existing_config);
+    }
+
+    #[test]
+    fn propose_keeps_existing_config_fields() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let dir = root.path().join("widget");
+        std::fs::create_dir_all(&dir).expect("dir");
+        std::fs::write(dir.join("b.json"), "{}").expect("marker");
+        std::fs::write(dir.join("CHANGELOG.md"), "# Changelog\n").expect("changelog");
+        std::fs::write(
+            dir.join("homeboy.json"),
+            r#"{"id":"widget-core","changelog_target":"docs/CHANGES.md"}"#,
+        )
+        .expect("homeboy.json");
+
+        let proposal = propose(&dir, &fixture_extensions()).expect("proposal");
+
+        assert!(proposal.detected.existing_config);
+        assert_eq!(proposal.component.id, "widget-core");
+        assert_eq!(
+            proposal.component.changelog_target.as_deref(),
+            Some("docs/CHANGES.md")
+        );
+        assert_eq!(
+            proposal.detected.changelog_target.as_deref(),
+            Some("CHANGELOG.md")
+        );
+        assert!(proposal
+            .component
+            .extensions
+            .expect("extensions")
+            .contains_key("marker-b"));
+        assert!(proposal.detected.build_extensions.is_empty());
+    }
+
+    #[test]
+    fn propose_rejects_missing_directory() {
+        let root = tempfile::tempdir().expect("tempdir");
+
+        let err = propose(&root.path().join("nope"), &[]).unwrap_err();
+
+        assert_eq!(err.code.as_str(), "validation.invalid_argument");
+    }
+
+    #[test]
+    fn test_derive_component_id() {
+        let root = tempfile::tempdir().expect("tempdir");
+        let dir = root.path().join("Some_Repo");
+        std::fs::create_dir_all(&dir).expect("dir");
+
+        assert_eq!(derive_component_id(&dir).expect("id"), "some-repo");
+    }
+}
@@ -13,6 +13,11 @@ use serde_json::{Map, Value};
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
+mod lock;
+
+pub(crate) use lock::ConfigLock;
+pub use lock::{lock_timeout_secs, set_lock_timeout_secs, DEFAULT_LOCK_TIMEOUT_SECS};
+
 // ============================================================================
 // JSON Parsing Utilities (internal)
 // ============================================================================
@@ -488,6 +493,64 @@ pub(crate) fn merge_config<T: Serialize + DeserializeOwned>(
     Ok(MergeFields { updated_fields })
 }
 
+/// Delete the fields at dotted `paths` (`build_command`, `auth.mode`) from
+/// any serializable config type and return the paths removed.
+///
+/// A path that is not set, or whose removal leaves the config unable to
+/// deserialize (a required field), is rejected and nothing is removed.
+pub(crate) fn unset_config<T: Serialize + DeserializeOwned>(
+    existing: &mut T,
+    paths: &[String],
+) -> Result<Vec<String>> {
+    let mut base = serde_json::to_value(&*existing)
+        .map_err(|e| Error::internal_json(e.to_string(), Some("serialize config".to_string())))?;
+
+    let mut removed = Vec::new();
+    for path in paths {
+        let mut segments: Vec<String> = path.split('.').map(str::to_string).collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(Error::validation_invalid_argument(
+                "unset",
+                format!("Invalid field path '{}'", path),
+                Some(path.clone()),
+                None,
+            ));
+        }
+        // Top-level keys are struct fields; accept them in any case like merges do.
+        segments[0] = segments[0].to_snake_case();
+
+        let (key, parents) = segments.split_last().expect("split yields a segment");
+        let removed_value = parents
+            .iter()
+            .try_fold(&mut base, |value, segment| value.get_mut(segment.as_str()))
+            .and_then(Value::as_object_mut)
+            .and_then(|parent| parent.remove(key));
+        if removed_value.is_none() {
+            return Err(Error::validation_invalid_argument(
+                "unset",
+                format!("Field '{}' is not set", path),
+                Some(path.clone()),
+                None,
+            ));
+        }
+        if let Err(e) = serde_json::from_value::<T>(base.clone()) {
+            return Err(Error::validation_invalid_argument(
+                "unset",
+                format!("Cannot unset required field '{}': {}", path, e),
+                Some(path.clone()),
+                None,
+            )
+            .with_hint("Required fields can be given a new value but not removed"));
+        }
+        removed.push(segments.join("."));
+    }
+
+    *existing = serde_json::from_value(base)
+        .map_err(|e| Error::validation_invalid_json(e, Some("unset config".to_string()), None))?;
+
+    Ok(removed)
+}
+
 /// Internal result from remove_config (no ID, caller adds it).
 pub(crate) struct RemoveFields {
     pub removed_from: Vec<String>,
@@ -728,10 +791,74 @@ pub(crate) trait ConfigEntity: Serialize + DeserializeOwned {
     }
 }
 
+/// Config entities that carry free-form grouping tags (`prod`, `staging`).
+///
+/// Implementing this and adding the `tags` feature to `entity_crud!` gives the
+/// entity `list_tagged`, `add_tags`, and `remove_tags`.
+pub(crate) trait Tagged: ConfigEntity {
+    fn tags(&self) -> &[String];
+    fn tags_mut(&mut self) -> &mut Vec<String>;
+}
+
+/// Whether `tags` contains every tag in `wanted`. Comparison is
+/// case-insensitive; an empty `wanted` matches everything.
+pub fn has_all_tags(tags: &[String], wanted: &[String]) -> bool {
+    wanted
+        .iter()
+        .all(|w| tags.iter().any(|t| t.eq_ignore_ascii_case(w.trim())))
+}
+
+pub(crate) fn list_tagged<T: Tagged>(wanted: &[String]) -> Result<Vec<T>> {
+    Ok(list::<T>()?
+        .into_iter()
+        .filter(|entity| has_all_tags(entity.tags(), wanted))
+        .collect())
+}
+
+/// Add and remove tags on a stored entity, then save it, holding the config
+/// lock from load through save. Tags are trimmed and lowercased; adding a tag
+/// that is already present is a no-op.
+pub(crate) fn update_tags<T: Tagged>(id: &str, add: &[String], remove: &[String]) -> Result<T> {
+    let add = add
+        .iter()
+        .map(|tag| normalize_tag(tag))
+        .collect::<Result<Vec<_>>>()?;
+    let remove = remove
+        .iter()
+        .map(|tag| normalize_tag(tag))
+        .collect::<Result<Vec<_>>>()?;
+
+    let _lock = ConfigLock::acquire()?;
+    let mut entity = load::<T>(id)?;
+    let tags = entity.tags_mut();
+    tags.retain(|tag| !remove.contains(tag));
+    for tag in add {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    save(&entity)?;
+    Ok(entity)
+}
+
+fn normalize_tag(tag: &str) -> Result<String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() || tag.contains(|c: char| c.is_whitespace() || c == ',') {
+        return Err(Error::validation_invalid_argument(
+            "tag",
+            format!("Invalid tag '{}': tags must be non-empty words", tag),
+            None,
+            Some(vec!["Use a single word like prod or staging".to_string()]),
+        ));
+    }
+    Ok(tag)
+}
+
 pub(crate) struct ConfigEntityMetadata {
     entity_type: &'static str,
     exists: fn(&str) -> bool,
     aliases: fn() -> Vec<(String, String)>,
+    invalid: fn() -> Vec<(String, String)>,
 }
 
 fn entity_metadata<T: ConfigEntity>() -> ConfigEntityMetadata {
@@ -739,9 +866,50 @@ fn entity_metadata<T: ConfigEntity>() -> ConfigEntityMetadata {
         entity_type: T::ENTITY_TYPE,
         exists: exists::<T>,
         aliases: alias_entries::<T>,
+        invalid: invalid_entries::<T>,
     }
 }
 
+/// A config file on disk that does not load.
+#[derive(Debug, Clone, Serialize)]
+pub struct InvalidConfigEntity {
+    pub entity_type: &'static str,
+    pub id: String,
+    pub error: String,
+}
+
+/// Every project, server, runner, extension, and fleet config that fails to
+/// load. `list` skips these with a warning; this names them.
+pub fn invalid_entities() -> Vec<InvalidConfigEntity> {
+    config_entity_registry()
+        .iter()
+        .flat_map(|metadata| {
+            (metadata.invalid)()
+                .into_iter()
+                .map(|(id, error)| InvalidConfigEntity {
+                    entity_type: metadata.entity_type,
+                    id,
+                    error,
+                })
+        })
+        .collect()
+}
+
+fn invalid_entries<T: ConfigEntity>() -> Vec<(String, String)> {
+    list_ids::<T>()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|id| {
+            let err = load::<T>(&id).err()?;
+            let detail = err.details.get("error").and_then(|value| value.as_str());
+            Some(match detail {
+                Some(detail) => (id, format!("{}: {}", err.message, detail)),
+                None => (id, err.message),
+            })
+        })
+        .collect()
+}
+
 fn config_entity_registry() -> [ConfigEntityMetadata; 5] {
     [
         entity_metadata::<crate::project::Project>(),
@@ -767,6 +935,7 @@ fn alias_entries<T: ConfigEntity>() -> Vec<(String, String)> {
 }
 
 pub(crate) fn load<T: ConfigEntity>(id: &str) -> Result<T> {
+    let _span = crate::profile::span("config.load");
     let path = T::config_path(id)?;
     if !path.exists() {
         // Try alias resolution before giving up
@@ -803,6 +972,7 @@ fn resolve_alias<T: ConfigEntity>(alias: &str) -> Option<String> {
 }
 
 pub(crate) fn list<T: ConfigEntity>() -> Result<Vec<T>> {
+    let _span = crate::profile::span("config.list");
     let dir = T::config_dir()?;
     let entries = local_files::local().list(&dir)?;
 
@@ -903,6 +1073,7 @@ fn check_alias_collision_all(id: &str, saving_type: &str) -> Result<()> {
 }
 
 pub(crate) fn save<T: ConfigEntity>(entity: &T) -> Result<()> {
+    let _lock = ConfigLock::acquire()?;
     identifier::validate_component_id(entity.id())?;
     check_id_collision(entity.id(), T::entity_type())?;
 
@@ -921,6 +1092,7 @@ pub(crate) fn save<T: ConfigEntity>(entity: &T) -> Result<()> {
 /// Internal: create a single entity from a constructed struct.
 /// Validates ID, checks for existence, runs entity-specific validation, then saves.
 fn create_single<T: ConfigEntity>(entity: T) -> Result<CreateResult<T>> {
+    let _lock = ConfigLock::acquire()?;
     identifier::validate_component_id(entity.id())?;
     entity.validate()?;
 
@@ -984,6 +1156,7 @@ pub(crate) fn create<T: ConfigEntity>(
 }
 
 pub(crate) fn delete<T: ConfigEntity>(id: &str) -> Result<()> {
+    let _lock = ConfigLock::acquire()?;
     let path = T::config_path(id)?;
     if !path.exists() {
         let suggestions = find_similar_ids::<T>(id);
@@ -1054,10 +1227,19 @@ pub(crate) fn merge<T: ConfigEntity>(
     id: Option<&str>,
     json_spec: &str,
     replace_fields: &[String],
+    unset_fields: &[String],
 ) -> Result<MergeOutput> {
     let raw = read_json_spec_to_string(json_spec)?;
 
     if is_json_array(&raw) {
+        if !unset_fields.is_empty() {
+            return Err(Error::validation_invalid_argument(
+                "unset",
+                "--unset cannot be combined with a bulk (JSON array) merge",
+                None,
+                None,
+            ));
+        }
         return Ok(MergeOutput::Bulk(merge_batch_from_json::<T>(&raw)?));
     }
 
@@ -1065,6 +1247,7 @@ pub(crate) fn merge<T: ConfigEntity>(
         id,
         &raw,
         replace_fields,
+        unset_fields,
     )?))
 }
 
@@ -1078,6 +1261,7 @@ pub(crate) fn create_batch<T: ConfigEntity>(
     spec: &str,
     skip_existing: bool,
 ) -> Result<BatchResult> {
+    let _lock = ConfigLock::acquire()?;
     let value: serde_json::Value = from_str(spec)?;
     let items: Vec<serde_json::Value> = if value.is_array() {
         value.as_array().expect("is_array() returned true").clone()
@@ -1146,6 +1330,7 @@ pub(crate) fn merge_from_json<T: ConfigEntity>(
     id: Option<&str>,
     json_spec: &str,
     replace_fields: &[String],
+    unset_fields: &[String],
 ) -> Result<MergeResult> {
     let raw = read_json_spec_to_string(json_spec)?;
     let mut parsed: serde_json::Value = from_str(&raw)?;
@@ -1169,18 +1354,28 @@ pub(crate) fn merge_from_json<T: ConfigEntity>(
         obj.remove("id");
     }
 
+    let _lock = ConfigLock::acquire()?;
     let mut entity = load::<T>(&effective_id)?;
-    let result = merge_config(&mut entity, parsed, replace_fields)?;
+    // An `--unset`-only set has nothing to merge.
+    let unset_only = !unset_fields.is_empty() && parsed.as_object().is_some_and(Map::is_empty);
+    let mut updated_fields = if unset_only {
+        Vec::new()
+    } else {
+        merge_config(&mut entity, parsed, replace_fields)?.updated_fields
+    };
+    // Applied after the merge so an unset always wins over a patch value.
+    updated_fields.extend(unset_config(&mut entity, unset_fields)?);
     entity.set_id(effective_id.clone());
     save(&entity)?;
 
     Ok(MergeResult {
         id: effective_id,
-        updated_fields: result.updated_fields,
+        updated_fields,
     })
 }
 
 pub(crate) fn merge_batch_from_json<T: ConfigEntity>(raw_json: &str) -> Result<BatchResult> {
+    let _lock = ConfigLock::acquire()?;
     let value: serde_json::Value = from_str(raw_json)?;
 
     let items: Vec<serde_json::Value> = if value.is_array() {
@@ -1258,6 +1453,7 @@ pub(crate) fn remove_from_json<T: ConfigEntity>(
         obj.remove("id");
     }
 
+    let _lock = ConfigLock::acquire()?;
     let mut entity = load::<T>(&effective_id)?;
     let result = remove_config(&mut entity, parsed)?;
     save(&entity)?;
@@ -1268,7 +1464,9 @@ pub(crate) fn remove_from_json<T: ConfigEntity>(
     })
 }
 
-pub(crate) fn rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
+/// Validate that `id` can be renamed to `new_id` without touching disk:
+/// the new ID is well-formed and collides with no existing entity.
+pub(crate) fn check_rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
     let new_id = new_id.to_lowercase();
     identifier::validate_component_id(&new_id)?;
 
@@ -1276,10 +1474,7 @@ pub(crate) fn rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
         return Ok(());
     }
 
-    let old_path = T::config_path(id)?;
-    let new_path = T::config_path(&new_id)?;
-
-    if new_path.exists() {
+    if T::config_path(&new_id)?.exists() {
         return Err(Error::validation_invalid_argument(
             format!("{}.id", T::entity_type()),
             format!(
@@ -1294,7 +1489,19 @@ pub(crate) fn rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
     }
 
     // Check cross-entity name collision
-    check_id_collision(&new_id, T::entity_type())?;
+    check_id_collision(&new_id, T::entity_type())
+}
+
+pub(crate) fn rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
+    let _lock = ConfigLock::acquire()?;
+    check_rename::<T>(id, new_id)?;
+    let new_id = new_id.to_lowercase();
+    if new_id == id {
+        return Ok(());
+    }
+
+    let old_path = T::config_path(id)?;
+    let new_path = T::config_path(&new_id)?;
 
     let mut entity: T = load(id)?;
     entity.set_id(new_id.clone());
@@ -1356,6 +1563,7 @@ pub(crate) fn rename<T: ConfigEntity>(id: &str, new_id: &str) -> Result<()> {
 /// Verifies the entity exists, checks for dependents via the trait hook,
 /// and only deletes if nothing references it.
 pub(crate) fn delete_safe<T: ConfigEntity>(id: &str) -> Result<()> {
+    let _lock = ConfigLock::acquire()?;
     if !exists::<T>(id) {
         let suggestions = find_similar_ids::<T>(id);
         return Err(T::not_found_error(id.to_string(), suggestions));
@@ -1454,6 +1662,8 @@ pub(crate) fn find_similar_ids<T: ConfigEntity>(target: &str) -> Vec<String> {
 /// - `merge` — generates the standard `merge()` one-liner (entities with
 ///   custom merge logic should omit this and implement their own)
 /// - `slugify_id` — generates `slugify_id(name) -> Result<String>`
+/// - `tags` — generates `list_tagged`, `add_tags`, and `remove_tags`
+///   (requires a `Tagged` impl)
 ///
 /// # Examples
 ///
@@ -1528,8 +1738,24 @@ macro_rules! entity_crud {
             id: Option<&str>,
             json_spec: &str,
             replace_fields: &[String],
+            unset_fields: &[String],
         ) -> Result<MergeOutput> {
-            config::merge::<$Entity>(id, json_spec, replace_fields)
+            config::merge::<$Entity>(id, json_spec, replace_fields, unset_fields)
+        }
+    };
+
+    // Feature: tags
+    (@feature $Entity:ty, tags) => {
+        pub fn list_tagged(tags: &[String]) -> Result<Vec<$Entity>> {
+            config::list_tagged::<$Entity>(tags)
+        }
+
+        pub fn add_tags(id: &str, tags: &[String]) -> Result<$Entity> {
+            config::update_tags::<$Entity>(id, tags, &[])
+        }
+
+        pub fn remove_tags(id: &str, tags: &[String]) -> Result<$Entity> {
+            config::update_tags::<$Entity>(id, &[], tags)
         }
     };
 
@@ -1580,6 +1806,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unset_config_removes_nested_and_top_level_fields() {
+        let mut config = TestConfig {
+            name: "test".to_string(),
+            description: Some("hello".to_string()),
+            extensions: Some(
+                [
+                    ("wordpress".to_string(), serde_json::json!({})),
+                    ("rust".to_string(), serde_json::json!({})),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        };
+
+        let removed = unset_config(
+            &mut config,
+            &["description".to_string(), "extensions.rust".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(removed, vec!["description", "extensions.rust"]);
+        assert_eq!(config.description, None);
+        let extensions = config.extensions.unwrap();
+        assert!(extensions.contains_key("wordpress"));
+        assert!(!extensions.contains_key("rust"));
+    }
+
+    #[test]
+    fn unset_config_rejects_required_and_missing_fields() {
+        let mut config = TestConfig {
+            name: "test".to_string(),
+            ..Default::default()
+        };
+
+        let required = unset_config(&mut config, &["name".to_string()]).unwrap_err();
+        assert!(required.message.contains("required field 'name'"));
+        assert_eq!(config.name, "test");
+
+        let missing = unset_config(&mut config, &["description".to_string()]).unwrap_err();
+        assert!(missing.message.contains("'description' is not set"));
+    }
+
     #[test]
     fn merge_config_accepts_known_fields() {
         let mut config = TestConfig {
@@ -9,9 +9,11 @@ use crate::project::{self, Project};
 use crate::server::SshClient;
 use crate::server::{self, Server};
 
+pub mod repo;
 pub mod report;
 
-pub use report::{build_report, build_report_for_component};
+pub use repo::{diff_refs, repo_context_at, RepoContext, RepoContextDiff};
+pub use report::{build_report, build_report_for_component, build_report_in};
 
 // === Local Context Detection (homeboy context command) ===
 
@@ -41,6 +43,17 @@ pub struct ProjectContext {
     pub domain: Option<String>,
 }
 
+/// A registered component the inspected directory sits inside.
+#[derive(Debug, Clone, Serialize)]
+
+pub struct ComponentMapping {
+    pub component_id: String,
+    pub local_path: String,
+    /// Projects the component is attached to.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 
 pub struct ContextOutput {
@@ -50,7 +63,11 @@ pub struct ContextOutput {
     pub git_root: Option<String>,
     pub managed: bool,
     pub matched_components: Vec<String>,
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mappings: Vec<ComponentMapping>,
+    /// Every registered component below the directory, for workspace and
+    /// monorepo roots.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub contained_components: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<ProjectContext>,
@@ -62,7 +79,7 @@ pub struct ContextOutput {
 /// Returns info about git root, matched components, and whether directory is managed.
 pub fn run(path: Option<&str>) -> Result<(ContextOutput, i32)> {
     let cwd = match path {
-        Some(p) => PathBuf::from(p),
+        Some(p) => resolve_context_dir(p)?,
         None => std::env::current_dir().map_err(|e| Error::internal_io(e.to_string(), None))?,
     };
 
@@ -76,13 +93,23 @@ pub fn run(path: Option<&str>) -> Result<(ContextOutput, i32)> {
         .map(|c| c.id.clone())
         .collect();
 
-    let matched: Vec<String> = matched_components
+    let mut matched: Vec<String> = matched_components
         .into_iter()
         .collect::<HashSet<_>>()
         .into_iter()
         .collect();
+    matched.sort();
 
     let managed = !matched.is_empty();
+    let mappings: Vec<ComponentMapping> = matched
+        .iter()
+        .filter_map(|id| components.iter().find(|c| c.id == *id))
+        .map(|c| ComponentMapping {
+            component_id: c.id.clone(),
+            local_path: c.local_path.clone(),
+            projects: component::projects_using(&c.id).unwrap_or_default(),
+        })
+        .collect();
 
     // Check for contained components (monorepo pattern)
     let all_local_components: Vec<component::Component> = components;
@@ -92,7 +119,8 @@ pub fn run(path: Option<&str>) -> Result<(ContextOutput, i32)> {
         .filter(|c| path_is_parent_of(&cwd, &c.local_path))
         .collect();
 
-    let contained_ids: Vec<String> = contained.iter().map(|c| c.id.clone()).collect();
+    let mut contained_ids: Vec<String> = contained.iter().map(|c| c.id.clone()).collect();
+    contained_ids.sort();
 
     // Find project if all contained components belong to one
     let project_ctx = if !contained_ids.is_empty() {
@@ -190,6 +218,7 @@ pub fn run(path: Option<&str>) -> Result<(ContextOutput, i32)> {
             git_root,
             managed,
             matched_components: matched,
+            mappings,
             contained_components: contained_ids,
             project: project_ctx,
             suggestion,
@@ -198,6 +227,27 @@ pub fn run(path: Option<&str>) -> Result<(ContextOutput, i32)> {
     ))
 }
 
+/// Resolve an explicit context directory, relative to the current one.
+fn resolve_context_dir(path: &str) -> Result<PathBuf> {
+    let expanded = PathBuf::from(shellexpand::tilde(path).as_ref());
+    let dir = if expanded.is_absolute() {
+        expanded
+    } else {
+        std::env::current_dir()
+            .map_err(|e| Error::internal_io(e.to_string(), None))?
+            .join(expanded)
+    };
+    if !dir.is_dir() {
+        return Err(Error::validation_invalid_argument(
+            "cwd",
+            format!("'{}' is not a directory", path),
+            Some(path.to_string()),
+            None,
+        ));
+    }
+    Ok(dir)
+}
+
 fn detect_git_root(cwd: &PathBuf) -> Option<String> {
     let output = std::process::Command::new("git")
         .args(["rev-parse", "--show-toplevel"])
@@ -361,7 +411,7 @@ fn extension_suggestions_for_path(local_path: &Path) -> Vec<String> {
         .unwrap_or_default()
 }
 
-fn extension_suggestions_from_manifests(
+pub(crate) fn extension_suggestions_from_manifests(
     local_path: &Path,
     extensions: &[ExtensionManifest],
 ) -> Vec<String> {
@@ -487,6 +537,40 @@ mod tests {
         manifest
     }
 
+    #[test]
+    fn test_run_with_explicit_path() {
+        crate::test_support::with_isolated_home(|home| {
+            let workspace = home.path().join("workspace");
+            let components = home.path().join(".config/homeboy/components");
+            std::fs::create_dir_all(&components).expect("components dir");
+            for id in ["beta", "alpha"] {
+                let local_path = workspace.join(id);
+                std::fs::create_dir_all(local_path.join("src")).expect("component dir");
+                std::fs::write(
+                    components.join(format!("{}.json", id)),
+                    serde_json::json!({ "local_path": local_path }).to_string(),
+                )
+                .expect("component config");
+            }
+
+            let (root, _) = run(Some(&workspace.to_string_lossy())).expect("workspace root");
+            assert!(!root.managed);
+            assert_eq!(root.contained_components, vec!["alpha", "beta"]);
+
+            let inside = workspace.join("alpha/src");
+            let (nested, _) = run(Some(&inside.to_string_lossy())).expect("inside component");
+            assert_eq!(nested.matched_components, vec!["alpha"]);
+            assert_eq!(nested.mappings[0].component_id, "alpha");
+            assert_eq!(
+                nested.mappings[0].local_path,
+                workspace.join("alpha").to_string_lossy()
+            );
+
+            let err = run(Some(&home.path().join("missing").to_string_lossy())).unwrap_err();
+            assert!(err.message.contains("not a directory"));
+        });
+    }
+
     #[test]
     fn extension_suggestions_are_manifest_driven() {
         let dir = tempfile::tempdir().expect("tempdir");
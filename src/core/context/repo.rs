@@ -0,0 +1,473 @@
+//! Repo context at a git ref, and the difference between two refs.
+//!
+//! Reads the tree at each ref without touching the working copy: portable
+//! `homeboy.json` configs define components, extension discovery markers are
+//! matched against the ref's file list, and versions come from each
+//! component's primary version target.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::Path;
+
+use crate::component::Component;
+use crate::error::Result;
+use crate::extension::{DiscoveryMarkerConfig, ExtensionManifest};
+use crate::git;
+use crate::release::version;
+
+const PORTABLE_CONFIG: &str = "homeboy.json";
+
+/// A component declared by a `homeboy.json` at some ref.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RepoComponent {
+    pub id: String,
+    /// Directory relative to the repo root (`.` for the root).
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+}
+
+/// Components and matching extensions in the tree at one git ref.
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoContext {
+    pub git_ref: String,
+    pub commit: String,
+    pub components: Vec<RepoComponent>,
+    pub extensions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentContextChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentContextDiff {
+    pub id: String,
+    pub change: ComponentContextChange,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_version: Option<String>,
+    pub files_changed: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions_added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions_removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoRefSummary {
+    pub git_ref: String,
+    pub commit: String,
+}
+
+/// What changed in repo context between `from` and `to`. Components with
+/// no file, version, or extension changes are omitted.
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoContextDiff {
+    pub command: &'static str,
+    pub from: RepoRefSummary,
+    pub to: RepoRefSummary,
+    pub files_changed: usize,
+    pub components: Vec<ComponentContextDiff>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions_added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub extensions_removed: Vec<String>,
+}
+
+/// Build the repo context for `git_ref` in the repository at `root`.
+pub fn repo_context_at(
+    root: &Path,
+    git_ref: &str,
+    extensions: &[ExtensionManifest],
+) -> Result<RepoContext> {
+    let commit = resolve_commit(root, git_ref)?;
+    let files: Vec<String> = git::run_git(
+        root,
+        &["ls-tree", "-r", "--name-only", &commit],
+        "git ls-tree",
+    )?
+    .lines()
+    .map(str::to_string)
+    .collect();
+    let tree = RefTree::new(&files);
+
+    let mut components = Vec::new();
+    for config_path in files.iter().filter(|file| is_portable_config(file)) {
+        let dir = config_path
+            .strip_suffix(PORTABLE_CONFIG)
+            .unwrap_or_default()
+            .trim_end_matches('/');
+        if let Some(component) = read_component(root, &commit, dir, &tree, extensions) {
+            components.push(component);
+        }
+    }
+    components.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut matched: BTreeSet<String> = matching_extensions(&tree, "", extensions)
+        .into_iter()
+        .collect();
+    matched.extend(components.iter().flat_map(|c| c.extensions.iter().cloned()));
+
+    Ok(RepoContext {
+        git_ref: git_ref.to_string(),
+        commit,
+        components,
+        extensions: matched.into_iter().collect(),
+    })
+}
+
+/// Compute both contexts and return their differences.
+pub fn diff_refs(root: &Path, from: &str, to: &str) -> Result<RepoContextDiff> {
+    let extensions = crate::extension::load_all_extensions().unwrap_or_default();
+    let from_ctx = repo_context_at(root, from, &extensions)?;
+    let to_ctx = repo_context_at(root, to, &extensions)?;
+    let changed_files: Vec<String> = git::run_git(
+        root,
+        &["diff", "--name-only", &from_ctx.commit, &to_ctx.commit],
+        "git diff --name-only",
+    )?
+    .lines()
+    .map(str::to_string)
+    .collect();
+
+    Ok(diff_contexts(&from_ctx, &to_ctx, &changed_files))
+}
+
+/// Compare two already-built contexts given the files changed between them.
+pub fn diff_contexts(
+    from: &RepoContext,
+    to: &RepoContext,
+    changed_files: &[String],
+) -> RepoContextDiff {
+    let before: BTreeMap<&str, &RepoComponent> =
+        from.components.iter().map(|c| (c.id.as_str(), c)).collect();
+    let after: BTreeMap<&str, &RepoComponent> =
+        to.components.iter().map(|c| (c.id.as_str(), c)).collect();
+    let ids: BTreeSet<&str> = before.keys().chain(after.keys()).copied().collect();
+
+    let mut components = Vec::new();
+    for id in ids {
+        let old = before.get(id).copied();
+        let new = after.get(id).copied();
+        let path = new.or(old).map(|c| c.path.clone()).unwrap_or_default();
+        let files_changed = changed_files
+            .iter()
+            .filter(|file| is_within(file, &path))
+            .count();
+        let old_extensions = old.map(|c| c.extensions.as_slice()).unwrap_or_default();
+        let new_extensions = new.map(|c| c.extensions.as_slice()).unwrap_or_default();
+        let (extensions_added, extensions_removed) = set_delta(old_extensions, new_extensions);
+
+        let change = match (old, new) {
+            (None, Some(_)) => ComponentContextChange::Added,
+            (Some(_), None) => ComponentContextChange::Removed,
+            (Some(old), Some(new)) => {
+                if files_changed == 0
+                    && old.version == new.version
+                    && old.path == new.path
+                    && extensions_added.is_empty()
+                    && extensions_removed.is_empty()
+                {
+                    continue;
+                }
+                ComponentContextChange::Modified
+            }
+            (None, None) => continue,
+        };
+
+        components.push(ComponentContextDiff {
+            id: id.to_string(),
+            change,
+            path,
+            from_version: old.and_then(|c| c.version.clone()),
+            to_version: new.and_then(|c| c.version.clone()),
+            files_changed,
+            extensions_added,
+            extensions_removed,
+        });
+    }
+
+    let (extensions_added, extensions_removed) = set_delta(&from.extensions, &to.extensions);
+
+    RepoContextDiff {
+        command: "status.diff",
+        from: RepoRefSummary {
+            git_ref: from.git_ref.clone(),
+            commit: from.commit.clone(),
+        },
+        to: RepoRefSummary {
+            git_ref: to.git_ref.clone(),
+            commit: to.commit.clone(),
+        },
+        files_changed: changed_files.len(),
+        components,
+        extensions_added,
+        extensions_removed,
+    }
+}
+
+fn resolve_commit(root: &Path, git_ref: &str) -> Result<String> {
+    let spec = format!("{}^{{commit}}", git_ref);
+    Ok(git::run_git(
+        root,
+        &["rev-parse", "--verify", "--quiet", &spec],
+        &format!("resolve git ref '{}'", git_ref),
+    )?
+    .trim()
+    .to_string())
+}
+
+fn is_portable_config(file: &str) -> bool {
+    file == PORTABLE_CONFIG || file.ends_with(&format!("/{}", PORTABLE_CONFIG))
+}
+
+fn is_within(file: &str, dir: &str) -> bool {
+    dir == "." || file.starts_with(&format!("{}/", dir))
+}
+
+fn set_delta(before: &[String], after: &[String]) -> (Vec<String>, Vec<String>) {
+    let before: BTreeSet<&String> = before.iter().collect();
+    let after: BTreeSet<&String> = after.iter().collect();
+    (
+        after.difference(&before).map(|s| s.to_string()).collect(),
+        before.difference(&after).map(|s| s.to_string()).collect(),
+    )
+}
+
+fn show_file(root: &Path, commit: &str, path: &str) -> Option<String> {
+    git::run_git(root, &["show", &format!("{}:{}", commit, path)], "git show").ok()
+}
+
+fn read_component(
+    root: &Path,
+    commit: &str,
+    dir: &str,
+    tree: &RefTree,
+    extensions: &[ExtensionManifest],
+) -> Option<RepoComponent> {
+    let config_path = join(dir, PORTABLE_CONFIG);
+    let mut portable: serde_json::Value =
+        serde_json::from_str(&show_file(root, commit, &config_path)?).ok()?;
+
+    let id_source = match portable.get("id").and_then(|id| id.as_str()) {
+        Some(id) => id.to_string(),
+        None => Path::new(dir)
+            .file_name()
+            .or_else(|| root.file_name())?
+            .to_string_lossy()
+            .to_string(),
+    };
+    let id = crate::engine::identifier::slugify_id(&id_source, "component_id").ok()?;
+
+    let obj = portable.as_object_mut()?;
+    obj.insert("id".to_string(), id.clone().into());
+    obj.insert(
+        "local_path".to_string(),
+        root.join(dir).to_string_lossy().to_string().into(),
+    );
+    obj.entry("remote_path".to_string())
+        .or_insert_with(|| String::new().into());
+    let component: Option<Component> = serde_json::from_value(portable).ok();
+
+    let version = component
+        .as_ref()
+        .and_then(|c| c.version_targets.as_ref()?.first().cloned())
+        .and_then(|target| {
+            let pattern = version::resolve_target_pattern(&target).ok()?;
+            let content = show_file(root, commit, &join(dir, &target.file))?;
+            version::parse_version(&content, &pattern)
+        });
+
+    Some(RepoComponent {
+        id,
+        path: if dir.is_empty() { "." } else { dir }.to_string(),
+        version,
+        extensions: matching_extensions(tree, dir, extensions),
+    })
+}
+
+fn join(dir: &str, file: &str) -> String {
+    if dir.is_empty() {
+        file.to_string()
+    } else {
+        format!("{}/{}", dir, file)
+    }
+}
+
+/// File and directory paths present in the tree at a ref.
+struct RefTree {
+    paths: HashSet<String>,
+}
+
+impl RefTree {
+    fn new(files: &[String]) -> Self {
+        let mut paths = HashSet::new();
+        for file in files {
+            let mut current = file.as_str();
+            paths.insert(current.to_string());
+            while let Some((parent, _)) = current.rsplit_once('/') {
+                paths.insert(parent.to_string());
+                current = parent;
+            }
+        }
+        Self { paths }
+    }
+
+    fn marker_exists(&self, dir: &str, marker: &str) -> bool {
+        let target = join(dir, marker.trim_start_matches("./").trim_end_matches('/'));
+        if marker.contains(['*', '?', '[']) {
+            self.paths
+                .iter()
+                .any(|path| glob_match::glob_match(&target, path))
+        } else {
+            self.paths.contains(&target)
+        }
+    }
+}
+
+fn matching_extensions(tree: &RefTree, dir: &str, extensions: &[ExtensionManifest]) -> Vec<String> {
+    let mut matched: Vec<String> = extensions
+        .iter()
+        .filter(|manifest| {
+            manifest
+                .discovery_markers()
+                .iter()
+                .any(|rule| marker_rule_matches(tree, dir, rule))
+        })
+        .map(|manifest| manifest.id.clone())
+        .collect();
+    matched.sort();
+    matched.dedup();
+    matched
+}
+
+fn marker_rule_matches(tree: &RefTree, dir: &str, rule: &DiscoveryMarkerConfig) -> bool {
+    if rule.all.is_empty() && rule.any.is_empty() {
+        return false;
+    }
+    rule.all
+        .iter()
+        .all(|marker| tree.marker_exists(dir, marker))
+        && (rule.any.is_empty()
+            || rule
+                .any
+                .iter()
+                .any(|marker| tree.marker_exists(dir, marker)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("git runs");
+        assert!(status.status.success(), "git {:?} failed", args);
+    }
+
+    fn commit_all(dir: &Path, message: &str) {
+        git(dir, &["add", "-A"]);
+        git(
+            dir,
+            &[
+                "-c",
+                "user.name=test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "-q",
+                "-m",
+                message,
+            ],
+        );
+    }
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let path = dir.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn marker_extension(id: &str, marker: &str) -> ExtensionManifest {
+        let mut manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
+            "name": id,
+            "version": "1.0.0",
+            "provides": { "discovery_markers": [{ "all": [marker] }] }
+        }))
+        .expect("manifest parses");
+        manifest.id = id.to_string();
+        manifest
+    }
+
+    const VERSION_TARGET: &str = r#"[{"file": "VERSION", "pattern": "(\\d+\\.\\d+\\.\\d+)"}]"#;
+
+    #[test]
+    fn test_diff_contexts() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let root = dir.path();
+        git(root, &["init", "-q"]);
+        write(
+            root,
+            "api/homeboy.json",
+            &format!(r#"{{"id": "api", "version_targets": {}}}"#, VERSION_TARGET),
+        );
+        write(root, "api/VERSION", "1.0.0\n");
+        write(root, "legacy/homeboy.json", r#"{"id": "legacy"}"#);
+        write(root, "docs/readme.md", "docs\n");
+        commit_all(root, "initial");
+
+        std::fs::remove_dir_all(root.join("legacy")).unwrap();
+        write(root, "api/VERSION", "1.1.0\n");
+        write(root, "api/Cargo.toml", "[package]\n");
+        write(root, "web/homeboy.json", r#"{"id": "web"}"#);
+        commit_all(root, "second");
+
+        let extensions = vec![marker_extension("rust", "Cargo.toml")];
+        let from = repo_context_at(root, "HEAD~1", &extensions).expect("from context");
+        let to = repo_context_at(root, "HEAD", &extensions).expect("to context");
+        let changed = git::run_git(root, &["diff", "--name-only", "HEAD~1", "HEAD"], "diff")
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+
+        let diff = diff_contexts(&from, &to, &changed);
+
+        let by_id: BTreeMap<&str, &ComponentContextDiff> =
+            diff.components.iter().map(|c| (c.id.as_str(), c)).collect();
+        assert_eq!(by_id.len(), 3);
+        let api = by_id["api"];
+        assert_eq!(api.change, ComponentContextChange::Modified);
+        assert_eq!(api.from_version.as_deref(), Some("1.0.0"));
+        assert_eq!(api.to_version.as_deref(), Some("1.1.0"));
+        assert_eq!(api.files_changed, 2);
+        assert_eq!(api.extensions_added, vec!["rust"]);
+        assert_eq!(by_id["legacy"].change, ComponentContextChange::Removed);
+        assert_eq!(by_id["web"].change, ComponentContextChange::Added);
+        assert_eq!(diff.extensions_added, vec!["rust"]);
+        assert!(diff.extensions_removed.is_empty());
+    }
+
+    #[test]
+    fn repo_context_at_rejects_unknown_ref() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        git(dir.path(), &["init", "-q"]);
+
+        let err = repo_context_at(dir.path(), "does-not-exist", &[]).unwrap_err();
+
+        assert_eq!(err.code.as_str(), "git.command_failed");
+    }
+}
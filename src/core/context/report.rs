@@ -183,6 +183,11 @@ pub fn build_report(show_all_flag: bool, command: &str) -> Result<ContextReport>
     build_report_at(show_all_flag, command, None, None)
 }
 
+/// Like [`build_report`], for the directory `cwd` instead of the current one.
+pub fn build_report_in(show_all_flag: bool, command: &str, cwd: &str) -> Result<ContextReport> {
+    build_report_at(show_all_flag, command, Some(cwd), None)
+}
+
 pub fn build_report_for_component(
     show_all_flag: bool,
     command: &str,
@@ -337,9 +342,15 @@ fn build_report_at(
     } else {
         None
     };
+    // The version snapshot comes from the first component, so its tags do too.
+    let tag_pattern = version_snapshot
+        .as_ref()
+        .and(components_with_state.first())
+        .and_then(|wrapper| wrapper.component.tag_pattern.as_deref());
     let git_snapshot = resolve_git_snapshot(
         context_output.git_root.as_ref(),
         version_snapshot.as_ref().map(|v| v.version.as_str()),
+        tag_pattern,
     );
     let (last_release, changelog_snapshot) = resolve_changelog_snapshots(&components_with_state);
 
@@ -679,9 +690,10 @@ fn resolve_version_snapshot(components: &[ComponentWithState]) -> Option<Version
 fn resolve_git_snapshot(
     git_root: Option<&String>,
     current_version: Option<&str>,
+    tag_pattern: Option<&str>,
 ) -> Option<GitSnapshot> {
     let root = git_root?;
-    let snapshot = git::build_repo_baseline_snapshot(root, current_version).ok()?;
+    let snapshot = git::build_repo_baseline_snapshot(root, current_version, tag_pattern).ok()?;
     Some(GitSnapshot {
         branch: snapshot.branch,
         clean: snapshot.clean,
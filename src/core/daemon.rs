@@ -503,6 +503,8 @@ fn handle_connection(mut stream: TcpStream, job_store: &JobStore) -> std::io::Re
             }
         }
     };
+    // The daemon outlives extension installs made from other processes.
+    crate::extension::invalidate_extension_cache();
     let response = route_with_job_store_and_body(method, path, parsed_body, job_store);
     write_http_response(stream, response)
 }
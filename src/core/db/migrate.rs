@@ -0,0 +1,347 @@
+//! Framework migrations (`db migrate status|up|down`).
+//!
+//! Runs the extension-declared migration commands on the project (locally or
+//! over SSH). Status output is parsed into applied/pending migrations when the
+//! extension declares a `status_parser`; otherwise it is passed through raw.
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+
+use super::operations::build_context_with;
+use crate::engine::executor::execute_for_project;
+use crate::engine::template::{render_map, TemplateVars};
+use crate::extension::{DatabaseMigrationsConfig, MigrationStatusParser};
+use crate::{Error, Result};
+
+/// Status values treated as applied when a parser lists none.
+const DEFAULT_APPLIED_STATUSES: &[&str] = &["applied", "ran", "up", "yes", "true", "done"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrateAction {
+    Status,
+    Up,
+    Down,
+}
+
+impl MigrateAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Status => "status",
+            Self::Up => "up",
+            Self::Down => "down",
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
+pub struct DbMigrateResult {
+    pub project_id: String,
+    pub action: String,
+    /// The rendered migration command.
+    pub migration_command: String,
+    pub dry_run: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+    pub exit_code: i32,
+    pub success: bool,
+    /// Parsed status (`status` with a `status_parser` only).
+    #[serde(flatten)]
+    pub status: Option<MigrationStatus>,
+    /// Why status output was not parsed, when a parser was declared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct MigrationStatus {
+    pub migrations: Vec<MigrationEntry>,
+    pub applied_count: usize,
+    pub pending_count: usize,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct MigrationEntry {
+    pub name: String,
+    pub applied: bool,
+    /// Status text as reported by the framework, when there is one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+/// Run (or with `dry_run`, only render) `action`'s migration command.
+pub fn migrate(
+    project_id: &str,
+    action: MigrateAction,
+    dry_run: bool,
+    subtarget: Option<&str>,
+) -> Result<DbMigrateResult> {
+    let ctx = build_context_with(
+        project_id,
+        subtarget,
+        |db| db.migrations.clone(),
+        "No extension with database migration configuration found",
+    )?;
+
+    let template = command_template(&ctx.config, action)?;
+    let mut vars = ctx.base_template_vars();
+    vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
+    let command = render_map(template, &vars);
+
+    let mut result = DbMigrateResult {
+        project_id: ctx.project.id.clone(),
+        action: action.as_str().to_string(),
+        migration_command: command.clone(),
+        dry_run,
+        stdout: None,
+        stderr: None,
+        exit_code: 0,
+        success: true,
+        status: None,
+        message: None,
+    };
+    if dry_run {
+        return Ok(result);
+    }
+
+    let parser = match (&ctx.config.status_parser, action) {
+        (Some(parser), MigrateAction::Status) => Some(CompiledParser::new(parser)?),
+        _ => None,
+    };
+
+    let output = execute_for_project(&ctx.project, &command)?;
+    if output.success {
+        if let Some(parser) = parser {
+            match parser.parse(&output.stdout) {
+                Some(migrations) => result.status = Some(MigrationStatus::from(migrations)),
+                None => {
+                    result.message =
+                        Some("Could not parse migration status; see stdout".to_string())
+                }
+            }
+        }
+    }
+
+    result.stdout = Some(output.stdout);
+    result.stderr = Some(output.stderr);
+    result.exit_code = output.exit_code;
+    result.success = output.success;
+    Ok(result)
+}
+
+fn command_template(config: &DatabaseMigrationsConfig, action: MigrateAction) -> Result<&str> {
+    match action {
+        MigrateAction::Status => Ok(&config.status_command),
+        MigrateAction::Up => Ok(&config.up_command),
+        MigrateAction::Down => config.down_command.as_deref().ok_or_else(|| {
+            Error::validation_invalid_argument(
+                "action",
+                "The database extension declares no migration down_command",
+                None,
+                None,
+            )
+        }),
+    }
+}
+
+impl From<Vec<MigrationEntry>> for MigrationStatus {
+    fn from(migrations: Vec<MigrationEntry>) -> Self {
+        let applied_count = migrations.iter().filter(|m| m.applied).count();
+        Self {
+            pending_count: migrations.len() - applied_count,
+            applied_count,
+            migrations,
+        }
+    }
+}
+
+enum CompiledParser {
+    Json {
+        applied: Vec<String>,
+    },
+    Lines {
+        pattern: Regex,
+        applied: Vec<String>,
+    },
+}
+
+impl CompiledParser {
+    fn new(parser: &MigrationStatusParser) -> Result<Self> {
+        Ok(match parser {
+            MigrationStatusParser::Json { applied } => Self::Json {
+                applied: applied_statuses(applied),
+            },
+            MigrationStatusParser::Lines { pattern, applied } => {
+                let invalid = |message: String| {
+                    Error::validation_invalid_argument("status_parser", message, None, None)
+                };
+                let pattern = Regex::new(pattern).map_err(|e| {
+                    invalid(format!(
+                        "Invalid migration status pattern '{}': {}",
+                        pattern, e
+                    ))
+                })?;
+                if pattern.capture_names().flatten().all(|name| name != "name") {
+                    return Err(invalid(
+                        "Migration status pattern needs a `name` capture group".to_string(),
+                    ));
+                }
+                Self::Lines {
+                    pattern,
+                    applied: applied_statuses(applied),
+                }
+            }
+        })
+    }
+
+    /// `None` when the output is not in the declared shape.
+    fn parse(&self, stdout: &str) -> Option<Vec<MigrationEntry>> {
+        match self {
+            Self::Json { applied } => parse_json_status(stdout, applied),
+            Self::Lines { pattern, applied } => Some(
+                stdout
+                    .lines()
+                    .filter_map(|line| pattern.captures(line))
+                    .map(|caps| {
+                        let status = caps.name("status").map(|m| m.as_str().trim().to_string());
+                        MigrationEntry {
+                            name: caps["name"].trim().to_string(),
+                            applied: status.as_deref().is_some_and(|s| is_applied(s, applied)),
+                            status,
+                        }
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn applied_statuses(configured: &[String]) -> Vec<String> {
+    if configured.is_empty() {
+        DEFAULT_APPLIED_STATUSES
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    } else {
+        configured.to_vec()
+    }
+}
+
+fn is_applied(status: &str, applied: &[String]) -> bool {
+    applied
+        .iter()
+        .any(|value| value.eq_ignore_ascii_case(status))
+}
+
+fn parse_json_status(stdout: &str, applied: &[String]) -> Option<Vec<MigrationEntry>> {
+    let Value::Array(items) = serde_json::from_str::<Value>(stdout.trim()).ok()? else {
+        return None;
+    };
+
+    items
+        .iter()
+        .map(|item| {
+            let name = item
+                .get("name")
+                .or_else(|| item.get("migration"))?
+                .as_str()?
+                .to_string();
+            let status = item
+                .get("status")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            let is_applied = match item.get("applied").and_then(Value::as_bool) {
+                Some(flag) => flag,
+                None => status.as_deref().is_some_and(|s| is_applied(s, applied)),
+            };
+            Some(MigrationEntry {
+                name,
+                applied: is_applied,
+                status,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines_parser(pattern: &str) -> CompiledParser {
+        CompiledParser::new(&MigrationStatusParser::Lines {
+            pattern: pattern.to_string(),
+            applied: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn lines_parser_reads_status_table() {
+        let parser = lines_parser(r"^\s*(?P<status>up|down)\s+\d+\s+(?P<name>.+)$");
+        let stdout = "Status   Migration ID    Migration Name\n\
+                      ----------------------------------------\n   \
+                      up     20240101000000  Create users\n  \
+                      down    20240201000000  Add email index\n";
+
+        let status = MigrationStatus::from(parser.parse(stdout).unwrap());
+        assert_eq!(status.applied_count, 1);
+        assert_eq!(status.pending_count, 1);
+        assert_eq!(status.migrations[0].name, "Create users");
+        assert_eq!(status.migrations[1].status.as_deref(), Some("down"));
+    }
+
+    #[test]
+    fn json_parser_reads_applied_flags_and_statuses() {
+        let parser = CompiledParser::new(&MigrationStatusParser::Json {
+            applied: vec!["Ran".to_string()],
+        })
+        .unwrap();
+        let stdout = r#"[
+            {"migration": "2024_01_01_create_users", "status": "Ran"},
+            {"name": "2024_02_01_add_index", "status": "Pending"},
+            {"name": "2024_03_01_seed", "applied": true}
+        ]"#;
+
+        let migrations = parser.parse(stdout).unwrap();
+        assert_eq!(
+            migrations
+                .iter()
+                .map(|m| (m.name.as_str(), m.applied))
+                .collect::<Vec<_>>(),
+            vec![
+                ("2024_01_01_create_users", true),
+                ("2024_02_01_add_index", false),
+                ("2024_03_01_seed", true),
+            ]
+        );
+        assert!(parser.parse("not json").is_none());
+    }
+
+    #[test]
+    fn lines_parser_requires_name_group() {
+        let err = CompiledParser::new(&MigrationStatusParser::Lines {
+            pattern: r"(?P<status>\w+)".to_string(),
+            applied: Vec::new(),
+        })
+        .err()
+        .unwrap();
+        assert!(err.message.contains("`name` capture group"));
+    }
+
+    #[test]
+    fn down_requires_down_command() {
+        let config = DatabaseMigrationsConfig {
+            status_command: "migrate status".to_string(),
+            up_command: "migrate up".to_string(),
+            down_command: None,
+            status_parser: None,
+        };
+        assert_eq!(
+            command_template(&config, MigrateAction::Up).unwrap(),
+            "migrate up"
+        );
+        assert!(command_template(&config, MigrateAction::Down).is_err());
+    }
+}
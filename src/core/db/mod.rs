@@ -1,15 +1,22 @@
 //! Database operations for homeboy projects.
 //!
-//! Two subsystems:
+//! Four subsystems:
 //! - **Operations**: Query, search, list/describe tables, delete rows, drop tables
 //!   via extension-defined CLI commands.
+//! - **Schema**: Table listings and column descriptions normalized across engines.
+//! - **Migrate**: Framework migration status/up/down via extension-defined commands.
 //! - **Tunnel**: SSH tunnel for forwarding local ports to remote databases.
 
+mod migrate;
 mod operations;
+mod schema;
 mod tunnel;
 
 // Re-export everything at module level to preserve existing import paths.
+pub use migrate::{migrate, DbMigrateResult, MigrateAction, MigrationEntry, MigrationStatus};
 pub use operations::{
-    delete_row, describe_table, drop_table, list_tables, query, search, DbResult,
+    delete_row, describe_table, drop_table, list_tables, query, search, DbQueryOptions,
+    DbQueryRows, DbResult, DEFAULT_QUERY_LIMIT,
 };
+pub use schema::{DbColumn, DbTable};
 pub use tunnel::{create_tunnel, DbTunnelInfo, DbTunnelResult};
@@ -4,13 +4,15 @@
 //! operations that execute through extension-defined CLI commands.
 
 use serde::Serialize;
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
+use super::schema::{self, DbColumn, DbTable};
 use crate::context::require_project_base_path;
 use crate::engine::executor::execute_for_project;
 use crate::engine::template::{render_map, TemplateVars};
 use crate::engine::text;
-use crate::extension::{load_all_extensions, DatabaseCliConfig};
+use crate::extension::{load_all_extensions, DatabaseCliConfig, DatabaseConfig, DatabaseEngine};
 use crate::project::{self, Project};
 use crate::{Error, Result};
 
@@ -25,21 +27,79 @@ pub struct DbResult {
     pub exit_code: i32,
     pub success: bool,
     pub tables: Option<Vec<String>>,
+    /// Tables with row estimates (`db tables` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_details: Option<Vec<DbTable>>,
     pub table: Option<String>,
+    /// Columns of the described table (`db describe` only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub columns: Option<Vec<DbColumn>>,
     pub sql: Option<String>,
+    /// Parsed result rows (`db query` only).
+    #[serde(flatten)]
+    pub rows: Option<DbQueryRows>,
+    /// Human-readable failure summary when the command exits non-zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
-struct DbContext {
-    project: Project,
-    base_path: String,
-    domain: String,
-    cli_path: String,
-    db_cli: DatabaseCliConfig,
+/// Rows returned by `db query`, keyed by column name.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DbQueryRows {
+    pub rows: Vec<Map<String, Value>>,
+    pub row_count: usize,
+    pub limit: usize,
+    /// True when the query produced more rows than `limit`.
+    pub truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Options for `db query`.
+#[derive(Debug, Clone, Default)]
+pub struct DbQueryOptions {
+    /// Permit statements that modify data or schema.
+    pub allow_write: bool,
+    /// Maximum rows to return (defaults to `DEFAULT_QUERY_LIMIT`).
+    pub limit: Option<usize>,
+}
+
+/// Default cap on rows returned by `db query`.
+pub const DEFAULT_QUERY_LIMIT: usize = 1000;
+
+const READ_ONLY_KEYWORDS: &[&str] = &["SELECT", "WITH", "SHOW", "DESCRIBE", "DESC", "EXPLAIN"];
+
+/// Words that make a statement starting with a read-only keyword write
+/// anyway: data-modifying CTEs (`WITH d AS (DELETE ... RETURNING *)`),
+/// `EXPLAIN ANALYZE` (which executes the statement), and `SELECT ... INTO`.
+const WRITE_KEYWORDS: &[&str] = &[
+    "INSERT", "UPDATE", "DELETE", "MERGE", "TRUNCATE", "DROP", "ALTER", "CREATE", "GRANT",
+    "REVOKE", "INTO", "CALL", "ANALYZE",
+];
+
+/// Resolved project plus the extension database config `C` a command runs.
+pub(super) struct DbContext<C = DatabaseCliConfig> {
+    pub(super) project: Project,
+    pub(super) base_path: String,
+    pub(super) domain: String,
+    pub(super) cli_path: String,
+    pub(super) config: C,
 }
 
 impl DbContext {
+    /// Render `query_command` for `sql` with JSON output requested.
+    fn query_command(&self, sql: &str) -> String {
+        let mut vars = self.base_template_vars();
+        vars.insert(TemplateVars::QUERY.to_string(), sql.to_string());
+        vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
+        vars.insert(TemplateVars::DOMAIN.to_string(), self.domain.clone());
+        render_map(&self.config.query_command, &vars)
+    }
+}
+
+impl<C> DbContext<C> {
     /// Build base template variables for database commands.
-    fn base_template_vars(&self) -> HashMap<String, String> {
+    pub(super) fn base_template_vars(&self) -> HashMap<String, String> {
         let mut vars = HashMap::with_capacity(8);
         vars.insert(TemplateVars::SITE_PATH.to_string(), self.base_path.clone());
         vars.insert(TemplateVars::CLI_PATH.to_string(), self.cli_path.clone());
@@ -65,6 +125,22 @@ impl DbContext {
 }
 
 fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext> {
+    build_context_with(
+        project_id,
+        subtarget,
+        |db| db.cli.clone(),
+        "No extension with database CLI configuration found",
+    )
+}
+
+/// Build a context from the first extension whose database config `select`
+/// accepts; `missing` is the error when none does.
+pub(super) fn build_context_with<C>(
+    project_id: &str,
+    subtarget: Option<&str>,
+    select: impl Fn(&DatabaseConfig) -> Option<C>,
+    missing: &str,
+) -> Result<DbContext<C>> {
     let project = project::load(project_id)?;
     let base_path = require_project_base_path(project_id, &project)?;
 
@@ -72,12 +148,10 @@ fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext>
 
     let extensions = load_all_extensions().unwrap_or_default();
 
-    let db_cli = extensions
+    let config = extensions
         .iter()
-        .find_map(|m| m.database().and_then(|db| db.cli.clone()))
-        .ok_or_else(|| {
-            Error::config("No extension with database CLI configuration found".to_string())
-        })?;
+        .find_map(|m| m.database().and_then(&select))
+        .ok_or_else(|| Error::config(missing.to_string()))?;
 
     let cli_path = extensions
         .iter()
@@ -89,7 +163,7 @@ fn build_context(project_id: &str, subtarget: Option<&str>) -> Result<DbContext>
         base_path,
         domain,
         cli_path,
-        db_cli,
+        config,
     })
 }
 
@@ -155,21 +229,30 @@ fn resolve_domain(project: &Project, subtarget: Option<&str>, project_id: &str)
     ))
 }
 
-fn parse_json_tables(json: &str) -> Vec<String> {
-    serde_json::from_str::<Vec<String>>(json).unwrap_or_default()
-}
-
+/// List tables. With a database `engine` configured, the engine's catalog
+/// is read through `query_command` to include row estimates.
 pub fn list_tables(project_id: &str, subtarget: Option<&str>) -> Result<DbResult> {
     let ctx = build_context(project_id, subtarget)?;
 
-    let vars = ctx.base_template_vars();
-    let command = render_map(&ctx.db_cli.tables_command, &vars);
+    let (command, sql) = match ctx.config.engine {
+        Some(engine) => {
+            let sql = schema::tables_sql(engine).to_string();
+            (ctx.query_command(&sql), Some(sql))
+        }
+        None => (
+            render_map(&ctx.config.tables_command, &ctx.base_template_vars()),
+            None,
+        ),
+    };
 
     let output = execute_for_project(&ctx.project, &command)?;
-    let tables = if output.success {
-        Some(parse_json_tables(&output.stdout))
+    let (table_details, error) = if output.success {
+        (Some(schema::parse_tables(&output.stdout)), None)
     } else {
-        None
+        (
+            None,
+            Some(query_failure_message(&output.stderr, output.exit_code)),
+        )
     };
 
     Ok(DbResult {
@@ -181,12 +264,20 @@ pub fn list_tables(project_id: &str, subtarget: Option<&str>) -> Result<DbResult
         stderr: Some(output.stderr),
         exit_code: output.exit_code,
         success: output.success,
-        tables,
+        tables: table_details
+            .as_ref()
+            .map(|tables| tables.iter().map(|table| table.name.clone()).collect()),
+        table_details,
         table: None,
-        sql: None,
+        columns: None,
+        sql,
+        rows: None,
+        error,
     })
 }
 
+/// Describe a table's columns, types, and keys, from the engine's catalog
+/// when a database `engine` is configured or `describe_command` otherwise.
 pub fn describe_table(
     project_id: &str,
     table: Option<&str>,
@@ -195,11 +286,27 @@ pub fn describe_table(
     let table = table.ok_or_else(|| Error::config("Table name required".to_string()))?;
     let ctx = build_context(project_id, subtarget)?;
 
-    let mut vars = ctx.base_template_vars();
-    vars.insert(TemplateVars::TABLE.to_string(), table.to_string());
-    let command = render_map(&ctx.db_cli.describe_command, &vars);
+    let (command, sql) = match ctx.config.engine {
+        Some(engine) => {
+            let sql = schema::describe_sql(engine, table)?;
+            (ctx.query_command(&sql), Some(sql))
+        }
+        None => {
+            let mut vars = ctx.base_template_vars();
+            vars.insert(TemplateVars::TABLE.to_string(), table.to_string());
+            (render_map(&ctx.config.describe_command, &vars), None)
+        }
+    };
 
     let output = execute_for_project(&ctx.project, &command)?;
+    let (columns, error) = if output.success {
+        (Some(schema::parse_columns(&output.stdout)), None)
+    } else {
+        (
+            None,
+            Some(query_failure_message(&output.stderr, output.exit_code)),
+        )
+    };
 
     Ok(DbResult {
         project_id: ctx.project.id.clone(),
@@ -211,60 +318,308 @@ pub fn describe_table(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_details: None,
         table: Some(table.to_string()),
-        sql: None,
+        columns,
+        sql,
+        rows: None,
+        error,
     })
 }
 
-pub fn query(project_id: &str, sql: &str, subtarget: Option<&str>) -> Result<DbResult> {
-    let ctx = build_context(project_id, subtarget)?;
-
+pub fn query(
+    project_id: &str,
+    sql: &str,
+    options: &DbQueryOptions,
+    subtarget: Option<&str>,
+) -> Result<DbResult> {
     if sql.trim().is_empty() {
-        return Err(Error::config("SQL query required".to_string()));
+        return Err(Error::validation_missing_argument(vec!["sql".to_string()]));
     }
 
-    let forbidden_prefixes = [
-        "INSERT", "UPDATE", "DELETE", "DROP", "ALTER", "TRUNCATE", "CREATE", "REPLACE", "GRANT",
-        "REVOKE",
-    ];
-
-    let upper_sql = sql.to_uppercase();
-    let trimmed_sql = upper_sql.trim_start();
-    if forbidden_prefixes
-        .iter()
-        .any(|keyword| trimmed_sql.starts_with(keyword))
-    {
-        return Err(Error::config(
-            "Write operations not allowed via query. Use the extension CLI directly for writes."
-                .to_string(),
+    if !options.allow_write && !is_read_only_sql(sql) {
+        return Err(Error::validation_invalid_argument(
+            "sql",
+            "Only a single read-only statement (SELECT, WITH, SHOW, DESCRIBE, EXPLAIN) with no data-modifying clause is allowed without --write",
+            None,
+            Some(vec![
+                "Pass --write to run statements that modify data or schema".to_string(),
+            ]),
         ));
     }
 
-    let escaped_sql = sql.replace('\'', "''");
+    let ctx = build_context(project_id, subtarget)?;
+    let limit = options.limit.unwrap_or(DEFAULT_QUERY_LIMIT);
+    let mut run_sql = limited_sql(sql, limit).unwrap_or_else(|| sql.to_string());
+    if !options.allow_write {
+        if let Some(engine) = ctx.config.engine {
+            run_sql = format!("{}\n{}", read_only_session_sql(engine), run_sql);
+        }
+    }
+    let escaped_sql = run_sql.replace('\'', "''");
 
     let mut vars = ctx.base_template_vars();
     vars.insert(TemplateVars::QUERY.to_string(), escaped_sql);
     vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
     vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
-    let command = render_map(&ctx.db_cli.query_command, &vars);
+    let command = render_map(&ctx.config.query_command, &vars);
 
     let output = execute_for_project(&ctx.project, &command)?;
 
+    let (rows, error) = if output.success {
+        (Some(parse_query_rows(&output.stdout, limit)), None)
+    } else {
+        (
+            None,
+            Some(query_failure_message(&output.stderr, output.exit_code)),
+        )
+    };
+    let stdout = query_stdout(output.stdout, rows.as_ref(), limit);
+
     Ok(DbResult {
         project_id: ctx.project.id.clone(),
         base_path: Some(ctx.base_path),
         domain: Some(ctx.domain),
         cli_path: Some(ctx.cli_path),
-        stdout: Some(output.stdout),
+        stdout,
         stderr: Some(output.stderr),
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_details: None,
         table: None,
+        columns: None,
         sql: Some(sql.to_string()),
+        rows,
+        error,
     })
 }
 
+/// True when `sql` is a single statement starting with a read-only keyword
+/// and containing none of `WRITE_KEYWORDS`.
+///
+/// Leading `--` and `/* */` comments are ignored, as are words inside quotes
+/// and comments. A trailing `;` is allowed, but anything after it counts as a
+/// second statement and is rejected.
+fn is_read_only_sql(sql: &str) -> bool {
+    let statement = strip_leading_sql_comments(sql);
+
+    if let Some((_, rest)) = statement.split_once(';') {
+        if !strip_leading_sql_comments(rest).is_empty() {
+            return false;
+        }
+    }
+
+    let words = sql_words(statement);
+    words
+        .first()
+        .is_some_and(|keyword| READ_ONLY_KEYWORDS.contains(&keyword.as_str()))
+        && !words
+            .iter()
+            .any(|word| WRITE_KEYWORDS.contains(&word.as_str()))
+}
+
+/// Statement run ahead of a read-only query so the database itself refuses
+/// writes, whatever the keyword check missed.
+fn read_only_session_sql(engine: DatabaseEngine) -> &'static str {
+    match engine {
+        DatabaseEngine::Mysql => "SET SESSION TRANSACTION READ ONLY;",
+        DatabaseEngine::Postgres => "SET SESSION CHARACTERISTICS AS TRANSACTION READ ONLY;",
+        DatabaseEngine::Sqlite => "PRAGMA query_only = ON;",
+    }
+}
+
+/// `sql` with `LIMIT limit + 1` appended, so the database stops one row past
+/// the cap instead of returning the whole result. Only read-only `SELECT` and
+/// `WITH` statements without their own `LIMIT`, `OFFSET`, or `FETCH` qualify.
+fn limited_sql(sql: &str, limit: usize) -> Option<String> {
+    if !is_read_only_sql(sql) {
+        return None;
+    }
+    let statement = strip_leading_sql_comments(sql);
+    let words = sql_words(statement);
+    if !matches!(words.first().map(String::as_str), Some("SELECT" | "WITH"))
+        || words
+            .iter()
+            .any(|word| matches!(word.as_str(), "LIMIT" | "OFFSET" | "FETCH"))
+    {
+        return None;
+    }
+    let body = statement
+        .split_once(';')
+        .map_or(statement, |(body, _)| body);
+    // A newline keeps the clause out of a trailing `--` comment.
+    Some(format!("{}\nLIMIT {}", body.trim_end(), limit + 1))
+}
+
+/// Uppercased words of `sql`, skipping quoted strings and identifiers and
+/// comments.
+fn sql_words(sql: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word.push(c.to_ascii_uppercase());
+            continue;
+        }
+        if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        match c {
+            '\'' | '"' | '`' => {
+                while let Some(next) = chars.next() {
+                    if next == '\\' {
+                        chars.next();
+                    } else if next == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            _ => {}
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
+
+fn strip_leading_sql_comments(sql: &str) -> &str {
+    let mut rest = sql.trim_start();
+    loop {
+        if let Some(line_comment) = rest.strip_prefix("--") {
+            rest = line_comment
+                .split_once('\n')
+                .map(|(_, tail)| tail)
+                .unwrap_or_default()
+                .trim_start();
+        } else if let Some(block_comment) = rest.strip_prefix("/*") {
+            rest = block_comment
+                .split_once("*/")
+                .map(|(_, tail)| tail)
+                .unwrap_or_default()
+                .trim_start();
+        } else {
+            return rest;
+        }
+    }
+}
+
+/// Parse query output into rows keyed by column name.
+///
+/// Accepts a JSON array of objects (extensions that honor `{{format}}`) or
+/// tab-separated output with a header row (`mysql --batch`, `wp db query`).
+fn parse_query_rows(stdout: &str, limit: usize) -> DbQueryRows {
+    let mut rows = parse_rows(stdout);
+
+    let truncated = rows.len() > limit;
+    rows.truncate(limit);
+    let row_count = rows.len();
+
+    let message = if row_count == 0 {
+        Some("Query returned no rows".to_string())
+    } else if truncated {
+        Some(format!(
+            "Showing the first {} rows; raise --limit to see more",
+            limit
+        ))
+    } else {
+        None
+    };
+
+    DbQueryRows {
+        rows,
+        row_count,
+        limit,
+        truncated,
+        message,
+    }
+}
+
+/// Raw client output to carry alongside parsed rows.
+///
+/// Parsed rows already hold the result, so the raw copy is dropped. Output
+/// that didn't parse (a `SHOW` variant the parser doesn't know, a write
+/// statement's summary) is kept, but only its first `limit` lines after the
+/// header, so `--limit` bounds the envelope either way.
+fn query_stdout(stdout: String, rows: Option<&DbQueryRows>, limit: usize) -> Option<String> {
+    if rows.is_some_and(|rows| rows.row_count > 0) {
+        return None;
+    }
+    if stdout.lines().count() <= limit + 1 {
+        return Some(stdout);
+    }
+    let mut kept: Vec<&str> = stdout.lines().take(limit + 1).collect();
+    kept.push("");
+    Some(kept.join("\n"))
+}
+
+/// Rows from a JSON array of objects or tab-separated output with a header.
+pub(super) fn parse_rows(stdout: &str) -> Vec<Map<String, Value>> {
+    match serde_json::from_str::<Value>(stdout.trim()) {
+        Ok(Value::Array(items)) => items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Object(map) => Some(map),
+                _ => None,
+            })
+            .collect(),
+        _ => parse_tabular_rows(stdout),
+    }
+}
+
+fn parse_tabular_rows(stdout: &str) -> Vec<Map<String, Value>> {
+    let mut lines = stdout.lines().filter(|line| !line.trim().is_empty());
+    let Some(header) = lines.next() else {
+        return Vec::new();
+    };
+    let columns: Vec<&str> = header.split('\t').collect();
+
+    lines
+        .map(|line| {
+            columns
+                .iter()
+                .zip(line.split('\t').chain(std::iter::repeat("")))
+                .map(|(column, value)| {
+                    let value = if value == "NULL" {
+                        Value::Null
+                    } else {
+                        Value::String(value.to_string())
+                    };
+                    (column.to_string(), value)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn query_failure_message(stderr: &str, exit_code: i32) -> String {
+    let detail = stderr
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("no error output");
+    format!("Query failed (exit {}): {}", exit_code, detail)
+}
+
 const DEFAULT_SEARCH_LIMIT: u32 = 100;
 
 pub fn search(
@@ -307,7 +662,7 @@ pub fn search(
     vars.insert(TemplateVars::QUERY.to_string(), search_sql.clone());
     vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
     vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
-    let command = render_map(&ctx.db_cli.query_command, &vars);
+    let command = render_map(&ctx.config.query_command, &vars);
 
     let output = execute_for_project(&ctx.project, &command)?;
 
@@ -321,8 +676,12 @@ pub fn search(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_details: None,
         table: Some(table.to_string()),
+        columns: None,
         sql: Some(search_sql),
+        rows: None,
+        error: None,
     })
 }
 
@@ -345,7 +704,7 @@ pub fn delete_row(
     vars.insert(TemplateVars::QUERY.to_string(), delete_sql.clone());
     vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
     vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
-    let command = render_map(&ctx.db_cli.query_command, &vars);
+    let command = render_map(&ctx.config.query_command, &vars);
 
     let output = execute_for_project(&ctx.project, &command)?;
 
@@ -359,8 +718,12 @@ pub fn delete_row(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_details: None,
         table: Some(table.to_string()),
+        columns: None,
         sql: Some(delete_sql),
+        rows: None,
+        error: None,
     })
 }
 
@@ -378,7 +741,7 @@ pub fn drop_table(
     vars.insert(TemplateVars::QUERY.to_string(), drop_sql.clone());
     vars.insert(TemplateVars::FORMAT.to_string(), "json".to_string());
     vars.insert(TemplateVars::DOMAIN.to_string(), ctx.domain.clone());
-    let command = render_map(&ctx.db_cli.query_command, &vars);
+    let command = render_map(&ctx.config.query_command, &vars);
 
     let output = execute_for_project(&ctx.project, &command)?;
 
@@ -392,8 +755,12 @@ pub fn drop_table(
         exit_code: output.exit_code,
         success: output.success,
         tables: None,
+        table_details: None,
         table: Some(table.to_string()),
+        columns: None,
         sql: Some(drop_sql),
+        rows: None,
+        error: None,
     })
 }
 
@@ -402,16 +769,109 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_json_tables_handles_array() {
-        let json = r#"["wp_posts", "wp_options", "wp_users"]"#;
-        let tables = parse_json_tables(json);
-        assert_eq!(tables, vec!["wp_posts", "wp_options", "wp_users"]);
+    fn read_only_sql_accepts_select_and_rejects_writes() {
+        assert!(is_read_only_sql("SELECT * FROM wp_posts"));
+        assert!(is_read_only_sql("  -- recent\n select 1;"));
+        assert!(is_read_only_sql("/* audit */ SHOW TABLES"));
+        assert!(!is_read_only_sql("UPDATE wp_options SET option_value = 1"));
+        assert!(!is_read_only_sql("SELECT 1; DROP TABLE wp_users"));
+        assert!(!is_read_only_sql("SELECTX 1"));
+        assert!(!is_read_only_sql(
+            "WITH d AS (DELETE FROM wp_posts RETURNING *) SELECT * FROM d"
+        ));
+        assert!(!is_read_only_sql("EXPLAIN ANALYZE DELETE FROM wp_posts"));
+        assert!(!is_read_only_sql(
+            "SELECT * INTO OUTFILE '/tmp/x' FROM wp_users"
+        ));
+        assert!(is_read_only_sql(
+            "SELECT 'delete me', \"update\" FROM t -- drop later"
+        ));
+    }
+
+    #[test]
+    fn limited_sql_pushes_the_cap_into_select_statements() {
+        assert_eq!(
+            limited_sql("SELECT * FROM wp_posts; -- all", 10).as_deref(),
+            Some("SELECT * FROM wp_posts\nLIMIT 11")
+        );
+        assert_eq!(
+            limited_sql("with t as (select 1) select * from t -- note", 5).as_deref(),
+            Some("with t as (select 1) select * from t -- note\nLIMIT 6")
+        );
+        assert_eq!(limited_sql("SELECT * FROM wp_posts LIMIT 5", 10), None);
+        assert_eq!(limited_sql("SHOW TABLES", 10), None);
+        assert_eq!(limited_sql("UPDATE wp_posts SET a = 1", 10), None);
+    }
+
+    #[test]
+    fn read_only_session_sql_covers_every_engine() {
+        assert!(read_only_session_sql(DatabaseEngine::Mysql).contains("READ ONLY"));
+        assert!(read_only_session_sql(DatabaseEngine::Postgres).contains("READ ONLY"));
+        assert!(read_only_session_sql(DatabaseEngine::Sqlite).contains("query_only"));
     }
 
     #[test]
-    fn parse_json_tables_returns_empty_on_invalid() {
-        let invalid = "not json";
-        let tables = parse_json_tables(invalid);
-        assert!(tables.is_empty());
+    fn parse_query_rows_reads_json_objects() {
+        let parsed = parse_query_rows(r#"[{"ID":"1","post_title":"Hello"}]"#, 10);
+
+        assert_eq!(parsed.row_count, 1);
+        assert_eq!(parsed.rows[0]["post_title"], "Hello");
+        assert!(!parsed.truncated);
+        assert_eq!(parsed.message, None);
+    }
+
+    #[test]
+    fn parse_query_rows_reads_tab_separated_output() {
+        let parsed = parse_query_rows("ID\tpost_title\n1\tHello\n2\tNULL\n", 10);
+
+        assert_eq!(parsed.row_count, 2);
+        assert_eq!(parsed.rows[0]["ID"], "1");
+        assert_eq!(parsed.rows[1]["post_title"], Value::Null);
+    }
+
+    #[test]
+    fn parse_query_rows_caps_rows_at_limit() {
+        let parsed = parse_query_rows(r#"[{"n":1},{"n":2},{"n":3}]"#, 2);
+
+        assert_eq!(parsed.rows.len(), 2);
+        assert_eq!(parsed.row_count, 2);
+        assert!(parsed.truncated);
+        assert!(parsed.message.unwrap().contains("--limit"));
+    }
+
+    #[test]
+    fn parse_query_rows_reports_empty_result() {
+        let parsed = parse_query_rows("", 10);
+
+        assert!(parsed.rows.is_empty());
+        assert_eq!(parsed.message.as_deref(), Some("Query returned no rows"));
+    }
+
+    #[test]
+    fn query_stdout_stays_within_limit() {
+        let stdout: String = std::iter::once("n".to_string())
+            .chain((1..=50).map(|n| n.to_string()))
+            .map(|line| line + "\n")
+            .collect();
+
+        let parsed = parse_query_rows(&stdout, 5);
+        assert_eq!(query_stdout(stdout.clone(), Some(&parsed), 5), None);
+
+        let unparsed = query_stdout(stdout.clone(), None, 5).unwrap();
+        assert_eq!(unparsed.lines().count(), 6, "header plus --limit lines");
+        assert_eq!(unparsed, "n\n1\n2\n3\n4\n5\n");
+
+        assert_eq!(
+            query_stdout("Query OK\n".to_string(), None, 5).as_deref(),
+            Some("Query OK\n")
+        );
+    }
+
+    #[test]
+    fn query_failure_message_uses_first_stderr_line() {
+        assert_eq!(
+            query_failure_message("\nERROR 1146: Table missing\nmore", 1),
+            "Query failed (exit 1): ERROR 1146: Table missing"
+        );
     }
 }
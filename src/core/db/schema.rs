@@ -0,0 +1,273 @@
+//! Schema introspection for `db tables` and `db describe`.
+//!
+//! With an `engine` on the extension's database CLI config, the catalog is
+//! queried through `query_command`; otherwise the output of
+//! `tables_command`/`describe_command` is parsed. Either way the rows are
+//! normalized into [`DbTable`] and [`DbColumn`], accepting the column names
+//! each engine uses (`Field`/`Type`/`Key` from MySQL, `column_name` from
+//! `information_schema`, `notnull`/`pk` from SQLite).
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::operations::parse_rows;
+use crate::extension::DatabaseEngine;
+use crate::{Error, Result};
+
+/// A table listed by `db tables`.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DbTable {
+    pub name: String,
+    /// Approximate row count from the engine's statistics, when cheap to read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_estimate: Option<u64>,
+}
+
+/// A column returned by `db describe`.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DbColumn {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub data_type: String,
+    pub nullable: bool,
+    /// `primary`, `unique`, `index`, or `foreign`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extra: Option<String>,
+}
+
+/// Catalog query listing tables with row estimates.
+pub(super) fn tables_sql(engine: DatabaseEngine) -> &'static str {
+    match engine {
+        DatabaseEngine::Mysql => "SHOW TABLE STATUS",
+        DatabaseEngine::Postgres => {
+            "SELECT c.relname AS name, c.reltuples::bigint AS row_estimate \
+             FROM pg_class c JOIN pg_namespace n ON n.oid = c.relnamespace \
+             WHERE c.relkind IN ('r', 'p') AND n.nspname = current_schema() ORDER BY 1"
+        }
+        // SQLite keeps no row statistics; counting would scan every table.
+        DatabaseEngine::Sqlite => {
+            "SELECT name FROM sqlite_master \
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
+        }
+    }
+}
+
+/// Catalog query describing the columns of `table`.
+pub(super) fn describe_sql(engine: DatabaseEngine, table: &str) -> Result<String> {
+    validate_table_name(table)?;
+    Ok(match engine {
+        DatabaseEngine::Mysql => format!("SHOW COLUMNS FROM {}", table),
+        DatabaseEngine::Postgres => format!(
+            "SELECT c.column_name AS name, c.data_type AS type, c.is_nullable AS nullable, \
+             c.column_default AS default_value, \
+             (SELECT tc.constraint_type FROM information_schema.key_column_usage k \
+             JOIN information_schema.table_constraints tc \
+             ON tc.constraint_name = k.constraint_name AND tc.table_schema = k.table_schema \
+             WHERE k.table_schema = c.table_schema AND k.table_name = c.table_name \
+             AND k.column_name = c.column_name \
+             ORDER BY tc.constraint_type = 'PRIMARY KEY' DESC LIMIT 1) AS key \
+             FROM information_schema.columns c \
+             WHERE c.table_schema = current_schema() AND c.table_name = '{}' \
+             ORDER BY c.ordinal_position",
+            table
+        ),
+        DatabaseEngine::Sqlite => format!("PRAGMA table_info({})", table),
+    })
+}
+
+/// Table names are interpolated into catalog SQL, so only plain identifiers
+/// (optionally schema-qualified) are accepted.
+fn validate_table_name(table: &str) -> Result<()> {
+    let valid = !table.is_empty()
+        && table
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '.'));
+    if valid {
+        return Ok(());
+    }
+    Err(Error::validation_invalid_argument(
+        "table",
+        format!(
+            "'{}' is not a plain table name (letters, digits, _, $, .)",
+            table
+        ),
+        Some(table.to_string()),
+        None,
+    ))
+}
+
+/// Tables from catalog rows or `tables_command` output.
+///
+/// Accepts a JSON array of names, a JSON array of row objects, tab-separated
+/// rows with a header, or one name per line.
+pub(super) fn parse_tables(stdout: &str) -> Vec<DbTable> {
+    if let Ok(names) = serde_json::from_str::<Vec<String>>(stdout.trim()) {
+        return names
+            .into_iter()
+            .map(|name| DbTable {
+                name,
+                row_estimate: None,
+            })
+            .collect();
+    }
+    let rows = parse_rows(stdout);
+    let tables: Vec<DbTable> = rows.iter().filter_map(table_from_row).collect();
+    if (!tables.is_empty() && tables.len() == rows.len())
+        || serde_json::from_str::<Value>(stdout.trim()).is_ok()
+    {
+        return tables;
+    }
+    // A bare list of names (`wp db tables`) has no header row.
+    stdout
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|name| DbTable {
+            name: name.to_string(),
+            row_estimate: None,
+        })
+        .collect()
+}
+
+fn table_from_row(row: &Map<String, Value>) -> Option<DbTable> {
+    let name = field(row, &["name", "table_name"]).or_else(|| {
+        row.iter()
+            .find(|(key, _)| key.to_ascii_lowercase().starts_with("tables_in_"))
+            .and_then(|(_, value)| text(value))
+    })?;
+    let row_estimate = field(row, &["row_estimate", "rows", "table_rows"])
+        .and_then(|value| value.parse::<i64>().ok())
+        .and_then(|value| u64::try_from(value).ok());
+    Some(DbTable { name, row_estimate })
+}
+
+/// Columns from catalog rows or `describe_command` output.
+pub(super) fn parse_columns(stdout: &str) -> Vec<DbColumn> {
+    parse_rows(stdout)
+        .iter()
+        .filter_map(column_from_row)
+        .collect()
+}
+
+fn column_from_row(row: &Map<String, Value>) -> Option<DbColumn> {
+    let name = field(row, &["name", "field", "column_name"])?;
+    let nullable = match field(row, &["nullable", "null", "is_nullable"]) {
+        Some(value) => is_truthy(&value),
+        None => field(row, &["notnull"]).is_some_and(|value| !is_truthy(&value)),
+    };
+    Some(DbColumn {
+        name,
+        data_type: field(row, &["type", "column_type", "data_type"]).unwrap_or_default(),
+        nullable,
+        key: field(row, &["key", "column_key", "pk"]).and_then(|key| normalize_key(&key)),
+        // Tab-separated output cannot tell an empty default from none.
+        default: field(
+            row,
+            &["default", "default_value", "column_default", "dflt_value"],
+        )
+        .filter(|default| !default.is_empty()),
+        extra: field(row, &["extra"]).filter(|extra| !extra.is_empty()),
+    })
+}
+
+/// First present, non-null field among `names`, matched case-insensitively.
+fn field(row: &Map<String, Value>, names: &[&str]) -> Option<String> {
+    names.iter().find_map(|name| {
+        row.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .and_then(|(_, value)| text(value))
+    })
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn is_truthy(value: &str) -> bool {
+    matches!(
+        value.to_ascii_lowercase().as_str(),
+        "yes" | "y" | "true" | "t" | "1"
+    )
+}
+
+fn normalize_key(key: &str) -> Option<String> {
+    let key = match key.trim().to_ascii_uppercase().as_str() {
+        "PRI" | "PRIMARY KEY" | "PRIMARY" => "primary",
+        "UNI" | "UNIQUE" => "unique",
+        "MUL" | "INDEX" => "index",
+        "FOREIGN KEY" => "foreign",
+        "" | "0" => return None,
+        // SQLite reports the column's position in the primary key.
+        other if other.parse::<u32>().is_ok() => "primary",
+        other => return Some(other.to_ascii_lowercase()),
+    };
+    Some(key.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tables() {
+        let names = parse_tables(r#"["wp_posts", "wp_users"]"#);
+        assert_eq!(names[1].name, "wp_users");
+        assert_eq!(names[1].row_estimate, None);
+
+        let status = "Name\tEngine\tRows\nwp_posts\tInnoDB\t1520\nwp_users\tInnoDB\t3\n";
+        let tables = parse_tables(status);
+        assert_eq!(tables[0].row_estimate, Some(1520));
+        assert_eq!(tables[1].row_estimate, Some(3));
+
+        let postgres = r#"[{ "name": "users", "row_estimate": -1 }]"#;
+        assert_eq!(parse_tables(postgres)[0].row_estimate, None);
+        assert!(parse_tables("[]").is_empty());
+
+        let show_tables = parse_tables("Tables_in_wordpress\nwp_options\nwp_posts\n");
+        assert_eq!(show_tables.len(), 2);
+        assert_eq!(show_tables[0].name, "wp_options");
+
+        let bare = parse_tables("wp_options\nwp_posts\n");
+        assert_eq!(bare.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        let mysql = "Field\tType\tNull\tKey\tDefault\tExtra\n\
+                     ID\tbigint(20) unsigned\tNO\tPRI\tNULL\tauto_increment\n\
+                     post_author\tbigint(20) unsigned\tNO\tMUL\t0\t\n";
+        let columns = parse_columns(mysql);
+        assert_eq!(columns[0].key.as_deref(), Some("primary"));
+        assert_eq!(columns[0].extra.as_deref(), Some("auto_increment"));
+        assert_eq!(columns[1].key.as_deref(), Some("index"));
+        assert_eq!(columns[1].default.as_deref(), Some("0"));
+        assert!(!columns[1].nullable);
+
+        let sqlite = r#"[
+            { "cid": 0, "name": "id", "type": "INTEGER", "notnull": 0, "dflt_value": null, "pk": 1 },
+            { "cid": 1, "name": "title", "type": "TEXT", "notnull": 1, "dflt_value": null, "pk": 0 }
+        ]"#;
+        let columns = parse_columns(sqlite);
+        assert_eq!(columns[0].key.as_deref(), Some("primary"));
+        assert!(columns[0].nullable);
+        assert_eq!(columns[1].key, None);
+        assert!(!columns[1].nullable);
+    }
+
+    #[test]
+    fn describe_sql_rejects_non_identifier_tables() {
+        assert_eq!(
+            describe_sql(DatabaseEngine::Mysql, "wp_posts").unwrap(),
+            "SHOW COLUMNS FROM wp_posts"
+        );
+        assert!(describe_sql(DatabaseEngine::Postgres, "users'; DROP TABLE x").is_err());
+    }
+}
@@ -67,6 +67,8 @@ pub fn create_tunnel(project_id: &str, local_port: Option<u16>) -> Result<DbTunn
         ssh_args.push(server.port.to_string());
     }
 
+    ssh_args.extend(client.proxy_args());
+
     ssh_args.push("-N".to_string());
     ssh_args.push("-L".to_string());
     ssh_args.push(format!("{}:{}:{}", bind_port, remote_host, remote_port));
@@ -3,6 +3,7 @@ use std::fs;
 
 use crate::engine::local_files;
 use crate::paths;
+use crate::upgrade::ReleaseChannel;
 
 /// Root configuration structure for homeboy.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +27,17 @@ pub struct HomeboyConfig {
     /// or set HOMEBOY_NO_UPDATE_CHECK=1.
     #[serde(default = "default_true")]
     pub update_check: bool,
+
+    /// Hours between startup update checks (default: 24). Results are read
+    /// from cache in between.
+    #[serde(default = "default_update_check_interval_hours")]
+    pub update_check_interval_hours: u64,
+
+    #[serde(default)]
+    pub release: ReleaseConfig,
+
+    #[serde(default)]
+    pub upgrade: UpgradeConfig,
 }
 
 impl Default for HomeboyConfig {
@@ -35,6 +47,9 @@ impl Default for HomeboyConfig {
             triage: TriageConfig::default(),
             artifact_root: None,
             update_check: true,
+            update_check_interval_hours: default_update_check_interval_hours(),
+            release: ReleaseConfig::default(),
+            upgrade: UpgradeConfig::default(),
         }
     }
 }
@@ -43,12 +58,46 @@ pub fn default_true() -> bool {
     true
 }
 
+fn default_update_check_interval_hours() -> u64 {
+    24
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TriageConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub priority_labels: Option<Vec<String>>,
 }
 
+/// Release tag signing defaults.
+///
+/// Enable with `homeboy config set /release/sign_tags true`; `--sign` on
+/// `release` and `git tag` signs a single run regardless.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ReleaseConfig {
+    /// Sign release tags without passing `--sign`.
+    #[serde(default)]
+    pub sign_tags: bool,
+
+    /// Signature format: `gpg` (default), `ssh`, or `x509`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_format: Option<String>,
+
+    /// Key passed to `git tag -u`. Without it git uses `user.signingkey`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_key: Option<String>,
+}
+
+/// `homeboy upgrade` defaults.
+///
+/// Switch channel with `homeboy config set /upgrade/channel beta`; the
+/// startup update check follows the same channel.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpgradeConfig {
+    /// Release channel used when `--channel` is not passed.
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+}
+
 /// All configurable defaults that can be overridden via homeboy.json
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Defaults {
@@ -160,7 +209,8 @@ fn default_homebrew_config() -> InstallMethodConfig {
 fn default_cargo_config() -> InstallMethodConfig {
     InstallMethodConfig {
         path_patterns: vec!["/.cargo/bin/".to_string()],
-        upgrade_command: "cargo install homeboy".to_string(),
+        upgrade_command: "cargo install homeboy ${HOMEBOY_UPGRADE_VERSION:+--version \"$HOMEBOY_UPGRADE_VERSION\"}"
+            .to_string(),
         list_command: None,
     }
 }
@@ -168,7 +218,8 @@ fn default_cargo_config() -> InstallMethodConfig {
 fn default_source_config() -> InstallMethodConfig {
     InstallMethodConfig {
         path_patterns: vec!["/target/release/".to_string(), "/target/debug/".to_string()],
-        upgrade_command: "git pull && . \"$HOME/.cargo/env\" && cargo build --release".to_string(),
+        upgrade_command: r#"if [ -n "${HOMEBOY_UPGRADE_VERSION:-}" ]; then git fetch --tags && git checkout "v$HOMEBOY_UPGRADE_VERSION"; else { git symbolic-ref -q HEAD >/dev/null || git checkout "$(git rev-parse --abbrev-ref origin/HEAD | sed 's|^origin/||')"; } && git pull; fi && . "$HOME/.cargo/env" && cargo build --release"#
+            .to_string(),
         list_command: None,
     }
 }
@@ -196,7 +247,11 @@ case "${OS}-${ARCH}" in
   *) echo "Unsupported platform for binary upgrade: ${OS}-${ARCH}" >&2; exit 1 ;;
 esac
 
-BASE_URL="https://github.com/Extra-Chill/homeboy/releases/latest/download"
+if [ -n "${HOMEBOY_UPGRADE_VERSION:-}" ]; then
+  BASE_URL="https://github.com/Extra-Chill/homeboy/releases/download/v${HOMEBOY_UPGRADE_VERSION}"
+else
+  BASE_URL="https://github.com/Extra-Chill/homeboy/releases/latest/download"
+fi
 TMP_DIR="$(mktemp -d)"
 
 cleanup() { rm -rf "$TMP_DIR"; }
@@ -400,6 +455,15 @@ pub fn config_exists() -> bool {
     paths::homeboy_json().map(|p| p.exists()).unwrap_or(false)
 }
 
+/// Why homeboy.json fails to load, when it exists. `load_config` falls back
+/// to defaults in that case.
+pub fn config_file_error() -> Option<crate::Error> {
+    if !config_exists() {
+        return None;
+    }
+    load_config_from_file().err()
+}
+
 /// Delete homeboy.json file (reset to defaults)
 pub fn reset_config() -> crate::Result<bool> {
     let path = paths::homeboy_json()?;
@@ -445,6 +509,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn source_upgrade_returns_to_a_branch_after_a_pinned_checkout() {
+        use std::process::Command;
+
+        let command = default_source_config().upgrade_command;
+        let checkout = &command[..command.find("fi && ").expect("checkout step") + 2];
+        let git = |dir: &std::path::Path, args: &[&str]| {
+            let status = Command::new("git")
+                .args([
+                    "-c",
+                    "user.name=t",
+                    "-c",
+                    "user.email=t@t",
+                    "-c",
+                    "commit.gpgsign=false",
+                ])
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .expect("git");
+            assert!(status.status.success(), "git {:?}", args);
+        };
+        let run = |dir: &std::path::Path, version: Option<&str>| {
+            let mut shell = Command::new("sh");
+            shell.args(["-c", checkout]).current_dir(dir);
+            match version {
+                Some(version) => shell.env("HOMEBOY_UPGRADE_VERSION", version),
+                None => shell.env_remove("HOMEBOY_UPGRADE_VERSION"),
+            };
+            assert!(shell.output().expect("sh").status.success());
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let origin = dir.path().join("origin");
+        std::fs::create_dir_all(&origin).unwrap();
+        git(&origin, &["init", "-q", "-b", "main"]);
+        git(&origin, &["commit", "-q", "--allow-empty", "-m", "one"]);
+        git(&origin, &["tag", "v1.0.0"]);
+        git(&origin, &["commit", "-q", "--allow-empty", "-m", "two"]);
+        git(dir.path(), &["clone", "-q", "origin", "checkout"]);
+        let checkout_dir = dir.path().join("checkout");
+
+        run(&checkout_dir, Some("1.0.0"));
+        run(&checkout_dir, None);
+
+        let branch = Command::new("git")
+            .args(["symbolic-ref", "--short", "HEAD"])
+            .current_dir(&checkout_dir)
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "main");
+    }
+
+    #[test]
+    fn homeboy_config_parses_upgrade_channel() {
+        let config: HomeboyConfig =
+            serde_json::from_str(r#"{ "upgrade": { "channel": "beta" } }"#).unwrap();
+        assert_eq!(config.upgrade.channel, ReleaseChannel::Beta);
+
+        let config: HomeboyConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.upgrade.channel, ReleaseChannel::Stable);
+    }
+
     #[test]
     fn homeboy_config_leaves_triage_priority_labels_unset_by_default() {
         let config = HomeboyConfig::default();
@@ -0,0 +1,267 @@
+//! Canary deploys across multiple projects.
+//!
+//! `deploy --canary` deploys to a subset of the targets first, verifies that
+//! subset (after an optional soak wait), and only then rolls out to the rest.
+//! A failed canary aborts the rollout, so the remaining projects keep what they
+//! are running.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+use super::types::{DeployConfig, MultiDeployResult, ProjectDeployResult};
+use super::verify::{verify, DeployVerifyResult};
+use super::{
+    deploy_project, multi_deploy_result, project_config, skipped_project_result,
+    unknown_project_results, validate_multi_targets,
+};
+
+/// How many projects make up the canary set: `N` or `N%`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CanarySize {
+    Count(usize),
+    Percent(u8),
+}
+
+impl CanarySize {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let raw = raw.trim();
+        let invalid = |problem: &str| {
+            Error::validation_invalid_argument("canary", problem, Some(raw.to_string()), None)
+                .with_hint("Use a project count like --canary 1 or a share like --canary 10%")
+        };
+
+        if let Some(percent) = raw.strip_suffix('%') {
+            let percent: u8 = percent
+                .parse()
+                .map_err(|_| invalid("canary percentage must be a whole number"))?;
+            if !(1..=100).contains(&percent) {
+                return Err(invalid("canary percentage must be between 1% and 100%"));
+            }
+            return Ok(CanarySize::Percent(percent));
+        }
+
+        let count: usize = raw
+            .parse()
+            .map_err(|_| invalid("canary size must be a count or a percentage"))?;
+        if count == 0 {
+            return Err(invalid("canary count must be at least 1"));
+        }
+        Ok(CanarySize::Count(count))
+    }
+
+    /// Number of canary projects out of `total`: rounded up, at least one.
+    pub fn count(&self, total: usize) -> usize {
+        let count = match *self {
+            CanarySize::Count(count) => count,
+            CanarySize::Percent(percent) => (total * percent as usize).div_ceil(100),
+        };
+        count.clamp(1, total.max(1))
+    }
+}
+
+pub struct CanaryConfig {
+    pub size: CanarySize,
+    /// Soak time between the canary deploy and its verification.
+    pub wait: Option<Duration>,
+}
+
+/// How the canary phase ended.
+#[derive(Debug, Clone, Serialize)]
+pub struct CanaryOutcome {
+    pub projects: Vec<String>,
+    /// `passed`, `failed`, or `declined`.
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Deploy to the canary projects, verify them, and roll out to the remaining
+/// projects only when the canary passed and `proceed` agrees.
+///
+/// `proceed` sees the canary results and is asked only when there is a
+/// rollout left to do. Projects that were not rolled out are reported as
+/// `skipped` in the `rollout` phase.
+pub fn run_multi_canary(
+    project_ids: &[String],
+    component_ids: &[String],
+    config: &DeployConfig,
+    canary: &CanaryConfig,
+    proceed: &mut dyn FnMut(&[ProjectDeployResult]) -> bool,
+) -> Result<MultiDeployResult> {
+    let (valid_project_ids, unknown_projects) =
+        validate_multi_targets(project_ids, component_ids, config)?;
+    let canary_count = canary.size.count(valid_project_ids.len());
+    let (canary_ids, rollout_ids) = valid_project_ids.split_at(canary_count);
+
+    log_status!(
+        "deploy",
+        "Canary: deploying to {} of {} project(s) first: {}",
+        canary_ids.len(),
+        valid_project_ids.len(),
+        canary_ids.join(", ")
+    );
+
+    let mut canary_results: Vec<ProjectDeployResult> = canary_ids
+        .iter()
+        .enumerate()
+        .map(|(index, project_id)| {
+            let mut result = deploy_project(project_id, component_ids, config, index == 0);
+            result.phase = Some("canary".to_string());
+            result
+        })
+        .collect();
+
+    let mut failure = canary_results
+        .iter()
+        .find(|result| result.status == "failed")
+        .map(|result| {
+            format!(
+                "Canary project '{}' failed: {}",
+                result.project_id,
+                result.error.as_deref().unwrap_or("deployment failed")
+            )
+        });
+
+    let planned = config.dry_run || config.check;
+    if failure.is_none() && !planned {
+        if let Some(wait) = canary.wait {
+            log_status!(
+                "deploy",
+                "Canary deployed; waiting {}s before verifying...",
+                wait.as_secs()
+            );
+            std::thread::sleep(wait);
+        }
+        if !config.no_verify {
+            failure = verify_canaries(&mut canary_results, component_ids, config);
+        }
+    }
+
+    let outcome = match failure {
+        Some(reason) => CanaryOutcome {
+            projects: canary_ids.to_vec(),
+            status: "failed".to_string(),
+            reason: Some(reason),
+        },
+        None if rollout_ids.is_empty() || proceed(&canary_results) => CanaryOutcome {
+            projects: canary_ids.to_vec(),
+            status: "passed".to_string(),
+            reason: None,
+        },
+        None => CanaryOutcome {
+            projects: canary_ids.to_vec(),
+            status: "declined".to_string(),
+            reason: Some("Rollout declined after the canary".to_string()),
+        },
+    };
+
+    if let Some(reason) = &outcome.reason {
+        log_status!("deploy", "Canary {}: {}", outcome.status, reason);
+    } else if !rollout_ids.is_empty() {
+        log_status!(
+            "deploy",
+            "Canary passed; rolling out to {} project(s)...",
+            rollout_ids.len()
+        );
+    }
+
+    let mut project_results = unknown_project_results(&unknown_projects);
+    project_results.extend(canary_results);
+    for project_id in rollout_ids {
+        let mut result = match &outcome.reason {
+            None => deploy_project(project_id, component_ids, config, false),
+            Some(reason) => skipped_project_result(project_id, format!("Not deployed: {}", reason)),
+        };
+        result.phase = Some("rollout".to_string());
+        project_results.push(result);
+    }
+
+    Ok(multi_deploy_result(
+        component_ids,
+        project_results,
+        Some(outcome),
+    ))
+}
+
+/// Re-run verification on the deployed canaries, marking any that no longer
+/// pass as failed. Returns the first failure.
+fn verify_canaries(
+    canary_results: &mut [ProjectDeployResult],
+    component_ids: &[String],
+    config: &DeployConfig,
+) -> Option<String> {
+    let mut verify_config = project_config(component_ids, config, false);
+    // Verify exactly what was deployed, even when the deploy selected it as
+    // outdated or behind upstream.
+    verify_config.outdated = false;
+    verify_config.behind_upstream = false;
+
+    let mut first_failure = None;
+    for result in canary_results
+        .iter_mut()
+        .filter(|result| result.status == "deployed")
+    {
+        let problem = match verify(&result.project_id, &verify_config) {
+            Ok(verification) => verification_failure(&verification),
+            Err(error) => Some(error.message),
+        };
+        if let Some(problem) = problem {
+            let reason = format!(
+                "Canary project '{}' failed verification: {}",
+                result.project_id, problem
+            );
+            result.status = "failed".to_string();
+            result.error = Some(reason.clone());
+            first_failure.get_or_insert(reason);
+        }
+    }
+    first_failure
+}
+
+fn verification_failure(verification: &DeployVerifyResult) -> Option<String> {
+    let component = verification
+        .results
+        .iter()
+        .find(|component| component.status == "failed")?;
+    let detail = component
+        .error
+        .clone()
+        .or_else(|| {
+            component
+                .checks
+                .iter()
+                .find(|check| !check.passed)
+                .and_then(|check| check.error.clone().or_else(|| Some(check.output.clone())))
+        })
+        .filter(|detail| !detail.is_empty())
+        .unwrap_or_else(|| "verification probe failed".to_string());
+    Some(format!("{}: {}", component.id, detail))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(CanarySize::parse("2").unwrap(), CanarySize::Count(2));
+        assert_eq!(CanarySize::parse("25%").unwrap(), CanarySize::Percent(25));
+        assert!(CanarySize::parse("0").is_err());
+        assert!(CanarySize::parse("0%").is_err());
+        assert!(CanarySize::parse("150%").is_err());
+        assert!(CanarySize::parse("half").is_err());
+    }
+
+    #[test]
+    fn test_count() {
+        assert_eq!(CanarySize::Count(2).count(10), 2);
+        assert_eq!(CanarySize::Count(5).count(3), 3);
+        assert_eq!(CanarySize::Percent(10).count(10), 1);
+        assert_eq!(CanarySize::Percent(25).count(10), 3);
+        assert_eq!(CanarySize::Percent(1).count(4), 1);
+        assert_eq!(CanarySize::Percent(100).count(4), 4);
+    }
+}
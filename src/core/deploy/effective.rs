@@ -0,0 +1,354 @@
+//! Effective deploy configuration with per-field provenance.
+//!
+//! Several extensions can each contribute deploy behavior through their
+//! `DeployCapability` (overrides, verifications, remote-path inference). This
+//! module resolves which contribution actually applies to a component, records
+//! where each value came from and which precedence rule picked it, and reports
+//! fields where more than one extension offered a different value.
+
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::component::Component;
+use crate::extension::{load_all_extensions, ExtensionManifest};
+
+use super::path_roots::component_remote_path;
+use super::version_overrides::{deploy_override_candidates, deploy_verification_candidates};
+
+/// Default strategy when a component does not set `deploy_strategy`.
+const DEFAULT_STRATEGY: &str = "rsync";
+
+/// Resolved deploy fields and the conflicts found while resolving them.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EffectiveDeployConfig {
+    pub fields: Vec<ResolvedDeployField>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<DeployFieldConflict>,
+}
+
+/// One resolved field and where its value came from.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedDeployField {
+    pub field: String,
+    pub value: Value,
+    /// `component`, `default`, or `extension:<id>`.
+    pub source: String,
+    /// Precedence rule that selected this source.
+    pub rule: String,
+}
+
+/// Two or more extensions supplied different values for the same field.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployFieldConflict {
+    pub field: String,
+    /// Source of the value that applies, or `None` when the conflict left the
+    /// field unresolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub winner: Option<String>,
+    pub candidates: Vec<DeployFieldCandidate>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployFieldCandidate {
+    pub source: String,
+    pub value: Value,
+    /// Whether the extension is linked to the component. Overrides and
+    /// verifications apply from any installed extension.
+    pub linked: bool,
+}
+
+impl EffectiveDeployConfig {
+    pub fn field(&self, name: &str) -> Option<&ResolvedDeployField> {
+        self.fields.iter().find(|field| field.field == name)
+    }
+
+    /// Human-readable warning per conflict, for plan output.
+    pub fn conflict_warnings(&self) -> Vec<String> {
+        self.conflicts
+            .iter()
+            .map(|conflict| {
+                let sources: Vec<&str> = conflict
+                    .candidates
+                    .iter()
+                    .map(|candidate| candidate.source.as_str())
+                    .collect();
+                match &conflict.winner {
+                    Some(winner) => format!(
+                        "Extensions conflict on deploy field '{}' ({}); using {}",
+                        conflict.field,
+                        sources.join(", "),
+                        winner
+                    ),
+                    None => format!(
+                        "Extensions conflict on deploy field '{}' ({}); field left unresolved",
+                        conflict.field,
+                        sources.join(", ")
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn push(&mut self, field: &str, value: Value, source: String, rule: &str) {
+        self.fields.push(ResolvedDeployField {
+            field: field.to_string(),
+            value,
+            source,
+            rule: rule.to_string(),
+        });
+    }
+}
+
+/// Resolve the effective deploy config for `component` against the installed
+/// extensions. `install_dir` is the resolved remote install path, when known;
+/// overrides and verifications are matched against it.
+pub fn resolve_effective_deploy_config(
+    component: &Component,
+    install_dir: Option<&str>,
+) -> EffectiveDeployConfig {
+    let extensions = load_all_extensions().unwrap_or_default();
+    let mut effective = EffectiveDeployConfig::default();
+    resolve_strategy(&mut effective, component);
+    resolve_remote_path(&mut effective, component);
+    if let Some(install_dir) = install_dir {
+        resolve_extension_fields(&mut effective, component, install_dir, &extensions);
+    }
+    effective
+}
+
+fn resolve_strategy(effective: &mut EffectiveDeployConfig, component: &Component) {
+    match &component.deploy_strategy {
+        Some(strategy) => effective.push(
+            "deploy_strategy",
+            Value::String(strategy.clone()),
+            "component".to_string(),
+            "component config",
+        ),
+        None => effective.push(
+            "deploy_strategy",
+            Value::String(DEFAULT_STRATEGY.to_string()),
+            "default".to_string(),
+            "built-in default",
+        ),
+    }
+}
+
+fn resolve_remote_path(effective: &mut EffectiveDeployConfig, component: &Component) {
+    if !component.remote_path.trim().is_empty() {
+        effective.push(
+            "remote_path",
+            Value::String(component.remote_path.clone()),
+            "component".to_string(),
+            "explicit component config beats extension inference",
+        );
+        return;
+    }
+
+    let matches = component.remote_path_inference_matches();
+    let distinct: BTreeSet<&str> = matches.iter().map(|(_, path)| path.as_str()).collect();
+    match distinct.len() {
+        0 => {}
+        1 => {
+            let sources: Vec<String> = matches
+                .iter()
+                .map(|(extension_id, _)| extension_source(extension_id))
+                .collect();
+            effective.push(
+                "remote_path",
+                Value::String(component_remote_path(component)),
+                sources.join(", "),
+                "single inferred path from linked extensions",
+            );
+        }
+        _ => effective.conflicts.push(DeployFieldConflict {
+            field: "remote_path".to_string(),
+            winner: None,
+            candidates: matches
+                .into_iter()
+                .map(|(extension_id, path)| DeployFieldCandidate {
+                    source: extension_source(&extension_id),
+                    value: Value::String(path),
+                    linked: true,
+                })
+                .collect(),
+        }),
+    }
+}
+
+fn resolve_extension_fields(
+    effective: &mut EffectiveDeployConfig,
+    component: &Component,
+    install_dir: &str,
+    extensions: &[ExtensionManifest],
+) {
+    const RULE: &str = "first installed extension by ID whose path_pattern matches";
+
+    let overrides: Vec<(String, Value)> = deploy_override_candidates(extensions, install_dir)
+        .into_iter()
+        .map(|(override_config, extension)| {
+            (
+                extension.id.clone(),
+                Value::String(override_config.install_command.clone()),
+            )
+        })
+        .collect();
+    resolve_first_match(effective, component, "install_command", overrides, RULE);
+
+    let verifications: Vec<(String, Value)> =
+        deploy_verification_candidates(extensions, install_dir)
+            .into_iter()
+            .map(|(verification, extension)| {
+                (
+                    extension.id.clone(),
+                    verification
+                        .verify_command
+                        .clone()
+                        .map(Value::String)
+                        .unwrap_or(Value::Null),
+                )
+            })
+            .collect();
+    resolve_first_match(effective, component, "verify_command", verifications, RULE);
+}
+
+/// Record the first candidate as the winner and flag a conflict when another
+/// extension offered a different value.
+fn resolve_first_match(
+    effective: &mut EffectiveDeployConfig,
+    component: &Component,
+    field: &str,
+    candidates: Vec<(String, Value)>,
+    rule: &str,
+) {
+    let Some((winner_id, winner_value)) = candidates.first().cloned() else {
+        return;
+    };
+    effective.push(
+        field,
+        winner_value.clone(),
+        extension_source(&winner_id),
+        rule,
+    );
+
+    let disagrees = candidates
+        .iter()
+        .any(|(extension_id, value)| *extension_id != winner_id && *value != winner_value);
+    if disagrees {
+        effective.conflicts.push(DeployFieldConflict {
+            field: field.to_string(),
+            winner: Some(extension_source(&winner_id)),
+            candidates: candidates
+                .into_iter()
+                .map(|(extension_id, value)| DeployFieldCandidate {
+                    linked: component
+                        .extensions
+                        .as_ref()
+                        .is_some_and(|linked| linked.contains_key(&extension_id)),
+                    source: extension_source(&extension_id),
+                    value,
+                })
+                .collect(),
+        });
+    }
+}
+
+fn extension_source(extension_id: &str) -> String {
+    format!("extension:{}", extension_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extension::{DeployCapability, DeployOverride, DeployVerification};
+    use serde_json::json;
+
+    fn extension(id: &str, overrides: Vec<DeployOverride>) -> ExtensionManifest {
+        let mut manifest: ExtensionManifest =
+            serde_json::from_value(json!({"name": id, "version": "1.0.0"})).expect("manifest");
+        manifest.id = id.to_string();
+        manifest.deploy = Some(DeployCapability {
+            verifications: vec![DeployVerification {
+                path_pattern: "/plugins/".to_string(),
+                verify_command: Some(format!("{}-verify", id)),
+                verify_error_message: None,
+            }],
+            overrides,
+            remote_path_inference: Vec::new(),
+            path_roots: Vec::new(),
+            version_patterns: Vec::new(),
+            since_tag: None,
+        });
+        manifest
+    }
+
+    fn install_override(command: &str) -> DeployOverride {
+        DeployOverride {
+            path_pattern: "/plugins/".to_string(),
+            staging_path: "/tmp/homeboy-staging".to_string(),
+            install_command: command.to_string(),
+            cleanup_command: None,
+            skip_permissions_fix: false,
+        }
+    }
+
+    fn component() -> Component {
+        Component::new(
+            "site".to_string(),
+            "/tmp/homeboy-effective-site".to_string(),
+            "wp-content/plugins/site".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_resolve_first_match() {
+        let extensions = vec![
+            extension("alpha", vec![install_override("wp plugin install")]),
+            extension("beta", vec![install_override("wp plugin install")]),
+        ];
+        let mut effective = EffectiveDeployConfig::default();
+
+        resolve_extension_fields(
+            &mut effective,
+            &component(),
+            "/var/www/wp-content/plugins/site",
+            &extensions,
+        );
+
+        let install = effective.field("install_command").expect("install_command");
+        assert_eq!(install.source, "extension:alpha");
+        let verify = effective.field("verify_command").expect("verify_command");
+        assert_eq!(verify.value, "alpha-verify");
+
+        // Identical install commands agree; differing verify commands conflict.
+        assert_eq!(effective.conflicts.len(), 1);
+        let conflict = &effective.conflicts[0];
+        assert_eq!(conflict.field, "verify_command");
+        assert_eq!(conflict.winner.as_deref(), Some("extension:alpha"));
+        assert_eq!(conflict.candidates.len(), 2);
+        assert!(!conflict.candidates[1].linked);
+        assert!(effective.conflict_warnings()[0].contains("using extension:alpha"));
+    }
+
+    #[test]
+    fn resolve_effective_deploy_config_prefers_component_values() {
+        let mut component = component();
+        component.deploy_strategy = Some("git".to_string());
+
+        let effective = crate::test_support::with_isolated_home(|_| {
+            resolve_effective_deploy_config(&component, None)
+        });
+
+        let strategy = effective.field("deploy_strategy").expect("strategy");
+        assert_eq!(strategy.value, "git");
+        assert_eq!(strategy.source, "component");
+        assert_eq!(
+            effective.field("remote_path").expect("remote_path").value,
+            "wp-content/plugins/site"
+        );
+        assert!(effective.field("install_command").is_none());
+        assert!(effective.conflicts.is_empty());
+    }
+}
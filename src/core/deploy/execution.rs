@@ -41,12 +41,16 @@ pub(super) fn execute_component_deploy(
         };
 
     // Build (git-deploy, file-deploy, skip-build, and release-download skip this step)
-    let (build_exit_code, build_error) =
-        if is_git_deploy || is_file_deploy || config.skip_build || release_artifact.is_some() {
-            (Some(0), None)
-        } else {
-            build::build_component(component)
-        };
+    let (build_exit_code, build_error) = if is_git_deploy
+        || is_file_deploy
+        || config.skip_build
+        || release_artifact.is_some()
+        || !config.step_filter.should_run("build")
+    {
+        (Some(0), None)
+    } else {
+        build::build_component(component)
+    };
 
     if let Some(ref error) = build_error {
         return ComponentDeployResult::failed(
@@ -110,6 +114,19 @@ pub(super) fn execute_component_deploy(
             }
         };
 
+    if !config.step_filter.should_run("upload") {
+        log_status!(
+            "deploy",
+            "Skipping upload for '{}' (--only/--skip)",
+            component.id
+        );
+        return ComponentDeployResult::new(component, base_path)
+            .with_status("skipped")
+            .with_versions(local_version, remote_version)
+            .with_remote_path(install_dir)
+            .with_build_exit_code(build_exit_code);
+    }
+
     // Dispatch by deploy strategy
     let strategy = component.deploy_strategy.as_deref().unwrap_or("rsync");
     let versions = (local_version, remote_version);
@@ -129,6 +146,7 @@ pub(super) fn execute_component_deploy(
     if strategy == "file" {
         return execute_file_deploy(
             component,
+            config,
             ctx,
             base_path,
             &install_dir,
@@ -151,7 +169,7 @@ pub(super) fn execute_component_deploy(
     )
 }
 
-fn should_try_download_release_artifact(
+pub(super) fn should_try_download_release_artifact(
     component: &Component,
     config: &DeployConfig,
     is_git_deploy: bool,
@@ -203,7 +221,9 @@ fn execute_git_deploy(
             if let Ok(Some(summary)) = cleanup_build_dependencies(component, config) {
                 log_status!("deploy", "Cleanup: {}", summary);
             }
-            run_post_deploy_hooks(&ctx.client, component, install_dir, base_path);
+            if config.step_filter.should_run("post_deploy") {
+                run_post_deploy_hooks(&ctx.client, component, install_dir, base_path);
+            }
 
             ComponentDeployResult::new(component, base_path)
                 .with_status("deployed")
@@ -243,6 +263,7 @@ fn execute_git_deploy(
 /// Upload uses atomic SCP (temp file + mv) to prevent partial writes.
 fn execute_file_deploy(
     component: &Component,
+    config: &DeployConfig,
     ctx: &RemoteProjectContext,
     base_path: &str,
     install_dir: &str,
@@ -330,12 +351,9 @@ fn execute_file_deploy(
                 }
             }
 
-            super::version_overrides::run_post_deploy_hooks(
-                &ctx.client,
-                component,
-                install_dir,
-                base_path,
-            );
+            if config.step_filter.should_run("post_deploy") {
+                run_post_deploy_hooks(&ctx.client, component, install_dir, base_path);
+            }
 
             ComponentDeployResult::new(component, base_path)
                 .with_status("deployed")
@@ -445,7 +463,11 @@ fn execute_artifact_deploy(
     };
 
     // Look up verification from extensions
-    let verification = find_deploy_verification(install_dir);
+    let verification = if config.no_verify {
+        None
+    } else {
+        find_deploy_verification(install_dir)
+    };
 
     // Check for extension-defined deploy override
     let deploy_result =
@@ -489,7 +511,9 @@ fn execute_artifact_deploy(
                     component.id
                 );
             }
-            run_post_deploy_hooks(&ctx.client, component, install_dir, base_path);
+            if config.step_filter.should_run("post_deploy") {
+                run_post_deploy_hooks(&ctx.client, component, install_dir, base_path);
+            }
 
             ComponentDeployResult::new(component, base_path)
                 .with_status("deployed")
@@ -580,6 +604,10 @@ fn cleanup_build_dependencies(
         return Ok(Some("skipped (--keep-deps flag)".to_string()));
     }
 
+    if !config.step_filter.should_run("cleanup") {
+        return Ok(Some("skipped (--only/--skip)".to_string()));
+    }
+
     // Collect cleanup paths from linked extensions
     let mut cleanup_paths = Vec::new();
     if let Some(ref extensions) = component.extensions {
@@ -710,6 +738,8 @@ mod tests {
             no_pull: false,
             head: true,
             tagged: false,
+            no_verify: false,
+            step_filter: Default::default(),
         };
 
         assert!(!should_try_download_release_artifact(
@@ -1,7 +1,10 @@
+mod canary;
+mod effective;
 mod execution;
 mod orchestration;
 mod path_roots;
 pub(crate) mod permissions;
+mod plan;
 mod planning;
 mod policy;
 pub(crate) mod provenance;
@@ -9,14 +12,24 @@ pub mod release_download;
 mod safety_and_artifact;
 mod transfer;
 mod types;
+mod verify;
 mod version_overrides;
 
 // Public API — re-export types and entry points used outside the deploy module
+pub use canary::{run_multi_canary, CanaryConfig, CanaryOutcome, CanarySize};
+pub use effective::{
+    resolve_effective_deploy_config, DeployFieldCandidate, DeployFieldConflict,
+    EffectiveDeployConfig, ResolvedDeployField,
+};
+pub use plan::{plan, DeployComponentPlan, DeployPlan};
 pub use planning::{bucket_release_states, calculate_release_state, classify_release_state};
 pub use types::{
     parse_bulk_component_ids, ComponentDeployResult, ComponentStatus, DeployConfig,
     DeployOrchestrationResult, DeployReason, DeploySummary, MultiDeployResult, MultiDeploySummary,
-    ProjectDeployResult, ReleaseState, ReleaseStateBuckets, ReleaseStateStatus,
+    ProjectDeployResult, ReleaseState, ReleaseStateBuckets, ReleaseStateStatus, DEPLOY_STEP_NAMES,
+};
+pub use verify::{
+    verify, ComponentVerifyResult, DeployVerifyResult, DeployVerifySummary, VerificationCheck,
 };
 pub use version_overrides::fetch_remote_versions;
 
@@ -30,6 +43,9 @@ use crate::project;
 /// This is the preferred entry point for callers - it handles project loading
 /// and SSH context resolution, keeping those details encapsulated.
 pub fn run(project_id: &str, config: &DeployConfig) -> Result<DeployOrchestrationResult> {
+    config
+        .step_filter
+        .validate("only/skip", DEPLOY_STEP_NAMES.iter().copied())?;
     let project = project::load(project_id)?;
     let (ctx, base_path) = resolve_project_ssh_with_base_path(project_id)?;
     orchestration::deploy_components(config, &project, &ctx, &base_path)
@@ -48,6 +64,29 @@ pub fn run_multi(
     component_ids: &[String],
     config: &DeployConfig,
 ) -> Result<MultiDeployResult> {
+    let (valid_project_ids, unknown_projects) =
+        validate_multi_targets(project_ids, component_ids, config)?;
+
+    let mut project_results = unknown_project_results(&unknown_projects);
+    for (index, project_id) in valid_project_ids.iter().enumerate() {
+        project_results.push(deploy_project(
+            project_id,
+            component_ids,
+            config,
+            index == 0,
+        ));
+    }
+
+    Ok(multi_deploy_result(component_ids, project_results, None))
+}
+
+/// Validate a multi-project deploy and split `project_ids` into known and
+/// unknown projects.
+fn validate_multi_targets(
+    project_ids: &[String],
+    component_ids: &[String],
+    config: &DeployConfig,
+) -> Result<(Vec<String>, Vec<String>)> {
     if component_ids.is_empty() {
         return Err(Error::validation_invalid_argument(
             "component_ids",
@@ -56,21 +95,16 @@ pub fn run_multi(
             None,
         ));
     }
+    config
+        .step_filter
+        .validate("only/skip", DEPLOY_STEP_NAMES.iter().copied())?;
 
     // Validate project IDs, skip unknown ones
     let known_projects = project::list_ids().unwrap_or_default();
-    let mut unknown_projects = Vec::new();
-    let valid_project_ids: Vec<&String> = project_ids
+    let (valid_project_ids, unknown_projects): (Vec<String>, Vec<String>) = project_ids
         .iter()
-        .filter(|pid| {
-            if known_projects.contains(pid) {
-                true
-            } else {
-                unknown_projects.push(pid.to_string());
-                false
-            }
-        })
-        .collect();
+        .cloned()
+        .partition(|pid| known_projects.contains(pid));
 
     for pid in &unknown_projects {
         log_status!(
@@ -105,123 +139,132 @@ pub fn run_multi(
         }
     );
 
-    let mut project_results = Vec::new();
-    let mut succeeded: u32 = 0;
-    let mut failed: u32 = 0;
-    let skipped: u32 = unknown_projects.len() as u32;
-    let mut planned: u32 = 0;
-    let mut first_project = true;
+    Ok((valid_project_ids, unknown_projects))
+}
+
+/// Skipped results for unknown projects.
+fn unknown_project_results(unknown_projects: &[String]) -> Vec<ProjectDeployResult> {
+    unknown_projects
+        .iter()
+        .map(|pid| skipped_project_result(pid, format!("Project '{}' not found — skipped", pid)))
+        .collect()
+}
 
-    // Record skipped results for unknown projects
-    for pid in &unknown_projects {
-        project_results.push(ProjectDeployResult {
-            project_id: pid.clone(),
-            status: "skipped".to_string(),
-            error: Some(format!("Project '{}' not found — skipped", pid)),
+fn skipped_project_result(project_id: &str, reason: String) -> ProjectDeployResult {
+    ProjectDeployResult {
+        project_id: project_id.to_string(),
+        status: "skipped".to_string(),
+        phase: None,
+        error: Some(reason),
+        results: vec![],
+        summary: DeploySummary {
+            total: 0,
+            succeeded: 0,
+            skipped: 0,
+            failed: 0,
+        },
+    }
+}
+
+/// Deploy to one project of a multi-project run.
+fn deploy_project(
+    project_id: &str,
+    component_ids: &[String],
+    config: &DeployConfig,
+    first_project: bool,
+) -> ProjectDeployResult {
+    log_status!("deploy", "Deploying to project '{}'...", project_id);
+
+    match run(
+        project_id,
+        &project_config(component_ids, config, first_project),
+    ) {
+        Ok(result) => {
+            let (status, error) = if result.summary.failed > 0 {
+                let error_msg = result
+                    .results
+                    .iter()
+                    .find_map(|r| r.error.clone())
+                    .unwrap_or_else(|| "Deployment failed".to_string());
+                ("failed", Some(error_msg))
+            } else if config.dry_run || config.check {
+                ("planned", None)
+            } else {
+                ("deployed", None)
+            };
+            ProjectDeployResult {
+                project_id: project_id.to_string(),
+                status: status.to_string(),
+                phase: None,
+                error,
+                results: result.results,
+                summary: result.summary,
+            }
+        }
+        Err(e) => ProjectDeployResult {
+            project_id: project_id.to_string(),
+            status: "failed".to_string(),
+            phase: None,
+            error: Some(e.to_string()),
             results: vec![],
             summary: DeploySummary {
                 total: 0,
                 succeeded: 0,
                 skipped: 0,
-                failed: 0,
+                failed: 1,
             },
-        });
+        },
     }
+}
 
-    for project_id in &valid_project_ids {
-        log_status!("deploy", "Deploying to project '{}'...", project_id);
-
-        let project_config = DeployConfig {
-            component_ids: component_ids.to_vec(),
-            all: config.all,
-            outdated: config.outdated,
-            behind_upstream: config.behind_upstream,
-            dry_run: config.dry_run,
-            check: config.check,
-            force: config.force,
-            // Build-skip optimization: only build on first project
-            skip_build: config.skip_build || !first_project,
-            keep_deps: config.keep_deps,
-            expected_version: config.expected_version.clone(),
-            // Only pull on first project
-            no_pull: config.no_pull || !first_project,
-            head: config.head,
-            tagged: config.tagged,
-        };
-
-        match run(project_id, &project_config) {
-            Ok(result) => {
-                let deploy_failed = result.summary.failed > 0;
-                let is_planned = config.dry_run || config.check;
-
-                if deploy_failed {
-                    let error_msg = result
-                        .results
-                        .iter()
-                        .find_map(|r| r.error.clone())
-                        .unwrap_or_else(|| "Deployment failed".to_string());
-
-                    project_results.push(ProjectDeployResult {
-                        project_id: project_id.to_string(),
-                        status: "failed".to_string(),
-                        error: Some(error_msg),
-                        results: result.results,
-                        summary: result.summary,
-                    });
-                    failed += 1;
-                } else if is_planned {
-                    project_results.push(ProjectDeployResult {
-                        project_id: project_id.to_string(),
-                        status: "planned".to_string(),
-                        error: None,
-                        results: result.results,
-                        summary: result.summary,
-                    });
-                    planned += 1;
-                } else {
-                    project_results.push(ProjectDeployResult {
-                        project_id: project_id.to_string(),
-                        status: "deployed".to_string(),
-                        error: None,
-                        results: result.results,
-                        summary: result.summary,
-                    });
-                    succeeded += 1;
-                }
-            }
-            Err(e) => {
-                project_results.push(ProjectDeployResult {
-                    project_id: project_id.to_string(),
-                    status: "failed".to_string(),
-                    error: Some(e.to_string()),
-                    results: vec![],
-                    summary: DeploySummary {
-                        total: 0,
-                        succeeded: 0,
-                        skipped: 0,
-                        failed: 1,
-                    },
-                });
-                failed += 1;
-            }
-        }
-
-        first_project = false;
+/// Per-project config for a multi-project run: the build-skip optimization
+/// only builds and pulls on the first project, later ones reuse its artifact.
+fn project_config(
+    component_ids: &[String],
+    config: &DeployConfig,
+    first_project: bool,
+) -> DeployConfig {
+    DeployConfig {
+        component_ids: component_ids.to_vec(),
+        all: config.all,
+        outdated: config.outdated,
+        behind_upstream: config.behind_upstream,
+        dry_run: config.dry_run,
+        check: config.check,
+        force: config.force,
+        // Build-skip optimization: only build on first project
+        skip_build: config.skip_build || !first_project,
+        keep_deps: config.keep_deps,
+        expected_version: config.expected_version.clone(),
+        // Only pull on first project
+        no_pull: config.no_pull || !first_project,
+        head: config.head,
+        tagged: config.tagged,
+        no_verify: config.no_verify,
+        step_filter: config.step_filter.clone(),
     }
+}
 
-    let total_projects = project_results.len() as u32;
+fn multi_deploy_result(
+    component_ids: &[String],
+    projects: Vec<ProjectDeployResult>,
+    canary: Option<CanaryOutcome>,
+) -> MultiDeployResult {
+    let count = |status: &str| projects.iter().filter(|p| p.status == status).count() as u32;
+    let summary = MultiDeploySummary {
+        total_projects: projects.len() as u32,
+        succeeded: count("deployed"),
+        failed: count("failed"),
+        skipped: count("skipped"),
+        planned: count("planned"),
+    };
 
-    Ok(MultiDeployResult {
+    MultiDeployResult {
         component_ids: component_ids.to_vec(),
-        projects: project_results,
-        summary: MultiDeploySummary {
-            total_projects,
-            succeeded,
-            failed,
-            skipped,
-            planned,
-        },
-    })
+        projects,
+        summary,
+        canary,
+    }
 }
 
 /// Find all projects that use any of the specified components.
@@ -260,3 +303,25 @@ pub fn resolve_shared_targets(component_ids: &[String]) -> Result<Vec<String>> {
 
     Ok(project_ids)
 }
+
+/// Resolve project IDs carrying every tag in `tags`.
+pub fn resolve_tagged_targets(tags: &[String]) -> Result<Vec<String>> {
+    let project_ids: Vec<String> = project::list_tagged(tags)?
+        .into_iter()
+        .map(|p| p.id)
+        .collect();
+
+    if project_ids.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "project_tag",
+            format!("No projects tagged {}", tags.join(", ")),
+            None,
+            Some(vec![
+                "Run 'homeboy project list' to see project tags".to_string(),
+                "Tag a project with 'homeboy project tag add <project> <tag>'".to_string(),
+            ]),
+        ));
+    }
+
+    Ok(project_ids)
+}
@@ -15,6 +15,7 @@ use super::planning::{
 };
 use super::types::{
     ComponentDeployResult, ComponentStatus, DeployConfig, DeployOrchestrationResult, DeploySummary,
+    DEPLOY_STEP_NAMES,
 };
 use super::version_overrides::fetch_remote_versions_for_project;
 
@@ -26,7 +27,7 @@ pub(super) fn deploy_components(
     ctx: &RemoteProjectContext,
     base_path: &str,
 ) -> Result<DeployOrchestrationResult> {
-    let loaded = load_project_components(project, &config.component_ids)?;
+    let loaded = load_project_components(project, &config.component_ids, config.force)?;
     if loaded.deployable.is_empty() {
         let message = if loaded.skipped.is_empty() {
             "No components configured for project".to_string()
@@ -108,7 +109,7 @@ pub(super) fn deploy_components(
     }
 
     // Sync: pull latest changes before deploying (unless --no-pull or --skip-build)
-    if !config.no_pull && !config.skip_build {
+    if !config.no_pull && !config.skip_build && config.step_filter.should_run("pull") {
         sync_components(&components)?;
     }
 
@@ -142,6 +143,10 @@ pub(super) fn deploy_components(
     let mut results: Vec<ComponentDeployResult> = vec![];
     let mut succeeded: u32 = 0;
     let mut failed: u32 = 0;
+    let mut skipped: u32 = 0;
+    let skipped_steps = config
+        .step_filter
+        .skipped_names(DEPLOY_STEP_NAMES.iter().copied());
 
     for component in &components {
         // Apply per-project overrides (e.g. different extract_command or remote_owner)
@@ -176,10 +181,12 @@ pub(super) fn deploy_components(
             }
         }
 
-        if result.status == "deployed" {
-            succeeded += 1;
-        } else {
-            failed += 1;
+        result = result.with_skipped_steps(skipped_steps.clone());
+
+        match result.status.as_str() {
+            "deployed" => succeeded += 1,
+            "skipped" => skipped += 1,
+            _ => failed += 1,
         }
         results.push(result);
     }
@@ -192,10 +199,10 @@ pub(super) fn deploy_components(
     Ok(DeployOrchestrationResult {
         results,
         summary: DeploySummary {
-            total: succeeded + failed,
+            total: succeeded + failed + skipped,
             succeeded,
             failed,
-            skipped: 0,
+            skipped,
         },
     })
 }
@@ -273,6 +280,11 @@ fn run_dry_run_mode(
                 .with_versions(
                     local_versions.get(&c.id).cloned(),
                     remote_versions.get(&c.id).cloned(),
+                )
+                .with_skipped_steps(
+                    config
+                        .step_filter
+                        .skipped_names(DEPLOY_STEP_NAMES.iter().copied()),
                 );
             if config.check {
                 result = result.with_component_status(status);
@@ -696,6 +708,8 @@ fn clone_config(config: &DeployConfig) -> DeployConfig {
         no_pull: config.no_pull,
         head: config.head,
         tagged: config.tagged,
+        no_verify: config.no_verify,
+        step_filter: config.step_filter.clone(),
     }
 }
 
@@ -756,6 +770,8 @@ mod tests {
             no_pull: false,
             head: false,
             tagged: false,
+            no_verify: false,
+            step_filter: Default::default(),
         }
     }
 
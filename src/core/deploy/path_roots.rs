@@ -228,6 +228,7 @@ mod tests {
             auth: None,
             is_local: true,
             env: HashMap::new(),
+            proxy_jump: None,
         }
     }
 
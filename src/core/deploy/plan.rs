@@ -0,0 +1,424 @@
+//! Side-effect-free deploy plan.
+//!
+//! `deploy --plan` resolves everything `deploy` would do — selected
+//! components, target server, build command, upload destination, verification,
+//! and hooks — and reports it as one [`HomeboyPlan`] per component without
+//! pulling, building, checking out tags, or touching the server. Each plan
+//! carries the [`EffectiveDeployConfig`] so extension precedence is visible.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::component::Component;
+use crate::context::resolve_project_ssh_with_base_path;
+use crate::engine::hooks;
+use crate::error::Result;
+use crate::extension::build::{resolve_build_command, ResolvedBuildCommand};
+use crate::git;
+use crate::plan::{HomeboyPlan, PlanKind, PlanStep, PlanStepStatus};
+use crate::project::{self, Project};
+
+use super::effective::{resolve_effective_deploy_config, EffectiveDeployConfig};
+use super::execution::should_try_download_release_artifact;
+use super::path_roots::resolve_effective_remote_path;
+use super::planning::{load_project_components, plan_components};
+use super::types::{DeployConfig, DEPLOY_STEP_NAMES};
+
+/// Fully-resolved deploy plan for one project.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployPlan {
+    pub project_id: String,
+    pub server_id: String,
+    pub host: String,
+    pub base_path: String,
+    pub components: Vec<DeployComponentPlan>,
+    /// Attached components with no artifact or deploy strategy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<String>,
+}
+
+/// Plan for one component plus the provenance of its deploy config.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployComponentPlan {
+    #[serde(flatten)]
+    pub plan: HomeboyPlan,
+    pub effective: EffectiveDeployConfig,
+}
+
+/// Resolve the deploy plan for `project_id` without executing any step.
+pub fn plan(project_id: &str, config: &DeployConfig) -> Result<DeployPlan> {
+    config
+        .step_filter
+        .validate("only/skip", DEPLOY_STEP_NAMES.iter().copied())?;
+    let (ctx, base_path) = resolve_project_ssh_with_base_path(project_id)?;
+    let project = ctx.project;
+
+    let loaded = load_project_components(&project, &config.component_ids, config.force)?;
+    let selected = plan_components(
+        config,
+        &loaded.deployable,
+        &loaded.skipped,
+        &project,
+        &base_path,
+        &ctx.client,
+    )?;
+
+    let components = selected
+        .iter()
+        .map(|component| {
+            let component = project::apply_component_overrides(component, &project);
+            plan_component(&component, config, &project, &base_path)
+        })
+        .collect();
+
+    Ok(DeployPlan {
+        project_id: project.id.clone(),
+        server_id: ctx.server_id,
+        host: ctx.server.host,
+        base_path,
+        components,
+        skipped: loaded.skipped,
+    })
+}
+
+fn plan_component(
+    component: &Component,
+    config: &DeployConfig,
+    project: &Project,
+    base_path: &str,
+) -> DeployComponentPlan {
+    let strategy = component.deploy_strategy.as_deref().unwrap_or("rsync");
+    let mut warnings = Vec::new();
+
+    let git_ref = if config.head {
+        "HEAD".to_string()
+    } else {
+        git::get_latest_tag(&component.local_path)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| "HEAD (no tags)".to_string())
+    };
+
+    let install_dir = match resolve_effective_remote_path(project, component, base_path) {
+        Ok(dir) => Some(dir),
+        Err(err) => {
+            warnings.push(err.message);
+            None
+        }
+    };
+
+    let effective = resolve_effective_deploy_config(component, install_dir.as_deref());
+    warnings.extend(effective.conflict_warnings());
+
+    let mut steps = vec![pull_step(config), build_step(component, config, strategy)];
+    steps.extend(upload_steps(
+        component,
+        config,
+        strategy,
+        install_dir.as_deref(),
+        &effective,
+    ));
+    steps.push(cleanup_step(component, config));
+    steps.push(post_deploy_step(component, config));
+
+    let plan = HomeboyPlan::builder_for_component(PlanKind::Deploy, component.id.clone())
+        .mode("plan")
+        .inputs([
+            ("project_id".to_string(), Value::String(project.id.clone())),
+            ("strategy".to_string(), Value::String(strategy.to_string())),
+            ("ref".to_string(), Value::String(git_ref)),
+        ])
+        .steps(steps)
+        .warnings(warnings)
+        .summarize_disabled_as_skipped()
+        .build();
+
+    DeployComponentPlan { plan, effective }
+}
+
+/// Disabled step for `name` when the config or `--only`/`--skip` rules it out.
+fn filtered(config: &DeployConfig, name: &str) -> Option<PlanStep> {
+    config
+        .step_filter
+        .skip_reason(name)
+        .map(|reason| PlanStep::disabled_with_reason(name, name, reason).build())
+}
+
+fn pull_step(config: &DeployConfig) -> PlanStep {
+    if let Some(step) = filtered(config, "pull") {
+        return step;
+    }
+    if config.no_pull {
+        return PlanStep::disabled_with_reason("pull", "git", "--no-pull").build();
+    }
+    if config.skip_build {
+        return PlanStep::disabled_with_reason("pull", "git", "build skipped by caller").build();
+    }
+    PlanStep::ready("pull", "git")
+        .label("Pull latest changes")
+        .build()
+}
+
+fn build_step(component: &Component, config: &DeployConfig, strategy: &str) -> PlanStep {
+    if let Some(step) = filtered(config, "build") {
+        return step;
+    }
+    if strategy == "git" || strategy == "file" {
+        let reason = format!("{} deploys need no build", strategy);
+        return PlanStep::disabled_with_reason("build", "build", reason).build();
+    }
+    if config.skip_build {
+        return PlanStep::disabled_with_reason("build", "build", "build skipped by caller").build();
+    }
+
+    let mut step = PlanStep::ready("build", "build")
+        .label("Build component")
+        .needs(["pull".to_string()]);
+    if should_try_download_release_artifact(component, config, false, false) {
+        step = step.input_value("release_download", Value::Bool(true));
+    }
+    match resolve_build_command(component) {
+        Ok(resolved) => {
+            let source = match &resolved {
+                ResolvedBuildCommand::ComponentScript { .. } => "component:scripts.build".into(),
+                ResolvedBuildCommand::ExtensionProvided { source, .. } => source.clone(),
+                ResolvedBuildCommand::LocalScript { script_name, .. } => {
+                    format!("local:{}", script_name)
+                }
+            };
+            step.input_value("command", Value::String(resolved.command().to_string()))
+                .input_value("source", Value::String(source))
+                .build()
+        }
+        Err(err) => {
+            let mut step = step.build();
+            step.status = PlanStepStatus::Missing;
+            step.missing.push(err.message);
+            step
+        }
+    }
+}
+
+fn upload_steps(
+    component: &Component,
+    config: &DeployConfig,
+    strategy: &str,
+    install_dir: Option<&str>,
+    effective: &EffectiveDeployConfig,
+) -> Vec<PlanStep> {
+    if let Some(step) = filtered(config, "upload") {
+        return vec![step];
+    }
+
+    let Some(install_dir) = install_dir else {
+        let mut step = PlanStep::ready("upload", "upload").build();
+        step.status = PlanStepStatus::Missing;
+        step.missing.push("remote install path".to_string());
+        return vec![step];
+    };
+
+    let mut upload = PlanStep::ready("upload", "upload")
+        .label("Upload to server")
+        .needs(["build".to_string()])
+        .input_value("strategy", Value::String(strategy.to_string()))
+        .input_value("destination", Value::String(install_dir.to_string()));
+    if strategy == "file" {
+        upload = upload.input_value("source", Value::String(component.local_path.clone()));
+    } else if let Some(artifact) = &component.build_artifact {
+        upload = upload.input_value("artifact", Value::String(artifact.clone()));
+    }
+    if let Some(owner) = &component.remote_owner {
+        upload = upload.input_value("remote_owner", Value::String(owner.clone()));
+    }
+    if let Some(extract) = &component.extract_command {
+        upload = upload.input_value("extract_command", Value::String(extract.clone()));
+    }
+    if strategy == "rsync" {
+        if let Some(install) = effective.field("install_command") {
+            upload = upload
+                .input_value("override_extension", Value::String(install.source.clone()))
+                .input_value("install_command", install.value.clone());
+        }
+    }
+
+    let verify = match effective
+        .field("verify_command")
+        .filter(|_| !config.no_verify)
+        .filter(|field| strategy == "rsync" && !field.value.is_null())
+    {
+        Some(field) => PlanStep::ready("verify", "verify")
+            .label("Verify deployed files")
+            .needs(["upload".to_string()])
+            .input_value("command", field.value.clone())
+            .input_value("extension", Value::String(field.source.clone()))
+            .build(),
+        None if config.no_verify => {
+            PlanStep::disabled_with_reason("verify", "verify", "--no-verify").build()
+        }
+        None => {
+            PlanStep::disabled_with_reason("verify", "verify", "no extension verification").build()
+        }
+    };
+
+    vec![upload.build(), verify]
+}
+
+fn cleanup_step(component: &Component, config: &DeployConfig) -> PlanStep {
+    if let Some(step) = filtered(config, "cleanup") {
+        return step;
+    }
+    if !component.auto_cleanup {
+        return PlanStep::disabled_with_reason("cleanup", "cleanup", "auto_cleanup is off").build();
+    }
+    if config.keep_deps {
+        return PlanStep::disabled_with_reason("cleanup", "cleanup", "--keep-deps").build();
+    }
+    PlanStep::ready("cleanup", "cleanup")
+        .label("Remove build dependencies")
+        .needs(["upload".to_string()])
+        .build()
+}
+
+fn post_deploy_step(component: &Component, config: &DeployConfig) -> PlanStep {
+    if let Some(step) = filtered(config, "post_deploy") {
+        return step;
+    }
+    let commands = hooks::resolve_hooks(component, hooks::events::POST_DEPLOY);
+    if commands.is_empty() {
+        return PlanStep::disabled_with_reason("post_deploy", "hook", "no post:deploy hooks")
+            .build();
+    }
+    PlanStep::ready("post_deploy", "hook")
+        .label("Run post:deploy hooks")
+        .needs(["upload".to_string()])
+        .blocking(false)
+        .input_value(
+            "commands",
+            Value::Array(commands.into_iter().map(Value::String).collect()),
+        )
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plan::PlanStepFilter;
+
+    fn config() -> DeployConfig {
+        DeployConfig {
+            component_ids: vec!["site".to_string()],
+            all: false,
+            outdated: false,
+            behind_upstream: false,
+            dry_run: false,
+            check: false,
+            force: false,
+            skip_build: false,
+            keep_deps: false,
+            expected_version: None,
+            no_pull: false,
+            head: true,
+            tagged: false,
+            no_verify: false,
+            step_filter: PlanStepFilter::default(),
+        }
+    }
+
+    fn component() -> Component {
+        let mut component = Component::new(
+            "site".to_string(),
+            "/tmp/homeboy-plan-site".to_string(),
+            "wp-content/plugins/site".to_string(),
+            Some("build/site.zip".to_string()),
+        );
+        component.hooks.insert(
+            hooks::events::POST_DEPLOY.to_string(),
+            vec!["wp cache flush".to_string()],
+        );
+        component
+    }
+
+    fn project() -> Project {
+        Project {
+            id: "prod".to_string(),
+            base_path: Some("/var/www".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn step<'a>(plan: &'a DeployComponentPlan, id: &str) -> &'a PlanStep {
+        plan.plan
+            .steps
+            .iter()
+            .find(|step| step.id == id)
+            .unwrap_or_else(|| panic!("missing step {}", id))
+    }
+
+    #[test]
+    fn test_plan_component() {
+        let plan = crate::test_support::with_isolated_home(|_| {
+            plan_component(&component(), &config(), &project(), "/var/www")
+        });
+
+        assert_eq!(plan.plan.id, "deploy.site");
+        assert_eq!(plan.plan.mode.as_deref(), Some("plan"));
+        assert_eq!(plan.plan.inputs["ref"], "HEAD");
+        assert_eq!(
+            plan.effective
+                .field("remote_path")
+                .map(|f| f.source.as_str()),
+            Some("component")
+        );
+
+        let upload = step(&plan, "upload");
+        assert_eq!(upload.status, PlanStepStatus::Ready);
+        assert_eq!(
+            upload.inputs["destination"],
+            "/var/www/wp-content/plugins/site"
+        );
+        assert_eq!(upload.inputs["artifact"], "build/site.zip");
+
+        let hooks = step(&plan, "post_deploy");
+        assert_eq!(hooks.status, PlanStepStatus::Ready);
+        assert_eq!(hooks.inputs["commands"][0], "wp cache flush");
+        assert_eq!(step(&plan, "cleanup").status, PlanStepStatus::Disabled);
+    }
+
+    #[test]
+    fn plan_component_honors_step_filter_and_git_strategy() {
+        let mut config = config();
+        config.step_filter = PlanStepFilter::new(Vec::new(), vec!["post_deploy".to_string()]);
+        let mut component = component();
+        component.deploy_strategy = Some("git".to_string());
+
+        let plan = crate::test_support::with_isolated_home(|_| {
+            plan_component(&component, &config, &project(), "/var/www")
+        });
+
+        let build = step(&plan, "build");
+        assert_eq!(build.status, PlanStepStatus::Disabled);
+        assert_eq!(
+            build.skip_reason.as_deref(),
+            Some("git deploys need no build")
+        );
+        assert_eq!(step(&plan, "verify").status, PlanStepStatus::Disabled);
+        assert_eq!(
+            step(&plan, "post_deploy").skip_reason.as_deref(),
+            Some("skipped by --skip")
+        );
+    }
+
+    #[test]
+    fn plan_component_disables_verify_with_no_verify() {
+        let mut config = config();
+        config.no_verify = true;
+
+        let plan = crate::test_support::with_isolated_home(|_| {
+            plan_component(&component(), &config, &project(), "/var/www")
+        });
+
+        let verify = step(&plan, "verify");
+        assert_eq!(verify.status, PlanStepStatus::Disabled);
+        assert_eq!(verify.skip_reason.as_deref(), Some("--no-verify"));
+    }
+}
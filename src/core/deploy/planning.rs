@@ -242,7 +242,12 @@ pub fn calculate_release_state(component: &Component) -> Option<ReleaseState> {
         .ok()
         .map(|info| info.version);
 
-    let baseline = git::detect_baseline_with_version(path, current_version.as_deref()).ok()?;
+    let baseline = git::detect_baseline_with_version(
+        path,
+        current_version.as_deref(),
+        component.tag_pattern.as_deref(),
+    )
+    .ok()?;
 
     let commits = git::get_commits_since_tag(path, baseline.reference.as_deref())
         .ok()
@@ -310,9 +315,12 @@ pub(super) struct LoadedComponents {
 ///
 /// Returns both the deployable components and the IDs of skipped (non-deployable) ones,
 /// so callers can produce accurate error messages.
+///
+/// Archived components are skipped, or rejected when requested by ID, unless `force`.
 pub(super) fn load_project_components(
     project: &Project,
     requested_ids: &[String],
+    force: bool,
 ) -> Result<LoadedComponents> {
     let mut deployable = Vec::new();
     let mut skipped = Vec::new();
@@ -325,6 +333,15 @@ pub(super) fn load_project_components(
 
         let mut loaded = project::resolve_project_component(project, &attachment.id)?;
 
+        if loaded.archived && !force {
+            if requested_ids.contains(&attachment.id) {
+                loaded.ensure_active("deploy", force)?;
+            }
+            log_status!("deploy", "Skipping '{}': component is archived", loaded.id);
+            skipped.push(loaded.id.clone());
+            continue;
+        }
+
         // Validate required extensions are installed before attempting artifact resolution.
         // Without this check, missing extensions cause resolve_artifact() to silently
         // return None, and the component gets skipped with a vague "no artifact" message.
@@ -5,13 +5,13 @@ use super::permissions;
 use crate::component;
 use crate::defaults;
 use crate::engine::shell;
-use crate::engine::template::{render_map, TemplateVars};
 use crate::error::{Error, Result};
 use crate::extension::DeployVerification;
 use crate::server::SshClient;
 
 use super::transfer::{upload_directory, upload_file};
 use super::types::DeployResult;
+use super::verify::run_verification;
 
 /// Framework-neutral shared directory names that typically contain sibling components.
 const DANGEROUS_PATH_SUFFIXES: &[&str] = &["/node_modules", "/vendor", "/packages", "/extensions"];
@@ -256,25 +256,11 @@ pub(super) fn deploy_artifact(
     }
 
     // Step 3: Run verification if configured
-    if let Some((v, verify_cmd_template)) = verification
-        .as_ref()
-        .and_then(|v| v.verify_command.as_ref().map(|cmd| (v, cmd)))
+    if let Some(check) =
+        verification.and_then(|v| run_verification(ssh_client, remote_path, v, None))
     {
-        let mut vars = HashMap::new();
-        vars.insert(
-            TemplateVars::TARGET_DIR.to_string(),
-            remote_path.to_string(),
-        );
-        let verify_cmd = render_map(verify_cmd_template, &vars);
-
-        let verify_output = ssh_client.execute(&verify_cmd);
-        if !verify_output.success || verify_output.stdout.trim().is_empty() {
-            let error_msg = v
-                .verify_error_message
-                .as_ref()
-                .map(|msg| render_map(msg, &vars))
-                .unwrap_or_else(|| format!("Deploy verification failed for {}", remote_path));
-            return Ok(DeployResult::failure(1, error_msg));
+        if let Some(error) = check.error {
+            return Ok(DeployResult::failure(1, error));
         }
     }
 
@@ -70,6 +70,12 @@ fn rsync_directory(
     if ssh_client.port != 22 {
         ssh_cmd_parts.extend(["-p".to_string(), ssh_client.port.to_string()]);
     }
+    ssh_cmd_parts.extend(
+        ssh_client
+            .proxy_args()
+            .iter()
+            .map(|arg| shell::quote_arg(arg)),
+    );
     // Use same safety options as SSH client
     ssh_cmd_parts.extend([
         "-o".to_string(),
@@ -94,6 +100,7 @@ fn rsync_directory(
         remote_str
     );
 
+    let _permit = crate::server::acquire_ssh_permit();
     let output = Command::new("rsync").args(&rsync_args).output();
     match output {
         Ok(output) => Ok(process_output_result(output)),
@@ -160,6 +167,7 @@ fn scp_transfer(
     if ssh_client.port != deploy_defaults.default_ssh_port {
         scp_args.extend(["-P".to_string(), ssh_client.port.to_string()]);
     }
+    scp_args.extend(ssh_client.proxy_args());
 
     scp_args.push(local_path.to_string_lossy().to_string());
     scp_args.push(format!(
@@ -179,6 +187,7 @@ fn scp_transfer(
         remote_path
     );
 
+    let _permit = crate::server::acquire_ssh_permit();
     let output = Command::new("scp").args(&scp_args).output();
     match output {
         Ok(output) => Ok(process_output_result(output)),
@@ -273,6 +282,7 @@ mod tests {
             auth: None,
             is_local: true,
             env: HashMap::new(),
+            proxy_jump: None,
         }
     }
 
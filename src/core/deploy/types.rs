@@ -5,8 +5,10 @@ use crate::config;
 use crate::error::Result;
 use crate::is_zero_u32;
 use crate::paths as base_path;
+use crate::plan::PlanStepFilter;
 use crate::project::Project;
 
+use super::canary::CanaryOutcome;
 use super::path_roots::resolve_effective_remote_path;
 
 /// Parse bulk component IDs from a JSON spec.
@@ -39,6 +41,9 @@ impl DeployResult {
     }
 }
 
+/// Named deploy pipeline steps selectable with `--only`/`--skip`.
+pub const DEPLOY_STEP_NAMES: &[&str] = &["pull", "build", "upload", "cleanup", "post_deploy"];
+
 pub struct DeployConfig {
     pub component_ids: Vec<String>,
     pub all: bool,
@@ -59,6 +64,10 @@ pub struct DeployConfig {
     pub head: bool,
     /// Force tag-based deploy, ignoring any reusable build artifacts
     pub tagged: bool,
+    /// Skip post-deploy verification probes
+    pub no_verify: bool,
+    /// `--only`/`--skip` selection over `DEPLOY_STEP_NAMES`
+    pub step_filter: PlanStepFilter,
 }
 
 /// Reason why a component was selected for deployment.
@@ -183,6 +192,9 @@ pub struct ComponentDeployResult {
     /// The git ref (tag or branch) that was built and deployed
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deployed_ref: Option<String>,
+    /// Deploy steps excluded by `--only`/`--skip`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_steps: Vec<String>,
 }
 
 impl ComponentDeployResult {
@@ -201,6 +213,7 @@ impl ComponentDeployResult {
             deploy_exit_code: None,
             release_state: None,
             deployed_ref: None,
+            skipped_steps: Vec::new(),
         }
     }
 
@@ -273,6 +286,11 @@ impl ComponentDeployResult {
         self.deployed_ref = Some(git_ref);
         self
     }
+
+    pub(super) fn with_skipped_steps(mut self, steps: Vec<String>) -> Self {
+        self.skipped_steps = steps;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -488,6 +506,15 @@ mod tests {
         assert_eq!(result.deployed_ref.as_deref(), Some("v1.2.3"));
     }
 
+    #[test]
+    fn test_with_skipped_steps() {
+        let result = deploy_result().with_skipped_steps(vec!["build".to_string()]);
+
+        assert_eq!(result.skipped_steps, vec!["build"]);
+        let serialized = serde_json::to_value(deploy_result()).expect("serialize result");
+        assert!(serialized.get("skipped_steps").is_none());
+    }
+
     #[test]
     fn release_state_status_uses_needs_release_public_name() {
         let state = release_state();
@@ -506,6 +533,9 @@ mod tests {
 pub struct ProjectDeployResult {
     pub project_id: String,
     pub status: String,
+    /// `canary` or `rollout` in a `--canary` deploy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     pub results: Vec<ComponentDeployResult>,
@@ -518,6 +548,8 @@ pub struct MultiDeployResult {
     pub component_ids: Vec<String>,
     pub projects: Vec<ProjectDeployResult>,
     pub summary: MultiDeploySummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canary: Option<CanaryOutcome>,
 }
 
 /// Summary of multi-project deployment.
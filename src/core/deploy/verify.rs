@@ -0,0 +1,181 @@
+//! Deploy verification.
+//!
+//! Extensions declare `DeployVerification` probes keyed by install path. Deploy
+//! runs the matching probe after upload (unless `--no-verify`), and
+//! `deploy --verify-only` runs it alone against what is already on the server,
+//! reporting a structured result per check.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::context::resolve_project_ssh_with_base_path;
+use crate::engine::template::{render_map, TemplateVars};
+use crate::error::Result;
+use crate::extension::{load_all_extensions, DeployVerification};
+use crate::project;
+use crate::server::SshClient;
+
+use super::path_roots::resolve_effective_remote_path;
+use super::planning::{load_project_components, plan_components};
+use super::types::DeployConfig;
+use super::version_overrides::deploy_verification_candidates;
+
+/// Outcome of one verification probe.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationCheck {
+    /// Extension that declared the probe.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extension: Option<String>,
+    pub path_pattern: String,
+    /// Rendered command that ran on the server.
+    pub command: String,
+    pub passed: bool,
+    pub exit_code: i32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub output: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentVerifyResult {
+    pub id: String,
+    /// `passed`, `failed`, or `skipped` (no probe applies).
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_path: Option<String>,
+    pub checks: Vec<VerificationCheck>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DeployVerifySummary {
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeployVerifyResult {
+    pub project_id: String,
+    pub results: Vec<ComponentVerifyResult>,
+    pub summary: DeployVerifySummary,
+}
+
+/// Run verification only — no pull, build, or upload — for the components
+/// `config` selects in `project_id`.
+pub fn verify(project_id: &str, config: &DeployConfig) -> Result<DeployVerifyResult> {
+    let (ctx, base_path) = resolve_project_ssh_with_base_path(project_id)?;
+    let project = ctx.project;
+
+    let loaded = load_project_components(&project, &config.component_ids, true)?;
+    let selected = plan_components(
+        config,
+        &loaded.deployable,
+        &loaded.skipped,
+        &project,
+        &base_path,
+        &ctx.client,
+    )?;
+
+    let extensions = load_all_extensions().unwrap_or_default();
+    let mut summary = DeployVerifySummary::default();
+    let mut results = Vec::with_capacity(selected.len());
+
+    for component in &selected {
+        let component = project::apply_component_overrides(component, &project);
+        let result = match resolve_effective_remote_path(&project, &component, &base_path) {
+            Ok(install_dir) => {
+                let checks: Vec<VerificationCheck> =
+                    deploy_verification_candidates(&extensions, &install_dir)
+                        .into_iter()
+                        .take(1)
+                        .filter_map(|(verification, extension)| {
+                            run_verification(
+                                &ctx.client,
+                                &install_dir,
+                                verification,
+                                Some(&extension.id),
+                            )
+                        })
+                        .collect();
+                let status = if checks.is_empty() {
+                    "skipped"
+                } else if checks.iter().all(|check| check.passed) {
+                    "passed"
+                } else {
+                    "failed"
+                };
+                ComponentVerifyResult {
+                    id: component.id.clone(),
+                    status: status.to_string(),
+                    remote_path: Some(install_dir),
+                    checks,
+                    error: None,
+                }
+            }
+            Err(err) => ComponentVerifyResult {
+                id: component.id.clone(),
+                status: "failed".to_string(),
+                remote_path: None,
+                checks: Vec::new(),
+                error: Some(err.message),
+            },
+        };
+
+        match result.status.as_str() {
+            "passed" => summary.passed += 1,
+            "failed" => summary.failed += 1,
+            _ => summary.skipped += 1,
+        }
+        results.push(result);
+    }
+
+    Ok(DeployVerifyResult {
+        project_id: project.id.clone(),
+        results,
+        summary,
+    })
+}
+
+/// Run one verification probe against `remote_path`. `None` when the
+/// verification declares no `verify_command`.
+///
+/// A probe passes when it exits 0 and prints something; empty output is
+/// treated as "nothing found".
+pub(super) fn run_verification(
+    ssh_client: &SshClient,
+    remote_path: &str,
+    verification: &DeployVerification,
+    extension_id: Option<&str>,
+) -> Option<VerificationCheck> {
+    let template = verification.verify_command.as_ref()?;
+    let mut vars = HashMap::new();
+    vars.insert(
+        TemplateVars::TARGET_DIR.to_string(),
+        remote_path.to_string(),
+    );
+    let command = render_map(template, &vars);
+
+    let output = ssh_client.execute(&command);
+    let passed = output.success && !output.stdout.trim().is_empty();
+    let error = (!passed).then(|| {
+        verification
+            .verify_error_message
+            .as_ref()
+            .map(|msg| render_map(msg, &vars))
+            .unwrap_or_else(|| format!("Deploy verification failed for {}", remote_path))
+    });
+
+    Some(VerificationCheck {
+        extension: extension_id.map(str::to_string),
+        path_pattern: verification.path_pattern.clone(),
+        command,
+        passed,
+        exit_code: output.exit_code,
+        output: output.stdout.trim().to_string(),
+        error,
+    })
+}
@@ -18,6 +18,7 @@ use crate::version;
 use super::path_roots::resolve_effective_remote_path;
 use super::transfer::scp_file;
 use super::types::DeployResult;
+use super::verify::run_verification;
 
 /// Detect if a component's artifact is a CLI binary matching the currently
 /// running process name. Used to print a post-deploy hint for self-deploy.
@@ -200,28 +201,59 @@ fn parse_component_version(content: &str, pattern: Option<&str>, filename: &str)
 
 /// Find deploy verification config from extensions.
 pub(super) fn find_deploy_verification(target_path: &str) -> Option<DeployVerification> {
-    for extension in load_all_extensions().unwrap_or_default() {
-        for verification in extension.deploy_verifications() {
-            if target_path.contains(&verification.path_pattern) {
-                return Some(verification.clone());
-            }
-        }
-    }
-    None
+    let extensions = load_all_extensions().unwrap_or_default();
+    deploy_verification_candidates(&extensions, target_path)
+        .into_iter()
+        .next()
+        .map(|(verification, _)| verification.clone())
 }
 
 /// Find deploy override config from extensions.
 pub(super) fn find_deploy_override(
     target_path: &str,
 ) -> Option<(DeployOverride, ExtensionManifest)> {
-    for extension in load_all_extensions().unwrap_or_default() {
-        for override_config in extension.deploy_overrides() {
-            if target_path.contains(&override_config.path_pattern) {
-                return Some((override_config.clone(), extension));
-            }
-        }
-    }
-    None
+    let extensions = load_all_extensions().unwrap_or_default();
+    deploy_override_candidates(&extensions, target_path)
+        .into_iter()
+        .next()
+        .map(|(override_config, extension)| (override_config.clone(), extension.clone()))
+}
+
+/// Every verification whose `path_pattern` matches `target_path`, in
+/// precedence order: extensions by ID, then declaration order. Deploy uses
+/// the first.
+pub(super) fn deploy_verification_candidates<'a>(
+    extensions: &'a [ExtensionManifest],
+    target_path: &str,
+) -> Vec<(&'a DeployVerification, &'a ExtensionManifest)> {
+    extensions
+        .iter()
+        .flat_map(|extension| {
+            extension
+                .deploy_verifications()
+                .iter()
+                .map(move |verification| (verification, extension))
+        })
+        .filter(|(verification, _)| target_path.contains(&verification.path_pattern))
+        .collect()
+}
+
+/// Every override whose `path_pattern` matches `target_path`, in the same
+/// precedence order as [`deploy_verification_candidates`].
+pub(super) fn deploy_override_candidates<'a>(
+    extensions: &'a [ExtensionManifest],
+    target_path: &str,
+) -> Vec<(&'a DeployOverride, &'a ExtensionManifest)> {
+    extensions
+        .iter()
+        .flat_map(|extension| {
+            extension
+                .deploy_overrides()
+                .iter()
+                .map(move |override_config| (override_config, extension))
+        })
+        .filter(|(override_config, _)| target_path.contains(&override_config.path_pattern))
+        .collect()
 }
 
 /// Deploy using extension-defined override strategy.
@@ -337,24 +369,11 @@ pub(super) fn deploy_with_override(
     }
 
     // Step 6: Run verification if configured
-    if let Some(v) = verification {
-        if let Some(ref verify_cmd_template) = v.verify_command {
-            let mut verify_vars = HashMap::new();
-            verify_vars.insert(
-                TemplateVars::TARGET_DIR.to_string(),
-                remote_path.to_string(),
-            );
-            let verify_cmd = render_map(verify_cmd_template, &verify_vars);
-
-            let verify_output = ssh_client.execute(&verify_cmd);
-            if !verify_output.success || verify_output.stdout.trim().is_empty() {
-                let error_msg = v
-                    .verify_error_message
-                    .as_ref()
-                    .map(|msg| render_map(msg, &verify_vars))
-                    .unwrap_or_else(|| format!("Deploy verification failed for {}", remote_path));
-                return Ok(DeployResult::failure(1, error_msg));
-            }
+    if let Some(check) =
+        verification.and_then(|v| run_verification(ssh_client, remote_path, v, None))
+    {
+        if let Some(error) = check.error {
+            return Ok(DeployResult::failure(1, error));
         }
     }
 
@@ -367,7 +367,7 @@ fn resolve_subtarget(project: &Project, args: &[String]) -> Result<(String, Vec<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::extension::{CliAutoFlagCondition, CliHelpConfig};
+    use crate::extension::{CliAutoFlagCondition, CliHelpConfig, CliOutputMode};
 
     fn cli_config(auto_flags: Vec<CliAutoFlag>) -> CliConfig {
         CliConfig {
@@ -379,6 +379,7 @@ mod tests {
             settings_flags: HashMap::new(),
             auto_flags,
             help: None::<CliHelpConfig>,
+            output_mode: CliOutputMode::Json,
         }
     }
 
@@ -455,6 +456,30 @@ mod tests {
         assert_eq!(config.auto_flags[0].flag, "--allow-root");
     }
 
+    #[test]
+    fn cli_config_output_mode_defaults_to_json() {
+        let config: CliConfig = serde_json::from_str(
+            r#"{ "tool": "wp", "display_name": "WP-CLI", "command_template": "wp {{args}}" }"#,
+        )
+        .expect("parse cli config");
+        assert_eq!(config.output_mode, CliOutputMode::Json);
+
+        let config: CliConfig = serde_json::from_str(
+            r#"{
+                "tool": "wp",
+                "display_name": "WP-CLI",
+                "command_template": "wp {{args}}",
+                "output_mode": "markdown"
+            }"#,
+        )
+        .expect("parse cli config");
+        assert_eq!(config.output_mode, CliOutputMode::Markdown);
+        assert_eq!(
+            serde_json::to_value(&config).unwrap()["output_mode"],
+            "markdown"
+        );
+    }
+
     #[test]
     fn project_cli_path_overrides_manifest_default() {
         let mut project = Project {
@@ -7,6 +7,8 @@
 
 use std::path::{Path, PathBuf};
 
+use super::ignore_rules::IgnoreRules;
+
 // ============================================================================
 // Skip directory configuration
 // ============================================================================
@@ -66,6 +68,9 @@ pub struct ScanConfig {
     /// Whether to skip hidden files/directories (names starting with `.`).
     /// VCS dirs (.git, .svn, .hg) are always skipped regardless of this setting.
     pub skip_hidden: bool,
+    /// Ignore the root `.gitignore` / `.homeboyignore` rules, which are
+    /// otherwise applied on top of the skip dirs.
+    pub disable_ignore_files: bool,
 }
 
 // ============================================================================
@@ -78,14 +83,22 @@ pub struct ScanConfig {
 /// - `ALWAYS_SKIP_DIRS` + `extra_skip_dirs` are skipped at any depth
 /// - `ROOT_ONLY_SKIP_DIRS` + `extra_root_skip_dirs` are skipped only at root level
 ///
-/// Files are filtered by the configured extension filter.
+/// Paths matched by the root ignore files are skipped as well (see
+/// [`IgnoreRules`]). Files are filtered by the configured extension filter.
 pub fn walk_files(root: &Path, config: &ScanConfig) -> Vec<PathBuf> {
     let mut files = Vec::new();
-    walk_recursive(root, root, config, &mut files);
+    let ignore = load_ignore_rules(root, config);
+    walk_recursive(root, root, config, &ignore, &mut files);
     files
 }
 
-fn walk_recursive(dir: &Path, root: &Path, config: &ScanConfig, files: &mut Vec<PathBuf>) {
+fn walk_recursive(
+    dir: &Path,
+    root: &Path,
+    config: &ScanConfig,
+    ignore: &IgnoreRules,
+    files: &mut Vec<PathBuf>,
+) {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return;
     };
@@ -100,14 +113,17 @@ fn walk_recursive(dir: &Path, root: &Path, config: &ScanConfig, files: &mut Vec<
             .unwrap_or_default();
 
         if path.is_dir() {
-            if should_skip_dir(&name, is_root, config) {
+            if should_skip_dir(&name, is_root, config) || is_ignored(&path, root, true, ignore) {
                 continue;
             }
-            walk_recursive(&path, root, config, files);
+            walk_recursive(&path, root, config, ignore, files);
         } else {
             if config.skip_hidden && name.starts_with('.') {
                 continue;
             }
+            if is_ignored(&path, root, false, ignore) {
+                continue;
+            }
 
             if matches_extension(&path, &config.extensions) {
                 files.push(path);
@@ -139,11 +155,17 @@ pub fn walk_files_with<F>(root: &Path, config: &ScanConfig, callback: &mut F)
 where
     F: FnMut(&Path),
 {
-    walk_recursive_with(root, root, config, callback);
+    let ignore = load_ignore_rules(root, config);
+    walk_recursive_with(root, root, config, &ignore, callback);
 }
 
-fn walk_recursive_with<F>(dir: &Path, root: &Path, config: &ScanConfig, callback: &mut F)
-where
+fn walk_recursive_with<F>(
+    dir: &Path,
+    root: &Path,
+    config: &ScanConfig,
+    ignore: &IgnoreRules,
+    callback: &mut F,
+) where
     F: FnMut(&Path),
 {
     let Ok(entries) = std::fs::read_dir(dir) else {
@@ -160,14 +182,17 @@ where
             .unwrap_or_default();
 
         if path.is_dir() {
-            if should_skip_dir(&name, is_root, config) {
+            if should_skip_dir(&name, is_root, config) || is_ignored(&path, root, true, ignore) {
                 continue;
             }
-            walk_recursive_with(&path, root, config, callback);
+            walk_recursive_with(&path, root, config, ignore, callback);
         } else {
             if config.skip_hidden && name.starts_with('.') {
                 continue;
             }
+            if is_ignored(&path, root, false, ignore) {
+                continue;
+            }
             if matches_extension(&path, &config.extensions) {
                 callback(&path);
             }
@@ -187,13 +212,15 @@ pub fn any_file_matches<F>(root: &Path, config: &ScanConfig, predicate: F) -> bo
 where
     F: Fn(&Path) -> bool,
 {
-    any_file_matches_recursive(root, root, config, &predicate)
+    let ignore = load_ignore_rules(root, config);
+    any_file_matches_recursive(root, root, config, &ignore, &predicate)
 }
 
 fn any_file_matches_recursive<F>(
     dir: &Path,
     root: &Path,
     config: &ScanConfig,
+    ignore: &IgnoreRules,
     predicate: &F,
 ) -> bool
 where
@@ -213,16 +240,19 @@ where
             .unwrap_or_default();
 
         if path.is_dir() {
-            if should_skip_dir(&name, is_root, config) {
+            if should_skip_dir(&name, is_root, config) || is_ignored(&path, root, true, ignore) {
                 continue;
             }
-            if any_file_matches_recursive(&path, root, config, predicate) {
+            if any_file_matches_recursive(&path, root, config, ignore, predicate) {
                 return true;
             }
         } else {
             if config.skip_hidden && name.starts_with('.') {
                 continue;
             }
+            if is_ignored(&path, root, false, ignore) {
+                continue;
+            }
             if matches_extension(&path, &config.extensions) && predicate(&path) {
                 return true;
             }
@@ -253,7 +283,8 @@ where
     F: Fn(&str, bool) -> bool,
 {
     let mut results = Vec::new();
-    walk_entries_recursive(root, root, config, &matcher, &mut results);
+    let ignore = load_ignore_rules(root, config);
+    walk_entries_recursive(root, root, config, &ignore, &matcher, &mut results);
     results
 }
 
@@ -261,6 +292,7 @@ fn walk_entries_recursive<F>(
     dir: &Path,
     root: &Path,
     config: &ScanConfig,
+    ignore: &IgnoreRules,
     matcher: &F,
     results: &mut Vec<WalkEntry>,
 ) where
@@ -280,17 +312,20 @@ fn walk_entries_recursive<F>(
             .unwrap_or_default();
 
         if path.is_dir() {
-            if should_skip_dir(&name, is_root, config) {
+            if should_skip_dir(&name, is_root, config) || is_ignored(&path, root, true, ignore) {
                 continue;
             }
             if matcher(&name, true) {
                 results.push(WalkEntry::Dir(path.clone()));
             }
-            walk_entries_recursive(&path, root, config, matcher, results);
+            walk_entries_recursive(&path, root, config, ignore, matcher, results);
         } else {
             if config.skip_hidden && name.starts_with('.') {
                 continue;
             }
+            if is_ignored(&path, root, false, ignore) {
+                continue;
+            }
             if matcher(&name, false) {
                 results.push(WalkEntry::File(path.clone()));
             }
@@ -336,6 +371,22 @@ fn should_skip_dir(name: &str, is_root: bool, config: &ScanConfig) -> bool {
     false
 }
 
+fn load_ignore_rules(root: &Path, config: &ScanConfig) -> IgnoreRules {
+    if config.disable_ignore_files {
+        IgnoreRules::default()
+    } else {
+        IgnoreRules::load(root)
+    }
+}
+
+fn is_ignored(path: &Path, root: &Path, is_dir: bool, ignore: &IgnoreRules) -> bool {
+    if ignore.is_empty() {
+        return false;
+    }
+    path.strip_prefix(root)
+        .is_ok_and(|relative| ignore.is_ignored(&relative.to_string_lossy(), is_dir))
+}
+
 // ============================================================================
 // Content search — boundary-aware matching
 // ============================================================================
@@ -753,6 +804,43 @@ mod tests {
         let _ = std::fs::remove_dir_all(&dir);
     }
 
+    #[test]
+    fn walk_files_honors_ignore_files() {
+        let dir = std::env::temp_dir().join("homeboy_scan_ignore_files_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::create_dir_all(dir.join("storage"));
+        let _ = std::fs::create_dir_all(dir.join("public/build"));
+        let _ = std::fs::create_dir_all(dir.join("src"));
+
+        std::fs::write(dir.join(".gitignore"), "*.gen.rs\n").unwrap();
+        std::fs::write(dir.join(".homeboyignore"), "storage/\n/public/build\n").unwrap();
+        std::fs::write(dir.join("storage/cache.rs"), "x").unwrap();
+        std::fs::write(dir.join("public/build/app.js"), "x").unwrap();
+        std::fs::write(dir.join("src/schema.gen.rs"), "x").unwrap();
+        std::fs::write(dir.join("src/lib.rs"), "x").unwrap();
+
+        let names = |config: &ScanConfig| -> Vec<String> {
+            let mut names: Vec<String> = walk_files(&dir, config)
+                .iter()
+                .map(|f| f.strip_prefix(&dir).unwrap().to_string_lossy().to_string())
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(names(&ScanConfig::default()), vec!["src/lib.rs"]);
+        assert_eq!(
+            names(&ScanConfig {
+                disable_ignore_files: true,
+                ..Default::default()
+            })
+            .len(),
+            4
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn walk_files_pycache_always_skipped() {
         let dir = std::env::temp_dir().join("homeboy_scan_pycache_test");
@@ -0,0 +1,154 @@
+//! Gitignore-syntax ignore rules read from a component root.
+//!
+//! Shared by every `codebase_scan` walker so audit, docs audit, and refactor
+//! agree on which paths are out of scope. Rules come from the root
+//! `.gitignore` followed by `.homeboyignore`; later rules win, so a
+//! `.homeboyignore` line like `!generated/keep.rs` can re-include a path git
+//! ignores. Nested ignore files are not read.
+
+use std::path::Path;
+
+/// Homeboy-specific ignore file, read after `.gitignore`.
+pub const HOMEBOY_IGNORE_FILE: &str = ".homeboyignore";
+
+/// Ignore files loaded from the root, in precedence order (last wins).
+pub const IGNORE_FILES: &[&str] = &[".gitignore", HOMEBOY_IGNORE_FILE];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct IgnoreRule {
+    glob: String,
+    negated: bool,
+    dir_only: bool,
+}
+
+/// Parsed ignore rules, matched against root-relative `/`-separated paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IgnoreRules {
+    rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreRules {
+    /// Load `.gitignore` and `.homeboyignore` from `root`. Missing files are
+    /// treated as empty.
+    pub fn load(root: &Path) -> Self {
+        let mut rules = Self::default();
+        for name in IGNORE_FILES {
+            if let Ok(content) = std::fs::read_to_string(root.join(name)) {
+                rules.extend_from_str(&content);
+            }
+        }
+        rules
+    }
+
+    /// Parse rules from gitignore-syntax text.
+    pub fn parse(content: &str) -> Self {
+        let mut rules = Self::default();
+        rules.extend_from_str(content);
+        rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether `relative_path` (relative to the root) is ignored.
+    pub fn is_ignored(&self, relative_path: &str, is_dir: bool) -> bool {
+        let path = relative_path.replace('\\', "/");
+        let path = path.trim_start_matches("./").trim_matches('/');
+        if path.is_empty() {
+            return false;
+        }
+
+        let mut ignored = false;
+        for rule in &self.rules {
+            if rule.dir_only && !is_dir {
+                continue;
+            }
+            if glob_match::glob_match(&rule.glob, path) {
+                ignored = !rule.negated;
+            }
+        }
+        ignored
+    }
+
+    fn extend_from_str(&mut self, content: &str) {
+        self.rules.extend(content.lines().filter_map(parse_line));
+    }
+}
+
+fn parse_line(line: &str) -> Option<IgnoreRule> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (negated, pattern) = match line.strip_prefix('!') {
+        Some(rest) => (true, rest),
+        None => (false, line.strip_prefix('\\').unwrap_or(line)),
+    };
+    let (dir_only, pattern) = match pattern.strip_suffix('/') {
+        Some(rest) => (true, rest),
+        None => (false, pattern),
+    };
+    if pattern.is_empty() {
+        return None;
+    }
+
+    // A slash anywhere but the end anchors the pattern to the root;
+    // otherwise it matches the basename at any depth.
+    let glob = if pattern.contains('/') {
+        pattern.trim_start_matches('/').to_string()
+    } else {
+        format!("**/{}", pattern)
+    };
+
+    Some(IgnoreRule {
+        glob,
+        negated,
+        dir_only,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let rules = IgnoreRules::parse("# comment\n\nstorage/\n/public/build\n*.log\n");
+
+        assert!(rules.is_ignored("storage", true));
+        assert!(rules.is_ignored("app/storage", true));
+        assert!(!rules.is_ignored("storage", false));
+        assert!(rules.is_ignored("public/build", true));
+        assert!(!rules.is_ignored("web/public/build", true));
+        assert!(rules.is_ignored("logs/debug.log", false));
+        assert!(!rules.is_ignored("src/main.rs", false));
+    }
+
+    #[test]
+    fn negation_reincludes_later_matches() {
+        let rules = IgnoreRules::parse("generated/*\n!generated/keep.rs\n");
+
+        assert!(rules.is_ignored("generated/skip.rs", false));
+        assert!(!rules.is_ignored("generated/keep.rs", false));
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join(".gitignore"), "out/\nfixture.txt\n").unwrap();
+        std::fs::write(
+            dir.path().join(HOMEBOY_IGNORE_FILE),
+            "cache-*/\n!fixture.txt\n",
+        )
+        .unwrap();
+
+        let rules = IgnoreRules::load(dir.path());
+
+        assert!(rules.is_ignored("out", true));
+        assert!(rules.is_ignored("cache-v2", true));
+        assert!(!rules.is_ignored("fixture.txt", false));
+        assert!(IgnoreRules::load(&dir.path().join("missing")).is_empty());
+    }
+}
@@ -0,0 +1,196 @@
+//! JUnit XML rendering for CI dashboards (Jenkins, GitLab, GitHub test
+//! reporters).
+//!
+//! Commands map their results onto [`JunitSuite`]s and [`JunitCase`]s; this
+//! module owns the XML shape and escaping. Suite counters are derived from the
+//! cases so they always agree with the document.
+
+use std::fmt::Write as _;
+
+/// One `<testsuite>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JunitSuite {
+    pub name: String,
+    /// Wall-clock seconds, when known.
+    pub time: Option<f64>,
+    pub cases: Vec<JunitCase>,
+}
+
+/// One `<testcase>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JunitCase {
+    pub classname: String,
+    pub name: String,
+    pub time: Option<f64>,
+    pub outcome: JunitOutcome,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum JunitOutcome {
+    #[default]
+    Passed,
+    Skipped,
+    /// `<failure>` with a one-line `message` and the full `detail` as text.
+    Failed {
+        message: String,
+        detail: String,
+    },
+    /// `<error>`: the case could not run (tooling or infrastructure failure).
+    Errored {
+        message: String,
+        detail: String,
+    },
+}
+
+impl JunitSuite {
+    fn count(&self, matches: fn(&JunitOutcome) -> bool) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| matches(&case.outcome))
+            .count()
+    }
+}
+
+/// Render `suites` as a `<testsuites>` document.
+pub fn render(name: &str, suites: &[JunitSuite]) -> String {
+    let count = |matches: fn(&JunitOutcome) -> bool| -> usize {
+        suites.iter().map(|suite| suite.count(matches)).sum()
+    };
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = write!(
+        xml,
+        "<testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\"",
+        escape(name),
+        suites.iter().map(|suite| suite.cases.len()).sum::<usize>(),
+        count(is_failure),
+        count(is_error),
+        count(is_skipped),
+    );
+    let total_time: Option<f64> = suites.iter().map(|suite| suite.time).sum();
+    write_time(&mut xml, total_time);
+    xml.push_str(">\n");
+
+    for suite in suites {
+        let _ = write!(
+            xml,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\"",
+            escape(&suite.name),
+            suite.cases.len(),
+            suite.count(is_failure),
+            suite.count(is_error),
+            suite.count(is_skipped),
+        );
+        write_time(&mut xml, suite.time);
+        xml.push_str(">\n");
+        for case in &suite.cases {
+            write_case(&mut xml, case);
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn write_case(xml: &mut String, case: &JunitCase) {
+    let _ = write!(
+        xml,
+        "    <testcase classname=\"{}\" name=\"{}\"",
+        escape(&case.classname),
+        escape(&case.name)
+    );
+    write_time(xml, case.time);
+    match &case.outcome {
+        JunitOutcome::Passed => xml.push_str("/>\n"),
+        JunitOutcome::Skipped => xml.push_str(">\n      <skipped/>\n    </testcase>\n"),
+        JunitOutcome::Failed { message, detail } => write_problem(xml, "failure", message, detail),
+        JunitOutcome::Errored { message, detail } => write_problem(xml, "error", message, detail),
+    }
+}
+
+fn write_problem(xml: &mut String, tag: &str, message: &str, detail: &str) {
+    let _ = writeln!(
+        xml,
+        ">\n      <{tag} message=\"{}\">{}</{tag}>\n    </testcase>",
+        escape(message),
+        escape(detail),
+    );
+}
+
+fn write_time(xml: &mut String, time: Option<f64>) {
+    if let Some(seconds) = time {
+        let _ = write!(xml, " time=\"{:.3}\"", seconds);
+    }
+}
+
+fn is_failure(outcome: &JunitOutcome) -> bool {
+    matches!(outcome, JunitOutcome::Failed { .. })
+}
+
+fn is_error(outcome: &JunitOutcome) -> bool {
+    matches!(outcome, JunitOutcome::Errored { .. })
+}
+
+fn is_skipped(outcome: &JunitOutcome) -> bool {
+    matches!(outcome, JunitOutcome::Skipped)
+}
+
+/// Escape text for XML attributes and content, dropping control characters
+/// XML 1.0 cannot represent (ANSI color codes in runner output).
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let suite = JunitSuite {
+            name: "widget".to_string(),
+            time: Some(1.5),
+            cases: vec![
+                JunitCase {
+                    classname: "widget".to_string(),
+                    name: "passes".to_string(),
+                    ..Default::default()
+                },
+                JunitCase {
+                    classname: "widget".to_string(),
+                    name: "fails <here>".to_string(),
+                    time: None,
+                    outcome: JunitOutcome::Failed {
+                        message: "expected \"a\"".to_string(),
+                        detail: "left & right\u{1b}[0m".to_string(),
+                    },
+                },
+            ],
+        };
+
+        let xml = render("homeboy test", &[suite]);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(
+            "<testsuites name=\"homeboy test\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\" time=\"1.500\">"
+        ));
+        assert!(xml.contains("<testcase classname=\"widget\" name=\"passes\"/>"));
+        assert!(xml.contains("name=\"fails &lt;here&gt;\""));
+        assert!(xml
+            .contains("<failure message=\"expected &quot;a&quot;\">left &amp; right[0m</failure>"));
+        assert!(xml.ends_with("</testsuites>\n"));
+    }
+}
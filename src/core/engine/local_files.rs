@@ -1,8 +1,41 @@
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use crate::error::{Error, Result};
 
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Turn the process-wide dry-run guard on or off.
+///
+/// While on, `LocalFs`, `write_file`, and `write_file_atomic` log and skip
+/// their writes, deletes, and directory creation, as do `RemoteFs` and
+/// package archiving. Those entry points are reserved for user state: config
+/// entities, component registrations and `homeboy.json`, defaults, stack
+/// specs, the release artifact manifest, version and changelog edits,
+/// refactor transforms, and SSH keys. Caches, run directories, and runtime
+/// scratch files that a preview still needs go through
+/// `write_scratch_file_atomic` or `std::fs` instead, never through the
+/// guarded functions.
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Log the mutation a dry run skips. Returns true when the caller should
+/// return without touching disk.
+pub(crate) fn skipped_for_dry_run(action: impl FnOnce() -> String) -> bool {
+    if !is_dry_run() {
+        return false;
+    }
+    crate::log_status!("dry-run", "Would {}", action());
+    true
+}
+
 /// Entry returned from directory listing
 #[derive(Debug, Clone)]
 pub struct Entry {
@@ -16,6 +49,13 @@ impl Entry {
     }
 }
 
+/// Metadata returned from `FileSystem::stat`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStat {
+    pub is_dir: bool,
+    pub size: u64,
+}
+
 /// Trait for file system operations - local or remote
 pub trait FileSystem {
     fn read(&self, path: &Path) -> Result<String>;
@@ -23,6 +63,8 @@ pub trait FileSystem {
     fn list(&self, dir: &Path) -> Result<Vec<Entry>>;
     fn delete(&self, path: &Path) -> Result<()>;
     fn ensure_dir(&self, dir: &Path) -> Result<()>;
+    /// Returns `None` when the path does not exist.
+    fn stat(&self, path: &Path) -> Result<Option<FileStat>>;
 }
 
 /// Local filesystem implementation
@@ -55,30 +97,7 @@ impl FileSystem for LocalFs {
     }
 
     fn write(&self, path: &Path, content: &str) -> Result<()> {
-        // Atomic write: write to temp file, then rename
-        let parent = path.parent().ok_or_else(|| {
-            Error::internal_io(
-                format!("Invalid path: {}", path.display()),
-                Some("write file".to_string()),
-            )
-        })?;
-
-        let filename = path.file_name().ok_or_else(|| {
-            Error::internal_io(
-                format!("Invalid path: {}", path.display()),
-                Some("write file".to_string()),
-            )
-        })?;
-
-        let tmp_path = parent.join(format!("{}.tmp", filename.to_string_lossy()));
-
-        fs::write(&tmp_path, content)
-            .map_err(|e| Error::internal_io(e.to_string(), Some("write temp file".to_string())))?;
-
-        fs::rename(&tmp_path, path)
-            .map_err(|e| Error::internal_io(e.to_string(), Some("rename temp file".to_string())))?;
-
-        Ok(())
+        write_file_atomic(path, content, "write file")
     }
 
     fn list(&self, dir: &Path) -> Result<Vec<Entry>> {
@@ -106,19 +125,36 @@ impl FileSystem for LocalFs {
                 Some("delete file".to_string()),
             ));
         }
+        if skipped_for_dry_run(|| format!("delete {}", path.display())) {
+            return Ok(());
+        }
 
         fs::remove_file(path)
             .map_err(|e| Error::internal_io(e.to_string(), Some("delete file".to_string())))
     }
 
     fn ensure_dir(&self, dir: &Path) -> Result<()> {
-        if !dir.exists() {
+        if !dir.exists() && !skipped_for_dry_run(|| format!("create {}", dir.display())) {
             fs::create_dir_all(dir).map_err(|e| {
                 Error::internal_io(e.to_string(), Some("create directory".to_string()))
             })?;
         }
         Ok(())
     }
+
+    fn stat(&self, path: &Path) -> Result<Option<FileStat>> {
+        match fs::metadata(path) {
+            Ok(meta) => Ok(Some(FileStat {
+                is_dir: meta.is_dir(),
+                size: meta.len(),
+            })),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Error::internal_io(
+                e.to_string(),
+                Some("stat file".to_string()),
+            )),
+        }
+    }
 }
 
 /// Convenience function to get local filesystem
@@ -156,12 +192,41 @@ pub fn read_file(path: &Path, operation: &str) -> Result<String> {
 
 /// Write content to file with standardized error handling.
 pub fn write_file(path: &Path, content: &str, operation: &str) -> Result<()> {
+    if skipped_for_dry_run(|| describe_write(path, content)) {
+        return Ok(());
+    }
     fs::write(path, content)
         .map_err(|e| Error::internal_io(e.to_string(), Some(operation.to_string())))
 }
 
 /// Write content to file atomically (write to .tmp, then rename).
+///
+/// The temp file lives next to the target so the rename never crosses a
+/// filesystem, and is flushed to disk before it replaces the target. Readers
+/// and interrupted writers therefore only ever see the old or the new
+/// content. A failed write removes the temp file and leaves the target as-is.
 pub fn write_file_atomic(path: &Path, content: &str, operation: &str) -> Result<()> {
+    if skipped_for_dry_run(|| describe_write(path, content)) {
+        return Ok(());
+    }
+    write_atomic_with(path, operation, |file| file.write_all(content.as_bytes()))
+}
+
+fn describe_write(path: &Path, content: &str) -> String {
+    format!("write {} bytes to {}", content.len(), path.display())
+}
+
+/// Atomic write that ignores the dry-run guard, for homeboy's own runtime
+/// scratch files that commands need even when previewing.
+pub(crate) fn write_scratch_file_atomic(path: &Path, content: &str, operation: &str) -> Result<()> {
+    write_atomic_with(path, operation, |file| file.write_all(content.as_bytes()))
+}
+
+fn write_atomic_with(
+    path: &Path,
+    operation: &str,
+    write: impl FnOnce(&mut fs::File) -> std::io::Result<()>,
+) -> Result<()> {
     let parent = path.parent().ok_or_else(|| {
         Error::internal_io(
             format!("Invalid path: {}", path.display()),
@@ -176,22 +241,42 @@ pub fn write_file_atomic(path: &Path, content: &str, operation: &str) -> Result<
         )
     })?;
 
-    let tmp_path = parent.join(format!("{}.tmp", filename.to_string_lossy()));
+    // Unique per process and call so concurrent writers never share a temp.
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let tmp_path = parent.join(format!(
+        ".{}.{}.{}.tmp",
+        filename.to_string_lossy(),
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = fs::File::create(&tmp_path).and_then(|mut file| {
+        if let Ok(metadata) = fs::metadata(path) {
+            // Keep the target's mode (e.g. 0600 on files holding secrets).
+            let _ = file.set_permissions(metadata.permissions());
+        }
+        write(&mut file)?;
+        file.sync_all()
+    });
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(Error::internal_io(
+            e.to_string(),
+            Some(format!("{} (write temp)", operation)),
+        ));
+    }
 
-    fs::write(&tmp_path, content).map_err(|e| {
-        Error::internal_io(e.to_string(), Some(format!("{} (write temp)", operation)))
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        Error::internal_io(e.to_string(), Some(format!("{} (rename)", operation)))
     })?;
 
-    fs::rename(&tmp_path, path)
-        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("{} (rename)", operation))))?;
-
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::Write;
     use tempfile::tempdir;
     use tempfile::NamedTempFile;
 
@@ -234,6 +319,25 @@ mod tests {
         assert!(!path.exists());
     }
 
+    #[test]
+    fn test_local_fs_stat() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("sized.txt");
+        let fs = local();
+
+        fs.write(&path, "12345").unwrap();
+
+        assert_eq!(
+            fs.stat(&path).unwrap(),
+            Some(FileStat {
+                is_dir: false,
+                size: 5
+            })
+        );
+        assert!(fs.stat(dir.path()).unwrap().unwrap().is_dir);
+        assert_eq!(fs.stat(&dir.path().join("missing")).unwrap(), None);
+    }
+
     #[test]
     fn read_file_succeeds_for_existing_file() {
         let mut temp = NamedTempFile::new().unwrap();
@@ -272,4 +376,38 @@ mod tests {
         let err = result.unwrap_err();
         assert_eq!(err.code.as_str(), "internal.io_error");
     }
+
+    #[test]
+    fn test_write_file_atomic() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("project.json");
+        fs::write(&path, "{\"id\":\"old\"}").unwrap();
+
+        write_file_atomic(&path, "{\"id\":\"new\"}", "save project").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"id\":\"new\"}");
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn write_file_atomic_failed_write_leaves_original_intact() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("project.json");
+        fs::write(&path, "{\"id\":\"old\"}").unwrap();
+
+        // Simulate a write that dies halfway (disk full, interrupted).
+        let err = write_atomic_with(&path, "save project", |file| {
+            file.write_all(b"{\"id\":")?;
+            Err(std::io::Error::other("disk full"))
+        })
+        .unwrap_err();
+
+        assert_eq!(err.code.as_str(), "internal.io_error");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{\"id\":\"old\"}");
+        let leftovers: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(leftovers, vec![std::ffi::OsString::from("project.json")]);
+    }
 }
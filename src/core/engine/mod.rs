@@ -13,10 +13,13 @@ pub mod executor;
 pub mod format_write;
 pub mod hooks;
 pub mod identifier;
+pub mod ignore_rules;
 pub mod invocation;
-pub(crate) mod local_files;
+pub mod junit;
+pub mod local_files;
 pub mod output_parse;
 pub mod refactor_primitive;
+pub mod remote_files;
 pub mod resource;
 pub mod run_dir;
 pub mod shell;
@@ -24,6 +27,7 @@ pub mod symbol_graph;
 pub mod temp;
 pub mod template;
 pub mod text;
+pub mod topo;
 pub mod undo;
 pub mod validate_write;
 pub mod validation;
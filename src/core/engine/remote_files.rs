@@ -0,0 +1,339 @@
+use std::path::Path;
+
+use base64::Engine as _;
+
+use crate::engine::local_files::{skipped_for_dry_run, Entry, FileStat, FileSystem};
+use crate::engine::shell;
+use crate::error::{Error, Result};
+use crate::server::{CommandOutput, SshClient};
+
+/// Runs shell commands on the remote side of a `RemoteFs`.
+///
+/// Implemented for `SshClient`; tests substitute a recording mock.
+pub trait RemoteExec {
+    fn execute(&self, command: &str) -> CommandOutput;
+}
+
+impl RemoteExec for SshClient {
+    fn execute(&self, command: &str) -> CommandOutput {
+        SshClient::execute(self, command)
+    }
+}
+
+/// Remote filesystem implementation backed by shell commands over SSH
+pub struct RemoteFs<E: RemoteExec = SshClient> {
+    exec: E,
+}
+
+impl<E: RemoteExec> RemoteFs<E> {
+    pub fn new(exec: E) -> Self {
+        Self { exec }
+    }
+
+    fn run(&self, command: &str, operation: &str) -> Result<CommandOutput> {
+        let output = self.exec.execute(command);
+        if output.success {
+            Ok(output)
+        } else {
+            Err(Error::internal_io(
+                remote_error_message(&output),
+                Some(operation.to_string()),
+            ))
+        }
+    }
+}
+
+impl<E: RemoteExec> FileSystem for RemoteFs<E> {
+    fn read(&self, path: &Path) -> Result<String> {
+        let quoted = quote(path);
+        let command = format!(
+            "if [ -f {0} ]; then cat {0}; else echo 'File not found' >&2; exit 2; fi",
+            quoted
+        );
+        let output = self.exec.execute(&command);
+        if output.exit_code == 2 {
+            return Err(Error::internal_io(
+                format!("File not found: {}", path.display()),
+                Some("read remote file".to_string()),
+            ));
+        }
+        if !output.success {
+            return Err(Error::internal_io(
+                remote_error_message(&output),
+                Some("read remote file".to_string()),
+            ));
+        }
+        Ok(output.stdout)
+    }
+
+    fn write(&self, path: &Path, content: &str) -> Result<()> {
+        // Atomic write: decode to temp file, then rename. Base64 keeps the
+        // content byte-exact regardless of quotes, heredoc markers, or
+        // trailing newlines.
+        let filename = path.file_name().ok_or_else(|| {
+            Error::internal_io(
+                format!("Invalid path: {}", path.display()),
+                Some("write remote file".to_string()),
+            )
+        })?;
+        if skipped_for_dry_run(|| {
+            format!("write {} bytes to remote {}", content.len(), path.display())
+        }) {
+            return Ok(());
+        }
+        let tmp_path = path.with_file_name(format!("{}.tmp", filename.to_string_lossy()));
+        let encoded = base64::engine::general_purpose::STANDARD.encode(content);
+
+        let command = format!(
+            "printf '%s' {} | base64 -d > {} && mv {} {}",
+            shell::quote_arg(&encoded),
+            quote(&tmp_path),
+            quote(&tmp_path),
+            quote(path)
+        );
+        self.run(&command, "write remote file")?;
+        Ok(())
+    }
+
+    fn list(&self, dir: &Path) -> Result<Vec<Entry>> {
+        // One entry per line: `d <name>` for directories, `f <name>` otherwise.
+        // A missing directory lists as empty, matching `LocalFs`.
+        let command = format!(
+            "[ -d {0} ] || exit 0; cd {0} && for e in * .[!.]* ..?*; do \
+             [ -e \"$e\" ] || continue; \
+             if [ -d \"$e\" ]; then echo \"d $e\"; else echo \"f $e\"; fi; done",
+            quote(dir)
+        );
+        let output = self.run(&command, "list remote directory")?;
+        Ok(parse_list_output(dir, &output.stdout))
+    }
+
+    fn delete(&self, path: &Path) -> Result<()> {
+        let quoted = quote(path);
+        if skipped_for_dry_run(|| format!("delete remote {}", path.display())) {
+            return Ok(());
+        }
+        let command = format!(
+            "if [ -e {0} ]; then rm -f {0}; else echo 'File not found' >&2; exit 2; fi",
+            quoted
+        );
+        let output = self.exec.execute(&command);
+        if output.exit_code == 2 {
+            return Err(Error::internal_io(
+                format!("File not found: {}", path.display()),
+                Some("delete remote file".to_string()),
+            ));
+        }
+        if !output.success {
+            return Err(Error::internal_io(
+                remote_error_message(&output),
+                Some("delete remote file".to_string()),
+            ));
+        }
+        Ok(())
+    }
+
+    fn ensure_dir(&self, dir: &Path) -> Result<()> {
+        if skipped_for_dry_run(|| format!("create remote {}", dir.display())) {
+            return Ok(());
+        }
+        let command = format!("mkdir -p {}", quote(dir));
+        self.run(&command, "create remote directory")?;
+        Ok(())
+    }
+
+    fn stat(&self, path: &Path) -> Result<Option<FileStat>> {
+        let command = format!(
+            "if [ -d {0} ]; then echo d 0; elif [ -e {0} ]; then echo f $(wc -c < {0}); fi",
+            quote(path)
+        );
+        let output = self.run(&command, "stat remote file")?;
+        Ok(parse_stat_output(&output.stdout))
+    }
+}
+
+/// Convenience function to get a remote filesystem over an SSH client
+pub fn remote(client: SshClient) -> RemoteFs {
+    RemoteFs::new(client)
+}
+
+fn quote(path: &Path) -> String {
+    shell::quote_path(&path.to_string_lossy())
+}
+
+fn remote_error_message(output: &CommandOutput) -> String {
+    let stderr = output.stderr.trim();
+    if stderr.is_empty() {
+        format!("Remote command exited with code {}", output.exit_code)
+    } else {
+        stderr.to_string()
+    }
+}
+
+fn parse_list_output(dir: &Path, stdout: &str) -> Vec<Entry> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (kind, name) = line.split_once(' ')?;
+            let is_dir = match kind {
+                "d" => true,
+                "f" => false,
+                _ => return None,
+            };
+            Some(Entry {
+                path: dir.join(name),
+                is_dir,
+            })
+        })
+        .collect()
+}
+
+fn parse_stat_output(stdout: &str) -> Option<FileStat> {
+    let mut parts = stdout.split_whitespace();
+    let is_dir = match parts.next()? {
+        "d" => true,
+        "f" => false,
+        _ => return None,
+    };
+    let size = parts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    Some(FileStat { is_dir, size })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+
+    /// Records every command and replays scripted outputs in order.
+    #[derive(Default)]
+    struct MockExec {
+        commands: RefCell<Vec<String>>,
+        responses: RefCell<VecDeque<CommandOutput>>,
+    }
+
+    impl MockExec {
+        fn respond(self, stdout: &str, exit_code: i32) -> Self {
+            self.responses.borrow_mut().push_back(CommandOutput {
+                stdout: stdout.to_string(),
+                stderr: if exit_code == 0 {
+                    String::new()
+                } else {
+                    "boom".to_string()
+                },
+                success: exit_code == 0,
+                exit_code,
+                child_resource: None,
+            });
+            self
+        }
+    }
+
+    impl RemoteExec for &MockExec {
+        fn execute(&self, command: &str) -> CommandOutput {
+            self.commands.borrow_mut().push(command.to_string());
+            self.responses
+                .borrow_mut()
+                .pop_front()
+                .expect("unexpected remote command")
+        }
+    }
+
+    /// Generic caller, standing in for config-style code that takes any `FileSystem`.
+    fn read_json_names(fs: &dyn FileSystem, dir: &Path) -> Result<Vec<String>> {
+        let mut names: Vec<String> = fs
+            .list(dir)?
+            .into_iter()
+            .filter(|e| e.is_json())
+            .filter_map(|e| e.path.file_stem().map(|s| s.to_string_lossy().to_string()))
+            .collect();
+        names.sort();
+        Ok(names)
+    }
+
+    #[test]
+    fn read_returns_stdout() {
+        let exec = MockExec::default().respond("{\"id\":\"a\"}", 0);
+        let fs = RemoteFs::new(&exec);
+
+        let content = fs.read(Path::new("/srv/app/a.json")).unwrap();
+
+        assert_eq!(content, "{\"id\":\"a\"}");
+        assert!(exec.commands.borrow()[0].contains("cat '/srv/app/a.json'"));
+    }
+
+    #[test]
+    fn read_missing_file_reports_not_found() {
+        let exec = MockExec::default().respond("", 2);
+        let fs = RemoteFs::new(&exec);
+
+        let err = fs.read(Path::new("/srv/missing.json")).unwrap_err();
+
+        assert_eq!(err.code.as_str(), "internal.io_error");
+        assert_eq!(err.details["error"], "File not found: /srv/missing.json");
+    }
+
+    #[test]
+    fn write_encodes_content_and_renames_atomically() {
+        let exec = MockExec::default().respond("", 0);
+        let fs = RemoteFs::new(&exec);
+        let content = "it's \"quoted\"\nHOMEBOYEOF\n";
+
+        fs.write(Path::new("/srv/app/config.json"), content)
+            .unwrap();
+
+        let command = exec.commands.borrow()[0].clone();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(content);
+        assert!(command.contains(&encoded));
+        assert!(command.contains("base64 -d > '/srv/app/config.json.tmp'"));
+        assert!(command.ends_with("mv '/srv/app/config.json.tmp' '/srv/app/config.json'"));
+    }
+
+    #[test]
+    fn list_parses_entries_through_file_system_trait() {
+        let exec = MockExec::default().respond("f a.json\nf notes.txt\nd nested\nf b.json\n", 0);
+        let fs = RemoteFs::new(&exec);
+
+        let entries = fs.list(Path::new("/srv/configs")).unwrap();
+        assert_eq!(entries.len(), 4);
+        assert!(entries
+            .iter()
+            .any(|e| e.is_dir && e.path == Path::new("/srv/configs/nested")));
+
+        let exec = MockExec::default().respond("f a.json\nf notes.txt\nd nested\nf b.json\n", 0);
+        let fs = RemoteFs::new(&exec);
+        let names = read_json_names(&fs, Path::new("/srv/configs")).unwrap();
+        assert_eq!(names, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn stat_reports_files_dirs_and_missing_paths() {
+        let exec = MockExec::default()
+            .respond("f 42\n", 0)
+            .respond("d 0\n", 0)
+            .respond("", 0);
+        let fs = RemoteFs::new(&exec);
+
+        assert_eq!(
+            fs.stat(Path::new("/srv/a.json")).unwrap(),
+            Some(FileStat {
+                is_dir: false,
+                size: 42
+            })
+        );
+        assert!(fs.stat(Path::new("/srv")).unwrap().unwrap().is_dir);
+        assert_eq!(fs.stat(Path::new("/srv/none")).unwrap(), None);
+    }
+
+    #[test]
+    fn command_failure_surfaces_stderr() {
+        let exec = MockExec::default().respond("", 1);
+        let fs = RemoteFs::new(&exec);
+
+        let err = fs.ensure_dir(Path::new("/srv/new")).unwrap_err();
+
+        assert_eq!(err.code.as_str(), "internal.io_error");
+        assert_eq!(err.details["error"], "boom");
+        assert_eq!(exec.commands.borrow()[0], "mkdir -p '/srv/new'");
+    }
+}
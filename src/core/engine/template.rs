@@ -2,6 +2,10 @@
 
 use std::collections::HashMap;
 
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+
 pub struct TemplateVars;
 
 impl TemplateVars {
@@ -51,3 +55,87 @@ pub fn is_present(template: &str, key: &str) -> bool {
     let placeholder = format!("{{{{{}}}}}", key);
     template.contains(&placeholder)
 }
+
+/// Render `template` against a JSON object, as for `--output-template`.
+///
+/// `{{key}}` reads a top-level field and `{{a.b}}` a nested one. Strings are
+/// inserted without quotes; other values as compact JSON. A placeholder with
+/// no matching field is an error, so scripts never get a silently blank value.
+pub fn render_json(template: &str, data: &Value) -> Result<String> {
+    let Some(object) = data.as_object() else {
+        return Err(Error::validation_invalid_argument(
+            "output_template",
+            "--output-template needs a command whose result is a JSON object",
+            None,
+            None,
+        ));
+    };
+
+    let mut variables = HashMap::new();
+    for key in placeholders(template) {
+        let pointer = format!("/{}", key.replace('.', "/"));
+        let value = data.pointer(&pointer).ok_or_else(|| {
+            let mut available: Vec<String> = object.keys().cloned().collect();
+            available.sort();
+            Error::validation_invalid_argument(
+                "output_template",
+                format!("Result has no field '{}'", key),
+                None,
+                Some(vec![format!("Top-level fields: {}", available.join(", "))]),
+            )
+        })?;
+        let rendered = match value {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+        variables.insert(key.to_string(), rendered);
+    }
+
+    Ok(render_map(template, &variables))
+}
+
+/// Keys named by `{{...}}` placeholders, in order of appearance.
+fn placeholders(template: &str) -> Vec<&str> {
+    let mut keys = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        keys.push(&after[..end]);
+        rest = &after[end + 2..];
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_json_scalars_and_nested_fields() {
+        let data = json!({
+            "next": "1.3.0",
+            "bump": 2,
+            "summary": {"failed": 0, "ok": true},
+        });
+        assert_eq!(render_json("{{next}}", &data).unwrap(), "1.3.0");
+        assert_eq!(
+            render_json("v{{next}} ({{bump}}, {{summary.ok}})", &data).unwrap(),
+            "v1.3.0 (2, true)"
+        );
+        assert_eq!(
+            render_json("{{summary}}", &data).unwrap(),
+            r#"{"failed":0,"ok":true}"#
+        );
+    }
+
+    #[test]
+    fn test_render_json_missing_key_is_an_error() {
+        let err = render_json("{{version}}", &json!({"next": "1.3.0"})).unwrap_err();
+        assert!(err.message.contains("no field 'version'"));
+        assert!(render_json("{{next}}", &json!(["1.3.0"])).is_err());
+    }
+}
@@ -55,6 +55,32 @@ pub fn levenshtein(a: &str, b: &str) -> usize {
     prev_row[b_len]
 }
 
+/// Whether two distinct identifiers probably name the same thing.
+///
+/// True when they match once case and separators (`-`, `_`, `.`, spaces) are
+/// dropped (`prod-server` / `prod_server`), or are one edit apart and at least
+/// six characters long (`marketing` / `marketng`). Identifiers that differ only
+/// in digits (`web-1` / `web-2`) are numbered siblings, not duplicates.
+pub fn is_near_duplicate(a: &str, b: &str) -> bool {
+    let squash = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !matches!(c, '-' | '_' | '.' | ' '))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let (a, b) = (squash(a), squash(b));
+    if a == b {
+        return true;
+    }
+
+    let without_digits =
+        |s: &str| -> String { s.chars().filter(|c| !c.is_ascii_digit()).collect() };
+    if without_digits(&a) == without_digits(&b) {
+        return false;
+    }
+    a.chars().count().min(b.chars().count()) >= 6 && levenshtein(&a, &b) == 1
+}
+
 /// Ensure a regex pattern has multiline mode enabled.
 pub fn ensure_multiline(pattern: &str) -> String {
     if pattern.contains("(?m)") {
@@ -237,6 +263,16 @@ mod tests {
         assert_eq!(levenshtein("kitten", "sitting"), 3);
     }
 
+    #[test]
+    fn test_is_near_duplicate() {
+        assert!(is_near_duplicate("prod-server", "prod_server"));
+        assert!(is_near_duplicate("ProdServer", "prod-server"));
+        assert!(is_near_duplicate("marketing", "marketng"));
+        assert!(!is_near_duplicate("web-1", "web-2"));
+        assert!(!is_near_duplicate("api", "app"));
+        assert!(!is_near_duplicate("production", "staging"));
+    }
+
     #[test]
     fn extract_first_finds_version() {
         let content = r#"Version: 1.2.3"#;
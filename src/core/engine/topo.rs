@@ -0,0 +1,110 @@
+//! Dependency ordering (Kahn's algorithm) with cycle reporting.
+//!
+//! Nodes are indices; `dependencies[i]` lists the nodes `i` depends on.
+//! Used by rig pipelines to order steps and by extension install to reject
+//! circular `requires.extensions`.
+
+use std::collections::VecDeque;
+
+/// Why a graph could not be ordered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Every node that could not be ordered: cycle members plus anything
+    /// that depends on them. Ascending.
+    pub blocked: Vec<usize>,
+    /// One concrete cycle, in dependency order, with the first node repeated
+    /// at the end (`a -> b -> a`).
+    pub path: Vec<usize>,
+}
+
+/// Order nodes so every node comes after its dependencies. Ties resolve in
+/// index order, so the result is stable for a given input.
+pub fn topo_sort(dependencies: &[Vec<usize>]) -> Result<Vec<usize>, Cycle> {
+    let mut indegree = vec![0usize; dependencies.len()];
+    let mut dependents = vec![Vec::<usize>::new(); dependencies.len()];
+
+    for (idx, deps) in dependencies.iter().enumerate() {
+        for &dependency_idx in deps {
+            indegree[idx] += 1;
+            dependents[dependency_idx].push(idx);
+        }
+    }
+    for child_indices in &mut dependents {
+        child_indices.sort_unstable();
+    }
+
+    let mut ready: VecDeque<usize> = indegree
+        .iter()
+        .enumerate()
+        .filter(|(_, count)| **count == 0)
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let mut ordered = Vec::with_capacity(dependencies.len());
+    while let Some(idx) = ready.pop_front() {
+        ordered.push(idx);
+        for dependent_idx in dependents[idx].iter().copied() {
+            indegree[dependent_idx] -= 1;
+            if indegree[dependent_idx] == 0 {
+                ready.push_back(dependent_idx);
+            }
+        }
+    }
+
+    if ordered.len() == dependencies.len() {
+        return Ok(ordered);
+    }
+
+    let blocked: Vec<usize> = (0..dependencies.len())
+        .filter(|&idx| indegree[idx] > 0)
+        .collect();
+    let path = cycle_path(dependencies, &indegree, blocked[0]);
+    Err(Cycle { blocked, path })
+}
+
+/// Every blocked node still has a blocked dependency, so following those
+/// edges from any blocked node must revisit a node; that loop is a cycle.
+fn cycle_path(dependencies: &[Vec<usize>], indegree: &[usize], start: usize) -> Vec<usize> {
+    let mut walk = vec![start];
+    let mut current = start;
+    loop {
+        let next = dependencies[current]
+            .iter()
+            .copied()
+            .find(|&dep| indegree[dep] > 0)
+            .expect("blocked node has a blocked dependency");
+        if let Some(pos) = walk.iter().position(|&seen| seen == next) {
+            let mut path = walk.split_off(pos);
+            path.push(next);
+            return path;
+        }
+        walk.push(next);
+        current = next;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topo_sort() {
+        // 0 <- 1 <- 2, and 3 depends on 0
+        let order = topo_sort(&[vec![], vec![0], vec![1], vec![0]]).unwrap();
+        assert_eq!(order, vec![0, 1, 3, 2]);
+    }
+
+    #[test]
+    fn test_topo_sort_reports_cycle_path() {
+        // 0 -> 1 -> 2 -> 1, and 3 depends on 0
+        let cycle = topo_sort(&[vec![1], vec![2], vec![1], vec![0]]).unwrap_err();
+        assert_eq!(cycle.blocked, vec![0, 1, 2, 3]);
+        assert_eq!(cycle.path, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn test_topo_sort_self_dependency() {
+        let cycle = topo_sort(&[vec![0]]).unwrap_err();
+        assert_eq!(cycle.path, vec![0, 0]);
+    }
+}
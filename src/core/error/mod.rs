@@ -15,6 +15,7 @@ pub enum ErrorCode {
     ConfigInvalidJson,
     ConfigInvalidValue,
     ConfigIdCollision,
+    ConfigLocked,
 
     ValidationMissingArgument,
     ValidationInvalidArgument,
@@ -29,6 +30,7 @@ pub enum ErrorCode {
     FleetNotFound,
     ExtensionNotFound,
     ExtensionUnsupported,
+    ExtensionScriptTimeout,
     DocsTopicNotFound,
     RigNotFound,
     RunnerNotFound,
@@ -64,6 +66,7 @@ impl ErrorCode {
             ErrorCode::ConfigInvalidJson => "config.invalid_json",
             ErrorCode::ConfigInvalidValue => "config.invalid_value",
             ErrorCode::ConfigIdCollision => "config.id_collision",
+            ErrorCode::ConfigLocked => "config.locked",
 
             ErrorCode::ValidationMissingArgument => "validation.missing_argument",
             ErrorCode::ValidationInvalidArgument => "validation.invalid_argument",
@@ -78,6 +81,7 @@ impl ErrorCode {
             ErrorCode::FleetNotFound => "fleet.not_found",
             ErrorCode::ExtensionNotFound => "extension.not_found",
             ErrorCode::ExtensionUnsupported => "extension.unsupported",
+            ErrorCode::ExtensionScriptTimeout => "extension.script_timeout",
             ErrorCode::DocsTopicNotFound => "docs.topic_not_found",
             ErrorCode::RigNotFound => "rig.not_found",
             ErrorCode::RunnerNotFound => "runner.not_found",
@@ -114,6 +118,12 @@ pub struct Hint {
     pub message: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct GitCheckoutBlockedDetails {
+    pub reference: String,
+    pub files: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 
 pub struct ConfigMissingKeyDetails {
@@ -146,6 +156,19 @@ pub struct ConfigIdCollisionDetails {
     pub existing_type: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct ConfigLockedDetails {
+    pub lock_path: String,
+    pub timeout_secs: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExtensionScriptTimeoutDetails {
+    pub extension_id: String,
+    pub script: String,
+    pub timeout_secs: u64,
+}
+
 #[derive(Debug, Serialize)]
 
 pub struct NoActiveProjectDetails {
@@ -312,7 +335,7 @@ impl Error {
             tried,
         });
 
-        Self::new(ErrorCode::ValidationInvalidArgument, message, details)
+        Self::new(ErrorCode::ValidationInvalidArgument, message, details).with_retryable(false)
     }
 
     pub fn validation_invalid_json(
@@ -363,6 +386,7 @@ impl Error {
             "Run 'homeboy {} list' to see available {}s",
             list_cmd, list_cmd
         ))
+        .with_retryable(false)
     }
 
     pub fn project_not_found(id: impl Into<String>, suggestions: Vec<String>) -> Self {
@@ -577,7 +601,28 @@ impl Error {
         )
     }
 
+    /// A failed remote command. `ssh` exits 255 when the connection itself
+    /// fails, so that exit code is reported as an SSH auth or connect error.
     pub fn remote_command_failed(details: RemoteCommandFailedDetails) -> Self {
+        if details.exit_code == 255 {
+            let auth_failed = details.stderr.contains("Permission denied")
+                || details.stderr.contains("Host key verification failed");
+            let message = details
+                .stderr
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or("ssh exited with code 255")
+                .trim()
+                .to_string();
+            let details = to_details(details);
+            return if auth_failed {
+                Self::ssh_auth_failed(message, details)
+            } else {
+                Self::ssh_connect_failed(message, details)
+            };
+        }
+
         let details = to_details(details);
 
         Self::new(
@@ -587,6 +632,37 @@ impl Error {
         )
     }
 
+    /// The SSH connection could not be established; usually transient.
+    pub fn ssh_connect_failed(message: impl Into<String>, details: Value) -> Self {
+        Self::new(
+            ErrorCode::SshConnectFailed,
+            format!("SSH connection failed: {}", message.into()),
+            details,
+        )
+        .with_retryable(true)
+    }
+
+    /// The server rejected the SSH credentials; retrying will not help.
+    pub fn ssh_auth_failed(message: impl Into<String>, details: Value) -> Self {
+        Self::new(
+            ErrorCode::SshAuthFailed,
+            format!("SSH authentication failed: {}", message.into()),
+            details,
+        )
+        .with_retryable(false)
+        .with_hint("Check the server's user and identity_file, or run 'homeboy server key'")
+    }
+
+    pub fn remote_command_timeout(command: impl Into<String>, timeout_secs: u64) -> Self {
+        let command = command.into();
+        Self::new(
+            ErrorCode::RemoteCommandTimeout,
+            format!("Remote command timed out after {}s", timeout_secs),
+            serde_json::json!({ "command": command, "timeout_secs": timeout_secs }),
+        )
+        .with_retryable(true)
+    }
+
     pub fn git_command_failed(message: impl Into<String>) -> Self {
         Self::new(
             ErrorCode::GitCommandFailed,
@@ -595,6 +671,21 @@ impl Error {
         )
     }
 
+    /// A checkout refused because it would overwrite local changes in `files`.
+    pub fn git_checkout_blocked(reference: impl Into<String>, files: Vec<String>) -> Self {
+        let reference = reference.into();
+        Self::new(
+            ErrorCode::GitCommandFailed,
+            format!(
+                "Checking out '{}' would overwrite local changes in {} file(s)",
+                reference,
+                files.len()
+            ),
+            to_details(GitCheckoutBlockedDetails { reference, files }),
+        )
+        .with_hint("Commit them, or run `git stash`, check out again, then `git stash pop`")
+    }
+
     pub fn config_missing_key(key: impl Into<String>, path: Option<String>) -> Self {
         let details = to_details(ConfigMissingKeyDetails {
             key: key.into(),
@@ -663,6 +754,47 @@ impl Error {
         ))
     }
 
+    pub fn config_locked(lock_path: impl Into<String>, timeout_secs: u64) -> Self {
+        let details = to_details(ConfigLockedDetails {
+            lock_path: lock_path.into(),
+            timeout_secs,
+        });
+
+        Self::new(
+            ErrorCode::ConfigLocked,
+            format!(
+                "Config is locked by another process (waited {}s)",
+                timeout_secs
+            ),
+            details,
+        )
+        .with_hint("Wait for the other homeboy command to finish, or raise --lock-timeout")
+        .with_retryable(true)
+    }
+
+    pub fn extension_script_timeout(
+        extension_id: impl Into<String>,
+        script: impl Into<String>,
+        timeout_secs: u64,
+    ) -> Self {
+        let extension_id = extension_id.into();
+        let script = script.into();
+        let message = format!(
+            "Extension '{}' {} script timed out after {}s",
+            extension_id, script, timeout_secs
+        );
+        let details = to_details(ExtensionScriptTimeoutDetails {
+            extension_id: extension_id.clone(),
+            script: script.clone(),
+            timeout_secs,
+        });
+
+        Self::new(ErrorCode::ExtensionScriptTimeout, message, details).with_hint(format!(
+            "Raise scripts.timeout_secs.{} in the '{}' extension manifest if the script needs longer",
+            script, extension_id
+        ))
+    }
+
     pub fn project_no_active(config_path: Option<String>) -> Self {
         let details = to_details(NoActiveProjectDetails { config_path });
 
@@ -706,6 +838,12 @@ impl Error {
         self
     }
 
+    /// Mark whether running the same operation again may succeed.
+    pub fn with_retryable(mut self, retryable: bool) -> Self {
+        self.retryable = Some(retryable);
+        self
+    }
+
     pub fn with_contextual_hint(self) -> Self {
         match self.code {
             ErrorCode::ComponentNotFound
@@ -13,8 +13,13 @@ use crate::paths;
 use crate::server::execute_local_command_in_dir;
 
 mod artifact;
+mod package;
 
 pub use artifact::{resolve_artifact_path, resolve_artifact_path_from_root};
+pub use package::{
+    collect_package_files, package_component, PackageFormat, PackageOptions, PackageOutput,
+    DEFAULT_PACKAGE_EXCLUDES,
+};
 
 // === Build Command Resolution ===
 
@@ -153,6 +158,9 @@ pub struct BuildOutput {
     #[serde(flatten)]
     pub output: CapturedOutput,
     pub success: bool,
+    /// Archive written by `build --package`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<PackageOutput>,
 }
 
 #[derive(Debug, Serialize)]
@@ -366,6 +374,70 @@ pub fn run_components(components: &[Component]) -> Result<(BuildResult, i32)> {
     ))
 }
 
+/// Package every successfully built component in `result`.
+///
+/// `components` supplies the resolved config for each built id. A packaging
+/// failure fails a single build; in a bulk build it fails that item only.
+pub fn package_build_result(
+    (result, exit_code): (BuildResult, i32),
+    components: &[Component],
+    options: &PackageOptions,
+) -> Result<(BuildResult, i32)> {
+    let component_for = |id: &str| components.iter().find(|c| c.id == id);
+    match result {
+        BuildResult::Single(mut output) => {
+            if output.success {
+                if let Some(component) = component_for(&output.component_id) {
+                    output.package = Some(package_built_component(component, options)?);
+                }
+            }
+            Ok((BuildResult::Single(output), exit_code))
+        }
+        BuildResult::Bulk(mut bulk) => {
+            for item in &mut bulk.results {
+                let Some(output) = item.result.as_mut().filter(|output| output.success) else {
+                    continue;
+                };
+                let Some(component) = component_for(&item.id) else {
+                    continue;
+                };
+                match package_built_component(component, options) {
+                    Ok(package) => output.package = Some(package),
+                    Err(error) => {
+                        output.success = false;
+                        item.error = Some(error.to_string());
+                        bulk.summary.succeeded -= 1;
+                        bulk.summary.failed += 1;
+                    }
+                }
+            }
+            let exit_code = if bulk.summary.failed > 0 {
+                1
+            } else {
+                exit_code
+            };
+            Ok((BuildResult::Bulk(bulk), exit_code))
+        }
+    }
+}
+
+fn package_built_component(
+    component: &Component,
+    options: &PackageOptions,
+) -> Result<PackageOutput> {
+    let version = crate::version::get_component_version(component);
+    let package = package_component(component, version.as_deref(), options)?;
+    log_status!(
+        "build",
+        "Packaged '{}' → {} ({} files, {} bytes)",
+        component.id,
+        package.path,
+        package.files,
+        package.size
+    );
+    Ok(package)
+}
+
 fn execute_build(component_id: &str, path_override: Option<&str>) -> Result<(BuildOutput, i32)> {
     let comp = component::resolve_effective(Some(component_id), path_override, None)?;
     execute_build_component(&comp)
@@ -408,6 +480,7 @@ fn execute_build_component(comp: &Component) -> Result<(BuildOutput, i32)> {
                     build_command: build_cmd,
                     output: CapturedOutput::new(String::new(), stderr),
                     success: false,
+                    package: None,
                 },
                 exit_code,
             ));
@@ -456,6 +529,7 @@ fn execute_build_component(comp: &Component) -> Result<(BuildOutput, i32)> {
             build_command: build_cmd,
             output: CapturedOutput::new(runner_output.stdout, runner_output.stderr),
             success,
+            package: None,
         },
         runner_output.exit_code,
     ))
@@ -0,0 +1,354 @@
+//! Archive a component's publishable files into a distributable package.
+//!
+//! `build --package <zip|tar.gz>` and the release `package` step (when
+//! `release --package` is given) collect every file under the component root
+//! that survives `.gitignore`, `.homeboyignore`, the default development
+//! excludes, and any extra `--exclude` globs. The files are archived under a
+//! top-level `<component>/` folder as `<component>-<version>.<ext>`.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::component::Component;
+use crate::engine::command;
+use crate::engine::ignore_rules::IgnoreRules;
+use crate::engine::local_files;
+use crate::error::{Error, Result};
+use crate::paths;
+
+/// Development-only paths left out of every package, in gitignore syntax.
+pub const DEFAULT_PACKAGE_EXCLUDES: &[&str] =
+    &[".git", ".github", "node_modules", "tests", ".DS_Store"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PackageFormat {
+    #[serde(rename = "zip")]
+    Zip,
+    #[serde(rename = "tar.gz")]
+    TarGz,
+}
+
+impl PackageFormat {
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "zip" => Ok(Self::Zip),
+            "tar.gz" | "tgz" => Ok(Self::TarGz),
+            other => Err(Error::validation_invalid_argument(
+                "package",
+                format!("Unknown package format '{}'", other),
+                Some(other.to_string()),
+                Some(vec!["zip".to_string(), "tar.gz".to_string()]),
+            )),
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::TarGz => "tar.gz",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PackageOptions {
+    pub format: PackageFormat,
+    /// Where to write the archive. Defaults to `<artifact_root>/packages/<component>`.
+    pub output_dir: Option<PathBuf>,
+    /// Extra gitignore-syntax globs excluded on top of the defaults.
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PackageOutput {
+    pub path: String,
+    pub format: PackageFormat,
+    pub size: u64,
+    pub sha256: String,
+    pub files: usize,
+}
+
+/// Archive `component`'s publishable files into the package output dir.
+///
+/// The archive is named `<component>-<version>.<ext>`, or `<component>.<ext>`
+/// when `version` is unknown.
+pub fn package_component(
+    component: &Component,
+    version: Option<&str>,
+    options: &PackageOptions,
+) -> Result<PackageOutput> {
+    let root = Path::new(&component.local_path);
+    let output_dir = match &options.output_dir {
+        Some(dir) => dir.clone(),
+        None => paths::component_packages(&component.id)?,
+    };
+    let file_name = match version {
+        Some(version) => format!(
+            "{}-{}.{}",
+            component.id,
+            version,
+            options.format.extension()
+        ),
+        None => format!("{}.{}", component.id, options.format.extension()),
+    };
+    let archive_path = output_dir.join(file_name);
+
+    let files = collect_package_files(root, &output_dir, &options.exclude)?;
+    if files.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "package",
+            format!(
+                "No publishable files found in {} after applying ignore rules",
+                root.display()
+            ),
+            Some(component.id.clone()),
+            None,
+        )
+        .with_hint("Check .gitignore, .homeboyignore, and --exclude globs"));
+    }
+
+    // Under `--dry-run` report what would be archived; size and checksum
+    // are unknown without writing the archive.
+    if local_files::skipped_for_dry_run(|| {
+        format!(
+            "write {}-file package to {}",
+            files.len(),
+            archive_path.display()
+        )
+    }) {
+        return Ok(PackageOutput {
+            path: archive_path.to_string_lossy().to_string(),
+            format: options.format,
+            size: 0,
+            sha256: String::new(),
+            files: files.len(),
+        });
+    }
+
+    fs::create_dir_all(&output_dir).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("create {}", output_dir.display())),
+        )
+    })?;
+    match options.format {
+        PackageFormat::Zip => write_zip(root, &component.id, &files, &archive_path)?,
+        PackageFormat::TarGz => write_tar_gz(root, &component.id, &files, &archive_path)?,
+    }
+
+    let bytes = fs::read(&archive_path).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("read package {}", archive_path.display())),
+        )
+    })?;
+
+    Ok(PackageOutput {
+        path: archive_path.to_string_lossy().to_string(),
+        format: options.format,
+        size: bytes.len() as u64,
+        sha256: format!("{:x}", Sha256::digest(&bytes)),
+        files: files.len(),
+    })
+}
+
+/// Root-relative, `/`-separated paths of every file that belongs in the package.
+pub fn collect_package_files(
+    root: &Path,
+    output_dir: &Path,
+    exclude: &[String],
+) -> Result<Vec<String>> {
+    let ignore = IgnoreRules::load(root);
+    let mut excludes: Vec<&str> = DEFAULT_PACKAGE_EXCLUDES.to_vec();
+    excludes.extend(exclude.iter().map(String::as_str));
+    let excludes = IgnoreRules::parse(&excludes.join("\n"));
+    let output_dir = fs::canonicalize(output_dir).unwrap_or_else(|_| output_dir.to_path_buf());
+
+    let mut files = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        if fs::canonicalize(&dir).is_ok_and(|dir| dir == output_dir) {
+            continue;
+        }
+        let entries = fs::read_dir(&dir).map_err(|e| {
+            Error::internal_io(e.to_string(), Some(format!("read {}", dir.display())))
+        })?;
+        for entry in entries {
+            let entry = entry.map_err(|e| {
+                Error::internal_io(e.to_string(), Some(format!("read {}", dir.display())))
+            })?;
+            let path = entry.path();
+            let Ok(relative) = path.strip_prefix(root) else {
+                continue;
+            };
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            let is_dir = path.is_dir();
+            if ignore.is_ignored(&relative, is_dir) || excludes.is_ignored(&relative, is_dir) {
+                continue;
+            }
+            if is_dir {
+                if !entry.file_type().is_ok_and(|t| t.is_symlink()) {
+                    pending.push(path);
+                }
+            } else if path.is_file() {
+                files.push(relative);
+            }
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+fn write_zip(root: &Path, prefix: &str, files: &[String], archive_path: &Path) -> Result<()> {
+    let io_err = |e: io::Error| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("write package {}", archive_path.display())),
+        )
+    };
+    let zip_err = |e: zip::result::ZipError| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("write package {}", archive_path.display())),
+        )
+    };
+
+    let mut zip = zip::ZipWriter::new(fs::File::create(archive_path).map_err(io_err)?);
+    for relative in files {
+        let source = root.join(relative);
+        let mut options =
+            zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            if let Ok(metadata) = fs::metadata(&source) {
+                options = options.unix_permissions(metadata.permissions().mode());
+            }
+        }
+        zip.start_file(format!("{}/{}", prefix, relative), options)
+            .map_err(zip_err)?;
+        let mut reader = fs::File::open(&source).map_err(io_err)?;
+        io::copy(&mut reader, &mut zip).map_err(io_err)?;
+    }
+    zip.finish().map_err(zip_err)?;
+    Ok(())
+}
+
+/// Stage the files under `<prefix>/` and archive the staging dir with `tar`,
+/// which keeps the top-level folder portable across GNU and BSD tar.
+fn write_tar_gz(root: &Path, prefix: &str, files: &[String], archive_path: &Path) -> Result<()> {
+    let staging = archive_path.with_extension("staging");
+    let result = stage_files(root, &staging.join(prefix), files).and_then(|()| {
+        let archive = fs::canonicalize(archive_path.parent().unwrap_or(Path::new(".")))
+            .map(|dir| dir.join(archive_path.file_name().unwrap_or_default()))
+            .unwrap_or_else(|_| archive_path.to_path_buf());
+        command::run_in(
+            &staging.to_string_lossy(),
+            "tar",
+            &["-czf", &archive.to_string_lossy(), prefix],
+            "tar package",
+        )
+        .map(|_| ())
+    });
+    let _ = fs::remove_dir_all(&staging);
+    result
+}
+
+fn stage_files(root: &Path, staging: &Path, files: &[String]) -> Result<()> {
+    for relative in files {
+        let target = staging.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                Error::internal_io(e.to_string(), Some(format!("create {}", parent.display())))
+            })?;
+        }
+        fs::copy(root.join(relative), &target)
+            .map_err(|e| Error::internal_io(e.to_string(), Some(format!("stage {}", relative))))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn fixture() -> (tempfile::TempDir, Component) {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "plugin.php", "<?php");
+        write(root, "src/lib.txt", "lib");
+        write(root, "tests/unit.txt", "test");
+        write(root, "node_modules/dep/index.js", "dep");
+        write(root, "cache/tmp.txt", "tmp");
+        write(root, "docs/notes.md", "notes");
+        write(root, ".gitignore", "cache/\n");
+        let component = Component {
+            id: "widget".to_string(),
+            local_path: root.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        (dir, component)
+    }
+
+    #[test]
+    fn test_collect_package_files_applies_ignore_rules_and_excludes() {
+        let (dir, _) = fixture();
+        let output = dir.path().join("dist");
+        let files = collect_package_files(dir.path(), &output, &["docs/".to_string()]).unwrap();
+
+        assert_eq!(files, vec![".gitignore", "plugin.php", "src/lib.txt"]);
+    }
+
+    #[test]
+    fn test_package_component_zip() {
+        let (dir, component) = fixture();
+        let options = PackageOptions {
+            format: PackageFormat::Zip,
+            output_dir: Some(dir.path().join("dist")),
+            exclude: Vec::new(),
+        };
+
+        let first = package_component(&component, Some("1.2.0"), &options).unwrap();
+        // A second run must not pick up the first archive from the output dir.
+        let package = package_component(&component, Some("1.2.0"), &options).unwrap();
+
+        assert!(package.path.ends_with("widget-1.2.0.zip"));
+        assert_eq!(package.files, first.files);
+        assert_eq!(package.size, fs::metadata(&package.path).unwrap().len());
+        assert_eq!(package.sha256.len(), 64);
+
+        let archive = zip::ZipArchive::new(fs::File::open(&package.path).unwrap()).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "widget/.gitignore",
+                "widget/docs/notes.md",
+                "widget/plugin.php",
+                "widget/src/lib.txt"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_package_format_parse() {
+        assert_eq!(PackageFormat::parse("zip").unwrap(), PackageFormat::Zip);
+        assert_eq!(
+            PackageFormat::parse("tar.gz").unwrap(),
+            PackageFormat::TarGz
+        );
+        assert!(PackageFormat::parse("rar").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! `extension exec-raw`: run one capability script with caller-supplied stdin.
+//!
+//! The structured callers (`run_fingerprint_script` and friends) swallow
+//! malformed output and return `None`. This runs the same script the same way
+//! but hands back the raw streams and exit status untouched, for debugging.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::{load_extension, run_json_script, ExtensionManifest};
+use crate::error::{Error, Result};
+
+/// Capabilities whose script `exec-raw` can run.
+pub const RAW_CAPABILITIES: &[&str] = &[
+    "fingerprint",
+    "refactor",
+    "topology",
+    "validate",
+    "format",
+    "contract",
+    "lint",
+    "build",
+    "test",
+    "bench",
+    "trace",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RawScriptOutput {
+    pub extension_id: String,
+    pub capability: String,
+    pub script_path: String,
+    /// `None` when the script was killed by a signal.
+    pub exit_code: Option<i32>,
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+/// Run `extension_id`'s `capability` script with `input` on stdin.
+pub fn exec_raw(extension_id: &str, capability: &str, input: &[u8]) -> Result<RawScriptOutput> {
+    let extension = load_extension(extension_id)?;
+    run_capability_script(&extension, capability, input)
+}
+
+fn run_capability_script(
+    extension: &ExtensionManifest,
+    capability: &str,
+    input: &[u8],
+) -> Result<RawScriptOutput> {
+    let script_rel = capability_script(extension, capability)?;
+    let extension_path = extension.extension_path.as_deref().unwrap_or_default();
+    let script_path = Path::new(extension_path).join(script_rel);
+
+    if !script_path.exists() {
+        return Err(Error::validation_invalid_argument(
+            "capability",
+            format!(
+                "{} script for '{}' not found at {}",
+                capability,
+                extension.id,
+                script_path.display()
+            ),
+            Some(extension.id.clone()),
+            None,
+        ));
+    }
+
+    let output = run_json_script(extension, capability, &script_path, input)?;
+
+    Ok(RawScriptOutput {
+        extension_id: extension.id.clone(),
+        capability: capability.to_string(),
+        script_path: script_path.to_string_lossy().to_string(),
+        exit_code: output.status.code(),
+        success: output.status.success(),
+        stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+        stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+    })
+}
+
+fn capability_script<'a>(extension: &'a ExtensionManifest, capability: &str) -> Result<&'a str> {
+    let script = match capability {
+        "fingerprint" => extension.fingerprint_script(),
+        "refactor" => extension.refactor_script(),
+        "topology" => extension.topology_script(),
+        "validate" => extension.validate_script(),
+        "format" => extension.format_script(),
+        "contract" => extension.contract_script(),
+        "lint" => extension.lint_script(),
+        "build" => extension.build_script(),
+        "test" => extension.test_script(),
+        "bench" => extension.bench_script(),
+        "trace" => extension.trace_script(),
+        _ => {
+            return Err(Error::validation_invalid_argument(
+                "capability",
+                format!(
+                    "Unknown capability '{}'. Valid capabilities: {}",
+                    capability,
+                    RAW_CAPABILITIES.join(", ")
+                ),
+                None,
+                None,
+            ))
+        }
+    };
+
+    script.ok_or_else(|| {
+        Error::validation_invalid_argument(
+            "capability",
+            format!("Extension '{}' has no {} script", extension.id, capability),
+            Some(extension.id.clone()),
+            None,
+        )
+        .with_hint(format!(
+            "Run `homeboy extension show {}` to see its capabilities",
+            extension.id
+        ))
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn fingerprint_extension(dir: &Path, body: &str) -> ExtensionManifest {
+        let script_path = dir.join("fingerprint.sh");
+        std::fs::write(&script_path, body).unwrap();
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        let mut manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
+            "name": "Raw",
+            "version": "1.0.0",
+            "scripts": { "fingerprint": "fingerprint.sh" }
+        }))
+        .unwrap();
+        manifest.id = "raw".to_string();
+        manifest.extension_path = Some(dir.to_string_lossy().to_string());
+        manifest
+    }
+
+    #[test]
+    fn test_run_capability_script_returns_raw_streams() {
+        let dir = tempfile::tempdir().unwrap();
+        let extension = fingerprint_extension(
+            dir.path(),
+            "#!/bin/sh\nprintf '{\"broken\": '\ncat >&2\nexit 3\n",
+        );
+
+        let output = run_capability_script(&extension, "fingerprint", b"stdin text").unwrap();
+        assert_eq!(output.exit_code, Some(3));
+        assert!(!output.success);
+        assert_eq!(output.stdout, "{\"broken\": ");
+        assert_eq!(output.stderr, "stdin text");
+    }
+
+    #[test]
+    fn test_capability_script_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let extension = fingerprint_extension(dir.path(), "#!/bin/sh\n");
+
+        let err = run_capability_script(&extension, "nope", b"").unwrap_err();
+        assert!(err.message.contains("Unknown capability"));
+        let err = run_capability_script(&extension, "refactor", b"").unwrap_err();
+        assert!(err.message.contains("has no refactor script"));
+    }
+}
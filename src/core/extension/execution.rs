@@ -16,6 +16,7 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use super::exec_context;
+use super::inputs::validate_inputs;
 use super::load_extension;
 use super::manifest::{ActionConfig, ActionType, ExtensionManifest, HttpMethod, RuntimeConfig};
 use super::runner_contract::RunnerStepFilter;
@@ -301,7 +302,8 @@ fn build_args_string(
         if let Some(value) = input_values.get(&input.id) {
             if !value.is_empty() {
                 argv.push(input.arg.clone());
-                argv.push(value.clone());
+                // Quoted so JSON values from `--input-file` survive the shell.
+                argv.push(shell::quote_arg(value));
             }
         }
     }
@@ -778,6 +780,7 @@ fn execute_extension_runtime(
         "extension_path not set",
     )?;
 
+    let inputs = validate_inputs(&extension, inputs)?;
     let args_str = build_args_string(&extension, inputs, args);
     let context = resolve_extension_context(
         &extension,
@@ -0,0 +1,457 @@
+//! Resolve `extension run` inputs against the manifest's declared `inputs`.
+//!
+//! Inputs arrive as `--input key=value` pairs and, optionally, a JSON document
+//! from `--input-file`. File values are applied first and command-line pairs
+//! override them; execution then checks the merged set against each
+//! [`InputConfig`] before the runtime command starts.
+//!
+//! `extension describe` renders the same declarations as a schema, sharing
+//! [`InputKind`] with validation so the two can't disagree about types.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::config::read_json_spec_to_string;
+use crate::error::{Error, Result, ValidationErrorItem};
+
+use super::{ExtensionManifest, InputConfig};
+
+/// How a declared input `type` is validated and described.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InputKind {
+    Number,
+    Boolean,
+    Select,
+    Text,
+}
+
+impl InputKind {
+    pub fn of(input: &InputConfig) -> Self {
+        match input.input_type.as_str() {
+            "number" => Self::Number,
+            "checkbox" | "boolean" => Self::Boolean,
+            "select" => Self::Select,
+            _ => Self::Text,
+        }
+    }
+
+    fn json_type(self) -> &'static str {
+        match self {
+            Self::Number => "number",
+            Self::Boolean => "boolean",
+            Self::Select | Self::Text => "string",
+        }
+    }
+}
+
+/// One declared input, as `extension describe` reports it.
+#[derive(Debug, Clone, Serialize)]
+pub struct InputField {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: InputKind,
+    pub label: String,
+    pub arg: String,
+    /// Must be passed: declared `required` with no `default`.
+    pub required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<i32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
+}
+
+fn is_required(input: &InputConfig) -> bool {
+    input.required && input.default.is_none()
+}
+
+/// Describe each declared input in declaration order.
+pub fn describe_inputs(extension: &ExtensionManifest) -> Vec<InputField> {
+    extension
+        .inputs()
+        .iter()
+        .map(|input| InputField {
+            id: input.id.clone(),
+            kind: InputKind::of(input),
+            label: input.label.clone(),
+            arg: input.arg.clone(),
+            required: is_required(input),
+            default: input.default.clone(),
+            min: input.min,
+            max: input.max,
+            allowed: input
+                .options
+                .iter()
+                .flatten()
+                .map(|option| option.value.clone())
+                .collect(),
+            help: input.help.clone(),
+        })
+        .collect()
+}
+
+/// Build a JSON Schema document for the `--input-file` object the extension accepts.
+pub fn input_json_schema(extension: &ExtensionManifest) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in describe_inputs(extension) {
+        let mut property = json!({
+            "type": field.kind.json_type(),
+            "title": field.label,
+        });
+        if let Some(help) = &field.help {
+            property["description"] = json!(help);
+        }
+        if let Some(default) = &field.default {
+            property["default"] = default.clone();
+        }
+        if let Some(min) = field.min {
+            property["minimum"] = json!(min);
+        }
+        if let Some(max) = field.max {
+            property["maximum"] = json!(max);
+        }
+        if !field.allowed.is_empty() {
+            property["enum"] = json!(field.allowed);
+        }
+        if field.required {
+            required.push(field.id.clone());
+        }
+        properties.insert(field.id, property);
+    }
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": format!("{} inputs", extension.id),
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Read an input document from a file path, or stdin when `path` is `-`.
+pub fn read_input_document(path: &str) -> Result<Value> {
+    let spec = if path.trim() == "-" {
+        "-".to_string()
+    } else {
+        format!("@{}", path)
+    };
+    let raw = read_json_spec_to_string(&spec)?;
+    let document: Value = serde_json::from_str(&raw).map_err(|e| {
+        Error::validation_invalid_json(e, Some("parse --input-file".to_string()), Some(raw))
+    })?;
+    if !document.is_object() {
+        return Err(Error::validation_invalid_argument(
+            "input-file",
+            "Input document must be a JSON object keyed by input ID",
+            None,
+            None,
+        ));
+    }
+    Ok(document)
+}
+
+/// Merge `document` with `overrides`; command-line pairs win.
+pub fn merge_inputs(
+    document: Option<&Value>,
+    overrides: Vec<(String, String)>,
+) -> Vec<(String, String)> {
+    let mut values: Vec<(String, String)> = Vec::new();
+    if let Some(Value::Object(map)) = document {
+        for (key, value) in map {
+            if let Some(value) = input_value_string(value) {
+                values.push((key.clone(), value));
+            }
+        }
+    }
+    for (key, value) in overrides {
+        values.retain(|(existing, _)| *existing != key);
+        values.push((key, value));
+    }
+    values
+}
+
+/// Check `inputs` against the extension's declared inputs before it runs.
+///
+/// Reports every problem at once — unknown IDs, missing required inputs,
+/// values that don't match the declared `type`, and `select` values outside
+/// the declared options — as one `validation.invalid_argument` error with an
+/// `errors` list in its details. On success returns the coerced values in
+/// declaration order.
+pub fn validate_inputs(
+    extension: &ExtensionManifest,
+    inputs: Vec<(String, String)>,
+) -> Result<Vec<(String, String)>> {
+    let declared = extension.inputs();
+    let mut values: HashMap<String, String> = inputs.into_iter().collect();
+    let mut problems: Vec<ValidationErrorItem> = Vec::new();
+
+    let mut unknown: Vec<&String> = values
+        .keys()
+        .filter(|key| !declared.iter().any(|input| &input.id == *key))
+        .collect();
+    unknown.sort();
+    for key in unknown {
+        let known: Vec<&str> = declared.iter().map(|input| input.id.as_str()).collect();
+        problems.push(problem(
+            key,
+            format!(
+                "Unknown input for extension '{}' (declared: {})",
+                extension.id,
+                if known.is_empty() {
+                    "none".to_string()
+                } else {
+                    known.join(", ")
+                }
+            ),
+            None,
+        ));
+    }
+
+    for input in declared {
+        let Some(value) = values.get_mut(&input.id).filter(|value| !value.is_empty()) else {
+            if is_required(input) {
+                problems.push(problem(
+                    &input.id,
+                    format!(
+                        "Required input is missing: {}",
+                        input.help.as_deref().unwrap_or(&input.label)
+                    ),
+                    None,
+                ));
+            }
+            continue;
+        };
+        match coerce_value(input, value) {
+            Ok(coerced) => *value = coerced,
+            Err(item) => problems.push(item),
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(invalid_inputs_error(&extension.id, problems));
+    }
+
+    Ok(declared
+        .iter()
+        .filter_map(|input| {
+            values
+                .remove(&input.id)
+                .map(|value| (input.id.clone(), value))
+        })
+        .collect())
+}
+
+fn invalid_inputs_error(extension_id: &str, problems: Vec<ValidationErrorItem>) -> Error {
+    let summary: Vec<String> = problems
+        .iter()
+        .map(|item| format!("{}: {}", item.field, item.problem))
+        .collect();
+    let mut err = Error::validation_invalid_argument(
+        "input",
+        summary.join("; "),
+        Some(extension_id.to_string()),
+        None,
+    );
+    err.details["errors"] = serde_json::to_value(&problems).unwrap_or(Value::Null);
+    err
+}
+
+fn problem(field: &str, problem: String, context: Option<Value>) -> ValidationErrorItem {
+    ValidationErrorItem {
+        field: field.to_string(),
+        problem,
+        context,
+    }
+}
+
+/// Strings pass through; scalars are stringified; nested arrays/objects are
+/// passed as compact JSON. `null` means "not set".
+fn input_value_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) => Some(s.clone()),
+        Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        Value::Array(_) | Value::Object(_) => Some(value.to_string()),
+    }
+}
+
+/// Validate `value` against the input's declared type, returning the
+/// normalized form passed to the extension.
+fn coerce_value(
+    input: &InputConfig,
+    value: &str,
+) -> std::result::Result<String, ValidationErrorItem> {
+    match InputKind::of(input) {
+        InputKind::Number => {
+            let trimmed = value.trim();
+            let Ok(number) = trimmed.parse::<f64>() else {
+                return Err(problem(
+                    &input.id,
+                    format!("Expected a number, got '{}'", value),
+                    None,
+                ));
+            };
+            if input.min.is_some_and(|min| number < f64::from(min))
+                || input.max.is_some_and(|max| number > f64::from(max))
+            {
+                return Err(problem(
+                    &input.id,
+                    format!(
+                        "Value {} is outside the allowed range {}..{}",
+                        trimmed,
+                        input.min.map(|n| n.to_string()).unwrap_or_default(),
+                        input.max.map(|n| n.to_string()).unwrap_or_default()
+                    ),
+                    None,
+                ));
+            }
+            Ok(trimmed.to_string())
+        }
+        InputKind::Boolean => match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok("true".to_string()),
+            "false" | "0" | "no" | "off" => Ok("false".to_string()),
+            _ => Err(problem(
+                &input.id,
+                format!("Expected true or false, got '{}'", value),
+                None,
+            )),
+        },
+        InputKind::Select => {
+            let Some(options) = &input.options else {
+                return Ok(value.to_string());
+            };
+            if options.iter().any(|option| option.value == value) {
+                return Ok(value.to_string());
+            }
+            let allowed: Vec<&str> = options.iter().map(|o| o.value.as_str()).collect();
+            Err(problem(
+                &input.id,
+                format!(
+                    "'{}' is not one of the allowed values: {}",
+                    value,
+                    allowed.join(", ")
+                ),
+                Some(json!({ "allowed": allowed })),
+            ))
+        }
+        InputKind::Text => Ok(value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extension() -> ExtensionManifest {
+        let mut manifest: ExtensionManifest = serde_json::from_value(json!({
+            "name": "Reporter",
+            "version": "1.0.0",
+            "executable": {
+                "runtime": {"run_command": "./report {{args}}"},
+                "inputs": [
+                    {"id": "site", "type": "text", "label": "Site", "arg": "--site",
+                     "required": true, "help": "Site URL to report on"},
+                    {"id": "limit", "type": "number", "label": "Limit", "arg": "--limit",
+                     "min": 1, "max": 50},
+                    {"id": "filters", "type": "text", "label": "Filters", "arg": "--filters"},
+                    {"id": "format", "type": "select", "label": "Format", "arg": "--format",
+                     "options": [{"value": "json", "label": "JSON"}, {"value": "csv", "label": "CSV"}]},
+                    {"id": "verbose", "type": "checkbox", "label": "Verbose", "arg": "--verbose"}
+                ]
+            }
+        }))
+        .expect("manifest");
+        manifest.id = "reporter".to_string();
+        manifest
+    }
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_inputs() {
+        let document = json!({"site": "https://a.test", "limit": 10, "filters": {"tag": "x"}});
+
+        let mut merged = merge_inputs(Some(&document), pairs(&[("limit", "20")]));
+        merged.sort();
+
+        assert_eq!(
+            merged,
+            pairs(&[
+                ("filters", r#"{"tag":"x"}"#),
+                ("limit", "20"),
+                ("site", "https://a.test"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_validate_inputs() {
+        let inputs = pairs(&[("verbose", "YES"), ("limit", " 5 "), ("site", "s")]);
+
+        let validated = validate_inputs(&extension(), inputs).expect("valid inputs");
+
+        assert_eq!(
+            validated,
+            pairs(&[("site", "s"), ("limit", "5"), ("verbose", "true")])
+        );
+    }
+
+    #[test]
+    fn validate_inputs_reports_missing_required_with_help() {
+        let err = validate_inputs(&extension(), Vec::new()).unwrap_err();
+
+        assert_eq!(err.code.as_str(), "validation.invalid_argument");
+        assert_eq!(
+            err.details["errors"][0]["problem"],
+            "Required input is missing: Site URL to report on"
+        );
+    }
+
+    #[test]
+    fn validate_inputs_reports_every_problem_at_once() {
+        let inputs = pairs(&[
+            ("site", "s"),
+            ("limit", "99"),
+            ("colour", "red"),
+            ("format", "xml"),
+            ("verbose", "maybe"),
+        ]);
+
+        let err = validate_inputs(&extension(), inputs).unwrap_err();
+
+        let errors = err.details["errors"].as_array().expect("errors");
+        let fields: Vec<&str> = errors.iter().filter_map(|e| e["field"].as_str()).collect();
+        assert_eq!(fields, ["colour", "limit", "format", "verbose"]);
+        assert_eq!(errors[2]["context"]["allowed"], json!(["json", "csv"]));
+        assert!(err.message.contains("allowed values: json, csv"));
+    }
+
+    #[test]
+    fn input_json_schema_mirrors_validation_rules() {
+        let schema = input_json_schema(&extension());
+
+        assert_eq!(schema["required"], json!(["site"]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+        let properties = &schema["properties"];
+        assert_eq!(properties["site"]["description"], "Site URL to report on");
+        assert_eq!(properties["limit"]["type"], "number");
+        assert_eq!(properties["limit"]["minimum"], 1);
+        assert_eq!(properties["limit"]["maximum"], 50);
+        assert_eq!(properties["format"]["enum"], json!(["json", "csv"]));
+        assert_eq!(properties["verbose"]["type"], "boolean");
+    }
+}
@@ -0,0 +1,208 @@
+//! Dry-run previews for `extension install` and `extension uninstall`.
+//!
+//! Resolves what an install or uninstall would do — the extension ID, the
+//! target path, what it would overwrite or remove — without cloning, linking,
+//! or deleting anything.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::lifecycle::{
+    derive_id_from_url, extension_dependents, is_git_url, manifest_path_for_extension,
+    resolve_local_source, slugify_id, ExtensionDependents,
+};
+use super::manifest::ExtensionManifest;
+use crate::config::{self, from_str};
+use crate::engine::local_files::{self, FileSystem};
+use crate::error::{Error, Result};
+use crate::paths;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallPlan {
+    pub extension_id: String,
+    pub source: String,
+    /// Local sources are symlinked; git URLs are cloned.
+    pub linked: bool,
+    pub path: PathBuf,
+    /// An extension is already installed at `path`.
+    pub exists: bool,
+    /// `--replace`: overwrite the existing install.
+    pub replace: bool,
+    /// Manifest found at a local source. Git URLs are not cloned, so theirs
+    /// is only read on a real install.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manifest: Option<PlannedManifest>,
+    /// Why the real install would fail or need attention.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedManifest {
+    pub path: PathBuf,
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UninstallPlan {
+    pub extension_id: String,
+    pub path: PathBuf,
+    /// Linked installs only lose the symlink; `source_path` is kept.
+    pub linked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<PathBuf>,
+    /// Components and installed extensions that still need this extension.
+    pub required_by: ExtensionDependents,
+    /// The real uninstall would refuse (still required and no `--force`).
+    pub blocked: bool,
+}
+
+/// Preview `extension install <source>` without touching the filesystem.
+pub fn plan_install(source: &str, id_override: Option<&str>, replace: bool) -> Result<InstallPlan> {
+    let linked = !is_git_url(source);
+    let (extension_id, source, manifest) = if linked {
+        let (source_dir, extension_id) = resolve_local_source(source, id_override)?;
+        let manifest = read_manifest(&source_dir, &extension_id)?;
+        (
+            extension_id,
+            source_dir.to_string_lossy().to_string(),
+            Some(manifest),
+        )
+    } else {
+        let extension_id = match id_override {
+            Some(id) => slugify_id(id)?,
+            None => derive_id_from_url(source)?,
+        };
+        (extension_id, source.to_string(), None)
+    };
+
+    config::check_id_collision(&extension_id, "extension")?;
+
+    let path = paths::extension(&extension_id)?;
+    let exists = path.exists() || path.is_symlink();
+    let mut warnings = Vec::new();
+    if exists && !replace {
+        warnings.push(format!(
+            "Extension '{}' already exists at {}; install would fail. Pass --replace to overwrite it",
+            extension_id,
+            path.display()
+        ));
+    }
+    if !exists && replace {
+        warnings.push(format!(
+            "Extension '{}' is not installed; --replace would fail",
+            extension_id
+        ));
+    }
+
+    Ok(InstallPlan {
+        extension_id,
+        source,
+        linked,
+        path,
+        exists,
+        replace,
+        manifest,
+        warnings,
+    })
+}
+
+fn read_manifest(source_dir: &Path, extension_id: &str) -> Result<PlannedManifest> {
+    let path = manifest_path_for_extension(source_dir, extension_id);
+    if !path.exists() {
+        return Err(Error::validation_invalid_argument(
+            "source",
+            format!("No {}.json found at {}", extension_id, source_dir.display()),
+            Some(source_dir.to_string_lossy().to_string()),
+            None,
+        ));
+    }
+    let manifest: ExtensionManifest = from_str(&local_files::local().read(&path)?)?;
+    Ok(PlannedManifest {
+        path,
+        name: manifest.name,
+        version: manifest.version,
+    })
+}
+
+/// Preview `extension uninstall <id>` without removing anything.
+pub fn plan_uninstall(extension_id: &str, force: bool) -> Result<UninstallPlan> {
+    let path = paths::extension(extension_id)?;
+    if !path.exists() && !path.is_symlink() {
+        return Err(Error::extension_not_found(extension_id.to_string(), vec![]));
+    }
+
+    let linked = path.is_symlink();
+    let required_by = extension_dependents(extension_id);
+    Ok(UninstallPlan {
+        extension_id: extension_id.to_string(),
+        source_path: linked.then(|| std::fs::read_link(&path).ok()).flatten(),
+        path,
+        linked,
+        blocked: !force && !required_by.is_empty(),
+        required_by,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+
+    fn write_extension(dir: &Path, id: &str) -> PathBuf {
+        let source = dir.join(id);
+        std::fs::create_dir_all(&source).unwrap();
+        std::fs::write(
+            source.join(format!("{}.json", id)),
+            r#"{"name": "Sample", "version": "1.2.0"}"#,
+        )
+        .unwrap();
+        source
+    }
+
+    #[test]
+    fn test_plan_install_previews_local_source() {
+        with_isolated_home(|home| {
+            let source = write_extension(home.path(), "sample");
+
+            let plan = plan_install(&source.to_string_lossy(), None, false).unwrap();
+            assert_eq!(plan.extension_id, "sample");
+            assert!(plan.linked);
+            assert!(!plan.exists);
+            assert!(plan.warnings.is_empty());
+            let manifest = plan.manifest.unwrap();
+            assert_eq!(manifest.name, "Sample");
+            assert_eq!(manifest.version, "1.2.0");
+            assert!(!plan.path.exists(), "dry run must not link");
+        });
+    }
+
+    #[test]
+    fn test_plan_install_warns_about_existing_install() {
+        with_isolated_home(|home| {
+            let source = write_extension(home.path(), "sample");
+            let path = paths::extension("sample").unwrap();
+            std::fs::create_dir_all(&path).unwrap();
+
+            let plan = plan_install(&source.to_string_lossy(), None, false).unwrap();
+            assert!(plan.exists);
+            assert!(plan.warnings[0].contains("--replace"));
+
+            let plan = plan_install(&source.to_string_lossy(), None, true).unwrap();
+            assert!(plan.warnings.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_plan_install_derives_id_from_url() {
+        with_isolated_home(|_| {
+            let plan =
+                plan_install("https://github.com/acme/Homeboy-Sample.git", None, false).unwrap();
+            assert_eq!(plan.extension_id, "homeboy-sample");
+            assert!(!plan.linked);
+            assert!(plan.manifest.is_none());
+        });
+    }
+}
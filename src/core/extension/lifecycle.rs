@@ -11,7 +11,9 @@ use std::sync::{Mutex, OnceLock};
 
 use super::execution::run_setup;
 use super::manifest::ExtensionManifest;
-use super::{is_extension_linked, load_extension, ExtensionSourceUpdate};
+use super::{
+    invalidate_extension_cache, is_extension_linked, load_extension, ExtensionSourceUpdate,
+};
 
 #[derive(Debug, Clone)]
 pub struct InstallResult {
@@ -19,6 +21,8 @@ pub struct InstallResult {
     pub url: String,
     pub path: PathBuf,
     pub source_revision: Option<String>,
+    /// Requirement cycle this install closed, kept only with `force`.
+    pub requirement_cycle: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -161,28 +165,78 @@ pub fn is_git_url(source: &str) -> bool {
 }
 
 /// Returns the path to a extension's manifest file: {extension_dir}/{id}.json
-fn manifest_path_for_extension(extension_dir: &Path, id: &str) -> PathBuf {
+pub(super) fn manifest_path_for_extension(extension_dir: &Path, id: &str) -> PathBuf {
     extension_dir.join(format!("{}.json", id))
 }
 
 /// Install a extension from a git URL or link a local directory.
 /// Automatically detects whether source is a URL (git clone) or local path (symlink).
 pub fn install(source: &str, id_override: Option<&str>) -> Result<InstallResult> {
-    install_with_revision(source, id_override, None)
+    install_with_revision(source, id_override, None, false)
 }
 
 /// Install a extension from a git URL or link a local directory.
 /// Git URL installs optionally check out a branch, tag, or commit after cloning.
+///
+/// If the new extension's `requires.extensions` closes a cycle with already
+/// installed extensions, the install is rolled back with an error naming the
+/// cycle, unless `force` is set, in which case it is kept and the cycle is
+/// reported in `requirement_cycle`.
 pub fn install_with_revision(
     source: &str,
     id_override: Option<&str>,
     revision: Option<&str>,
+    force: bool,
 ) -> Result<InstallResult> {
-    if is_git_url(source) {
+    let result = if is_git_url(source) {
         install_from_url(source, id_override, revision)
     } else {
         install_from_path(source, id_override)
+    };
+    invalidate_extension_cache();
+    let mut result = result?;
+
+    if let Some(cycle) = requirement_cycle_introduced_by(&result.extension_id) {
+        let rendered = cycle.join(" -> ");
+        if !force {
+            uninstall(&result.extension_id)?;
+            return Err(Error::validation_invalid_argument(
+                "extension_id",
+                format!(
+                    "Installing '{}' would create a circular extension dependency: {}",
+                    result.extension_id, rendered
+                ),
+                Some(result.extension_id.clone()),
+                None,
+            )
+            .with_hint("Remove one of the requires.extensions entries in the cycle")
+            .with_hint("Pass --force to install anyway"));
+        }
+        log_status!(
+            "extension",
+            "Warning: '{}' creates a circular extension dependency: {}",
+            result.extension_id,
+            rendered
+        );
+        result.requirement_cycle = Some(cycle);
     }
+
+    Ok(result)
+}
+
+/// The requirement cycle that `extension_id` closes, if any. A cycle that
+/// already existed without it is not attributed to this install.
+fn requirement_cycle_introduced_by(extension_id: &str) -> Option<Vec<String>> {
+    let installed = super::load_all_extensions().ok()?;
+    let cycle = super::find_requirement_cycle(&installed)?;
+
+    let without: Vec<ExtensionManifest> = installed
+        .into_iter()
+        .filter(|extension| extension.id != extension_id)
+        .collect();
+    super::find_requirement_cycle(&without)
+        .is_none()
+        .then_some(cycle)
 }
 
 /// Install every extension declared by a component from the same source.
@@ -329,6 +383,7 @@ fn install_from_url(
         url: url.to_string(),
         path: extension_dir,
         source_revision,
+        requirement_cycle: None,
     })
 }
 
@@ -449,8 +504,12 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     )
 }
 
-/// Install a extension by symlinking a local directory.
-fn install_from_path(source_path: &str, id_override: Option<&str>) -> Result<InstallResult> {
+/// Resolve a local extension source to an absolute directory and the
+/// extension ID it installs as (override, else the directory name).
+pub(super) fn resolve_local_source(
+    source_path: &str,
+    id_override: Option<&str>,
+) -> Result<(PathBuf, String)> {
     let source = Path::new(source_path);
 
     // Resolve to absolute path
@@ -486,6 +545,13 @@ fn install_from_path(source_path: &str, id_override: Option<&str>) -> Result<Ins
         None => slugify_id(dir_name)?,
     };
 
+    Ok((source, extension_id))
+}
+
+/// Install a extension by symlinking a local directory.
+fn install_from_path(source_path: &str, id_override: Option<&str>) -> Result<InstallResult> {
+    let (source, extension_id) = resolve_local_source(source_path, id_override)?;
+
     // Check cross-entity name collision before checking extension-specific existence
     config::check_id_collision(&extension_id, "extension")?;
 
@@ -536,11 +602,18 @@ fn install_from_path(source_path: &str, id_override: Option<&str>) -> Result<Ins
         url: source.to_string_lossy().to_string(),
         path: extension_dir,
         source_revision,
+        requirement_cycle: None,
     })
 }
 
 /// Update an installed extension by pulling latest changes.
 pub fn update(extension_id: &str, force: bool) -> Result<UpdateResult> {
+    let result = update_extension(extension_id, force);
+    invalidate_extension_cache();
+    result
+}
+
+fn update_extension(extension_id: &str, force: bool) -> Result<UpdateResult> {
     let extension_dir = paths::extension(extension_id)?;
     if !extension_dir.exists() {
         return Err(Error::extension_not_found(extension_id.to_string(), vec![]));
@@ -790,9 +863,73 @@ pub fn uninstall(extension_id: &str) -> Result<PathBuf> {
         })?;
     }
 
+    invalidate_extension_cache();
     Ok(extension_dir)
 }
 
+/// What still needs an extension: components that list it in `extensions`
+/// and installed extensions that list it in `requires.extensions`.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ExtensionDependents {
+    pub components: Vec<String>,
+    pub extensions: Vec<String>,
+}
+
+impl ExtensionDependents {
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.extensions.is_empty()
+    }
+}
+
+pub fn extension_dependents(extension_id: &str) -> ExtensionDependents {
+    let mut extensions: Vec<String> = super::load_all_extensions()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|extension| {
+            extension.id != extension_id
+                && extension
+                    .requires
+                    .as_ref()
+                    .is_some_and(|requires| requires.extensions.iter().any(|id| id == extension_id))
+        })
+        .map(|extension| extension.id)
+        .collect();
+    extensions.sort();
+
+    ExtensionDependents {
+        components: crate::component::components_using_extension(extension_id).unwrap_or_default(),
+        extensions,
+    }
+}
+
+/// Uninstall unless something still needs the extension (see
+/// [`extension_dependents`]); `force` removes it anyway.
+pub fn uninstall_checked(extension_id: &str, force: bool) -> Result<PathBuf> {
+    let dependents = extension_dependents(extension_id);
+    if !force && !dependents.is_empty() {
+        let mut users = Vec::new();
+        if !dependents.components.is_empty() {
+            users.push(format!("component(s) {}", dependents.components.join(", ")));
+        }
+        if !dependents.extensions.is_empty() {
+            users.push(format!("extension(s) {}", dependents.extensions.join(", ")));
+        }
+        return Err(Error::validation_invalid_argument(
+            "extension_id",
+            format!(
+                "Extension '{}' is still required by {}",
+                extension_id,
+                users.join(" and ")
+            ),
+            Some(extension_id.to_string()),
+            None,
+        )
+        .with_hint("Remove it from those components' `extensions` first")
+        .with_hint("Pass --force to uninstall anyway"));
+    }
+    uninstall(extension_id)
+}
+
 /// Check if a git-cloned extension has updates available.
 /// Runs `git fetch` then checks if HEAD is behind the remote tracking branch.
 /// Returns None for linked extensions or if check fails.
@@ -839,15 +976,68 @@ pub fn check_update_available(extension_id: &str) -> Option<UpdateAvailable> {
     Some(UpdateAvailable {
         extension_id: extension_id.to_string(),
         installed_version,
+        latest_version: upstream_version(&extension_dir, extension_id),
         behind_count,
+        changelog_url: upstream_changelog_url(&extension_dir, extension_id),
     })
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct UpdateAvailable {
     pub extension_id: String,
     pub installed_version: String,
+    /// Manifest version on the upstream branch, when it can be read.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_version: Option<String>,
     pub behind_count: usize,
+    /// Upstream `CHANGELOG.md`, when the extension has one and an https origin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changelog_url: Option<String>,
+}
+
+/// Read `version` from the manifest on the upstream tracking branch.
+fn upstream_version(extension_dir: &Path, extension_id: &str) -> Option<String> {
+    let manifest = git_stdout(
+        extension_dir,
+        &["show", &format!("@{{u}}:{}.json", extension_id)],
+    )?;
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).ok()?;
+    manifest
+        .get("version")
+        .and_then(|version| version.as_str())
+        .map(str::to_string)
+}
+
+/// Link to the upstream `CHANGELOG.md` for extensions cloned over https.
+fn upstream_changelog_url(extension_dir: &Path, extension_id: &str) -> Option<String> {
+    git_stdout(extension_dir, &["cat-file", "-e", "@{u}:CHANGELOG.md"])?;
+    let upstream = git_stdout(extension_dir, &["rev-parse", "--abbrev-ref", "@{u}"])?;
+    let branch = upstream
+        .split_once('/')
+        .map_or(upstream.as_str(), |(_, b)| b);
+    let url = source_metadata::resolve_source_url(extension_id).ok()?.url;
+    if !url.starts_with("https://") {
+        return None;
+    }
+    Some(format!(
+        "{}/blob/{}/CHANGELOG.md",
+        url.trim_end_matches('/').trim_end_matches(".git"),
+        branch
+    ))
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
 /// Read the source revision for an installed extension.
@@ -874,11 +1064,11 @@ pub fn read_source_revision(extension_id: &str) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::{
-        install, install_for_component, install_with_revision, load_extension,
-        read_source_revision, source_metadata, update,
+        check_update_available, install, install_for_component, install_with_revision,
+        load_extension, read_source_revision, source_metadata, uninstall_checked, update,
     };
     use crate::component;
-    use crate::extension::update_all;
+    use crate::extension::{apply_updates, check_all_updates, update_all};
     use crate::test_support::with_isolated_home;
     use std::fs;
     use std::path::Path;
@@ -908,6 +1098,18 @@ mod tests {
         .expect("extension manifest");
     }
 
+    fn write_extension_fixture_requiring(root: &Path, id: &str, requires: &[&str]) {
+        let dir = root.join(id);
+        fs::create_dir_all(&dir).expect("extension dir");
+        let manifest = serde_json::json!({
+            "name": format!("{} extension", id),
+            "version": "1.0.0",
+            "requires": { "extensions": requires },
+        });
+        fs::write(dir.join(format!("{}.json", id)), manifest.to_string())
+            .expect("extension manifest");
+    }
+
     fn write_extension_fixture_with_setup(root: &Path, id: &str) {
         let dir = root.join(id);
         fs::create_dir_all(&dir).expect("extension dir");
@@ -1140,6 +1342,84 @@ exec '{}' "$@"
         });
     }
 
+    #[test]
+    fn uninstall_checked_refuses_extension_still_required() {
+        with_isolated_home(|home| {
+            let source = home.path().join("source");
+            write_extension_fixture(&source, "alpha");
+            write_extension_fixture_requiring(&source, "beta", &["alpha"]);
+            write_extension_fixture(&source, "gamma");
+            for id in ["alpha", "beta", "gamma"] {
+                install(&source.join(id).to_string_lossy(), Some(id)).expect("install");
+            }
+
+            let component_dir = home.path().join("component");
+            fs::create_dir_all(&component_dir).expect("component dir");
+            write_component_fixture(&component_dir, &["gamma"]);
+            let registry = crate::paths::components().expect("components dir");
+            fs::create_dir_all(&registry).expect("registry dir");
+            fs::write(
+                registry.join("multi-extension-component.json"),
+                serde_json::json!({ "local_path": component_dir }).to_string(),
+            )
+            .expect("component registration");
+
+            let err = uninstall_checked("alpha", false).expect_err("beta requires alpha");
+            assert!(err.message.contains("required by extension(s) beta"));
+            let err = uninstall_checked("gamma", false).expect_err("component uses gamma");
+            assert!(err
+                .message
+                .contains("required by component(s) multi-extension-component"));
+            assert!(
+                load_extension("gamma").is_ok(),
+                "refused uninstall keeps it"
+            );
+
+            uninstall_checked("gamma", true).expect("--force removes it");
+            assert!(load_extension("gamma").is_err());
+        });
+    }
+
+    #[test]
+    fn install_refuses_extension_that_closes_requirement_cycle() {
+        with_isolated_home(|home| {
+            let source = home.path().join("source");
+            write_extension_fixture_requiring(&source, "alpha", &["beta"]);
+            write_extension_fixture_requiring(&source, "beta", &["gamma"]);
+            write_extension_fixture_requiring(&source, "gamma", &["alpha"]);
+
+            install(&source.join("alpha").to_string_lossy(), Some("alpha")).expect("alpha");
+            install(&source.join("beta").to_string_lossy(), Some("beta")).expect("beta");
+
+            let err = install(&source.join("gamma").to_string_lossy(), Some("gamma"))
+                .expect_err("cycle should be refused");
+            assert!(err
+                .message
+                .contains("circular extension dependency: alpha -> beta -> gamma -> alpha"));
+            assert!(
+                load_extension("gamma").is_err(),
+                "refused install is rolled back"
+            );
+
+            let result = install_with_revision(
+                &source.join("gamma").to_string_lossy(),
+                Some("gamma"),
+                None,
+                true,
+            )
+            .expect("--force keeps the install");
+            assert_eq!(
+                result.requirement_cycle,
+                Some(vec![
+                    "alpha".to_string(),
+                    "beta".to_string(),
+                    "gamma".to_string(),
+                    "alpha".to_string()
+                ])
+            );
+        });
+    }
+
     #[test]
     fn install_without_replace_remains_non_destructive() {
         with_isolated_home(|home| {
@@ -1243,6 +1523,7 @@ exec '{}' "$@"
                 &remote_url.to_string_lossy(),
                 Some("wordpress"),
                 Some(&pinned_revision),
+                false,
             )
             .expect("install pinned revision");
 
@@ -1283,6 +1564,7 @@ exec '{}' "$@"
                 &remote_url.to_string_lossy(),
                 Some("wordpress"),
                 Some("next-extension"),
+                false,
             )
             .expect("install branch revision");
 
@@ -1613,6 +1895,42 @@ exec '{}' "$@"
         });
     }
 
+    #[test]
+    fn test_check_update_available() {
+        with_isolated_home(|home| {
+            let source = home.path().join("source");
+            write_extension_fixture(&source, "solo");
+            let repo = source.join("solo");
+            fs::write(repo.join(".gitignore"), ".source-*\n").expect("gitignore");
+            let remote = match prepare_git_repo(&repo) {
+                Some(remote) => remote,
+                None => return,
+            };
+            let remote_url = remote.path().join("extension.git");
+            install(&remote_url.to_string_lossy(), Some("solo")).expect("install solo");
+            assert!(check_update_available("solo").is_none());
+
+            write_extension_fixture_with_version(&source, "solo", "1.1.0");
+            fs::write(repo.join("CHANGELOG.md"), "## 1.1.0\n").expect("changelog");
+            assert!(commit_all(&repo, "release 1.1.0"));
+            assert!(run_git(&repo, &["push", "--quiet", "origin", "HEAD"]));
+
+            let available = check_update_available("solo").expect("update available");
+            assert_eq!(available.installed_version, "1.0.0");
+            assert_eq!(available.latest_version.as_deref(), Some("1.1.0"));
+            assert_eq!(available.behind_count, 1);
+            // Local origins have no browsable changelog link.
+            assert!(available.changelog_url.is_none());
+
+            let check = check_all_updates();
+            assert_eq!(check.outdated.len(), 1);
+            let applied = apply_updates(check, false, false);
+            assert!(applied.failed.is_empty());
+            assert_eq!(applied.updated[0].new_version, "1.1.0");
+            assert!(check_update_available("solo").is_none());
+        });
+    }
+
     #[test]
     fn update_all_reports_actionable_missing_source_metadata_skip() {
         with_isolated_home(|home| {
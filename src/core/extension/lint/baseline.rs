@@ -28,10 +28,38 @@ pub struct LintFinding {
     pub file: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_position",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line: Option<usize>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_position",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub column: Option<usize>,
+    /// Linter rule/sniff code, e.g. `WordPress.Security.EscapeOutput`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rule: Option<String>,
     #[serde(flatten, default, skip_serializing_if = "BTreeMap::is_empty")]
     pub extra: BTreeMap<String, Value>,
 }
 
+/// Accept line/column as a number or numeric string; anything else is `None`
+/// so one odd sidecar entry can't fail the whole findings file.
+fn deserialize_position<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Number(n) => n.as_u64().map(|n| n as usize),
+        Value::String(s) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LintBaselineMetadata {
     pub findings_count: usize,
@@ -226,4 +254,26 @@ mod tests {
         assert_eq!(findings.len(), 1);
         assert_eq!(findings[0].file.as_deref(), Some("src/lib.rs"));
     }
+
+    #[test]
+    fn parse_findings_file_reads_position_and_rule() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("lint-findings.json");
+        std::fs::write(
+            &path,
+            r#"[
+                {"id":"a","message":"m","category":"c","line":12,"column":"4","rule":"no-eval"},
+                {"id":"b","message":"m","category":"c","line":"n/a"}
+            ]"#,
+        )
+        .expect("findings file written");
+
+        let findings = parse_findings_file(&path).expect("findings parsed");
+
+        assert_eq!(findings[0].line, Some(12));
+        assert_eq!(findings[0].column, Some(4));
+        assert_eq!(findings[0].rule.as_deref(), Some("no-eval"));
+        assert_eq!(findings[1].line, None);
+        assert!(findings[1].extra.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 pub mod baseline;
+pub mod output;
 pub mod report;
 pub mod run;
 
@@ -6,6 +7,7 @@ use crate::component::Component;
 use crate::extension::{ExtensionCapability, ExtensionExecutionContext, ExtensionRunner};
 
 pub use baseline::{BaselineComparison, LintBaseline, LintBaselineMetadata, LintFinding};
+pub use output::{parse_lint_output, LintSeverity};
 pub use report::LintCommandOutput;
 pub use run::{
     run_main_lint_workflow, run_self_check_lint_workflow, LintRunWorkflowArgs,
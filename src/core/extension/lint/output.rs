@@ -0,0 +1,373 @@
+//! Normalize raw linter output into [`LintFinding`]s.
+//!
+//! Extensions that can't write a `lint-findings.json` sidecar declare their
+//! linter's stdout format on `LintConfig.output`; this module turns JSON,
+//! checkstyle XML, or regex-captured lines into findings with a normalized
+//! severity so the workflow can gate on errors only.
+
+use regex::Regex;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::error::{Error, Result};
+use crate::extension::lint::baseline::LintFinding;
+use crate::extension::{LintOutputConfig, LintOutputFormat};
+
+const DEFAULT_TOOL: &str = "lint";
+
+/// Normalized lint severity. Ordered so `>=` means "at least as severe".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl LintSeverity {
+    /// Map a linter's severity label onto error/warning/info. Unknown labels
+    /// count as errors so an unfamiliar linter never silently passes CI.
+    pub fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "warning" | "warn" | "minor" | "1" => Self::Warning,
+            "info" | "information" | "notice" | "note" | "hint" | "suggestion" | "ignore" | "0" => {
+                Self::Info
+            }
+            _ => Self::Error,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl LintFinding {
+    /// Normalized severity. Findings without one are treated as errors,
+    /// matching the pre-severity behavior where every finding failed the run.
+    pub fn severity_level(&self) -> LintSeverity {
+        self.severity
+            .as_deref()
+            .map(LintSeverity::from_label)
+            .unwrap_or(LintSeverity::Error)
+    }
+}
+
+/// Parse linter stdout according to the extension's declared output format.
+///
+/// Non-empty output the format does not recognize is an error rather than
+/// an empty finding list, so a misconfigured parser can't turn a failing
+/// linter into a pass.
+pub fn parse_lint_output(config: &LintOutputConfig, text: &str) -> Result<Vec<LintFinding>> {
+    let tool = config.tool.as_deref().unwrap_or(DEFAULT_TOOL);
+    let findings = match config.format {
+        LintOutputFormat::Json => parse_json(text, tool),
+        LintOutputFormat::Checkstyle => parse_checkstyle(text, tool),
+        LintOutputFormat::Regex => {
+            config.validate()?;
+            let pattern = config.pattern.as_deref().unwrap_or_default();
+            let re = Regex::new(pattern).expect("validated lint output pattern");
+            let findings = parse_regex(text, &re, tool);
+            (!findings.is_empty()).then_some(findings)
+        }
+    };
+
+    match findings {
+        Some(findings) => Ok(findings),
+        None if text.trim().is_empty() => Ok(Vec::new()),
+        None => Err(Error::validation_invalid_argument(
+            "lint.output",
+            format!(
+                "Linter output did not match the declared {} format",
+                format_name(config.format)
+            ),
+            None,
+            None,
+        )),
+    }
+}
+
+fn format_name(format: LintOutputFormat) -> &'static str {
+    match format {
+        LintOutputFormat::Json => "json",
+        LintOutputFormat::Checkstyle => "checkstyle",
+        LintOutputFormat::Regex => "regex",
+    }
+}
+
+#[derive(Default)]
+struct RawFinding {
+    file: Option<String>,
+    line: Option<usize>,
+    column: Option<usize>,
+    severity: Option<String>,
+    rule: Option<String>,
+    message: String,
+}
+
+impl RawFinding {
+    fn into_finding(self, tool: &str) -> LintFinding {
+        let key = self.rule.as_deref().unwrap_or(&self.message);
+        let id = match &self.file {
+            Some(file) => format!("{}::{}", file, key),
+            None => key.to_string(),
+        };
+
+        LintFinding {
+            id,
+            message: self.message,
+            category: tool.to_string(),
+            tool: Some(tool.to_string()),
+            file: self.file,
+            severity: Some(
+                self.severity
+                    .as_deref()
+                    .map(LintSeverity::from_label)
+                    .unwrap_or(LintSeverity::Error)
+                    .as_str()
+                    .to_string(),
+            ),
+            line: self.line,
+            column: self.column,
+            rule: self.rule,
+            ..LintFinding::default()
+        }
+    }
+}
+
+/// `None` when `text` is not a JSON array.
+fn parse_json(text: &str, tool: &str) -> Option<Vec<LintFinding>> {
+    let Ok(Value::Array(items)) = serde_json::from_str::<Value>(text.trim()) else {
+        return None;
+    };
+
+    let mut findings = Vec::new();
+    for item in items.iter().filter_map(Value::as_object) {
+        // ESLint-style: one entry per file with a nested `messages` array.
+        if let Some(messages) = item.get("messages").and_then(Value::as_array) {
+            let file = json_str(item, &["filePath", "file", "path"]);
+            for message in messages.iter().filter_map(Value::as_object) {
+                let mut raw = json_raw_finding(message);
+                raw.file = raw.file.or_else(|| file.clone());
+                findings.push(raw.into_finding(tool));
+            }
+        } else {
+            findings.push(json_raw_finding(item).into_finding(tool));
+        }
+    }
+    Some(findings)
+}
+
+fn json_raw_finding(item: &Map<String, Value>) -> RawFinding {
+    RawFinding {
+        file: json_str(item, &["file", "filePath", "path", "filename"]),
+        line: json_position(item, &["line", "startLine"]),
+        column: json_position(item, &["column", "col", "startColumn"]),
+        severity: json_str(item, &["severity", "level", "type"]),
+        rule: json_str(item, &["rule", "ruleId", "code", "source", "check_name"]),
+        message: json_str(item, &["message", "description", "text"]).unwrap_or_default(),
+    }
+}
+
+fn json_str(item: &Map<String, Value>, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match item.get(*key)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    })
+}
+
+fn json_position(item: &Map<String, Value>, keys: &[&str]) -> Option<usize> {
+    json_str(item, keys).and_then(|value| value.parse().ok())
+}
+
+/// `None` when `text` has no `<checkstyle>` root.
+fn parse_checkstyle(text: &str, tool: &str) -> Option<Vec<LintFinding>> {
+    if !text.contains("<checkstyle") {
+        return None;
+    }
+    let file_re = Regex::new(r#"(?s)<file\b([^>]*?)(?:/>|>(.*?)</file>)"#).expect("valid regex");
+    let error_re = Regex::new(r#"<error\b([^>]*?)/?>"#).expect("valid regex");
+    let attr_re = Regex::new(r#"([\w-]+)\s*=\s*"([^"]*)""#).expect("valid regex");
+
+    let attrs = |raw: &str| -> Map<String, Value> {
+        attr_re
+            .captures_iter(raw)
+            .map(|caps| (caps[1].to_string(), Value::String(unescape_xml(&caps[2]))))
+            .collect()
+    };
+
+    let mut findings = Vec::new();
+    for file_caps in file_re.captures_iter(text) {
+        let file = json_str(&attrs(&file_caps[1]), &["name"]);
+        let Some(body) = file_caps.get(2) else {
+            continue;
+        };
+        for error_caps in error_re.captures_iter(body.as_str()) {
+            let error = attrs(&error_caps[1]);
+            let raw = RawFinding {
+                file: file.clone(),
+                line: json_position(&error, &["line"]),
+                column: json_position(&error, &["column"]),
+                severity: json_str(&error, &["severity"]),
+                rule: json_str(&error, &["source"]),
+                message: json_str(&error, &["message"]).unwrap_or_default(),
+            };
+            findings.push(raw.into_finding(tool));
+        }
+    }
+    Some(findings)
+}
+
+fn unescape_xml(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn parse_regex(text: &str, re: &Regex, tool: &str) -> Vec<LintFinding> {
+    text.lines()
+        .filter_map(|line| re.captures(line))
+        .map(|caps| {
+            let group = |name: &str| {
+                caps.name(name)
+                    .map(|m| m.as_str().trim().to_string())
+                    .filter(|value| !value.is_empty())
+            };
+            RawFinding {
+                file: group("file"),
+                line: group("line").and_then(|value| value.parse().ok()),
+                column: group("column").and_then(|value| value.parse().ok()),
+                severity: group("severity"),
+                rule: group("rule"),
+                message: group("message").unwrap_or_default(),
+            }
+            .into_finding(tool)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(format: LintOutputFormat, pattern: Option<&str>) -> LintOutputConfig {
+        LintOutputConfig {
+            format,
+            pattern: pattern.map(str::to_string),
+            tool: Some("fixture-lint".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_from_label() {
+        assert_eq!(LintSeverity::from_label("ERROR"), LintSeverity::Error);
+        assert_eq!(LintSeverity::from_label("warn"), LintSeverity::Warning);
+        assert_eq!(LintSeverity::from_label("2"), LintSeverity::Error);
+        assert_eq!(LintSeverity::from_label("notice"), LintSeverity::Info);
+        assert_eq!(LintSeverity::from_label("critical"), LintSeverity::Error);
+    }
+
+    #[test]
+    fn parse_lint_output_reads_eslint_json() {
+        let text = r#"[{"filePath":"src/app.js","messages":[
+            {"ruleId":"no-eval","severity":2,"message":"eval is evil","line":3,"column":5},
+            {"ruleId":"semi","severity":1,"message":"Missing semicolon","line":9,"column":2}
+        ]}]"#;
+
+        let findings = parse_lint_output(&config(LintOutputFormat::Json, None), text).unwrap();
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].id, "src/app.js::no-eval");
+        assert_eq!(findings[0].file.as_deref(), Some("src/app.js"));
+        assert_eq!(findings[0].line, Some(3));
+        assert_eq!(findings[0].column, Some(5));
+        assert_eq!(findings[0].severity.as_deref(), Some("error"));
+        assert_eq!(findings[1].severity_level(), LintSeverity::Warning);
+        assert_eq!(findings[1].tool.as_deref(), Some("fixture-lint"));
+    }
+
+    #[test]
+    fn parse_lint_output_reads_checkstyle() {
+        let text = r#"<?xml version="1.0"?>
+<checkstyle>
+  <file name="inc/a.php">
+    <error line="4" column="1" severity="warning" message="Use &quot;yoda&quot; conditions" source="WordPress.PHP.YodaConditions"/>
+  </file>
+  <file name="inc/b.php"/>
+</checkstyle>"#;
+
+        let findings =
+            parse_lint_output(&config(LintOutputFormat::Checkstyle, None), text).unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].file.as_deref(), Some("inc/a.php"));
+        assert_eq!(findings[0].line, Some(4));
+        assert_eq!(findings[0].message, "Use \"yoda\" conditions");
+        assert_eq!(
+            findings[0].rule.as_deref(),
+            Some("WordPress.PHP.YodaConditions")
+        );
+        assert_eq!(findings[0].severity_level(), LintSeverity::Warning);
+    }
+
+    #[test]
+    fn parse_lint_output_reads_regex_captures() {
+        let pattern = r"^(?P<file>[^:]+):(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.+?) \[(?P<rule>[^\]]+)\]$";
+        let text = "src/lib.rs:10:4: warning: unused variable [unused]\nnoise\n";
+
+        let findings =
+            parse_lint_output(&config(LintOutputFormat::Regex, Some(pattern)), text).unwrap();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].id, "src/lib.rs::unused");
+        assert_eq!(findings[0].column, Some(4));
+        assert_eq!(findings[0].message, "unused variable");
+        assert_eq!(findings[0].severity.as_deref(), Some("warning"));
+    }
+
+    #[test]
+    fn parse_lint_output_rejects_unrecognized_output() {
+        let text = "Found 3 problems\n";
+
+        assert!(parse_lint_output(&config(LintOutputFormat::Json, None), text).is_err());
+        assert!(parse_lint_output(&config(LintOutputFormat::Checkstyle, None), text).is_err());
+        assert!(parse_lint_output(
+            &config(LintOutputFormat::Regex, Some(r"^(?P<file>\S+):")),
+            text
+        )
+        .is_err());
+        assert!(parse_lint_output(&config(LintOutputFormat::Regex, Some("(")), "a:1").is_err());
+
+        let clean = parse_lint_output(&config(LintOutputFormat::Json, None), "[]").unwrap();
+        assert!(clean.is_empty());
+        let silent = parse_lint_output(&config(LintOutputFormat::Regex, Some("x")), "").unwrap();
+        assert!(silent.is_empty());
+    }
+
+    #[test]
+    fn lint_output_config_validates_regex_pattern() {
+        assert!(config(LintOutputFormat::Regex, Some("("))
+            .validate()
+            .is_err());
+        assert!(config(LintOutputFormat::Regex, None).validate().is_err());
+        assert!(config(LintOutputFormat::Regex, Some(r"(?P<file>\S+)"))
+            .validate()
+            .is_ok());
+        assert!(config(LintOutputFormat::Json, None).validate().is_ok());
+    }
+
+    #[test]
+    fn severity_level_defaults_to_error() {
+        let finding = LintFinding::default();
+
+        assert_eq!(finding.severity_level(), LintSeverity::Error);
+    }
+}
@@ -3,6 +3,7 @@
 //! Mirrors `core/extension/test/report.rs` — the command layer calls a single
 //! builder function to convert a workflow result into the command output tuple.
 
+use crate::engine::junit::{JunitCase, JunitOutcome, JunitSuite};
 use crate::extension::lint::baseline::{BaselineComparison, LintFinding};
 use crate::extension::{
     phase_failure_category_from_exit_code, phase_status_from_exit_code, PhaseFailure,
@@ -166,6 +167,86 @@ pub fn from_lint_fix(component_label: String, run: RefactorSourceRun) -> (LintCo
     )
 }
 
+/// Map a lint run onto a JUnit suite for `--format junit`: one failing case
+/// per lint rule with its findings listed, or a single case for the
+/// component when there are no findings. `time` is the wall-clock duration
+/// of the run.
+pub fn junit_suite(output: &LintCommandOutput, time: Option<f64>) -> JunitSuite {
+    let mut rules: Vec<(String, Vec<&LintFinding>)> = Vec::new();
+    for finding in output.lint_findings.iter().flatten() {
+        let rule = finding
+            .rule
+            .clone()
+            .unwrap_or_else(|| finding.category.clone());
+        match rules.iter_mut().find(|(name, _)| *name == rule) {
+            Some((_, findings)) => findings.push(finding),
+            None => rules.push((rule, vec![finding])),
+        }
+    }
+
+    let mut cases: Vec<JunitCase> = rules
+        .into_iter()
+        .map(|(rule, findings)| JunitCase {
+            classname: output.component.clone(),
+            name: rule,
+            time: None,
+            outcome: JunitOutcome::Failed {
+                message: format!("{} finding(s)", findings.len()),
+                detail: findings
+                    .iter()
+                    .map(|finding| finding_line(finding))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            },
+        })
+        .collect();
+
+    if cases.is_empty() {
+        let outcome = match &output.failure {
+            None => JunitOutcome::Passed,
+            Some(failure) => {
+                let message = failure.summary.clone();
+                let detail = output.phase.summary.clone();
+                if failure.category == PhaseFailureCategory::Infrastructure {
+                    JunitOutcome::Errored { message, detail }
+                } else {
+                    JunitOutcome::Failed { message, detail }
+                }
+            }
+        };
+        cases.push(JunitCase {
+            classname: output.component.clone(),
+            name: "lint".to_string(),
+            time: None,
+            outcome,
+        });
+    }
+
+    JunitSuite {
+        name: output.component.clone(),
+        time,
+        cases,
+    }
+}
+
+/// `file:line:column: message`, with whichever location parts are known.
+fn finding_line(finding: &LintFinding) -> String {
+    let location = [
+        finding.file.clone(),
+        finding.line.map(|line| line.to_string()),
+        finding.column.map(|column| column.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(":");
+    if location.is_empty() {
+        finding.message.clone()
+    } else {
+        format!("{}: {}", location, finding.message)
+    }
+}
+
 fn lint_phase_failure(exit_code: i32, finding_count: usize) -> PhaseFailure {
     let category = phase_failure_category_from_exit_code(exit_code);
     PhaseFailure {
@@ -185,3 +266,50 @@ fn lint_phase_failure(exit_code: i32, finding_count: usize) -> PhaseFailure {
         category,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(rule: &str, line: usize) -> LintFinding {
+        LintFinding {
+            id: format!("{}-{}", rule, line),
+            message: "Bad thing".to_string(),
+            category: "style".to_string(),
+            file: Some("src/lib.rs".to_string()),
+            line: Some(line),
+            rule: Some(rule.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_junit_suite() {
+        let (output, _) = from_main_workflow(LintRunWorkflowResult {
+            status: "failed".to_string(),
+            component: "widget".to_string(),
+            exit_code: 1,
+            autofix: None,
+            hints: None,
+            baseline_comparison: None,
+            lint_findings: Some(vec![
+                finding("unused", 3),
+                finding("naming", 7),
+                finding("unused", 9),
+            ]),
+            summary: None,
+        });
+
+        let suite = junit_suite(&output, Some(0.5));
+
+        let names: Vec<&str> = suite.cases.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["unused", "naming"]);
+        assert_eq!(
+            suite.cases[0].outcome,
+            JunitOutcome::Failed {
+                message: "2 finding(s)".to_string(),
+                detail: "src/lib.rs:3: Bad thing\nsrc/lib.rs:9: Bad thing".to_string(),
+            }
+        );
+    }
+}
@@ -9,9 +9,11 @@ use crate::engine::baseline::BaselineFlags;
 use crate::engine::run_dir::{self, RunDir};
 use crate::engine::shell;
 use crate::extension::lint::baseline::{self as lint_baseline, LintFinding};
-use crate::extension::lint::build_lint_runner;
+use crate::extension::lint::output::{parse_lint_output, LintSeverity};
+use crate::extension::lint::{build_lint_runner, resolve_lint_command};
 use crate::extension::{self, ExtensionCapability, LintChangedFileRoute};
 use crate::git;
+use crate::output::{ExitPolicy, IssueCounts};
 use crate::refactor::AppliedRefactor;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -35,6 +37,11 @@ pub struct LintRunWorkflowArgs {
     pub category: Option<String>,
     pub baseline_flags: BaselineFlags,
     pub json_summary: bool,
+    /// Fail on warnings as well as errors. Info findings never fail the run.
+    pub fail_on_warnings: bool,
+    /// `--fail-on`: derive the exit code from finding severities. Overrides
+    /// `fail_on_warnings` and baseline gating.
+    pub exit_policy: Option<ExitPolicy>,
 }
 
 /// Result of the main lint workflow — ready for report assembly.
@@ -122,20 +129,43 @@ pub fn run_main_lint_workflow(
     };
 
     let lint_findings_file = run_dir.step_file(run_dir::files::LINT_FINDINGS);
-    let raw_lint_findings = lint_baseline::parse_findings_file(&lint_findings_file)?;
+    let mut raw_lint_findings = lint_baseline::parse_findings_file(&lint_findings_file)?;
+    let mut parse_error = None;
+    if raw_lint_findings.is_empty() {
+        if let Some(config) = lint_output_config(component)? {
+            match parse_lint_output(&config, &output.stdout) {
+                Ok(findings) => raw_lint_findings = findings,
+                Err(err) => parse_error = Some(err.message),
+            }
+        }
+    }
     let lint_findings = filter_lint_findings(raw_lint_findings, &args);
+    let blocking_findings = count_blocking_findings(&lint_findings, args.fail_on_warnings);
 
     let mut hints = Vec::new();
 
-    let runner_exit_code =
-        normalize_empty_finding_exit_code(output.exit_code, output.success, &lint_findings);
-    let lint_exit_code = normalize_finding_exit_code(runner_exit_code, &lint_findings);
+    // Output that couldn't be parsed says nothing about findings, so the
+    // runner's own exit code stands.
+    let runner_exit_code = match &parse_error {
+        Some(message) => {
+            hints.push(format!("Could not parse lint output: {}", message));
+            output.exit_code
+        }
+        None => {
+            normalize_empty_finding_exit_code(output.exit_code, output.success, blocking_findings)
+        }
+    };
+    let lint_exit_code = normalize_finding_exit_code(runner_exit_code, blocking_findings);
 
     // Baseline lifecycle
     let (baseline_comparison, baseline_exit_override) =
         process_baseline(source_path, &args, &lint_findings)?;
 
     let exit_code = effective_lint_exit_code(lint_exit_code, baseline_exit_override);
+    let exit_code = match args.exit_policy {
+        Some(policy) => policy.apply(exit_code, lint_issue_counts(&lint_findings)),
+        None => exit_code,
+    };
     let status = if exit_code == 0 { "passed" } else { "failed" }.to_string();
     let lint_clean = lint_findings.is_empty() && exit_code == 0;
 
@@ -240,23 +270,60 @@ fn finding_matches_sniff(finding: &LintFinding, sniff: &str) -> bool {
 }
 
 fn finding_rule(finding: &LintFinding) -> Option<&str> {
-    finding.extra.get("rule").and_then(|value| value.as_str())
+    finding.rule.as_deref()
+}
+
+/// Stdout format declared by the component's lint extension, if any. A
+/// manifest that fails to load (e.g. an invalid `pattern`) is an error.
+fn lint_output_config(component: &Component) -> crate::Result<Option<extension::LintOutputConfig>> {
+    let Ok(context) = resolve_lint_command(component) else {
+        return Ok(None);
+    };
+    Ok(extension::load_extension(&context.extension_id)?
+        .lint
+        .and_then(|lint| lint.output))
+}
+
+/// Findings severe enough to fail the run: errors, plus warnings when
+/// `fail_on_warnings` is set.
+fn count_blocking_findings(lint_findings: &[LintFinding], fail_on_warnings: bool) -> usize {
+    let threshold = if fail_on_warnings {
+        LintSeverity::Warning
+    } else {
+        LintSeverity::Error
+    };
+    lint_findings
+        .iter()
+        .filter(|finding| finding.severity_level() >= threshold)
+        .count()
+}
+
+fn lint_issue_counts(lint_findings: &[LintFinding]) -> IssueCounts {
+    let mut counts = IssueCounts::default();
+    for finding in lint_findings {
+        match finding.severity_level() {
+            LintSeverity::Error => counts.errors += 1,
+            LintSeverity::Warning => counts.warnings += 1,
+            LintSeverity::Info => counts.info += 1,
+        }
+    }
+    counts
 }
 
 fn normalize_empty_finding_exit_code(
     exit_code: i32,
     success: bool,
-    lint_findings: &[LintFinding],
+    blocking_findings: usize,
 ) -> i32 {
-    if lint_findings.is_empty() && !success && exit_code == 1 {
+    if blocking_findings == 0 && !success && exit_code == 1 {
         0
     } else {
         exit_code
     }
 }
 
-fn normalize_finding_exit_code(exit_code: i32, lint_findings: &[LintFinding]) -> i32 {
-    if !lint_findings.is_empty() && exit_code == 0 {
+fn normalize_finding_exit_code(exit_code: i32, blocking_findings: usize) -> i32 {
+    if blocking_findings > 0 && exit_code == 0 {
         1
     } else {
         exit_code
@@ -364,6 +431,8 @@ fn run_scoped_lint_runs(
 ) -> crate::Result<extension::RunnerOutput> {
     let mut success = true;
     let mut exit_code = 0;
+    let mut stdout = String::new();
+    let mut stderr = String::new();
 
     for (index, run) in runs.iter().enumerate() {
         let scoped_run_dir;
@@ -390,6 +459,8 @@ fn run_scoped_lint_runs(
         )?
         .passthrough(!args.json_summary)
         .run()?;
+        stdout.push_str(&output.stdout);
+        stderr.push_str(&output.stderr);
 
         if !output.success {
             success = false;
@@ -402,8 +473,8 @@ fn run_scoped_lint_runs(
     Ok(extension::RunnerOutput {
         exit_code,
         success,
-        stdout: String::new(),
-        stderr: String::new(),
+        stdout,
+        stderr,
     })
 }
 
@@ -661,6 +732,8 @@ mod tests {
             category: None,
             baseline_flags: BaselineFlags::default(),
             json_summary: false,
+            fail_on_warnings: false,
+            exit_policy: None,
         }
     }
 
@@ -823,25 +896,50 @@ mod tests {
 
     #[test]
     fn empty_filtered_findings_turn_lint_finding_exit_into_pass() {
-        let exit_code = normalize_empty_finding_exit_code(1, false, &[]);
+        let exit_code = normalize_empty_finding_exit_code(1, false, 0);
 
         assert_eq!(exit_code, 0);
     }
 
     #[test]
     fn empty_filtered_findings_do_not_hide_infrastructure_errors() {
-        let exit_code = normalize_empty_finding_exit_code(2, false, &[]);
+        let exit_code = normalize_empty_finding_exit_code(2, false, 0);
 
         assert_eq!(exit_code, 2);
     }
 
     #[test]
     fn findings_force_failure_when_runner_exits_cleanly() {
-        let exit_code = normalize_finding_exit_code(0, &[lint_finding("a", "security", "rule")]);
+        let blocking = count_blocking_findings(&[lint_finding("a", "security", "rule")], false);
+        let exit_code = normalize_finding_exit_code(0, blocking);
 
         assert_eq!(exit_code, 1);
     }
 
+    #[test]
+    fn test_count_blocking_findings() {
+        let with_severity = |severity: &str| LintFinding {
+            severity: Some(severity.to_string()),
+            ..lint_finding("a", "style", "rule")
+        };
+        let findings = vec![
+            with_severity("warning"),
+            with_severity("info"),
+            with_severity("warning"),
+        ];
+
+        assert_eq!(count_blocking_findings(&findings, false), 0);
+        assert_eq!(count_blocking_findings(&findings, true), 2);
+        assert_eq!(
+            normalize_empty_finding_exit_code(1, false, count_blocking_findings(&findings, false)),
+            0
+        );
+
+        let mut findings = findings;
+        findings.push(with_severity("error"));
+        assert_eq!(count_blocking_findings(&findings, false), 1);
+    }
+
     #[test]
     fn baseline_clean_override_honors_known_findings_but_not_infrastructure_errors() {
         assert_eq!(effective_lint_exit_code(1, Some(0)), 0);
@@ -980,9 +1078,7 @@ mod tests {
             id: id.to_string(),
             message: "message".to_string(),
             category: category.to_string(),
-            extra: [("rule".to_string(), serde_json::json!(rule))]
-                .into_iter()
-                .collect(),
+            rule: Some(rule.to_string()),
             ..LintFinding::default()
         }
     }
@@ -303,6 +303,26 @@ pub struct ScriptsConfig {
     /// Used by the test generator, doc generator, and refactor safety checker.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub contract: Option<String>,
+    /// Per-script timeout overrides in seconds, keyed by script name
+    /// (`fingerprint`, `refactor`, `topology`). Scripts without an entry use
+    /// [`DEFAULT_SCRIPT_TIMEOUT_SECS`]; a script that runs past its timeout is
+    /// killed along with its process group.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub timeout_secs: HashMap<String, u64>,
+}
+
+/// Default timeout for JSON stdin/stdout extension scripts. These run once
+/// per file, so anything slower than this is treated as hung.
+pub const DEFAULT_SCRIPT_TIMEOUT_SECS: u64 = 10;
+
+impl ScriptsConfig {
+    /// Effective timeout in seconds for `script` (10 when unset).
+    pub fn effective_timeout_secs(&self, script: &str) -> u64 {
+        self.timeout_secs
+            .get(script)
+            .copied()
+            .unwrap_or(DEFAULT_SCRIPT_TIMEOUT_SECS)
+    }
 }
 
 /// Unified extension manifest decomposed into capability groups.
@@ -712,6 +732,14 @@ impl ExtensionManifest {
         self.scripts.as_ref().and_then(|s| s.refactor.as_deref())
     }
 
+    /// Effective timeout in seconds for the named script.
+    pub fn script_timeout_secs(&self, script: &str) -> u64 {
+        self.scripts
+            .as_ref()
+            .map(|s| s.effective_timeout_secs(script))
+            .unwrap_or(DEFAULT_SCRIPT_TIMEOUT_SECS)
+    }
+
     /// Get the topology script path (relative to extension dir), if configured.
     pub fn topology_script(&self) -> Option<&str> {
         self.scripts.as_ref().and_then(|s| s.topology.as_deref())
@@ -747,6 +775,13 @@ impl ConfigEntity for ExtensionManifest {
         Error::extension_not_found(id, suggestions)
     }
 
+    fn validate(&self) -> Result<()> {
+        match self.lint.as_ref().and_then(|lint| lint.output.as_ref()) {
+            Some(output) => output.validate(),
+            None => Ok(()),
+        }
+    }
+
     /// Override: extensions use `{dir}/{id}/{id}.json` pattern.
     fn config_path(id: &str) -> Result<PathBuf> {
         paths::extension_manifest(id)
@@ -769,6 +804,8 @@ pub struct RequirementsConfig {
 pub struct DatabaseConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cli: Option<DatabaseCliConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub migrations: Option<DatabaseMigrationsConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -776,6 +813,53 @@ pub struct DatabaseCliConfig {
     pub tables_command: String,
     pub describe_command: String,
     pub query_command: String,
+    /// Database engine behind `query_command`. When set, `db tables` and
+    /// `db describe` read the engine's catalog through `query_command`
+    /// instead of parsing `tables_command`/`describe_command` output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<DatabaseEngine>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatabaseEngine {
+    Mysql,
+    Postgres,
+    Sqlite,
+}
+
+/// Framework migration commands run by `db migrate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseMigrationsConfig {
+    pub status_command: String,
+    pub up_command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub down_command: Option<String>,
+    /// How to read `status_command` output. Without one, output is passed
+    /// through raw.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_parser: Option<MigrationStatusParser>,
+}
+
+/// Parser for migration status output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MigrationStatusParser {
+    /// A JSON array of objects with `name` (or `migration`) and either an
+    /// `applied` bool or a `status` string.
+    Json {
+        /// Status values that mean applied (case-insensitive).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        applied: Vec<String>,
+    },
+    /// One migration per matching line. `pattern` is a regex with named
+    /// groups `name` and `status`; lines that don't match are skipped.
+    Lines {
+        pattern: String,
+        /// Status values that mean applied (case-insensitive).
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        applied: Vec<String>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -805,6 +889,29 @@ pub struct CliConfig {
     pub auto_flags: Vec<CliAutoFlag>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help: Option<CliHelpConfig>,
+    /// How the tool's output is rendered. Defaults to the JSON envelope.
+    #[serde(default, skip_serializing_if = "CliOutputMode::is_json")]
+    pub output_mode: CliOutputMode,
+}
+
+/// Response mode for an extension CLI tool.
+///
+/// `json` wraps stdout/stderr in the standard envelope. `markdown` and `raw`
+/// print the tool's stdout as-is, the same way built-in markdown commands do.
+/// `raw` always passes stderr through; `markdown` only when the tool fails.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CliOutputMode {
+    #[default]
+    Json,
+    Markdown,
+    Raw,
+}
+
+impl CliOutputMode {
+    pub fn is_json(&self) -> bool {
+        *self == Self::Json
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -943,6 +1050,65 @@ pub struct LintConfig {
     /// selectors instead of passing every changed file through one invocation.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub changed_file_routes: Vec<LintChangedFileRoute>,
+
+    /// How to parse findings from the linter's stdout when the extension
+    /// doesn't write a `lint-findings.json` sidecar.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<LintOutputConfig>,
+}
+
+/// Linter stdout format used to build normalized lint findings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LintOutputConfig {
+    pub format: LintOutputFormat,
+
+    /// Regex with named captures for `format: "regex"`. Recognized groups:
+    /// `file`, `line`, `column`, `severity`, `rule`, `message`. Matched
+    /// line by line.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+
+    /// Tool name recorded on each finding (e.g. `eslint`). Also used as the
+    /// finding category.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+}
+
+impl LintOutputConfig {
+    /// `format: "regex"` needs a `pattern` that compiles.
+    pub fn validate(&self) -> Result<()> {
+        if self.format != LintOutputFormat::Regex {
+            return Ok(());
+        }
+        let Some(pattern) = self.pattern.as_deref() else {
+            return Err(Error::validation_invalid_argument(
+                "lint.output.pattern",
+                "lint.output.format \"regex\" requires a pattern",
+                None,
+                None,
+            ));
+        };
+        regex::Regex::new(pattern).map(|_| ()).map_err(|e| {
+            Error::validation_invalid_argument(
+                "lint.output.pattern",
+                format!("Invalid lint output pattern: {}", e),
+                Some(pattern.to_string()),
+                None,
+            )
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LintOutputFormat {
+    /// A JSON array of finding objects, or ESLint-style per-file results
+    /// with a nested `messages` array.
+    Json,
+    /// Checkstyle XML (`<file name><error line column severity message source/>`).
+    Checkstyle,
+    /// One finding per line, matched by `pattern`.
+    Regex,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1160,6 +1326,13 @@ pub struct InputConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<SelectOption>>,
     pub arg: String,
+    /// `extension run` refuses to start when a required input has no value
+    /// and no `default`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    /// Shown when the input is missing or invalid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub help: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
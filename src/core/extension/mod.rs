@@ -1,9 +1,12 @@
 pub mod bench;
 pub mod build;
 pub mod component_script;
+mod exec_raw;
 mod execution;
 pub mod grammar;
 pub mod grammar_items;
+pub mod inputs;
+mod install_plan;
 mod lifecycle;
 pub mod lint;
 mod manifest;
@@ -12,6 +15,7 @@ mod runner;
 mod runner_contract;
 mod runtime_helper;
 mod scope;
+mod script_runner;
 pub mod self_check;
 pub mod test;
 pub mod trace;
@@ -28,15 +32,19 @@ pub use runner_contract::{
     PhaseFailureCategory, PhaseReport, PhaseStatus, RunnerStepFilter, VerificationPhase,
 };
 pub use runtime_helper::RUNNER_STEPS_ENV;
+pub(crate) use script_runner::{log_script_timeout, run_json_script};
+
+pub use exec_raw::{exec_raw, RawScriptOutput, RAW_CAPABILITIES};
 
 // Re-export manifest types
 pub use manifest::{
     ActionConfig, ActionType, AuditCapability, AutofixVerifyConfig, BenchConfig, BuildConfig,
-    CliAutoFlag, CliAutoFlagCondition, CliConfig, CliHelpConfig, ComponentEnvConfig,
-    DatabaseCliConfig, DatabaseConfig, DeployCapability, DeployOverride, DeployVerification,
-    DiscoveryConfig, DiscoveryMarkerConfig, DocTarget, ExecutableCapability, ExtensionManifest,
-    FeatureContextRule, FileContainsCondition, HttpMethod, InputConfig, LintChangedFileRoute,
-    LintConfig, OutputConfig, OutputSchema, PlatformCapability, ProvidesConfig,
+    CliAutoFlag, CliAutoFlagCondition, CliConfig, CliHelpConfig, CliOutputMode, ComponentEnvConfig,
+    DatabaseCliConfig, DatabaseConfig, DatabaseEngine, DatabaseMigrationsConfig, DeployCapability,
+    DeployOverride, DeployVerification, DiscoveryConfig, DiscoveryMarkerConfig, DocTarget,
+    ExecutableCapability, ExtensionManifest, FeatureContextRule, FileContainsCondition, HttpMethod,
+    InputConfig, LintChangedFileRoute, LintConfig, LintOutputConfig, LintOutputFormat,
+    MigrationStatusParser, OutputConfig, OutputSchema, PlatformCapability, ProvidesConfig,
     RemotePathInferenceRule, RemotePathRootRule, RequirementsConfig, RuntimeConfig,
     RuntimeRequirementsConfig, ScriptsConfig, SelectOption, SettingConfig, SinceTagConfig,
     StructuredSidecarDeclaration, TestConfig, TestDriftConfig, TestMappingConfig, TraceConfig,
@@ -58,16 +66,18 @@ pub use execution::{
 pub use scope::ExtensionScope;
 
 // Re-export lifecycle types and functions
+pub use install_plan::{plan_install, plan_uninstall, InstallPlan, PlannedManifest, UninstallPlan};
 pub use lifecycle::source_metadata::SourceMetadataRepair;
 pub use lifecycle::{
-    check_update_available, derive_id_from_url, install, install_for_component,
-    install_with_revision, is_git_url, read_source_revision, slugify_id, uninstall, update,
-    InstallForComponentResult, InstallResult, UpdateAvailable, UpdateResult,
+    check_update_available, derive_id_from_url, extension_dependents, install,
+    install_for_component, install_with_revision, is_git_url, read_source_revision, slugify_id,
+    uninstall, uninstall_checked, update, ExtensionDependents, InstallForComponentResult,
+    InstallResult, UpdateAvailable, UpdateResult,
 };
 pub use repair::{relink, replace, replace_with_revision, ReplaceResult};
 pub use update_output::{
-    ExtensionSourceUpdate, SourceMetadataRepairEntry, UpdateAllResult, UpdateEntry,
-    UpdateSkippedEntry,
+    ExtensionSourceUpdate, SourceMetadataRepairEntry, UpdateAllResult, UpdateCheckResult,
+    UpdateEntry, UpdateSkippedEntry,
 };
 
 pub(crate) fn stderr_tail(stderr: &str) -> String {
@@ -83,7 +93,7 @@ pub(crate) fn stderr_tail(stderr: &str) -> String {
 // Extension loader functions
 
 use crate::component::Component;
-use crate::config;
+use crate::config::{self, ConfigEntity};
 use crate::engine::run_dir::RunDir;
 use crate::error::Result;
 use crate::error::{Error, ErrorCode};
@@ -91,15 +101,55 @@ use crate::output::MergeOutput;
 use crate::paths;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 pub fn load_extension(id: &str) -> Result<ExtensionManifest> {
     let mut manifest = config::load::<ExtensionManifest>(id)?;
+    manifest.validate()?;
     let extension_dir = paths::extension(id)?;
     manifest.extension_path = Some(extension_dir.to_string_lossy().to_string());
     Ok(manifest)
 }
 
+/// Load every installed extension manifest.
+///
+/// Manifests are read from disk once per process and served from memory
+/// afterwards. Anything that changes what is installed must call
+/// [`invalidate_extension_cache`].
 pub fn load_all_extensions() -> Result<Vec<ExtensionManifest>> {
+    let extensions_dir = paths::extensions()?;
+    let mut cache = extension_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some((dir, extensions)) = cache.as_ref() {
+        if *dir == extensions_dir {
+            return Ok(extensions.clone());
+        }
+    }
+
+    let extensions = read_all_extensions()?;
+    *cache = Some((extensions_dir, extensions.clone()));
+    Ok(extensions)
+}
+
+/// Drop the cached manifests so the next [`load_all_extensions`] re-reads
+/// them from disk.
+pub fn invalidate_extension_cache() {
+    *extension_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Cached manifests, keyed by the extensions directory they were read from.
+type ExtensionCache = Option<(PathBuf, Vec<ExtensionManifest>)>;
+
+fn extension_cache() -> &'static Mutex<ExtensionCache> {
+    static CACHE: OnceLock<Mutex<ExtensionCache>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(None))
+}
+
+fn read_all_extensions() -> Result<Vec<ExtensionManifest>> {
+    let _span = crate::profile::span("extension.load_all");
     let extensions = config::list::<ExtensionManifest>()?;
     let mut extensions_with_paths = Vec::new();
     for mut extension in extensions {
@@ -162,6 +212,14 @@ struct ExtensionCapabilityDescriptor {
 }
 
 impl ExtensionCapability {
+    pub(crate) const ALL: [ExtensionCapability; 5] = [
+        ExtensionCapability::Build,
+        ExtensionCapability::Lint,
+        ExtensionCapability::Test,
+        ExtensionCapability::Bench,
+        ExtensionCapability::Trace,
+    ];
+
     fn descriptor(self) -> ExtensionCapabilityDescriptor {
         match self {
             ExtensionCapability::Lint => ExtensionCapabilityDescriptor {
@@ -511,22 +569,18 @@ pub fn run_fingerprint_script(
         "content": content,
     });
 
-    // Invoke the script directly so its shebang resolves the interpreter.
-    // Wrapping with `sh -c <script>` bypasses `#!/usr/bin/env bash` and runs
-    // under POSIX sh — which breaks scripts using bash-only features. See #1276.
-    let output = std::process::Command::new(&script_path)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::null())
-        .spawn()
-        .ok()
-        .and_then(|mut child| {
-            use std::io::Write;
-            if let Some(ref mut stdin) = child.stdin {
-                let _ = stdin.write_all(input.to_string().as_bytes());
-            }
-            child.wait_with_output().ok()
-        })?;
+    let output = match run_json_script(
+        extension,
+        "fingerprint",
+        &script_path,
+        input.to_string().as_bytes(),
+    ) {
+        Ok(output) => output,
+        Err(err) => {
+            log_script_timeout(&err);
+            return None;
+        }
+    };
 
     if !output.status.success() {
         return None;
@@ -736,22 +790,18 @@ pub fn run_refactor_script(
         return None;
     }
 
-    // Invoke the script directly so its shebang resolves the interpreter.
-    // Wrapping with `sh -c <script>` bypasses `#!/usr/bin/env bash` and runs
-    // under POSIX sh — which breaks scripts using bash-only features. See #1276.
-    let output = std::process::Command::new(&script_path)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .ok()
-        .and_then(|mut child| {
-            use std::io::Write;
-            if let Some(ref mut stdin) = child.stdin {
-                let _ = stdin.write_all(command.to_string().as_bytes());
-            }
-            child.wait_with_output().ok()
-        })?;
+    let output = match run_json_script(
+        extension,
+        "refactor",
+        &script_path,
+        command.to_string().as_bytes(),
+    ) {
+        Ok(output) => output,
+        Err(err) => {
+            log_script_timeout(&err);
+            return None;
+        }
+    };
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -883,6 +933,55 @@ pub struct ExtensionSummary {
     pub has_setup: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub has_ready_check: Option<bool>,
+    /// Declared capabilities: build, deploy, lint, test, bench, trace, cli.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub capabilities: Vec<String>,
+    /// Capability scripts declared in the manifest and whether each resolves on disk.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub scripts: Vec<CapabilityScript>,
+    /// Ready, and every declared capability script exists.
+    pub healthy: bool,
+}
+
+/// A capability script declared by an extension manifest.
+#[derive(Debug, Clone, Serialize)]
+pub struct CapabilityScript {
+    pub capability: String,
+    pub path: String,
+    pub exists: bool,
+}
+
+/// Labels of the capabilities an extension manifest declares.
+pub fn capability_labels(ext: &ExtensionManifest) -> Vec<String> {
+    let mut labels = Vec::new();
+    for capability in ExtensionCapability::ALL {
+        if capability.has_manifest_support(ext) {
+            labels.push(capability.label().to_string());
+        }
+        if capability == ExtensionCapability::Build && ext.deploy.is_some() {
+            labels.push("deploy".to_string());
+        }
+    }
+    if ext.has_cli() {
+        labels.push("cli".to_string());
+    }
+    labels
+}
+
+/// Resolve each declared capability script against the extension directory.
+pub fn capability_scripts(ext: &ExtensionManifest) -> Vec<CapabilityScript> {
+    let root = PathBuf::from(ext.extension_path.as_deref().unwrap_or_default());
+    ExtensionCapability::ALL
+        .into_iter()
+        .filter_map(|capability| {
+            let script = capability.script_path(ext)?;
+            Some(CapabilityScript {
+                capability: capability.label().to_string(),
+                path: script.to_string(),
+                exists: root.join(script).is_file(),
+            })
+        })
+        .collect()
 }
 
 /// Summary of an extension action.
@@ -934,6 +1033,8 @@ pub fn list_summaries(project: Option<&crate::project::Project>) -> Vec<Extensio
                 .map(|_| true);
 
             let source_revision = read_source_revision(&ext.id);
+            let scripts = capability_scripts(ext);
+            let healthy = ready_status.ready && scripts.iter().all(|script| script.exists);
 
             ExtensionSummary {
                 id: ext.id.clone(),
@@ -962,6 +1063,9 @@ pub fn list_summaries(project: Option<&crate::project::Project>) -> Vec<Extensio
                 actions,
                 has_setup,
                 has_ready_check,
+                capabilities: capability_labels(ext),
+                scripts,
+                healthy,
             }
         })
         .collect()
@@ -976,45 +1080,19 @@ pub fn update_all(force: bool) -> UpdateAllResult {
     let mut repaired_source_metadata = Vec::new();
 
     for id in &extension_ids {
-        let old_version = load_extension(id).ok().map(|m| m.version.clone());
-
-        match update(id, force) {
-            Ok(result) => {
-                let new_version = load_extension(id)
-                    .ok()
-                    .map(|m| m.version.clone())
-                    .unwrap_or_default();
-                let repaired = result.repaired_source_metadata;
-
-                if let Some(repair) = repaired.clone() {
+        match update_entry(id, force) {
+            Ok(entry) => {
+                if let Some(repair) = entry.repaired_source_metadata.clone() {
                     repaired_source_metadata.push(SourceMetadataRepairEntry {
                         extension_id: id.clone(),
                         repair,
                     });
                 }
-
-                updated.push(UpdateEntry {
-                    extension_id: id.clone(),
-                    old_version: old_version.unwrap_or_default(),
-                    new_version,
-                    linked: result.linked,
-                    source_path: result
-                        .source_path
-                        .map(|path| path.to_string_lossy().to_string()),
-                    git_root: result
-                        .git_root
-                        .map(|path| path.to_string_lossy().to_string()),
-                    source_update: result.source_update,
-                    repaired_source_metadata: repaired,
-                });
+                updated.push(entry);
             }
-            Err(err) => {
+            Err(skip) => {
                 skipped.push(id.clone());
-                skipped_details.push(UpdateSkippedEntry {
-                    extension_id: id.clone(),
-                    reason: err.message,
-                    hints: err.hints.into_iter().map(|hint| hint.message).collect(),
-                });
+                skipped_details.push(skip);
             }
         }
     }
@@ -1027,6 +1105,76 @@ pub fn update_all(force: bool) -> UpdateAllResult {
     }
 }
 
+/// Check every installed extension against its origin without changing it.
+pub fn check_all_updates() -> UpdateCheckResult {
+    let extension_ids = available_extension_ids();
+    UpdateCheckResult {
+        checked: extension_ids.len(),
+        outdated: extension_ids
+            .iter()
+            .filter_map(|id| check_update_available(id))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Update every extension listed in `check.outdated`. Stops at the first
+/// failure unless `continue_on_error`, listing the rest as not attempted.
+pub fn apply_updates(
+    mut check: UpdateCheckResult,
+    force: bool,
+    continue_on_error: bool,
+) -> UpdateCheckResult {
+    let ids: Vec<String> = check
+        .outdated
+        .iter()
+        .map(|update| update.extension_id.clone())
+        .collect();
+    for (index, id) in ids.iter().enumerate() {
+        match update_entry(id, force) {
+            Ok(entry) => check.updated.push(entry),
+            Err(skip) => {
+                check.failed.push(skip);
+                if !continue_on_error {
+                    check.not_attempted = ids[index + 1..].to_vec();
+                    break;
+                }
+            }
+        }
+    }
+    check
+}
+
+/// Update one extension, recording its versions before and after.
+fn update_entry(id: &str, force: bool) -> std::result::Result<UpdateEntry, UpdateSkippedEntry> {
+    let old_version = load_extension(id).ok().map(|m| m.version.clone());
+
+    match update(id, force) {
+        Ok(result) => Ok(UpdateEntry {
+            extension_id: id.to_string(),
+            old_version: old_version.unwrap_or_default(),
+            new_version: load_extension(id)
+                .ok()
+                .map(|m| m.version.clone())
+                .unwrap_or_default(),
+            linked: result.linked,
+            source_path: result
+                .source_path
+                .map(|path| path.to_string_lossy().to_string()),
+            git_root: result
+                .git_root
+                .map(|path| path.to_string_lossy().to_string()),
+            source_update: result.source_update,
+            repaired_source_metadata: result.repaired_source_metadata,
+        }),
+        Err(err) => Err(UpdateSkippedEntry {
+            extension_id: id.to_string(),
+            reason: err.message,
+            hints: err.hints.into_iter().map(|hint| hint.message).collect(),
+        }),
+    }
+}
+
 /// Execute a tool from an extension's vendor directory.
 ///
 /// Sets up PATH with the extension's vendor/bin and node_modules/.bin,
@@ -1072,11 +1220,15 @@ pub fn exec_tool(extension_id: &str, component_id: Option<&str>, args: &[String]
 }
 
 pub fn save_manifest(manifest: &ExtensionManifest) -> Result<()> {
-    config::save(manifest)
+    let result = config::save(manifest);
+    invalidate_extension_cache();
+    result
 }
 
 pub fn merge(id: Option<&str>, json_spec: &str, replace_fields: &[String]) -> Result<MergeOutput> {
-    config::merge::<ExtensionManifest>(id, json_spec, replace_fields)
+    let result = config::merge::<ExtensionManifest>(id, json_spec, replace_fields, &[]);
+    invalidate_extension_cache();
+    result
 }
 
 /// Check if a extension is a symlink (linked, not installed).
@@ -1099,7 +1251,7 @@ pub fn validate_required_extensions(component: &crate::component::Component) ->
 
     let mut missing: Vec<String> = Vec::new();
     for extension_id in extensions.keys() {
-        if load_extension(extension_id).is_err() {
+        if check_extension_requirement(extension_id, None).status == RequirementStatus::Missing {
             missing.push(extension_id.clone());
         }
     }
@@ -1154,6 +1306,123 @@ pub fn validate_required_extensions(component: &crate::component::Component) ->
     Err(err)
 }
 
+/// How one declared extension requirement resolves against what is installed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RequirementStatus {
+    Satisfied,
+    Missing,
+    VersionMismatch,
+    InvalidConstraint,
+    InvalidVersion,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RequirementCheck {
+    pub status: RequirementStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installed_version: Option<String>,
+    /// Human-readable problem; `None` when satisfied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Check a single requirement on `extension_id`, optionally constrained to a
+/// version range. Shared by the component validators and `component deps`.
+pub fn check_extension_requirement(
+    extension_id: &str,
+    constraint: Option<&str>,
+) -> RequirementCheck {
+    let check =
+        |status, installed_version: Option<String>, message: Option<String>| RequirementCheck {
+            status,
+            installed_version,
+            message,
+        };
+
+    let parsed_constraint = match constraint.map(version::VersionConstraint::parse) {
+        Some(Ok(parsed)) => Some(parsed),
+        Some(Err(_)) => {
+            return check(
+                RequirementStatus::InvalidConstraint,
+                None,
+                Some(format!(
+                    "Invalid version constraint '{}' for extension '{}'",
+                    constraint.unwrap_or_default(),
+                    extension_id
+                )),
+            )
+        }
+        None => None,
+    };
+
+    let Ok(extension) = load_extension(extension_id) else {
+        return check(
+            RequirementStatus::Missing,
+            None,
+            Some(format!("Extension '{}' is not installed", extension_id)),
+        );
+    };
+    let installed = Some(extension.version.clone());
+
+    let Some(constraint) = parsed_constraint else {
+        return check(RequirementStatus::Satisfied, installed, None);
+    };
+    match extension.semver() {
+        Ok(installed_version) if constraint.matches(&installed_version) => {
+            check(RequirementStatus::Satisfied, installed, None)
+        }
+        Ok(installed_version) => check(
+            RequirementStatus::VersionMismatch,
+            installed,
+            Some(format!(
+                "'{}' requires {}, but {} is installed",
+                extension_id, constraint, installed_version
+            )),
+        ),
+        Err(_) => check(
+            RequirementStatus::InvalidVersion,
+            installed,
+            Some(format!(
+                "Extension '{}' has invalid version '{}'",
+                extension_id, extension.version
+            )),
+        ),
+    }
+}
+
+/// Find a cycle in `requires.extensions` across `extensions`, returned as ids
+/// in dependency order with the first repeated at the end (`a -> b -> a`).
+/// Requirements on extensions not in the list are ignored.
+pub fn find_requirement_cycle(extensions: &[ExtensionManifest]) -> Option<Vec<String>> {
+    let index: HashMap<&str, usize> = extensions
+        .iter()
+        .enumerate()
+        .map(|(idx, extension)| (extension.id.as_str(), idx))
+        .collect();
+    let dependencies: Vec<Vec<usize>> = extensions
+        .iter()
+        .map(|extension| {
+            extension
+                .requires
+                .iter()
+                .flat_map(|requires| &requires.extensions)
+                .filter_map(|id| index.get(id.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    crate::engine::topo::topo_sort(&dependencies)
+        .err()
+        .map(|cycle| {
+            cycle
+                .path
+                .into_iter()
+                .map(|idx| extensions[idx].id.clone())
+                .collect()
+        })
+}
+
 /// Validate that all extensions declared in a component's `extensions` field are installed
 /// and satisfy the declared version constraints.
 ///
@@ -1168,51 +1437,24 @@ pub fn validate_extension_requirements(component: &crate::component::Component)
     let mut hints: Vec<String> = Vec::new();
 
     for (extension_id, ext_config) in extensions {
-        let constraint_str = match &ext_config.version {
-            Some(v) => v.as_str(),
-            None => continue, // No version constraint, skip validation
+        let Some(constraint) = ext_config.version.as_deref() else {
+            continue; // No version constraint, skip validation
         };
 
-        let constraint = match version::VersionConstraint::parse(constraint_str) {
-            Ok(c) => c,
-            Err(_) => {
-                errors.push(format!(
-                    "Invalid version constraint '{}' for extension '{}'",
-                    constraint_str, extension_id
-                ));
-                continue;
-            }
-        };
-
-        match load_extension(extension_id) {
-            Ok(extension) => match extension.semver() {
-                Ok(installed_version) => {
-                    if !constraint.matches(&installed_version) {
-                        errors.push(format!(
-                            "'{}' requires {}, but {} is installed",
-                            extension_id, constraint, installed_version
-                        ));
-                        hints.push(format!(
-                            "Run `homeboy extension update {}` to get the latest version",
-                            extension_id
-                        ));
-                    }
-                }
-                Err(_) => {
-                    errors.push(format!(
-                        "Extension '{}' has invalid version '{}'",
-                        extension_id, extension.version
-                    ));
-                }
-            },
-            Err(_) => {
-                errors.push(format!("Extension '{}' is not installed", extension_id));
-                hints.push(format!(
-                    "homeboy extension install https://github.com/Extra-Chill/homeboy-extensions --id {}",
-                    extension_id
-                ));
-            }
+        let check = check_extension_requirement(extension_id, Some(constraint));
+        match check.status {
+            RequirementStatus::Satisfied => continue,
+            RequirementStatus::VersionMismatch => hints.push(format!(
+                "Run `homeboy extension update {}` to get the latest version",
+                extension_id
+            )),
+            RequirementStatus::Missing => hints.push(format!(
+                "homeboy extension install https://github.com/Extra-Chill/homeboy-extensions --id {}",
+                extension_id
+            )),
+            RequirementStatus::InvalidConstraint | RequirementStatus::InvalidVersion => {}
         }
+        errors.extend(check.message);
     }
 
     if errors.is_empty() {
@@ -1272,6 +1514,35 @@ mod tests {
     use crate::component::{Component, ScopedExtensionConfig};
     use std::collections::HashMap;
 
+    fn write_manifest(id: &str) {
+        let dir = paths::extension(id).unwrap();
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join(format!("{}.json", id)),
+            r#"{"name": "Example", "version": "1.0.0"}"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn load_all_extensions_reads_disk_once_until_invalidated() {
+        crate::test_support::with_isolated_home(|_| {
+            write_manifest("alpha");
+            assert_eq!(load_all_extensions().unwrap().len(), 1);
+
+            write_manifest("beta");
+            assert_eq!(load_all_extensions().unwrap().len(), 1);
+
+            invalidate_extension_cache();
+            let ids: Vec<String> = load_all_extensions()
+                .unwrap()
+                .into_iter()
+                .map(|extension| extension.id)
+                .collect();
+            assert_eq!(ids, ["alpha", "beta"]);
+        });
+    }
+
     #[test]
     fn extension_capability_owns_labels_and_scripts() {
         let manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
@@ -1322,6 +1593,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn capability_inventory_reports_labels_and_missing_scripts() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("lint.sh"), "#!/bin/sh\n").unwrap();
+        let mut manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
+            "name": "Example",
+            "version": "0.0.0",
+            "deploy": {},
+            "cli": {
+                "tool": "wp",
+                "display_name": "WP-CLI",
+                "command_template": "wp {{args}}"
+            },
+            "lint": { "extension_script": "lint.sh" },
+            "test": { "extension_script": "test.sh" },
+            "build": {}
+        }))
+        .unwrap();
+        manifest.extension_path = Some(dir.path().to_string_lossy().to_string());
+
+        assert_eq!(
+            capability_labels(&manifest),
+            ["build", "deploy", "lint", "test", "cli"]
+        );
+
+        let scripts: Vec<(String, bool)> = capability_scripts(&manifest)
+            .into_iter()
+            .map(|script| (script.capability, script.exists))
+            .collect();
+        assert_eq!(
+            scripts,
+            [("lint".to_string(), true), ("test".to_string(), false)]
+        );
+    }
+
     #[test]
     fn manifest_parses_declared_structured_sidecar_schema_versions() {
         let manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
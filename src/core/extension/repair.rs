@@ -5,6 +5,7 @@ use crate::git;
 use crate::paths;
 use std::path::{Path, PathBuf};
 
+use super::invalidate_extension_cache;
 use super::lifecycle::{
     derive_id_from_url, is_git_url, rename_dir, resolve_cloned_extension, run_setup_if_configured,
     slugify_id, write_source_metadata,
@@ -30,15 +31,19 @@ pub fn replace_with_revision(
     id_override: Option<&str>,
     revision: Option<&str>,
 ) -> Result<ReplaceResult> {
-    if is_git_url(source) {
+    let result = if is_git_url(source) {
         replace_from_url(source, id_override, revision)
     } else {
         replace_from_path(source, id_override, false)
-    }
+    };
+    invalidate_extension_cache();
+    result
 }
 
 pub fn relink(extension_id: &str, source: &str) -> Result<ReplaceResult> {
-    replace_from_path(source, Some(extension_id), true)
+    let result = replace_from_path(source, Some(extension_id), true);
+    invalidate_extension_cache();
+    result
 }
 
 fn replace_from_url(
@@ -73,7 +73,7 @@ fn ensure_helper(runtime_dir: &std::path::Path, helper: &RuntimeHelper) -> Resul
     let current = fs::read_to_string(&helper_path).ok();
 
     if current.as_deref() != Some(helper.content) {
-        local_files::write_file_atomic(
+        local_files::write_scratch_file_atomic(
             &helper_path,
             helper.content,
             &format!("write runtime {} helper", helper.filename),
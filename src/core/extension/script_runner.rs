@@ -0,0 +1,217 @@
+//! Bounded execution for JSON stdin/stdout extension scripts.
+//!
+//! Fingerprint, refactor, and topology scripts run once per file, so a hung
+//! script would otherwise block Homeboy forever. Each script runs in its own
+//! process group under the manifest's `scripts.timeout_secs` budget; on
+//! timeout the whole group is killed so grandchildren don't linger.
+
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Child, Command, Output, Stdio};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use super::ExtensionManifest;
+use crate::error::{Error, ErrorCode, Result};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Run `script_path` with `input` on stdin, returning its output regardless
+/// of exit status. Errors on spawn failure or when the script outlives the
+/// extension's timeout for `script`.
+pub(crate) fn run_json_script(
+    extension: &ExtensionManifest,
+    script: &str,
+    script_path: &Path,
+    input: &[u8],
+) -> Result<Output> {
+    let timeout_secs = extension.script_timeout_secs(script);
+
+    // Invoke the script directly so its shebang resolves the interpreter.
+    // Wrapping with `sh -c <script>` bypasses `#!/usr/bin/env bash` and runs
+    // under POSIX sh — which breaks scripts using bash-only features. See #1276.
+    let mut cmd = Command::new(script_path);
+    cmd.stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    own_process_group(&mut cmd);
+
+    let mut child = cmd.spawn().map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("spawn {} script {}", script, script_path.display())),
+        )
+    })?;
+
+    // Feed stdin and drain stdout/stderr on their own threads: a script that
+    // never reads its input or fills a pipe buffer must not block the timer.
+    let stdin = child.stdin.take().map(|mut stdin| {
+        let input = input.to_vec();
+        std::thread::spawn(move || {
+            let _ = stdin.write_all(&input);
+        })
+    });
+    let stdout = child.stdout.take().map(drain);
+    let stderr = child.stderr.take().map(drain);
+
+    let status = match wait_with_timeout(&mut child, Duration::from_secs(timeout_secs)) {
+        Ok(Some(status)) => status,
+        Ok(None) => {
+            kill_process_group(&mut child);
+            join(stdin);
+            return Err(Error::extension_script_timeout(
+                &extension.id,
+                script,
+                timeout_secs,
+            ));
+        }
+        Err(e) => {
+            kill_process_group(&mut child);
+            return Err(Error::internal_io(
+                e.to_string(),
+                Some(format!("wait on {} script", script)),
+            ));
+        }
+    };
+
+    join(stdin);
+    Ok(Output {
+        status,
+        stdout: join(stdout).unwrap_or_default(),
+        stderr: join(stderr).unwrap_or_default(),
+    })
+}
+
+/// Log a script failure when it is a timeout. Spawn and wait failures stay
+/// silent, matching how callers already treat a missing or broken script.
+pub(crate) fn log_script_timeout(err: &Error) {
+    if err.code == ErrorCode::ExtensionScriptTimeout {
+        crate::log_status!("extension", "{}", err.message);
+    }
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+fn join<T>(handle: Option<JoinHandle<T>>) -> Option<T> {
+    handle.and_then(|h| h.join().ok())
+}
+
+fn wait_with_timeout(
+    child: &mut Child,
+    timeout: Duration,
+) -> std::io::Result<Option<std::process::ExitStatus>> {
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if started.elapsed() >= timeout {
+            return Ok(None);
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(unix)]
+fn own_process_group(cmd: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    cmd.process_group(0);
+}
+
+#[cfg(not(unix))]
+fn own_process_group(_cmd: &mut Command) {}
+
+fn kill_process_group(child: &mut Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    fn script_extension(dir: &Path, body: &str, timeout_secs: u64) -> ExtensionManifest {
+        use std::os::unix::fs::PermissionsExt;
+
+        let script_path = dir.join("fingerprint.sh");
+        std::fs::write(&script_path, body).expect("script should be written");
+        std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755))
+            .expect("script should become executable");
+
+        let mut manifest: ExtensionManifest = serde_json::from_value(serde_json::json!({
+            "name": "Slow",
+            "version": "1.0.0",
+            "scripts": {
+                "fingerprint": "fingerprint.sh",
+                "timeout_secs": { "fingerprint": timeout_secs }
+            }
+        }))
+        .expect("manifest parses");
+        manifest.id = "slow".to_string();
+        manifest.extension_path = Some(dir.to_string_lossy().to_string());
+        manifest
+    }
+
+    #[test]
+    fn test_run_json_script() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let extension = script_extension(dir.path(), "#!/bin/sh\ncat\n", 5);
+
+        let output = run_json_script(
+            &extension,
+            "fingerprint",
+            &dir.path().join("fingerprint.sh"),
+            br#"{"ok":true}"#,
+        )
+        .expect("script should run");
+
+        assert!(output.status.success());
+        assert_eq!(output.stdout, br#"{"ok":true}"#);
+    }
+
+    #[test]
+    fn run_json_script_kills_process_group_on_timeout() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let pid_file = dir.path().join("grandchild.pid");
+        let body = format!(
+            "#!/bin/sh\nsleep 30 &\necho $! > {}\nwait\n",
+            pid_file.display()
+        );
+        let extension = script_extension(dir.path(), &body, 1);
+
+        let started = Instant::now();
+        let err = run_json_script(
+            &extension,
+            "fingerprint",
+            &dir.path().join("fingerprint.sh"),
+            b"{}",
+        )
+        .unwrap_err();
+
+        assert_eq!(err.code.as_str(), "extension.script_timeout");
+        assert!(err.message.contains("timed out after 1s"));
+        assert!(started.elapsed() < Duration::from_secs(10));
+
+        let pid: libc::pid_t = std::fs::read_to_string(&pid_file)
+            .expect("grandchild pid recorded")
+            .trim()
+            .parse()
+            .expect("pid parses");
+        std::thread::sleep(Duration::from_millis(100));
+        // A killed orphan may linger as a zombie until init reaps it.
+        let alive = std::fs::read_to_string(format!("/proc/{}/stat", pid))
+            .map(|stat| !stat.contains(") Z "))
+            .unwrap_or(false);
+        assert!(!alive, "grandchild sleep should be killed with the group");
+    }
+}
@@ -4,6 +4,7 @@
 //! produce domain-specific result types. This module provides the unified output
 //! envelope and builder functions that assemble results into command-ready output.
 
+use crate::engine::junit::{JunitCase, JunitOutcome, JunitSuite};
 use crate::extension::test::{
     CoverageOutput, DriftReport, TestAnalysis, TestBaselineComparison, TestCounts, TestScopeOutput,
     TestSummaryOutput,
@@ -223,6 +224,94 @@ fn test_phase_report(status: &str, exit_code: i32, counts: Option<&TestCounts>)
     }
 }
 
+/// Map a test run onto a JUnit suite for `--format junit`.
+///
+/// Each reported failed test becomes a failing case. Runners only report
+/// failures by name, so passing tests collapse into one case for the
+/// component; a failing run without per-test detail becomes a single failing
+/// (or, for infrastructure failures, erroring) case carrying the output tail.
+/// `time` is the wall-clock duration of the run.
+pub fn junit_suite(output: &TestCommandOutput, time: Option<f64>) -> JunitSuite {
+    let component = output.component.clone();
+    let case = |name: &str, outcome| JunitCase {
+        classname: component.clone(),
+        name: name.to_string(),
+        time: None,
+        outcome,
+    };
+
+    let mut cases: Vec<JunitCase> = output
+        .failed_tests
+        .iter()
+        .flatten()
+        .map(|test| {
+            let mut detail = test.detail.clone().unwrap_or_default();
+            if let Some(location) = &test.location {
+                detail = format!("{}\n{}", location, detail).trim_end().to_string();
+            }
+            let message = test
+                .detail
+                .as_deref()
+                .and_then(|detail| detail.lines().next())
+                .unwrap_or("test failed")
+                .to_string();
+            case(&test.name, JunitOutcome::Failed { message, detail })
+        })
+        .collect();
+
+    let passed = output.test_counts.as_ref().map(|counts| counts.passed);
+    if output.passed || passed.unwrap_or(0) > 0 {
+        let outcome = if output.status == "skipped" {
+            JunitOutcome::Skipped
+        } else {
+            JunitOutcome::Passed
+        };
+        let name = match passed {
+            Some(count) => format!("{} passing test(s)", count),
+            None => "tests".to_string(),
+        };
+        cases.push(case(&name, outcome));
+    }
+
+    if !output.passed
+        && output
+            .failed_tests
+            .as_ref()
+            .is_none_or(|tests| tests.is_empty())
+    {
+        let message = output
+            .failure
+            .as_ref()
+            .map(|failure| failure.summary.clone())
+            .unwrap_or_else(|| format!("test phase failed (exit {})", output.exit_code));
+        let detail = output
+            .raw_output
+            .as_ref()
+            .map(|raw| {
+                format!("{}\n{}", raw.stdout_tail, raw.stderr_tail)
+                    .trim()
+                    .to_string()
+            })
+            .unwrap_or_default();
+        let infrastructure = output
+            .failure
+            .as_ref()
+            .is_some_and(|failure| failure.category == PhaseFailureCategory::Infrastructure);
+        let outcome = if infrastructure {
+            JunitOutcome::Errored { message, detail }
+        } else {
+            JunitOutcome::Failed { message, detail }
+        };
+        cases.push(case("tests", outcome));
+    }
+
+    JunitSuite {
+        name: component,
+        time,
+        cases,
+    }
+}
+
 fn test_phase_failure(exit_code: i32, counts: Option<&TestCounts>) -> PhaseFailure {
     let category = if exit_code != 0 && counts.map(|counts| counts.total == 0).unwrap_or(false) {
         PhaseFailureCategory::Findings
@@ -319,6 +408,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_junit_suite() {
+        let (output, _) = from_main_workflow(workflow_result(Some(vec![FailedTest {
+            name: "tests::fails".to_string(),
+            detail: Some("assertion failed\nleft: 1".to_string()),
+            location: Some("tests/fails.rs:42".to_string()),
+        }])));
+
+        let suite = junit_suite(&output, Some(2.0));
+
+        assert_eq!(suite.name, "homeboy");
+        assert_eq!(suite.cases.len(), 2);
+        assert_eq!(suite.cases[0].name, "tests::fails");
+        assert_eq!(
+            suite.cases[0].outcome,
+            JunitOutcome::Failed {
+                message: "assertion failed".to_string(),
+                detail: "tests/fails.rs:42\nassertion failed\nleft: 1".to_string(),
+            }
+        );
+        assert_eq!(suite.cases[1].name, "1 passing test(s)");
+        assert_eq!(suite.cases[1].outcome, JunitOutcome::Passed);
+    }
+
+    #[test]
+    fn junit_suite_reports_failures_without_test_detail() {
+        let (output, _) = from_main_workflow(workflow_result(None));
+
+        let suite = junit_suite(&output, None);
+
+        let last = suite.cases.last().expect("failure case");
+        assert_eq!(last.name, "tests");
+        assert!(matches!(last.outcome, JunitOutcome::Failed { .. }));
+    }
+
     #[test]
     fn serializes_failed_tests_when_present() {
         let (output, exit_code) = from_main_workflow(workflow_result(Some(vec![FailedTest {
@@ -1,7 +1,8 @@
 //! Startup extension update check — warns users when installed extensions have updates available.
 //!
 //! Same pattern as the CLI update check (`update_check.rs`):
-//! - Check on first run of the day (24h cache)
+//! - Check on first run of the day (`update_check_interval_hours` cache),
+//!   in the background so the command never waits on `git fetch`
 //! - Print notice to stderr if any extension has updates
 //! - `homeboy extension update <name>` to apply
 //!
@@ -13,13 +14,12 @@
 //! [`crate::core::update_check_cache`]. The on-disk filename and JSON
 //! schema live here and are unchanged.
 
-use crate::core::update_check_cache;
+use crate::core::update_check_cache::{self, BackgroundRefresh};
 use crate::extension;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 const CACHE_FILENAME: &str = "extension_update_check.json";
-const CHECK_INTERVAL_SECS: u64 = 86400;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExtensionUpdateCache {
@@ -75,25 +75,37 @@ fn print_extension_hints(extensions_behind: &HashMap<String, usize>) {
     }
 }
 
-pub fn run_startup_check() {
+/// Print cached hints and, when the cache is stale, start a background
+/// refresh. Hold the returned guard until the command finishes.
+pub fn run_startup_check() -> Option<BackgroundRefresh> {
     if is_disabled_by_env() || is_disabled_by_config() {
-        return;
+        return None;
     }
 
-    let mut already_printed = false;
-    let cached = read_cache();
-
-    if let Some(ref cache) = cached {
-        if !cache.extensions_behind.is_empty() {
-            print_extension_hints(&cache.extensions_behind);
-            already_printed = true;
-        }
+    let cache = read_cache();
+    if let Some(cache) = &cache {
+        print_extension_hints(&cache.extensions_behind);
 
-        if update_check_cache::is_cache_fresh(cache.checked_at, CHECK_INTERVAL_SECS) {
-            return;
+        if update_check_cache::is_cache_fresh(
+            cache.checked_at,
+            update_check_cache::check_interval_secs(),
+        ) {
+            return None;
         }
     }
 
+    // Stamp the cache first so a refresh cut short at exit is not restarted
+    // by every following command.
+    write_cache(&ExtensionUpdateCache {
+        extensions_behind: cache
+            .map(|cache| cache.extensions_behind)
+            .unwrap_or_default(),
+        checked_at: update_check_cache::now_unix(),
+    });
+    Some(update_check_cache::spawn_refresh(refresh))
+}
+
+fn refresh() {
     let extension_ids = extension::available_extension_ids();
     let mut extensions_behind: HashMap<String, usize> = HashMap::new();
 
@@ -104,19 +116,17 @@ pub fn run_startup_check() {
     }
 
     write_cache(&ExtensionUpdateCache {
-        extensions_behind: extensions_behind.clone(),
+        extensions_behind,
         checked_at: update_check_cache::now_unix(),
     });
-
-    if !already_printed && !extensions_behind.is_empty() {
-        print_extension_hints(&extensions_behind);
-    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const CHECK_INTERVAL_SECS: u64 = 86400;
+
     #[test]
     fn cache_freshness_within_24h() {
         let cache = ExtensionUpdateCache {
@@ -1,6 +1,6 @@
 use serde::Serialize;
 
-use super::SourceMetadataRepair;
+use super::{SourceMetadataRepair, UpdateAvailable};
 
 /// Result of updating all extensions.
 #[derive(Debug, Clone, Serialize)]
@@ -13,6 +13,22 @@ pub struct UpdateAllResult {
     pub repaired_source_metadata: Vec<SourceMetadataRepairEntry>,
 }
 
+/// Installed extensions behind their origin, and the outcome of applying
+/// those updates when requested.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCheckResult {
+    /// Number of installed extensions checked.
+    pub checked: usize,
+    pub outdated: Vec<UpdateAvailable>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub updated: Vec<UpdateEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<UpdateSkippedEntry>,
+    /// Outdated extensions left alone after an earlier update failed.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub not_attempted: Vec<String>,
+}
+
 /// A single extension update entry with before/after versions.
 #[derive(Debug, Clone, Serialize)]
 pub struct UpdateEntry {
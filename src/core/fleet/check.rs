@@ -55,6 +55,8 @@ pub fn collect_check(
             no_pull: true,
             head: true,
             tagged: false,
+            no_verify: false,
+            step_filter: Default::default(),
         };
 
         match deploy::run(project_id, &config) {
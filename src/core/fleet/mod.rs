@@ -76,33 +76,84 @@ entity_crud!(Fleet; list_ids, merge);
 // Operations
 // ============================================================================
 
-/// Add a project to a fleet
-pub fn add_project(fleet_id: &str, project_id: &str) -> Result<Fleet> {
-    let mut fleet = load(fleet_id)?;
+/// Outcome of adding or removing fleet members.
+#[derive(Debug, Clone, Serialize)]
+pub struct FleetMembershipChange {
+    pub fleet: Fleet,
+    /// Projects added or removed by this call.
+    pub changed: Vec<String>,
+    /// Requested projects already in (or already absent from) the fleet.
+    pub unchanged: Vec<String>,
+}
 
-    // Validate project exists
-    if !project::exists(project_id) {
-        let suggestions = config::find_similar_ids::<crate::project::Project>(project_id);
-        return Err(Error::project_not_found(project_id, suggestions));
+/// Add projects to a fleet. Every project must exist; nothing is saved if
+/// any is missing. Projects already in the fleet are reported as unchanged.
+pub fn add_projects(fleet_id: &str, project_ids: &[String]) -> Result<FleetMembershipChange> {
+    let mut fleet = load(fleet_id)?;
+    validate_project_ids(project_ids)?;
+
+    let mut changed = Vec::new();
+    let mut unchanged = Vec::new();
+    for project_id in dedup(project_ids) {
+        if fleet.project_ids.contains(&project_id) {
+            unchanged.push(project_id);
+        } else {
+            fleet.project_ids.push(project_id.clone());
+            changed.push(project_id);
+        }
     }
 
-    // Don't add duplicates
-    if !fleet.project_ids.contains(&project_id.to_string()) {
-        fleet.project_ids.push(project_id.to_string());
+    if !changed.is_empty() {
         save(&fleet)?;
     }
 
-    Ok(fleet)
+    Ok(FleetMembershipChange {
+        fleet,
+        changed,
+        unchanged,
+    })
 }
 
-/// Remove a project from a fleet
-pub fn remove_project(fleet_id: &str, project_id: &str) -> Result<Fleet> {
+/// Remove projects from a fleet. Projects that are not members are reported
+/// as unchanged rather than rejected, so removal is safe to repeat.
+pub fn remove_projects(fleet_id: &str, project_ids: &[String]) -> Result<FleetMembershipChange> {
     let mut fleet = load(fleet_id)?;
 
-    fleet.project_ids.retain(|id| id != project_id);
-    save(&fleet)?;
+    let (changed, unchanged): (Vec<String>, Vec<String>) = dedup(project_ids)
+        .into_iter()
+        .partition(|project_id| fleet.project_ids.contains(project_id));
+
+    if !changed.is_empty() {
+        fleet.project_ids.retain(|id| !changed.contains(id));
+        save(&fleet)?;
+    }
+
+    Ok(FleetMembershipChange {
+        fleet,
+        changed,
+        unchanged,
+    })
+}
+
+/// Error with suggestions for the first project ID that does not exist.
+pub fn validate_project_ids(project_ids: &[String]) -> Result<()> {
+    match project_ids.iter().find(|id| !project::exists(id)) {
+        Some(missing) => {
+            let suggestions = config::find_similar_ids::<crate::project::Project>(missing);
+            Err(Error::project_not_found(missing, suggestions))
+        }
+        None => Ok(()),
+    }
+}
 
-    Ok(fleet)
+fn dedup(project_ids: &[String]) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for project_id in project_ids {
+        if !unique.contains(project_id) {
+            unique.push(project_id.clone());
+        }
+    }
+    unique
 }
 
 /// Get all projects in a fleet with full project data
@@ -146,6 +197,66 @@ pub fn component_usage(fleet_id: &str) -> Result<std::collections::HashMap<Strin
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::with_isolated_home;
+
+    fn save_projects(ids: &[&str]) {
+        for id in ids {
+            project::save(&crate::project::Project {
+                id: id.to_string(),
+                ..Default::default()
+            })
+            .expect("save project");
+        }
+    }
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_add_projects() {
+        with_isolated_home(|_| {
+            save_projects(&["alpha", "beta", "gamma"]);
+            save(&Fleet::new("web".to_string(), ids(&["alpha"]))).expect("save fleet");
+
+            let result = add_projects("web", &ids(&["beta", "alpha", "beta", "gamma"])).unwrap();
+
+            assert_eq!(result.changed, ids(&["beta", "gamma"]));
+            assert_eq!(result.unchanged, ids(&["alpha"]));
+            assert_eq!(
+                load("web").unwrap().project_ids,
+                ids(&["alpha", "beta", "gamma"])
+            );
+        });
+    }
+
+    #[test]
+    fn add_projects_rejects_missing_project_without_saving() {
+        with_isolated_home(|_| {
+            save_projects(&["alpha"]);
+            save(&Fleet::new("web".to_string(), vec![])).expect("save fleet");
+
+            let err = add_projects("web", &ids(&["alpha", "missing"])).unwrap_err();
+
+            assert_eq!(err.code.as_str(), "project.not_found");
+            assert!(load("web").unwrap().project_ids.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_remove_projects() {
+        with_isolated_home(|_| {
+            save(&Fleet::new("web".to_string(), ids(&["alpha", "beta"]))).expect("save fleet");
+
+            let result = remove_projects("web", &ids(&["alpha", "other"])).unwrap();
+            assert_eq!(result.changed, ids(&["alpha"]));
+            assert_eq!(result.unchanged, ids(&["other"]));
+
+            let result = remove_projects("web", &ids(&["beta"])).unwrap();
+            assert!(result.fleet.project_ids.is_empty());
+            assert!(load("web").unwrap().project_ids.is_empty());
+        });
+    }
 
     #[test]
     fn fleet_new_has_empty_component_overrides() {
@@ -278,6 +278,8 @@ fn collect_project_component_statuses(
         no_pull: true,
         head: true,
         tagged: false,
+        no_verify: false,
+        step_filter: Default::default(),
     };
 
     match deploy::run(project_id, &config) {
@@ -408,3 +410,101 @@ fn compute_server_summary(
         }
     }
 }
+
+// ============================================================================
+// CSV
+// ============================================================================
+
+impl crate::output::CsvRows for FleetStatusResult {
+    fn csv_header(&self) -> &'static [&'static str] {
+        &[
+            "project_id",
+            "server_id",
+            "component_id",
+            "local_version",
+            "remote_version",
+            "version_source",
+            "drift",
+            "unreleased_commits",
+            "health.uptime",
+            "health.load.one",
+            "health.disk.percent",
+            "health.memory.percent",
+        ]
+    }
+
+    /// One row per project component; a project without components (such as
+    /// `--health-only`) still gets a row for its server health.
+    fn to_csv_rows(&self) -> Vec<serde_json::Value> {
+        let mut rows = Vec::new();
+        for project in &self.projects {
+            let base = serde_json::json!({
+                "project_id": project.project_id,
+                "server_id": project.server_id,
+                "health": project.health,
+            });
+            if project.components.is_empty() {
+                rows.push(base);
+                continue;
+            }
+            for component in &project.components {
+                let mut row = serde_json::to_value(component).unwrap_or_default();
+                if let (Some(row), Some(base)) = (row.as_object_mut(), base.as_object()) {
+                    row.extend(base.clone());
+                }
+                rows.push(row);
+            }
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::server::health::LoadAverage;
+
+    #[test]
+    fn csv_rows_flatten_components_and_health() {
+        let result = FleetStatusResult {
+            projects: vec![
+                FleetProjectStatus {
+                    project_id: "blog".to_string(),
+                    server_id: Some("prod".to_string()),
+                    components: vec![FleetComponentStatus {
+                        component_id: "theme".to_string(),
+                        local_version: Some("1.2.0".to_string()),
+                        remote_version: Some("1.1.0".to_string()),
+                        version_source: "live".to_string(),
+                        drift: FleetComponentDrift::NeedsUpdate,
+                        unreleased_commits: 3,
+                    }],
+                    health: Some(ServerHealth {
+                        uptime: Some("10 days".to_string()),
+                        load: Some(LoadAverage {
+                            one: 0.5,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                },
+                FleetProjectStatus {
+                    project_id: "shop".to_string(),
+                    server_id: None,
+                    components: Vec::new(),
+                    health: None,
+                },
+            ],
+            summary: FleetStatusSummary::default(),
+        };
+
+        assert_eq!(
+            crate::output::render_csv(&result),
+            "project_id,server_id,component_id,local_version,remote_version,version_source,\
+             drift,unreleased_commits,health.uptime,health.load.one,health.disk.percent,\
+             health.memory.percent\n\
+             blog,prod,theme,1.2.0,1.1.0,live,needs_update,3,10 days,0.5,,\n\
+             shop,,,,,,,,,,,\n"
+        );
+    }
+}
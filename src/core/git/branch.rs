@@ -0,0 +1,263 @@
+//! Branch listing, creation, and checkout (`git branch`, `git checkout`).
+//!
+//! Each operation reports the branch checked out afterwards so callers can
+//! confirm a switch without a second `git status`.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::{current_branch, execute_git, resolve_target};
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GitBranch {
+    pub name: String,
+    pub current: bool,
+    /// Short SHA of the branch tip.
+    pub commit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitBranchOutput {
+    pub component_id: String,
+    pub path: String,
+    pub action: String,
+    /// Branch checked out after the action; `None` on a detached HEAD.
+    pub current_branch: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub branches: Vec<GitBranch>,
+}
+
+/// Local branches of the repo at `path`, sorted by name.
+pub fn branch_list(path: &str) -> Result<Vec<GitBranch>> {
+    let stdout = run(
+        path,
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)%00%(HEAD)%00%(objectname:short)%00%(upstream:short)",
+            "refs/heads",
+        ],
+    )?;
+    Ok(stdout.lines().filter_map(parse_branch_line).collect())
+}
+
+/// Create branch `name` at `from` (default: `HEAD`) without switching to it.
+pub fn branch_create(path: &str, name: &str, from: Option<&str>) -> Result<GitBranch> {
+    let valid = execute_git(path, &["check-ref-format", "--branch", name])
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+    if !valid {
+        return Err(Error::validation_invalid_argument(
+            "name",
+            format!("'{}' is not a valid branch name", name),
+            Some(name.to_string()),
+            None,
+        ));
+    }
+
+    let mut args = vec!["branch", "--end-of-options", name];
+    args.extend(from);
+    run(path, &args)?;
+
+    branch_list(path)?
+        .into_iter()
+        .find(|branch| branch.name == name)
+        .ok_or_else(|| Error::git_command_failed(format!("Branch '{}' was not created", name)))
+}
+
+/// Check out `reference` (a branch, tag, or commit) and return the branch
+/// checked out afterwards, or `None` for a detached HEAD.
+///
+/// A checkout that would overwrite local changes fails with the blocking
+/// files listed.
+pub fn checkout(path: &str, reference: &str) -> Result<Option<String>> {
+    if reference.is_empty() || reference.starts_with('-') {
+        return Err(Error::validation_invalid_argument(
+            "ref",
+            format!("'{}' is not a valid ref", reference),
+            Some(reference.to_string()),
+            None,
+        ));
+    }
+    // `--` keeps a ref that shares a name with a file from restoring the file.
+    let output = execute_git(path, &["checkout", reference, "--"])
+        .map_err(|e| Error::git_command_failed(e.to_string()))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let files = blocking_files(&stderr);
+        if !files.is_empty() {
+            return Err(Error::git_checkout_blocked(reference, files));
+        }
+        return Err(Error::git_command_failed(format!(
+            "git checkout {} failed: {}",
+            reference,
+            stderr.trim()
+        )));
+    }
+    Ok(current_branch(Path::new(path)))
+}
+
+/// [`branch_list`] for a component, or the checkout at `path_override`.
+pub fn branch_list_at(
+    component_id: Option<&str>,
+    path_override: Option<&str>,
+) -> Result<GitBranchOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    let branches = branch_list(&path)?;
+    Ok(output(id, path, "branch.list", branches))
+}
+
+/// [`branch_create`] for a component, or the checkout at `path_override`.
+pub fn branch_create_at(
+    component_id: Option<&str>,
+    name: &str,
+    from: Option<&str>,
+    path_override: Option<&str>,
+) -> Result<GitBranchOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    let branch = branch_create(&path, name, from)?;
+    Ok(output(id, path, "branch.create", vec![branch]))
+}
+
+/// [`checkout`] for a component, or the checkout at `path_override`.
+pub fn checkout_at(
+    component_id: Option<&str>,
+    reference: &str,
+    path_override: Option<&str>,
+) -> Result<GitBranchOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    checkout(&path, reference)?;
+    Ok(output(id, path, "checkout", Vec::new()))
+}
+
+fn output(id: String, path: String, action: &str, branches: Vec<GitBranch>) -> GitBranchOutput {
+    GitBranchOutput {
+        current_branch: current_branch(Path::new(&path)),
+        component_id: id,
+        path,
+        action: action.to_string(),
+        branches,
+    }
+}
+
+fn run(path: &str, args: &[&str]) -> Result<String> {
+    let output = execute_git(path, args).map_err(|e| Error::git_command_failed(e.to_string()))?;
+    if !output.status.success() {
+        return Err(Error::git_command_failed(format!(
+            "git {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+fn parse_branch_line(line: &str) -> Option<GitBranch> {
+    let mut fields = line.split('\0');
+    let name = fields.next().filter(|name| !name.is_empty())?;
+    let current = fields.next() == Some("*");
+    let commit = fields.next().unwrap_or_default();
+    let upstream = fields.next().filter(|upstream| !upstream.is_empty());
+    Some(GitBranch {
+        name: name.to_string(),
+        current,
+        commit: commit.to_string(),
+        upstream: upstream.map(str::to_string),
+    })
+}
+
+/// Files git lists under "... would be overwritten by checkout:".
+fn blocking_files(stderr: &str) -> Vec<String> {
+    let mut files = Vec::new();
+    let mut in_list = false;
+    for line in stderr.lines() {
+        if line.contains("would be overwritten by checkout") {
+            in_list = true;
+        } else if in_list && line.starts_with(char::is_whitespace) {
+            files.push(line.trim().to_string());
+        } else {
+            in_list = false;
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+
+    fn git(path: &str, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .expect("run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo() -> (tempfile::TempDir, String) {
+        let dir = tempfile::TempDir::new().expect("tempdir");
+        let path = dir.path().to_string_lossy().to_string();
+        git(&path, &["init", "-q", "-b", "main"]);
+        git(&path, &["config", "user.email", "test@test.com"]);
+        git(&path, &["config", "user.name", "Test"]);
+        fs::write(dir.path().join("README.md"), "initial\n").unwrap();
+        git(&path, &["add", "."]);
+        git(&path, &["commit", "-q", "-m", "initial"]);
+        (dir, path)
+    }
+
+    #[test]
+    fn test_branch_create_and_checkout() {
+        let (_dir, path) = init_repo();
+
+        let created = branch_create(&path, "feature", None).unwrap();
+        assert_eq!(created.name, "feature");
+        assert!(!created.current);
+
+        assert_eq!(
+            checkout(&path, "feature").unwrap().as_deref(),
+            Some("feature")
+        );
+        let names: Vec<(String, bool)> = branch_list(&path)
+            .unwrap()
+            .into_iter()
+            .map(|branch| (branch.name, branch.current))
+            .collect();
+        assert_eq!(
+            names,
+            vec![("feature".to_string(), true), ("main".to_string(), false)]
+        );
+
+        let head = super::super::get_head_commit(&path).unwrap();
+        assert_eq!(checkout(&path, &head).unwrap(), None);
+    }
+
+    #[test]
+    fn checkout_over_local_changes_lists_blocking_files() {
+        let (dir, path) = init_repo();
+        git(&path, &["checkout", "-q", "-b", "other"]);
+        fs::write(dir.path().join("README.md"), "other\n").unwrap();
+        git(&path, &["commit", "-q", "-am", "other"]);
+        git(&path, &["checkout", "-q", "main"]);
+        fs::write(dir.path().join("README.md"), "local edit\n").unwrap();
+
+        let err = checkout(&path, "other").unwrap_err();
+
+        assert_eq!(err.code.as_str(), "git.command_failed");
+        assert_eq!(err.details["files"], serde_json::json!(["README.md"]));
+        assert_eq!(current_branch(Path::new(&path)).as_deref(), Some("main"));
+    }
+
+    #[test]
+    fn branch_create_rejects_invalid_names() {
+        let (_dir, path) = init_repo();
+        let err = branch_create(&path, "bad..name", None).unwrap_err();
+        assert_eq!(err.code.as_str(), "validation.invalid_argument");
+    }
+}
@@ -3,7 +3,7 @@ use semver::Version;
 use serde::Serialize;
 
 use crate::engine::command;
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 // Docs file patterns for categorizing commits
 const DOCS_FILE_EXTENSIONS: [&str; 1] = [".md"];
@@ -327,6 +327,75 @@ pub fn get_latest_tag_with_prefix(path: &str, tag_prefix: Option<&str>) -> Resul
         .map(|(tag, _)| tag))
 }
 
+/// Placeholder marking the version in a component's `tag_pattern`.
+pub const TAG_PATTERN_VERSION: &str = "{{version}}";
+
+/// Get the latest tag of the form `pattern`, where [`TAG_PATTERN_VERSION`]
+/// stands for an exact semver core: `component-v{{version}}` matches
+/// `component-v1.2.3`, `release/{{version}}` matches `release/1.2.3`.
+///
+/// Tags in other namespaces are ignored, so monorepos with per-component
+/// tags resolve each component's own last release.
+pub fn get_latest_tag_matching(path: &str, pattern: &str) -> Result<Option<String>> {
+    validate_tag_pattern(pattern)?;
+    let Some(tags) = command::run_in_optional(
+        path,
+        "git",
+        &["tag", "--merged", "HEAD", "--sort=-v:refname", "--list"],
+    ) else {
+        return Ok(None);
+    };
+
+    Ok(tags
+        .lines()
+        .filter_map(|tag| {
+            let tag = tag.trim();
+            version_from_tag_pattern(tag, pattern).map(|version| (tag.to_string(), version))
+        })
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(tag, _)| tag))
+}
+
+/// [`get_latest_tag_matching`] when a pattern is configured, otherwise the
+/// newest exact `v1.2.3`/`1.2.3` tag.
+pub fn get_latest_tag_for(path: &str, tag_pattern: Option<&str>) -> Result<Option<String>> {
+    match tag_pattern {
+        Some(pattern) => get_latest_tag_matching(path, pattern),
+        None => get_latest_tag(path),
+    }
+}
+
+/// The version a tag carries under `pattern`, if the tag has that shape.
+pub fn version_from_tag_pattern(tag: &str, pattern: &str) -> Option<Version> {
+    let (prefix, suffix) = pattern.split_once(TAG_PATTERN_VERSION)?;
+    let version = tag.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    if !is_exact_semver_core(version) {
+        return None;
+    }
+    Version::parse(version).ok()
+}
+
+/// Render the tag `pattern` gives `version`.
+pub fn format_tag_pattern(pattern: &str, version: &str) -> String {
+    pattern.replace(TAG_PATTERN_VERSION, version)
+}
+
+pub(crate) fn validate_tag_pattern(pattern: &str) -> Result<()> {
+    if pattern.matches(TAG_PATTERN_VERSION).count() != 1 {
+        return Err(Error::validation_invalid_argument(
+            "tag_pattern",
+            format!(
+                "Tag pattern '{}' must contain {} exactly once",
+                pattern, TAG_PATTERN_VERSION
+            ),
+            Some(pattern.to_string()),
+            None,
+        )
+        .with_hint("Examples: v{{version}}, my-component-v{{version}}, release/{{version}}"));
+    }
+    Ok(())
+}
+
 fn exact_release_version_from_tag(tag: &str, tag_prefix: Option<&str>) -> Option<Version> {
     let tag = match tag_prefix {
         Some(prefix) => tag.strip_prefix(&format!("{}-", prefix))?,
@@ -482,6 +551,32 @@ pub fn get_commits_since_tag_for_path(
     Ok(parse_commit_records(&stdout))
 }
 
+/// Get commits whose commit date falls inside `window`, optionally scoped to
+/// `path_prefix` like [`get_commits_since_tag_for_path`]. Newest first.
+pub fn get_commits_in_window(
+    path: &str,
+    window: &super::CommitWindow,
+    path_prefix: Option<&str>,
+) -> Result<Vec<CommitInfo>> {
+    let mut args = vec!["log".to_string(), "--no-merges".to_string()];
+    args.extend(window.git_log_args());
+    args.push("HEAD".to_string());
+    args.push(format!(
+        "--format=%h{}%s{}%b{}",
+        FIELD_SEP, FIELD_SEP, RECORD_SEP
+    ));
+
+    if let Some(prefix) = path_prefix {
+        args.push("--".to_string());
+        args.push(prefix.to_string());
+    }
+
+    let args_refs: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let stdout = command::run_in(path, "git", &args_refs, "git log")?;
+
+    Ok(parse_commit_records(&stdout))
+}
+
 /// Counts of commits categorized by type.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct CommitCounts {
@@ -1087,6 +1182,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn latest_tag_matching_ignores_other_tag_namespaces() {
+        let (dir, path) = init_repo();
+        git(&path, &["tag", "api-v1.4.0"]);
+        git(&path, &["tag", "release/1.2.0"]);
+        commit_file(&dir, &path, "api.txt", "api\n", "fix: api");
+        git(&path, &["tag", "v9.0.0"]);
+        git(&path, &["tag", "web-v3.0.0"]);
+        git(&path, &["tag", "api-v2"]);
+        git(&path, &["tag", "release/1.10.0"]);
+
+        assert_eq!(
+            get_latest_tag_matching(&path, "api-v{{version}}").unwrap(),
+            Some("api-v1.4.0".to_string())
+        );
+        assert_eq!(
+            get_latest_tag_matching(&path, "release/{{version}}").unwrap(),
+            Some("release/1.10.0".to_string())
+        );
+        assert_eq!(
+            get_latest_tag_for(&path, None).unwrap(),
+            Some("v9.0.0".to_string())
+        );
+        assert_eq!(
+            get_latest_tag_matching(&path, "docs-v{{version}}").unwrap(),
+            None
+        );
+        assert!(get_latest_tag_matching(&path, "api-latest").is_err());
+    }
+
+    #[test]
+    fn version_from_tag_pattern_requires_exact_shape() {
+        let pattern = "api-v{{version}}";
+        assert_eq!(
+            version_from_tag_pattern("api-v1.2.3", pattern),
+            Some(Version::new(1, 2, 3))
+        );
+        assert!(version_from_tag_pattern("api-1.2.3", pattern).is_none());
+        assert!(version_from_tag_pattern("web-api-v1.2.3", pattern).is_none());
+        assert!(version_from_tag_pattern("api-v1.2.3-rc1", pattern).is_none());
+        assert_eq!(format_tag_pattern(pattern, "1.2.3"), "api-v1.2.3");
+    }
+
     #[test]
     fn monorepo_context_format_tag() {
         let ctx = MonorepoContext {
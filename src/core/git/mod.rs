@@ -1,22 +1,29 @@
+mod branch;
 mod changes;
 mod commits;
 mod github;
 mod operations;
 mod pr_policy;
 mod primitives;
+mod stale_branches;
+mod window;
 
+pub use branch::*;
 pub use changes::*;
 pub use commits::*;
 pub use github::*;
 pub use operations::*;
 pub use pr_policy::*;
 pub use primitives::*;
+pub use stale_branches::*;
+pub use window::*;
 
 use std::process::Command;
 
 use crate::error::Error;
 
 fn execute_git(path: &str, args: &[&str]) -> std::io::Result<std::process::Output> {
+    let _span = crate::profile::span("git");
     Command::new("git").args(args).current_dir(path).output()
 }
 
@@ -71,6 +71,7 @@ pub fn is_workdir_clean_or_not_git(path: &Path) -> bool {
 
 /// Run a git command in a repository and return stdout.
 pub fn run_git(git_root: &Path, args: &[&str], context: &str) -> Result<String> {
+    let _span = crate::profile::span("git");
     let output = Command::new("git")
         .args(args)
         .current_dir(git_root)
@@ -103,7 +104,7 @@ pub fn current_branch(git_root: &Path) -> Option<String> {
     .filter(|value| !value.is_empty())
 }
 
-fn default_remote_branch(git_root: &Path) -> Option<String> {
+pub(crate) fn default_remote_branch(git_root: &Path) -> Option<String> {
     run_git(
         git_root,
         &[
@@ -0,0 +1,295 @@
+//! Stale local branches (`git stale-branches`).
+//!
+//! Reports branches that are fully merged into the default branch or whose
+//! upstream is gone, each with the command that would delete it. Homeboy
+//! never deletes anything itself.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::{current_branch, default_remote_branch, resolve_target, run_git};
+use crate::engine::shell;
+use crate::error::Result;
+
+/// Why a branch is reported as stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StaleBranchReason {
+    /// Fully merged into the default branch.
+    Merged,
+    /// Its upstream branch no longer exists on the remote.
+    UpstreamGone,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StaleBranch {
+    pub name: String,
+    /// Short SHA of the branch tip.
+    pub commit: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream: Option<String>,
+    pub reasons: Vec<StaleBranchReason>,
+    /// Command that would delete the branch. Homeboy never runs it.
+    pub suggested_action: String,
+}
+
+/// Stale branches of one checkout.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentStaleBranches {
+    pub component_id: String,
+    pub path: String,
+    /// Branch merges are checked against; `None` when none could be found.
+    pub default_branch: Option<String>,
+    pub branches: Vec<StaleBranch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GitStaleBranchesOutput {
+    pub action: String,
+    pub components: Vec<ComponentStaleBranches>,
+    pub stale_count: usize,
+}
+
+/// Local branches of the repo at `path` that are fully merged into the
+/// default branch or whose upstream is gone, plus that default branch.
+///
+/// The current branch and the default branch itself are never reported.
+pub fn stale_branches(path: &str) -> Result<(Option<String>, Vec<StaleBranch>)> {
+    let root = Path::new(path);
+    let default_branch = default_branch(root);
+    let current = current_branch(root);
+    let merged: Vec<String> = match &default_branch {
+        Some(base) => run_git(
+            root,
+            &[
+                "for-each-ref",
+                "--format=%(refname:short)",
+                "--merged",
+                base,
+                "refs/heads",
+            ],
+            "git merged branches",
+        )?
+        .lines()
+        .map(str::to_string)
+        .collect(),
+        None => Vec::new(),
+    };
+    let default_local = default_branch
+        .as_deref()
+        .map(|base| base.strip_prefix("origin/").unwrap_or(base));
+
+    let stdout = run_git(
+        root,
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)%00%(objectname:short)%00%(upstream:short)%00%(upstream:track)",
+            "refs/heads",
+        ],
+        "git branch list",
+    )?;
+    let mut branches = Vec::new();
+    for line in stdout.lines() {
+        let mut fields = line.split('\0');
+        let Some(name) = fields.next().filter(|name| !name.is_empty()) else {
+            continue;
+        };
+        if current.as_deref() == Some(name) || default_local == Some(name) {
+            continue;
+        }
+        let commit = fields.next().unwrap_or_default();
+        let upstream = fields.next().filter(|upstream| !upstream.is_empty());
+        let gone = fields.next().is_some_and(|track| track.contains("gone"));
+
+        let mut reasons = Vec::new();
+        if merged.iter().any(|merged| merged == name) {
+            reasons.push(StaleBranchReason::Merged);
+        }
+        if gone {
+            reasons.push(StaleBranchReason::UpstreamGone);
+        }
+        if reasons.is_empty() {
+            continue;
+        }
+        // `-d` refuses unmerged work; a gone-only branch needs `-D`.
+        let flag = if reasons.contains(&StaleBranchReason::Merged) {
+            "-d"
+        } else {
+            "-D"
+        };
+        branches.push(StaleBranch {
+            suggested_action: format!(
+                "git -C {} branch {} {}",
+                shell::quote_path(path),
+                flag,
+                shell::quote_arg(name)
+            ),
+            name: name.to_string(),
+            commit: commit.to_string(),
+            upstream: upstream.map(str::to_string),
+            reasons,
+        });
+    }
+    Ok((default_branch, branches))
+}
+
+/// `origin/HEAD` when the remote advertises one, else a local `main` or
+/// `master`.
+fn default_branch(root: &Path) -> Option<String> {
+    default_remote_branch(root).or_else(|| {
+        ["main", "master"]
+            .into_iter()
+            .find(|name| {
+                run_git(
+                    root,
+                    &[
+                        "rev-parse",
+                        "--verify",
+                        "--quiet",
+                        &format!("refs/heads/{}", name),
+                    ],
+                    "git default branch",
+                )
+                .is_ok()
+            })
+            .map(str::to_string)
+    })
+}
+
+/// [`stale_branches`] for a component, or the checkout at `path_override`.
+pub fn stale_branches_at(
+    component_id: Option<&str>,
+    path_override: Option<&str>,
+) -> Result<GitStaleBranchesOutput> {
+    let (id, path) = resolve_target(component_id, path_override)?;
+    let (default_branch, branches) = stale_branches(&path)?;
+    Ok(stale_output(vec![ComponentStaleBranches {
+        component_id: id,
+        path,
+        default_branch,
+        branches,
+        error: None,
+    }]))
+}
+
+/// [`stale_branches`] for every registered component with a local git
+/// checkout. A checkout git cannot read is reported with `error` instead of
+/// failing the whole run.
+pub fn stale_branches_all() -> Result<GitStaleBranchesOutput> {
+    let mut components = Vec::new();
+    for component in crate::component::list()? {
+        if !Path::new(&component.local_path).join(".git").exists() {
+            continue;
+        }
+        let entry = match stale_branches(&component.local_path) {
+            Ok((default_branch, branches)) => ComponentStaleBranches {
+                component_id: component.id,
+                path: component.local_path,
+                default_branch,
+                branches,
+                error: None,
+            },
+            Err(err) => ComponentStaleBranches {
+                component_id: component.id,
+                path: component.local_path,
+                default_branch: None,
+                branches: Vec::new(),
+                error: Some(err.message),
+            },
+        };
+        components.push(entry);
+    }
+    Ok(stale_output(components))
+}
+
+fn stale_output(components: Vec<ComponentStaleBranches>) -> GitStaleBranchesOutput {
+    GitStaleBranchesOutput {
+        action: "stale-branches".to_string(),
+        stale_count: components.iter().map(|c| c.branches.len()).sum(),
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::process::Command;
+
+    fn git(path: &str, args: &[&str]) {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(path)
+            .output()
+            .expect("run git");
+        assert!(output.status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn stale_branches_reports_merged_and_gone_branches() {
+        let dir = tempfile::TempDir::new().expect("tempdir");
+        let checkout = dir.path().join("my repo");
+        fs::create_dir(&checkout).unwrap();
+        let path = checkout.to_string_lossy().to_string();
+        git(&path, &["init", "-q", "-b", "main"]);
+        git(&path, &["config", "user.email", "test@test.com"]);
+        git(&path, &["config", "user.name", "Test"]);
+        fs::write(checkout.join("README.md"), "initial\n").unwrap();
+        git(&path, &["add", "."]);
+        git(&path, &["commit", "-q", "-m", "initial"]);
+
+        git(&path, &["branch", "merged"]);
+        git(&path, &["checkout", "-q", "-b", "unmerged"]);
+        fs::write(checkout.join("README.md"), "unmerged\n").unwrap();
+        git(&path, &["commit", "-q", "-am", "unmerged"]);
+        git(&path, &["checkout", "-q", "-b", "gone$x"]);
+        // A remote whose tracking ref for the branch was never fetched.
+        git(
+            &path,
+            &[
+                "remote",
+                "add",
+                "origin",
+                "https://example.invalid/repo.git",
+            ],
+        );
+        git(&path, &["config", "branch.gone$x.remote", "origin"]);
+        git(
+            &path,
+            &["config", "branch.gone$x.merge", "refs/heads/gone$x"],
+        );
+        git(&path, &["checkout", "-q", "main"]);
+
+        let (default_branch, branches) = stale_branches(&path).unwrap();
+
+        assert_eq!(default_branch.as_deref(), Some("main"));
+        let found: Vec<(&str, &[StaleBranchReason], &str)> = branches
+            .iter()
+            .map(|branch| {
+                (
+                    branch.name.as_str(),
+                    branch.reasons.as_slice(),
+                    branch.suggested_action.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "gone$x",
+                    &[StaleBranchReason::UpstreamGone][..],
+                    format!("git -C '{}' branch -D 'gone$x'", path).as_str()
+                ),
+                (
+                    "merged",
+                    &[StaleBranchReason::Merged][..],
+                    format!("git -C '{}' branch -d merged", path).as_str()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Time windows for commit listing (`changes --since 2w --until 2025-03-01`).
+//!
+//! A window replaces the tag baseline: commits are selected by commit date
+//! instead of by release boundary, which is what period reports want.
+
+use chrono::{Local, Months, NaiveDate};
+
+use crate::error::{Error, Result};
+
+/// Inclusive commit date range passed to `git log --since/--until`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommitWindow {
+    pub since: Option<NaiveDate>,
+    pub until: Option<NaiveDate>,
+}
+
+impl CommitWindow {
+    /// Parse `--since`/`--until` values. Returns `None` when neither is set.
+    pub fn parse(since: Option<&str>, until: Option<&str>) -> Result<Option<Self>> {
+        Self::parse_relative_to(since, until, Local::now().date_naive())
+    }
+
+    fn parse_relative_to(
+        since: Option<&str>,
+        until: Option<&str>,
+        today: NaiveDate,
+    ) -> Result<Option<Self>> {
+        if since.is_none() && until.is_none() {
+            return Ok(None);
+        }
+
+        let since = since
+            .map(|value| parse_bound("since", value, today))
+            .transpose()?;
+        let until = until
+            .map(|value| parse_bound("until", value, today))
+            .transpose()?;
+
+        if let (Some(since), Some(until)) = (since, until) {
+            if since > until {
+                return Err(Error::validation_invalid_argument(
+                    "until",
+                    format!("--until {} is before --since {}", until, since),
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        Ok(Some(Self { since, until }))
+    }
+
+    /// `git log` arguments for this window. Bounds cover whole days.
+    pub fn git_log_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(since) = self.since {
+            args.push(format!("--since={} 00:00:00", since));
+        }
+        if let Some(until) = self.until {
+            args.push(format!("--until={} 23:59:59", until));
+        }
+        args
+    }
+
+    /// Human-readable form used as the changes `baseline_ref`.
+    pub fn describe(&self) -> String {
+        match (self.since, self.until) {
+            (Some(since), Some(until)) => format!("{}..{}", since, until),
+            (Some(since), None) => format!("{}..", since),
+            (None, Some(until)) => format!("..{}", until),
+            (None, None) => "..".to_string(),
+        }
+    }
+}
+
+/// Parse a date (`YYYY-MM-DD`) or a duration back from `today`
+/// (`3d`, `2w`, `6m`, `1y`).
+pub fn parse_time_spec(value: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let value = value.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    let unit = value.chars().last()?;
+    let amount: u32 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    match unit.to_ascii_lowercase() {
+        'd' => today.checked_sub_days(chrono::Days::new(amount.into())),
+        'w' => today.checked_sub_days(chrono::Days::new(u64::from(amount) * 7)),
+        'm' => today.checked_sub_months(Months::new(amount)),
+        'y' => today.checked_sub_months(Months::new(amount.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+/// Whether `value` is a date or duration rather than a tag name.
+pub fn is_time_spec(value: &str) -> bool {
+    parse_time_spec(value, Local::now().date_naive()).is_some()
+}
+
+fn parse_bound(field: &str, value: &str, today: NaiveDate) -> Result<NaiveDate> {
+    parse_time_spec(value, today).ok_or_else(|| {
+        Error::validation_invalid_argument(
+            field,
+            format!("'{}' is not a date or duration", value),
+            None,
+            Some(vec![
+                "Use a date like 2025-03-01".to_string(),
+                "Or a duration back from today: 3d, 2w, 6m, 1y".to_string(),
+            ]),
+        )
+    })
+}
+
+/// Pick the baseline for `changes` from its flags: a tag (`since_tag`) or a
+/// time window (`since`/`until`), never both.
+///
+/// For compatibility with the old tag-only `--since`, a `since` value that
+/// is neither a date nor a duration is treated as a tag name.
+pub fn resolve_changes_baseline(
+    since_tag: Option<&str>,
+    since: Option<&str>,
+    until: Option<&str>,
+) -> Result<(Option<String>, Option<CommitWindow>)> {
+    let (tag, since) = match (since_tag, since) {
+        (None, Some(value)) if !is_time_spec(value) => (Some(value), None),
+        pair => pair,
+    };
+
+    if let Some(tag) = tag {
+        if since.is_some() || until.is_some() {
+            return Err(baseline_conflict_error(tag));
+        }
+        return Ok((Some(tag.to_string()), None));
+    }
+
+    Ok((None, CommitWindow::parse(since, until)?))
+}
+
+pub(crate) fn baseline_conflict_error(tag: &str) -> Error {
+    Error::validation_invalid_argument(
+        "since",
+        format!(
+            "Choose one baseline: tag '{}' or a --since/--until time window, not both",
+            tag
+        ),
+        None,
+        Some(vec![
+            "Use --since-tag <tag> to list commits after a release tag".to_string(),
+            "Use --since <date|duration> [--until <date>] to list commits by date".to_string(),
+        ]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_parse_time_spec() {
+        let today = date("2025-03-15");
+        assert_eq!(
+            parse_time_spec("2025-01-02", today),
+            Some(date("2025-01-02"))
+        );
+        assert_eq!(parse_time_spec("3d", today), Some(date("2025-03-12")));
+        assert_eq!(parse_time_spec("2w", today), Some(date("2025-03-01")));
+        assert_eq!(parse_time_spec("1m", today), Some(date("2025-02-15")));
+        assert_eq!(parse_time_spec("1y", today), Some(date("2024-03-15")));
+        assert_eq!(parse_time_spec("v1.2.0", today), None);
+        assert_eq!(parse_time_spec("w", today), None);
+    }
+
+    #[test]
+    fn test_window_git_log_args() {
+        let window =
+            CommitWindow::parse_relative_to(Some("2w"), Some("2025-03-10"), date("2025-03-15"))
+                .unwrap()
+                .unwrap();
+        assert_eq!(
+            window.git_log_args(),
+            vec![
+                "--since=2025-03-01 00:00:00".to_string(),
+                "--until=2025-03-10 23:59:59".to_string(),
+            ]
+        );
+        assert_eq!(window.describe(), "2025-03-01..2025-03-10");
+    }
+
+    #[test]
+    fn test_window_rejects_inverted_range() {
+        let err = CommitWindow::parse_relative_to(
+            Some("2025-03-10"),
+            Some("2025-03-01"),
+            date("2025-03-15"),
+        )
+        .unwrap_err();
+        assert!(err.message.contains("before"));
+    }
+
+    #[test]
+    fn test_resolve_changes_baseline() {
+        let (tag, window) = resolve_changes_baseline(None, Some("v1.2.0"), None).unwrap();
+        assert_eq!(tag.as_deref(), Some("v1.2.0"));
+        assert!(window.is_none());
+
+        let (tag, window) = resolve_changes_baseline(None, Some("2025-01-01"), None).unwrap();
+        assert!(tag.is_none());
+        assert_eq!(window.unwrap().since, Some(date("2025-01-01")));
+
+        let err = resolve_changes_baseline(Some("v1.2.0"), Some("2w"), None).unwrap_err();
+        assert!(err.message.contains("Choose one"));
+        let err = resolve_changes_baseline(None, Some("v1.2.0"), Some("2025-01-01")).unwrap_err();
+        assert!(err.message.contains("Choose one"));
+    }
+}
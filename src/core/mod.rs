@@ -25,6 +25,7 @@ pub mod keychain;
 pub mod observation;
 pub mod output;
 pub mod plan;
+pub mod profile;
 pub mod project;
 pub mod quality;
 pub mod refactor;
@@ -36,6 +37,7 @@ pub mod self_status;
 pub mod server;
 pub mod source_snapshot;
 pub mod stack;
+pub mod status_log;
 pub mod top_n;
 pub mod triage;
 pub mod update_check_cache;
@@ -61,7 +63,7 @@ pub use server::auth;
 // Re-export common types for convenience
 pub use error::{Error, ErrorCode, Result};
 pub use output::{
-    BatchResult, BatchResultItem, BulkResult, BulkSummary, CreateOutput, CreateResult,
+    BatchResult, BatchResultItem, BulkResult, BulkSummary, CreateOutput, CreateResult, CsvRows,
     EntityCrudOutput, ItemOutcome, MergeOutput, MergeResult, NoExtra, ObservationOutputDetails,
     ObservationOutputMetadata, RemoveResult,
 };
@@ -132,9 +132,12 @@ pub fn finding_record_from_lint(run_id: &str, finding: &LintFinding) -> NewFindi
     NewFindingRecord {
         run_id: run_id.to_string(),
         tool: finding.tool.clone().unwrap_or_else(|| "lint".to_string()),
-        rule: lint_extra_string(finding, "rule").or_else(|| Some(finding.category.clone())),
+        rule: finding
+            .rule
+            .clone()
+            .or_else(|| Some(finding.category.clone())),
         file: finding.file.clone(),
-        line: lint_extra_i64(finding, "line"),
+        line: finding.line.map(|line| line as i64),
         severity: finding.severity.clone(),
         fingerprint: Some(finding.id.clone()),
         message: finding.message.clone(),
@@ -326,14 +329,6 @@ fn annotation_fingerprint(annotation: &AnnotationFindingRecord) -> Option<String
     ))
 }
 
-fn lint_extra_string(finding: &LintFinding, key: &str) -> Option<String> {
-    finding.extra.get(key)?.as_str().map(str::to_string)
-}
-
-fn lint_extra_i64(finding: &LintFinding, key: &str) -> Option<i64> {
-    finding.extra.get(key)?.as_i64()
-}
-
 fn lint_extra_bool(finding: &LintFinding, key: &str) -> Option<bool> {
     match finding.extra.get(key)? {
         Value::Bool(value) => Some(*value),
@@ -408,11 +403,10 @@ mod tests {
             tool: Some("phpcs".to_string()),
             file: Some("src/lib.rs".to_string()),
             severity: Some("error".to_string()),
-            extra: BTreeMap::from([
-                ("line".to_string(), serde_json::json!(10)),
-                ("rule".to_string(), serde_json::json!("WordPress.Security")),
-                ("fixable".to_string(), serde_json::json!(true)),
-            ]),
+            line: Some(10),
+            column: None,
+            rule: Some("WordPress.Security".to_string()),
+            extra: BTreeMap::from([("fixable".to_string(), serde_json::json!(true))]),
         };
 
         let record = finding_record_from_lint("run-1", &finding);
@@ -575,6 +569,9 @@ mod tests {
             tool: tool.map(str::to_string),
             file: Some("src/lib.rs".to_string()),
             severity: Some("error".to_string()),
+            line: None,
+            column: None,
+            rule: None,
             extra: BTreeMap::new(),
         }
     }
@@ -6,6 +6,13 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::engine::shell;
+
+pub mod color;
+mod redact;
+
+pub use redact::{redact, redact_secret, RedactionRules, REDACTED};
+
 // ============================================================================
 // Observation-backed Outputs
 // ============================================================================
@@ -73,6 +80,156 @@ mod tests {
         );
     }
 
+    struct Inventory(Vec<serde_json::Value>);
+
+    impl CsvRows for Inventory {
+        fn csv_header(&self) -> &'static [&'static str] {
+            &["id", "port", "auth.mode", "tags", "env"]
+        }
+
+        fn to_csv_rows(&self) -> Vec<serde_json::Value> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let inventory = Inventory(vec![
+            serde_json::json!({
+                "id": "web, \"main\"",
+                "port": 22,
+                "auth": { "mode": "key" },
+                "tags": ["prod", "eu"],
+                "env": { "A": "1" },
+            }),
+            serde_json::json!({ "id": "db", "tags": [] }),
+        ]);
+
+        assert_eq!(
+            render_csv(&inventory),
+            "id,port,auth.mode,tags,env\n\"web, \"\"main\"\"\",22,key,prod;eu,\ndb,,,,\n"
+        );
+        assert_eq!(
+            render_csv(&Inventory(Vec::new())),
+            "id,port,auth.mode,tags,env\n"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let inventory = Inventory(vec![
+            serde_json::json!({ "id": "web", "port": 22, "auth": { "mode": "key" } }),
+            serde_json::json!({ "id": "database-primary", "tags": ["prod", "eu"] }),
+        ]);
+        let columns = vec!["id".to_string(), "auth.mode".to_string()];
+
+        assert_eq!(
+            render_table(&inventory, &columns, None).unwrap(),
+            "id                auth.mode\nweb               key\ndatabase-primary\n"
+        );
+        assert_eq!(
+            render_table(&inventory, &columns, Some(16)).unwrap(),
+            "id       auth.m…\nweb      key\ndataba…\n"
+        );
+
+        let err = render_table(&inventory, &["nope".to_string()], None).unwrap_err();
+        assert!(err.message.contains("'nope'"));
+    }
+
+    #[test]
+    fn test_select_fields() {
+        let result = serde_json::json!({
+            "servers": [
+                { "id": "web", "host": "a", "auth": { "mode": "key", "user": "x" } },
+                { "id": "db", "host": "b" },
+            ],
+            "count": 2,
+        });
+        let paths = |list: &[&str]| list.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+
+        let selected = select_fields(
+            &result,
+            &paths(&["servers.id", "servers.auth.mode", "count.x", "nope"]),
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected,
+            serde_json::json!({
+                "servers": [
+                    { "id": "web", "auth": { "mode": "key" } },
+                    { "id": "db" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn strict_select_names_missing_fields() {
+        let result = serde_json::json!([{ "id": "web" }, { "id": "db" }]);
+
+        let selected = select_fields(&result, &["id".to_string()], true).unwrap();
+        let empty = serde_json::json!({ "servers": [] });
+        assert!(select_fields(&empty, &["servers.id".to_string()], true).is_ok());
+        assert_eq!(
+            selected,
+            serde_json::json!([{ "id": "web" }, { "id": "db" }])
+        );
+
+        let err =
+            select_fields(&result, &["id".to_string(), "port".to_string()], true).unwrap_err();
+        assert!(err.message.contains("'port'"));
+    }
+
+    #[test]
+    fn test_raw_scalar() {
+        let result = serde_json::json!({ "next": "1.2.0" });
+        assert_eq!(raw_scalar(&result).unwrap(), "1.2.0");
+        assert_eq!(
+            raw_scalar(&serde_json::json!({ "summary": { "count": 3 } })).unwrap(),
+            "3"
+        );
+        assert_eq!(raw_scalar(&serde_json::json!(true)).unwrap(), "true");
+
+        let err = raw_scalar(&serde_json::json!({ "id": "web", "host": "a" })).unwrap_err();
+        assert!(err.message.contains("several fields"));
+        assert!(raw_scalar(&serde_json::json!(["a"])).is_err());
+        assert!(raw_scalar(&serde_json::json!({ "value": null })).is_err());
+    }
+
+    #[test]
+    fn test_env_assignments() {
+        let result = serde_json::json!({
+            "component": { "id": "site", "version": "1.2.3" },
+            "files": ["a.php", "it's.php"],
+            "changed": true,
+            "notes": null,
+            "build-dir": "/tmp/my build",
+        });
+        assert_eq!(
+            env_assignments(&result).unwrap(),
+            "BUILD_DIR='/tmp/my build'\n\
+             CHANGED=true\n\
+             COMPONENT_ID=site\n\
+             COMPONENT_VERSION=1.2.3\n\
+             FILES_0=a.php\n\
+             FILES_1='it'\\''s.php'\n\
+             NOTES=''"
+        );
+
+        let err = env_assignments(&serde_json::json!(["a"])).unwrap_err();
+        assert!(err.message.contains("object result"));
+
+        let err = env_assignments(&serde_json::json!({ "build-dir": "a", "build_dir": "b" }))
+            .unwrap_err();
+        assert!(err.message.contains("BUILD_DIR"));
+        assert!(err.message.contains("'build-dir' and 'build_dir'"));
+
+        let err = env_assignments(&serde_json::json!({ "a.b": 1, "a": { "b": 2 } })).unwrap_err();
+        assert!(err.message.contains("A_B"));
+    }
+
     #[test]
     fn test_exit_code() {
         let clean = BatchResult::new();
@@ -126,6 +283,27 @@ mod tests {
         assert_eq!(result.items[0].status, "error");
         assert_eq!(result.items[0].error.as_deref(), Some("boom"));
     }
+
+    #[test]
+    fn test_exit_policy_apply() {
+        let counts = IssueCounts {
+            errors: 0,
+            warnings: 2,
+            info: 1,
+        };
+        let policy = |fail_on| ExitPolicy {
+            fail_on,
+            exit_code: 3,
+        };
+
+        assert_eq!(policy(FailOn::Findings).apply(0, counts), 3);
+        assert_eq!(policy(FailOn::Warnings).apply(0, counts), 3);
+        assert_eq!(policy(FailOn::Errors).apply(1, counts), 0);
+        assert_eq!(policy(FailOn::None).apply(1, counts), 0);
+        assert_eq!(policy(FailOn::None).apply(2, counts), 2);
+        assert_eq!(policy(FailOn::Findings).apply(0, IssueCounts::default()), 0);
+        assert!("bogus".parse::<FailOn>().is_err());
+    }
 }
 
 // ============================================================================
@@ -339,3 +517,566 @@ impl<T: Serialize, E: Serialize + Default> Default for EntityCrudOutput<T, E> {
         }
     }
 }
+
+// ============================================================================
+// Issue Exit Codes (--fail-on)
+// ============================================================================
+
+/// Lowest issue severity that fails a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailOn {
+    /// Any finding, including info.
+    Findings,
+    /// Warnings and errors.
+    Warnings,
+    /// Errors only.
+    Errors,
+    /// Issues never fail the run.
+    None,
+}
+
+impl std::str::FromStr for FailOn {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "findings" => Ok(Self::Findings),
+            "warnings" => Ok(Self::Warnings),
+            "errors" => Ok(Self::Errors),
+            "none" => Ok(Self::None),
+            _ => Err(format!(
+                "unknown --fail-on level '{}'. Valid levels: findings, warnings, errors, none",
+                value
+            )),
+        }
+    }
+}
+
+/// Issues a command found, by severity.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IssueCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+}
+
+impl IssueCounts {
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info
+    }
+}
+
+/// Maps the issues a command found to its exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExitPolicy {
+    pub fail_on: FailOn,
+    /// Exit code used when issues reach `fail_on`.
+    pub exit_code: i32,
+}
+
+impl ExitPolicy {
+    /// Exit code for a run that found `counts`. Replaces the command's own
+    /// issue-based exit code, but `default` codes of 2 and above are
+    /// infrastructure failures and always win.
+    pub fn apply(&self, default: i32, counts: IssueCounts) -> i32 {
+        if default >= 2 {
+            return default;
+        }
+        let failing = match self.fail_on {
+            FailOn::Findings => counts.total(),
+            FailOn::Warnings => counts.errors + counts.warnings,
+            FailOn::Errors => counts.errors,
+            FailOn::None => 0,
+        };
+        if failing > 0 {
+            self.exit_code
+        } else {
+            0
+        }
+    }
+}
+
+// ============================================================================
+// CSV Output (--format csv)
+// ============================================================================
+
+/// Tabular form of a command result for `--format csv`.
+///
+/// Opt-in per result type. Each row is a JSON record; each header entry names
+/// the field it reads, and dotted names (`auth.mode`) reach into nested
+/// objects. Arrays of scalars are joined with `;`. Any other nested value is
+/// dropped (written as an empty cell) unless a dotted column selects part of it.
+pub trait CsvRows {
+    /// Column names, in order. Fixed per result type so spreadsheets and
+    /// scripts can rely on them even when rows are empty.
+    fn csv_header(&self) -> &'static [&'static str];
+
+    fn to_csv_rows(&self) -> Vec<serde_json::Value>;
+}
+
+/// Render `result` as CSV: a header row, then one line per record.
+pub fn render_csv(result: &impl CsvRows) -> String {
+    let header = result.csv_header();
+    let mut out = csv_line(header.iter().map(|name| name.to_string()));
+    for row in result.to_csv_rows() {
+        out.push_str(&csv_line(
+            header
+                .iter()
+                .map(|column| csv_cell(csv_field(&row, column))),
+        ));
+    }
+    out
+}
+
+fn csv_field<'a>(row: &'a serde_json::Value, column: &str) -> Option<&'a serde_json::Value> {
+    column
+        .split('.')
+        .try_fold(row, |value, key| value.as_object()?.get(key))
+}
+
+fn csv_cell(value: Option<&serde_json::Value>) -> String {
+    use serde_json::Value;
+
+    let scalar = |value: &Value| match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Bool(flag) => Some(flag.to_string()),
+        _ => None,
+    };
+    match value {
+        Some(Value::Array(items)) if items.iter().all(|item| scalar(item).is_some()) => items
+            .iter()
+            .filter_map(scalar)
+            .collect::<Vec<_>>()
+            .join(";"),
+        Some(value) => scalar(value).unwrap_or_default(),
+        None => String::new(),
+    }
+}
+
+fn csv_line(cells: impl Iterator<Item = String>) -> String {
+    let mut line = cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+// ============================================================================
+// Table Output (--format table)
+// ============================================================================
+
+/// Render `result` as an aligned table with a header row.
+///
+/// `columns` picks fields like `--select` paths; empty means the CSV header.
+/// Rows missing a field get an empty cell, but an explicit column no row has
+/// is an error. With a `width`, the widest columns
+/// shrink until each line fits, and cut cells end in `…`.
+pub fn render_table(
+    result: &impl CsvRows,
+    columns: &[String],
+    width: Option<usize>,
+) -> crate::error::Result<String> {
+    let explicit = !columns.is_empty();
+    let columns: Vec<String> = if explicit {
+        columns.to_vec()
+    } else {
+        result.csv_header().iter().map(|c| c.to_string()).collect()
+    };
+    let rows = serde_json::Value::Array(result.to_csv_rows());
+    let projected = select_fields(&rows, &columns, false)?;
+    let rows = projected.as_array().map(Vec::as_slice).unwrap_or_default();
+
+    let missing: Vec<&String> = columns
+        .iter()
+        .filter(|column| !rows.iter().any(|row| csv_field(row, column).is_some()))
+        .collect();
+    // A typo names a field no row has; sparse fields just leave blank cells.
+    if explicit && !rows.is_empty() && !missing.is_empty() {
+        return Err(crate::error::Error::validation_invalid_argument(
+            "columns",
+            format!(
+                "No row has column {}",
+                missing
+                    .iter()
+                    .map(|column| format!("'{}'", column))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None,
+            None,
+        )
+        .with_hint(format!(
+            "Default columns: {}",
+            result.csv_header().join(",")
+        )));
+    }
+
+    let mut lines = vec![columns.clone()];
+    lines.extend(rows.iter().map(|row| {
+        columns
+            .iter()
+            .map(|column| csv_cell(csv_field(row, column)).replace(['\n', '\r'], " "))
+            .collect()
+    }));
+
+    let mut widths: Vec<usize> = (0..columns.len())
+        .map(|i| {
+            lines
+                .iter()
+                .map(|line| line[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    if let Some(width) = width {
+        fit_widths(&mut widths, width);
+    }
+
+    let mut out = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", truncate_cell(cell, width)))
+            .collect();
+        let text = cells.join(TABLE_GAP);
+        if index == 0 {
+            out.push_str(&color::paint(
+                color::Stream::Stdout,
+                color::Style::Bold,
+                text.trim_end(),
+            ));
+        } else {
+            out.push_str(text.trim_end());
+        }
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+const TABLE_GAP: &str = "  ";
+
+/// Narrowest a column shrinks to when fitting the terminal.
+const MIN_COLUMN_WIDTH: usize = 4;
+
+/// Shrink the widest column one character at a time until the row fits.
+fn fit_widths(widths: &mut [usize], total: usize) {
+    let gaps = TABLE_GAP.len() * widths.len().saturating_sub(1);
+    while widths.iter().sum::<usize>() + gaps > total {
+        let Some(widest) = widths
+            .iter_mut()
+            .filter(|width| **width > MIN_COLUMN_WIDTH)
+            .max_by_key(|width| **width)
+        else {
+            return;
+        };
+        *widest -= 1;
+    }
+}
+
+fn truncate_cell(cell: &str, width: usize) -> String {
+    if cell.chars().count() <= width {
+        return cell.to_string();
+    }
+    let mut cut: String = cell.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+// ============================================================================
+// Field Selection
+// ============================================================================
+
+/// Project a command result down to `paths`, as for `--select`.
+///
+/// Each path names a field (`id`) or a nested one (`auth.mode`). Arrays are
+/// projected element by element, at the top level or anywhere along a path.
+/// Fields keep the result's order. A path that matches nothing is dropped, or
+/// with `strict` is an error naming it.
+pub fn select_fields(
+    value: &serde_json::Value,
+    paths: &[String],
+    strict: bool,
+) -> crate::error::Result<serde_json::Value> {
+    let mut tree = SelectTree::default();
+    for path in paths {
+        let segments: Vec<&str> = path.split('.').map(str::trim).collect();
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(crate::error::Error::validation_invalid_argument(
+                "select",
+                format!("Invalid field path '{}'", path),
+                None,
+                None,
+            ));
+        }
+        tree.insert(&segments, path);
+    }
+
+    let mut matched = std::collections::HashSet::new();
+    let projected = tree
+        .project(value, &mut matched)
+        .unwrap_or(serde_json::Value::Null);
+    if strict {
+        let missing: Vec<&String> = paths
+            .iter()
+            .filter(|p| !matched.contains(p.as_str()))
+            .collect();
+        if !missing.is_empty() {
+            return Err(crate::error::Error::validation_invalid_argument(
+                "select",
+                format!(
+                    "Result has no field {}",
+                    missing
+                        .iter()
+                        .map(|path| format!("'{}'", path))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                None,
+                None,
+            )
+            .with_hint("Drop --strict-select to omit missing fields"));
+        }
+    }
+    Ok(projected)
+}
+
+/// Selected paths as a tree; a node carrying `path` selects its whole value.
+#[derive(Default)]
+struct SelectTree<'a> {
+    path: Option<&'a str>,
+    children: Vec<(&'a str, SelectTree<'a>)>,
+}
+
+impl<'a> SelectTree<'a> {
+    fn insert(&mut self, segments: &[&'a str], path: &'a str) {
+        let Some((first, rest)) = segments.split_first() else {
+            self.path = Some(path);
+            return;
+        };
+        let index = match self.children.iter().position(|(key, _)| key == first) {
+            Some(index) => index,
+            None => {
+                self.children.push((first, SelectTree::default()));
+                self.children.len() - 1
+            }
+        };
+        self.children[index].1.insert(rest, path);
+    }
+
+    fn mark_matched(&self, matched: &mut std::collections::HashSet<&'a str>) {
+        matched.extend(self.path);
+        for (_, child) in &self.children {
+            child.mark_matched(matched);
+        }
+    }
+
+    fn project(
+        &self,
+        value: &serde_json::Value,
+        matched: &mut std::collections::HashSet<&'a str>,
+    ) -> Option<serde_json::Value> {
+        use serde_json::Value;
+
+        if self.path.is_some() {
+            // Selecting a field whole covers any deeper paths under it.
+            self.mark_matched(matched);
+            return Some(value.clone());
+        }
+        match value {
+            Value::Array(items) if items.is_empty() => {
+                // No element can show the field is missing.
+                self.mark_matched(matched);
+                Some(Value::Array(Vec::new()))
+            }
+            Value::Array(items) => Some(Value::Array(
+                items
+                    .iter()
+                    .filter_map(|item| self.project(item, matched))
+                    .collect(),
+            )),
+            Value::Object(object) => Some(Value::Object(
+                object
+                    .iter()
+                    .filter_map(|(key, field)| {
+                        let (_, child) = self.children.iter().find(|(name, _)| name == key)?;
+                        Some((key.clone(), child.project(field, matched)?))
+                    })
+                    .collect(),
+            )),
+            // A path reaching into a scalar selects nothing.
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Raw Output
+// ============================================================================
+
+/// Render a command result as bare text, as for `--output-raw`.
+///
+/// Objects with a single field are unwrapped until a scalar is reached, so
+/// `--select next` narrows `{"next": "1.2.0"}` to `1.2.0`. Strings print
+/// without quotes, numbers and booleans as JSON. Anything else is an error.
+pub fn raw_scalar(value: &serde_json::Value) -> crate::error::Result<String> {
+    use serde_json::Value;
+
+    let mut current = value;
+    while let Value::Object(object) = current {
+        match object.values().next() {
+            Some(field) if object.len() == 1 => current = field,
+            _ => break,
+        }
+    }
+    match current {
+        Value::String(text) => Ok(text.clone()),
+        Value::Number(_) | Value::Bool(_) => Ok(current.to_string()),
+        other => {
+            let kind = match other {
+                Value::Null => "null",
+                Value::Array(_) => "an array",
+                Value::Object(object) if object.is_empty() => "an empty object",
+                _ => "an object with several fields",
+            };
+            Err(crate::error::Error::validation_invalid_argument(
+                "output_raw",
+                format!(
+                    "--output-raw needs a single scalar result, but got {}",
+                    kind
+                ),
+                None,
+                None,
+            )
+            .with_hint(
+                "Narrow the result with --select <field>, or format it with --output-template",
+            ))
+        }
+    }
+}
+
+/// Render an object result as shell variable assignments, as for
+/// `--output-env`.
+///
+/// Nested fields flatten into uppercased, underscore-joined names
+/// (`component.version` becomes `COMPONENT_VERSION`), array elements are
+/// numbered, and values are quoted with the same rules used for remote
+/// commands so the output is safe to `eval`. Nulls become empty strings.
+pub fn env_assignments(value: &serde_json::Value) -> crate::error::Result<String> {
+    let serde_json::Value::Object(object) = value else {
+        return Err(crate::error::Error::validation_invalid_argument(
+            "output_env",
+            "--output-env needs an object result to name the variables",
+            None,
+            None,
+        )
+        .with_hint("Print a single value with --output-raw instead"));
+    };
+
+    let mut assignments = Vec::new();
+    for (key, field) in object {
+        push_env_assignments(&env_name(key), key, field, &mut assignments);
+    }
+
+    // Distinct keys can map to one name (`build-dir` and `build_dir`, or
+    // `a.b` and `{"a": {"b": ..}}`); refuse rather than let the last one win.
+    let mut sources: std::collections::HashMap<&str, &str> = std::collections::HashMap::new();
+    for assignment in &assignments {
+        if let Some(previous) = sources.insert(&assignment.name, &assignment.path) {
+            return Err(crate::error::Error::validation_invalid_argument(
+                "output_env",
+                format!(
+                    "--output-env would assign {} from both '{}' and '{}'",
+                    assignment.name, previous, assignment.path
+                ),
+                None,
+                None,
+            )
+            .with_hint("Narrow the result with --select so each field has its own name"));
+        }
+    }
+
+    Ok(assignments
+        .iter()
+        .map(|assignment| format!("{}={}", assignment.name, assignment.value))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// One `--output-env` line, with the field path it came from.
+struct EnvAssignment {
+    name: String,
+    path: String,
+    value: String,
+}
+
+fn push_env_assignments(
+    name: &str,
+    path: &str,
+    value: &serde_json::Value,
+    assignments: &mut Vec<EnvAssignment>,
+) {
+    use serde_json::Value;
+
+    let value = match value {
+        Value::Object(object) => {
+            for (key, field) in object {
+                push_env_assignments(
+                    &format!("{}_{}", name, env_name(key)),
+                    &format!("{}.{}", path, key),
+                    field,
+                    assignments,
+                );
+            }
+            return;
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                push_env_assignments(
+                    &format!("{}_{}", name, index),
+                    &format!("{}.{}", path, index),
+                    item,
+                    assignments,
+                );
+            }
+            return;
+        }
+        Value::String(text) => shell::quote_arg(text),
+        Value::Null => shell::quote_arg(""),
+        Value::Number(_) | Value::Bool(_) => value.to_string(),
+    };
+    assignments.push(EnvAssignment {
+        name: name.to_string(),
+        path: path.to_string(),
+        value,
+    });
+}
+
+/// Uppercase `key`, turning anything that is not valid in a variable name
+/// into `_`.
+fn env_name(key: &str) -> String {
+    let name: String = key
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|ch: char| ch.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
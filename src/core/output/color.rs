@@ -0,0 +1,200 @@
+//! Color capability detection shared by every renderer.
+//!
+//! The decision is made once per process: `--color always|never` wins, then
+//! `NO_COLOR`, `CLICOLOR_FORCE`, and `CLICOLOR=0`. Otherwise (`auto`) a stream
+//! gets color only when it is a terminal and `TERM` is not `dumb`. Renderers
+//! call [`enabled`] or [`paint`] instead of checking the terminal themselves,
+//! so ANSI codes never leak into pipes, logs, or CI output.
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when the stream is a terminal.
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "auto" => Some(Self::Auto),
+            "always" => Some(Self::Always),
+            "never" => Some(Self::Never),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    Bold,
+    Dim,
+    Red,
+    Green,
+    Yellow,
+    Cyan,
+}
+
+impl Style {
+    fn code(self) -> &'static str {
+        match self {
+            Self::Bold => "1",
+            Self::Dim => "2",
+            Self::Red => "31",
+            Self::Green => "32",
+            Self::Yellow => "33",
+            Self::Cyan => "36",
+        }
+    }
+}
+
+static CHOICE: AtomicU8 = AtomicU8::new(ColorChoice::Auto as u8);
+
+pub fn set_choice(choice: ColorChoice) {
+    CHOICE.store(choice as u8, Ordering::Relaxed);
+}
+
+pub fn choice() -> ColorChoice {
+    match CHOICE.load(Ordering::Relaxed) {
+        c if c == ColorChoice::Always as u8 => ColorChoice::Always,
+        c if c == ColorChoice::Never as u8 => ColorChoice::Never,
+        _ => ColorChoice::Auto,
+    }
+}
+
+/// The color-related environment variables, read once at startup.
+#[derive(Debug, Clone, Default)]
+pub struct ColorEnv {
+    pub no_color: Option<String>,
+    pub clicolor: Option<String>,
+    pub clicolor_force: Option<String>,
+}
+
+impl ColorEnv {
+    pub fn from_process() -> Self {
+        Self {
+            no_color: std::env::var("NO_COLOR").ok(),
+            clicolor: std::env::var("CLICOLOR").ok(),
+            clicolor_force: std::env::var("CLICOLOR_FORCE").ok(),
+        }
+    }
+}
+
+/// Resolve the choice from `--color` and the environment. An explicit
+/// `always`/`never` flag wins; `NO_COLOR` (any non-empty value) beats
+/// `CLICOLOR_FORCE`, which beats `CLICOLOR=0`.
+pub fn resolve_choice(flag: Option<ColorChoice>, env: &ColorEnv) -> ColorChoice {
+    if let Some(flag) = flag.filter(|flag| *flag != ColorChoice::Auto) {
+        return flag;
+    }
+    if env
+        .no_color
+        .as_deref()
+        .is_some_and(|value| !value.is_empty())
+    {
+        ColorChoice::Never
+    } else if env
+        .clicolor_force
+        .as_deref()
+        .is_some_and(|value| !value.is_empty() && value != "0")
+    {
+        ColorChoice::Always
+    } else if env.clicolor.as_deref() == Some("0") {
+        ColorChoice::Never
+    } else {
+        ColorChoice::Auto
+    }
+}
+
+/// Whether `stream` is attached to a terminal.
+pub fn is_terminal(stream: Stream) -> bool {
+    match stream {
+        Stream::Stdout => std::io::stdout().is_terminal(),
+        Stream::Stderr => std::io::stderr().is_terminal(),
+    }
+}
+
+/// Whether output written to `stream` right now may contain ANSI color.
+pub fn enabled(stream: Stream) -> bool {
+    match choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            is_terminal(stream) && std::env::var("TERM").map_or(true, |term| term != "dumb")
+        }
+    }
+}
+
+/// Wrap `text` in `style` when color is enabled for `stream`.
+pub fn paint(stream: Stream, style: Style, text: &str) -> String {
+    if enabled(stream) {
+        style_text(style, text)
+    } else {
+        text.to_string()
+    }
+}
+
+fn style_text(style: Style, text: &str) -> String {
+    format!("\u{1b}[{}m{}\u{1b}[0m", style.code(), text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(no_color: Option<&str>, clicolor: Option<&str>, force: Option<&str>) -> ColorEnv {
+        ColorEnv {
+            no_color: no_color.map(str::to_string),
+            clicolor: clicolor.map(str::to_string),
+            clicolor_force: force.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_resolve_choice() {
+        let none = env(None, None, None);
+        assert_eq!(resolve_choice(None, &none), ColorChoice::Auto);
+        assert_eq!(
+            resolve_choice(Some(ColorChoice::Never), &none),
+            ColorChoice::Never
+        );
+        assert_eq!(
+            resolve_choice(None, &env(Some("1"), None, Some("1"))),
+            ColorChoice::Never
+        );
+        assert_eq!(
+            resolve_choice(None, &env(Some(""), None, None)),
+            ColorChoice::Auto
+        );
+        assert_eq!(
+            resolve_choice(None, &env(None, Some("0"), Some("1"))),
+            ColorChoice::Always
+        );
+        assert_eq!(
+            resolve_choice(None, &env(None, Some("0"), Some("0"))),
+            ColorChoice::Never
+        );
+        assert_eq!(
+            resolve_choice(Some(ColorChoice::Always), &env(Some("1"), None, None)),
+            ColorChoice::Always
+        );
+        assert_eq!(
+            resolve_choice(Some(ColorChoice::Auto), &env(None, Some("0"), None)),
+            ColorChoice::Never
+        );
+    }
+
+    #[test]
+    fn test_style_text() {
+        assert_eq!(style_text(Style::Bold, "id"), "\u{1b}[1mid\u{1b}[0m");
+    }
+}
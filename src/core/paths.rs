@@ -165,6 +165,31 @@ pub fn backups() -> Result<PathBuf> {
     Ok(homeboy()?.join("backups"))
 }
 
+/// Pinned release artifact manifests (~/.config/homeboy/release-artifacts/)
+pub fn release_artifacts() -> Result<PathBuf> {
+    Ok(homeboy()?.join("release-artifacts"))
+}
+
+/// Pinned release artifact manifest for a component
+/// (~/.config/homeboy/release-artifacts/{id}.json)
+pub fn release_artifact_manifest(component_id: &str) -> Result<PathBuf> {
+    Ok(release_artifacts()?.join(format!("{}.json", component_id)))
+}
+
+/// Default output dir for a component's packaged archives
+/// (<artifact_root>/packages/{id})
+pub fn component_packages(component_id: &str) -> Result<PathBuf> {
+    Ok(artifact_root()?.join("packages").join(component_id))
+}
+
+/// Cached audit fingerprints for a component
+/// (~/.config/homeboy/fingerprint-cache/{id}.json)
+pub fn fingerprint_cache(component_id: &str) -> Result<PathBuf> {
+    Ok(homeboy()?
+        .join("fingerprint-cache")
+        .join(format!("{}.json", component_id)))
+}
+
 /// Rigs directory (~/.config/homeboy/rigs/)
 pub fn rigs() -> Result<PathBuf> {
     Ok(homeboy()?.join("rigs"))
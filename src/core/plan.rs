@@ -484,6 +484,135 @@ impl PlanSummary {
     }
 }
 
+/// User-facing `--only`/`--skip` selection over the named steps of a plan.
+///
+/// A selector matches a step when it equals the step name or names the
+/// step's dotted group, so `changelog` selects `changelog.policy`,
+/// `changelog.generate`, and `changelog.finalize` together.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanStepFilter {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub only: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip: Vec<String>,
+}
+
+impl PlanStepFilter {
+    pub fn new(only: Vec<String>, skip: Vec<String>) -> Self {
+        let clean = |values: Vec<String>| -> Vec<String> {
+            values
+                .into_iter()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .collect()
+        };
+        Self {
+            only: clean(only),
+            skip: clean(skip),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.only.is_empty() && self.skip.is_empty()
+    }
+
+    /// Returns true if the named step should run under this filter.
+    pub fn should_run(&self, step_name: &str) -> bool {
+        if !self.only.is_empty()
+            && !self
+                .only
+                .iter()
+                .any(|selector| selector_matches(selector, step_name))
+        {
+            return false;
+        }
+
+        !self
+            .skip
+            .iter()
+            .any(|selector| selector_matches(selector, step_name))
+    }
+
+    /// Reason recorded on a step this filter excludes.
+    pub fn skip_reason(&self, step_name: &str) -> Option<String> {
+        if self.should_run(step_name) {
+            return None;
+        }
+        if self
+            .skip
+            .iter()
+            .any(|selector| selector_matches(selector, step_name))
+        {
+            Some("skipped by --skip".to_string())
+        } else {
+            Some("not selected by --only".to_string())
+        }
+    }
+
+    /// Names from `valid_names` this filter excludes, in their original order.
+    pub fn skipped_names<'a>(&self, valid_names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+        valid_names
+            .into_iter()
+            .filter(|name| !self.should_run(name))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Reject selectors that do not match any known step name.
+    pub fn validate<'a>(
+        &self,
+        field: &str,
+        valid_names: impl IntoIterator<Item = &'a str>,
+    ) -> crate::error::Result<()> {
+        let valid_names: Vec<&str> = valid_names.into_iter().collect();
+        let unknown: Vec<&str> = self
+            .only
+            .iter()
+            .chain(self.skip.iter())
+            .map(String::as_str)
+            .filter(|selector| {
+                !valid_names
+                    .iter()
+                    .any(|name| selector_matches(selector, name))
+            })
+            .collect();
+
+        if unknown.is_empty() {
+            return Ok(());
+        }
+
+        Err(crate::error::Error::validation_invalid_argument(
+            field,
+            format!("Unknown step(s): {}", unknown.join(", ")),
+            None,
+            Some(vec![format!("Valid steps: {}", valid_names.join(", "))]),
+        ))
+    }
+
+    /// Mark runnable steps excluded by this filter as `Skipped`.
+    pub(crate) fn apply(&self, steps: &mut [PlanStep]) {
+        if self.is_empty() {
+            return;
+        }
+        for step in steps.iter_mut() {
+            if !matches!(step.status, PlanStepStatus::Ready | PlanStepStatus::Missing) {
+                continue;
+            }
+            if let Some(reason) = self.skip_reason(&step.id) {
+                step.status = PlanStepStatus::Skipped;
+                step.skip_reason = Some(reason);
+            }
+        }
+    }
+}
+
+fn selector_matches(selector: &str, step_name: &str) -> bool {
+    step_name == selector
+        || step_name
+            .strip_prefix(selector)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
 fn default_blocking() -> bool {
     true
 }
@@ -514,7 +643,8 @@ fn slug_fragment(value: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::{
-        HomeboyPlan, PlanBuilder, PlanKind, PlanStep, PlanStepStatus, PlanSummary, PlanValues,
+        HomeboyPlan, PlanBuilder, PlanKind, PlanStep, PlanStepFilter, PlanStepStatus, PlanSummary,
+        PlanValues,
     };
 
     #[test]
@@ -908,4 +1038,60 @@ mod tests {
 
         assert_eq!(summary.skipped, 1);
     }
+
+    #[test]
+    fn step_filter_matches_exact_names_and_dotted_groups() {
+        let filter = PlanStepFilter::new(vec!["changelog".to_string()], vec![]);
+
+        assert!(filter.should_run("changelog.finalize"));
+        assert!(!filter.should_run("changelogger"));
+        assert!(!filter.should_run("git.tag"));
+    }
+
+    #[test]
+    fn step_filter_skip_wins_over_only() {
+        let filter = PlanStepFilter::new(vec!["git".to_string()], vec!["git.push".to_string()]);
+
+        assert!(filter.should_run("git.tag"));
+        assert!(!filter.should_run("git.push"));
+        assert_eq!(
+            filter.skip_reason("git.push").as_deref(),
+            Some("skipped by --skip")
+        );
+        assert_eq!(
+            filter.skip_reason("version").as_deref(),
+            Some("not selected by --only")
+        );
+    }
+
+    #[test]
+    fn step_filter_validate_lists_valid_steps() {
+        let filter = PlanStepFilter::new(vec!["upload".to_string()], vec![]);
+
+        let err = filter
+            .validate("only", ["build", "deploy"])
+            .expect_err("unknown step should be rejected");
+
+        assert!(err.message.contains("upload"));
+        assert!(err
+            .details
+            .to_string()
+            .contains("Valid steps: build, deploy"));
+    }
+
+    #[test]
+    fn step_filter_apply_marks_excluded_steps_skipped() {
+        let mut steps = vec![
+            PlanStep::ready("version", "version").build(),
+            PlanStep::ready("changelog.finalize", "changelog.finalize").build(),
+            PlanStep::disabled("preflight.git_identity", "preflight.git_identity").build(),
+        ];
+
+        PlanStepFilter::new(vec![], vec!["changelog".to_string()]).apply(&mut steps);
+
+        assert_eq!(steps[0].status, PlanStepStatus::Ready);
+        assert_eq!(steps[1].status, PlanStepStatus::Skipped);
+        assert_eq!(steps[1].skip_reason.as_deref(), Some("skipped by --skip"));
+        assert_eq!(steps[2].status, PlanStepStatus::Disabled);
+    }
 }
@@ -0,0 +1,146 @@
+//! Lightweight phase timing for `--profile-phases`.
+//!
+//! Code wraps major phases (config load, SSH commands, git calls, ...) in a
+//! [`span`]. When profiling is off a span is a no-op; when it is on, elapsed
+//! time is aggregated per phase name and [`render_table`] prints the
+//! breakdown at the end of the invocation.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Aggregated timing for one phase.
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTiming {
+    pub phase: String,
+    pub calls: u32,
+    pub total_ms: f64,
+    pub max_ms: f64,
+}
+
+/// Turn on span recording for the rest of the process (`--profile-phases`).
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// RAII timer; records its elapsed time under `phase` when dropped.
+pub struct Span {
+    phase: &'static str,
+    started: Option<Instant>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(started) = self.started {
+            record_elapsed(self.phase, started.elapsed());
+        }
+    }
+}
+
+/// Start timing `phase`. Spans nest freely; each is counted under its own
+/// phase, so nested time is included in the outer phase too.
+pub fn span(phase: &'static str) -> Span {
+    Span {
+        phase,
+        started: is_enabled().then(Instant::now),
+    }
+}
+
+/// Phases recorded so far, in the order they were first seen.
+pub fn timings() -> Vec<PhaseTiming> {
+    collector()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// Render `timings` as a fixed-width table, slowest phase first.
+pub fn render_table(timings: &[PhaseTiming]) -> String {
+    let mut rows: Vec<&PhaseTiming> = timings.iter().collect();
+    rows.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+
+    let width = rows
+        .iter()
+        .map(|row| row.phase.len())
+        .max()
+        .unwrap_or(0)
+        .max("phase".len());
+    let mut out = format!(
+        "{:<width$}  {:>6}  {:>11}  {:>9}\n",
+        "phase", "calls", "total (ms)", "max (ms)"
+    );
+    for row in rows {
+        out.push_str(&format!(
+            "{:<width$}  {:>6}  {:>11.1}  {:>9.1}\n",
+            row.phase, row.calls, row.total_ms, row.max_ms
+        ));
+    }
+    out
+}
+
+/// Record an externally measured duration under `phase`. No-op unless
+/// profiling is enabled.
+pub fn record(phase: &str, elapsed: Duration) {
+    if is_enabled() {
+        record_elapsed(phase, elapsed);
+    }
+}
+
+fn record_elapsed(phase: &str, elapsed: Duration) {
+    let ms = elapsed.as_secs_f64() * 1000.0;
+    let mut timings = collector()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match timings.iter_mut().find(|timing| timing.phase == phase) {
+        Some(timing) => {
+            timing.calls += 1;
+            timing.total_ms += ms;
+            timing.max_ms = timing.max_ms.max(ms);
+        }
+        None => timings.push(PhaseTiming {
+            phase: phase.to_string(),
+            calls: 1,
+            total_ms: ms,
+            max_ms: ms,
+        }),
+    }
+}
+
+fn collector() -> &'static Mutex<Vec<PhaseTiming>> {
+    static TIMINGS: OnceLock<Mutex<Vec<PhaseTiming>>> = OnceLock::new();
+    TIMINGS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        record_elapsed("profile.test.fast", Duration::from_millis(2));
+        record_elapsed("profile.test.slow", Duration::from_millis(30));
+        record_elapsed("profile.test.slow", Duration::from_millis(10));
+
+        let rows: Vec<PhaseTiming> = timings()
+            .into_iter()
+            .filter(|row| row.phase.starts_with("profile.test."))
+            .collect();
+        let slow = rows.iter().find(|row| row.phase == "profile.test.slow");
+        assert_eq!(slow.map(|row| row.calls), Some(2));
+        assert_eq!(slow.map(|row| row.max_ms.round()), Some(30.0));
+
+        let table = render_table(&rows);
+        let lines: Vec<&str> = table.lines().collect();
+        assert!(lines[0].starts_with("phase"));
+        assert!(lines[1].starts_with("profile.test.slow"));
+        assert!(lines[2].starts_with("profile.test.fast"));
+    }
+}
@@ -931,6 +931,7 @@ pub fn download(
     if ctx.client.port != deploy_defaults.default_ssh_port {
         scp_args.extend(["-P".to_string(), ctx.client.port.to_string()]);
     }
+    scp_args.extend(ctx.client.proxy_args());
 
     // Remote source (reverse of upload)
     scp_args.push(format!(
@@ -952,6 +953,7 @@ pub fn download(
         local_path
     );
 
+    let _permit = crate::server::acquire_ssh_permit();
     let output = Command::new("scp").args(&scp_args).output();
     match output {
         Ok(output) if output.status.success() => Ok(DownloadResult {
@@ -0,0 +1,210 @@
+//! `project set <name> --from-url`: derive a project and its first component
+//! from a repository.
+//!
+//! A git URL is cloned (an existing checkout at the target is reused); a local
+//! path is inspected in place. The checkout then goes through component
+//! scaffold detection, so the derived config can be reviewed before
+//! [`apply`] persists anything.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::{attach_component_path, exists, load, save, slugify_id, Project};
+use crate::component::scaffold::{self, ScaffoldDetection};
+use crate::component::{self, Component};
+use crate::config::{self, ConfigEntity};
+use crate::error::{Error, Result};
+use crate::extension::{derive_id_from_url, is_git_url};
+use crate::git;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FromUrlPlan {
+    pub project_id: String,
+    /// The project already exists; the component is attached to it.
+    pub project_exists: bool,
+    pub source: String,
+    pub local_path: String,
+    /// The checkout was cloned while planning.
+    pub cloned: bool,
+    pub component_id: String,
+    /// Portable component config that would be written to `homeboy.json`.
+    pub component: serde_json::Value,
+    pub detected: ScaffoldDetection,
+    /// Whether the plan was persisted.
+    pub saved: bool,
+    #[serde(skip)]
+    proposal: Component,
+}
+
+/// Resolve `source` to a checkout and propose the project and component.
+///
+/// Git URLs are cloned into `into` (default `./<repo-name>`); local paths are
+/// used as they are and must not be combined with `into`.
+pub fn plan(project_name: &str, source: &str, into: Option<&Path>) -> Result<FromUrlPlan> {
+    let project_id = slugify_id(project_name)?;
+    let project_exists = exists(&project_id);
+    if !project_exists {
+        config::check_id_collision(&project_id, Project::ENTITY_TYPE)?;
+    }
+
+    let (checkout, cloned, url_id) = if is_git_url(source) {
+        let url_id = derive_id_from_url(source)?;
+        let target = match into {
+            Some(dir) => dir.to_path_buf(),
+            None => std::env::current_dir()
+                .map_err(|e| Error::internal_io(e.to_string(), Some("read cwd".to_string())))?
+                .join(&url_id),
+        };
+        let cloned = checkout_url(source, &target)?;
+        (target, cloned, Some(url_id))
+    } else {
+        if into.is_some() {
+            return Err(Error::validation_invalid_argument(
+                "into",
+                "--into only applies to git URLs; a local path is used in place",
+                None,
+                None,
+            ));
+        }
+        (
+            PathBuf::from(shellexpand::tilde(source).as_ref()),
+            false,
+            None,
+        )
+    };
+
+    let checkout = std::fs::canonicalize(&checkout).map_err(|e| {
+        Error::validation_invalid_argument(
+            "from_url",
+            format!("Cannot resolve checkout {}: {}", checkout.display(), e),
+            Some(source.to_string()),
+            None,
+        )
+    })?;
+    let mut proposal = scaffold::scaffold(&checkout)?;
+    if let Some(url_id) = url_id.filter(|_| !proposal.detected.existing_config) {
+        proposal.component.id = url_id;
+    }
+
+    Ok(FromUrlPlan {
+        project_id,
+        project_exists,
+        source: source.to_string(),
+        local_path: proposal.component.local_path.clone(),
+        cloned,
+        component_id: proposal.component.id.clone(),
+        component: component::portable_json(&proposal.component)?,
+        detected: proposal.detected,
+        saved: false,
+        proposal: proposal.component,
+    })
+}
+
+/// Clone `url` into `target` unless it already holds a checkout. Returns
+/// whether a clone happened.
+fn checkout_url(url: &str, target: &Path) -> Result<bool> {
+    if target.join(".git").exists() {
+        return Ok(false);
+    }
+    let occupied = target
+        .read_dir()
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if occupied {
+        return Err(Error::validation_invalid_argument(
+            "into",
+            format!("{} exists and is not a git checkout", target.display()),
+            Some(target.to_string_lossy().to_string()),
+            None,
+        )
+        .with_hint("Pass --into <dir> to clone somewhere else"));
+    }
+    git::clone_repo(url, target)?;
+    Ok(true)
+}
+
+/// Persist `plan`: create the project when missing, write the component's
+/// `homeboy.json` and registration, and attach it to the project.
+pub fn apply(plan: &mut FromUrlPlan) -> Result<Project> {
+    if !exists(&plan.project_id) {
+        let mut project = Project::default();
+        project.set_id(plan.project_id.clone());
+        save(&project)?;
+    }
+
+    let dir = Path::new(&plan.local_path);
+    component::write_portable_config(dir, &plan.proposal)?;
+    if let Err(e) = component::inventory::write_standalone_registration(&plan.proposal) {
+        crate::log_status!(
+            "project",
+            "Warning: could not write standalone registration: {}",
+            e
+        );
+    }
+    attach_component_path(&plan.project_id, &plan.component_id, &plan.local_path)?;
+
+    plan.saved = true;
+    load(&plan.project_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+
+    #[test]
+    fn plans_and_applies_local_checkout() {
+        with_isolated_home(|home| {
+            let dir = home.path().join("Widget Site");
+            std::fs::create_dir_all(dir.join("docs")).unwrap();
+
+            let mut plan = plan("Acme Blog", &dir.to_string_lossy(), None).unwrap();
+            assert_eq!(plan.project_id, "acme-blog");
+            assert!(!plan.project_exists);
+            assert!(!plan.cloned);
+            assert_eq!(plan.component_id, "widget-site");
+            assert_eq!(plan.detected.docs_dirs, vec!["docs"]);
+            assert!(!exists("acme-blog"), "planning must not persist");
+
+            let project = apply(&mut plan).unwrap();
+            assert!(plan.saved);
+            assert_eq!(project.components.len(), 1);
+            assert_eq!(project.components[0].id, "widget-site");
+            assert!(dir.join("homeboy.json").exists());
+        });
+    }
+
+    #[test]
+    fn clones_git_url_and_reuses_checkout() {
+        with_isolated_home(|home| {
+            let origin = home.path().join("origin").join("My-Plugin.git");
+            std::fs::create_dir_all(&origin).unwrap();
+            crate::engine::command::run_in(
+                &origin.to_string_lossy(),
+                "git",
+                &["init", "--quiet"],
+                "git init",
+            )
+            .unwrap();
+            let target = home.path().join("checkouts").join("plugin");
+            let url = origin.to_string_lossy().to_string();
+
+            let first = plan("shop", &url, Some(&target)).unwrap();
+            assert!(first.cloned);
+            assert_eq!(first.component_id, "my-plugin");
+            assert!(target.join(".git").exists());
+
+            let again = plan("shop", &url, Some(&target)).unwrap();
+            assert!(!again.cloned);
+        });
+    }
+
+    #[test]
+    fn rejects_into_for_local_paths() {
+        with_isolated_home(|home| {
+            let err = plan("shop", &home.path().to_string_lossy(), Some(home.path())).unwrap_err();
+            assert!(err.message.contains("--into only applies to git URLs"));
+        });
+    }
+}
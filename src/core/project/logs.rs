@@ -12,6 +12,7 @@ use crate::error::{Error, Result};
 use crate::paths as base_path;
 use crate::project::{self, Project};
 use serde::Serialize;
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct LogEntry {
@@ -137,13 +138,74 @@ pub fn show(project_id: &str, path: &str, lines: u32, local: bool) -> Result<Log
     })
 }
 
-pub fn follow(project_id: &str, path: &str, local: bool) -> Result<i32> {
+/// Default cap on reconnections for `logs show --follow`.
+pub const DEFAULT_FOLLOW_RECONNECTS: u32 = 5;
+
+/// Exit status ssh uses for connection errors, including a dropped session.
+const SSH_CONNECTION_LOST: i32 = 255;
+const RECONNECT_MAX_DELAY_SECS: u64 = 30;
+/// A stream that stayed up this long before dropping starts a fresh
+/// reconnection budget.
+const STABLE_STREAM: Duration = Duration::from_secs(60);
+
+/// Follow a log file. Over SSH, a dropped connection is retried with
+/// exponential backoff up to `max_reconnects` times; any other exit ends the
+/// stream. Reconnections resume at the end of the file, so lines written
+/// while disconnected are not replayed.
+pub fn follow(project_id: &str, path: &str, local: bool, max_reconnects: u32) -> Result<i32> {
     let project = load_project(project_id, local)?;
     let base_path = require_project_base_path(project_id, &project)?;
     let full_path = base_path::join_remote_path(Some(&base_path), path)?;
+    let quoted = shell::quote_path(&full_path);
+
+    let remote = project.server_id.as_ref().is_some_and(|id| !id.is_empty());
+    let mut tail_cmd = format!("tail -f {}", quoted);
+    let mut attempts = 0;
+    loop {
+        let started = Instant::now();
+        let code = execute_for_project_interactive(&project, &tail_cmd)?;
+        if !remote || code != SSH_CONNECTION_LOST {
+            return Ok(code);
+        }
 
-    let tail_cmd = format!("tail -f {}", shell::quote_path(&full_path));
-    execute_for_project_interactive(&project, &tail_cmd)
+        if started.elapsed() >= STABLE_STREAM {
+            attempts = 0;
+        }
+        if attempts >= max_reconnects {
+            return Err(Error::ssh_connect_failed(
+                format!(
+                    "log stream for {} dropped and did not come back after {} reconnection attempts",
+                    full_path, max_reconnects
+                ),
+                serde_json::json!({ "project_id": project_id, "path": full_path }),
+            )
+            .with_hint(format!(
+                "Check the connection with: homeboy ssh {} -- true",
+                project_id
+            )));
+        }
+
+        attempts += 1;
+        let delay = reconnect_delay(attempts);
+        crate::log_status!(
+            "reconnecting",
+            "Log stream dropped; retrying in {}s ({}/{})",
+            delay.as_secs(),
+            attempts,
+            max_reconnects
+        );
+        std::thread::sleep(delay);
+        tail_cmd = format!("tail -n 0 -f {}", quoted);
+    }
+}
+
+/// Backoff before reconnection `attempt` (1-based): 1s, 2s, 4s, ... capped.
+fn reconnect_delay(attempt: u32) -> Duration {
+    let secs = 1u64
+        .checked_shl(attempt.saturating_sub(1))
+        .unwrap_or(u64::MAX)
+        .min(RECONNECT_MAX_DELAY_SECS);
+    Duration::from_secs(secs)
 }
 
 pub fn clear(project_id: &str, path: &str, local: bool) -> Result<String> {
@@ -234,3 +296,15 @@ fn parse_grep_output(output: &str) -> Vec<LogSearchMatch> {
 
     matches
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reconnect_delay() {
+        let delays: Vec<u64> = (1..=7).map(|n| reconnect_delay(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(reconnect_delay(200).as_secs(), 30);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::component::ScopedExtensionConfig;
-use crate::config::{self, ConfigEntity};
+use crate::config::{self, ConfigEntity, Tagged};
 use crate::engine::local_files::{self, FileSystem};
 use crate::error::{Error, Result};
 use crate::output::{CreateOutput, MergeOutput, RemoveResult};
@@ -11,9 +11,12 @@ use std::path::PathBuf;
 
 pub mod component;
 pub mod files;
+pub mod from_url;
 pub mod logs;
 pub mod pins;
 mod readiness;
+pub mod references;
+pub mod repair;
 pub mod report;
 mod status;
 
@@ -31,11 +34,16 @@ pub use pins::{
     ProjectPinListItem, ProjectPinOutput,
 };
 pub use readiness::calculate_deploy_readiness;
+pub use references::{rewrite_project_references, ProjectReferenceUpdate};
+pub use repair::{
+    repair, ProjectRepairReport, RepairFix, RepairIssue, RepairOutcome, RepairStatus, REPAIR_CHECKS,
+};
 pub use report::{
-    build_components_output, build_create_output, build_delete_output, build_init_output,
-    build_list_output, build_pin_output, build_remove_output, build_rename_output,
-    build_set_output, build_show_output, build_status_output, list_report, show_report,
-    status_report, ProjectComponentVersion, ProjectListItem, ProjectListReport, ProjectReportExtra,
+    build_clone_output, build_components_output, build_create_output, build_delete_output,
+    build_init_output, build_list_output, build_pin_output, build_remove_output,
+    build_rename_output, build_repair_output, build_set_output, build_show_output,
+    build_status_output, build_tag_output, list_report, show_report, status_report,
+    ProjectComponentVersion, ProjectListItem, ProjectListReport, ProjectReportExtra,
     ProjectReportOutput, ProjectShowReport, ProjectStatusReport,
 };
 pub use status::{collect_status, ProjectComponentStatus, ProjectStatusSnapshot};
@@ -82,6 +90,10 @@ pub struct Project {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
 
+    /// Grouping tags (`prod`, `staging`) used by `--tag` filters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extensions: Option<HashMap<String, ScopedExtensionConfig>>,
 
@@ -401,7 +413,61 @@ pub struct NewsletterConfig {
 // Core CRUD - Generated by entity_crud! macro
 // ============================================================================
 
-entity_crud!(Project; list_ids, merge, slugify_id);
+impl Tagged for Project {
+    fn tags(&self) -> &[String] {
+        &self.tags
+    }
+    fn tags_mut(&mut self) -> &mut Vec<String> {
+        &mut self.tags
+    }
+}
+
+entity_crud!(Project; list_ids, merge, slugify_id, tags);
+
+/// Result of cloning a project under a new ID.
+#[derive(Debug, Clone)]
+pub struct CloneResult {
+    pub project: Project,
+    pub updated_fields: Vec<String>,
+}
+
+/// Copy `source_id` to a new project whose ID is slugified from `new_name`,
+/// applying `overrides` (a JSON object patch) before saving. The source is
+/// not modified. Aliases are not copied since they must stay unique.
+pub fn clone(
+    source_id: &str,
+    new_name: &str,
+    overrides: Option<serde_json::Value>,
+) -> Result<CloneResult> {
+    let new_id = slugify_id(new_name)?;
+    if exists(&new_id) {
+        return Err(Error::config_id_collision(
+            &new_id,
+            Project::ENTITY_TYPE,
+            Project::ENTITY_TYPE,
+        ));
+    }
+    config::check_id_collision(&new_id, Project::ENTITY_TYPE)?;
+
+    let mut project = load(source_id)?;
+    project.aliases.clear();
+
+    let updated_fields = match overrides {
+        Some(patch) => {
+            let replace_fields = config::collect_array_fields(&patch);
+            config::merge_config(&mut project, patch, &replace_fields)?.updated_fields
+        }
+        None => Vec::new(),
+    };
+    project.set_id(new_id);
+    project.validate()?;
+    save(&project)?;
+
+    Ok(CloneResult {
+        project,
+        updated_fields,
+    })
+}
 
 // ============================================================================
 // Project directory operations
@@ -677,6 +743,61 @@ pub fn project_cli_path(project: &Project) -> Option<String> {
     None
 }
 
+#[cfg(test)]
+mod clone_tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+
+    fn save_source() {
+        save(&Project {
+            id: "production".to_string(),
+            aliases: vec!["prod".to_string()],
+            domain: Some("example.com".to_string()),
+            base_path: Some("/var/www/example".to_string()),
+            services: vec!["nginx".to_string()],
+            ..Default::default()
+        })
+        .expect("save source");
+    }
+
+    #[test]
+    fn test_clone() {
+        with_isolated_home(|_| {
+            save_source();
+
+            let result = clone(
+                "production",
+                "Example Staging",
+                Some(serde_json::json!({"domain": "staging.example.com"})),
+            )
+            .expect("clone");
+
+            assert_eq!(result.project.id, "example-staging");
+            assert_eq!(result.updated_fields, vec!["domain"]);
+            let copy = load("example-staging").expect("load copy");
+            assert_eq!(copy.domain.as_deref(), Some("staging.example.com"));
+            assert_eq!(copy.base_path.as_deref(), Some("/var/www/example"));
+            assert_eq!(copy.services, vec!["nginx"]);
+            assert!(copy.aliases.is_empty());
+
+            let source = load("production").expect("load source");
+            assert_eq!(source.domain.as_deref(), Some("example.com"));
+            assert_eq!(source.aliases, vec!["prod"]);
+        });
+    }
+
+    #[test]
+    fn clone_refuses_existing_id() {
+        with_isolated_home(|_| {
+            save_source();
+
+            let err = clone("production", "Production", None).unwrap_err();
+
+            assert_eq!(err.code.as_str(), "config.id_collision");
+        });
+    }
+}
+
 #[cfg(test)]
 mod cli_path_tests {
     use super::*;
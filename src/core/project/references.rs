@@ -0,0 +1,42 @@
+//! Rewrite references that project configs hold to other entities.
+//!
+//! Component and server rename both go through `rewrite_project_references`
+//! so they scan projects the same way and report updates in the same shape.
+
+use serde::Serialize;
+
+use super::{list, save, Project};
+use crate::error::Result;
+
+/// A project whose config was (or, in a dry run, would be) rewritten.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProjectReferenceUpdate {
+    pub project_id: String,
+    pub fields: Vec<String>,
+}
+
+/// Apply `rewrite` to every project. `rewrite` mutates the project and returns
+/// the fields it changed; changed projects are saved unless `dry_run`.
+pub fn rewrite_project_references<F>(
+    dry_run: bool,
+    mut rewrite: F,
+) -> Result<Vec<ProjectReferenceUpdate>>
+where
+    F: FnMut(&mut Project) -> Vec<String>,
+{
+    let mut updates = Vec::new();
+    for mut project in list().unwrap_or_default() {
+        let fields = rewrite(&mut project);
+        if fields.is_empty() {
+            continue;
+        }
+        if !dry_run {
+            save(&project)?;
+        }
+        updates.push(ProjectReferenceUpdate {
+            project_id: project.id,
+            fields,
+        });
+    }
+    Ok(updates)
+}
@@ -0,0 +1,329 @@
+//! Detect and fix references a project config holds to things that no longer
+//! exist.
+//!
+//! Each entry in [`REPAIR_CHECKS`] detects one kind of breakage and proposes a
+//! fix. The caller decides per issue whether to apply it (prompting in a TTY,
+//! accepting everything under `--yes`), and the project is saved once at the
+//! end with every accepted fix. Homeboy keeps no active-project pointer, so
+//! there is none to repair; commands always name their project.
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::component::discovery::discover_attached_component;
+use super::{load, save, Project};
+use crate::config::ConfigLock;
+use crate::error::Result;
+
+/// A change `project repair` can make to a project config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum RepairFix {
+    /// Point the project at another existing server.
+    SetServer { server_id: String },
+    /// Drop a component attachment whose checkout is gone, with its overrides.
+    DetachComponent { component_id: String },
+    /// Drop overrides for a component the project no longer attaches.
+    DropOverride { component_id: String },
+}
+
+impl RepairFix {
+    /// Apply the fix to `project` in memory. Returns the config field changed.
+    pub fn apply(&self, project: &mut Project) -> &'static str {
+        match self {
+            RepairFix::SetServer { server_id } => {
+                project.server_id = Some(server_id.clone());
+                "server_id"
+            }
+            RepairFix::DetachComponent { component_id } => {
+                project.components.retain(|c| &c.id != component_id);
+                project.component_overrides.remove(component_id);
+                "components"
+            }
+            RepairFix::DropOverride { component_id } => {
+                project.component_overrides.remove(component_id);
+                "component_overrides"
+            }
+        }
+    }
+}
+
+/// Something broken in a project config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepairIssue {
+    /// Name of the check that found it.
+    pub check: &'static str,
+    pub message: String,
+    /// The fix to apply. `None` when the user has to choose, e.g. which
+    /// server replaces a missing one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fix: Option<RepairFix>,
+    /// Options to choose from when `fix` is `None`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct RepairCheck {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub detect: fn(&Project) -> Vec<RepairIssue>,
+}
+
+pub const REPAIR_CHECKS: &[RepairCheck] = &[
+    RepairCheck {
+        name: "server",
+        description: "The project's server_id names a configured server",
+        detect: detect_missing_server,
+    },
+    RepairCheck {
+        name: "components",
+        description: "Every attached component still has a homeboy.json at its local_path",
+        detect: detect_missing_components,
+    },
+    RepairCheck {
+        name: "component_overrides",
+        description: "Component overrides only name attached components",
+        detect: detect_orphaned_overrides,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepairStatus {
+    Applied,
+    Skipped,
+}
+
+/// One issue and what happened to it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RepairOutcome {
+    #[serde(flatten)]
+    pub issue: RepairIssue,
+    pub status: RepairStatus,
+    /// The fix that was applied, when it was chosen rather than proposed
+    /// (a replacement server).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chosen: Option<RepairFix>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectRepairReport {
+    pub repairs: Vec<RepairOutcome>,
+    /// Config fields the applied fixes changed.
+    pub updated_fields: Vec<String>,
+}
+
+impl ProjectRepairReport {
+    pub fn unresolved(&self) -> usize {
+        self.repairs
+            .iter()
+            .filter(|r| r.status == RepairStatus::Skipped)
+            .count()
+    }
+}
+
+/// Run every repair check against `project`.
+pub fn detect(project: &Project) -> Vec<RepairIssue> {
+    REPAIR_CHECKS
+        .iter()
+        .flat_map(|check| (check.detect)(project))
+        .collect()
+}
+
+/// Detect issues in `project_id`, ask `decide` which fix (if any) to apply
+/// for each, and save the project once when anything was applied.
+///
+/// Decisions are collected first, without the config lock, since `decide`
+/// may prompt. The accepted fixes are then applied to a fresh load under
+/// [`ConfigLock`], so a concurrent edit to the project is not lost.
+pub fn repair<F>(project_id: &str, mut decide: F) -> Result<(Project, ProjectRepairReport)>
+where
+    F: FnMut(&RepairIssue) -> Result<Option<RepairFix>>,
+{
+    let mut repairs = Vec::new();
+    let mut fixes = Vec::new();
+    for issue in detect(&load(project_id)?) {
+        let chosen = decide(&issue)?;
+        let status = match &chosen {
+            Some(fix) => {
+                fixes.push(fix.clone());
+                RepairStatus::Applied
+            }
+            None => RepairStatus::Skipped,
+        };
+        let chosen = chosen.filter(|fix| issue.fix.as_ref() != Some(fix));
+        repairs.push(RepairOutcome {
+            issue,
+            status,
+            chosen,
+        });
+    }
+
+    let _lock = ConfigLock::acquire()?;
+    let mut project = load(project_id)?;
+    let mut updated_fields: Vec<String> = Vec::new();
+    for fix in &fixes {
+        let field = fix.apply(&mut project).to_string();
+        if !updated_fields.contains(&field) {
+            updated_fields.push(field);
+        }
+    }
+    if !updated_fields.is_empty() {
+        save(&project)?;
+    }
+
+    Ok((
+        project,
+        ProjectRepairReport {
+            repairs,
+            updated_fields,
+        },
+    ))
+}
+
+fn detect_missing_server(project: &Project) -> Vec<RepairIssue> {
+    let Some(server_id) = project.server_id.as_deref() else {
+        return Vec::new();
+    };
+    if crate::server::exists(server_id) {
+        return Vec::new();
+    }
+
+    let choices: Vec<String> = crate::server::list()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|server| server.id)
+        .collect();
+    vec![RepairIssue {
+        check: "server",
+        message: format!("Server '{}' does not exist", server_id),
+        fix: None,
+        choices,
+    }]
+}
+
+fn detect_missing_components(project: &Project) -> Vec<RepairIssue> {
+    project
+        .components
+        .iter()
+        .filter(|c| discover_attached_component(Path::new(&c.local_path)).is_none())
+        .map(|c| RepairIssue {
+            check: "components",
+            message: format!(
+                "Component '{}' points to '{}' but no homeboy.json was found",
+                c.id, c.local_path
+            ),
+            fix: Some(RepairFix::DetachComponent {
+                component_id: c.id.clone(),
+            }),
+            choices: Vec::new(),
+        })
+        .collect()
+}
+
+fn detect_orphaned_overrides(project: &Project) -> Vec<RepairIssue> {
+    let mut orphaned: Vec<&String> = project
+        .component_overrides
+        .keys()
+        .filter(|id| !project.components.iter().any(|c| &&c.id == id))
+        .collect();
+    orphaned.sort();
+    orphaned
+        .into_iter()
+        .map(|id| RepairIssue {
+            check: "component_overrides",
+            message: format!("Overrides for '{}', which is not attached", id),
+            fix: Some(RepairFix::DropOverride {
+                component_id: id.clone(),
+            }),
+            choices: Vec::new(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::project::{ProjectComponentAttachment, ProjectComponentOverrides};
+    use crate::test_support::with_isolated_home;
+
+    fn broken_project(checkout: &Path) -> Project {
+        let mut project = Project {
+            id: "site".to_string(),
+            server_id: Some("gone".to_string()),
+            components: vec![
+                ProjectComponentAttachment {
+                    id: "theme".to_string(),
+                    local_path: checkout.to_string_lossy().to_string(),
+                },
+                ProjectComponentAttachment {
+                    id: "plugin".to_string(),
+                    local_path: "/nonexistent/plugin".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+        project.component_overrides.insert(
+            "old-plugin".to_string(),
+            ProjectComponentOverrides::default(),
+        );
+        project
+    }
+
+    #[test]
+    fn test_detect() {
+        with_isolated_home(|home| {
+            let checkout = home.path().join("theme");
+            std::fs::create_dir_all(&checkout).unwrap();
+            std::fs::write(checkout.join("homeboy.json"), r#"{"id":"theme"}"#).unwrap();
+
+            let issues = detect(&broken_project(&checkout));
+
+            let checks: Vec<&str> = issues.iter().map(|i| i.check).collect();
+            assert_eq!(checks, ["server", "components", "component_overrides"]);
+            assert_eq!(issues[0].fix, None);
+            assert_eq!(
+                issues[1].fix,
+                Some(RepairFix::DetachComponent {
+                    component_id: "plugin".to_string()
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn test_repair() {
+        with_isolated_home(|home| {
+            let checkout = home.path().join("theme");
+            std::fs::create_dir_all(&checkout).unwrap();
+            std::fs::write(checkout.join("homeboy.json"), r#"{"id":"theme"}"#).unwrap();
+            save(&broken_project(&checkout)).unwrap();
+
+            // Accept proposed fixes; leave the server for the user to pick.
+            let (project, report) = repair("site", |issue| Ok(issue.fix.clone())).unwrap();
+
+            assert_eq!(report.unresolved(), 1);
+            assert_eq!(report.updated_fields, ["components", "component_overrides"]);
+            let saved = load("site").unwrap();
+            assert_eq!(saved.components.len(), 1);
+            assert!(saved.component_overrides.is_empty());
+            assert_eq!(saved.server_id.as_deref(), Some("gone"));
+            assert_eq!(project.components[0].id, "theme");
+        });
+    }
+
+    #[test]
+    fn repair_saves_nothing_when_every_issue_is_skipped() {
+        with_isolated_home(|home| {
+            save(&broken_project(home.path())).unwrap();
+
+            let (_, report) = repair("site", |_| Ok(None)).unwrap();
+
+            assert!(report.updated_fields.is_empty());
+            assert_eq!(report.unresolved(), 4);
+            assert_eq!(load("site").unwrap().components.len(), 2);
+        });
+    }
+}
@@ -3,13 +3,15 @@ use serde::Serialize;
 use crate::error::Result;
 use crate::output::{CreateOutput, EntityCrudOutput, MergeOutput, RemoveResult};
 
-use super::{calculate_deploy_readiness, collect_status, list, load, Project};
+use super::{calculate_deploy_readiness, collect_status, list_tagged, load, Project};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ProjectListItem {
     pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -38,6 +40,19 @@ pub struct ProjectListReport {
     pub hint: Option<String>,
 }
 
+impl crate::output::CsvRows for ProjectListReport {
+    fn csv_header(&self) -> &'static [&'static str] {
+        &["id", "domain", "tags"]
+    }
+
+    fn to_csv_rows(&self) -> Vec<serde_json::Value> {
+        self.projects
+            .iter()
+            .filter_map(|item| serde_json::to_value(item).ok())
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ProjectStatusReport {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,18 +79,24 @@ pub struct ProjectReportExtra {
     pub health: Option<crate::server::health::ServerHealth>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub component_versions: Option<Vec<ProjectComponentVersion>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_url: Option<crate::project::from_url::FromUrlPlan>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repairs: Option<Vec<crate::project::RepairOutcome>>,
 }
 
 pub type ProjectReportOutput = EntityCrudOutput<Project, ProjectReportExtra>;
 
-pub fn list_report() -> Result<ProjectListReport> {
-    let projects = list()?;
+/// List projects carrying every tag in `tags` (all projects when empty).
+pub fn list_report(tags: &[String]) -> Result<ProjectListReport> {
+    let projects = list_tagged(tags)?;
 
     let items: Vec<ProjectListItem> = projects
         .into_iter()
         .map(|p| ProjectListItem {
             id: p.id,
             domain: p.domain,
+            tags: p.tags,
         })
         .collect();
 
@@ -248,6 +269,27 @@ pub fn build_rename_output(project: Project) -> ProjectReportOutput {
     }
 }
 
+pub fn build_tag_output(action: &str, project: Project) -> ProjectReportOutput {
+    ProjectReportOutput {
+        command: format!("project.tag.{}", action),
+        id: Some(project.id.clone()),
+        entity: Some(project),
+        updated_fields: vec!["tags".to_string()],
+        ..Default::default()
+    }
+}
+
+pub fn build_clone_output(source_id: &str, result: super::CloneResult) -> ProjectReportOutput {
+    ProjectReportOutput {
+        command: "project.clone".to_string(),
+        id: Some(result.project.id.clone()),
+        entity: Some(result.project),
+        updated_fields: result.updated_fields,
+        hint: Some(format!("Cloned from project '{}'", source_id)),
+        ..Default::default()
+    }
+}
+
 pub fn build_delete_output(project_id: &str) -> ProjectReportOutput {
     ProjectReportOutput {
         command: "project.delete".to_string(),
@@ -303,6 +345,32 @@ pub fn build_status_output(project_id: &str, report: ProjectStatusReport) -> Pro
     }
 }
 
+pub fn build_repair_output(
+    project: Project,
+    report: crate::project::ProjectRepairReport,
+) -> ProjectReportOutput {
+    let hint = match report.unresolved() {
+        0 if report.repairs.is_empty() => Some("No problems found".to_string()),
+        0 => None,
+        n => Some(format!(
+            "{} issue(s) left unrepaired. Re-run in a terminal to choose fixes, or pass --yes (and --server <id> for a missing server)",
+            n
+        )),
+    };
+    ProjectReportOutput {
+        command: "project.repair".to_string(),
+        id: Some(project.id.clone()),
+        entity: Some(project),
+        updated_fields: report.updated_fields,
+        hint,
+        extra: ProjectReportExtra {
+            repairs: Some(report.repairs),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 pub fn build_init_output(project_id: &str, dir: &std::path::Path) -> ProjectReportOutput {
     ProjectReportOutput {
         command: "project.init".to_string(),
@@ -53,6 +53,8 @@ fn collect_component_versions(project_id: &str) -> Option<Vec<ProjectComponentSt
         no_pull: true,
         head: true,
         tagged: false,
+        no_verify: false,
+        step_filter: Default::default(),
     };
 
     deploy::run(project_id, &config).ok().map(|result| {
@@ -0,0 +1,438 @@
+//! Built-in JS/TS signature extraction for signature-consistency checks.
+//!
+//! Grammars rarely model class-field arrow functions, so JS/TS declarations
+//! are scanned directly: function declarations, class methods (including
+//! `async`, `static`, generators, and accessors), and `name = (...) =>` /
+//! `name = function (...)` fields and bindings. Each is rendered as
+//! `[modifiers ]name(params)[: ReturnType]`, with parameter types dropped and
+//! optional or defaulted parameters marked `?`, so siblings compare by arity,
+//! optionality, and `async`-ness rather than by formatting.
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use super::signatures::MethodSignature;
+use crate::code_audit::conventions::Language;
+
+/// How many lines a declaration's parameter list may span.
+const MAX_DECLARATION_LINES: usize = 20;
+
+static FUNCTION_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*(?:export\s+)?(?:default\s+)?(?:declare\s+)?(async\s+)?function\s*(\*)?\s*([A-Za-z_$][\w$]*)\s*(?:<[^(]*>)?\s*\(",
+    )
+    .unwrap()
+});
+
+static METHOD_DECL: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*((?:(?:public|private|protected|static|async|override|get|set)\s+)*)(\*\s*)?(#?[A-Za-z_$][\w$]*)\s*\??\s*(?:<[^(]*>)?\s*\(",
+    )
+    .unwrap()
+});
+
+static FIELD_FUNCTION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^\s*((?:(?:export|const|let|var|public|private|protected|static|readonly|override)\s+)*)(#?[A-Za-z_$][\w$]*)\s*(?::[^=]*)?=\s*(async\s+)?(?:function\s*(\*)?\s*[\w$]*\s*)?(?:<[^(]*>)?\s*\(",
+    )
+    .unwrap()
+});
+
+/// Call-like words that open a block but never declare a method.
+const KEYWORDS: &[&str] = &[
+    "if", "for", "while", "switch", "catch", "with", "return", "function", "typeof", "new",
+    "await", "yield", "super", "import", "do", "else",
+];
+
+/// Extract JS/TS function and method signatures from `content`.
+pub(crate) fn extract_js_signatures(content: &str, language: &Language) -> Vec<MethodSignature> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut signatures = Vec::new();
+
+    for start in 0..lines.len() {
+        let end = (start + MAX_DECLARATION_LINES).min(lines.len());
+        let window = lines[start..end].join("\n");
+        if let Some(signature) = parse_declaration(&window) {
+            signatures.push(MethodSignature {
+                name: signature.name,
+                signature: signature.rendered,
+                language: language.clone(),
+                body: super::signatures::extract_method_body(&lines, start),
+            });
+        }
+    }
+
+    signatures
+}
+
+struct Declaration {
+    name: String,
+    rendered: String,
+}
+
+/// Parse a declaration starting on the first line of `text`.
+fn parse_declaration(text: &str) -> Option<Declaration> {
+    // Only the first line may hold the declaration head.
+    let first_line_len = text.find('\n').unwrap_or(text.len());
+
+    if let Some(caps) = FUNCTION_DECL.captures(text) {
+        let open = caps.get(0)?.end() - 1;
+        let mut modifiers = Vec::new();
+        if caps.get(1).is_some() {
+            modifiers.push("async");
+        }
+        let generator = caps.get(2).is_some();
+        let name = caps[3].to_string();
+        return finish(
+            text,
+            open,
+            first_line_len,
+            name,
+            modifiers,
+            generator,
+            false,
+        );
+    }
+
+    if let Some(caps) = FIELD_FUNCTION.captures(text) {
+        let open = caps.get(0)?.end() - 1;
+        let mut modifiers: Vec<&str> = caps[1]
+            .split_whitespace()
+            .filter(|word| *word == "static")
+            .collect();
+        if caps.get(3).is_some() {
+            modifiers.push("async");
+        }
+        let generator = caps.get(4).is_some();
+        let is_arrow = !caps[0].contains("function");
+        let name = caps[2].to_string();
+        return finish(
+            text,
+            open,
+            first_line_len,
+            name,
+            modifiers,
+            generator,
+            is_arrow,
+        );
+    }
+
+    if let Some(caps) = METHOD_DECL.captures(text) {
+        let name = caps[3].to_string();
+        if KEYWORDS.contains(&name.as_str()) {
+            return None;
+        }
+        let open = caps.get(0)?.end() - 1;
+        let modifiers: Vec<&str> = caps[1]
+            .split_whitespace()
+            .filter(|word| matches!(*word, "static" | "async" | "get" | "set"))
+            .collect();
+        let generator = caps.get(2).is_some();
+        return finish(
+            text,
+            open,
+            first_line_len,
+            name,
+            modifiers,
+            generator,
+            false,
+        );
+    }
+
+    None
+}
+
+fn finish(
+    text: &str,
+    open: usize,
+    first_line_len: usize,
+    name: String,
+    modifiers: Vec<&str>,
+    generator: bool,
+    is_arrow: bool,
+) -> Option<Declaration> {
+    if open >= first_line_len {
+        return None;
+    }
+    let close = matching_paren(text, open)?;
+    let params = render_params(&text[open + 1..close]);
+
+    // What follows the parameter list decides whether this declares a
+    // function: a body `{` (methods) or `=>` (arrows). Calls end in `;`,
+    // `)`, or an operator instead.
+    let rest = text[close + 1..].trim_start();
+    let (return_type, after) = split_return_type(rest, is_arrow);
+    let declares = if is_arrow {
+        after.starts_with("=>")
+    } else {
+        after.starts_with('{')
+    };
+    if !declares {
+        return None;
+    }
+
+    let mut rendered = String::new();
+    for modifier in &modifiers {
+        rendered.push_str(modifier);
+        rendered.push(' ');
+    }
+    if generator {
+        rendered.push('*');
+    }
+    rendered.push_str(&name);
+    rendered.push('(');
+    rendered.push_str(&params.join(", "));
+    rendered.push(')');
+    if let Some(return_type) = return_type {
+        rendered.push_str(": ");
+        rendered.push_str(&return_type);
+    }
+
+    Some(Declaration { name, rendered })
+}
+
+/// Index of the `)` closing the `(` at `open`.
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, ch) in text[open..].char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split an optional `: ReturnType` off the text after a parameter list.
+fn split_return_type(rest: &str, is_arrow: bool) -> (Option<String>, &str) {
+    let Some(annotation) = rest.strip_prefix(':') else {
+        return (None, rest);
+    };
+    let end = if is_arrow {
+        annotation.find("=>")
+    } else {
+        annotation.find('{')
+    };
+    match end {
+        Some(end) => {
+            let return_type = annotation[..end].split_whitespace().collect::<Vec<_>>();
+            (
+                Some(return_type.join(" ")).filter(|text| !text.is_empty()),
+                &annotation[end..],
+            )
+        }
+        None => (None, rest),
+    }
+}
+
+/// Reduce a parameter list to names: `...rest` keeps its spread, optional
+/// and defaulted parameters gain `?`, destructured ones become `{}`/`[]`.
+fn render_params(params: &str) -> Vec<String> {
+    split_top_level(params)
+        .into_iter()
+        .filter_map(|param| {
+            let mut param = param.trim();
+            for modifier in [
+                "public ",
+                "private ",
+                "protected ",
+                "readonly ",
+                "override ",
+            ] {
+                param = param.strip_prefix(modifier).unwrap_or(param).trim_start();
+            }
+            if param.is_empty() {
+                return None;
+            }
+            let (spread, param) = match param.strip_prefix("...") {
+                Some(rest) => ("...", rest.trim_start()),
+                None => ("", param),
+            };
+            let name = if param.starts_with('{') {
+                "{}".to_string()
+            } else if param.starts_with('[') {
+                "[]".to_string()
+            } else {
+                param
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                    .collect()
+            };
+            // TypeScript's `this` parameter only types the receiver.
+            if name == "this" {
+                return None;
+            }
+            let after_name = top_level_tail(param);
+            let optional = after_name.starts_with('?') || has_top_level_default(param);
+            Some(format!(
+                "{}{}{}",
+                spread,
+                name,
+                if optional && spread.is_empty() {
+                    "?"
+                } else {
+                    ""
+                }
+            ))
+        })
+        .collect()
+}
+
+/// Text after a parameter's name or destructuring pattern.
+fn top_level_tail(param: &str) -> &str {
+    if param.starts_with('{') || param.starts_with('[') {
+        let mut depth = 0;
+        for (i, ch) in param.char_indices() {
+            match ch {
+                '{' | '[' => depth += 1,
+                '}' | ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return param[i + 1..].trim_start();
+                    }
+                }
+                _ => {}
+            }
+        }
+        return "";
+    }
+    let name_len = param
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(param.len());
+    param[name_len..].trim_start()
+}
+
+fn has_top_level_default(param: &str) -> bool {
+    let mut depth = 0;
+    let chars: Vec<char> = param.chars().collect();
+    for (i, ch) in chars.iter().enumerate() {
+        match ch {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            '=' if depth == 0 => {
+                let next = chars.get(i + 1);
+                let prev = i.checked_sub(1).and_then(|p| chars.get(p));
+                if next != Some(&'>') && next != Some(&'=') && prev != Some(&'=') {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Split on commas outside brackets, braces, parens, and generics.
+fn split_top_level(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    let mut prev = '\0';
+    for (i, ch) in text.char_indices() {
+        match ch {
+            '(' | '[' | '{' | '<' => depth += 1,
+            // `=>` inside a function-typed parameter is not a closing `>`.
+            '>' if prev == '=' => {}
+            ')' | ']' | '}' | '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        prev = ch;
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rendered(content: &str) -> Vec<String> {
+        extract_js_signatures(content, &Language::TypeScript)
+            .into_iter()
+            .map(|sig| sig.signature)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_js_signatures() {
+        let content = r#"
+export async function loadConfig(path: string, options: Record<string, unknown> = {}): Promise<Config> {
+    return read(path);
+}
+
+export class Handler extends Base {
+    static create(id) {
+        return new Handler(id);
+    }
+
+    async handle(req: Request, res?: Response): Promise<void> {
+        if (req.ok) {
+            await this.send(res);
+        }
+    }
+
+    *entries() {
+        yield 1;
+    }
+
+    get name(): string {
+        return "handler";
+    }
+
+    validate = async ({ body }, ...rest) => {
+        return check(body, rest);
+    };
+
+    private format = function (value) {
+        return String(value);
+    };
+}
+
+const render = (
+    template,
+    context,
+) => template(context);
+"#;
+
+        assert_eq!(
+            rendered(content),
+            vec![
+                "async loadConfig(path, options?): Promise<Config>",
+                "static create(id)",
+                "async handle(req, res?): Promise<void>",
+                "*entries()",
+                "get name(): string",
+                "async validate({}, ...rest)",
+                "format(value)",
+                "render(template, context)",
+            ]
+        );
+    }
+
+    #[test]
+    fn extract_js_signatures_skips_calls_and_control_flow() {
+        let content = r#"
+handle(request);
+if (ready) {
+    run(ready);
+}
+const value = compute(a, b);
+interface Shape {
+    area(scale: number): number;
+}
+"#;
+
+        assert!(rendered(content).is_empty());
+    }
+}
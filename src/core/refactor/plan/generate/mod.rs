@@ -5,6 +5,7 @@ mod convention_fixes;
 mod doc_fixes;
 mod duplicate_fixes;
 mod intra_duplicate_fixes;
+mod js_signatures;
 mod module_surface;
 mod near_duplicate_fixes;
 mod orphaned_test_fixes;
@@ -206,7 +206,7 @@ pub(crate) fn extract_signatures_from_items(
 /// Extract the body of a method from source lines, starting from the
 /// declaration line. Finds the opening `{` and walks to the matching `}`,
 /// returning the lines between them (the body content).
-fn extract_method_body(lines: &[&str], start_line: usize) -> Option<String> {
+pub(super) fn extract_method_body(lines: &[&str], start_line: usize) -> Option<String> {
     let mut brace_depth = 0i32;
     let mut found_open = false;
     let mut body_start_line = start_line + 1;
@@ -242,6 +242,15 @@ fn extract_method_body(lines: &[&str], start_line: usize) -> Option<String> {
     None
 }
 
+/// Signatures for consistency checks. JS/TS use the built-in scanner, which
+/// also sees arrow-function fields, and fall back to the grammar when it
+/// finds nothing.
 pub(crate) fn extract_signatures(content: &str, language: &Language) -> Vec<MethodSignature> {
+    if matches!(language, Language::JavaScript | Language::TypeScript) {
+        let signatures = super::js_signatures::extract_js_signatures(content, language);
+        if !signatures.is_empty() {
+            return signatures;
+        }
+    }
     extract_signatures_from_items(content, language)
 }
@@ -0,0 +1,266 @@
+//! Pin release artifacts to a version.
+//!
+//! `release --output <dir>` copies the artifacts the `package` step produced
+//! into a local directory and records them, with SHA-256 checksums, in a
+//! per-component manifest under the config dir. `release artifacts` reads that
+//! manifest back so an exact historical build can be located and re-deployed.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::engine::local_files::{self, FileSystem};
+use crate::error::{Error, Result};
+use crate::paths;
+
+use super::types::ReleaseArtifact;
+
+/// One artifact copied out of a release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinnedArtifact {
+    /// The local copy, in `ReleaseArtifact` shape so a pin's artifact list can
+    /// be fed back through `parse_release_artifacts`.
+    #[serde(flatten)]
+    pub artifact: ReleaseArtifact,
+    /// Where the package step wrote the artifact.
+    pub source_path: String,
+    pub sha256: String,
+    pub size: u64,
+}
+
+/// Artifacts pinned to one released version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArtifactPin {
+    pub component_id: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    pub pinned_at: String,
+    pub artifacts: Vec<PinnedArtifact>,
+}
+
+/// On-disk manifest: version → pin.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ArtifactManifest {
+    #[serde(default)]
+    pins: BTreeMap<String, ArtifactPin>,
+}
+
+/// Copy `artifacts` into `output_dir/<version>/` and record them in the
+/// component's manifest. Relative artifact paths resolve against
+/// `local_path`, the directory the package step ran in. Re-pinning a version
+/// replaces its previous entry.
+pub fn pin_artifacts(
+    component_id: &str,
+    version: &str,
+    tag: Option<&str>,
+    artifacts: &[ReleaseArtifact],
+    local_path: &str,
+    output_dir: &Path,
+) -> Result<ArtifactPin> {
+    let version_dir = output_dir.join(version);
+    fs::create_dir_all(&version_dir).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("create {}", version_dir.display())),
+        )
+    })?;
+
+    let mut pinned = Vec::with_capacity(artifacts.len());
+    for artifact in artifacts {
+        let source = paths::resolve_path(local_path, &artifact.path);
+        let file_name = source.file_name().ok_or_else(|| {
+            Error::validation_invalid_argument(
+                "release.artifacts",
+                format!("Artifact path '{}' has no file name", artifact.path),
+                None,
+                None,
+            )
+        })?;
+        let target = version_dir.join(file_name);
+        let size = fs::copy(&source, &target).map_err(|e| {
+            Error::internal_io(
+                e.to_string(),
+                Some(format!(
+                    "copy artifact {} to {}",
+                    source.display(),
+                    target.display()
+                )),
+            )
+        })?;
+
+        pinned.push(PinnedArtifact {
+            artifact: ReleaseArtifact {
+                path: absolute(&target).to_string_lossy().to_string(),
+                ..artifact.clone()
+            },
+            source_path: source.to_string_lossy().to_string(),
+            sha256: sha256_file(&target)?,
+            size,
+        });
+    }
+
+    let pin = ArtifactPin {
+        component_id: component_id.to_string(),
+        version: version.to_string(),
+        tag: tag.map(str::to_string),
+        pinned_at: chrono::Utc::now().to_rfc3339(),
+        artifacts: pinned,
+    };
+
+    let mut manifest = load_manifest(component_id)?;
+    manifest.pins.insert(version.to_string(), pin.clone());
+    save_manifest(component_id, &manifest)?;
+
+    Ok(pin)
+}
+
+/// Pinned artifacts for a component, newest version first. With `version`,
+/// only that version (an error if it was never pinned).
+pub fn list_pins(component_id: &str, version: Option<&str>) -> Result<Vec<ArtifactPin>> {
+    let mut manifest = load_manifest(component_id)?;
+
+    if let Some(version) = version {
+        let version = version.trim_start_matches('v');
+        return match manifest.pins.remove(version) {
+            Some(pin) => Ok(vec![pin]),
+            None => Err(Error::validation_invalid_argument(
+                "version",
+                format!(
+                    "No pinned artifacts for {} version {}",
+                    component_id, version
+                ),
+                Some(version.to_string()),
+                Some(manifest.pins.into_keys().rev().collect()),
+            )),
+        };
+    }
+
+    let mut pins: Vec<ArtifactPin> = manifest.pins.into_values().collect();
+    pins.sort_by(|a, b| compare_versions(&b.version, &a.version));
+    Ok(pins)
+}
+
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+fn load_manifest(component_id: &str) -> Result<ArtifactManifest> {
+    let path = paths::release_artifact_manifest(component_id)?;
+    if !path.exists() {
+        return Ok(ArtifactManifest::default());
+    }
+    let raw = local_files::local().read(&path)?;
+    serde_json::from_str(&raw).map_err(|e| {
+        Error::internal_json(
+            e.to_string(),
+            Some(format!("parse artifact manifest {}", path.display())),
+        )
+    })
+}
+
+fn save_manifest(component_id: &str, manifest: &ArtifactManifest) -> Result<()> {
+    let path = paths::release_artifact_manifest(component_id)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| {
+            Error::internal_io(e.to_string(), Some(format!("create {}", parent.display())))
+        })?;
+    }
+    let content = serde_json::to_string_pretty(manifest).map_err(|e| {
+        Error::internal_json(e.to_string(), Some("serialize artifact manifest".into()))
+    })?;
+    local_files::write_file_atomic(&path, &content, "write artifact manifest")
+}
+
+fn sha256_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path).map_err(|e| {
+        Error::internal_io(
+            e.to_string(),
+            Some(format!("read artifact bytes {}", path.display())),
+        )
+    })?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::release::parse_release_artifacts;
+
+    #[test]
+    fn test_pin_artifacts() {
+        crate::test_support::with_isolated_home(|home| {
+            let package_dir = home.path().join("plugin");
+            fs::create_dir_all(package_dir.join("build")).unwrap();
+            fs::write(package_dir.join("build/plugin.zip"), b"zip-bytes").unwrap();
+            let artifacts = parse_release_artifacts(&serde_json::json!([
+                {"path": "build/plugin.zip", "type": "zip"}
+            ]))
+            .unwrap();
+            let output = home.path().join("out");
+
+            let pin = pin_artifacts(
+                "plugin",
+                "1.2.0",
+                Some("v1.2.0"),
+                &artifacts,
+                &package_dir.to_string_lossy(),
+                &output,
+            )
+            .expect("pin");
+
+            let pinned = &pin.artifacts[0];
+            assert!(output.join("1.2.0/plugin.zip").is_file());
+            assert_eq!(pinned.size, 9);
+            assert_eq!(pinned.artifact.artifact_type.as_deref(), Some("zip"));
+            assert_eq!(pinned.sha256, format!("{:x}", Sha256::digest(b"zip-bytes")));
+
+            let reparsed =
+                parse_release_artifacts(&serde_json::to_value(&pin.artifacts).unwrap()).unwrap();
+            assert_eq!(reparsed[0].path, pinned.artifact.path);
+        });
+    }
+
+    #[test]
+    fn list_pins_orders_newest_first_and_filters_by_version() {
+        crate::test_support::with_isolated_home(|home| {
+            let package_dir = home.path().join("plugin");
+            fs::create_dir_all(&package_dir).unwrap();
+            fs::write(package_dir.join("plugin.zip"), b"x").unwrap();
+            let artifacts = parse_release_artifacts(&serde_json::json!(["plugin.zip"])).unwrap();
+            let local_path = package_dir.to_string_lossy().to_string();
+            for version in ["1.9.0", "1.10.0"] {
+                pin_artifacts(
+                    "plugin",
+                    version,
+                    None,
+                    &artifacts,
+                    &local_path,
+                    &home.path().join("out"),
+                )
+                .unwrap();
+            }
+
+            let versions: Vec<String> = list_pins("plugin", None)
+                .unwrap()
+                .into_iter()
+                .map(|pin| pin.version)
+                .collect();
+            assert_eq!(versions, ["1.10.0", "1.9.0"]);
+
+            assert_eq!(list_pins("plugin", Some("v1.9.0")).unwrap().len(), 1);
+            let err = list_pins("plugin", Some("2.0.0")).unwrap_err();
+            assert!(err.message.contains("No pinned artifacts"));
+        });
+    }
+}
@@ -4,7 +4,10 @@ use crate::component;
 use crate::engine::local_files;
 use crate::error::Result;
 
+use super::format::*;
 use super::io::*;
+use super::range::*;
+use super::settings::*;
 
 // === Changelog Show Operations ===
 
@@ -16,6 +19,11 @@ pub struct ShowOutput {
 }
 
 pub fn show(component_id: &str) -> Result<ShowOutput> {
+    show_range(component_id, &ReleaseRange::default())
+}
+
+/// Show the part of a component's changelog `range` selects.
+pub fn show_range(component_id: &str, range: &ReleaseRange) -> Result<ShowOutput> {
     let component = component::resolve_effective(Some(component_id), None, None)?;
     let changelog_path = resolve_changelog_path(&component)?;
 
@@ -23,6 +31,13 @@ pub fn show(component_id: &str) -> Result<ShowOutput> {
         &changelog_path,
         &format!("read changelog at {}", changelog_path.display()),
     )?;
+    let settings = resolve_effective_settings(Some(&component));
+    let content = format_for(&component)?.to_markdown(&content, &settings.next_section_aliases)?;
+    let content = if range.is_full() {
+        content
+    } else {
+        slice_releases(&content, range, &settings.next_section_aliases)?
+    };
 
     Ok(ShowOutput {
         component_id: component_id.to_string(),
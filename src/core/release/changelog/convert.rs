@@ -0,0 +1,209 @@
+//! `changelog convert`: move a component's changelog to another format.
+//!
+//! Conversion is refused unless it is lossless in both directions: the source
+//! must parse without dropping content, and the converted file must parse
+//! back to the same document.
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::component;
+use crate::engine::local_files::{self, FileSystem};
+use crate::error::{Error, Result};
+
+use super::format::*;
+use super::io::*;
+use super::settings::*;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConvertOutput {
+    pub component_id: String,
+    pub from: String,
+    pub to: String,
+    pub source_path: String,
+    pub target_path: String,
+    pub releases: usize,
+    pub entries: usize,
+    pub dry_run: bool,
+    /// Converted content, returned instead of written on `--dry-run`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Whether `changelog_format` / `changelog_target` were updated.
+    pub config_updated: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+}
+
+/// Convert `component_id`'s changelog to the `to` format. The target path
+/// defaults to the current path with the new format's extension; `output` is
+/// relative to the component's `local_path`.
+pub fn convert(
+    component_id: &str,
+    to: &str,
+    output: Option<&str>,
+    dry_run: bool,
+) -> Result<ConvertOutput> {
+    let component = component::resolve_effective(Some(component_id), None, None)?;
+    let source_format = format_for(&component)?;
+    let target_format = changelog_format(to)?;
+    let settings = resolve_effective_settings(Some(&component));
+    let aliases = &settings.next_section_aliases;
+
+    let source_path = resolve_changelog_path(&component)?;
+    let content = local_files::read_file(
+        &source_path,
+        &format!("read changelog at {}", source_path.display()),
+    )?;
+
+    let document = source_format.parse(&content, aliases)?;
+    source_format.verify_parse(&content, &document)?;
+
+    let converted = target_format.render(&document);
+    if !same_content(&target_format.parse(&converted, aliases)?, &document) {
+        return Err(Error::validation_invalid_argument(
+            "to",
+            format!(
+                "Converting to {} would not round-trip; nothing was written",
+                target_format.id()
+            ),
+            None,
+            None,
+        ));
+    }
+
+    let local_path = Path::new(&component.local_path);
+    let target_path = match output {
+        Some(output) => crate::paths::resolve_path(&component.local_path, output),
+        None => source_path.with_extension(target_format.extension()),
+    };
+    if target_path != source_path && target_path.exists() {
+        return Err(Error::validation_invalid_argument(
+            "output",
+            format!("{} already exists", target_path.display()),
+            None,
+            Some(vec![
+                "Pass --output <path> to write somewhere else".to_string()
+            ]),
+        ));
+    }
+
+    let mut hints = Vec::new();
+    let mut config_updated = false;
+    if !dry_run {
+        local_files::local().write(&target_path, &converted)?;
+
+        let target = relative_target(local_path, &target_path);
+        let format_id = target_format.id();
+        component::mutate_portable(component_id, |component| {
+            component.changelog_format =
+                (format_id != DEFAULT_CHANGELOG_FORMAT).then(|| format_id.to_string());
+            component.changelog_target = Some(target.clone());
+            Ok(())
+        })?;
+        config_updated = true;
+
+        if target_path != source_path {
+            hints.push(format!(
+                "{} is no longer used; remove it once you have checked the conversion",
+                source_path.display()
+            ));
+        }
+    }
+
+    Ok(ConvertOutput {
+        component_id: component_id.to_string(),
+        from: source_format.id().to_string(),
+        to: target_format.id().to_string(),
+        source_path: source_path.to_string_lossy().to_string(),
+        target_path: target_path.to_string_lossy().to_string(),
+        releases: document.releases.len(),
+        entries: document.entry_count(),
+        dry_run,
+        content: dry_run.then_some(converted),
+        config_updated,
+        hints,
+    })
+}
+
+/// Documents are the same changelog if everything but the heading text of
+/// versioned releases matches; those headings are derived from version and
+/// date by formats like `conventional`.
+fn same_content(a: &ChangelogDocument, b: &ChangelogDocument) -> bool {
+    a.preamble == b.preamble
+        && a.releases.len() == b.releases.len()
+        && a.releases.iter().zip(&b.releases).all(|(x, y)| {
+            (x.version.is_some() || x.heading == y.heading)
+                && x.version == y.version
+                && x.date == y.date
+                && x.unreleased == y.unreleased
+                && x.groups == y.groups
+        })
+}
+
+fn relative_target(local_path: &Path, target: &Path) -> String {
+    target
+        .strip_prefix(local_path)
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| target.to_path_buf())
+        .to_string_lossy()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+    use std::fs;
+
+    const CHANGELOG: &str = "# Changelog\n\n## Unreleased\n\n### Added\n- Pending\n\n## [1.0.0] - 2025-01-14\n\n### Fixed\n- One\n";
+
+    fn write_component(home: &Path) -> PathBuf {
+        let repo = home.join("convert-me");
+        fs::create_dir_all(&repo).unwrap();
+        fs::write(repo.join("CHANGELOG.md"), CHANGELOG).unwrap();
+        fs::write(
+            repo.join("homeboy.json"),
+            r#"{"id":"convert-me","changelog_target":"CHANGELOG.md"}"#,
+        )
+        .unwrap();
+
+        let standalone_dir = home.join(".config/homeboy/components");
+        fs::create_dir_all(&standalone_dir).unwrap();
+        fs::write(
+            standalone_dir.join("convert-me.json"),
+            serde_json::json!({ "local_path": repo.to_string_lossy() }).to_string(),
+        )
+        .unwrap();
+        repo
+    }
+
+    #[test]
+    fn test_convert_to_json_and_back_is_lossless() {
+        with_isolated_home(|home| {
+            let repo = write_component(home.path());
+
+            let out = convert("convert-me", "json", None, false).unwrap();
+            assert_eq!(out.from, "markdown");
+            assert_eq!(out.to, "json");
+            assert_eq!(out.releases, 2);
+            assert_eq!(out.entries, 2);
+            assert!(out.target_path.ends_with("CHANGELOG.json"));
+
+            let component = component::resolve_effective(Some("convert-me"), None, None).unwrap();
+            assert_eq!(component.changelog_format.as_deref(), Some("json"));
+            assert_eq!(
+                component.changelog_target.as_deref(),
+                Some("CHANGELOG.json")
+            );
+
+            fs::remove_file(repo.join("CHANGELOG.md")).unwrap();
+            let back = convert("convert-me", "markdown", None, false).unwrap();
+            assert!(back.target_path.ends_with("CHANGELOG.md"));
+            assert_eq!(
+                fs::read_to_string(repo.join("CHANGELOG.md")).unwrap(),
+                CHANGELOG
+            );
+        });
+    }
+}
@@ -0,0 +1,539 @@
+//! Pluggable changelog storage formats.
+//!
+//! Every format parses to and renders from the same [`ChangelogDocument`].
+//! Release operations (finalize, notes, snapshots) go through the
+//! [`ChangelogFormat`] trait: the default methods work on the parsed document,
+//! so a new format only has to implement `parse` and `render`. The markdown
+//! format overrides them with the line-preserving editors in `sections`, so
+//! hand-written Keep a Changelog files keep their exact layout.
+//!
+//! A component selects its format with `changelog_format` (default
+//! `markdown`).
+
+mod conventional;
+mod json;
+mod markdown;
+
+pub use conventional::*;
+pub use json::*;
+pub use markdown::*;
+
+use std::collections::HashMap;
+
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+
+use crate::component::Component;
+use crate::error::{Error, Result};
+
+use super::io::{FinalizedReleaseSnapshot, INITIAL_CHANGELOG_CONTENT};
+use super::settings::KEEP_A_CHANGELOG_SUBSECTIONS;
+
+pub const DEFAULT_CHANGELOG_FORMAT: &str = "markdown";
+
+/// A changelog independent of how it is stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogDocument {
+    /// Title and intro text before the first release, as markdown.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub preamble: String,
+    /// Newest first. The next (Unreleased) section, when present, is first.
+    #[serde(default)]
+    pub releases: Vec<ChangelogRelease>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogRelease {
+    /// Heading text as written, without the `## ` marker.
+    pub heading: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// The next section that release finalization turns into a version.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub unreleased: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<ChangelogGroup>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChangelogGroup {
+    /// Change type (`Added`, `Fixed`, ...). `None` for entries directly
+    /// under the release heading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Free text lines that are not entries, kept verbatim.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub entries: Vec<String>,
+}
+
+impl ChangelogDocument {
+    /// What release bootstrap writes for a component with no changelog yet.
+    pub fn initial() -> Self {
+        Self {
+            preamble: INITIAL_CHANGELOG_CONTENT.trim_end().to_string(),
+            releases: Vec::new(),
+        }
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.releases
+            .iter()
+            .map(ChangelogRelease::entry_count)
+            .sum()
+    }
+
+    fn next_section_mut(&mut self) -> Option<&mut ChangelogRelease> {
+        self.releases.iter_mut().find(|release| release.unreleased)
+    }
+
+    fn latest_release(&self) -> Option<&ChangelogRelease> {
+        self.releases
+            .iter()
+            .find(|release| !release.unreleased && release.version.is_some())
+    }
+}
+
+impl ChangelogRelease {
+    pub fn entries(&self) -> impl Iterator<Item = &String> {
+        self.groups.iter().flat_map(|group| group.entries.iter())
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.groups.iter().map(|group| group.entries.len()).sum()
+    }
+
+    /// Add `entry` under the `### <kind>` group for `entry_type`, creating the
+    /// group in Keep a Changelog order. Returns false for a duplicate.
+    fn add_entry(&mut self, entry_type: &str, entry: &str) -> bool {
+        if self.entries().any(|existing| existing == entry) {
+            return false;
+        }
+
+        let kind = group_kind_from_type(entry_type);
+        let index = match self
+            .groups
+            .iter()
+            .position(|group| group.kind.as_deref() == Some(kind.as_str()))
+        {
+            Some(index) => index,
+            None => {
+                let rank = group_rank(&kind);
+                let index = self
+                    .groups
+                    .iter()
+                    .position(|group| {
+                        group
+                            .kind
+                            .as_deref()
+                            .is_some_and(|existing| group_rank(existing) > rank)
+                    })
+                    .unwrap_or(self.groups.len());
+                self.groups.insert(
+                    index,
+                    ChangelogGroup {
+                        kind: Some(kind),
+                        ..Default::default()
+                    },
+                );
+                index
+            }
+        };
+        self.groups[index].entries.push(entry.to_string());
+        true
+    }
+}
+
+/// `added` -> `Added`, matching the markdown `### Added` subsection.
+fn group_kind_from_type(entry_type: &str) -> String {
+    let mut chars = entry_type.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn group_rank(kind: &str) -> usize {
+    KEEP_A_CHANGELOG_SUBSECTIONS
+        .iter()
+        .position(|header| header.trim_start_matches("### ").eq_ignore_ascii_case(kind))
+        .unwrap_or(KEEP_A_CHANGELOG_SUBSECTIONS.len())
+}
+
+/// A changelog storage format.
+///
+/// `next_section_aliases` are the labels that mark the next section (see
+/// [`super::resolve_effective_settings`]); formats that store the flag
+/// explicitly ignore them.
+pub trait ChangelogFormat: Sync {
+    /// Value of `changelog_format` that selects this format.
+    fn id(&self) -> &'static str;
+
+    /// File extension for a changelog in this format, without the dot.
+    fn extension(&self) -> &'static str;
+
+    fn parse(&self, content: &str, next_section_aliases: &[String]) -> Result<ChangelogDocument>;
+
+    fn render(&self, document: &ChangelogDocument) -> String;
+
+    /// Fail if `document` (parsed from `content`) dropped anything. The
+    /// default re-renders and compares line by line, ignoring blank lines,
+    /// whitespace runs, and the bullet marker.
+    fn verify_parse(&self, content: &str, document: &ChangelogDocument) -> Result<()> {
+        let rendered = self.render(document);
+        let original: Vec<String> = comparable_lines(content).collect();
+        let round_trip: Vec<String> = comparable_lines(&rendered).collect();
+        if original == round_trip {
+            return Ok(());
+        }
+
+        let index = original
+            .iter()
+            .zip(&round_trip)
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| original.len().min(round_trip.len()));
+        let line = original
+            .get(index)
+            .or_else(|| round_trip.get(index))
+            .cloned()
+            .unwrap_or_default();
+        Err(Error::validation_invalid_argument(
+            "changelog",
+            format!(
+                "The {} changelog has content the document model cannot represent near: {}",
+                self.id(),
+                line
+            ),
+            None,
+            Some(vec![
+                "Entries must be bullets under a '## ' release heading (optionally under '### ' change types)".to_string(),
+                "Text after an entry in the same group, or heading decorations like links, cannot be preserved".to_string(),
+            ]),
+        ))
+    }
+
+    /// Content written when release bootstraps a missing changelog.
+    fn initial_content(&self) -> String {
+        self.render(&ChangelogDocument::initial())
+    }
+
+    /// Markdown for `changelog show`.
+    fn to_markdown(&self, content: &str, next_section_aliases: &[String]) -> Result<String> {
+        Ok(MarkdownFormat.render(&self.parse(content, next_section_aliases)?))
+    }
+
+    fn latest_finalized_version(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+    ) -> Result<Option<String>> {
+        Ok(self
+            .parse(content, next_section_aliases)?
+            .latest_release()
+            .and_then(|release| release.version.clone()))
+    }
+
+    fn unreleased_entries(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+    ) -> Result<Vec<String>> {
+        Ok(self
+            .parse(content, next_section_aliases)?
+            .releases
+            .iter()
+            .find(|release| release.unreleased)
+            .map(|release| release.entries().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    /// Body of the latest finalized release as markdown, for release notes.
+    fn release_notes(&self, content: &str, next_section_aliases: &[String]) -> Option<String> {
+        let document = self.parse(content, next_section_aliases).ok()?;
+        let notes = render_release_body(document.latest_release()?);
+        (!notes.is_empty()).then_some(notes)
+    }
+
+    fn last_release_snapshot(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+    ) -> Option<FinalizedReleaseSnapshot> {
+        let document = self.parse(content, next_section_aliases).ok()?;
+        let release = document.latest_release()?;
+        let snapshot = FinalizedReleaseSnapshot {
+            tag: format!("v{}", release.version.as_deref()?),
+            date: release.date.clone(),
+            summary: release.entries().next().cloned(),
+        };
+        Some(snapshot)
+    }
+
+    /// Turn the next section into `## [new_version] - <today>`. With
+    /// `allow_empty`, an empty or missing-entries section is left alone and
+    /// `false` is returned instead of an error.
+    fn finalize_next_section(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+        new_version: &str,
+        allow_empty: bool,
+    ) -> Result<(String, bool)> {
+        let mut document = self.parse(content, next_section_aliases)?;
+        if !finalize_document(
+            &mut document,
+            next_section_aliases,
+            new_version,
+            allow_empty,
+        )? {
+            return Ok((content.to_string(), false));
+        }
+        Ok((self.render(&document), true))
+    }
+
+    /// Add an empty `version` release above the latest finalized one,
+    /// leaving the next section in place.
+    fn insert_release(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+        version: &str,
+    ) -> Result<String> {
+        let mut document = self.parse(content, next_section_aliases)?;
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let index = document
+            .releases
+            .iter()
+            .position(|release| !release.unreleased)
+            .unwrap_or(document.releases.len());
+        document.releases.insert(
+            index,
+            ChangelogRelease {
+                heading: format!("[{}] - {}", version.trim(), today),
+                version: Some(version.trim().to_string()),
+                date: Some(today),
+                ..Default::default()
+            },
+        );
+        Ok(self.render(&document))
+    }
+
+    /// Add generated entries (keyed by type, e.g. `added`) to the next
+    /// section and finalize it in one pass.
+    fn finalize_with_generated_entries(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+        entries_by_type: &HashMap<&str, Vec<String>>,
+        new_version: &str,
+    ) -> Result<(String, bool)> {
+        if entries_by_type.values().all(|messages| messages.is_empty()) {
+            return Ok((content.to_string(), false));
+        }
+
+        let mut document = self.parse(content, next_section_aliases)?;
+        if document.next_section_mut().is_none() {
+            document.releases.insert(
+                0,
+                ChangelogRelease {
+                    heading: next_section_aliases
+                        .first()
+                        .cloned()
+                        .unwrap_or_else(|| "Unreleased".to_string()),
+                    unreleased: true,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let next = document
+            .next_section_mut()
+            .expect("next section was just ensured");
+        let mut types: Vec<&&str> = entries_by_type.keys().collect();
+        types.sort_by_key(|entry_type| group_rank(&group_kind_from_type(entry_type)));
+        for entry_type in types {
+            for message in &entries_by_type[*entry_type] {
+                let trimmed = message.trim();
+                if !trimmed.is_empty() {
+                    next.add_entry(entry_type, trimmed);
+                }
+            }
+        }
+
+        finalize_document(&mut document, next_section_aliases, new_version, false)?;
+        Ok((self.render(&document), true))
+    }
+}
+
+fn finalize_document(
+    document: &mut ChangelogDocument,
+    next_section_aliases: &[String],
+    new_version: &str,
+    allow_empty: bool,
+) -> Result<bool> {
+    let new_version = new_version.trim();
+    if new_version.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "newVersion",
+            "New version label cannot be empty",
+            None,
+            None,
+        ));
+    }
+
+    let Some(next) = document.next_section_mut() else {
+        return Err(Error::validation_invalid_argument(
+            "changelog",
+            format!(
+                "No unreleased changelog section found (looked for: {})",
+                next_section_aliases.join(", ")
+            ),
+            None,
+            None,
+        ));
+    };
+
+    if next.entry_count() == 0 {
+        if allow_empty {
+            return Ok(false);
+        }
+        return Err(
+            Error::validation_invalid_argument("changelog", "Changelog has no items", None, None)
+                .with_hint("Commit all changes before running version bump — homeboy generates changelog entries from conventional-prefixed commits (feat:/fix:/...) at release time."),
+        );
+    }
+
+    let today = Local::now().format("%Y-%m-%d").to_string();
+    next.heading = format!("[{}] - {}", new_version, today);
+    next.version = Some(new_version.to_string());
+    next.date = Some(today);
+    next.unreleased = false;
+    Ok(true)
+}
+
+fn comparable_lines(content: &str) -> impl Iterator<Item = String> + '_ {
+    content.lines().filter_map(|line| {
+        let line = line.trim_end();
+        if line.is_empty() {
+            return None;
+        }
+        let line = match line.strip_prefix("* ") {
+            Some(rest) => format!("- {}", rest),
+            None => line.to_string(),
+        };
+        Some(line.split_whitespace().collect::<Vec<_>>().join(" "))
+    })
+}
+
+static FORMATS: &[&dyn ChangelogFormat] = &[&MarkdownFormat, &JsonFormat, &ConventionalFormat];
+
+/// Ids accepted by `changelog_format`.
+pub fn changelog_format_ids() -> Vec<&'static str> {
+    FORMATS.iter().map(|format| format.id()).collect()
+}
+
+pub fn changelog_format(id: &str) -> Result<&'static dyn ChangelogFormat> {
+    FORMATS
+        .iter()
+        .copied()
+        .find(|format| format.id().eq_ignore_ascii_case(id.trim()))
+        .ok_or_else(|| {
+            Error::validation_invalid_argument(
+                "changelog_format",
+                format!("Unknown changelog format '{}'", id),
+                None,
+                Some(vec![format!(
+                    "Available formats: {}",
+                    changelog_format_ids().join(", ")
+                )]),
+            )
+        })
+}
+
+/// The format a component's changelog is stored in.
+pub fn format_for(component: &Component) -> Result<&'static dyn ChangelogFormat> {
+    changelog_format(
+        component
+            .changelog_format
+            .as_deref()
+            .unwrap_or(DEFAULT_CHANGELOG_FORMAT),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) const KEEP_A_CHANGELOG: &str = "# Changelog\n\nAll notable changes.\n\n## [Unreleased]\n\n### Fixed\n- Pending fix\n\n## [1.1.0] - 2025-02-01\n\n### Added\n- Two\n  with a continuation\n- Three\n\n### Fixed\n- Bug\n\n## [1.0.0] - 2025-01-14\n\nFirst public release.\n- Initial\n";
+
+    pub(super) fn aliases() -> Vec<String> {
+        vec!["Unreleased".to_string(), "[Unreleased]".to_string()]
+    }
+
+    #[test]
+    fn test_changelog_format_lookup() {
+        assert_eq!(changelog_format("JSON").unwrap().id(), "json");
+        let err = changelog_format("yaml").err().unwrap();
+        assert_eq!(
+            err.details["tried"][0],
+            "Available formats: markdown, json, conventional"
+        );
+    }
+
+    #[test]
+    fn test_document_finalize_with_generated_entries() {
+        let mut entries = HashMap::new();
+        entries.insert(
+            "fixed",
+            vec!["Another fix".to_string(), "Pending fix".to_string()],
+        );
+        entries.insert("added", vec!["Feature".to_string()]);
+
+        let (out, changed) = JsonFormat
+            .finalize_with_generated_entries(
+                &JsonFormat.render(&MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap()),
+                &aliases(),
+                &entries,
+                "1.2.0",
+            )
+            .unwrap();
+        assert!(changed);
+
+        let document = JsonFormat.parse(&out, &aliases()).unwrap();
+        let release = &document.releases[0];
+        assert_eq!(release.version.as_deref(), Some("1.2.0"));
+        assert!(!release.unreleased);
+        let kinds: Vec<_> = release.groups.iter().map(|g| g.kind.as_deref()).collect();
+        assert_eq!(kinds, vec![Some("Added"), Some("Fixed")]);
+        assert_eq!(
+            release.groups[1].entries,
+            vec!["Pending fix", "Another fix"]
+        );
+        assert_eq!(
+            JsonFormat
+                .latest_finalized_version(&out, &aliases())
+                .unwrap(),
+            Some("1.2.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_document_finalize_requires_entries() {
+        let empty = "# Changelog\n\n## Unreleased\n\n## [1.0.0] - 2025-01-14\n\n- Initial\n";
+        let json = JsonFormat.render(&MarkdownFormat.parse(empty, &aliases()).unwrap());
+        assert!(JsonFormat
+            .finalize_next_section(&json, &aliases(), "1.1.0", false)
+            .is_err());
+        let (out, changed) = JsonFormat
+            .finalize_next_section(&json, &aliases(), "1.1.0", true)
+            .unwrap();
+        assert!(!changed);
+        assert_eq!(out, json);
+    }
+}
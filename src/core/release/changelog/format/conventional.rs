@@ -0,0 +1,102 @@
+//! conventional-changelog style markdown: `## 1.2.0 (2025-03-01)` headings
+//! and change types named after commit groups (`### Features`,
+//! `### Bug Fixes`).
+//!
+//! Release headings are derived from version and date, so parsing stores the
+//! Keep a Changelog heading and converting back to markdown yields
+//! `## [1.2.0] - 2025-03-01`.
+
+use crate::error::Result;
+
+use super::markdown::{parse_markdown, render_markdown};
+use super::{ChangelogDocument, ChangelogFormat, ChangelogRelease};
+
+/// Keep a Changelog change type -> conventional group title.
+const GROUP_TITLES: &[(&str, &str)] = &[
+    ("Added", "Features"),
+    ("Changed", "Changes"),
+    ("Deprecated", "Deprecations"),
+    ("Removed", "Removals"),
+    ("Fixed", "Bug Fixes"),
+    ("Security", "Security"),
+];
+
+pub struct ConventionalFormat;
+
+impl ChangelogFormat for ConventionalFormat {
+    fn id(&self) -> &'static str {
+        "conventional"
+    }
+
+    fn extension(&self) -> &'static str {
+        "md"
+    }
+
+    fn parse(&self, content: &str, next_section_aliases: &[String]) -> Result<ChangelogDocument> {
+        let mut document = parse_markdown(content, next_section_aliases, |title| {
+            GROUP_TITLES
+                .iter()
+                .find(|(_, conventional)| conventional.eq_ignore_ascii_case(title))
+                .map(|(kind, _)| kind.to_string())
+                .unwrap_or_else(|| title.to_string())
+        });
+        for release in &mut document.releases {
+            if let Some(version) = &release.version {
+                release.heading = match &release.date {
+                    Some(date) => format!("[{}] - {}", version, date),
+                    None => format!("[{}]", version),
+                };
+            }
+        }
+        Ok(document)
+    }
+
+    fn render(&self, document: &ChangelogDocument) -> String {
+        render_markdown(document, conventional_heading, |kind| {
+            GROUP_TITLES
+                .iter()
+                .find(|(keep_a_changelog, _)| keep_a_changelog.eq_ignore_ascii_case(kind))
+                .map(|(_, conventional)| conventional.to_string())
+                .unwrap_or_else(|| kind.to_string())
+        })
+    }
+
+    fn to_markdown(&self, content: &str, _next_section_aliases: &[String]) -> Result<String> {
+        Ok(content.to_string())
+    }
+}
+
+fn conventional_heading(release: &ChangelogRelease) -> String {
+    match (&release.version, &release.date) {
+        (Some(version), Some(date)) => format!("{} ({})", version, date),
+        (Some(version), None) => version.clone(),
+        (None, _) => release.heading.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{aliases, KEEP_A_CHANGELOG};
+    use super::super::MarkdownFormat;
+    use super::*;
+
+    #[test]
+    fn test_conventional_render_groups_by_commit_type() {
+        let document = MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap();
+        let rendered = ConventionalFormat.render(&document);
+
+        assert!(rendered.contains("## 1.1.0 (2025-02-01)\n\n### Features\n- Two"));
+        assert!(rendered.contains("### Bug Fixes\n- Bug"));
+        assert!(rendered.contains("## [Unreleased]\n\n### Bug Fixes\n- Pending fix"));
+    }
+
+    #[test]
+    fn test_conventional_round_trips_to_keep_a_changelog() {
+        let document = MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap();
+        let rendered = ConventionalFormat.render(&document);
+
+        let back = ConventionalFormat.parse(&rendered, &aliases()).unwrap();
+        assert_eq!(back, document);
+        assert!(ConventionalFormat.verify_parse(&rendered, &back).is_ok());
+    }
+}
@@ -0,0 +1,68 @@
+//! The [`ChangelogDocument`] stored as pretty-printed JSON.
+
+use crate::error::{Error, Result};
+
+use super::{ChangelogDocument, ChangelogFormat};
+
+pub struct JsonFormat;
+
+impl ChangelogFormat for JsonFormat {
+    fn id(&self) -> &'static str {
+        "json"
+    }
+
+    fn extension(&self) -> &'static str {
+        "json"
+    }
+
+    fn parse(&self, content: &str, _next_section_aliases: &[String]) -> Result<ChangelogDocument> {
+        serde_json::from_str(content).map_err(|e| {
+            Error::validation_invalid_json(
+                e,
+                Some("parse JSON changelog".to_string()),
+                Some(content.chars().take(200).collect()),
+            )
+        })
+    }
+
+    fn render(&self, document: &ChangelogDocument) -> String {
+        let mut out =
+            serde_json::to_string_pretty(document).expect("changelog document serializes");
+        out.push('\n');
+        out
+    }
+
+    /// Unknown fields are rejected at parse time, so nothing can be dropped.
+    fn verify_parse(&self, _content: &str, _document: &ChangelogDocument) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{aliases, KEEP_A_CHANGELOG};
+    use super::super::MarkdownFormat;
+    use super::*;
+
+    #[test]
+    fn test_json_round_trips_markdown_without_loss() {
+        let document = MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap();
+        let json = JsonFormat.render(&document);
+
+        let back = JsonFormat.parse(&json, &aliases()).unwrap();
+        assert_eq!(back, document);
+        assert_eq!(MarkdownFormat.render(&back), KEEP_A_CHANGELOG);
+        assert!(json.contains("\"unreleased\": true"));
+    }
+
+    #[test]
+    fn test_json_rejects_unknown_fields() {
+        let err = JsonFormat
+            .parse(r#"{"releases": [{"heading": "1.0.0", "extra": 1}]}"#, &[])
+            .unwrap_err();
+        assert!(err.details["error"]
+            .as_str()
+            .unwrap()
+            .contains("unknown field `extra`"));
+    }
+}
@@ -0,0 +1,286 @@
+//! Keep a Changelog markdown, the default format.
+
+use std::collections::HashMap;
+
+use crate::error::Result;
+use crate::release::extract_latest_notes;
+
+use super::super::io::{FinalizedReleaseSnapshot, INITIAL_CHANGELOG_CONTENT};
+use super::super::sections::*;
+use super::{ChangelogDocument, ChangelogFormat, ChangelogGroup, ChangelogRelease};
+
+pub struct MarkdownFormat;
+
+impl ChangelogFormat for MarkdownFormat {
+    fn id(&self) -> &'static str {
+        "markdown"
+    }
+
+    fn extension(&self) -> &'static str {
+        "md"
+    }
+
+    fn parse(&self, content: &str, next_section_aliases: &[String]) -> Result<ChangelogDocument> {
+        Ok(parse_markdown(content, next_section_aliases, |kind| {
+            kind.to_string()
+        }))
+    }
+
+    fn render(&self, document: &ChangelogDocument) -> String {
+        render_markdown(
+            document,
+            |release| release.heading.clone(),
+            |kind| kind.to_string(),
+        )
+    }
+
+    fn initial_content(&self) -> String {
+        INITIAL_CHANGELOG_CONTENT.to_string()
+    }
+
+    fn to_markdown(&self, content: &str, _next_section_aliases: &[String]) -> Result<String> {
+        Ok(content.to_string())
+    }
+
+    fn latest_finalized_version(
+        &self,
+        content: &str,
+        _next_section_aliases: &[String],
+    ) -> Result<Option<String>> {
+        Ok(get_latest_finalized_version(content))
+    }
+
+    fn unreleased_entries(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+    ) -> Result<Vec<String>> {
+        Ok(get_unreleased_entries(content, next_section_aliases))
+    }
+
+    fn release_notes(&self, content: &str, _next_section_aliases: &[String]) -> Option<String> {
+        extract_latest_notes(content)
+    }
+
+    fn last_release_snapshot(
+        &self,
+        content: &str,
+        _next_section_aliases: &[String],
+    ) -> Option<FinalizedReleaseSnapshot> {
+        extract_last_release_snapshot(content)
+    }
+
+    fn finalize_next_section(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+        new_version: &str,
+        allow_empty: bool,
+    ) -> Result<(String, bool)> {
+        finalize_next_section(content, next_section_aliases, new_version, allow_empty)
+    }
+
+    fn insert_release(
+        &self,
+        content: &str,
+        _next_section_aliases: &[String],
+        version: &str,
+    ) -> Result<String> {
+        Ok(insert_release_heading(content, version))
+    }
+
+    fn finalize_with_generated_entries(
+        &self,
+        content: &str,
+        next_section_aliases: &[String],
+        entries_by_type: &HashMap<&str, Vec<String>>,
+        new_version: &str,
+    ) -> Result<(String, bool)> {
+        finalize_with_generated_entries(content, next_section_aliases, entries_by_type, new_version)
+    }
+}
+
+/// Parse `## ` / `### ` / bullet markdown. `kind_from_heading` maps a `### `
+/// label to the stored change type.
+pub(super) fn parse_markdown(
+    content: &str,
+    next_section_aliases: &[String],
+    kind_from_heading: impl Fn(&str) -> String,
+) -> ChangelogDocument {
+    let mut preamble: Vec<&str> = Vec::new();
+    let mut releases: Vec<ChangelogRelease> = Vec::new();
+    let mut in_fence = false;
+    let mut last_was_entry = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let fence_marker = trimmed.starts_with("```");
+
+        if !in_fence && !fence_marker {
+            if let Some(label) = trimmed.strip_prefix("## ") {
+                let label = label.trim();
+                let unreleased = is_matching_next_section_heading(trimmed, next_section_aliases);
+                releases.push(ChangelogRelease {
+                    heading: label.to_string(),
+                    version: (!unreleased)
+                        .then(|| extract_version_from_heading(label))
+                        .flatten(),
+                    date: (!unreleased)
+                        .then(|| extract_date_from_heading(label))
+                        .flatten(),
+                    unreleased,
+                    groups: Vec::new(),
+                });
+                last_was_entry = false;
+                continue;
+            }
+        }
+
+        let Some(release) = releases.last_mut() else {
+            preamble.push(line);
+            if fence_marker {
+                in_fence = !in_fence;
+            }
+            continue;
+        };
+
+        if fence_marker {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence && !fence_marker {
+            if trimmed.is_empty() {
+                continue;
+            }
+            if let Some(kind) = trimmed.strip_prefix("### ") {
+                release.groups.push(ChangelogGroup {
+                    kind: Some(kind_from_heading(kind.trim())),
+                    ..Default::default()
+                });
+                last_was_entry = false;
+                continue;
+            }
+            let bullet = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "));
+            if let Some(entry) = bullet {
+                current_group(release)
+                    .entries
+                    .push(entry.trim().to_string());
+                last_was_entry = true;
+                continue;
+            }
+        }
+
+        let group = current_group(release);
+        if last_was_entry && (line.starts_with(char::is_whitespace) || in_fence || fence_marker) {
+            let entry = group.entries.last_mut().expect("last line was an entry");
+            entry.push('\n');
+            entry.push_str(line.trim_end());
+        } else {
+            group.notes.push(line.trim_end().to_string());
+            last_was_entry = false;
+        }
+    }
+
+    ChangelogDocument {
+        preamble: preamble
+            .join("\n")
+            .trim_matches('\n')
+            .trim_end()
+            .to_string(),
+        releases,
+    }
+}
+
+fn current_group(release: &mut ChangelogRelease) -> &mut ChangelogGroup {
+    if release.groups.is_empty() {
+        release.groups.push(ChangelogGroup::default());
+    }
+    release.groups.last_mut().expect("group was just ensured")
+}
+
+/// Render blocks separated by blank lines: preamble, each `## ` heading, and
+/// each group (`### ` heading, notes, then bullets).
+pub(super) fn render_markdown(
+    document: &ChangelogDocument,
+    heading: impl Fn(&ChangelogRelease) -> String,
+    heading_from_kind: impl Fn(&str) -> String,
+) -> String {
+    let mut blocks: Vec<String> = Vec::new();
+    if !document.preamble.is_empty() {
+        blocks.push(document.preamble.clone());
+    }
+    for release in &document.releases {
+        blocks.push(format!("## {}", heading(release)));
+        blocks.extend(render_groups(release, &heading_from_kind));
+    }
+
+    let mut out = blocks.join("\n\n");
+    out.push('\n');
+    out
+}
+
+fn render_groups(
+    release: &ChangelogRelease,
+    heading_from_kind: &impl Fn(&str) -> String,
+) -> Vec<String> {
+    release
+        .groups
+        .iter()
+        .map(|group| {
+            let mut lines: Vec<String> = Vec::new();
+            if let Some(kind) = &group.kind {
+                lines.push(format!("### {}", heading_from_kind(kind)));
+            }
+            lines.extend(group.notes.iter().cloned());
+            lines.extend(group.entries.iter().map(|entry| format!("- {}", entry)));
+            lines.join("\n")
+        })
+        .collect()
+}
+
+/// A release body (groups only, no `## ` heading) as Keep a Changelog markdown.
+pub(super) fn render_release_body(release: &ChangelogRelease) -> String {
+    render_groups(release, &|kind: &str| kind.to_string()).join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{aliases, KEEP_A_CHANGELOG};
+    use super::*;
+
+    #[test]
+    fn test_parse_markdown_structure() {
+        let document = MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap();
+
+        assert_eq!(document.preamble, "# Changelog\n\nAll notable changes.");
+        assert_eq!(document.releases.len(), 3);
+        assert!(document.releases[0].unreleased);
+        assert_eq!(document.releases[1].version.as_deref(), Some("1.1.0"));
+        assert_eq!(document.releases[1].date.as_deref(), Some("2025-02-01"));
+        assert_eq!(
+            document.releases[1].groups[0].entries,
+            vec!["Two\n  with a continuation", "Three"]
+        );
+        let first = &document.releases[2].groups[0];
+        assert_eq!(first.kind, None);
+        assert_eq!(first.notes, vec!["First public release."]);
+        assert_eq!(first.entries, vec!["Initial"]);
+    }
+
+    #[test]
+    fn test_render_markdown_round_trips() {
+        let document = MarkdownFormat.parse(KEEP_A_CHANGELOG, &aliases()).unwrap();
+        assert_eq!(MarkdownFormat.render(&document), KEEP_A_CHANGELOG);
+        assert!(MarkdownFormat
+            .verify_parse(KEEP_A_CHANGELOG, &document)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_verify_parse_reports_unrepresentable_content() {
+        let content = "# Changelog\n\n## [1.0.0] - 2025-01-14\n\n- Initial\n\nTrailing note.\n";
+        let document = MarkdownFormat.parse(content, &aliases()).unwrap();
+        let err = MarkdownFormat.verify_parse(content, &document).unwrap_err();
+        assert!(err.message.ends_with("near: - Initial"));
+    }
+}
@@ -6,7 +6,7 @@ use crate::engine::validation;
 use crate::error::Result;
 use crate::paths::resolve_path;
 
-use super::sections::*;
+use super::format::*;
 use super::settings::*;
 
 /// Common changelog file locations to check when the configured path doesn't exist.
@@ -111,12 +111,13 @@ pub fn read_component_snapshots(
     let changelog_path = resolve_changelog_path(component)?;
     let content = local_files::read_file(&changelog_path, "read changelog")?;
     let settings = resolve_effective_settings(Some(component));
+    let format = format_for(component)?;
 
-    let last_release = extract_last_release_snapshot(&content);
+    let last_release = format.last_release_snapshot(&content, &settings.next_section_aliases);
     let unreleased = Some(ChangelogSnapshotData {
         path: changelog_path.to_string_lossy().to_string(),
+        items: format.unreleased_entries(&content, &settings.next_section_aliases)?,
         label: settings.next_section_label,
-        items: get_unreleased_entries(&content, &settings.next_section_aliases),
     });
 
     Ok((last_release, unreleased))
@@ -0,0 +1,355 @@
+//! Keep-a-Changelog conformance checks for `changelog lint`.
+
+use chrono::NaiveDate;
+use semver::Version;
+use serde::Serialize;
+
+use crate::component;
+use crate::engine::local_files;
+use crate::error::{Error, Result};
+
+use super::format::*;
+use super::io::*;
+use super::sections::*;
+use super::settings::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogLintIssue {
+    /// 1-based line in the changelog file.
+    pub line: usize,
+    pub severity: LintSeverity,
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangelogLintOutput {
+    pub component_id: String,
+    pub changelog_path: String,
+    pub passed: bool,
+    pub errors: usize,
+    pub warnings: usize,
+    pub issues: Vec<ChangelogLintIssue>,
+}
+
+pub fn lint(component_id: &str) -> Result<ChangelogLintOutput> {
+    let component = component::resolve_effective(Some(component_id), None, None)?;
+    let format = format_for(&component)?;
+    if format.id() != MarkdownFormat.id() {
+        return Err(Error::validation_invalid_argument(
+            "changelog_format",
+            format!(
+                "changelog lint checks Keep a Changelog markdown; '{}' uses the {} format",
+                component_id,
+                format.id()
+            ),
+            None,
+            None,
+        ));
+    }
+    let changelog_path = resolve_changelog_path(&component)?;
+    let settings = resolve_effective_settings(Some(&component));
+
+    let content = local_files::read_file(
+        &changelog_path,
+        &format!("read changelog at {}", changelog_path.display()),
+    )?;
+
+    let issues = lint_content(&content, &settings.next_section_aliases);
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.severity == LintSeverity::Error)
+        .count();
+
+    Ok(ChangelogLintOutput {
+        component_id: component_id.to_string(),
+        changelog_path: changelog_path.to_string_lossy().to_string(),
+        passed: errors == 0,
+        errors,
+        warnings: issues.len() - errors,
+        issues,
+    })
+}
+
+/// Where bullets currently land while walking the file.
+enum Scope {
+    /// Before the first `##` heading.
+    Preamble,
+    /// Under an `##` heading that is neither the next section nor a release.
+    UnknownSection,
+    /// Under the next section or a release heading.
+    Section { subsection: Subsection },
+}
+
+enum Subsection {
+    None,
+    Recognized,
+    Unknown,
+}
+
+/// Lint changelog `content`. `next_section_aliases` are the labels accepted
+/// for the Unreleased section (see [`resolve_effective_settings`]).
+pub fn lint_content(content: &str, next_section_aliases: &[String]) -> Vec<ChangelogLintIssue> {
+    let mut issues = Vec::new();
+    let mut scope = Scope::Preamble;
+    let mut in_fence = false;
+    let mut has_next_section = false;
+    let mut releases: Vec<(usize, Version)> = Vec::new();
+
+    let mut push = |line: usize, severity: LintSeverity, code: &str, message: String| {
+        issues.push(ChangelogLintIssue {
+            line,
+            severity,
+            code: code.to_string(),
+            message,
+        });
+    };
+
+    for (index, raw) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let trimmed = raw.trim();
+
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+
+        if let Some(label) = trimmed.strip_prefix("## ") {
+            let label = label.trim();
+            if is_matching_next_section_heading(trimmed, next_section_aliases) {
+                if has_next_section {
+                    push(
+                        line_no,
+                        LintSeverity::Error,
+                        "duplicate_unreleased",
+                        format!("Duplicate '{}' section", normalize_heading_label(label)),
+                    );
+                }
+                has_next_section = true;
+                scope = Scope::Section {
+                    subsection: Subsection::None,
+                };
+                continue;
+            }
+
+            let Some(version) = extract_version_from_heading(label) else {
+                push(
+                    line_no,
+                    LintSeverity::Warning,
+                    "unrecognized_heading",
+                    format!(
+                        "Heading '{}' is neither the Unreleased section nor a release",
+                        label
+                    ),
+                );
+                scope = Scope::UnknownSection;
+                continue;
+            };
+            scope = Scope::Section {
+                subsection: Subsection::None,
+            };
+
+            lint_release_date(label, &version, line_no, &mut push);
+
+            let Ok(parsed) = Version::parse(&version) else {
+                continue;
+            };
+            if let Some((first_line, _)) = releases.iter().find(|(_, seen)| *seen == parsed) {
+                push(
+                    line_no,
+                    LintSeverity::Error,
+                    "duplicate_version",
+                    format!(
+                        "Version {} already has a heading on line {}",
+                        version, first_line
+                    ),
+                );
+            } else if let Some((prev_line, prev)) = releases.last() {
+                if parsed > *prev {
+                    push(
+                        line_no,
+                        LintSeverity::Error,
+                        "version_order",
+                        format!(
+                            "Version {} is listed below {} (line {}); releases must be newest first",
+                            version, prev, prev_line
+                        ),
+                    );
+                }
+            }
+            releases.push((line_no, parsed));
+            continue;
+        }
+
+        if trimmed.starts_with("### ") {
+            if let Scope::Section { subsection } = &mut scope {
+                let recognized = KEEP_A_CHANGELOG_SUBSECTIONS
+                    .iter()
+                    .any(|heading| heading.eq_ignore_ascii_case(trimmed));
+                *subsection = if recognized {
+                    Subsection::Recognized
+                } else {
+                    push(
+                        line_no,
+                        LintSeverity::Warning,
+                        "unknown_subsection",
+                        format!(
+                            "'{}' is not a Keep a Changelog change type (Added, Changed, Deprecated, Removed, Fixed, Security)",
+                            trimmed
+                        ),
+                    );
+                    Subsection::Unknown
+                };
+            }
+            continue;
+        }
+
+        let is_entry = trimmed.starts_with("- ") || trimmed.starts_with("* ");
+        if !is_entry || raw.starts_with(char::is_whitespace) {
+            continue;
+        }
+        match &scope {
+            Scope::Preamble => push(
+                line_no,
+                LintSeverity::Error,
+                "entry_outside_section",
+                "Entry appears before any release or Unreleased heading".to_string(),
+            ),
+            Scope::UnknownSection => push(
+                line_no,
+                LintSeverity::Error,
+                "entry_outside_section",
+                "Entry is under a heading that is not a release or Unreleased section".to_string(),
+            ),
+            Scope::Section {
+                subsection: Subsection::None,
+            } => push(
+                line_no,
+                LintSeverity::Warning,
+                "entry_outside_subsection",
+                "Entry is not under a change-type subsection (e.g. ### Added)".to_string(),
+            ),
+            Scope::Section { .. } => {}
+        }
+    }
+
+    if !has_next_section {
+        push(
+            1,
+            LintSeverity::Warning,
+            "missing_unreleased",
+            format!(
+                "No '{}' section found",
+                next_section_aliases
+                    .first()
+                    .map(String::as_str)
+                    .unwrap_or(DEFAULT_NEXT_SECTION_LABEL)
+            ),
+        );
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    issues
+}
+
+/// Release headings should read `## [X.Y.Z] - YYYY-MM-DD`.
+fn lint_release_date(
+    label: &str,
+    version: &str,
+    line_no: usize,
+    push: &mut impl FnMut(usize, LintSeverity, &str, String),
+) {
+    let rest = label
+        .split_once(version)
+        .map(|(_, rest)| rest.trim_start_matches(']').trim())
+        .unwrap_or_default();
+    let date = rest.trim_start_matches(['-', '–']).trim();
+
+    if date.is_empty() {
+        push(
+            line_no,
+            LintSeverity::Warning,
+            "missing_date",
+            format!("Release {} has no date (expected '- YYYY-MM-DD')", version),
+        );
+        return;
+    }
+
+    let iso = extract_date_from_heading(date)
+        .filter(|found| found == date)
+        .and_then(|found| NaiveDate::parse_from_str(&found, "%Y-%m-%d").ok());
+    if iso.is_none() {
+        push(
+            line_no,
+            LintSeverity::Error,
+            "invalid_date",
+            format!(
+                "Release {} date '{}' is not an ISO 8601 date (YYYY-MM-DD)",
+                version, date
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases() -> Vec<String> {
+        vec!["Unreleased".to_string(), "[Unreleased]".to_string()]
+    }
+
+    fn codes(issues: &[ChangelogLintIssue]) -> Vec<(usize, &str)> {
+        issues
+            .iter()
+            .map(|issue| (issue.line, issue.code.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_content_accepts_well_formed_changelog() {
+        let content = "# Changelog\n\nNotes about the project.\n\n## [Unreleased]\n\n### Added\n- New thing\n\n## [1.1.0] - 2025-02-01\n\n### Fixed\n- Bug\n\n## [1.0.0] - 2025-01-14\n\n### Added\n- Initial\n";
+        assert!(lint_content(content, &aliases()).is_empty());
+    }
+
+    #[test]
+    fn test_lint_content_reports_issues_with_line_numbers() {
+        let content = "# Changelog\n- stray\n\n## [1.0.0] - 2025/01/14\n\n### Added\n- Initial\n\n## [1.2.0] - 2025-03-01\n\n### Misc\n- Other\n\n## 1.0.0 - 2025-01-14\n- Loose\n";
+        let issues = lint_content(content, &aliases());
+
+        assert_eq!(
+            codes(&issues),
+            vec![
+                (1, "missing_unreleased"),
+                (2, "entry_outside_section"),
+                (4, "invalid_date"),
+                (9, "version_order"),
+                (11, "unknown_subsection"),
+                (14, "duplicate_version"),
+                (15, "entry_outside_subsection"),
+            ]
+        );
+        let errors: Vec<usize> = issues
+            .iter()
+            .filter(|issue| issue.severity == LintSeverity::Error)
+            .map(|issue| issue.line)
+            .collect();
+        assert_eq!(errors, vec![2, 4, 9, 14]);
+    }
+
+    #[test]
+    fn test_lint_content_ignores_fenced_code() {
+        let content = "# Changelog\n\n## Unreleased\n\n### Added\n- Example\n\n```md\n## 9.9.9\n- not an entry\n```\n";
+        assert!(lint_content(content, &aliases()).is_empty());
+    }
+}
@@ -1,9 +1,17 @@
 mod bulk;
+mod convert;
+mod format;
 mod io;
+mod lint;
+mod range;
 mod sections;
 mod settings;
 
 pub use bulk::*;
+pub use convert::*;
+pub use format::*;
 pub use io::*;
+pub use lint::*;
+pub use range::*;
 pub use sections::*;
 pub use settings::*;
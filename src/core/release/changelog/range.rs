@@ -0,0 +1,199 @@
+//! Slice a changelog down to a range of releases (`changelog show --from/--to`).
+
+use semver::Version;
+
+use crate::error::{Error, Result};
+
+use super::sections::*;
+
+/// Which part of a changelog `changelog show` should print.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseRange {
+    /// Oldest release to include (inclusive). Defaults to the oldest release.
+    pub from: Option<String>,
+    /// Newest release to include (inclusive). Defaults to the newest release.
+    pub to: Option<String>,
+    /// Only the next (Unreleased) section.
+    pub unreleased_only: bool,
+}
+
+impl ReleaseRange {
+    pub fn is_full(&self) -> bool {
+        self.from.is_none() && self.to.is_none() && !self.unreleased_only
+    }
+}
+
+/// One `## ` section: its heading line plus everything up to the next one.
+struct ChangelogSection<'a> {
+    version: Option<Version>,
+    is_next: bool,
+    lines: Vec<&'a str>,
+}
+
+fn split_sections<'a>(
+    content: &'a str,
+    next_section_aliases: &[String],
+) -> Vec<ChangelogSection<'a>> {
+    let mut sections: Vec<ChangelogSection<'a>> = Vec::new();
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        }
+
+        if !in_fence && trimmed.starts_with("## ") {
+            let label = trimmed.trim_start_matches("## ").trim();
+            sections.push(ChangelogSection {
+                version: extract_version_from_heading(label)
+                    .and_then(|version| Version::parse(&version).ok()),
+                is_next: is_matching_next_section_heading(trimmed, next_section_aliases),
+                lines: vec![line],
+            });
+        } else if let Some(section) = sections.last_mut() {
+            section.lines.push(line);
+        }
+    }
+
+    sections
+}
+
+/// Return the markdown for the releases `range` selects, starting at the
+/// first included heading. Sections are kept in file order.
+pub fn slice_releases(
+    content: &str,
+    range: &ReleaseRange,
+    next_section_aliases: &[String],
+) -> Result<String> {
+    let sections = split_sections(content, next_section_aliases);
+
+    let selected: Vec<&ChangelogSection> = if range.unreleased_only {
+        let next: Vec<&ChangelogSection> =
+            sections.iter().filter(|section| section.is_next).collect();
+        if next.is_empty() {
+            return Err(Error::validation_invalid_argument(
+                "unreleased_only",
+                "Changelog has no Unreleased section",
+                None,
+                None,
+            ));
+        }
+        next
+    } else {
+        let available: Vec<&Version> = sections
+            .iter()
+            .filter_map(|section| section.version.as_ref())
+            .collect();
+        let from = resolve_bound("from", range.from.as_deref(), &available)?;
+        let to = resolve_bound("to", range.to.as_deref(), &available)?;
+        let (low, high) = match (from, to) {
+            (Some(from), Some(to)) if from > to => (Some(to), Some(from)),
+            bounds => bounds,
+        };
+
+        sections
+            .iter()
+            .filter(|section| {
+                let Some(version) = section.version.as_ref() else {
+                    return false;
+                };
+                low.is_none_or(|low| version >= low) && high.is_none_or(|high| version <= high)
+            })
+            .collect()
+    };
+
+    let mut out = selected
+        .iter()
+        .map(|section| section.lines.join("\n").trim_end().to_string())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    out.push('\n');
+    Ok(out)
+}
+
+fn resolve_bound<'a>(
+    field: &str,
+    requested: Option<&str>,
+    available: &[&'a Version],
+) -> Result<Option<&'a Version>> {
+    let Some(requested) = requested else {
+        return Ok(None);
+    };
+    let wanted = Version::parse(requested.trim().trim_start_matches('v')).ok();
+    if let Some(found) = available
+        .iter()
+        .find(|version| Some(**version) == wanted.as_ref())
+    {
+        return Ok(Some(found));
+    }
+
+    let listed: Vec<String> = available
+        .iter()
+        .map(|version| version.to_string())
+        .collect();
+    Err(Error::validation_invalid_argument(
+        field,
+        format!("Version '{}' not found in changelog", requested),
+        None,
+        Some(vec![format!("Available versions: {}", listed.join(", "))]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANGELOG: &str = "# Changelog\n\nIntro text.\n\n## Unreleased\n\n### Added\n- Pending\n\n## [1.2.0] - 2025-03-01\n\n### Fixed\n- Three\n\n## [1.1.0] - 2025-02-01\n\n### Added\n- Two\n\n## [1.0.0] - 2025-01-01\n\n### Added\n- One\n";
+
+    fn aliases() -> Vec<String> {
+        vec!["Unreleased".to_string(), "[Unreleased]".to_string()]
+    }
+
+    fn range(from: Option<&str>, to: Option<&str>) -> ReleaseRange {
+        ReleaseRange {
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+            unreleased_only: false,
+        }
+    }
+
+    #[test]
+    fn test_slice_releases_inclusive_range() {
+        let out =
+            slice_releases(CHANGELOG, &range(Some("1.0.0"), Some("1.1.0")), &aliases()).unwrap();
+        assert_eq!(
+            out,
+            "## [1.1.0] - 2025-02-01\n\n### Added\n- Two\n\n## [1.0.0] - 2025-01-01\n\n### Added\n- One\n"
+        );
+    }
+
+    #[test]
+    fn test_slice_releases_open_ended() {
+        let out = slice_releases(CHANGELOG, &range(Some("v1.1.0"), None), &aliases()).unwrap();
+        assert!(out.starts_with("## [1.2.0]"));
+        assert!(out.contains("## [1.1.0]"));
+        assert!(!out.contains("1.0.0"));
+        assert!(!out.contains("Unreleased"));
+    }
+
+    #[test]
+    fn test_slice_releases_unreleased_only() {
+        let only = ReleaseRange {
+            unreleased_only: true,
+            ..Default::default()
+        };
+        let out = slice_releases(CHANGELOG, &only, &aliases()).unwrap();
+        assert_eq!(out, "## Unreleased\n\n### Added\n- Pending\n");
+    }
+
+    #[test]
+    fn test_slice_releases_unknown_version_lists_available() {
+        let err = slice_releases(CHANGELOG, &range(Some("0.9.0"), None), &aliases()).unwrap_err();
+        assert!(err.message.contains("0.9.0"));
+        assert_eq!(
+            err.details["tried"][0],
+            "Available versions: 1.2.0, 1.1.0, 1.0.0"
+        );
+    }
+}
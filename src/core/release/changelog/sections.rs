@@ -154,6 +154,39 @@ pub fn finalize_with_generated_entries(
     finalize_next_section(&content, aliases, new_version, false)
 }
 
+/// Insert an empty `## [version] - date` section above the latest finalized
+/// release, leaving any next section in place. Appends when there is none.
+pub fn insert_release_heading(changelog_content: &str, version: &str) -> String {
+    let heading = format!(
+        "## [{}] - {}",
+        version.trim(),
+        Local::now().format("%Y-%m-%d")
+    );
+    let mut lines: Vec<String> = changelog_content.lines().map(str::to_string).collect();
+    let latest = lines.iter().position(|line| {
+        line.trim()
+            .strip_prefix("## ")
+            .is_some_and(|label| extract_version_from_heading(label.trim()).is_some())
+    });
+
+    match latest {
+        Some(index) => {
+            lines.insert(index, String::new());
+            lines.insert(index, heading);
+        }
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(heading);
+        }
+    }
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    out
+}
+
 pub(super) fn find_next_section_start(lines: &[&str], aliases: &[String]) -> Option<usize> {
     lines
         .iter()
@@ -410,6 +443,21 @@ mod tests {
         assert!(err.message.contains("Invalid"));
     }
 
+    #[test]
+    fn test_insert_release_heading() {
+        let content = "# Changelog\n\n## Unreleased\n\n## [0.1.0] - 2025-01-14\n\n- Old\n";
+        let out = insert_release_heading(content, "0.2.0");
+        assert_eq!(
+            get_latest_finalized_version(&out),
+            Some("0.2.0".to_string())
+        );
+        assert!(out.starts_with("# Changelog\n\n## Unreleased\n\n## [0.2.0] - "));
+        assert!(out.ends_with("\n\n## [0.1.0] - 2025-01-14\n\n- Old\n"));
+
+        let out = insert_release_heading("# Changelog\n", "1.0.0");
+        assert!(out.starts_with("# Changelog\n\n## [1.0.0] - "));
+    }
+
     #[test]
     fn get_latest_finalized_version_finds_first_semver() {
         let content = "# Changelog\n\n## Unreleased\n\n## 0.2.16\n\n- Item\n\n## 0.2.15\n";
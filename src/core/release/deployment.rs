@@ -88,6 +88,8 @@ fn execute_deployment(component_id: &str, local_path: &str) -> ReleaseDeployment
         no_pull: true,
         head: true,
         tagged: true,
+        no_verify: false,
+        step_filter: Default::default(),
     };
 
     let deployment = match deploy::run_multi(&projects, &[component_id.to_string()], &config) {
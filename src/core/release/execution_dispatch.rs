@@ -23,6 +23,10 @@ pub(super) fn execute_release_plan_step(
         return Ok(None);
     }
 
+    if matches!(step.status, PlanStepStatus::Skipped) {
+        return Ok(Some(skipped_result(step)));
+    }
+
     match step.kind.as_str() {
         "preflight.default_branch" => Ok(Some(run_default_branch_preflight(step, context))),
         "preflight.git_identity" => configure_git_identity(step, context).map(Some),
@@ -57,15 +61,24 @@ pub(super) fn execute_release_plan_step(
             executor::run_git_commit(context.component, context.component_id, &context.state)
                 .map(Some)
         }
-        "package" => Ok(Some(
-            executor::run_package(
-                context.extensions,
-                &mut context.state,
-                context.component_id,
-                &context.component.local_path,
-            )
-            .unwrap_or_else(|err| failed_result("package", "package", err)),
-        )),
+        "package" => {
+            let mut result = match context.options.package_format {
+                Some(format) => {
+                    executor::run_builtin_package(context.component, &mut context.state, format)
+                }
+                None => executor::run_package(
+                    context.extensions,
+                    &mut context.state,
+                    context.component_id,
+                    &context.component.local_path,
+                ),
+            }
+            .unwrap_or_else(|err| failed_result("package", "package", err));
+            if matches!(result.status, ReleaseStepStatus::Success) {
+                pin_packaged_artifacts(&mut result, context);
+            }
+            Ok(Some(result))
+        }
         "git.tag" => {
             let tag_name = step
                 .inputs
@@ -73,11 +86,20 @@ pub(super) fn execute_release_plan_step(
                 .and_then(|value| value.as_str())
                 .map(str::to_string)
                 .unwrap_or_else(|| format!("v{}", context.state.version.as_deref().unwrap_or("")));
+            let signing: Option<git::TagSigning> = step
+                .inputs
+                .get("signing")
+                .map(|value| serde_json::from_value(value.clone()))
+                .transpose()
+                .map_err(|e| {
+                    Error::internal_json(e.to_string(), Some("git.tag signing input".to_string()))
+                })?;
             executor::run_git_tag(
                 context.component,
                 context.component_id,
                 &mut context.state,
                 &tag_name,
+                signing.as_ref(),
             )
             .map(Some)
         }
@@ -133,6 +155,42 @@ pub(super) fn execute_release_plan_step(
     }
 }
 
+/// Copy freshly packaged artifacts to `--output` and pin them to the version.
+/// The release has already built its artifacts, so a pinning failure is a
+/// warning on the package step rather than a failed release.
+fn pin_packaged_artifacts(result: &mut ReleaseStepResult, context: &ReleaseExecutionContext) {
+    let Some(output) = context.options.artifact_output.as_deref() else {
+        return;
+    };
+    let Some(version) = context.state.version.as_deref() else {
+        result
+            .warnings
+            .push("Artifacts not pinned: release version is unknown".to_string());
+        return;
+    };
+
+    match super::artifacts::pin_artifacts(
+        context.component_id,
+        version,
+        context.state.tag.as_deref(),
+        &context.state.artifacts,
+        &context.component.local_path,
+        std::path::Path::new(output),
+    ) {
+        Ok(pin) => {
+            if let Some(serde_json::Value::Object(data)) = result.data.as_mut() {
+                data.insert(
+                    "pinned".to_string(),
+                    serde_json::to_value(&pin).unwrap_or_default(),
+                );
+            }
+        }
+        Err(err) => result
+            .warnings
+            .push(format!("Artifacts not pinned: {}", err.message)),
+    }
+}
+
 fn release_step_is_plan_only(step: &PlanStep) -> bool {
     (step.kind.starts_with("preflight.")
         && step.kind != "preflight.default_branch"
@@ -375,6 +433,23 @@ fn step_config_string_array(step: &PlanStep, key: &str) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// A skipped `ReleaseStepResult`, carrying the plan's skip reason.
+fn skipped_result(step: &PlanStep) -> ReleaseStepResult {
+    ReleaseStepResult {
+        id: step.id.clone(),
+        step_type: step.kind.clone(),
+        status: ReleaseStepStatus::Skipped,
+        missing: Vec::new(),
+        warnings: Vec::new(),
+        hints: Vec::new(),
+        data: step
+            .skip_reason
+            .as_ref()
+            .map(|reason| serde_json::json!({ "reason": reason })),
+        error: None,
+    }
+}
+
 /// Convert a step error into a failed `ReleaseStepResult`.
 fn failed_result(id: &str, step_type: &str, err: Error) -> ReleaseStepResult {
     ReleaseStepResult {
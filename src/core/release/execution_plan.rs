@@ -14,10 +14,11 @@ pub(super) fn build_initial_preflight_plan(
     component_id: &str,
     options: &ReleaseOptions,
 ) -> ReleasePlan {
-    let steps = build_preflight_steps(options, None)
+    let mut steps: Vec<PlanStep> = build_preflight_steps(options, None)
         .into_iter()
         .filter(|step| initial_executable_preflight_ids().contains(&step.id.as_str()))
         .collect();
+    options.step_filter.apply(&mut steps);
 
     ReleasePlan::new(component_id, true, steps, None, Vec::new(), Vec::new())
 }
@@ -12,6 +12,7 @@
 //! steps, so the DAG scaffolding bought nothing but indirection. The logic
 //! inside each `run_*` function is unchanged; only the plumbing is different.
 
+use crate::build::{self, PackageFormat, PackageOptions};
 use crate::component::Component;
 use crate::engine::local_files::FileSystem;
 use crate::engine::validation;
@@ -20,7 +21,7 @@ use crate::extension::{self, ExtensionManifest};
 use crate::{changelog as release_changelog, version};
 
 use super::types::{ReleaseArtifact, ReleaseState, ReleaseStepResult, ReleaseStepStatus};
-use super::utils::{extract_latest_notes, parse_release_artifacts};
+use super::utils::parse_release_artifacts;
 
 pub(crate) mod changelog;
 pub(crate) mod prepare;
@@ -388,6 +389,7 @@ pub(crate) fn run_git_tag(
     component_id: &str,
     state: &mut ReleaseState,
     tag_name: &str,
+    signing: Option<&crate::git::TagSigning>,
 ) -> Result<ReleaseStepResult> {
     if let Some(version) = state.version.as_deref() {
         if let Some(mismatches) = collect_head_version_mismatches(component, version) {
@@ -462,6 +464,7 @@ pub(crate) fn run_git_tag(
         Some(component_id),
         Some(tag_name),
         Some(&message),
+        signing,
         Some(&component.local_path),
     )?;
     let data = serde_json::to_value(&output)
@@ -584,6 +587,29 @@ pub(crate) fn run_package(
     Ok(step_success("package", "package", Some(data), Vec::new()))
 }
 
+/// Archive the component with the built-in packager (`release --package`)
+/// and stash the archive in [`ReleaseState::artifacts`].
+pub(crate) fn run_builtin_package(
+    component: &Component,
+    state: &mut ReleaseState,
+    format: PackageFormat,
+) -> Result<ReleaseStepResult> {
+    let options = PackageOptions {
+        format,
+        output_dir: None,
+        exclude: Vec::new(),
+    };
+    let package = build::package_component(component, state.version.as_deref(), &options)?;
+    state.artifacts.push(ReleaseArtifact {
+        path: package.path.clone(),
+        artifact_type: Some(format.extension().to_string()),
+        platform: None,
+    });
+
+    let data = serde_json::json!({ "package": package });
+    Ok(step_success("package", "package", Some(data), Vec::new()))
+}
+
 /// Invoke the `release.publish` action on the named extension.
 pub(crate) fn run_publish(
     extensions: &[ExtensionManifest],
@@ -1016,8 +1042,10 @@ pub(crate) fn run_github_release(
 fn load_release_notes(component: &Component) -> Result<String> {
     let changelog_path = release_changelog::resolve_changelog_path(component)?;
     let changelog_content = crate::engine::local_files::local().read(&changelog_path)?;
+    let settings = release_changelog::resolve_effective_settings(Some(component));
     validation::require(
-        extract_latest_notes(&changelog_content),
+        release_changelog::format_for(component)?
+            .release_notes(&changelog_content, &settings.next_section_aliases),
         "changelog",
         "No finalized changelog entries found for release notes",
     )
@@ -1475,7 +1503,7 @@ mod tests {
             ..ReleaseState::default()
         };
 
-        let result = run_git_tag(&component, "fixture", &mut state, "v0.6.13")
+        let result = run_git_tag(&component, "fixture", &mut state, "v0.6.13", None)
             .expect("step should return a result, not propagate Err");
 
         assert_eq!(result.status, ReleaseStepStatus::Failed);
@@ -1501,7 +1529,7 @@ mod tests {
             ..ReleaseState::default()
         };
 
-        let result = run_git_tag(&component, "fixture", &mut state, "v0.6.13")
+        let result = run_git_tag(&component, "fixture", &mut state, "v0.6.13", None)
             .expect("step should succeed when HEAD shows the bumped version");
 
         assert_eq!(result.status, ReleaseStepStatus::Success);
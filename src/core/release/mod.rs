@@ -1,3 +1,4 @@
+mod artifacts;
 pub mod changelog;
 mod context;
 mod deployment;
@@ -21,6 +22,7 @@ mod utils;
 pub mod version;
 mod workflow;
 
+pub use artifacts::{list_pins, pin_artifacts, ArtifactPin, PinnedArtifact};
 pub use pipeline::run;
 pub use planner::plan;
 pub use types::{
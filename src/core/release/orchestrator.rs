@@ -6,10 +6,12 @@
 use crate::error::Result;
 use std::collections::HashSet;
 
+use super::context::{load_component, resolve_extensions};
 use super::execution_plan::{
     build_initial_preflight_plan, execute_plan_steps, initial_executable_preflight_ids,
 };
 use super::pipeline_summary::{build_summary, derive_overall_status};
+use super::plan_steps::known_release_step_ids;
 use super::planner::plan;
 use super::types::{ReleaseOptions, ReleasePlan, ReleaseRun, ReleaseRunResult, ReleaseStepResult};
 
@@ -28,6 +30,17 @@ pub(crate) fn run_with_plan(
 ) -> Result<(ReleasePlan, ReleaseRun)> {
     let mut results: Vec<ReleaseStepResult> = Vec::new();
 
+    if !options.step_filter.is_empty() {
+        let component = load_component(component_id, options)?;
+        let extensions = resolve_extensions(&component)?;
+        options.step_filter.validate(
+            "only/skip",
+            known_release_step_ids(options, &extensions)
+                .iter()
+                .map(String::as_str),
+        )?;
+    }
+
     let initial_plan = build_initial_preflight_plan(component_id, options);
     let initial_stop = execute_plan_steps(
         &initial_plan.plan.steps,
@@ -11,6 +11,39 @@ use crate::Result;
 
 type StepConfig = PlanValues;
 
+const CHANGELOG_POLICY: &str = "changelog.policy";
+const CHANGELOG_GENERATE: &str = "changelog.generate";
+const CHANGELOG_FINALIZE: &str = "changelog.finalize";
+const VERSION: &str = "version";
+const RELEASE_PREPARE: &str = "release.prepare";
+const GIT_COMMIT: &str = "git.commit";
+const PACKAGE: &str = "package";
+const GIT_TAG: &str = "git.tag";
+const GIT_PUSH: &str = "git.push";
+const GITHUB_RELEASE: &str = "github.release";
+const CLEANUP: &str = "cleanup";
+const POST_RELEASE: &str = "post_release";
+const DEPLOY: &str = "deploy";
+
+/// Every step id `build_release_steps` can emit, besides the extension-derived
+/// `publish.<target>` steps. The builder names its steps with the constants
+/// above, which keeps `--only`/`--skip` validation in step with the plan.
+const RELEASE_STEP_IDS: &[&str] = &[
+    CHANGELOG_POLICY,
+    CHANGELOG_GENERATE,
+    CHANGELOG_FINALIZE,
+    VERSION,
+    RELEASE_PREPARE,
+    GIT_COMMIT,
+    PACKAGE,
+    GIT_TAG,
+    GIT_PUSH,
+    GITHUB_RELEASE,
+    CLEANUP,
+    POST_RELEASE,
+    DEPLOY,
+];
+
 /// Return true if this component should get a GitHub Release created.
 ///
 /// Resolves the remote URL from the component config (preferred) or from
@@ -186,7 +219,10 @@ pub(super) fn build_release_steps(
     let mut steps = Vec::new();
     let publish_targets = get_publish_targets(extensions);
 
-    if !publish_targets.is_empty() && !has_package_capability(extensions) {
+    if !publish_targets.is_empty()
+        && options.package_format.is_none()
+        && !has_package_capability(extensions)
+    {
         warnings.push(
             "Publish targets derived from extensions but no extension provides 'release.package'. \
              Add an extension that provides packaging."
@@ -205,76 +241,85 @@ pub(super) fn build_release_steps(
         .string("from", current_version)
         .string("to", new_version);
     steps.push(ready_step(
-        "version",
-        "version",
+        VERSION,
+        VERSION,
         format!(
             "Bump version {} → {} ({})",
             current_version, new_version, options.bump_type
         ),
-        vec!["changelog.finalize".to_string()],
+        vec![CHANGELOG_FINALIZE.to_string()],
         version_config,
     ));
 
     let commit_needs = if has_prepare_capability(extensions) {
         steps.push(ready_step(
-            "release.prepare",
-            "release.prepare",
+            RELEASE_PREPARE,
+            RELEASE_PREPARE,
             "Prepare release files",
-            vec!["version".to_string()],
+            vec![VERSION.to_string()],
             StepConfig::new(),
         ));
-        vec!["release.prepare".to_string()]
+        vec![RELEASE_PREPARE.to_string()]
     } else {
-        vec!["version".to_string()]
+        vec![VERSION.to_string()]
     };
 
     steps.push(ready_step(
-        "git.commit",
-        "git.commit",
+        GIT_COMMIT,
+        GIT_COMMIT,
         format!("Commit release: v{}", new_version),
         commit_needs,
         StepConfig::new(),
     ));
 
-    let tag_needs = if !publish_targets.is_empty() && !options.skip_publish {
+    let packages = !publish_targets.is_empty() || options.package_format.is_some();
+    let tag_needs = if packages && !options.skip_publish {
+        let package_config = match options.package_format {
+            Some(format) => StepConfig::new().string("format", format.extension()),
+            None => StepConfig::new(),
+        };
         steps.push(ready_step(
-            "package",
-            "package",
+            PACKAGE,
+            PACKAGE,
             "Package release artifacts",
-            vec!["git.commit".to_string()],
-            StepConfig::new(),
+            vec![GIT_COMMIT.to_string()],
+            package_config,
         ));
-        vec!["package".to_string()]
+        vec![PACKAGE.to_string()]
     } else {
-        vec!["git.commit".to_string()]
+        vec![GIT_COMMIT.to_string()]
     };
 
     let tag_name = match monorepo {
         Some(ctx) => ctx.format_tag(new_version),
         None => format!("v{}", new_version),
     };
+    let mut tag_config = string_config("name", tag_name.clone());
+    if let Some(signing) = crate::git::TagSigning::resolve(options.sign_tag) {
+        tag_config = tag_config.json("signing", signing);
+    }
     steps.push(ready_step(
-        "git.tag",
-        "git.tag",
+        GIT_TAG,
+        GIT_TAG,
         format!("Tag {}", tag_name),
         tag_needs,
-        string_config("name", tag_name),
+        tag_config,
     ));
 
     steps.push(ready_step(
-        "git.push",
-        "git.push",
+        GIT_PUSH,
+        GIT_PUSH,
         "Push to remote",
-        vec!["git.tag".to_string()],
+        vec![GIT_TAG.to_string()],
         StepConfig::new().bool("tags", true),
     ));
 
     if !options.skip_github_release && github_release_applies(component) {
         steps.push(ready_step(
-            "github.release",
-            "github.release",
+            GITHUB_RELEASE,
+            GITHUB_RELEASE,
             "Create GitHub Release",
-            vec!["git.push".to_string()],
+            vec![GIT_PUSH.to_string()],
             StepConfig::new(),
         ));
     }
@@ -288,15 +333,15 @@ pub(super) fn build_release_steps(
                 &step_id,
                 &step_id,
                 format!("Publish to {}", target),
-                vec!["git.push".to_string()],
+                vec![GIT_PUSH.to_string()],
                 StepConfig::new(),
             ));
         }
 
         if !options.deploy {
             steps.push(ready_step(
-                "cleanup",
-                "cleanup",
+                CLEANUP,
+                CLEANUP,
                 "Clean up release artifacts",
                 publish_step_ids.clone(),
                 StepConfig::new(),
@@ -313,15 +358,15 @@ pub(super) fn build_release_steps(
             if options.deploy {
                 publish_step_ids.clone()
             } else {
-                vec!["cleanup".to_string()]
+                vec![CLEANUP.to_string()]
             }
         } else {
-            vec!["git.push".to_string()]
+            vec![GIT_PUSH.to_string()]
         };
 
         steps.push(ready_step(
-            "post_release",
-            "post_release",
+            POST_RELEASE,
+            POST_RELEASE,
             "Run post-release hooks",
             post_release_needs,
             string_array_config("commands", &post_release_hooks),
@@ -330,16 +375,16 @@ pub(super) fn build_release_steps(
 
     if options.deploy {
         let deploy_needs = if !post_release_hooks.is_empty() {
-            vec!["post_release".to_string()]
+            vec![POST_RELEASE.to_string()]
         } else if !options.skip_publish && !publish_step_ids.is_empty() {
             publish_step_ids
         } else {
-            vec!["git.push".to_string()]
+            vec![GIT_PUSH.to_string()]
         };
 
         steps.push(ready_step(
-            "deploy",
-            "deploy",
+            DEPLOY,
+            DEPLOY,
             "Deploy released component",
             deploy_needs,
             string_config("execution", "release_plan"),
@@ -349,6 +394,27 @@ pub(super) fn build_release_steps(
     Ok(steps)
 }
 
+/// Every step id a release plan can contain for these extensions.
+///
+/// Used to validate `--only`/`--skip` selectors before any step executes,
+/// so a typo fails fast instead of after the preflight lint/test run.
+pub(super) fn known_release_step_ids(
+    options: &ReleaseOptions,
+    extensions: &[ExtensionManifest],
+) -> Vec<String> {
+    let mut ids: Vec<String> = build_preflight_steps(options, None)
+        .into_iter()
+        .map(|step| step.id)
+        .collect();
+    ids.extend(RELEASE_STEP_IDS.iter().map(|id| id.to_string()));
+    ids.extend(
+        get_publish_targets(extensions)
+            .into_iter()
+            .map(|target| format!("publish.{}", target)),
+    );
+    ids
+}
+
 fn build_changelog_steps(
     changelog_plan: &ReleaseChangelogPlan,
     current_version: &str,
@@ -373,24 +439,24 @@ fn build_changelog_steps(
 
     vec![
         ready_step(
-            "changelog.policy",
-            "changelog.policy",
+            CHANGELOG_POLICY,
+            CHANGELOG_POLICY,
             "Resolve changelog policy",
             vec!["preflight.changelog_bootstrap".to_string()],
             policy_config,
         ),
         ready_step(
-            "changelog.generate",
-            "changelog.generate",
+            CHANGELOG_GENERATE,
+            CHANGELOG_GENERATE,
             "Generate changelog entries from commits",
-            vec!["changelog.policy".to_string()],
+            vec![CHANGELOG_POLICY.to_string()],
             generate_config,
         ),
         ready_step(
-            "changelog.finalize",
-            "changelog.finalize",
+            CHANGELOG_FINALIZE,
+            CHANGELOG_FINALIZE,
             "Finalize changelog entries into release section",
-            vec!["changelog.generate".to_string()],
+            vec![CHANGELOG_GENERATE.to_string()],
             finalize_config,
         ),
     ]
@@ -402,7 +468,10 @@ fn string_array_config(key: &str, values: &[String]) -> StepConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::{build_preflight_steps, build_release_steps, github_release_applies};
+    use super::{
+        build_preflight_steps, build_release_steps, github_release_applies, known_release_step_ids,
+        RELEASE_STEP_IDS,
+    };
     use crate::component::Component;
     use crate::plan::PlanStepStatus;
     use crate::release::types::{
@@ -438,6 +507,72 @@ mod tests {
         assert_eq!(steps[2].needs, vec!["preflight.git_identity"]);
     }
 
+    #[test]
+    fn known_release_step_ids_cover_preflight_and_core_steps() {
+        let ids = known_release_step_ids(&ReleaseOptions::default(), &[]);
+
+        for expected in ["preflight.lint", "changelog.finalize", "git.tag", "deploy"] {
+            assert!(ids.iter().any(|id| id == expected), "missing {expected}");
+        }
+        assert!(!ids.iter().any(|id| id.starts_with("publish.")));
+    }
+
+    #[test]
+    fn release_step_ids_match_the_steps_the_builder_emits() {
+        let component = Component {
+            remote_url: Some("https://github.com/owner/fixture".to_string()),
+            hooks: std::collections::HashMap::from([(
+                crate::engine::hooks::events::POST_RELEASE.to_string(),
+                vec!["true".to_string()],
+            )]),
+            ..fixture_component()
+        };
+        let mut extension: crate::extension::ExtensionManifest =
+            serde_json::from_value(serde_json::json!({
+                "name": "Fixture",
+                "version": "1.0.0",
+                "actions": [
+                    { "id": "release.prepare", "label": "Prepare", "type": "command", "command": "true" },
+                    { "id": "release.publish", "label": "Publish", "type": "command", "command": "true" }
+                ]
+            }))
+            .expect("extension manifest");
+        extension.id = "fixture".to_string();
+        let extensions = [extension];
+
+        // `cleanup` only runs without `--deploy`, so plan both ways.
+        let mut emitted = std::collections::BTreeSet::new();
+        for deploy in [false, true] {
+            let options = ReleaseOptions {
+                bump_type: "patch".to_string(),
+                deploy,
+                ..Default::default()
+            };
+            let steps = build_release_steps(
+                &component,
+                &extensions,
+                "1.0.0",
+                "1.0.1",
+                &fixture_changelog_plan(),
+                &options,
+                None,
+                &mut Vec::new(),
+                &mut Vec::new(),
+            )
+            .expect("steps");
+            emitted.extend(steps.into_iter().map(|step| step.id));
+        }
+
+        let known: std::collections::BTreeSet<String> =
+            known_release_step_ids(&ReleaseOptions::default(), &extensions)
+                .into_iter()
+                .filter(|id| !id.starts_with("preflight."))
+                .collect();
+        assert!(emitted.contains("publish.fixture"));
+        assert_eq!(emitted, known);
+        assert_eq!(known.len(), RELEASE_STEP_IDS.len() + 1);
+    }
+
     #[test]
     fn release_plan_marks_git_identity_ready_when_requested() {
         let options = ReleaseOptions {
@@ -630,6 +765,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn release_plan_carries_tag_signing_when_requested() {
+        let options = ReleaseOptions {
+            bump_type: "patch".to_string(),
+            sign_tag: true,
+            ..Default::default()
+        };
+
+        let steps = crate::test_support::with_isolated_home(|_| {
+            build_release_steps(
+                &fixture_component(),
+                &[],
+                "1.0.0",
+                "1.0.1",
+                &fixture_changelog_plan(),
+                &options,
+                None,
+                &mut Vec::new(),
+                &mut Vec::new(),
+            )
+        })
+        .expect("steps");
+
+        let tag = steps
+            .iter()
+            .find(|step| step.id == "git.tag")
+            .expect("git.tag step");
+        assert_eq!(tag.inputs.get("signing"), Some(&serde_json::json!({})));
+    }
+
     #[test]
     fn test_build_release_steps() {
         let component = fixture_component();
@@ -795,6 +960,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn release_plan_packages_with_builtin_format_without_publish_targets() {
+        let component = fixture_component();
+        let mut warnings = Vec::new();
+        let mut hints = Vec::new();
+        let options = ReleaseOptions {
+            bump_type: "patch".to_string(),
+            package_format: Some(crate::build::PackageFormat::Zip),
+            ..Default::default()
+        };
+
+        let steps = build_release_steps(
+            &component,
+            &[],
+            "1.0.0",
+            "1.0.1",
+            &fixture_changelog_plan(),
+            &options,
+            None,
+            &mut warnings,
+            &mut hints,
+        )
+        .expect("steps");
+
+        let package = steps
+            .iter()
+            .find(|step| step.id == "package")
+            .expect("package step");
+        assert_eq!(
+            package
+                .inputs
+                .get("format")
+                .and_then(|value| value.as_str()),
+            Some("zip")
+        );
+        let tag = steps
+            .iter()
+            .find(|step| step.id == "git.tag")
+            .expect("tag step");
+        assert_eq!(tag.needs, vec!["package"]);
+        assert!(!steps.iter().any(|step| step.id == "cleanup"));
+    }
+
     #[test]
     fn test_github_release_applies() {
         let mut github_component = fixture_component();
@@ -6,7 +6,7 @@ use crate::git;
 use crate::version;
 
 use super::context::{load_component, resolve_extensions};
-use super::plan_steps::{build_preflight_steps, build_release_steps};
+use super::plan_steps::{build_preflight_steps, build_release_steps, known_release_step_ids};
 use super::planning_changelog::{build_changelog_plan, generate_changelog_entries};
 use super::planning_policy::release_skip_plan;
 use super::planning_semver::{build_semver_recommendation, validate_release_version_floor};
@@ -21,6 +21,12 @@ use super::types::{ReleaseOptions, ReleasePlan};
 pub fn plan(component_id: &str, options: &ReleaseOptions) -> Result<ReleasePlan> {
     let component = load_component(component_id, options)?;
     let extensions = resolve_extensions(&component)?;
+    options.step_filter.validate(
+        "only/skip",
+        known_release_step_ids(options, &extensions)
+            .iter()
+            .map(String::as_str),
+    )?;
 
     let mut v = ValidationCollector::new();
 
@@ -105,6 +111,8 @@ pub fn plan(component_id: &str, options: &ReleaseOptions) -> Result<ReleasePlan>
         &mut hints,
     )?);
 
+    options.step_filter.apply(&mut steps);
+
     if options.dry_run {
         hints.push("Dry run: no changes will be made".to_string());
     }
@@ -65,9 +65,12 @@ pub(super) fn generate_changelog_entries(
     // release commit was produced in a prior run that got interrupted before
     // tagging. No new entries to generate; let the rest of the pipeline finish.
     let changelog_path = changelog::resolve_changelog_path(component)?;
+    let format = changelog::format_for(component)?;
+    let settings = changelog::resolve_effective_settings(Some(component));
     let changelog_content =
         read_changelog_for_release(component, &changelog_path, options.dry_run)?;
-    let latest_changelog_version = changelog::get_latest_finalized_version(&changelog_content);
+    let latest_changelog_version =
+        format.latest_finalized_version(&changelog_content, &settings.next_section_aliases)?;
     if let (Some(latest_tag), Some(changelog_ver_str)) = (&latest_tag, latest_changelog_version) {
         let tag_version = latest_tag.trim_start_matches('v');
         if let (Ok(tag_ver), Ok(cl_ver)) = (
@@ -118,7 +121,7 @@ fn read_changelog_for_release(
                 changelog_path.display(),
                 component.id
             );
-            Ok(changelog::INITIAL_CHANGELOG_CONTENT.to_string())
+            Ok(changelog::format_for(component)?.initial_content())
         }
         Err(err) => Err(err),
     }
@@ -159,8 +162,8 @@ pub(super) fn ensure_changelog_initialized(component: &Component) -> Result<()>
         crate::engine::local_files::local().ensure_dir(parent)?;
     }
 
-    crate::engine::local_files::local()
-        .write(&configured_path, changelog::INITIAL_CHANGELOG_CONTENT)?;
+    let initial_content = changelog::format_for(component)?.initial_content();
+    crate::engine::local_files::local().write(&configured_path, &initial_content)?;
 
     log_status!(
         "release",
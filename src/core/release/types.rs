@@ -2,8 +2,9 @@ use serde::ser::Error as SerializeError;
 use serde::{Deserialize, Serialize, Serializer};
 use std::collections::HashMap;
 
+use crate::build::PackageFormat;
 use crate::is_zero_u32;
-use crate::plan::{HomeboyPlan, PlanKind, PlanStep};
+use crate::plan::{HomeboyPlan, PlanKind, PlanStep, PlanStepFilter};
 
 /// Ordered release plan shared by dry-run output and release execution.
 ///
@@ -227,6 +228,19 @@ pub struct ReleaseOptions {
     /// Bump policy controls that affect release plan validation.
     #[serde(default, skip_serializing_if = "ReleaseBumpPolicyOptions::is_default")]
     pub bump_policy: ReleaseBumpPolicyOptions,
+    /// `--only`/`--skip` selection over release plan step ids.
+    #[serde(default, skip_serializing_if = "PlanStepFilter::is_empty")]
+    pub step_filter: PlanStepFilter,
+    /// Copy packaged artifacts here and pin them to the released version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifact_output: Option<String>,
+    /// `--sign`: sign the release tag even when `release.sign_tags` is off.
+    #[serde(default)]
+    pub sign_tag: bool,
+    /// `--package`: archive the component with the built-in packager instead
+    /// of an extension's `release.package` action.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_format: Option<PackageFormat>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -276,6 +290,18 @@ pub struct ReleaseCommandInput {
     /// Git identity for release commits: "bot", "Name <email>", or None (use existing config).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub git_identity: Option<String>,
+    /// `--only`/`--skip` selection over release plan step ids.
+    #[serde(skip_serializing_if = "PlanStepFilter::is_empty")]
+    pub step_filter: PlanStepFilter,
+    /// `--output`: copy packaged artifacts here and pin them to the version.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub artifact_output: Option<String>,
+    /// `--sign`: sign the release tag.
+    #[serde(default)]
+    pub sign_tag: bool,
+    /// `--package`: archive format for the built-in packager.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_format: Option<PackageFormat>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
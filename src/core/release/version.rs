@@ -1,8 +1,10 @@
 mod default_pattern_for_file;
+mod sources;
 mod types;
 mod version;
 
 pub use default_pattern_for_file::*;
+pub use sources::*;
 pub use types::*;
 pub use version::*;
 
@@ -237,6 +239,7 @@ pub(crate) fn validate_and_finalize_changelog(
     generated_entries: Option<&std::collections::HashMap<String, Vec<String>>>,
 ) -> Result<ChangelogValidationResult> {
     let settings = changelog::resolve_effective_settings(Some(component));
+    let format = changelog::format_for(component)?;
     let changelog_path = changelog::resolve_changelog_path(component)?;
 
     // resolve_changelog_path() already handles fallback discovery when the
@@ -263,7 +266,8 @@ pub(crate) fn validate_and_finalize_changelog(
     // Bootstrap case: a fresh changelog with no finalized versions is a
     // legitimate first-release state, not an error. The finalize step below
     // writes the first version section. See #1172.
-    let latest_changelog_version = changelog::get_latest_finalized_version(&changelog_content);
+    let latest_changelog_version =
+        format.latest_finalized_version(&changelog_content, &settings.next_section_aliases)?;
 
     // Reject if changelog is ahead of files (version gap). Skipped on the
     // bootstrap case (no prior finalized version).
@@ -299,7 +303,7 @@ pub(crate) fn validate_and_finalize_changelog(
             .iter()
             .map(|(k, v)| (k.as_str(), v.clone()))
             .collect();
-        changelog::finalize_with_generated_entries(
+        format.finalize_with_generated_entries(
             &changelog_content,
             &settings.next_section_aliases,
             &entries_ref,
@@ -307,7 +311,7 @@ pub(crate) fn validate_and_finalize_changelog(
         )?
     } else {
         // Legacy path: finalize an existing ## Unreleased section.
-        changelog::finalize_next_section(
+        format.finalize_next_section(
             &changelog_content,
             &settings.next_section_aliases,
             new_version,
@@ -26,9 +26,15 @@ pub(crate) fn parse_versions(content: &str, pattern: &str) -> Option<Vec<String>
 /// Get version pattern from extension configuration.
 /// Returns None if no extension defines a pattern for this file type.
 pub fn default_pattern_for_file(filename: &str) -> Option<String> {
+    default_pattern_source_for_file(filename).map(|(_, pattern)| pattern)
+}
+
+/// Like [`default_pattern_for_file`], also returning the ID of the extension
+/// that provides the pattern.
+pub fn default_pattern_source_for_file(filename: &str) -> Option<(String, String)> {
     for extension in load_all_extensions().unwrap_or_default() {
         if let Some(pattern) = find_version_pattern_in_extension(&extension, filename) {
-            return Some(pattern);
+            return Some((extension.id, pattern));
         }
     }
     None
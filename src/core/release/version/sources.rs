@@ -0,0 +1,338 @@
+//! sources — read and reconcile the places a component's version is recorded:
+//! version targets, the latest release tag, and the changelog.
+
+use std::path::{Path, PathBuf};
+
+use crate::component::Component;
+use crate::engine::local_files::{self, FileSystem};
+use crate::engine::text;
+use crate::error::{Error, Result};
+use crate::git;
+use crate::release::changelog;
+
+use super::default_pattern_for_file::{
+    parse_versions, read_component_version, resolve_target_pattern, resolve_version_file_path,
+};
+use super::types::{VersionSources, VersionSyncResult, VersionSyncStatus, VersionSyncStep};
+use super::update_version_in_file;
+
+/// Options for [`sync_version_sources`].
+#[derive(Debug, Clone, Default)]
+pub struct VersionSyncOptions {
+    /// Report what would change without writing anything.
+    pub dry_run: bool,
+    /// Allow a target older than a version already recorded.
+    pub allow_downgrade: bool,
+    /// Also create the release tag when it is missing.
+    pub tag: bool,
+}
+
+/// Read the component's version from its version targets, its latest release
+/// tag, and its changelog's latest finalized release. Missing tags or a
+/// missing changelog are reported as `None` and do not count as drift.
+pub fn list_version_sources(component: &Component) -> Result<VersionSources> {
+    let source = read_component_version(component)?.version;
+
+    let monorepo = git::MonorepoContext::detect(&component.local_path, &component.id);
+    let tag = match &monorepo {
+        Some(ctx) => git::get_latest_tag_with_prefix(&ctx.git_root, Some(&ctx.tag_prefix)),
+        None => git::get_latest_tag(&component.local_path),
+    }
+    .ok()
+    .flatten();
+    let tag_version = tag.as_deref().map(|tag| {
+        let tag = monorepo
+            .as_ref()
+            .and_then(|ctx| tag.strip_prefix(&format!("{}-", ctx.tag_prefix)))
+            .unwrap_or(tag);
+        tag.strip_prefix('v').unwrap_or(tag).to_string()
+    });
+
+    let changelog = match read_changelog(component) {
+        Some((_, content)) => {
+            let settings = changelog::resolve_effective_settings(Some(component));
+            changelog::format_for(component)?
+                .latest_finalized_version(&content, &settings.next_section_aliases)?
+        }
+        None => None,
+    };
+
+    let in_sync = [&tag_version, &changelog]
+        .into_iter()
+        .flatten()
+        .all(|version| *version == source);
+
+    Ok(VersionSources {
+        source,
+        tag,
+        tag_version,
+        changelog,
+        in_sync,
+    })
+}
+
+/// Bring every version target, the changelog, and (with `options.tag`) the
+/// release tag to `version`. Sources already at `version` are skipped, so
+/// re-running is safe. A tag is only created once no files change in the
+/// same run, so it never points at a commit without the new version.
+pub fn sync_version_sources(
+    component: &Component,
+    version: &str,
+    options: &VersionSyncOptions,
+) -> Result<VersionSyncResult> {
+    let version = version.trim().strip_prefix('v').unwrap_or(version.trim());
+    let target = semver::Version::parse(version).map_err(|_| {
+        Error::validation_invalid_argument(
+            "version",
+            format!("'{}' is not a semantic version", version),
+            None,
+            None,
+        )
+    })?;
+
+    let current = list_version_sources(component)?;
+    if !options.allow_downgrade {
+        check_not_downgrade(&current, &target)?;
+    }
+
+    let mut steps = Vec::new();
+    let targets = component.version_targets.as_deref().unwrap_or_default();
+    for version_target in targets {
+        let pattern = resolve_target_pattern(version_target)?;
+        let full_path = resolve_version_file_path(&component.local_path, &version_target.file);
+        let content = local_files::local().read(Path::new(&full_path))?;
+        let found = parse_versions(&content, &pattern).unwrap_or_default();
+        let from = text::require_identical(&found, &version_target.file)?;
+
+        let status = if from == version {
+            VersionSyncStatus::Skipped
+        } else if options.dry_run {
+            VersionSyncStatus::Planned
+        } else {
+            update_version_in_file(&full_path, &pattern, &from, version)?;
+            VersionSyncStatus::Updated
+        };
+        steps.push(VersionSyncStep {
+            source: "source".to_string(),
+            target: version_target.file.clone(),
+            from: Some(from),
+            status,
+        });
+    }
+
+    if let Some((path, content)) = read_changelog(component) {
+        let status = if current.changelog.as_deref() == Some(version) {
+            VersionSyncStatus::Skipped
+        } else if options.dry_run {
+            VersionSyncStatus::Planned
+        } else {
+            write_changelog_release(component, &path, &content, version)?;
+            VersionSyncStatus::Updated
+        };
+        steps.push(VersionSyncStep {
+            source: "changelog".to_string(),
+            target: path.to_string_lossy().to_string(),
+            from: current.changelog.clone(),
+            status,
+        });
+    }
+
+    if options.tag {
+        steps.push(sync_tag(component, version, &steps, options)?);
+    }
+
+    Ok(VersionSyncResult {
+        version: version.to_string(),
+        dry_run: options.dry_run,
+        steps,
+    })
+}
+
+fn read_changelog(component: &Component) -> Option<(PathBuf, String)> {
+    let path = changelog::resolve_changelog_path(component).ok()?;
+    let content = local_files::local().read(&path).ok()?;
+    Some((path, content))
+}
+
+fn check_not_downgrade(current: &VersionSources, target: &semver::Version) -> Result<()> {
+    let recorded = [
+        Some(&current.source),
+        current.tag_version.as_ref(),
+        current.changelog.as_ref(),
+    ];
+    let Some(newest) = recorded
+        .into_iter()
+        .flatten()
+        .filter_map(|version| semver::Version::parse(version).ok())
+        .max()
+        .filter(|newest| newest > target)
+    else {
+        return Ok(());
+    };
+
+    Err(Error::validation_invalid_argument(
+        "version",
+        format!("Refusing to move backwards from {} to {}", newest, target),
+        None,
+        None,
+    )
+    .with_hint("Pass --allow-downgrade to sync to an older version anyway"))
+}
+
+/// Finalize the next section as `version` when it has entries, otherwise add
+/// an empty `version` release so the changelog's latest heading matches.
+fn write_changelog_release(
+    component: &Component,
+    path: &Path,
+    content: &str,
+    version: &str,
+) -> Result<()> {
+    let settings = changelog::resolve_effective_settings(Some(component));
+    let format = changelog::format_for(component)?;
+    let pending = format.unreleased_entries(content, &settings.next_section_aliases)?;
+    let updated = if pending.is_empty() {
+        format.insert_release(content, &settings.next_section_aliases, version)?
+    } else {
+        format
+            .finalize_next_section(content, &settings.next_section_aliases, version, false)?
+            .0
+    };
+    local_files::local().write(path, &updated)
+}
+
+fn sync_tag(
+    component: &Component,
+    version: &str,
+    steps: &[VersionSyncStep],
+    options: &VersionSyncOptions,
+) -> Result<VersionSyncStep> {
+    let monorepo = git::MonorepoContext::detect(&component.local_path, &component.id);
+    let tag_name = match &monorepo {
+        Some(ctx) => ctx.format_tag(version),
+        None => format!("v{}", version),
+    };
+    let files_pending = steps
+        .iter()
+        .any(|step| step.status != VersionSyncStatus::Skipped);
+
+    let status = if git::tag_exists_locally(&component.local_path, &tag_name)? {
+        VersionSyncStatus::Skipped
+    } else if files_pending {
+        VersionSyncStatus::Deferred
+    } else if options.dry_run {
+        VersionSyncStatus::Planned
+    } else {
+        let output = git::tag_at(
+            Some(&component.id),
+            Some(&tag_name),
+            Some(&format!("Release {}", tag_name)),
+            git::TagSigning::resolve(false).as_ref(),
+            Some(&component.local_path),
+        )?;
+        if !output.success {
+            return Err(Error::git_command_failed(format!(
+                "Failed to create tag {}: {}",
+                tag_name,
+                output.stderr.trim()
+            )));
+        }
+        VersionSyncStatus::Updated
+    };
+
+    Ok(VersionSyncStep {
+        source: "tag".to_string(),
+        target: tag_name,
+        from: None,
+        status,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::VersionTarget;
+    use std::fs;
+
+    fn component_at(temp_dir: &tempfile::TempDir, version: &str, changelog: &str) -> Component {
+        fs::write(
+            temp_dir.path().join("package.json"),
+            format!("{{\n  \"version\": \"{}\"\n}}\n", version),
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("CHANGELOG.md"), changelog).unwrap();
+        Component {
+            id: "test-component".to_string(),
+            local_path: temp_dir.path().to_string_lossy().to_string(),
+            changelog_target: Some("CHANGELOG.md".to_string()),
+            version_targets: Some(vec![VersionTarget {
+                file: "package.json".to_string(),
+                pattern: Some(r#""version"\s*:\s*"([^"]+)""#.to_string()),
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_list_version_sources() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let component = component_at(
+            &temp_dir,
+            "0.3.0",
+            "# Changelog\n\n## [0.2.0] - 2026-01-01\n\n### Fixed\n- entry\n",
+        );
+
+        let sources = list_version_sources(&component).expect("list sources");
+
+        assert_eq!(sources.source, "0.3.0");
+        assert_eq!(sources.changelog.as_deref(), Some("0.2.0"));
+        assert_eq!(sources.tag, None);
+        assert!(!sources.in_sync);
+    }
+
+    #[test]
+    fn test_sync_version_sources() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let component = component_at(
+            &temp_dir,
+            "0.2.0",
+            "# Changelog\n\n## Unreleased\n\n## [0.2.0] - 2026-01-01\n\n- entry\n",
+        );
+        let options = VersionSyncOptions::default();
+
+        let result = sync_version_sources(&component, "0.3.0", &options).expect("sync");
+        let statuses: Vec<_> = result.steps.iter().map(|step| step.status).collect();
+        assert_eq!(
+            statuses,
+            vec![VersionSyncStatus::Updated, VersionSyncStatus::Updated]
+        );
+        assert!(list_version_sources(&component).unwrap().in_sync);
+
+        let again = sync_version_sources(&component, "0.3.0", &options).expect("resync");
+        assert!(again
+            .steps
+            .iter()
+            .all(|step| step.status == VersionSyncStatus::Skipped));
+    }
+
+    #[test]
+    fn sync_refuses_downgrade_and_writes_nothing_on_dry_run() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let component = component_at(&temp_dir, "1.0.0", "# Changelog\n");
+
+        let err =
+            sync_version_sources(&component, "0.9.0", &VersionSyncOptions::default()).unwrap_err();
+        assert!(err.message.contains("Refusing to move backwards"));
+
+        let options = VersionSyncOptions {
+            dry_run: true,
+            allow_downgrade: true,
+            tag: false,
+        };
+        let result = sync_version_sources(&component, "0.9.0", &options).expect("dry run");
+        assert!(result
+            .steps
+            .iter()
+            .all(|step| step.status == VersionSyncStatus::Planned));
+        assert_eq!(list_version_sources(&component).unwrap().source, "1.0.0");
+    }
+}
@@ -66,3 +66,50 @@ pub struct UnconfiguredPattern {
 
 /// Default placeholder pattern for `@since` tags.
 pub(crate) const DEFAULT_SINCE_PLACEHOLDER: &str = r"0\.0\.0|NEXT|TBD|TODO|UNRELEASED|x\.x\.x";
+
+/// Where a component's version is recorded, and whether those places agree.
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionSources {
+    /// Version declared in the component's version targets.
+    pub source: String,
+    /// Latest release tag reachable from HEAD, as written.
+    pub tag: Option<String>,
+    /// `tag` without the monorepo prefix and leading `v`.
+    pub tag_version: Option<String>,
+    /// Version of the changelog's latest finalized release.
+    pub changelog: Option<String>,
+    /// True when every recorded version matches `source`.
+    pub in_sync: bool,
+}
+
+/// What `version sync` did, or would do, to one version source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionSyncStatus {
+    /// Already at the target version.
+    Skipped,
+    /// Changed to the target version.
+    Updated,
+    /// Would change; nothing was written (`--dry-run`).
+    Planned,
+    /// Tag not created because files changed in this run and need committing first.
+    Deferred,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionSyncStep {
+    /// `source`, `changelog`, or `tag`.
+    pub source: String,
+    /// Version file, changelog path, or tag name.
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    pub status: VersionSyncStatus,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VersionSyncResult {
+    pub version: String,
+    pub dry_run: bool,
+    pub steps: Vec<VersionSyncStep>,
+}
@@ -123,6 +123,10 @@ pub fn run_command(input: ReleaseCommandInput) -> Result<(ReleaseCommandResult,
             force_empty_release: input.bump_override.is_some(),
             require_explicit_major,
         },
+        step_filter: input.step_filter.clone(),
+        artifact_output: input.artifact_output.clone(),
+        sign_tag: input.sign_tag,
+        package_format: input.package_format,
     };
 
     if options.dry_run {
@@ -366,6 +370,7 @@ fn run_recover(input: &ReleaseCommandInput) -> Result<(ReleaseCommandResult, i32
             Some(&input.component_id),
             Some(&tag_name),
             Some(&format!("Release {}", tag_name)),
+            git::TagSigning::resolve(input.sign_tag).as_ref(),
         )?;
         if !tag_result.success {
             return Err(Error::git_command_failed(format!(
@@ -577,6 +582,10 @@ pub fn run_batch(
             skip_publish: input_template.skip_publish,
             skip_github_release: input_template.skip_github_release,
             git_identity: input_template.git_identity.clone(),
+            step_filter: input_template.step_filter.clone(),
+            artifact_output: input_template.artifact_output.clone(),
+            sign_tag: input_template.sign_tag,
+            package_format: input_template.package_format,
         };
 
         match run_command(input) {
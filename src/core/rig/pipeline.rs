@@ -6,7 +6,7 @@
 //! Every step emits a `PipelineStepOutcome`. The runner aggregates them into
 //! a `PipelineOutcome` with overall success/failure.
 
-use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -23,6 +23,7 @@ use super::stack as rig_stack;
 use super::state::{now_rfc3339, RigState, SharedPathState};
 use super::toolchain;
 use crate::component::Component;
+use crate::engine::topo;
 use crate::error::{Error, Result};
 
 /// Result of one pipeline step.
@@ -215,9 +216,7 @@ fn order_pipeline_steps(
         }
     }
 
-    let mut indegree = vec![0usize; steps.len()];
-    let mut dependents = vec![Vec::<usize>::new(); steps.len()];
-
+    let mut dependencies = vec![Vec::<usize>::new(); steps.len()];
     for (idx, step) in steps.iter().enumerate() {
         for dependency_id in step_dependencies(step) {
             let Some(&dependency_idx) = id_to_index.get(dependency_id.as_str()) else {
@@ -231,52 +230,26 @@ fn order_pipeline_steps(
                     ),
                 ));
             };
-            indegree[idx] += 1;
-            dependents[dependency_idx].push(idx);
+            dependencies[idx].push(dependency_idx);
         }
     }
 
-    for child_indices in &mut dependents {
-        child_indices.sort_unstable();
-    }
-
-    let mut ready = VecDeque::new();
-    for (idx, count) in indegree.iter().enumerate() {
-        if *count == 0 {
-            ready.push_back(idx);
-        }
-    }
-
-    let mut ordered = Vec::with_capacity(steps.len());
-    while let Some(idx) = ready.pop_front() {
-        ordered.push(idx);
-        for dependent_idx in dependents[idx].iter().copied() {
-            indegree[dependent_idx] -= 1;
-            if indegree[dependent_idx] == 0 {
-                ready.push_back(dependent_idx);
-            }
-        }
-    }
-
-    if ordered.len() != steps.len() {
-        let cycle_members = steps
+    topo::topo_sort(&dependencies).map_err(|cycle| {
+        let cycle_members = cycle
+            .blocked
             .iter()
-            .enumerate()
-            .filter(|&(idx, _step)| indegree[idx] > 0)
-            .map(|(idx, step)| step_node_label(step, idx))
+            .map(|&idx| step_node_label(&steps[idx], idx))
             .collect::<Vec<_>>()
             .join(", ");
-        return Err(Error::rig_pipeline_failed(
+        Error::rig_pipeline_failed(
             &rig.id,
             pipeline_name,
             format!(
                 "pipeline dependency cycle detected involving {}",
                 cycle_members
             ),
-        ));
-    }
-
-    Ok(ordered)
+        )
+    })
 }
 
 fn step_id(step: &PipelineStep) -> Option<&str> {
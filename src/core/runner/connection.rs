@@ -304,6 +304,16 @@ fn open_loopback_tunnel(
         args.push("-p".to_string());
         args.push(server.port.to_string());
     }
+    match server::resolve_proxy_jump(server) {
+        Ok(proxy_jump) => args.extend(proxy_jump.map(|jump| jump.ssh_args()).unwrap_or_default()),
+        Err(err) => {
+            return SshTunnelOutput {
+                pid: None,
+                stderr: err.message,
+                success: false,
+            }
+        }
+    }
     if let Some(auth) = &server.auth {
         if auth.mode == ServerAuthMode::KeyPlusPasswordControlmaster {
             let control_path = auth
@@ -642,8 +652,11 @@ mod tests {
             port: 22,
             identity_file: None,
             kind: None,
+            tags: Vec::new(),
             auth: None,
             env: HashMap::new(),
+            proxy_jump: None,
+            service_manager: None,
         };
 
         let tunnel = open_loopback_tunnel(&server, 49100, "127.0.0.1", 49200);
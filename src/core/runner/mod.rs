@@ -173,6 +173,7 @@ mod tests {
                 Some("lab-local"),
                 r#"{"workspace_root":"/tmp/b","concurrency_limit":3}"#,
                 &[],
+                &[],
             )
             .expect("merge runner");
 
@@ -1,4 +1,4 @@
-use crate::upgrade::{self, InstallMethod};
+use crate::upgrade::{self, InstallMethod, ReleaseChannel};
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -73,7 +73,10 @@ pub struct ProbeOutput {
 pub fn collect_status() -> SelfStatus {
     collect_status_with(
         std::env::current_exe().ok(),
-        || upgrade::fetch_latest_version(InstallMethod::Homebrew).map_err(|e| e.to_string()),
+        || {
+            upgrade::fetch_latest_version(InstallMethod::Homebrew, ReleaseChannel::Stable)
+                .map_err(|e| e.to_string())
+        },
         run_external,
     )
 }
@@ -11,6 +11,10 @@ pub struct ApiOutput {
     pub project_id: String,
     pub method: String,
     pub endpoint: String,
+    /// JSON pointer applied to the response body; `response` holds only the
+    /// value it resolved to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
     pub response: Value,
 }
 
@@ -20,6 +24,8 @@ pub struct ApiOutput {
 /// ```json
 /// {"projectId": "my-project", "method": "GET", "endpoint": "/wp/v2/posts", "body": null}
 /// ```
+///
+/// An optional `"extract"` JSON pointer (RFC 6901) narrows the response.
 pub fn run(input: &str) -> Result<(ApiOutput, i32)> {
     let parsed: ApiInput = serde_json::from_str(input).map_err(|e| {
         Error::validation_invalid_json(
@@ -67,11 +73,17 @@ pub fn run(input: &str) -> Result<(ApiOutput, i32)> {
         }
     };
 
+    let response = match &parsed.extract {
+        Some(pointer) => extract(&response, pointer)?,
+        None => response,
+    };
+
     Ok((
         ApiOutput {
             project_id: parsed.project_id,
             method: parsed.method.to_uppercase(),
             endpoint: parsed.endpoint,
+            pointer: parsed.extract,
             response,
         },
         0,
@@ -88,6 +100,45 @@ struct ApiInput {
     body: Option<Value>,
     #[serde(default, rename = "bodyFormat")]
     body_format: BodyFormat,
+    #[serde(default)]
+    extract: Option<String>,
+}
+
+/// Resolve an RFC 6901 JSON pointer against `response`.
+///
+/// When it doesn't resolve, the error lists the top-level keys (or array
+/// length) so the caller can correct the pointer.
+pub fn extract(response: &Value, pointer: &str) -> Result<Value> {
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(Error::validation_invalid_argument(
+            "extract",
+            format!(
+                "Invalid JSON pointer '{}': must be empty or start with '/'",
+                pointer
+            ),
+            None,
+            None,
+        )
+        .with_hint(format!("Did you mean '/{}'?", pointer)));
+    }
+
+    response.pointer(pointer).cloned().ok_or_else(|| {
+        let available = match response {
+            Value::Object(map) => format!(
+                "Top-level keys: {}",
+                map.keys().cloned().collect::<Vec<_>>().join(", ")
+            ),
+            Value::Array(items) => format!("Response is an array of {} item(s)", items.len()),
+            _ => "Response is not an object or array".to_string(),
+        };
+        Error::validation_invalid_argument(
+            "extract",
+            format!("JSON pointer '{}' did not resolve in the response", pointer),
+            None,
+            None,
+        )
+        .with_hint(available)
+    })
 }
 
 impl Default for BodyFormat {
@@ -112,3 +163,24 @@ impl<'de> serde::Deserialize<'de> for BodyFormat {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_extract() {
+        let response = json!({"data": {"items": [{"id": 7, "name": "a/b"}]}, "ok": true});
+
+        assert_eq!(extract(&response, "/data/items/0/id").unwrap(), json!(7));
+        assert_eq!(extract(&response, "").unwrap(), response);
+
+        let err = extract(&response, "/data/missing").unwrap_err();
+        assert!(err.message.contains("/data/missing"));
+        assert!(err.hints[0].message.contains("data, ok"));
+
+        let err = extract(&response, "data").unwrap_err();
+        assert!(err.message.contains("must be empty or start with '/'"));
+    }
+}
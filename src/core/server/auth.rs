@@ -6,7 +6,9 @@
 use super::http::ApiClient;
 use crate::error::Result;
 use crate::keychain;
+use crate::output;
 use crate::project;
+use base64::Engine;
 use serde::Serialize;
 use std::collections::HashMap;
 
@@ -20,15 +22,35 @@ pub struct LoginResult {
 pub struct AuthStatus {
     pub project_id: String,
     pub authenticated: bool,
+    /// Scheme from the auth header (`bearer`, `basic`, ...), or `header` for
+    /// a custom header. `None` when the project has no auth configured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Whether any keychain-backed variable is stored for this project.
+    pub stored: bool,
+    /// Earliest expiry (RFC 3339) among stored JWT tokens, when decodable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
     pub variables: Vec<AuthVariableStatus>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct AuthStatusAll {
+    pub projects: Vec<AuthStatus>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct LogoutResult {
     pub project_id: String,
     pub removed: usize,
 }
 
+#[derive(Debug, Serialize)]
+pub struct LogoutAllResult {
+    pub projects: Vec<LogoutResult>,
+    pub removed: usize,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SetResult {
     pub project_id: String,
@@ -56,6 +78,8 @@ pub struct AuthVariableStatus {
     pub name: String,
     pub source: String,
     pub available: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
 }
 
 /// Authenticates with a project's API using provided credentials.
@@ -85,6 +109,25 @@ pub fn logout(project_id: &str) -> Result<LogoutResult> {
     })
 }
 
+/// Clears stored authentication for every project with keychain-backed
+/// variables. Projects with nothing stored are omitted from the result.
+pub fn logout_all() -> Result<LogoutAllResult> {
+    let mut projects = Vec::new();
+    for project in project::list()? {
+        let variable_names = keychain_variable_names(&project);
+        let removed = keychain::remove_many(&project.id, &variable_names)?;
+        if removed > 0 {
+            projects.push(LogoutResult {
+                project_id: project.id.clone(),
+                removed,
+            });
+        }
+    }
+
+    let removed = projects.iter().map(|result| result.removed).sum();
+    Ok(LogoutAllResult { projects, removed })
+}
+
 /// Stores a project API variable in the keychain.
 pub fn set(project_id: &str, variable: &str, value: &str) -> Result<SetResult> {
     keychain::set(project_id, variable, value)?;
@@ -97,16 +140,13 @@ pub fn set(project_id: &str, variable: &str, value: &str) -> Result<SetResult> {
 
 /// Retrieves a project API variable from the keychain.
 pub fn get(project_id: &str, variable: &str, redacted: bool) -> Result<GetResult> {
-    let value =
-        keychain::get(project_id, variable)?.map(
-            |value| {
-                if redacted {
-                    redact(&value)
-                } else {
-                    value
-                }
-            },
-        );
+    let value = keychain::get(project_id, variable)?.map(|value| {
+        if redacted {
+            output::redact_secret(&value)
+        } else {
+            value
+        }
+    });
 
     Ok(GetResult {
         project_id: project_id.to_string(),
@@ -132,12 +172,77 @@ pub fn remove(project_id: &str, variable: &str) -> Result<RemoveResult> {
 pub fn status(project_id: &str) -> Result<AuthStatus> {
     let project = project::load(project_id)?;
     let client = ApiClient::new(project_id, &project.api)?;
+    Ok(build_status(&project, client.is_authenticated()))
+}
 
-    Ok(AuthStatus {
-        project_id: project_id.to_string(),
-        authenticated: client.is_authenticated(),
-        variables: variable_statuses(project_id, &project),
-    })
+/// Reports status for every project that has a stored keychain credential.
+pub fn status_all() -> Result<AuthStatusAll> {
+    let projects = project::list()?
+        .iter()
+        .filter(|project| {
+            keychain_variable_names(project)
+                .iter()
+                .any(|name| keychain::exists(&project.id, name))
+        })
+        .map(|project| {
+            let authenticated = ApiClient::new(&project.id, &project.api)
+                .map(|client| client.is_authenticated())
+                .unwrap_or(false);
+            build_status(project, authenticated)
+        })
+        .collect();
+
+    Ok(AuthStatusAll { projects })
+}
+
+fn build_status(project: &project::Project, authenticated: bool) -> AuthStatus {
+    let variables = variable_statuses(&project.id, project);
+    let stored = variables
+        .iter()
+        .any(|variable| variable.source == "keychain" && variable.available);
+    let expires_at = variables
+        .iter()
+        .filter_map(|variable| variable.expires_at.clone())
+        .min();
+
+    AuthStatus {
+        project_id: project.id.clone(),
+        authenticated,
+        method: project
+            .api
+            .auth
+            .as_ref()
+            .map(|auth| auth_method(&auth.header)),
+        stored,
+        expires_at,
+        variables,
+    }
+}
+
+/// Classify an auth header template like `Authorization: Bearer {{token}}`.
+fn auth_method(header: &str) -> String {
+    let value = header.split_once(':').map(|(_, value)| value).unwrap_or("");
+    match value.split_whitespace().next() {
+        Some(scheme) if !scheme.starts_with("{{") => scheme.to_ascii_lowercase(),
+        _ => "header".to_string(),
+    }
+}
+
+/// Read the `exp` claim from a JWT without verifying it. Returns `None` for
+/// anything that is not a decodable JWT with a numeric `exp`.
+fn jwt_expiry(token: &str) -> Option<String> {
+    let mut parts = token.trim().split('.');
+    let (_header, payload, _signature) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    let claims: serde_json::Value = serde_json::from_slice(&decoded).ok()?;
+    let exp = claims.get("exp")?.as_i64()?;
+    chrono::DateTime::from_timestamp(exp, 0).map(|at| at.to_rfc3339())
 }
 
 fn keychain_variable_names(project: &project::Project) -> Vec<String> {
@@ -163,10 +268,19 @@ fn variable_statuses(project_id: &str, project: &project::Project) -> Vec<AuthVa
 
     auth.variables
         .iter()
-        .map(|(name, source)| AuthVariableStatus {
-            name: name.to_string(),
-            source: source.source.clone(),
-            available: variable_available(project_id, name, source),
+        .map(|(name, source)| {
+            // Only keychain values are read back; the secret itself never
+            // leaves this function.
+            let expires_at = (source.source == "keychain")
+                .then(|| keychain::get(project_id, name).ok().flatten())
+                .flatten()
+                .and_then(|value| jwt_expiry(&value));
+            AuthVariableStatus {
+                name: name.to_string(),
+                source: source.source.clone(),
+                available: variable_available(project_id, name, source),
+                expires_at,
+            }
         })
         .collect()
 }
@@ -184,22 +298,30 @@ fn variable_available(project_id: &str, name: &str, source: &project::VariableSo
     }
 }
 
-fn redact(value: &str) -> String {
-    if value.is_empty() {
-        return String::new();
-    }
-    "********".to_string()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::project::VariableSource;
 
     #[test]
-    fn test_redact() {
-        assert_eq!(redact("secret"), "********");
-        assert_eq!(redact(""), "");
+    fn test_auth_method() {
+        assert_eq!(auth_method("Authorization: Bearer {{token}}"), "bearer");
+        assert_eq!(auth_method("Authorization: Basic {{credentials}}"), "basic");
+        assert_eq!(auth_method("X-Api-Key: {{key}}"), "header");
+    }
+
+    #[test]
+    fn test_jwt_expiry() {
+        let engine = base64::engine::general_purpose::URL_SAFE_NO_PAD;
+        let payload = engine.encode(r#"{"sub":"me","exp":1767225600}"#);
+        let token = format!("{}.{}.sig", engine.encode(r#"{"alg":"HS256"}"#), payload);
+
+        assert_eq!(
+            jwt_expiry(&token).as_deref(),
+            Some("2026-01-01T00:00:00+00:00")
+        );
+        assert_eq!(jwt_expiry("plain-api-token"), None);
+        assert_eq!(jwt_expiry("a.b.c"), None);
     }
 
     #[test]
@@ -265,7 +387,7 @@ mod tests {
         set("homeboy-auth-test", "token", "secret-value").expect("store value");
         let result = get("homeboy-auth-test", "token", true).expect("read value");
 
-        assert_eq!(result.value.as_deref(), Some("********"));
+        assert_eq!(result.value.as_deref(), Some(output::REDACTED));
         assert!(result.redacted);
         remove("homeboy-auth-test", "token").expect("cleanup value");
     }
@@ -12,8 +12,8 @@ use crate::error::{Error, Result};
 use chrono::Utc;
 
 use super::{
-    ensure_control_path_parent, ManagedSshSession, ManagedSshSessionOutput, Server, ServerAuthMode,
-    ServerSessionConfig,
+    ensure_control_path_parent, resolve_proxy_jump, ManagedSshSession, ManagedSshSessionOutput,
+    ProxyJump, Server, ServerAuthMode, ServerSessionConfig,
 };
 use std::process::{Command, Stdio};
 
@@ -35,6 +35,8 @@ pub struct SshClient {
     /// Environment variables to inject before remote commands.
     /// Values are passed through the shell, so `$PATH`-style expansion works.
     pub env: HashMap<String, String>,
+    /// Resolved `proxy_jump` of the server.
+    pub proxy_jump: Option<ProxyJump>,
 }
 
 pub struct CommandOutput {
@@ -76,6 +78,7 @@ impl SshClient {
             }
             _ => None,
         };
+        let proxy_jump = resolve_proxy_jump(server)?;
 
         Ok(Self {
             host: server.host.clone(),
@@ -85,9 +88,24 @@ impl SshClient {
             auth,
             is_local,
             env: server.env.clone(),
+            proxy_jump,
         })
     }
 
+    /// Options routing `ssh`/`scp` through the server's jump host, if any.
+    pub fn proxy_args(&self) -> Vec<String> {
+        self.proxy_jump
+            .as_ref()
+            .map(ProxyJump::ssh_args)
+            .unwrap_or_default()
+    }
+
+    /// Explain a failed connection (exit 255), naming the bastion or the
+    /// target as the unreachable hop.
+    pub fn describe_connection_failure(&self, stderr: &str) -> String {
+        super::describe_connection_failure(&self.host, self.proxy_jump.as_ref(), stderr)
+    }
+
     fn build_ssh_args(&self, command: Option<&str>, interactive: bool) -> Vec<String> {
         let mut args = Vec::new();
 
@@ -101,6 +119,8 @@ impl SshClient {
             args.push(self.port.to_string());
         }
 
+        args.extend(self.proxy_args());
+
         if let Some(session) = &self.auth {
             args.extend([
                 "-o".to_string(),
@@ -201,6 +221,8 @@ impl SshClient {
             args.push(self.port.to_string());
         }
 
+        args.extend(self.proxy_args());
+
         args.extend([
             "-M".to_string(),
             "-N".to_string(),
@@ -218,6 +240,7 @@ impl SshClient {
     }
 
     pub fn connect_managed_session(&self) -> Result<ManagedSshSessionOutput> {
+        let _span = crate::profile::span("ssh.connect");
         if self.is_local {
             return Ok(self.local_managed_session_output(true));
         }
@@ -308,10 +331,72 @@ impl SshClient {
     }
 
     pub fn execute(&self, command: &str) -> CommandOutput {
+        let _span = crate::profile::span("ssh.execute");
         let effective = self.prepend_env(command);
         self.execute_with_stdin(&effective, None)
     }
 
+    /// Run `command` with `input` written to its stdin, so a secret reaches
+    /// the remote process without appearing in argv or shell history. Not
+    /// retried: the first attempt consumes the input.
+    ///
+    /// Bounded like [`Self::execute`]: remote runs use the same BatchMode,
+    /// ConnectTimeout and ServerAlive options, and local runs get the same
+    /// process-group teardown.
+    pub fn execute_with_input(&self, command: &str, input: &[u8]) -> CommandOutput {
+        use std::io::Write;
+
+        let _span = crate::profile::span("ssh.execute");
+        let effective = self.prepend_env(command);
+        let (mut cmd, _permit) = if self.is_local {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", &effective]);
+            configure_process_group_cleanup(&mut cmd);
+            (cmd, None)
+        } else {
+            let mut cmd = Command::new("ssh");
+            cmd.args(self.build_ssh_args(Some(&effective), false));
+            (cmd, Some(super::acquire_ssh_permit()))
+        };
+        cmd.stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let output = cmd.spawn().and_then(|mut child| {
+            let cleanup_guard = self
+                .is_local
+                .then(|| ProcessGroupCleanupGuard::new(child.id()));
+            if let Some(mut stdin) = child.stdin.take() {
+                // A remote that exits before reading (no sudo, say) closes the
+                // pipe; its output still explains what happened. Dropping
+                // `stdin` sends EOF so nothing waits for more input.
+                let _ = stdin.write_all(input);
+            }
+            let output = child.wait_with_output();
+            if let Some(guard) = cleanup_guard {
+                guard.cleanup();
+            }
+            output
+        });
+
+        match output {
+            Ok(out) => CommandOutput {
+                stdout: String::from_utf8_lossy(&out.stdout).to_string(),
+                stderr: String::from_utf8_lossy(&out.stderr).to_string(),
+                success: out.status.success(),
+                exit_code: out.status.code().unwrap_or(-1),
+                child_resource: None,
+            },
+            Err(e) => CommandOutput {
+                stdout: String::new(),
+                stderr: format!("SSH error: {}", e),
+                success: false,
+                exit_code: -1,
+                child_resource: None,
+            },
+        }
+    }
+
     /// Build an env preamble that sets configured variables via `export`.
     /// Values are quoted but allow shell expansion (e.g. `$PATH`).
     fn prepend_env(&self, command: &str) -> String {
@@ -384,6 +469,7 @@ impl SshClient {
         }
 
         let args = self.build_ssh_args(Some(command), false);
+        let _permit = super::acquire_ssh_permit();
 
         let mut cmd = Command::new("ssh");
         cmd.args(&args);
@@ -425,6 +511,41 @@ impl SshClient {
         }
     }
 
+    /// Run `command` without `BatchMode`, so ssh may prompt on the terminal
+    /// for a password or passphrase, while still capturing stdout.
+    pub fn execute_prompting(&self, command: &str) -> CommandOutput {
+        let effective = self.prepend_env(command);
+        if self.is_local {
+            return execute_local_command(&effective);
+        }
+
+        let mut args = self.build_ssh_args(None, true);
+        args.push(effective);
+        let output = Command::new("ssh")
+            .args(&args)
+            .stdin(Stdio::inherit())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output();
+
+        match output {
+            Ok(out) => CommandOutput {
+                stdout: String::from_utf8_lossy(&out.stdout).to_string(),
+                stderr: String::from_utf8_lossy(&out.stderr).to_string(),
+                success: out.status.success(),
+                exit_code: out.status.code().unwrap_or(-1),
+                child_resource: None,
+            },
+            Err(e) => CommandOutput {
+                stdout: String::new(),
+                stderr: format!("SSH error: {}", e),
+                success: false,
+                exit_code: -1,
+                child_resource: None,
+            },
+        }
+    }
+
     pub fn execute_interactive(&self, command: Option<&str>) -> i32 {
         let effective = command.map(|c| self.prepend_env(c));
         let effective_ref = effective.as_deref();
@@ -1237,6 +1358,7 @@ mod tests {
             auth: None,
             is_local: true,
             env: HashMap::new(),
+            proxy_jump: None,
         };
 
         let output = client.upload_file(&source.to_string_lossy(), &target.to_string_lossy());
@@ -1258,6 +1380,7 @@ mod tests {
             port: 2222,
             identity_file: None,
             kind: Some("password-gated".to_string()),
+            tags: Vec::new(),
             auth: Some(super::super::ServerAuth {
                 mode: ServerAuthMode::KeyPlusPasswordControlmaster,
                 session: ServerSessionConfig {
@@ -1266,6 +1389,8 @@ mod tests {
                 },
             }),
             env: HashMap::new(),
+            proxy_jump: None,
+            service_manager: None,
         };
 
         let client = SshClient::from_server(&server, "bastion").expect("client");
@@ -1292,6 +1417,7 @@ mod tests {
             }),
             is_local: false,
             env: HashMap::new(),
+            proxy_jump: None,
         };
 
         let args = client.build_session_connect_args().expect("args");
@@ -1318,6 +1444,7 @@ mod tests {
             port: 22,
             identity_file: None,
             kind: Some("local".to_string()),
+            tags: Vec::new(),
             auth: Some(super::super::ServerAuth {
                 mode: ServerAuthMode::KeyPlusPasswordControlmaster,
                 session: ServerSessionConfig {
@@ -1326,6 +1453,8 @@ mod tests {
                 },
             }),
             env: HashMap::new(),
+            proxy_jump: None,
+            service_manager: None,
         };
 
         let client = SshClient::from_server(&server, "local").expect("client");
@@ -1410,6 +1539,7 @@ mod tests {
             auth: None,
             is_local: true,
             env: HashMap::new(),
+            proxy_jump: None,
         };
 
         assert_eq!(client.execute_interactive(Some("true")), 0);
@@ -1440,6 +1570,7 @@ mod tests {
             }),
             is_local: true,
             env: HashMap::new(),
+            proxy_jump: None,
         }
     }
 
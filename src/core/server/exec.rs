@@ -0,0 +1,423 @@
+//! Run one command on many servers (`server exec --all`).
+//!
+//! Every server runs in parallel and gets its own outcome; a server that
+//! cannot be reached is a failed item, never an error for the whole batch.
+//!
+//! With [`Sudo`] the command runs through `sudo`. A probe runs first, so a
+//! missing `sudo`, a needed password, or a rejected password is reported as a
+//! [`SudoFailure`] rather than as the command's own exit code. A password is
+//! written to `sudo -S` over the SSH session's stdin, never into argv.
+
+use std::thread;
+
+use serde::Serialize;
+
+use super::{Server, SshClient};
+use crate::engine::shell;
+use crate::error::{Error, Result};
+use crate::output::{BulkResult, BulkSummary, ItemOutcome};
+
+/// `ssh` reserves exit code 255 for its own failures (unreachable host,
+/// auth refused), so it never comes from the remote command.
+const SSH_CONNECTION_FAILED: i32 = 255;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerExecResult {
+    pub server_id: String,
+    pub exit_code: i32,
+    pub stdout: String,
+    pub stderr: String,
+    pub success: bool,
+    /// Set when `sudo` failed before the command ran.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sudo_failure: Option<SudoFailure>,
+}
+
+/// Run the command through `sudo` (`server exec --sudo`).
+#[derive(Debug, Clone, Default)]
+pub struct Sudo {
+    /// Target user for `sudo -u`; root when unset.
+    pub user: Option<String>,
+    /// Fed to `sudo -S` on stdin. Without one, `sudo` must not need a password.
+    pub password: Option<String>,
+}
+
+/// Why `sudo` failed, as opposed to the command it ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SudoFailure {
+    /// `sudo` is not installed on the server.
+    Unavailable,
+    /// `sudo` needs a password and none was supplied.
+    PasswordRequired,
+    /// `sudo` refused the password, or the user may not run as the target.
+    Rejected,
+}
+
+/// Prefix of the stderr line the sudo probe writes before exiting.
+const SUDO_MARKER: &str = "homeboy-sudo:";
+
+/// Run `command` on every configured server matching `filters` and carrying
+/// all of `tags`.
+///
+/// With no filters or tags every server is targeted. Results keep server
+/// list order.
+pub fn exec_all(
+    command: &str,
+    filters: &[String],
+    tags: &[String],
+) -> Result<BulkResult<ServerExecResult>> {
+    Ok(exec_on(&select_servers(filters, tags)?, command))
+}
+
+/// Configured servers matching `filters` and carrying all of `tags`, in
+/// server list order. Errors when none match.
+pub fn select_servers(filters: &[String], tags: &[String]) -> Result<Vec<Server>> {
+    let patterns = compile_filters(filters)?;
+    let servers: Vec<Server> = super::list_tagged(tags)?
+        .into_iter()
+        .filter(|server| patterns.is_empty() || patterns.iter().any(|p| matches(server, p)))
+        .collect();
+
+    if servers.is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "filter",
+            "No configured servers match",
+            None,
+            Some(vec![
+                "Run `homeboy server list` to see server IDs, kinds, and tags".to_string(),
+            ]),
+        ));
+    }
+
+    Ok(servers)
+}
+
+/// Run `command` on `servers` in parallel, one outcome per server.
+pub fn exec_on(servers: &[Server], command: &str) -> BulkResult<ServerExecResult> {
+    exec_on_with(servers, command, None)
+}
+
+/// Run `command` on `servers` in parallel, through `sudo` when given.
+pub fn exec_on_with(
+    servers: &[Server],
+    command: &str,
+    sudo: Option<&Sudo>,
+) -> BulkResult<ServerExecResult> {
+    let results: Vec<ItemOutcome<ServerExecResult>> = thread::scope(|scope| {
+        let handles: Vec<_> = servers
+            .iter()
+            .map(|server| scope.spawn(move || exec_one(server, command, sudo)))
+            .collect();
+
+        handles
+            .into_iter()
+            .zip(servers)
+            .map(|(handle, server)| {
+                handle.join().unwrap_or_else(|_| ItemOutcome {
+                    id: server.id.clone(),
+                    result: None,
+                    error: Some("exec worker panicked".to_string()),
+                })
+            })
+            .collect()
+    });
+
+    exec_result(results)
+}
+
+/// IDs of the servers whose `sudo` needed a password that was not supplied.
+pub fn sudo_password_required(result: &BulkResult<ServerExecResult>) -> Vec<String> {
+    result
+        .results
+        .iter()
+        .filter(|item| {
+            item.result.as_ref().and_then(|r| r.sudo_failure) == Some(SudoFailure::PasswordRequired)
+        })
+        .map(|item| item.id.clone())
+        .collect()
+}
+
+/// Re-run `command` with `sudo` on the servers of `result` that needed a
+/// password, replacing their outcomes. The command never ran on them, so
+/// servers that already ran it are not touched.
+pub fn rerun_with_sudo_password(
+    result: BulkResult<ServerExecResult>,
+    servers: &[Server],
+    command: &str,
+    sudo: &Sudo,
+) -> BulkResult<ServerExecResult> {
+    let pending = sudo_password_required(&result);
+    let retry: Vec<Server> = servers
+        .iter()
+        .filter(|server| pending.contains(&server.id))
+        .cloned()
+        .collect();
+    let mut retried = exec_on_with(&retry, command, Some(sudo)).results;
+
+    let results = result
+        .results
+        .into_iter()
+        .map(|item| match retried.iter().position(|r| r.id == item.id) {
+            Some(index) => retried.remove(index),
+            None => item,
+        })
+        .collect();
+    exec_result(results)
+}
+
+fn exec_result(results: Vec<ItemOutcome<ServerExecResult>>) -> BulkResult<ServerExecResult> {
+    let succeeded = results.iter().filter(|item| item.error.is_none()).count();
+    BulkResult {
+        action: "exec".to_string(),
+        summary: BulkSummary {
+            total: results.len(),
+            succeeded,
+            failed: results.len() - succeeded,
+        },
+        results,
+    }
+}
+
+fn exec_one(server: &Server, command: &str, sudo: Option<&Sudo>) -> ItemOutcome<ServerExecResult> {
+    let client = match SshClient::from_server(server, &server.id) {
+        Ok(client) => client,
+        Err(err) => {
+            return ItemOutcome {
+                id: server.id.clone(),
+                result: None,
+                error: Some(err.to_string()),
+            }
+        }
+    };
+
+    let mut output = match sudo {
+        Some(sudo) => {
+            let script = sudo_script(command, sudo);
+            match &sudo.password {
+                Some(password) => client.execute_with_input(&script, &sudo_input(password)),
+                None => client.execute(&script),
+            }
+        }
+        None => client.execute(command),
+    };
+    let sudo_failure = if sudo.is_some() && !output.success {
+        take_sudo_failure(&mut output.stderr)
+    } else {
+        None
+    };
+    let error = match (sudo_failure, output.exit_code) {
+        (Some(failure), _) => Some(describe_sudo_failure(failure, sudo)),
+        _ if output.success => None,
+        (None, SSH_CONNECTION_FAILED) => Some(client.describe_connection_failure(&output.stderr)),
+        (None, code) => Some(format!("Command exited with code {}", code)),
+    };
+
+    ItemOutcome {
+        id: server.id.clone(),
+        result: Some(ServerExecResult {
+            server_id: server.id.clone(),
+            exit_code: output.exit_code,
+            stdout: output.stdout,
+            stderr: output.stderr,
+            success: output.success,
+            sudo_failure,
+        }),
+        error,
+    }
+}
+
+/// Wrap `command` in a shell script that checks `sudo` before running it.
+///
+/// The probe (`sudo ... true`) authenticates first so a rejected password is
+/// reported apart from the command's own failures. With a password the real
+/// run is also `sudo -S`, reading the password again from stdin instead of
+/// relying on sudo's credential cache (`timestamp_timeout=0` disables it);
+/// see [`sudo_input`]. The command's stdin is `/dev/null` so it can never
+/// read the password.
+fn sudo_script(command: &str, sudo: &Sudo) -> String {
+    let user = sudo
+        .user
+        .as_deref()
+        .map(|user| format!(" -u {}", shell::quote_arg(user)))
+        .unwrap_or_default();
+    let fail = |failure: &str| format!("{{ echo '{} {}' >&2; exit 1; }}", SUDO_MARKER, failure);
+    let (probe, run) = if sudo.password.is_some() {
+        (
+            format!(
+                "sudo -S -p ''{} true 2>/dev/null || {}",
+                user,
+                fail("rejected")
+            ),
+            format!(
+                "sudo -S -p ''{} -- sh -c {}",
+                user,
+                shell::quote_arg(&format!("exec </dev/null; {}", command))
+            ),
+        )
+    } else {
+        (
+            format!(
+                "sudo -n{} true 2>/dev/null || {}",
+                user,
+                fail("password_required")
+            ),
+            format!(
+                "sudo -n{} -- sh -c {} </dev/null",
+                user,
+                shell::quote_arg(command)
+            ),
+        )
+    };
+    format!(
+        "command -v sudo >/dev/null 2>&1 || {}; {}; {}",
+        fail("unavailable"),
+        probe,
+        run
+    )
+}
+
+/// Stdin for a password-authenticated [`sudo_script`]: one password line for
+/// the probe and one for the real run. Each `sudo -S` reads at most a line,
+/// and only when it has to authenticate; unread lines never reach the command.
+fn sudo_input(password: &str) -> Vec<u8> {
+    format!("{}\n{}\n", password, password).into_bytes()
+}
+
+/// Remove the probe's marker line from `stderr` and decode it.
+fn take_sudo_failure(stderr: &mut String) -> Option<SudoFailure> {
+    let line = stderr
+        .lines()
+        .find(|line| line.starts_with(SUDO_MARKER))?
+        .to_string();
+    let failure = match line[SUDO_MARKER.len()..].trim() {
+        "unavailable" => SudoFailure::Unavailable,
+        "password_required" => SudoFailure::PasswordRequired,
+        "rejected" => SudoFailure::Rejected,
+        _ => return None,
+    };
+    *stderr = stderr
+        .lines()
+        .filter(|l| *l != line)
+        .map(|l| format!("{}\n", l))
+        .collect();
+    Some(failure)
+}
+
+fn describe_sudo_failure(failure: SudoFailure, sudo: Option<&Sudo>) -> String {
+    let target = sudo.and_then(|sudo| sudo.user.as_deref()).unwrap_or("root");
+    match failure {
+        SudoFailure::Unavailable => "sudo is not installed on the server".to_string(),
+        SudoFailure::PasswordRequired => {
+            "sudo requires a password; run from a terminal to be prompted".to_string()
+        }
+        SudoFailure::Rejected => format!(
+            "sudo rejected the password, or does not allow running as '{}'",
+            target
+        ),
+    }
+}
+
+fn compile_filters(filters: &[String]) -> Result<Vec<glob::Pattern>> {
+    filters
+        .iter()
+        .map(|filter| {
+            glob::Pattern::new(filter).map_err(|e| {
+                Error::validation_invalid_argument(
+                    "filter",
+                    format!("Invalid filter pattern '{}': {}", filter, e),
+                    None,
+                    None,
+                )
+            })
+        })
+        .collect()
+}
+
+/// A filter matches a server's ID, any alias, or its `kind`.
+fn matches(server: &Server, pattern: &glob::Pattern) -> bool {
+    std::iter::once(&server.id)
+        .chain(&server.aliases)
+        .chain(&server.kind)
+        .any(|value| pattern.matches(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(id: &str, aliases: &[&str], kind: Option<&str>) -> Server {
+        Server {
+            id: id.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            host: format!("{}.example.com", id),
+            user: "deploy".to_string(),
+            port: 22,
+            identity_file: None,
+            kind: kind.map(str::to_string),
+            tags: Vec::new(),
+            auth: None,
+            env: Default::default(),
+            proxy_jump: None,
+            service_manager: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_matches_id_alias_and_kind() {
+        let patterns = compile_filters(&["prod-*".to_string(), "staging".to_string()]).unwrap();
+        let matched = |s: &Server| patterns.iter().any(|p| matches(s, p));
+
+        assert!(matched(&server("prod-web", &[], None)));
+        assert!(matched(&server("web-1", &["prod-legacy"], None)));
+        assert!(matched(&server("web-2", &[], Some("staging"))));
+        assert!(!matched(&server("dev-box", &["stage"], Some("dev"))));
+    }
+
+    #[test]
+    fn test_sudo_script_probes_before_running() {
+        let script = sudo_script(
+            "systemctl restart nginx",
+            &Sudo {
+                user: Some("www-data".to_string()),
+                password: None,
+            },
+        );
+        assert_eq!(
+            script,
+            "command -v sudo >/dev/null 2>&1 || { echo 'homeboy-sudo: unavailable' >&2; exit 1; }; \
+             sudo -n -u www-data true 2>/dev/null || { echo 'homeboy-sudo: password_required' >&2; exit 1; }; \
+             sudo -n -u www-data -- sh -c 'systemctl restart nginx' </dev/null"
+        );
+
+        let script = sudo_script(
+            "id",
+            &Sudo {
+                user: None,
+                password: Some("secret".to_string()),
+            },
+        );
+        assert!(script.contains("sudo -S -p '' true"));
+        assert!(script.ends_with("sudo -S -p '' -- sh -c 'exec </dev/null; id'"));
+        assert!(!script.contains("sudo -n"));
+        assert!(!script.contains("secret"));
+        assert_eq!(sudo_input("secret"), b"secret\nsecret\n");
+    }
+
+    #[test]
+    fn test_take_sudo_failure() {
+        let mut stderr = "warning\nhomeboy-sudo: rejected\n".to_string();
+        assert_eq!(take_sudo_failure(&mut stderr), Some(SudoFailure::Rejected));
+        assert_eq!(stderr, "warning\n");
+
+        let mut stderr = "permission denied\n".to_string();
+        assert_eq!(take_sudo_failure(&mut stderr), None);
+        assert_eq!(stderr, "permission denied\n");
+    }
+
+    #[test]
+    fn test_invalid_filter_is_rejected() {
+        let err = compile_filters(&["[".to_string()]).unwrap_err();
+        assert!(err.message.contains("Invalid filter pattern"));
+    }
+}
@@ -21,19 +21,26 @@ fn not_found_error(msg: impl Into<String>) -> Error {
 }
 
 pub(crate) fn http_error(e: reqwest::Error) -> Error {
-    Error::new(
+    let err = Error::new(
         ErrorCode::RemoteCommandFailed,
         format!("HTTP request failed: {}", e),
         json!({ "error": e.to_string() }),
-    )
+    );
+    if e.is_connect() || e.is_timeout() {
+        err.with_retryable(true)
+    } else {
+        err
+    }
 }
 
+/// 5xx and 429 responses may succeed on retry; other statuses will not.
 fn api_error(status: u16, body: &str) -> Error {
     Error::new(
         ErrorCode::RemoteCommandFailed,
         format!("API error: HTTP {}", status),
         json!({ "status": status, "body": body }),
     )
+    .with_retryable(status >= 500 || status == 429)
 }
 
 fn parse_error(msg: impl Into<String>) -> Error {
@@ -469,6 +476,14 @@ mod tests {
 
         assert_eq!(err.code, ErrorCode::RemoteCommandFailed);
         assert!(err.message.contains("HTTP request failed"));
+        assert_eq!(err.retryable, None);
+    }
+
+    #[test]
+    fn test_api_error() {
+        assert_eq!(api_error(503, "").retryable, Some(true));
+        assert_eq!(api_error(429, "").retryable, Some(true));
+        assert_eq!(api_error(404, "").retryable, Some(false));
     }
 
     #[test]
@@ -3,22 +3,30 @@ pub mod auth;
 pub mod auth_profiles;
 mod client;
 mod connection;
+mod exec;
 pub mod health;
 pub(crate) mod http;
 mod keys;
+mod pool;
+mod proxy;
+mod service;
 mod session;
 pub mod transfer;
 
 pub use client::*;
 pub use connection::*;
+pub use exec::*;
 pub use health::*;
 pub use keys::*;
+pub use pool::*;
+pub use proxy::*;
+pub use service::*;
 pub use session::*;
 pub use transfer::*;
 
 use std::collections::HashMap;
 
-use crate::config::{self, ConfigEntity};
+use crate::config::{self, ConfigEntity, Tagged};
 use crate::error::{Error, Result};
 use crate::output::{CreateOutput, MergeOutput, RemoveResult};
 use crate::paths;
@@ -40,12 +48,22 @@ pub struct Server {
     pub identity_file: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kind: Option<String>,
+    /// Grouping tags (`prod`, `staging`) used by `--tag` filters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub auth: Option<ServerAuth>,
     /// Environment variables to set before executing commands on this server.
     /// Values support `$PATH`-style expansion — the shell handles it.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub env: HashMap<String, String>,
+    /// Jump host: a registered server ID (its credentials are used) or a
+    /// literal `user@host[:port]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_jump: Option<String>,
+    /// Init system used by `server service`; detected per service when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_manager: Option<ServiceManager>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -92,6 +110,9 @@ impl ConfigEntity for Server {
     fn not_found_error(id: String, suggestions: Vec<String>) -> Error {
         Error::server_not_found(id, suggestions)
     }
+    fn validate(&self) -> Result<()> {
+        resolve_proxy_jump(self).map(|_| ())
+    }
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
@@ -103,13 +124,76 @@ impl ConfigEntity for Server {
             .map(|p| p.id.clone())
             .collect())
     }
+    fn on_rename(old_id: &str, new_id: &str) -> Result<()> {
+        rewrite_server_references(old_id, new_id, false)?;
+        Ok(())
+    }
 }
 
 // ============================================================================
 // Core CRUD - Generated by entity_crud! macro
 // ============================================================================
 
-entity_crud!(Server; merge);
+impl Tagged for Server {
+    fn tags(&self) -> &[String] {
+        &self.tags
+    }
+    fn tags_mut(&mut self) -> &mut Vec<String> {
+        &mut self.tags
+    }
+}
+
+entity_crud!(Server; merge, tags);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerRenameResult {
+    pub server: Server,
+    pub old_id: String,
+    pub updated_projects: Vec<project::ProjectReferenceUpdate>,
+    pub dry_run: bool,
+}
+
+/// Rename a server, slugifying `new_id`, and point every project that
+/// references it at the new ID. With `dry_run`, only validates and reports.
+pub fn rename_with_references(id: &str, new_id: &str, dry_run: bool) -> Result<ServerRenameResult> {
+    let new_id = crate::engine::identifier::slugify_id(new_id, "server_id")?;
+    let mut server = load(id)?;
+
+    let updated_projects = if dry_run {
+        config::check_rename::<Server>(id, &new_id)?;
+        server.id = new_id.clone();
+        rewrite_server_references(id, &new_id, true)?
+    } else {
+        config::rename::<Server>(id, &new_id)?;
+        server = load(&new_id)?;
+        rewrite_server_references(id, &new_id, false)?
+    };
+
+    Ok(ServerRenameResult {
+        server,
+        old_id: id.to_string(),
+        updated_projects,
+        dry_run,
+    })
+}
+
+fn rewrite_server_references(
+    old_id: &str,
+    new_id: &str,
+    dry_run: bool,
+) -> Result<Vec<project::ProjectReferenceUpdate>> {
+    if old_id == new_id {
+        return Ok(Vec::new());
+    }
+    project::rewrite_project_references(dry_run, |proj| {
+        if proj.server_id.as_deref() == Some(old_id) {
+            proj.server_id = Some(new_id.to_string());
+            vec!["server_id".to_string()]
+        } else {
+            Vec::new()
+        }
+    })
+}
 
 pub fn find_by_host(host: &str) -> Option<Server> {
     list().ok()?.into_iter().find(|s| s.host == host)
@@ -125,3 +209,114 @@ pub fn set_identity_file(id: &str, identity_file: Option<String>) -> Result<Serv
     save(&server)?;
     Ok(server)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::with_isolated_home;
+
+    fn save_fixtures() {
+        save(&Server {
+            id: "old-box".to_string(),
+            aliases: Vec::new(),
+            host: "example.test".to_string(),
+            user: "deploy".to_string(),
+            port: 22,
+            identity_file: None,
+            kind: None,
+            tags: Vec::new(),
+            auth: None,
+            env: HashMap::new(),
+            proxy_jump: None,
+            service_manager: None,
+        })
+        .expect("save server");
+        for (id, server_id) in [("site", Some("old-box")), ("other", None)] {
+            project::save(&project::Project {
+                id: id.to_string(),
+                server_id: server_id.map(str::to_string),
+                ..Default::default()
+            })
+            .expect("save project");
+        }
+    }
+
+    #[test]
+    fn test_rename_with_references() {
+        with_isolated_home(|_| {
+            save_fixtures();
+
+            let result = rename_with_references("old-box", "New Box", false).expect("rename");
+
+            assert_eq!(result.server.id, "new-box");
+            assert!(exists("new-box"));
+            assert!(!exists("old-box"));
+            assert_eq!(
+                result.updated_projects,
+                vec![project::ProjectReferenceUpdate {
+                    project_id: "site".to_string(),
+                    fields: vec!["server_id".to_string()],
+                }]
+            );
+            let site = project::load("site").expect("load site");
+            assert_eq!(site.server_id.as_deref(), Some("new-box"));
+        });
+    }
+
+    #[test]
+    fn rename_dry_run_writes_nothing() {
+        with_isolated_home(|_| {
+            save_fixtures();
+
+            let result = rename_with_references("old-box", "new-box", true).expect("dry run");
+
+            assert!(result.dry_run);
+            assert_eq!(result.updated_projects.len(), 1);
+            assert!(exists("old-box"));
+            assert!(!exists("new-box"));
+            let site = project::load("site").expect("load site");
+            assert_eq!(site.server_id.as_deref(), Some("old-box"));
+        });
+    }
+
+    #[test]
+    fn test_tags_add_remove_and_filter() {
+        with_isolated_home(|_| {
+            save_fixtures();
+
+            let server =
+                add_tags("old-box", &["Prod".to_string(), "eu".to_string()]).expect("add tags");
+            assert_eq!(server.tags, vec!["prod", "eu"]);
+            assert_eq!(load("old-box").unwrap().tags, vec!["prod", "eu"]);
+
+            assert_eq!(list_tagged(&["PROD".to_string()]).unwrap().len(), 1);
+            assert!(list_tagged(&["prod".to_string(), "us".to_string()])
+                .unwrap()
+                .is_empty());
+
+            let server = remove_tags("old-box", &["eu".to_string()]).expect("remove tag");
+            assert_eq!(server.tags, vec!["prod"]);
+
+            let err = add_tags("old-box", &["two words".to_string()]).unwrap_err();
+            assert!(err.message.contains("Invalid tag"));
+        });
+    }
+
+    #[test]
+    fn concurrent_tag_adds_keep_every_tag() {
+        with_isolated_home(|_| {
+            save_fixtures();
+
+            let handles: Vec<_> = (0..8)
+                .map(|i| std::thread::spawn(move || add_tags("old-box", &[format!("t{}", i)])))
+                .collect();
+            for handle in handles {
+                handle.join().expect("join").expect("add tag");
+            }
+
+            let mut tags = load("old-box").unwrap().tags;
+            tags.sort();
+            assert_eq!(tags, (0..8).map(|i| format!("t{}", i)).collect::<Vec<_>>());
+        });
+    }
+}
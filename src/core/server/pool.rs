@@ -0,0 +1,187 @@
+//! Process-wide cap on concurrent SSH processes.
+//!
+//! Every remote command, scp, and rsync-over-ssh acquires an [`SshPermit`]
+//! before spawning, so a fan-out across many servers never forks more than
+//! [`jobs`] ssh processes at once, whichever command drives it. A
+//! remote-to-remote pipe takes one slot per end; with `--jobs 1` it runs
+//! alone rather than never. The cap comes from `--jobs`, then `HOMEBOY_JOBS`,
+//! then the CPU count. Time spent waiting for a permit is recorded under the
+//! `ssh.wait` profile phase.
+
+use std::sync::{Condvar, Mutex, OnceLock};
+
+use serde::Serialize;
+
+/// Pool usage for `--profile-phases`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SshPoolStats {
+    pub jobs: usize,
+    pub in_flight: usize,
+    pub peak_in_flight: usize,
+}
+
+struct Pool {
+    state: Mutex<SshPoolStats>,
+    released: Condvar,
+}
+
+impl Pool {
+    fn new(jobs: usize) -> Self {
+        Pool {
+            state: Mutex::new(SshPoolStats {
+                jobs: jobs.max(1),
+                in_flight: 0,
+                peak_in_flight: 0,
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, SshPoolStats> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn stats(&self) -> SshPoolStats {
+        *self.lock()
+    }
+
+    fn set_jobs(&self, jobs: usize) {
+        self.lock().jobs = jobs.max(1);
+        self.released.notify_all();
+    }
+
+    /// Wait until `slots` are free at once and take them together, so two
+    /// callers each needing several slots can't deadlock holding half. A
+    /// request larger than the cap waits for an empty pool instead of
+    /// forever.
+    fn acquire(&self, slots: usize) -> SshPermit<'_> {
+        let _span = crate::profile::span("ssh.wait");
+        let mut state = self.lock();
+        while state.in_flight > 0 && state.in_flight + slots > state.jobs {
+            state = self
+                .released
+                .wait(state)
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+        state.in_flight += slots;
+        state.peak_in_flight = state.peak_in_flight.max(state.in_flight);
+        SshPermit { pool: self, slots }
+    }
+}
+
+fn pool() -> &'static Pool {
+    static POOL: OnceLock<Pool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        Pool::new(resolve_jobs(
+            None,
+            std::env::var("HOMEBOY_JOBS").ok().as_deref(),
+        ))
+    })
+}
+
+/// Pick the cap: `--jobs`, then `HOMEBOY_JOBS`, then the CPU count. Zero and
+/// unparseable values fall through to the next source.
+pub fn resolve_jobs(flag: Option<usize>, env: Option<&str>) -> usize {
+    flag.filter(|jobs| *jobs > 0)
+        .or_else(|| {
+            env.and_then(|value| value.trim().parse().ok())
+                .filter(|jobs| *jobs > 0)
+        })
+        .unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(usize::from)
+                .unwrap_or(1)
+        })
+}
+
+/// Set the cap for the rest of the process (`--jobs`).
+pub fn set_jobs(jobs: usize) {
+    pool().set_jobs(jobs);
+}
+
+pub fn jobs() -> usize {
+    pool().stats().jobs
+}
+
+pub fn ssh_pool_stats() -> SshPoolStats {
+    pool().stats()
+}
+
+/// Held while ssh processes run; frees its slots when dropped.
+pub struct SshPermit<'a> {
+    pool: &'a Pool,
+    slots: usize,
+}
+
+impl Drop for SshPermit<'_> {
+    fn drop(&mut self) {
+        self.pool.lock().in_flight -= self.slots;
+        self.pool.released.notify_all();
+    }
+}
+
+/// Wait for a free slot.
+pub fn acquire_ssh_permit() -> SshPermit<'static> {
+    pool().acquire(1)
+}
+
+/// Wait for `slots` free slots, taken together, for work that runs several
+/// ssh processes at once (a remote-to-remote pipe).
+pub fn acquire_ssh_permits(slots: usize) -> SshPermit<'static> {
+    pool().acquire(slots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_jobs() {
+        assert_eq!(resolve_jobs(Some(4), Some("8")), 4);
+        assert_eq!(resolve_jobs(None, Some("8")), 8);
+        assert_eq!(resolve_jobs(Some(0), Some(" 3 ")), 3);
+        assert!(resolve_jobs(None, Some("many")) >= 1);
+        assert!(resolve_jobs(None, None) >= 1);
+    }
+
+    #[test]
+    fn test_acquire_ssh_permit() {
+        let pool: &'static Pool = Box::leak(Box::new(Pool::new(2)));
+        let first = pool.acquire(1);
+        let _second = pool.acquire(1);
+        assert_eq!(pool.stats().in_flight, 2);
+
+        let waiter = std::thread::spawn(move || {
+            let _third = pool.acquire(1);
+            pool.stats().in_flight
+        });
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!waiter.is_finished(), "third permit should wait for a slot");
+
+        drop(first);
+        assert_eq!(waiter.join().unwrap(), 2);
+        assert!(pool.stats().peak_in_flight <= 2);
+    }
+
+    #[test]
+    fn test_acquire_several_slots_at_once() {
+        let pool: &'static Pool = Box::leak(Box::new(Pool::new(2)));
+        let single = pool.acquire(1);
+
+        let waiter = std::thread::spawn(move || pool.acquire(2).slots);
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(!waiter.is_finished(), "two slots should wait for both");
+        assert_eq!(pool.stats().in_flight, 1, "no half-taken permit");
+
+        drop(single);
+        assert_eq!(waiter.join().unwrap(), 2);
+        assert_eq!(pool.stats().in_flight, 0);
+
+        pool.set_jobs(1);
+        let oversized = pool.acquire(2);
+        assert_eq!(pool.stats().in_flight, 2, "oversized request runs alone");
+        drop(oversized);
+    }
+}
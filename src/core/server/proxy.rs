@@ -0,0 +1,340 @@
+//! SSH jump hosts (`proxy_jump`).
+//!
+//! A server's `proxy_jump` is either the ID of a registered bastion server or
+//! a literal `user@host[:port]`. Literal addresses go to `ssh` as `ProxyJump`.
+//! Registered bastions are reached through a `ProxyCommand` so their own
+//! identity file, port, and jump host apply.
+
+use super::Server;
+use crate::engine::shell;
+use crate::error::{Error, Result};
+
+/// Bastions can themselves sit behind a bastion; deeper chains are almost
+/// certainly a misconfiguration.
+const MAX_JUMP_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProxyJump {
+    /// `user@host[:port]`, passed through as `ProxyJump`.
+    Address(String),
+    /// A registered server used as the bastion.
+    Server(Box<Bastion>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bastion {
+    pub id: String,
+    pub user: String,
+    pub host: String,
+    pub port: u16,
+    pub identity_file: Option<String>,
+    pub proxy_jump: Option<ProxyJump>,
+}
+
+/// Resolve `server.proxy_jump`, following registered bastions.
+///
+/// Fails when the value is neither a registered server nor `user@host[:port]`,
+/// or when the chain loops back on itself.
+pub fn resolve_proxy_jump(server: &Server) -> Result<Option<ProxyJump>> {
+    let Some(spec) = server.proxy_jump.as_deref().filter(|spec| !spec.is_empty()) else {
+        return Ok(None);
+    };
+    let mut chain = vec![server.id.clone()];
+    resolve(spec, &mut chain, super::load).map(Some)
+}
+
+fn resolve(
+    spec: &str,
+    chain: &mut Vec<String>,
+    load: impl Fn(&str) -> Result<Server> + Copy,
+) -> Result<ProxyJump> {
+    if chain.iter().any(|id| id == spec) {
+        chain.push(spec.to_string());
+        return Err(invalid(
+            spec,
+            format!("proxy_jump loops back on itself: {}", chain.join(" -> ")),
+        ));
+    }
+    if chain.len() > MAX_JUMP_DEPTH {
+        return Err(invalid(
+            spec,
+            format!("proxy_jump chain is deeper than {} hops", MAX_JUMP_DEPTH),
+        ));
+    }
+
+    match load(spec) {
+        Ok(bastion) => {
+            chain.push(spec.to_string());
+            let proxy_jump = match bastion.proxy_jump.as_deref().filter(|s| !s.is_empty()) {
+                Some(next) => Some(resolve(next, chain, load)?),
+                None => None,
+            };
+            let identity_file = bastion
+                .identity_file
+                .filter(|path| !path.is_empty())
+                .map(|path| shellexpand::tilde(&path).to_string());
+            Ok(ProxyJump::Server(Box::new(Bastion {
+                id: spec.to_string(),
+                user: bastion.user,
+                host: bastion.host,
+                port: bastion.port,
+                identity_file,
+                proxy_jump,
+            })))
+        }
+        Err(_) if is_address(spec) => Ok(ProxyJump::Address(spec.to_string())),
+        Err(_) => Err(invalid(
+            spec,
+            format!(
+                "proxy_jump '{}' is neither a registered server nor user@host[:port]",
+                spec
+            ),
+        )
+        .with_hint("Run `homeboy server list` to see server IDs")),
+    }
+}
+
+fn is_address(spec: &str) -> bool {
+    let Some((user, host)) = spec.split_once('@') else {
+        return false;
+    };
+    let host = match host.rsplit_once(':') {
+        Some((host, port)) => {
+            if port.parse::<u16>().is_err() {
+                return false;
+            }
+            host
+        }
+        None => host,
+    };
+    !user.is_empty() && !host.is_empty() && !spec.contains(char::is_whitespace)
+}
+
+fn invalid(spec: &str, message: String) -> Error {
+    Error::validation_invalid_argument("proxy_jump", message, Some(spec.to_string()), None)
+}
+
+impl ProxyJump {
+    /// `ssh`/`scp` options that route the connection through this jump host.
+    pub fn ssh_args(&self) -> Vec<String> {
+        match self {
+            ProxyJump::Address(address) => {
+                vec!["-o".to_string(), format!("ProxyJump={}", address)]
+            }
+            ProxyJump::Server(bastion) => {
+                vec![
+                    "-o".to_string(),
+                    format!("ProxyCommand={}", bastion.proxy_command()),
+                ]
+            }
+        }
+    }
+
+    /// Server ID or address, for messages.
+    pub fn label(&self) -> &str {
+        match self {
+            ProxyJump::Address(address) => address,
+            ProxyJump::Server(bastion) => &bastion.id,
+        }
+    }
+
+    fn host(&self) -> &str {
+        match self {
+            ProxyJump::Address(address) => {
+                let host = address.split_once('@').map_or(address.as_str(), |(_, h)| h);
+                host.rsplit_once(':').map_or(host, |(host, _)| host)
+            }
+            ProxyJump::Server(bastion) => &bastion.host,
+        }
+    }
+}
+
+impl Bastion {
+    /// `ssh -W %h:%p` to the bastion with its own credentials. Nested jump
+    /// options have `%` doubled so only the innermost `ssh` expands them.
+    fn proxy_command(&self) -> String {
+        let mut args = vec!["ssh".to_string()];
+        if let Some(identity_file) = &self.identity_file {
+            args.extend(["-i".to_string(), shell::quote_arg(identity_file)]);
+        }
+        if self.port != 22 {
+            args.extend(["-p".to_string(), self.port.to_string()]);
+        }
+        if let Some(next) = &self.proxy_jump {
+            for arg in next.ssh_args() {
+                args.push(shell::quote_arg(&arg.replace('%', "%%")));
+            }
+        }
+        args.extend([
+            "-o".to_string(),
+            "BatchMode=yes".to_string(),
+            "-o".to_string(),
+            "ConnectTimeout=10".to_string(),
+            "-W".to_string(),
+            "%h:%p".to_string(),
+            shell::quote_arg(&format!("{}@{}", self.user, self.host)),
+        ]);
+        args.join(" ")
+    }
+}
+
+/// Explain an `ssh` connection failure (exit 255) to `target_host`,
+/// separating an unreachable bastion from a target the bastion cannot reach.
+pub fn describe_connection_failure(
+    target_host: &str,
+    proxy_jump: Option<&ProxyJump>,
+    stderr: &str,
+) -> String {
+    let Some(proxy_jump) = proxy_jump else {
+        return format!("Could not connect to {}", target_host);
+    };
+
+    let target_failed = ["stdio forwarding failed", "channel 0: open failed"]
+        .iter()
+        .any(|marker| stderr.contains(marker));
+    if target_failed {
+        return format!(
+            "Target {} unreachable through bastion {}",
+            target_host,
+            proxy_jump.label()
+        );
+    }
+
+    let bastion_host = proxy_jump.host();
+    let bastion_failed = [
+        format!("connect to host {} ", bastion_host),
+        format!("resolve hostname {}", bastion_host),
+        "Connection closed by UNKNOWN".to_string(),
+    ]
+    .iter()
+    .any(|marker| stderr.contains(marker.as_str()));
+    if bastion_failed {
+        return format!(
+            "Bastion {} ({}) unreachable",
+            proxy_jump.label(),
+            bastion_host
+        );
+    }
+
+    format!(
+        "Could not connect to {} through bastion {}",
+        target_host,
+        proxy_jump.label()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn server(id: &str, host: &str, proxy_jump: Option<&str>) -> Server {
+        Server {
+            id: id.to_string(),
+            aliases: Vec::new(),
+            host: host.to_string(),
+            user: "deploy".to_string(),
+            port: 22,
+            identity_file: None,
+            kind: None,
+            tags: Vec::new(),
+            auth: None,
+            env: HashMap::new(),
+            proxy_jump: proxy_jump.map(str::to_string),
+            service_manager: None,
+        }
+    }
+
+    fn registry(servers: &[Server]) -> impl Fn(&str) -> Result<Server> + Copy + '_ {
+        move |id| {
+            servers
+                .iter()
+                .find(|server| server.id == id)
+                .cloned()
+                .ok_or_else(|| Error::server_not_found(id.to_string(), Vec::new()))
+        }
+    }
+
+    #[test]
+    fn resolves_literal_address() {
+        let proxy = resolve("ops@bastion.example.com:2222", &mut vec![], registry(&[])).unwrap();
+        assert_eq!(
+            proxy.ssh_args(),
+            vec!["-o", "ProxyJump=ops@bastion.example.com:2222"]
+        );
+        assert_eq!(proxy.host(), "bastion.example.com");
+    }
+
+    #[test]
+    fn rejects_unknown_server_id() {
+        let err = resolve("bastion", &mut vec![], registry(&[])).unwrap_err();
+        assert!(err.message.contains("neither a registered server"));
+    }
+
+    #[test]
+    fn chains_registered_bastions_with_their_credentials() {
+        let mut edge = server("edge", "edge.example.com", Some("ops@gate.example.com"));
+        edge.identity_file = Some("/keys/edge key".to_string());
+        edge.port = 2200;
+        let servers = [edge];
+
+        let proxy = resolve("edge", &mut vec!["app".to_string()], registry(&servers)).unwrap();
+        let args = proxy.ssh_args();
+        assert_eq!(args[0], "-o");
+        assert_eq!(
+            args[1],
+            "ProxyCommand=ssh -i '/keys/edge key' -p 2200 -o ProxyJump=ops@gate.example.com \
+             -o BatchMode=yes -o ConnectTimeout=10 -W %h:%p deploy@edge.example.com"
+        );
+    }
+
+    #[test]
+    fn nested_bastion_escapes_percent_tokens() {
+        let servers = [
+            server("outer", "outer.example.com", Some("inner")),
+            server("inner", "inner.example.com", None),
+        ];
+
+        let proxy = resolve("outer", &mut vec![], registry(&servers)).unwrap();
+        let command = &proxy.ssh_args()[1];
+        assert!(command.contains("-W %%h:%%p deploy@inner.example.com"));
+        assert!(command.ends_with("-W %h:%p deploy@outer.example.com"));
+    }
+
+    #[test]
+    fn rejects_jump_loops() {
+        let servers = [
+            server("a", "a.example.com", Some("b")),
+            server("b", "b.example.com", Some("a")),
+        ];
+
+        let err = resolve("b", &mut vec!["a".to_string()], registry(&servers)).unwrap_err();
+        assert!(err.message.contains("a -> b -> a"));
+    }
+
+    #[test]
+    fn describes_bastion_and_target_failures() {
+        let proxy = ProxyJump::Address("ops@bastion.example.com".to_string());
+
+        assert_eq!(
+            describe_connection_failure(
+                "app.internal",
+                Some(&proxy),
+                "ssh: connect to host bastion.example.com port 22: Connection refused\r\n"
+            ),
+            "Bastion ops@bastion.example.com (bastion.example.com) unreachable"
+        );
+        assert_eq!(
+            describe_connection_failure(
+                "app.internal",
+                Some(&proxy),
+                "channel 0: open failed: connect failed: No route to host\r\nstdio forwarding failed\r\n"
+            ),
+            "Target app.internal unreachable through bastion ops@bastion.example.com"
+        );
+        assert_eq!(
+            describe_connection_failure("app.internal", None, ""),
+            "Could not connect to app.internal"
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! Remote service control (`server service`).
+//!
+//! Maps start/stop/restart/status onto the server's init system (systemd,
+//! PM2, or Supervisor) and runs it over SSH. The manager comes from the
+//! server's `service_manager` setting; when unset it is detected by asking
+//! each manager whether it knows the service.
+
+use serde::{Deserialize, Serialize};
+
+use super::{load, Server, SshClient};
+use crate::engine::shell;
+use crate::error::{Error, RemoteCommandFailedDetails, Result, TargetDetails};
+
+/// Log lines attached to a failed start or restart.
+const LOG_TAIL_LINES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceManager {
+    Systemd,
+    Pm2,
+    Supervisor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceAction {
+    Start,
+    Stop,
+    Restart,
+    Status,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceControlResult {
+    pub server_id: String,
+    pub service: String,
+    pub manager: ServiceManager,
+    pub action: ServiceAction,
+    /// Whether the service is running after the action.
+    pub active: bool,
+    /// Status as the manager reports it (`active`, `online`, `RUNNING`, ...).
+    pub status: String,
+    /// Output of the action command.
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub output: String,
+}
+
+impl ServiceManager {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            "systemd" => Some(Self::Systemd),
+            "pm2" => Some(Self::Pm2),
+            "supervisor" => Some(Self::Supervisor),
+            _ => None,
+        }
+    }
+
+    /// systemd and Supervisor manage system services and need root; PM2
+    /// runs as the deploying user.
+    fn needs_root(self) -> bool {
+        !matches!(self, Self::Pm2)
+    }
+}
+
+impl ServiceAction {
+    fn verb(self) -> &'static str {
+        match self {
+            Self::Start => "start",
+            Self::Stop => "stop",
+            Self::Restart => "restart",
+            Self::Status => "status",
+        }
+    }
+
+    fn expects_running(self) -> bool {
+        matches!(self, Self::Start | Self::Restart)
+    }
+}
+
+/// Run `action` for `service` on a server and report the resulting status.
+///
+/// A start or restart that fails, or leaves the service stopped, is an
+/// error carrying the service's last log lines. `status` never fails on a
+/// stopped service; callers read `active`.
+pub fn control(
+    server_id: &str,
+    action: ServiceAction,
+    service: &str,
+) -> Result<ServiceControlResult> {
+    if service.trim().is_empty() {
+        return Err(Error::validation_invalid_argument(
+            "service",
+            "Service name cannot be empty",
+            None,
+            None,
+        ));
+    }
+
+    let server = load(server_id)?;
+    let client = SshClient::from_server(&server, server_id)?;
+    let manager = match server.service_manager {
+        Some(manager) => manager,
+        None => detect_manager(&client, &server, service)?,
+    };
+    let sudo = manager.needs_root() && server.user != "root";
+
+    let mut output = String::new();
+    if action != ServiceAction::Status {
+        let command = action_command(manager, action, service, sudo);
+        let result = client.execute(&command);
+        if !result.success {
+            let error = failure(&server, command, result);
+            return Err(with_logs(error, &client, manager, action, service, sudo));
+        }
+        output = result.stdout.trim().to_string();
+    }
+
+    let status = parse_status(
+        manager,
+        service,
+        &client
+            .execute(&status_command(manager, service, sudo))
+            .stdout,
+    );
+    let active = is_active(&status);
+
+    if action.expects_running() && !active {
+        let error = Error::validation_invalid_argument(
+            "service",
+            format!(
+                "'{}' is {} after {} on '{}'",
+                service,
+                status,
+                action.verb(),
+                server_id
+            ),
+            Some(service.to_string()),
+            None,
+        );
+        return Err(with_logs(error, &client, manager, action, service, sudo));
+    }
+
+    Ok(ServiceControlResult {
+        server_id: server_id.to_string(),
+        service: service.to_string(),
+        manager,
+        action,
+        active,
+        status,
+        output,
+    })
+}
+
+/// Pick the manager that knows `service`: PM2 and Supervisor first, since
+/// systemd is present on most hosts whether or not it runs the service.
+fn detect_manager(client: &SshClient, server: &Server, service: &str) -> Result<ServiceManager> {
+    let name = shell::quote_arg(service);
+    let command = format!(
+        "if command -v pm2 >/dev/null 2>&1 && pm2 describe {name} >/dev/null 2>&1; then echo pm2; \
+         elif command -v supervisorctl >/dev/null 2>&1 && supervisorctl status {name} 2>&1 | grep -qv 'no such process'; then echo supervisor; \
+         elif command -v systemctl >/dev/null 2>&1; then echo systemd; fi"
+    );
+    let output = client.execute(&command);
+    if let Some(manager) = ServiceManager::from_name(&output.stdout) {
+        return Ok(manager);
+    }
+    if !output.success {
+        return Err(failure(server, command, output));
+    }
+
+    Err(Error::validation_invalid_argument(
+        "service_manager",
+        format!("No service manager on '{}' knows '{}'", server.id, service),
+        Some(service.to_string()),
+        None,
+    )
+    .with_hint(format!(
+        "Set one explicitly: homeboy server set {} service_manager=systemd (or pm2, supervisor)",
+        server.id
+    )))
+}
+
+fn action_command(
+    manager: ServiceManager,
+    action: ServiceAction,
+    service: &str,
+    sudo: bool,
+) -> String {
+    let name = shell::quote_arg(service);
+    let command = match manager {
+        ServiceManager::Systemd => format!("systemctl {} {}", action.verb(), name),
+        ServiceManager::Pm2 => format!("pm2 {} {}", action.verb(), name),
+        ServiceManager::Supervisor => format!("supervisorctl {} {}", action.verb(), name),
+    };
+    with_sudo(command, sudo)
+}
+
+fn status_command(manager: ServiceManager, service: &str, sudo: bool) -> String {
+    let name = shell::quote_arg(service);
+    match manager {
+        ServiceManager::Systemd => format!("systemctl is-active {}", name),
+        ServiceManager::Pm2 => "pm2 jlist".to_string(),
+        ServiceManager::Supervisor => with_sudo(format!("supervisorctl status {}", name), sudo),
+    }
+}
+
+fn logs_command(manager: ServiceManager, service: &str, sudo: bool) -> String {
+    let name = shell::quote_arg(service);
+    match manager {
+        ServiceManager::Systemd => with_sudo(
+            format!("journalctl -u {} -n {} --no-pager", name, LOG_TAIL_LINES),
+            sudo,
+        ),
+        ServiceManager::Pm2 => format!(
+            "pm2 logs {} --lines {} --nostream --raw",
+            name, LOG_TAIL_LINES
+        ),
+        ServiceManager::Supervisor => {
+            with_sudo(format!("supervisorctl tail {} stderr", name), sudo)
+        }
+    }
+}
+
+fn with_sudo(command: String, sudo: bool) -> String {
+    if sudo {
+        format!("sudo -n {}", command)
+    } else {
+        command
+    }
+}
+
+/// Reduce a manager's status output to one word.
+fn parse_status(manager: ServiceManager, service: &str, stdout: &str) -> String {
+    let status = match manager {
+        ServiceManager::Systemd => stdout.lines().next().map(|line| line.trim().to_string()),
+        ServiceManager::Pm2 => serde_json::from_str::<Vec<serde_json::Value>>(stdout)
+            .ok()
+            .and_then(|processes| {
+                processes
+                    .iter()
+                    .find(|process| process["name"].as_str() == Some(service))
+                    .and_then(|process| process["pm2_env"]["status"].as_str())
+                    .map(str::to_string)
+            }),
+        ServiceManager::Supervisor => stdout
+            .lines()
+            .find_map(|line| {
+                let mut fields = line.split_whitespace();
+                (fields.next() == Some(service)).then(|| fields.next())?
+            })
+            .map(str::to_string),
+    };
+    status
+        .filter(|status| !status.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn is_active(status: &str) -> bool {
+    matches!(status, "active" | "online" | "RUNNING")
+}
+
+fn failure(server: &Server, command: String, output: super::CommandOutput) -> Error {
+    Error::remote_command_failed(RemoteCommandFailedDetails {
+        command,
+        exit_code: output.exit_code,
+        stdout: output.stdout,
+        stderr: output.stderr,
+        target: TargetDetails {
+            project_id: None,
+            server_id: Some(server.id.clone()),
+            host: Some(server.host.clone()),
+        },
+    })
+}
+
+/// Attach the service's recent log lines to a start/restart failure.
+fn with_logs(
+    error: Error,
+    client: &SshClient,
+    manager: ServiceManager,
+    action: ServiceAction,
+    service: &str,
+    sudo: bool,
+) -> Error {
+    if !action.expects_running() {
+        return error;
+    }
+    let logs = client.execute(&logs_command(manager, service, sudo));
+    match tail(&logs.stdout, LOG_TAIL_LINES) {
+        Some(lines) => error.with_hint(format!("Last log lines for '{}':\n{}", service, lines)),
+        None => error,
+    }
+}
+
+fn tail(text: &str, lines: usize) -> Option<String> {
+    let kept: Vec<&str> = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    if kept.is_empty() {
+        return None;
+    }
+    Some(kept[kept.len().saturating_sub(lines)..].join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_command() {
+        assert_eq!(
+            action_command(
+                ServiceManager::Systemd,
+                ServiceAction::Restart,
+                "nginx",
+                true
+            ),
+            "sudo -n systemctl restart nginx"
+        );
+        assert_eq!(
+            action_command(ServiceManager::Pm2, ServiceAction::Stop, "my api", false),
+            "pm2 stop 'my api'"
+        );
+        assert_eq!(
+            action_command(
+                ServiceManager::Supervisor,
+                ServiceAction::Start,
+                "worker",
+                false
+            ),
+            "supervisorctl start worker"
+        );
+    }
+
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(
+            parse_status(ServiceManager::Systemd, "nginx", "failed\n"),
+            "failed"
+        );
+
+        let jlist = r#"[{"name":"other","pm2_env":{"status":"online"}},{"name":"api","pm2_env":{"status":"errored"}}]"#;
+        assert_eq!(parse_status(ServiceManager::Pm2, "api", jlist), "errored");
+        assert_eq!(
+            parse_status(ServiceManager::Pm2, "missing", jlist),
+            "unknown"
+        );
+
+        let supervisor = "worker                           RUNNING   pid 42, uptime 0:01:00\n";
+        assert_eq!(
+            parse_status(ServiceManager::Supervisor, "worker", supervisor),
+            "RUNNING"
+        );
+        assert!(is_active("RUNNING"));
+        assert!(!is_active("inactive"));
+    }
+
+    #[test]
+    fn test_tail() {
+        assert_eq!(tail("a\nb\n\nc\n", 2).as_deref(), Some("b\nc"));
+        assert_eq!(tail("\n  \n", 5), None);
+    }
+}
@@ -1,7 +1,15 @@
 use super::SshClient;
+use crate::engine::shell;
 use serde::Serialize;
 use std::process::{Command, Stdio};
 
+mod resume;
+mod verify;
+
+pub use resume::ResumeReport;
+use verify::{ChecksumEndpoint, ExcludeFilter};
+pub use verify::{ChecksumMismatch, TransferVerification, VERIFY_SIZE_THRESHOLD};
+
 /// Configuration for a file transfer operation.
 pub struct TransferConfig {
     /// Source: local path or server_id:/path
@@ -16,6 +24,12 @@ pub struct TransferConfig {
     pub dry_run: bool,
     /// Exclude patterns
     pub exclude: Vec<String>,
+    /// Compare sha256 checksums after the transfer. `None` verifies
+    /// single-file transfers of at least `VERIFY_SIZE_THRESHOLD` bytes.
+    pub verify: Option<bool>,
+    /// Skip files already on the destination and append to partial ones
+    /// instead of copying everything again. Verifies unless `verify` is `Some(false)`.
+    pub resume: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -30,6 +44,10 @@ pub struct TransferOutput {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
     pub dry_run: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<TransferVerification>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resume: Option<ResumeReport>,
 }
 
 fn transfer_output(
@@ -50,6 +68,8 @@ fn transfer_output(
         success,
         error,
         dry_run,
+        verification: None,
+        resume: None,
     }
 }
 
@@ -126,6 +146,8 @@ fn build_scp_args(client: &SshClient) -> Vec<String> {
         args.push(client.port.to_string());
     }
 
+    args.extend(client.proxy_args());
+
     args
 }
 
@@ -149,6 +171,8 @@ fn build_ssh_args(client: &SshClient) -> String {
         args.push(format!("-p {}", client.port));
     }
 
+    args.extend(client.proxy_args().iter().map(|arg| shell::quote_arg(arg)));
+
     args.join(" ")
 }
 
@@ -225,6 +249,21 @@ fn run_push(
         ));
     }
 
+    let source_end = ChecksumEndpoint::local();
+    let destination_end = ChecksumEndpoint::remote(&client);
+    if config.resume {
+        return Ok(run_resume(
+            config,
+            "push",
+            &source_end,
+            local_path,
+            &destination_end,
+            remote_path,
+        ));
+    }
+    let verify_path = should_verify(config, &source_end, local_path)
+        .then(|| scp_landing_path(&destination_end, remote_path, local_path));
+
     let mut scp_args = build_scp_args(&client);
 
     if config.recursive || local.is_dir() {
@@ -245,7 +284,18 @@ fn run_push(
         remote_path
     );
 
-    execute_scp(&scp_args, config)
+    let result = execute_scp(&scp_args, config)?;
+    Ok(match verify_path {
+        Some(path) => apply_verification(
+            result,
+            &source_end,
+            local_path,
+            &destination_end,
+            &path,
+            &config.exclude,
+        ),
+        None => result,
+    })
 }
 
 /// Pull a remote file/directory to a local path via scp.
@@ -284,6 +334,21 @@ fn run_pull(
         }
     }
 
+    let source_end = ChecksumEndpoint::remote(&client);
+    let destination_end = ChecksumEndpoint::local();
+    if config.resume {
+        return Ok(run_resume(
+            config,
+            "pull",
+            &source_end,
+            remote_path,
+            &destination_end,
+            local_path,
+        ));
+    }
+    let verify_path = should_verify(config, &source_end, remote_path)
+        .then(|| scp_landing_path(&destination_end, local_path, remote_path));
+
     let mut scp_args = build_scp_args(&client);
 
     if config.recursive {
@@ -304,7 +369,18 @@ fn run_pull(
         local_path
     );
 
-    execute_scp(&scp_args, config)
+    let result = execute_scp(&scp_args, config)?;
+    Ok(match verify_path {
+        Some(path) => apply_verification(
+            result,
+            &source_end,
+            remote_path,
+            &destination_end,
+            &path,
+            &config.exclude,
+        ),
+        None => result,
+    })
 }
 
 /// Transfer between two remote servers via SSH tar pipe.
@@ -342,6 +418,20 @@ fn run_server_to_server(
         ));
     }
 
+    let source_end = ChecksumEndpoint::remote(&src_client);
+    let destination_end = ChecksumEndpoint::remote(&dst_client);
+    if config.resume {
+        return Ok(run_resume(
+            config,
+            "server-to-server",
+            &source_end,
+            src_path,
+            &destination_end,
+            dst_path,
+        ));
+    }
+    let verify = should_verify(config, &source_end, src_path);
+
     let source_ssh_args = build_ssh_args(&src_client);
     let dest_ssh_args = build_ssh_args(&dst_client);
 
@@ -400,7 +490,7 @@ fn run_server_to_server(
                 log_status!("transfer", "Complete");
             }
 
-            Ok((
+            let result = (
                 transfer_output(
                     config,
                     method,
@@ -410,7 +500,19 @@ fn run_server_to_server(
                     false,
                 ),
                 if success { 0 } else { 1 },
-            ))
+            );
+            Ok(if verify {
+                apply_verification(
+                    result,
+                    &source_end,
+                    src_path,
+                    &destination_end,
+                    dst_path,
+                    &config.exclude,
+                )
+            } else {
+                result
+            })
         }
         Err(e) => Ok((
             transfer_output(
@@ -426,11 +528,153 @@ fn run_server_to_server(
     }
 }
 
+/// Resume a transfer file by file, then verify unless `--no-verify` was given.
+///
+/// A directory source resumes into `destination_path` itself; a file source
+/// lands where scp would put it.
+fn run_resume(
+    config: &TransferConfig,
+    direction: &str,
+    source: &ChecksumEndpoint,
+    source_path: &str,
+    destination: &ChecksumEndpoint,
+    destination_path: &str,
+) -> (TransferOutput, i32) {
+    let destination_path = if source.is_dir(source_path) {
+        destination_path.to_string()
+    } else {
+        scp_landing_path(destination, destination_path, source_path)
+    };
+
+    log_status!(
+        "transfer",
+        "Resuming {} -> {}",
+        config.source,
+        config.destination
+    );
+    let exclude = ExcludeFilter::new(&config.exclude);
+    let result = match resume::resume(
+        source,
+        source_path,
+        destination,
+        &destination_path,
+        &exclude,
+    ) {
+        Ok(report) => {
+            log_status!(
+                "transfer",
+                "Complete: {} bytes resumed, {} bytes sent",
+                report.resumed_bytes,
+                report.fresh_bytes
+            );
+            let mut output = transfer_output(config, "resume", direction, true, None, false);
+            output.resume = Some(report);
+            (output, 0)
+        }
+        Err(e) => {
+            eprintln!("[transfer] Failed: {}", e.message);
+            (
+                transfer_output(config, "resume", direction, false, Some(e.message), false),
+                1,
+            )
+        }
+    };
+
+    if config.verify == Some(false) {
+        result
+    } else {
+        apply_verification(
+            result,
+            source,
+            source_path,
+            destination,
+            &destination_path,
+            &config.exclude,
+        )
+    }
+}
+
+/// Whether to checksum both ends after transferring `source_path`.
+fn should_verify(config: &TransferConfig, source: &ChecksumEndpoint, source_path: &str) -> bool {
+    config.verify.unwrap_or_else(|| {
+        !config.recursive
+            && !source.is_dir(source_path)
+            && source
+                .file_size(source_path)
+                .is_some_and(|size| size >= VERIFY_SIZE_THRESHOLD)
+    })
+}
+
+/// Where scp lands `source_path`: inside `destination_path` when that is an
+/// existing directory, otherwise at `destination_path` itself. Must be
+/// resolved before the transfer creates the destination.
+fn scp_landing_path(
+    destination: &ChecksumEndpoint,
+    destination_path: &str,
+    source_path: &str,
+) -> String {
+    let name = std::path::Path::new(source_path.trim_end_matches('/')).file_name();
+    match name {
+        Some(name) if destination.is_dir(destination_path) => format!(
+            "{}/{}",
+            destination_path.trim_end_matches('/'),
+            name.to_string_lossy()
+        ),
+        _ => destination_path.to_string(),
+    }
+}
+
+/// Checksum both ends of a successful transfer, failing it on any mismatch.
+fn apply_verification(
+    (mut output, code): (TransferOutput, i32),
+    source: &ChecksumEndpoint,
+    source_path: &str,
+    destination: &ChecksumEndpoint,
+    destination_path: &str,
+    exclude: &[String],
+) -> (TransferOutput, i32) {
+    if !output.success {
+        return (output, code);
+    }
+
+    log_status!("verify", "Comparing sha256 checksums");
+    let exclude = ExcludeFilter::new(exclude);
+    match verify::verify(source, source_path, destination, destination_path, &exclude) {
+        Ok(verification) => {
+            let mismatch = verification
+                .mismatch
+                .as_ref()
+                .map(ChecksumMismatch::message);
+            output.verification = Some(verification);
+            match mismatch {
+                Some(message) => {
+                    eprintln!("[verify] {}", message);
+                    output.success = false;
+                    output.error = Some(message);
+                    (output, 1)
+                }
+                None => {
+                    log_status!("verify", "Checksums match");
+                    (output, code)
+                }
+            }
+        }
+        Err(e) => {
+            let message = format!("Transfer completed but verification failed: {}", e.message);
+            eprintln!("[verify] {}", message);
+            output.success = false;
+            output.error = Some(message);
+            (output, 1)
+        }
+    }
+}
+
 /// Execute an scp command and return structured output.
 fn execute_scp(
     scp_args: &[String],
     config: &TransferConfig,
 ) -> crate::Result<(TransferOutput, i32)> {
+    let _permit = super::acquire_ssh_permit();
     let output = Command::new("scp")
         .args(scp_args)
         .stdin(Stdio::null())
@@ -495,8 +739,11 @@ mod tests {
             port: 22,
             identity_file: None,
             kind: None,
+            tags: Vec::new(),
             auth: None,
             env: HashMap::new(),
+            proxy_jump: None,
+            service_manager: None,
         })
         .expect("save server");
     }
@@ -532,6 +779,8 @@ mod tests {
                 compress: true,
                 dry_run: true,
                 exclude: Vec::new(),
+                verify: None,
+                resume: false,
             })
             .expect("dry run transfer");
 
@@ -557,6 +806,8 @@ mod tests {
                 compress: true,
                 dry_run: true,
                 exclude: vec!["cache".to_string()],
+                verify: Some(true),
+                resume: false,
             })
             .expect("dry run server transfer");
 
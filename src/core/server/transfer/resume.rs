@@ -0,0 +1,343 @@
+//! Resume an interrupted transfer instead of starting over.
+//!
+//! Each source file not matched by `--exclude` is compared with what already
+//! sits on the destination: files with the same size and sha256 are skipped,
+//! shorter destination files whose bytes match the same-length source prefix
+//! get the missing tail appended (`tail -c`), and anything else is copied
+//! fresh. Sizes are fetched in one call per endpoint and prefix checksums in
+//! fixed-size batches.
+
+use std::collections::BTreeMap;
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+
+use crate::engine::shell;
+use crate::error::{Error, Result};
+
+use super::verify::{join_relative, ChecksumEndpoint, ExcludeFilter};
+
+/// Bytes and files a resumed transfer reused versus sent.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResumeReport {
+    /// Already complete on the destination (same size and sha256).
+    pub files_skipped: usize,
+    /// Partially present; only the missing tail was sent.
+    pub files_resumed: usize,
+    /// Missing, longer than the source, or not a prefix of it; copied from the start.
+    pub files_fresh: usize,
+    /// Bytes already on the destination that were not sent again.
+    pub resumed_bytes: u64,
+    /// Bytes sent by this run.
+    pub fresh_bytes: u64,
+}
+
+/// Bring `destination_path` up to date with `source_path`, sending only the
+/// bytes the destination is missing. Both paths are files, or both directories.
+pub(crate) fn resume(
+    source: &ChecksumEndpoint,
+    source_path: &str,
+    destination: &ChecksumEndpoint,
+    destination_path: &str,
+    exclude: &ExcludeFilter,
+) -> Result<ResumeReport> {
+    if !source.exists(source_path) {
+        return Err(Error::validation_invalid_argument(
+            "source",
+            format!("Source path does not exist: {}", source_path),
+            None,
+            None,
+        ));
+    }
+    let mut source_sizes = source.file_sizes(source_path)?;
+    exclude.retain(&mut source_sizes);
+    let destination_sizes = destination.file_sizes(destination_path)?;
+
+    // A destination file no longer than its source is reusable only if it
+    // matches the same-length source prefix.
+    let prefixes: BTreeMap<String, u64> = source_sizes
+        .iter()
+        .filter_map(|(relative, &size)| {
+            let existing = *destination_sizes.get(relative)?;
+            (existing > 0 && existing <= size).then(|| (relative.clone(), existing))
+        })
+        .collect();
+    let source_prefixes = source.prefix_checksums(source_path, &prefixes)?;
+    let destination_prefixes = destination.prefix_checksums(destination_path, &prefixes)?;
+
+    let mut report = ResumeReport::default();
+    for (relative, &size) in &source_sizes {
+        let existing = match destination_sizes.get(relative) {
+            Some(0) => Some(0),
+            Some(_) => source_prefixes
+                .get(relative)
+                .filter(|sum| destination_prefixes.get(relative) == Some(*sum))
+                .and(prefixes.get(relative).copied()),
+            None => None,
+        };
+
+        if existing == Some(size) {
+            report.files_skipped += 1;
+            report.resumed_bytes += size;
+            continue;
+        }
+
+        let offset = existing.unwrap_or(0);
+        if offset > 0 {
+            report.files_resumed += 1;
+        } else {
+            report.files_fresh += 1;
+        }
+        report.resumed_bytes += offset;
+        report.fresh_bytes += size - offset;
+
+        send_from_offset(
+            source,
+            &join_relative(source_path, relative),
+            destination,
+            &join_relative(destination_path, relative),
+            offset,
+            size,
+        )?;
+    }
+
+    Ok(report)
+}
+
+/// Pipe `source_file` from byte `offset` onward into `destination_file`,
+/// appending when resuming and truncating when starting fresh.
+///
+/// The reader and writer run as separate processes so a failure on either
+/// side is caught, and the destination must end up exactly `size` bytes long.
+fn send_from_offset(
+    source: &ChecksumEndpoint,
+    source_file: &str,
+    destination: &ChecksumEndpoint,
+    destination_file: &str,
+    offset: u64,
+    size: u64,
+) -> Result<()> {
+    let quoted_destination = shell::quote_path(destination_file);
+    let redirect = if offset > 0 { ">>" } else { ">" };
+    let mut write = format!("cat {} {}", redirect, quoted_destination);
+    if let Some(parent) = std::path::Path::new(destination_file)
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        write = format!(
+            "mkdir -p {} && {}",
+            shell::quote_path(&parent.to_string_lossy()),
+            write
+        );
+    }
+    let read = format!("tail -c +{} {}", offset + 1, shell::quote_path(source_file));
+
+    // One slot per ssh process: a remote-to-remote pipe runs two.
+    let ssh_processes = usize::from(source.is_remote()) + usize::from(destination.is_remote());
+    let _permit = (ssh_processes > 0).then(|| crate::server::acquire_ssh_permits(ssh_processes));
+    let spawn_error = |e: std::io::Error| {
+        Error::internal_io(e.to_string(), Some("spawn transfer pipe".to_string()))
+    };
+    let mut reader = Command::new("sh")
+        .args(["-c", &source.shell_command(&read)])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+    let reader_stdout = reader.stdout.take().expect("reader stdout is piped");
+    let writer = Command::new("sh")
+        .args(["-c", &destination.shell_command(&write)])
+        .stdin(Stdio::from(reader_stdout))
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(spawn_error)?;
+
+    let writer_output = writer.wait_with_output().map_err(spawn_error)?;
+    let reader_output = reader.wait_with_output().map_err(spawn_error)?;
+    for (side, output) in [("read", &reader_output), ("write", &writer_output)] {
+        if !output.status.success() {
+            return Err(Error::internal_unexpected(format!(
+                "Failed to transfer {} ({} side): {}",
+                source_file,
+                side,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+    }
+
+    match destination.file_size(destination_file) {
+        Some(actual) if actual == size => Ok(()),
+        actual => Err(Error::internal_unexpected(format!(
+            "Failed to transfer {}: destination has {} bytes, expected {}",
+            source_file,
+            actual.map_or_else(|| "no".to_string(), |bytes| bytes.to_string()),
+            size
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("src");
+        let destination = dir.path().join("dst");
+        std::fs::create_dir_all(source.join("nested")).expect("mkdir");
+        std::fs::create_dir_all(&destination).expect("mkdir");
+        std::fs::write(source.join("done.txt"), "complete").expect("write");
+        std::fs::write(source.join("partial.bin"), "0123456789").expect("write");
+        std::fs::write(source.join("nested/new.txt"), "fresh").expect("write");
+        std::fs::write(destination.join("done.txt"), "complete").expect("write");
+        std::fs::write(destination.join("partial.bin"), "0123").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let report = resume(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &ExcludeFilter::new(&[]),
+        )
+        .expect("resume");
+
+        assert_eq!(
+            report,
+            ResumeReport {
+                files_skipped: 1,
+                files_resumed: 1,
+                files_fresh: 1,
+                resumed_bytes: 8 + 4,
+                fresh_bytes: 6 + 5,
+            }
+        );
+        assert_eq!(
+            std::fs::read_to_string(destination.join("partial.bin")).unwrap(),
+            "0123456789"
+        );
+        assert_eq!(
+            std::fs::read_to_string(destination.join("nested/new.txt")).unwrap(),
+            "fresh"
+        );
+    }
+
+    #[test]
+    fn resume_restarts_files_longer_than_the_source() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("a.txt");
+        let destination = dir.path().join("b.txt");
+        std::fs::write(&source, "short").expect("write");
+        std::fs::write(&destination, "much longer stale").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let report = resume(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &ExcludeFilter::new(&[]),
+        )
+        .expect("resume");
+
+        assert_eq!(report.files_fresh, 1);
+        assert_eq!(report.fresh_bytes, 5);
+        assert_eq!(std::fs::read_to_string(&destination).unwrap(), "short");
+    }
+
+    #[test]
+    fn resume_restarts_partial_files_whose_prefix_differs() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("a.bin");
+        let destination = dir.path().join("b.bin");
+        std::fs::write(&source, "0123456789").expect("write");
+        std::fs::write(&destination, "xxxx").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let report = resume(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &ExcludeFilter::new(&[]),
+        )
+        .expect("resume");
+
+        assert_eq!(report.files_resumed, 0);
+        assert_eq!(report.files_fresh, 1);
+        assert_eq!(report.fresh_bytes, 10);
+        assert_eq!(std::fs::read_to_string(&destination).unwrap(), "0123456789");
+    }
+
+    #[test]
+    fn resume_accepts_an_empty_source_directory() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("src");
+        std::fs::create_dir_all(&source).expect("mkdir");
+
+        let local = ChecksumEndpoint::local();
+        let report = resume(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &dir.path().join("dst").to_string_lossy(),
+            &ExcludeFilter::new(&[]),
+        )
+        .expect("resume");
+        assert_eq!(report, ResumeReport::default());
+
+        let missing = resume(
+            &local,
+            &dir.path().join("missing").to_string_lossy(),
+            &local,
+            &dir.path().join("dst").to_string_lossy(),
+            &ExcludeFilter::new(&[]),
+        );
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn send_from_offset_fails_when_the_source_read_fails() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let destination = dir.path().join("b.txt");
+
+        let local = ChecksumEndpoint::local();
+        let result = send_from_offset(
+            &local,
+            &dir.path().join("missing.txt").to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            0,
+            5,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn resume_skips_excluded_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("src");
+        let destination = dir.path().join("dst");
+        std::fs::create_dir_all(source.join("cache")).expect("mkdir");
+        std::fs::create_dir_all(&destination).expect("mkdir");
+        std::fs::write(source.join("index.php"), "<?php").expect("write");
+        std::fs::write(source.join("cache/page.html"), "cached").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let report = resume(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &ExcludeFilter::new(&["cache".to_string()]),
+        )
+        .expect("resume");
+
+        assert_eq!(report.files_fresh, 1);
+        assert!(destination.join("index.php").exists());
+        assert!(!destination.join("cache").exists());
+    }
+}
@@ -0,0 +1,649 @@
+//! Post-transfer integrity check: sha256 on both ends of a transfer.
+//!
+//! Each endpoint detects its checksum tool once, on first use: remotes try
+//! `sha256sum` then `shasum -a 256`. Local paths, and remotes with neither
+//! tool, are hashed in-process (for a remote, by streaming the file over SSH).
+
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::engine::shell;
+use crate::error::{Error, RemoteCommandFailedDetails, Result, TargetDetails};
+use crate::server::SshClient;
+
+/// Single-file transfers at or above this size are verified unless disabled.
+pub const VERIFY_SIZE_THRESHOLD: u64 = 10 * 1024 * 1024;
+
+/// Tool name reported for checksums computed in-process.
+const LOCAL_TOOL: &str = "local";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferVerification {
+    pub files_checked: usize,
+    pub source_tool: String,
+    pub destination_tool: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mismatch: Option<ChecksumMismatch>,
+}
+
+/// First file whose checksum differs between source and destination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChecksumMismatch {
+    pub path: String,
+    pub source_sha256: String,
+    /// `None` when the file is missing on the destination.
+    pub destination_sha256: Option<String>,
+}
+
+impl ChecksumMismatch {
+    pub fn message(&self) -> String {
+        match &self.destination_sha256 {
+            Some(destination) => format!(
+                "Checksum mismatch for {}: source sha256 {} != destination sha256 {}",
+                self.path, self.source_sha256, destination
+            ),
+            None => format!(
+                "Checksum mismatch for {}: source sha256 {} but file is missing on destination",
+                self.path, self.source_sha256
+            ),
+        }
+    }
+}
+
+/// Files hashed per remote script by [`ChecksumEndpoint::prefix_checksums`].
+const PREFIX_BATCH: usize = 200;
+
+/// One end of a transfer. The remote checksum tool is detected lazily, once.
+pub(crate) struct ChecksumEndpoint<'a> {
+    client: Option<&'a SshClient>,
+    tool: OnceCell<Option<&'static str>>,
+}
+
+impl<'a> ChecksumEndpoint<'a> {
+    pub(crate) fn local() -> Self {
+        Self {
+            client: None,
+            tool: OnceCell::new(),
+        }
+    }
+
+    pub(crate) fn remote(client: &'a SshClient) -> Self {
+        Self {
+            client: Some(client),
+            tool: OnceCell::new(),
+        }
+    }
+
+    pub(crate) fn is_dir(&self, path: &str) -> bool {
+        match self.client {
+            None => Path::new(path).is_dir(),
+            Some(client) => {
+                client
+                    .execute(&format!("test -d {}", shell::quote_path(path)))
+                    .success
+            }
+        }
+    }
+
+    pub(crate) fn exists(&self, path: &str) -> bool {
+        match self.client {
+            None => Path::new(path).exists(),
+            Some(client) => {
+                client
+                    .execute(&format!("test -e {}", shell::quote_path(path)))
+                    .success
+            }
+        }
+    }
+
+    pub(crate) fn file_size(&self, path: &str) -> Option<u64> {
+        match self.client {
+            None => std::fs::metadata(path).ok().map(|meta| meta.len()),
+            Some(client) => {
+                let output = client.execute(&format!("wc -c < {}", shell::quote_path(path)));
+                if !output.success {
+                    return None;
+                }
+                output.stdout.trim().parse().ok()
+            }
+        }
+    }
+
+    /// Whether commands on this end go over SSH.
+    pub(crate) fn is_remote(&self) -> bool {
+        self.client.is_some()
+    }
+
+    /// Wrap `command` so `sh -c` runs it on this end: as-is locally, or over SSH.
+    pub(crate) fn shell_command(&self, command: &str) -> String {
+        match self.client {
+            None => command.to_string(),
+            Some(client) => format!(
+                "ssh {} {}@{} {}",
+                super::build_ssh_args(client),
+                client.user,
+                client.host,
+                shell::quote_arg(command),
+            ),
+        }
+    }
+
+    /// File sizes keyed like [`Self::checksums`]. Empty when `path` is missing.
+    pub(crate) fn file_sizes(&self, path: &str) -> Result<BTreeMap<String, u64>> {
+        if !self.is_dir(path) {
+            return Ok(self
+                .file_size(path)
+                .map(|size| BTreeMap::from([(String::new(), size)]))
+                .unwrap_or_default());
+        }
+        let Some(client) = self.client else {
+            let mut sizes = BTreeMap::new();
+            size_local_tree(Path::new(path), Path::new(path), &mut sizes)?;
+            return Ok(sizes);
+        };
+
+        let command = format!(
+            "cd {} && find . -type f | while IFS= read -r f; do printf '%s %s\\n' \"$(wc -c < \"$f\")\" \"$f\"; done",
+            shell::quote_path(path)
+        );
+        Ok(run_remote(client, &command)?
+            .lines()
+            .filter_map(|line| {
+                let (size, relative) = line.trim_start().split_once(' ')?;
+                Some((normalize_relative(relative)?, size.parse().ok()?))
+            })
+            .collect())
+    }
+
+    /// sha256 of the first `len` bytes of each file under `root`, keyed like
+    /// [`Self::checksums`]. A remote end hashes up to [`PREFIX_BATCH`] prefixes
+    /// per SSH call so the script stays well under `ARG_MAX`.
+    pub(crate) fn prefix_checksums(
+        &self,
+        root: &str,
+        prefixes: &BTreeMap<String, u64>,
+    ) -> Result<BTreeMap<String, String>> {
+        if prefixes.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+        let Some(client) = self.client else {
+            return prefixes
+                .iter()
+                .map(|(relative, &len)| {
+                    let file = join_relative(root, relative);
+                    Ok((relative.clone(), hash_local_prefix(Path::new(&file), len)?))
+                })
+                .collect();
+        };
+
+        let Some(tool) = self.tool() else {
+            return prefixes
+                .iter()
+                .map(|(relative, &len)| {
+                    let file = join_relative(root, relative);
+                    let read = format!("head -c {} {}", len, shell::quote_path(&file));
+                    Ok((relative.clone(), hash_remote_stream(client, &read)?))
+                })
+                .collect();
+        };
+
+        let entries: Vec<_> = prefixes.iter().collect();
+        let mut sums = BTreeMap::new();
+        for batch in entries.chunks(PREFIX_BATCH) {
+            let script = batch
+                .iter()
+                .map(|(relative, &len)| {
+                    format!(
+                        "h=$(head -c {} {} | {}) && printf '%s %s\n' \"${{h%% *}}\" {}",
+                        len,
+                        shell::quote_path(&join_relative(root, relative)),
+                        tool,
+                        shell::quote_arg(if relative.is_empty() { "." } else { relative }),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            sums.extend(parse_checksum_lines(&run_remote(client, &script)?));
+        }
+        Ok(sums)
+    }
+
+    fn tool(&self) -> Option<&'static str> {
+        *self.tool.get_or_init(|| {
+            let client = self.client?;
+            let output = client.execute(
+                "if command -v sha256sum >/dev/null 2>&1; then echo sha256sum; \
+                 elif command -v shasum >/dev/null 2>&1; then echo shasum; fi",
+            );
+            match output.stdout.trim() {
+                "sha256sum" => Some("sha256sum"),
+                "shasum" => Some("shasum -a 256"),
+                _ => None,
+            }
+        })
+    }
+
+    fn tool_name(&self) -> String {
+        self.tool().unwrap_or(LOCAL_TOOL).to_string()
+    }
+
+    /// Checksums keyed by path relative to `path`. A single file is keyed `""`.
+    pub(crate) fn checksums(&self, path: &str) -> Result<BTreeMap<String, String>> {
+        let is_dir = self.is_dir(path);
+        let Some(client) = self.client else {
+            return if is_dir {
+                let mut sums = BTreeMap::new();
+                hash_local_tree(Path::new(path), Path::new(path), &mut sums)?;
+                Ok(sums)
+            } else {
+                Ok(BTreeMap::from([(
+                    String::new(),
+                    hash_local_file(Path::new(path))?,
+                )]))
+            };
+        };
+
+        let quoted = shell::quote_path(path);
+        match (self.tool(), is_dir) {
+            (Some(tool), true) => {
+                let command = format!("cd {} && find . -type f -exec {} {{}} +", quoted, tool);
+                Ok(parse_checksum_lines(&run_remote(client, &command)?))
+            }
+            (Some(tool), false) => {
+                let stdout = run_remote(client, &format!("{} {}", tool, quoted))?;
+                let sum = parse_checksum_lines(&stdout)
+                    .into_values()
+                    .next()
+                    .ok_or_else(|| {
+                        Error::internal_unexpected(format!("No checksum output for {}", path))
+                    })?;
+                Ok(BTreeMap::from([(String::new(), sum)]))
+            }
+            (None, true) => {
+                let listing = run_remote(client, &format!("cd {} && find . -type f", quoted))?;
+                let mut sums = BTreeMap::new();
+                for relative in listing.lines().filter_map(normalize_relative) {
+                    let file = join_relative(path, &relative);
+                    let read = format!("cat {}", shell::quote_path(&file));
+                    sums.insert(relative, hash_remote_stream(client, &read)?);
+                }
+                Ok(sums)
+            }
+            (None, false) => Ok(BTreeMap::from([(
+                String::new(),
+                hash_remote_stream(client, &format!("cat {}", quoted))?,
+            )])),
+        }
+    }
+}
+
+/// `--exclude` patterns matched the way `tar --exclude` matches them: a
+/// pattern hits any run of path components, so `cache` skips every `cache`
+/// directory and everything beneath it.
+pub(crate) struct ExcludeFilter {
+    patterns: Vec<glob::Pattern>,
+}
+
+impl ExcludeFilter {
+    pub(crate) fn new(exclude: &[String]) -> Self {
+        Self {
+            patterns: exclude
+                .iter()
+                .filter_map(|raw| {
+                    let raw = raw.trim_end_matches('/');
+                    // Malformed globs match literally, as tar treats them.
+                    glob::Pattern::new(raw)
+                        .or_else(|_| glob::Pattern::new(&glob::Pattern::escape(raw)))
+                        .ok()
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether `relative` (a `/`-separated path under the transfer root) is excluded.
+    pub(crate) fn is_excluded(&self, relative: &str) -> bool {
+        if self.patterns.is_empty() || relative.is_empty() {
+            return false;
+        }
+        let parts: Vec<&str> = relative.split('/').collect();
+        (0..parts.len()).any(|start| {
+            (start + 1..=parts.len()).any(|end| {
+                let candidate = parts[start..end].join("/");
+                self.patterns
+                    .iter()
+                    .any(|pattern| pattern.matches(&candidate))
+            })
+        })
+    }
+
+    /// Drop excluded entries from a listing keyed by relative path.
+    pub(crate) fn retain<V>(&self, listing: &mut BTreeMap<String, V>) {
+        listing.retain(|relative, _| !self.is_excluded(relative));
+    }
+}
+
+/// Compare checksums of `source_path` and `destination_path`, reporting the
+/// first mismatching file. Extra files on the destination and paths matching
+/// `exclude` on either end are ignored.
+pub(crate) fn verify(
+    source: &ChecksumEndpoint,
+    source_path: &str,
+    destination: &ChecksumEndpoint,
+    destination_path: &str,
+    exclude: &ExcludeFilter,
+) -> Result<TransferVerification> {
+    let mut source_sums = source.checksums(source_path)?;
+    let mut destination_sums = destination.checksums(destination_path)?;
+    exclude.retain(&mut source_sums);
+    exclude.retain(&mut destination_sums);
+    let mismatch = first_mismatch(&source_sums, &destination_sums).map(|mut mismatch| {
+        if mismatch.path.is_empty() {
+            mismatch.path = destination_path.to_string();
+        }
+        mismatch
+    });
+
+    Ok(TransferVerification {
+        files_checked: source_sums.len(),
+        source_tool: source.tool_name(),
+        destination_tool: destination.tool_name(),
+        mismatch,
+    })
+}
+
+fn first_mismatch(
+    source: &BTreeMap<String, String>,
+    destination: &BTreeMap<String, String>,
+) -> Option<ChecksumMismatch> {
+    source.iter().find_map(|(path, source_sum)| {
+        let destination_sum = destination.get(path);
+        if destination_sum == Some(source_sum) {
+            return None;
+        }
+        Some(ChecksumMismatch {
+            path: path.clone(),
+            source_sha256: source_sum.clone(),
+            destination_sha256: destination_sum.cloned(),
+        })
+    })
+}
+
+/// Parse `sha256sum` / `shasum` output (`<hex>  <path>`, or `<hex> *<path>`
+/// in binary mode) into checksums keyed by `./`-stripped path.
+fn parse_checksum_lines(stdout: &str) -> BTreeMap<String, String> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let (sum, path) = line.split_once(' ')?;
+            if sum.len() != 64 || !sum.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            let path = path.trim_start_matches(' ');
+            let path = path.strip_prefix('*').unwrap_or(path);
+            Some((
+                normalize_relative(path).unwrap_or_default(),
+                sum.to_ascii_lowercase(),
+            ))
+        })
+        .collect()
+}
+
+/// `root` itself for the `""` key of a single-file transfer, else `root/relative`.
+pub(crate) fn join_relative(root: &str, relative: &str) -> String {
+    if relative.is_empty() {
+        root.to_string()
+    } else {
+        format!("{}/{}", root.trim_end_matches('/'), relative)
+    }
+}
+
+fn normalize_relative(path: &str) -> Option<String> {
+    let path = path.trim().trim_start_matches("./");
+    (!path.is_empty() && path != ".").then(|| path.to_string())
+}
+
+fn run_remote(client: &SshClient, command: &str) -> Result<String> {
+    let output = client.execute(command);
+    if output.success {
+        return Ok(output.stdout);
+    }
+    Err(Error::remote_command_failed(RemoteCommandFailedDetails {
+        command: command.to_string(),
+        exit_code: output.exit_code,
+        stdout: output.stdout,
+        stderr: output.stderr,
+        target: TargetDetails {
+            project_id: None,
+            server_id: None,
+            host: Some(client.host.clone()),
+        },
+    }))
+}
+
+fn hash_local_file(path: &Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("open {}", path.display()))))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("hash {}", path.display()))))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn hash_local_prefix(path: &Path, len: u64) -> Result<String> {
+    use std::io::Read;
+    let file = std::fs::File::open(path)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("open {}", path.display()))))?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file.take(len), &mut hasher)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("hash {}", path.display()))))?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Hash regular files under `dir`; symlinks are skipped like `find -type f`.
+fn hash_local_tree(root: &Path, dir: &Path, sums: &mut BTreeMap<String, String>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("read {}", dir.display()))))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            hash_local_tree(root, &path, sums)?;
+        } else if file_type.is_file() {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            sums.insert(relative, hash_local_file(&path)?);
+        }
+    }
+    Ok(())
+}
+
+/// Sizes of regular files under `dir`, keyed like [`hash_local_tree`].
+fn size_local_tree(root: &Path, dir: &Path, sizes: &mut BTreeMap<String, u64>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| Error::internal_io(e.to_string(), Some(format!("read {}", dir.display()))))?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            size_local_tree(root, &path, sizes)?;
+        } else if file_type.is_file() {
+            let relative = path
+                .strip_prefix(root)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            let size = entry.metadata().map(|meta| meta.len()).unwrap_or_default();
+            sizes.insert(relative, size);
+        }
+    }
+    Ok(())
+}
+
+/// Fallback for remotes without a checksum tool: stream the output of
+/// `remote_command` (a `cat` or `head -c`) over SSH and hash it locally.
+fn hash_remote_stream(client: &SshClient, remote_command: &str) -> Result<String> {
+    let command = ChecksumEndpoint::remote(client).shell_command(remote_command);
+    let _permit = crate::server::acquire_ssh_permit();
+    let mut child = Command::new("sh")
+        .args(["-c", &command])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| Error::internal_io(e.to_string(), Some("spawn ssh".to_string())))?;
+
+    let mut hasher = Sha256::new();
+    if let Some(stdout) = child.stdout.as_mut() {
+        std::io::copy(stdout, &mut hasher).map_err(|e| {
+            Error::internal_io(e.to_string(), Some(format!("stream {}", remote_command)))
+        })?;
+    }
+    let status = child
+        .wait()
+        .map_err(|e| Error::internal_io(e.to_string(), Some("wait for ssh".to_string())))?;
+    if !status.success() {
+        return Err(Error::remote_command_failed(RemoteCommandFailedDetails {
+            command: remote_command.to_string(),
+            exit_code: status.code().unwrap_or(1),
+            stdout: String::new(),
+            stderr: String::new(),
+            target: TargetDetails {
+                project_id: None,
+                server_id: None,
+                host: Some(client.host.clone()),
+            },
+        }));
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUM_A: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+    const SUM_B: &str = "3e23e8160039594a33894f6564e1b1348bbd7a0088d42c4acb73eeaed59c009d";
+
+    #[test]
+    fn test_parse_checksum_lines() {
+        let sums = parse_checksum_lines(&format!(
+            "{SUM_A}  ./wp-content/a.txt\n{SUM_B} *./b.bin\nnot a checksum line\n"
+        ));
+
+        assert_eq!(sums.len(), 2);
+        assert_eq!(sums["wp-content/a.txt"], SUM_A);
+        assert_eq!(sums["b.bin"], SUM_B);
+    }
+
+    #[test]
+    fn test_first_mismatch() {
+        let source = BTreeMap::from([
+            ("a.txt".to_string(), SUM_A.to_string()),
+            ("b.txt".to_string(), SUM_B.to_string()),
+        ]);
+        let mut destination = source.clone();
+        destination.insert("extra.txt".to_string(), SUM_A.to_string());
+        assert_eq!(first_mismatch(&source, &destination), None);
+
+        destination.insert("a.txt".to_string(), SUM_B.to_string());
+        destination.remove("b.txt");
+        let mismatch = first_mismatch(&source, &destination).expect("mismatch");
+        assert_eq!(mismatch.path, "a.txt");
+        assert_eq!(mismatch.destination_sha256.as_deref(), Some(SUM_B));
+        assert!(mismatch.message().contains(SUM_A));
+        assert!(mismatch.message().contains(SUM_B));
+    }
+
+    #[test]
+    fn test_verify() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("src");
+        let destination = dir.path().join("dst");
+        for root in [&source, &destination] {
+            std::fs::create_dir_all(root.join("nested")).expect("mkdir");
+            std::fs::write(root.join("nested/a.txt"), "abc").expect("write");
+        }
+        std::fs::write(source.join("b.txt"), "full").expect("write");
+        std::fs::write(destination.join("b.txt"), "fu").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let no_exclude = ExcludeFilter::new(&[]);
+        let report = verify(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &no_exclude,
+        )
+        .expect("verify");
+
+        assert_eq!(report.files_checked, 2);
+        assert_eq!(report.source_tool, "local");
+        let mismatch = report.mismatch.expect("mismatch");
+        assert_eq!(mismatch.path, "b.txt");
+        assert!(mismatch.destination_sha256.is_some());
+
+        let report = verify(
+            &local,
+            &source.join("nested/a.txt").to_string_lossy(),
+            &local,
+            &destination.join("nested/a.txt").to_string_lossy(),
+            &no_exclude,
+        )
+        .expect("verify file");
+        assert_eq!(report.files_checked, 1);
+        assert!(report.mismatch.is_none());
+    }
+
+    #[test]
+    fn test_verify_ignores_excluded_paths() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let source = dir.path().join("src");
+        let destination = dir.path().join("dst");
+        for root in [&source, &destination] {
+            std::fs::create_dir_all(root).expect("mkdir");
+            std::fs::write(root.join("a.txt"), "abc").expect("write");
+        }
+        // Excluded on the way over, so never transferred.
+        std::fs::create_dir_all(source.join("wp-content/cache")).expect("mkdir");
+        std::fs::write(source.join("wp-content/cache/page.html"), "x").expect("write");
+        std::fs::write(source.join("debug.log"), "x").expect("write");
+
+        let local = ChecksumEndpoint::local();
+        let exclude = ExcludeFilter::new(&["cache".to_string(), "*.log".to_string()]);
+        let report = verify(
+            &local,
+            &source.to_string_lossy(),
+            &local,
+            &destination.to_string_lossy(),
+            &exclude,
+        )
+        .expect("verify");
+
+        assert_eq!(report.files_checked, 1);
+        assert!(report.mismatch.is_none());
+    }
+
+    #[test]
+    fn test_exclude_filter_matches_like_tar() {
+        let exclude = ExcludeFilter::new(&["cache".to_string(), "uploads/*.tmp".to_string()]);
+        assert!(exclude.is_excluded("cache"));
+        assert!(exclude.is_excluded("wp-content/cache/page.html"));
+        assert!(exclude.is_excluded("wp-content/uploads/a.tmp"));
+        assert!(!exclude.is_excluded("wp-content/cached.txt"));
+        assert!(!exclude.is_excluded("uploads/a.txt"));
+        assert!(!exclude.is_excluded(""));
+    }
+}
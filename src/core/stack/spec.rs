@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
 
+use crate::engine::local_files;
 use crate::error::{Error, Result};
 use crate::expand;
 use crate::paths;
@@ -209,14 +210,11 @@ pub fn save(spec: &StackSpec) -> Result<()> {
     let json = serde_json::to_string_pretty(spec).map_err(|e| {
         Error::internal_unexpected(format!("Failed to serialize stack spec: {}", e))
     })?;
-    fs::write(&path, format!("{}\n", json)).map_err(|e| {
-        Error::internal_unexpected(format!(
-            "Failed to write stack spec {}: {}",
-            path.display(),
-            e
-        ))
-    })?;
-    Ok(())
+    local_files::write_file_atomic(
+        &path,
+        &format!("{}\n", json),
+        &format!("write stack spec {}", path.display()),
+    )
 }
 
 /// Parse a `<remote>/<branch>` CLI string into a [`GitRef`]. Errors with a
@@ -0,0 +1,75 @@
+//! Gating for `log_status!` stderr lines.
+//!
+//! By default status lines print only when stderr is a terminal, as judged by
+//! the shared [`crate::output::color`] detector, which also decides whether
+//! the `[prefix]` is colored. `--quiet`
+//! (or `HOMEBOY_QUIET`) silences them everywhere; `--status-always` prints
+//! them even when stderr is piped, for log capture. Errors and command output
+//! are not affected.
+
+use crate::output::color::{self, Stream};
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusMode {
+    /// Print when stderr is a terminal.
+    Auto,
+    Quiet,
+    Always,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(StatusMode::Auto as u8);
+
+pub fn set_mode(mode: StatusMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn mode() -> StatusMode {
+    match MODE.load(Ordering::Relaxed) {
+        m if m == StatusMode::Quiet as u8 => StatusMode::Quiet,
+        m if m == StatusMode::Always as u8 => StatusMode::Always,
+        _ => StatusMode::Auto,
+    }
+}
+
+/// Whether a status line should be written to stderr right now.
+pub fn enabled() -> bool {
+    match mode() {
+        StatusMode::Quiet => false,
+        StatusMode::Always => true,
+        StatusMode::Auto => color::is_terminal(Stream::Stderr),
+    }
+}
+
+/// Resolve the mode from the CLI flags and `HOMEBOY_QUIET`. Flags win over
+/// the environment.
+pub fn resolve_mode(quiet: bool, status_always: bool, env_quiet: Option<&str>) -> StatusMode {
+    if status_always {
+        StatusMode::Always
+    } else if quiet || env_quiet.is_some_and(is_truthy) {
+        StatusMode::Quiet
+    } else {
+        StatusMode::Auto
+    }
+}
+
+fn is_truthy(value: &str) -> bool {
+    !matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "false" | "no" | "off"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_mode() {
+        assert_eq!(resolve_mode(false, false, None), StatusMode::Auto);
+        assert_eq!(resolve_mode(true, false, None), StatusMode::Quiet);
+        assert_eq!(resolve_mode(false, false, Some("1")), StatusMode::Quiet);
+        assert_eq!(resolve_mode(false, false, Some("false")), StatusMode::Auto);
+        assert_eq!(resolve_mode(false, true, Some("1")), StatusMode::Always);
+    }
+}
@@ -9,12 +9,23 @@
 //! The on-disk filenames (`update_check.json`, `extension_update_check.json`)
 //! and JSON schemas are owned by the callers and are intentionally not
 //! changed here so existing user caches keep working.
+//!
+//! Network refreshes run on a background thread ([`spawn_refresh`]) so a
+//! slow network never delays the command; results land in the cache and are
+//! reported on the next invocation. Callers stamp `checked_at` before
+//! spawning, so a refresh abandoned at exit waits for the next interval
+//! instead of restarting on every command.
 
 use crate::paths;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::path::PathBuf;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// How long a background refresh may hold up process exit, counted from
+/// when it started. Commands that outlast it never wait at all.
+const REFRESH_GRACE: Duration = Duration::from_millis(750);
 
 /// Current unix timestamp in seconds. Returns `0` if the system clock is
 /// before the epoch (matching the prior per-module behavior).
@@ -51,7 +62,54 @@ pub fn write_cache<T: Serialize>(filename: &str, payload: &T) {
     let Ok(content) = serde_json::to_string_pretty(payload) else {
         return;
     };
-    let _ = std::fs::write(&path, content);
+    let _ = crate::engine::local_files::write_scratch_file_atomic(
+        &path,
+        &content,
+        "write update check cache",
+    );
+}
+
+/// Seconds between update checks (`update_check_interval_hours`, default 24).
+pub fn check_interval_secs() -> u64 {
+    crate::defaults::load_config()
+        .update_check_interval_hours
+        .saturating_mul(3600)
+}
+
+/// A cache refresh running beside the command.
+///
+/// Dropping it waits for the refresh only until [`REFRESH_GRACE`] after it
+/// started; an unfinished refresh is abandoned with the process and retried
+/// once the check interval has passed again.
+pub struct BackgroundRefresh {
+    handle: JoinHandle<()>,
+    deadline: Instant,
+}
+
+impl BackgroundRefresh {
+    pub fn is_finished(&self) -> bool {
+        self.handle.is_finished()
+    }
+}
+
+impl Drop for BackgroundRefresh {
+    fn drop(&mut self) {
+        while !self.handle.is_finished() && Instant::now() < self.deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+/// Run `refresh` on a background thread. Hold the returned guard for the
+/// life of the command.
+pub fn spawn_refresh<F>(refresh: F) -> BackgroundRefresh
+where
+    F: FnOnce() + Send + 'static,
+{
+    BackgroundRefresh {
+        deadline: Instant::now() + REFRESH_GRACE,
+        handle: std::thread::spawn(refresh),
+    }
 }
 
 /// Pure time-based freshness check: `now - checked_at < interval_secs`.
@@ -89,6 +147,22 @@ mod tests {
         assert!(!is_cache_fresh(now_unix().saturating_sub(200), 100));
     }
 
+    #[test]
+    fn background_refresh_does_not_block_past_grace() {
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+        let started = Instant::now();
+        let refresh = spawn_refresh(move || {
+            let _ = wait.recv_timeout(Duration::from_secs(30));
+        });
+        assert!(!refresh.is_finished());
+
+        drop(refresh);
+        let waited = started.elapsed();
+        assert!(waited >= REFRESH_GRACE);
+        assert!(waited < Duration::from_secs(5), "waited {:?}", waited);
+        drop(release);
+    }
+
     #[test]
     fn stale_when_clock_skew() {
         // checked_at in the future — saturating_sub yields 0, treated as fresh.
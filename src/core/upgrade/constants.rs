@@ -4,3 +4,11 @@ pub(crate) const CRATES_IO_API: &str = "https://crates.io/api/v1/crates/homeboy"
 
 pub(crate) const GITHUB_RELEASES_API: &str =
     "https://api.github.com/repos/Extra-Chill/homeboy/releases/latest";
+
+/// Recent releases, pre-releases included, for the beta channel.
+pub(crate) const GITHUB_RELEASE_LIST_API: &str =
+    "https://api.github.com/repos/Extra-Chill/homeboy/releases?per_page=30";
+
+/// Exact version handed to the install method's upgrade command, when one
+/// was selected (a pin, the beta channel, or a downgrade).
+pub(crate) const UPGRADE_VERSION_ENV: &str = "HOMEBOY_UPGRADE_VERSION";
@@ -3,25 +3,38 @@ use crate::error::{Error, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::constants::UPGRADE_VERSION_ENV;
 use super::helpers::{current_version, version_is_newer};
 use super::types::InstallMethod;
 
+/// Run the install method's upgrade command. `version` is exported to it as
+/// `HOMEBOY_UPGRADE_VERSION`; without one the command installs the latest
+/// stable release.
 pub(crate) fn execute_upgrade(
     method: InstallMethod,
     source_path: Option<&Path>,
+    version: Option<&str>,
 ) -> Result<(bool, Option<String>)> {
     let defaults = defaults::load_defaults();
+    let shell = |cmd: &str| {
+        let mut command = Command::new("sh");
+        command.args(["-c", cmd]);
+        if let Some(version) = version {
+            command.env(UPGRADE_VERSION_ENV, version);
+        }
+        command
+    };
 
     let output = match method {
         InstallMethod::Homebrew => {
             let cmd = &defaults.install_methods.homebrew.upgrade_command;
-            Command::new("sh").args(["-c", cmd]).output().map_err(|e| {
+            shell(cmd).output().map_err(|e| {
                 Error::internal_io(e.to_string(), Some("run homebrew upgrade".to_string()))
             })?
         }
         InstallMethod::Cargo => {
             let cmd = &defaults.install_methods.cargo.upgrade_command;
-            Command::new("sh").args(["-c", cmd]).output().map_err(|e| {
+            shell(cmd).output().map_err(|e| {
                 Error::internal_io(e.to_string(), Some("run cargo upgrade".to_string()))
             })?
         }
@@ -30,8 +43,7 @@ pub(crate) fn execute_upgrade(
 
             // Execute the upgrade command from defaults
             let cmd = &defaults.install_methods.source.upgrade_command;
-            Command::new("sh")
-                .args(["-c", cmd])
+            shell(cmd)
                 .current_dir(&workspace_root)
                 .output()
                 .map_err(|e| {
@@ -40,7 +52,7 @@ pub(crate) fn execute_upgrade(
         }
         InstallMethod::Binary => {
             let cmd = &defaults.install_methods.binary.upgrade_command;
-            Command::new("sh").args(["-c", cmd]).output().map_err(|e| {
+            shell(cmd).output().map_err(|e| {
                 Error::internal_io(e.to_string(), Some("run binary upgrade".to_string()))
             })?
         }
@@ -68,7 +80,11 @@ pub(crate) fn execute_upgrade(
     }
 
     let new_version = active_binary_version().ok().flatten();
-    let success = upgrade_verification_result(current_version(), new_version.as_deref());
+    let success = match version {
+        // A pinned or downgraded install succeeds when exactly that version is active.
+        Some(expected) => new_version.as_deref() == Some(expected),
+        None => upgrade_verification_result(current_version(), new_version.as_deref()),
+    };
 
     Ok((success, new_version))
 }
@@ -215,7 +231,7 @@ pub(crate) fn upgrade_verification_result(
 }
 
 fn parse_cli_version_output(output: &str) -> Option<String> {
-    let re = regex::Regex::new(r"(\d+\.\d+\.\d+)").ok()?;
+    let re = regex::Regex::new(r"(\d+\.\d+\.\d+(?:-[0-9A-Za-z.-]+)?)").ok()?;
     re.find(output).map(|m| m.as_str().to_string())
 }
 
@@ -231,6 +247,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parses_prerelease_version_output() {
+        assert_eq!(
+            parse_cli_version_output("homeboy 0.160.0-beta.2\n").as_deref(),
+            Some("0.160.0-beta.2")
+        );
+    }
+
     #[test]
     fn test_execute_upgrade() {
         assert_eq!(
@@ -3,7 +3,7 @@ use crate::error::{Error, Result};
 use std::path::Path;
 use std::process::Command;
 
-use super::constants::{CRATES_IO_API, GITHUB_RELEASES_API, VERSION};
+use super::constants::{CRATES_IO_API, GITHUB_RELEASES_API, GITHUB_RELEASE_LIST_API, VERSION};
 use super::execution::execute_upgrade;
 use super::planning::resolve_binary_on_path;
 use super::types::*;
@@ -13,7 +13,12 @@ pub fn current_version() -> &'static str {
     VERSION
 }
 
-pub(crate) fn fetch_latest_crates_io_version() -> Result<String> {
+/// The `upgrade.channel` from homeboy.json (stable unless configured).
+pub fn configured_channel() -> ReleaseChannel {
+    defaults::load_config().upgrade.channel
+}
+
+pub(crate) fn fetch_latest_crates_io_version(channel: ReleaseChannel) -> Result<String> {
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!("homeboy/{}", VERSION))
         .timeout(std::time::Duration::from_secs(10))
@@ -29,43 +34,81 @@ pub(crate) fn fetch_latest_crates_io_version() -> Result<String> {
             Error::internal_json(e.to_string(), Some("parse crates.io response".to_string()))
         })?;
 
-    Ok(response.crate_info.newest_version)
+    let info = response.crate_info;
+    let version = match channel {
+        ReleaseChannel::Stable => info.max_stable_version,
+        ReleaseChannel::Beta => info.max_version,
+    };
+    Ok(version.unwrap_or(info.newest_version))
 }
 
-pub(crate) fn fetch_latest_github_version() -> Result<String> {
+pub(crate) fn fetch_latest_github_version(channel: ReleaseChannel) -> Result<String> {
     let client = reqwest::blocking::Client::builder()
         .user_agent(format!("homeboy/{}", VERSION))
         .timeout(std::time::Duration::from_secs(10))
         .build()
         .map_err(|e| Error::internal_io(e.to_string(), Some("create HTTP client".to_string())))?;
 
-    let response: GitHubRelease = client
-        .get(GITHUB_RELEASES_API)
-        .send()
-        .map_err(|e| Error::internal_io(e.to_string(), Some("query GitHub releases".to_string())))?
-        .json()
-        .map_err(|e| {
-            Error::internal_json(
-                e.to_string(),
-                Some("parse GitHub release response".to_string()),
-            )
-        })?;
+    // `/releases/latest` never returns a pre-release, so the beta channel
+    // picks the newest of the recent releases instead.
+    let url = match channel {
+        ReleaseChannel::Stable => GITHUB_RELEASES_API,
+        ReleaseChannel::Beta => GITHUB_RELEASE_LIST_API,
+    };
+    let response = client.get(url).send().map_err(|e| {
+        Error::internal_io(e.to_string(), Some("query GitHub releases".to_string()))
+    })?;
+    let parse_error = |e: reqwest::Error| {
+        Error::internal_json(
+            e.to_string(),
+            Some("parse GitHub release response".to_string()),
+        )
+    };
 
-    // Strip "v" prefix if present (e.g., "v0.15.0" -> "0.15.0")
-    let version = response
-        .tag_name
-        .strip_prefix('v')
-        .unwrap_or(&response.tag_name);
-    Ok(version.to_string())
+    match channel {
+        ReleaseChannel::Stable => {
+            let release: GitHubRelease = response.json().map_err(parse_error)?;
+            Ok(strip_tag_prefix(&release.tag_name).to_string())
+        }
+        ReleaseChannel::Beta => {
+            let releases: Vec<GitHubRelease> = response.json().map_err(parse_error)?;
+            newest_release(&releases).ok_or_else(|| {
+                Error::internal_io(
+                    "No published releases found".to_string(),
+                    Some("query GitHub releases".to_string()),
+                )
+            })
+        }
+    }
+}
+
+/// Strip a "v" tag prefix (e.g., "v0.15.0" -> "0.15.0").
+fn strip_tag_prefix(tag: &str) -> &str {
+    tag.strip_prefix('v').unwrap_or(tag)
 }
 
-pub fn fetch_latest_version(method: InstallMethod) -> Result<String> {
+/// Highest published version among `releases`, pre-releases included.
+pub(crate) fn newest_release(releases: &[GitHubRelease]) -> Option<String> {
+    releases
+        .iter()
+        .filter(|release| !release.draft)
+        .map(|release| strip_tag_prefix(&release.tag_name))
+        .filter_map(|version| {
+            semver::Version::parse(version)
+                .ok()
+                .map(|parsed| (parsed, version))
+        })
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, version)| version.to_string())
+}
+
+pub fn fetch_latest_version(method: InstallMethod, channel: ReleaseChannel) -> Result<String> {
     match method {
-        InstallMethod::Cargo => fetch_latest_crates_io_version(),
+        InstallMethod::Cargo => fetch_latest_crates_io_version(channel),
         InstallMethod::Homebrew
         | InstallMethod::Source
         | InstallMethod::Binary
-        | InstallMethod::Unknown => fetch_latest_github_version(),
+        | InstallMethod::Unknown => fetch_latest_github_version(channel),
     }
 }
 
@@ -129,6 +172,12 @@ where
 }
 
 pub(crate) fn version_is_newer(latest: &str, current: &str) -> bool {
+    // Semver first, so pre-releases order below their release.
+    let semver = |v: &str| semver::Version::parse(strip_tag_prefix(v)).ok();
+    if let (Some(l), Some(c)) = (semver(latest), semver(current)) {
+        return l > c;
+    }
+
     let parse = |v: &str| -> Option<(u32, u32, u32)> {
         let parts: Vec<&str> = v.split('.').collect();
         if parts.len() >= 3 {
@@ -153,9 +202,16 @@ pub fn run_upgrade_with_method(
     method_override: Option<InstallMethod>,
     skip_extensions: bool,
     source_path: Option<&Path>,
+    selection: &ReleaseSelection,
 ) -> Result<UpgradeResult> {
     let install_method = method_override.unwrap_or_else(detect_install_method);
     let previous_version = current_version().to_string();
+    let channel = selection.channel.unwrap_or_else(configured_channel);
+    let pinned = selection
+        .version
+        .as_deref()
+        .map(parse_pinned_version)
+        .transpose()?;
 
     if install_method == InstallMethod::Unknown {
         return Err(Error::validation_invalid_argument(
@@ -169,35 +225,59 @@ pub fn run_upgrade_with_method(
         .with_hint("Or: cargo install homeboy"));
     }
 
+    ensure_method_honors_selection(install_method, selection, channel)?;
+
+    // The release to install. `None` leaves the choice to the upgrade
+    // command (the latest stable release).
+    let target = match &pinned {
+        Some(version) => Some(version.clone()),
+        None if force && channel == ReleaseChannel::Stable => None,
+        None => check_for_updates(channel)?.latest_version,
+    };
+
     // Check if update is available (unless forcing)
-    if !force {
-        let check = check_for_updates()?;
-        if !check.update_available {
-            // Even when no binary update is needed, still run extension updates
-            // and config migrations — these are independent of the binary version.
-            let (extensions_updated, extensions_skipped) = if skip_extensions {
-                (vec![], vec![])
-            } else {
-                update_all_extensions()
-            };
-            let projects_migrated = migrate_all_projects();
-            return Ok(UpgradeResult {
-                command: "upgrade".to_string(),
-                install_method,
-                previous_version: previous_version.clone(),
-                new_version: Some(previous_version),
-                upgraded: false,
-                message: "Already at latest version".to_string(),
-                restart_required: false,
-                extensions_updated,
-                extensions_skipped,
-                projects_migrated,
-            });
-        }
+    if let Some(message) = upgrade_skip_reason(
+        &previous_version,
+        target.as_deref(),
+        pinned.is_some(),
+        channel,
+        force,
+        selection.allow_downgrade,
+    )? {
+        // Even when no binary update is needed, still run extension updates
+        // and config migrations — these are independent of the binary version.
+        let (extensions_updated, extensions_skipped) = if skip_extensions {
+            (vec![], vec![])
+        } else {
+            update_all_extensions()
+        };
+        let projects_migrated = migrate_all_projects();
+        return Ok(UpgradeResult {
+            command: "upgrade".to_string(),
+            install_method,
+            channel,
+            previous_version: previous_version.clone(),
+            new_version: Some(previous_version),
+            upgraded: false,
+            message,
+            restart_required: false,
+            extensions_updated,
+            extensions_skipped,
+            projects_migrated,
+        });
     }
 
+    // Only name a version when it differs from what the command fetches by
+    // default, so configured upgrade commands keep working unchanged.
+    let downgrade = target
+        .as_deref()
+        .is_some_and(|target| version_is_newer(&previous_version, target));
+    let install_version = target
+        .as_deref()
+        .filter(|_| pinned.is_some() || channel == ReleaseChannel::Beta || downgrade);
+
     // Execute the upgrade
-    let (success, new_version) = execute_upgrade(install_method, source_path)?;
+    let (success, new_version) = execute_upgrade(install_method, source_path, install_version)?;
 
     // Auto-update all installed extensions after a successful upgrade.
     // This prevents CI/local extension version drift that causes baseline
@@ -216,11 +296,16 @@ pub fn run_upgrade_with_method(
     Ok(UpgradeResult {
         command: "upgrade".to_string(),
         install_method,
+        channel,
         previous_version,
         new_version: new_version.clone(),
         upgraded: success,
         message: if success {
-            format!("Upgraded to {}", new_version.as_deref().unwrap_or("latest"))
+            format!(
+                "{} to {}",
+                if downgrade { "Downgraded" } else { "Upgraded" },
+                new_version.as_deref().unwrap_or("latest")
+            )
         } else if let Some(version) = &new_version {
             format!(
                 "Upgrade command completed but active binary is still {}",
@@ -236,6 +321,102 @@ pub fn run_upgrade_with_method(
     })
 }
 
+/// Refuse `--version`, the beta channel and `--allow-downgrade` up front for
+/// install methods whose upgrade command always fetches the latest stable
+/// release, instead of running it and failing verification afterwards.
+pub(crate) fn ensure_method_honors_selection(
+    install_method: InstallMethod,
+    selection: &ReleaseSelection,
+    channel: ReleaseChannel,
+) -> Result<()> {
+    if install_method != InstallMethod::Homebrew {
+        return Ok(());
+    }
+    let requested = if selection.version.is_some() {
+        "--version"
+    } else if channel == ReleaseChannel::Beta {
+        "the beta channel"
+    } else if selection.allow_downgrade {
+        "--allow-downgrade"
+    } else {
+        return Ok(());
+    };
+    Err(Error::validation_invalid_argument(
+        "version",
+        format!(
+            "Homebrew installs only track the latest stable release; {} is not supported",
+            requested
+        ),
+        selection.version.clone(),
+        None,
+    )
+    .with_hint("Try: homeboy upgrade --method binary")
+    .with_hint("Or stay on the stable channel: homeboy upgrade --channel stable"))
+}
+
+fn parse_pinned_version(version: &str) -> Result<String> {
+    let version = strip_tag_prefix(version.trim());
+    semver::Version::parse(version)
+        .map(|_| version.to_string())
+        .map_err(|e| {
+            Error::validation_invalid_argument(
+                "version",
+                format!("Invalid version '{}': {}", version, e),
+                Some(version.to_string()),
+                None,
+            )
+            .with_hint("Use a full version, e.g. --version 0.158.0")
+        })
+}
+
+/// Why `upgrade` should leave the installed binary alone, or `None` to
+/// install `target`. A pinned version older than `current` is refused
+/// outright unless downgrades are allowed.
+pub(crate) fn upgrade_skip_reason(
+    current: &str,
+    target: Option<&str>,
+    pinned: bool,
+    channel: ReleaseChannel,
+    force: bool,
+    allow_downgrade: bool,
+) -> Result<Option<String>> {
+    let Some(target) = target else {
+        // No known target: forced upgrades run the default command, anything
+        // else (e.g. the release lookup failed) is a no-op.
+        return Ok((!force).then(|| "Already at latest version".to_string()));
+    };
+
+    if version_is_newer(current, target) {
+        if allow_downgrade {
+            return Ok(None);
+        }
+        let message = format!("Homeboy {} is older than the installed {}", target, current);
+        if pinned {
+            return Err(Error::validation_invalid_argument(
+                "version",
+                message,
+                Some(target.to_string()),
+                None,
+            )
+            .with_hint("Pass --allow-downgrade to install it anyway"));
+        }
+        return Ok(Some(format!(
+            "{}; pass --allow-downgrade to switch to the latest {} release",
+            message,
+            channel.as_str()
+        )));
+    }
+
+    if !force && !version_is_newer(target, current) {
+        return Ok(Some(if pinned {
+            format!("Already at {}", target)
+        } else {
+            "Already at latest version".to_string()
+        }));
+    }
+    Ok(None)
+}
+
 /// Migrate all flat-file projects to directory-based layout.
 /// Best-effort — failures are logged and returned in the result.
 fn migrate_all_projects() -> Vec<ProjectMigrationEntry> {
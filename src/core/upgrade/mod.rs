@@ -7,8 +7,8 @@ pub mod update_check;
 mod validation;
 
 pub use helpers::{
-    current_version, detect_install_method, fetch_latest_version, restart_with_new_binary,
-    run_upgrade_with_method,
+    configured_channel, current_version, detect_install_method, fetch_latest_version,
+    restart_with_new_binary, run_upgrade_with_method,
 };
 pub use planning::resolve_binary_on_path;
 pub use types::*;
@@ -41,6 +41,101 @@ mod tests {
         assert!(!helpers::version_is_newer("0.10.0", "0.11.0"));
     }
 
+    #[test]
+    fn test_version_comparison_orders_prereleases() {
+        assert!(helpers::version_is_newer("0.12.0", "0.12.0-beta.1"));
+        assert!(helpers::version_is_newer("0.12.0-beta.2", "0.12.0-beta.1"));
+        assert!(helpers::version_is_newer("0.12.0-beta.1", "0.11.0"));
+        assert!(!helpers::version_is_newer("0.11.0", "0.12.0-beta.1"));
+    }
+
+    #[test]
+    fn test_newest_release_includes_prereleases_but_not_drafts() {
+        let release = |tag: &str, draft: bool| types::GitHubRelease {
+            tag_name: tag.to_string(),
+            draft,
+        };
+        let releases = vec![
+            release("v0.11.0", false),
+            release("v0.12.0-beta.1", false),
+            release("v0.13.0", true),
+            release("nightly", false),
+        ];
+
+        assert_eq!(
+            helpers::newest_release(&releases).as_deref(),
+            Some("0.12.0-beta.1")
+        );
+    }
+
+    #[test]
+    fn test_upgrade_skip_reason() {
+        let skip = |target, pinned, force, allow_downgrade| {
+            helpers::upgrade_skip_reason(
+                "0.12.0",
+                target,
+                pinned,
+                ReleaseChannel::Stable,
+                force,
+                allow_downgrade,
+            )
+        };
+
+        assert_eq!(skip(Some("0.13.0"), false, false, false).unwrap(), None);
+        assert_eq!(
+            skip(Some("0.12.0"), true, false, false).unwrap().as_deref(),
+            Some("Already at 0.12.0")
+        );
+        assert_eq!(skip(Some("0.12.0"), true, true, false).unwrap(), None);
+        assert_eq!(
+            skip(None, false, false, false).unwrap().as_deref(),
+            Some("Already at latest version")
+        );
+        assert_eq!(skip(None, false, true, false).unwrap(), None);
+
+        let err = skip(Some("0.11.0"), true, false, false).unwrap_err();
+        assert!(err.message.contains("older than the installed 0.12.0"));
+        assert!(err
+            .hints
+            .iter()
+            .any(|hint| hint.message.contains("--allow-downgrade")));
+        assert_eq!(skip(Some("0.11.0"), true, false, true).unwrap(), None);
+
+        // An unpinned channel whose newest release is older is left alone.
+        let reason = skip(Some("0.11.0"), false, false, false).unwrap().unwrap();
+        assert!(reason.contains("--allow-downgrade"));
+    }
+
+    #[test]
+    fn test_ensure_method_honors_selection() {
+        let selection = |version: Option<&str>, allow_downgrade| ReleaseSelection {
+            channel: None,
+            version: version.map(str::to_string),
+            allow_downgrade,
+        };
+        let check = |method, selection: &ReleaseSelection, channel| {
+            helpers::ensure_method_honors_selection(method, selection, channel)
+        };
+
+        let plain = selection(None, false);
+        assert!(check(InstallMethod::Homebrew, &plain, ReleaseChannel::Stable).is_ok());
+        assert!(check(InstallMethod::Homebrew, &plain, ReleaseChannel::Beta).is_err());
+        let pinned = selection(Some("0.11.0"), false);
+        assert!(check(InstallMethod::Homebrew, &pinned, ReleaseChannel::Stable).is_err());
+        let downgrade = selection(None, true);
+        let err = check(InstallMethod::Homebrew, &downgrade, ReleaseChannel::Stable).unwrap_err();
+        assert!(err.message.contains("--allow-downgrade"));
+
+        for method in [
+            InstallMethod::Cargo,
+            InstallMethod::Source,
+            InstallMethod::Binary,
+        ] {
+            assert!(check(method, &pinned, ReleaseChannel::Beta).is_ok());
+            assert!(check(method, &downgrade, ReleaseChannel::Stable).is_ok());
+        }
+    }
+
     #[test]
     fn test_current_version() {
         let version = current_version();
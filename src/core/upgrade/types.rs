@@ -13,10 +13,44 @@ pub enum InstallMethod {
     Unknown,
 }
 
+/// Which releases `upgrade` and the startup update check consider.
+///
+/// Set the default with `homeboy config set /upgrade/channel beta`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReleaseChannel {
+    /// Tagged releases only.
+    #[default]
+    Stable,
+    /// Pre-releases (`x.y.z-beta.n`) as well as stable releases.
+    Beta,
+}
+
+impl ReleaseChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Beta => "beta",
+        }
+    }
+}
+
+/// Which release `upgrade` installs.
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseSelection {
+    /// `None` uses the configured channel.
+    pub channel: Option<ReleaseChannel>,
+    /// Install exactly this version instead of the channel's newest.
+    pub version: Option<String>,
+    /// Allow installing a version older than the running one.
+    pub allow_downgrade: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 
 pub struct VersionCheck {
     pub command: String,
+    pub channel: ReleaseChannel,
     pub current_version: String,
     pub latest_version: Option<String>,
     pub update_available: bool,
@@ -28,6 +62,7 @@ pub struct VersionCheck {
 pub struct UpgradeResult {
     pub command: String,
     pub install_method: InstallMethod,
+    pub channel: ReleaseChannel,
     pub previous_version: String,
     pub new_version: Option<String>,
     pub upgraded: bool,
@@ -71,9 +106,15 @@ pub(super) struct CratesIoResponse {
 #[derive(Deserialize)]
 pub(super) struct CrateInfo {
     pub(super) newest_version: String,
+    #[serde(default)]
+    pub(super) max_version: Option<String>,
+    #[serde(default)]
+    pub(super) max_stable_version: Option<String>,
 }
 
 #[derive(Deserialize)]
 pub(super) struct GitHubRelease {
     pub(super) tag_name: String,
+    #[serde(default)]
+    pub(super) draft: bool,
 }
@@ -2,8 +2,14 @@
 //!
 //! On every command invocation, reads a local cache file. If the cache indicates
 //! an update is available, prints a one-line hint to stderr. If the cache is stale
-//! (older than 24 hours) or missing, fetches the latest version from the network
-//! and refreshes the cache.
+//! (older than `update_check_interval_hours`, default 24) or missing, fetches the
+//! latest version on a background thread and refreshes the cache; the command
+//! never waits on the network, and a new release is announced next time. The
+//! cache is stamped before the refresh starts, so a refresh cut short by the
+//! command exiting is retried after the next interval rather than on every run.
+//!
+//! Only releases on the configured channel (`upgrade.channel`, stable by
+//! default) are considered, so stable users are not told about betas.
 //!
 //! Disable via:
 //! - Environment variable: `HOMEBOY_NO_UPDATE_CHECK=1`
@@ -13,12 +19,11 @@
 //! [`crate::core::update_check_cache`]. The on-disk filename and JSON
 //! schema live here and are unchanged.
 
-use crate::core::update_check_cache;
-use crate::upgrade;
+use crate::core::update_check_cache::{self, BackgroundRefresh};
+use crate::upgrade::{self, ReleaseChannel};
 use serde::{Deserialize, Serialize};
 
 const CACHE_FILENAME: &str = "update_check.json";
-const CHECK_INTERVAL_SECS: u64 = 86400;
 const ENV_VAR_DISABLE: &str = "HOMEBOY_NO_UPDATE_CHECK";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +32,9 @@ pub struct UpdateCheckCache {
     pub current_version: String,
     pub update_available: bool,
     pub checked_at: u64,
+    /// Channel the check ran against; caches older than this field are stable.
+    #[serde(default)]
+    pub channel: ReleaseChannel,
 }
 
 fn read_cache() -> Option<UpdateCheckCache> {
@@ -37,9 +45,15 @@ fn write_cache(cache: &UpdateCheckCache) {
     update_check_cache::write_cache(CACHE_FILENAME, cache);
 }
 
-fn is_cache_fresh(cache: &UpdateCheckCache) -> bool {
-    update_check_cache::is_cache_fresh(cache.checked_at, CHECK_INTERVAL_SECS)
+fn is_cache_fresh(cache: &UpdateCheckCache, channel: ReleaseChannel) -> bool {
+    update_check_cache::is_cache_fresh(cache.checked_at, update_check_cache::check_interval_secs())
         && cache.current_version == upgrade::current_version()
+        && cache.channel == channel
+}
+
+/// Whether a cached result still applies to this binary and channel.
+fn cache_applies(cache: &UpdateCheckCache, channel: ReleaseChannel) -> bool {
+    cache.current_version == upgrade::current_version() && cache.channel == channel
 }
 
 fn is_disabled_by_env() -> bool {
@@ -61,26 +75,52 @@ fn print_hint(latest: &str, current: &str) {
     );
 }
 
-pub fn run_startup_check() {
+/// Print any cached hint and, when the cache is stale, start a background
+/// refresh. Hold the returned guard until the command finishes.
+pub fn run_startup_check() -> Option<BackgroundRefresh> {
     if is_disabled_by_env() || is_disabled_by_config() {
-        return;
+        return None;
     }
 
-    let mut already_printed = false;
-    let cached = read_cache();
-
-    if let Some(ref cache) = cached {
-        if cache.update_available && cache.current_version == upgrade::current_version() {
+    let channel = upgrade::configured_channel();
+    let cache = read_cache();
+    if let Some(cache) = &cache {
+        if cache.update_available && cache_applies(cache, channel) {
             print_hint(&cache.latest_version, upgrade::current_version());
-            already_printed = true;
         }
 
-        if is_cache_fresh(cache) {
-            return;
+        if is_cache_fresh(cache, channel) {
+            return None;
         }
     }
 
-    let check = match upgrade::check_for_updates() {
+    write_cache(&refresh_started(cache, channel));
+    Some(update_check_cache::spawn_refresh(move || refresh(channel)))
+}
+
+/// The cache to write before a refresh starts: the previous result, if it
+/// still applies, stamped with the current time. Commands that start while
+/// the refresh is running, or after it was abandoned at exit, see a fresh
+/// cache and do not repeat the network check until the next interval.
+fn refresh_started(cache: Option<UpdateCheckCache>, channel: ReleaseChannel) -> UpdateCheckCache {
+    let checked_at = update_check_cache::now_unix();
+    match cache {
+        Some(cache) if cache_applies(&cache, channel) => UpdateCheckCache {
+            checked_at,
+            ..cache
+        },
+        _ => UpdateCheckCache {
+            latest_version: String::new(),
+            current_version: upgrade::current_version().to_string(),
+            update_available: false,
+            checked_at,
+            channel,
+        },
+    }
+}
+
+fn refresh(channel: ReleaseChannel) {
+    let check = match upgrade::check_for_updates(channel) {
         Ok(check) => check,
         Err(_) => return,
     };
@@ -90,13 +130,8 @@ pub fn run_startup_check() {
         current_version: check.current_version.clone(),
         update_available: check.update_available,
         checked_at: update_check_cache::now_unix(),
+        channel,
     });
-
-    if !already_printed && check.update_available {
-        if let Some(latest) = &check.latest_version {
-            print_hint(latest, &check.current_version);
-        }
-    }
 }
 
 #[cfg(test)]
@@ -119,4 +154,46 @@ mod tests {
 
         std::env::remove_var(ENV_VAR_DISABLE);
     }
+
+    #[test]
+    fn test_cache_applies_only_to_its_channel() {
+        let cache: UpdateCheckCache = serde_json::from_value(serde_json::json!({
+            "latest_version": "99.0.0",
+            "current_version": upgrade::current_version(),
+            "update_available": true,
+            "checked_at": update_check_cache::now_unix(),
+        }))
+        .unwrap();
+
+        assert_eq!(cache.channel, ReleaseChannel::Stable);
+        assert!(cache_applies(&cache, ReleaseChannel::Stable));
+        assert!(is_cache_fresh(&cache, ReleaseChannel::Stable));
+        assert!(!cache_applies(&cache, ReleaseChannel::Beta));
+        assert!(!is_cache_fresh(&cache, ReleaseChannel::Beta));
+    }
+
+    #[test]
+    fn test_refresh_started_stamps_a_fresh_cache() {
+        let stale = UpdateCheckCache {
+            latest_version: "99.0.0".to_string(),
+            current_version: upgrade::current_version().to_string(),
+            update_available: true,
+            checked_at: 0,
+            channel: ReleaseChannel::Stable,
+        };
+
+        let kept = refresh_started(Some(stale.clone()), ReleaseChannel::Stable);
+        assert!(is_cache_fresh(&kept, ReleaseChannel::Stable));
+        assert_eq!(kept.latest_version, "99.0.0");
+        assert!(kept.update_available);
+
+        let switched = refresh_started(Some(stale), ReleaseChannel::Beta);
+        assert!(is_cache_fresh(&switched, ReleaseChannel::Beta));
+        assert!(!switched.update_available);
+
+        assert!(is_cache_fresh(
+            &refresh_started(None, ReleaseChannel::Stable),
+            ReleaseChannel::Stable
+        ));
+    }
 }
@@ -3,13 +3,13 @@ use crate::error::Result;
 use super::helpers::{
     current_version, detect_install_method, fetch_latest_version, version_is_newer,
 };
-use super::types::VersionCheck;
+use super::types::{ReleaseChannel, VersionCheck};
 
-pub fn check_for_updates() -> Result<VersionCheck> {
+pub fn check_for_updates(channel: ReleaseChannel) -> Result<VersionCheck> {
     let install_method = detect_install_method();
     let current = current_version().to_string();
 
-    let latest = fetch_latest_version(install_method).ok();
+    let latest = fetch_latest_version(install_method, channel).ok();
     let update_available = latest
         .as_ref()
         .map(|l| version_is_newer(l, &current))
@@ -17,6 +17,7 @@ pub fn check_for_updates() -> Result<VersionCheck> {
 
     Ok(VersionCheck {
         command: "upgrade.check".to_string(),
+        channel,
         current_version: current,
         latest_version: latest,
         update_available,
@@ -1,4 +1,7 @@
-/// Macro for prefixed status logging to stderr (only when stderr is a terminal).
+/// Macro for prefixed status logging to stderr.
+///
+/// Prints only when stderr is a terminal, unless overridden by `--quiet` /
+/// `HOMEBOY_QUIET` or `--status-always` (see `status_log`).
 ///
 /// Usage:
 /// ```ignore
@@ -8,8 +11,16 @@
 #[macro_export]
 macro_rules! log_status {
     ($prefix:expr, $($arg:tt)*) => {
-        if ::std::io::IsTerminal::is_terminal(&::std::io::stderr()) {
-            eprintln!(concat!("[", $prefix, "] {}"), format_args!($($arg)*));
+        if $crate::core::status_log::enabled() {
+            eprintln!(
+                "{} {}",
+                $crate::core::output::color::paint(
+                    $crate::core::output::color::Stream::Stderr,
+                    $crate::core::output::color::Style::Cyan,
+                    concat!("[", $prefix, "]"),
+                ),
+                format_args!($($arg)*)
+            );
         }
     };
 }
@@ -7,14 +7,18 @@ use homeboy::cli_surface::{
 use homeboy::commands::GlobalArgs;
 
 use homeboy::commands;
-use homeboy::commands::utils::{args, entity_suggest, resource_policy, response as output, tty};
+use homeboy::commands::utils::{
+    args, component_picker, entity_suggest, output_schema, resource_policy, response as output, tty,
+};
 use homeboy::commands::{cli, review, trace};
-use homeboy::extension::load_all_extensions;
+use homeboy::extension::{load_all_extensions, CliOutputMode};
+use homeboy::output::color::{self, ColorChoice};
 
 struct ExtensionCliCommand {
     tool: String,
     project_id: String,
     args: Vec<String>,
+    output_mode: CliOutputMode,
 }
 
 struct ExtensionCliInfo {
@@ -24,6 +28,7 @@ struct ExtensionCliInfo {
     project_id_help: Option<String>,
     args_help: Option<String>,
     examples: Vec<String>,
+    output_mode: CliOutputMode,
 }
 
 fn collect_extension_cli_info() -> Vec<ExtensionCliInfo> {
@@ -40,6 +45,7 @@ fn collect_extension_cli_info() -> Vec<ExtensionCliInfo> {
                     project_id_help: help.project_id_help,
                     args_help: help.args_help,
                     examples: help.examples,
+                    output_mode: cli.output_mode,
                 }
             })
         })
@@ -96,9 +102,7 @@ fn try_parse_extension_cli_command(
 ) -> Option<ExtensionCliCommand> {
     let (tool, sub_matches) = matches.subcommand()?;
 
-    if !extension_info.iter().any(|m| m.tool == tool) {
-        return None;
-    }
+    let info = extension_info.iter().find(|m| m.tool == tool)?;
 
     let project_id = sub_matches.get_one::<String>("project_id")?.clone();
     let args: Vec<String> = sub_matches
@@ -110,16 +114,50 @@ fn try_parse_extension_cli_command(
         tool: tool.to_string(),
         project_id,
         args,
+        output_mode: info.output_mode,
     })
 }
 
+/// True when any subcommand on the matched path was given `--dry-run`.
+fn dry_run_requested(matches: &clap::ArgMatches) -> bool {
+    let mut current = Some(matches);
+    while let Some(m) = current {
+        if matches!(m.try_get_one::<bool>("dry_run"), Ok(Some(true))) {
+            return true;
+        }
+        current = m.subcommand().map(|(_, sub)| sub);
+    }
+    false
+}
+
 fn main() -> std::process::ExitCode {
+    let started = std::time::Instant::now();
+    let exit_code = run_cli(started);
+    if homeboy::profile::is_enabled() {
+        homeboy::profile::record("total", started.elapsed());
+        let pool = homeboy::server::ssh_pool_stats();
+        eprintln!(
+            "{}ssh pool: {} in flight, peak {} of {} jobs",
+            homeboy::profile::render_table(&homeboy::profile::timings()),
+            pool.in_flight,
+            pool.peak_in_flight,
+            pool.jobs
+        );
+    }
+    exit_code
+}
+
+fn run_cli(started: std::time::Instant) -> std::process::ExitCode {
     let extension_info = collect_extension_cli_info();
     let cmd = build_augmented_command(&extension_info);
 
     let args: Vec<String> = std::env::args().collect();
     let normalized = args::normalize(args);
 
+    if let Some(command) = print_schema_request(&normalized) {
+        return print_schema(command);
+    }
+
     let matches = match cmd.try_get_matches_from(normalized.clone()) {
         Ok(m) => m,
         Err(e) => {
@@ -131,6 +169,31 @@ fn main() -> std::process::ExitCode {
         }
     };
 
+    if let Some(command) = matches.subcommand_name() {
+        // clap's `exclusive` does not cover subcommands, so
+        // `--print-schema <cmd> <subcommand>` parses; refuse to run it.
+        if matches
+            .try_get_one::<String>("print_schema")
+            .ok()
+            .flatten()
+            .is_some()
+        {
+            let err = homeboy::Error::validation_invalid_argument(
+                "print_schema",
+                format!(
+                    "--print-schema takes the command name instead of running '{}'",
+                    command
+                ),
+                None,
+                None,
+            )
+            .with_hint(format!("Run: homeboy --print-schema {}", command));
+            output::print_result::<serde_json::Value>(Err(err)).ok();
+            return std::process::ExitCode::from(exit_code_to_u8(2));
+        }
+        output::set_schema_command(command);
+    }
+
     let global = GlobalArgs {};
 
     // Extract --output early so it's available for all code paths (including
@@ -148,23 +211,95 @@ fn main() -> std::process::ExitCode {
         .cloned();
     homeboy::set_artifact_root_override(artifact_root_override.clone());
 
+    if let Ok(Some(secs)) = matches.try_get_one::<u64>("lock_timeout") {
+        homeboy::config::set_lock_timeout_secs(*secs);
+    }
+
+    if let Ok(Some(jobs)) = matches.try_get_one::<usize>("jobs") {
+        homeboy::server::set_jobs(homeboy::server::resolve_jobs(
+            Some(*jobs),
+            std::env::var("HOMEBOY_JOBS").ok().as_deref(),
+        ));
+    }
+
+    homeboy::engine::local_files::set_dry_run(dry_run_requested(&matches));
+
+    let quiet = matches!(matches.try_get_one::<bool>("quiet"), Ok(Some(true)));
+    let status_always = matches!(matches.try_get_one::<bool>("status_always"), Ok(Some(true)));
+    homeboy::status_log::set_mode(homeboy::status_log::resolve_mode(
+        quiet,
+        status_always,
+        std::env::var("HOMEBOY_QUIET").ok().as_deref(),
+    ));
+
+    let color = if matches!(matches.try_get_one::<bool>("no_color"), Ok(Some(true))) {
+        Some(ColorChoice::Never)
+    } else {
+        matches
+            .try_get_one::<ColorChoice>("color")
+            .ok()
+            .flatten()
+            .copied()
+    };
+    color::set_choice(color::resolve_choice(
+        color,
+        &color::ColorEnv::from_process(),
+    ));
+
+    if let Ok(Some(true)) = matches.try_get_one::<bool>("profile_phases") {
+        homeboy::profile::enable();
+        homeboy::profile::record("startup", started.elapsed());
+    }
+
     if let Some(extension_cmd) = try_parse_extension_cli_command(&matches, &extension_info) {
         let cli_args = cli::CliArgs {
             tool: extension_cmd.tool,
             identifier: extension_cmd.project_id,
             args: extension_cmd.args,
         };
+
+        // Extensions that declare a markdown/raw output mode print the tool's
+        // stdout directly, unless `--output` or `--redact` needs the JSON envelope.
+        let redact = matches!(matches.try_get_one::<bool>("redact"), Ok(Some(true)));
+        if !extension_cmd.output_mode.is_json() && output_file.is_none() && !redact {
+            return match cli::run(cli_args, &global) {
+                Ok((output, exit_code)) => {
+                    print!("{}", output.result.stdout);
+                    // Markdown keeps stderr out of the document unless the
+                    // tool failed, so errors are never swallowed.
+                    if extension_cmd.output_mode == CliOutputMode::Raw || exit_code != 0 {
+                        eprint!("{}", output.result.stderr);
+                    }
+                    std::process::ExitCode::from(exit_code_to_u8(exit_code))
+                }
+                Err(err) => {
+                    output::print_result::<serde_json::Value>(Err(err)).ok();
+                    std::process::ExitCode::from(exit_code_to_u8(1))
+                }
+            };
+        }
+
         let result = cli::run(cli_args, &global);
 
         let (json_result, exit_code) = output::map_cmd_result_to_json(result);
+        let json_result = if redact {
+            redact_result(json_result)
+        } else {
+            json_result
+        };
         if let Some(ref path) = output_file {
             output::write_json_to_file(&json_result, path, exit_code);
         }
-        output::print_json_result(json_result, exit_code).ok();
+        output::print_json_result(
+            json_result,
+            exit_code,
+            &output::JsonOutputOptions::default(),
+        )
+        .ok();
         return std::process::ExitCode::from(exit_code_to_u8(exit_code));
     }
 
-    let cli = match Cli::from_arg_matches(&matches) {
+    let mut cli = match Cli::from_arg_matches(&matches) {
         Ok(cli) => cli,
         Err(e) => e.exit(),
     };
@@ -189,6 +324,13 @@ fn main() -> std::process::ExitCode {
         );
     }
 
+    if let Some((component_id, discovers_from_cwd)) = cli.command.omitted_component_id() {
+        if let Err(err) = component_picker::pick_if_omitted(component_id, discovers_from_cwd) {
+            output::print_result::<serde_json::Value>(Err(err)).ok();
+            return std::process::ExitCode::from(exit_code_to_u8(1));
+        }
+    }
+
     homeboy::set_artifact_root_override(cli.artifact_root.clone().or(artifact_root_override));
 
     if matches!(&cli.command, Commands::Runs(args) if args.is_bundle_export()) {
@@ -219,16 +361,38 @@ fn main() -> std::process::ExitCode {
         }
     }
 
-    // Startup update checks — skip for upgrade (it handles this itself)
-    if !matches!(
+    // Startup update checks — skip for upgrade (it handles this itself).
+    // Refreshes run in the background; holding the guards lets them finish
+    // briefly after the command instead of delaying it.
+    let _update_refreshes: Vec<_> = if !matches!(
         &cli.command,
         Commands::Upgrade(_) | Commands::Daemon(_) | Commands::SelfCmd(_)
     ) {
-        homeboy::upgrade::update_check::run_startup_check();
-        homeboy::extension::update_check::run_startup_check();
-    }
+        let _span = homeboy::profile::span("update_check");
+        [
+            homeboy::upgrade::update_check::run_startup_check(),
+            homeboy::extension::update_check::run_startup_check(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    } else {
+        Vec::new()
+    };
 
     let mode = cli.command.response_mode(output_file.is_some());
+    let json_options = output::JsonOutputOptions {
+        template: cli.output_template.clone(),
+        raw: cli.output_raw,
+        env: cli.output_env,
+        redact: cli.redact,
+        select: cli.select.clone(),
+    };
+    if let Err(err) = json_options.require_json_output(matches!(mode, CommandResponseMode::Json)) {
+        output::print_result::<serde_json::Value>(Err(err)).ok();
+        return std::process::ExitCode::from(exit_code_to_u8(2));
+    }
+    let strict_select = cli.strict_select;
     let output_artifact_policy = cli.command.output_artifact_policy(output_file.is_some());
 
     match mode {
@@ -268,6 +432,8 @@ fn main() -> std::process::ExitCode {
         }
     }
 
+    let _command_span = homeboy::profile::span("command");
+
     if let CommandResponseMode::Raw(CommandRawOutputMode::Markdown) = mode {
         let markdown_result = commands::run_markdown(cli.command, &global);
 
@@ -308,6 +474,28 @@ fn main() -> std::process::ExitCode {
         }
     };
 
+    let (json_result, output_json_result) = if json_options.redact {
+        (
+            redact_result(json_result),
+            output_json_result.map(redact_result),
+        )
+    } else {
+        (json_result, output_json_result)
+    };
+
+    let json_result = match json_result {
+        Ok(data) if !json_options.select.is_empty() => {
+            match homeboy::output::select_fields(&data, &json_options.select, strict_select) {
+                Ok(selected) => Ok(selected),
+                Err(err) => {
+                    output::print_result::<serde_json::Value>(Err(err)).ok();
+                    return std::process::ExitCode::from(exit_code_to_u8(2));
+                }
+            }
+        }
+        other => other,
+    };
+
     // Write JSON to --output file if specified (before printing to stdout).
     if let Some(ref path) = output_file {
         match output_artifact_policy {
@@ -331,7 +519,10 @@ fn main() -> std::process::ExitCode {
 
     match mode {
         CommandResponseMode::Json => {
-            output::print_json_result(json_result, exit_code).ok();
+            if let Err(err) = output::print_json_result(json_result, exit_code, &json_options) {
+                output::print_result::<serde_json::Value>(Err(err)).ok();
+                return std::process::ExitCode::from(exit_code_to_u8(2));
+            }
         }
         CommandResponseMode::Raw(CommandRawOutputMode::InteractivePassthrough) => {}
         CommandResponseMode::Raw(CommandRawOutputMode::Markdown) => {}
@@ -341,6 +532,50 @@ fn main() -> std::process::ExitCode {
     std::process::ExitCode::from(exit_code_to_u8(exit_code))
 }
 
+/// Apply `--redact` to a result and to the details of an error.
+fn redact_result(result: homeboy::Result<serde_json::Value>) -> homeboy::Result<serde_json::Value> {
+    match result {
+        Ok(mut data) => {
+            homeboy::output::redact(&mut data);
+            Ok(data)
+        }
+        Err(mut err) => {
+            homeboy::output::redact(&mut err.details);
+            err.message = homeboy::output::RedactionRules::builtin().redact_text(&err.message);
+            Err(err)
+        }
+    }
+}
+
+/// `homeboy --print-schema <command>` runs without a subcommand, so it is
+/// answered before clap, which requires one. Any other use of the flag falls
+/// through to clap and its usual errors.
+fn print_schema_request(args: &[String]) -> Option<&str> {
+    match args.get(1..)? {
+        [flag, command] if flag == "--print-schema" => Some(command),
+        [flag] => flag.strip_prefix("--print-schema="),
+        _ => None,
+    }
+}
+
+fn print_schema(command: &str) -> std::process::ExitCode {
+    use std::io::Write;
+
+    let result = output_schema::print_schema(command);
+    match result {
+        Ok(schema) => {
+            let payload = serde_json::to_string_pretty(&schema).unwrap_or_default();
+            // Ignore write errors such as a closed pipe (`| head`).
+            let _ = writeln!(std::io::stdout().lock(), "{}", payload);
+            std::process::ExitCode::SUCCESS
+        }
+        Err(err) => {
+            output::print_result::<serde_json::Value>(Err(err)).ok();
+            std::process::ExitCode::from(exit_code_to_u8(2))
+        }
+    }
+}
+
 fn exit_code_to_u8(code: i32) -> u8 {
     if code <= 0 {
         0
@@ -1,4 +1,4 @@
-use super::{run, FileArgs, FileCommand, FileCommandOutput};
+use super::{resolve_editor, run, FileArgs, FileCommand, FileCommandOutput};
 use crate::commands::GlobalArgs;
 use crate::test_support::with_isolated_home;
 
@@ -56,3 +56,13 @@ fn file_read_json_includes_size_metadata() {
     assert_eq!(payload.content.as_deref(), Some(content));
     assert_eq!(payload.size, Some(content.len() as i64));
 }
+
+#[test]
+fn resolve_editor_prefers_visual_then_editor_then_vi() {
+    assert_eq!(
+        resolve_editor(Some("code --wait"), Some("nano")),
+        "code --wait"
+    );
+    assert_eq!(resolve_editor(Some(" "), Some("nano")), "nano");
+    assert_eq!(resolve_editor(None, None), "vi");
+}
@@ -0,0 +1,371 @@
+use super::{print_schema, COMMAND_SCHEMAS};
+use crate::cli_surface::Cli;
+use crate::commands::GlobalArgs;
+use crate::test_support::with_isolated_home;
+use clap::Parser;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Minimal validator for the subset of JSON Schema `print_schema` emits.
+fn conforms(schema: &Value, value: &Value) -> bool {
+    if let Some(variants) = schema["anyOf"].as_array() {
+        return variants.iter().any(|variant| conforms(variant, value));
+    }
+    if let Some(expected) = schema.get("const") {
+        return value == expected;
+    }
+    let type_matches = |ty: &Value| match ty.as_str() {
+        Some("string") => value.is_string(),
+        Some("integer") => value.is_i64() || value.is_u64(),
+        Some("number") => value.is_number(),
+        Some("boolean") => value.is_boolean(),
+        Some("array") => value.is_array(),
+        Some("object") => value.is_object(),
+        Some("null") => value.is_null(),
+        _ => true,
+    };
+    let types_ok = match &schema["type"] {
+        Value::Null => true,
+        Value::Array(types) => types.iter().any(type_matches),
+        ty => type_matches(ty),
+    };
+    let required_ok = schema["required"].as_array().is_none_or(|required| {
+        required
+            .iter()
+            .all(|name| value.get(name.as_str().unwrap_or_default()).is_some())
+    });
+    let properties_ok = schema["properties"].as_object().is_none_or(|properties| {
+        properties.iter().all(|(name, field)| {
+            value
+                .get(name)
+                .is_none_or(|field_value| conforms(field, field_value))
+        })
+    });
+    types_ok && required_ok && properties_ok
+}
+
+fn data_schema_for(command: &str) -> Value {
+    print_schema(command).expect("schema")["properties"]["data"].clone()
+}
+
+/// `data` of `homeboy <args>`, dispatched in-process like the binary does.
+/// A non-zero exit is fine; only a command error fails the test.
+fn run(args: &[&str]) -> Value {
+    let cli = Cli::try_parse_from(std::iter::once("homeboy").chain(args.iter().copied()))
+        .unwrap_or_else(|err| panic!("homeboy {}: {}", args.join(" "), err));
+    let (result, _) = crate::commands::run_json(cli.command, &GlobalArgs {});
+    result.unwrap_or_else(|err| panic!("homeboy {}: {}", args.join(" "), err.message))
+}
+
+fn to_value(output: impl Serialize) -> Value {
+    serde_json::to_value(output).expect("serialize")
+}
+
+fn git(path: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(path)
+        .output()
+        .expect("run git");
+    assert!(output.status.success(), "git {:?} failed", args);
+}
+
+/// A committed checkout of component `demo` with a version target and a
+/// changelog.
+fn demo_checkout(path: &Path) {
+    std::fs::write(
+        path.join("homeboy.json"),
+        json!({
+            "id": "demo",
+            "version_targets": [{ "file": "Cargo.toml", "pattern": "version = \"(.*)\"" }],
+        })
+        .to_string(),
+    )
+    .unwrap();
+    std::fs::write(
+        path.join("Cargo.toml"),
+        "[package]\nname = \"demo\"\nversion = \"0.1.0\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        path.join("CHANGELOG.md"),
+        "# Changelog\n\n## Unreleased\n\n- thing\n\n## 0.1.0\n\n- init\n",
+    )
+    .unwrap();
+    git(path, &["init", "-q", "-b", "main"]);
+    git(path, &["config", "user.email", "test@test.com"]);
+    git(path, &["config", "user.name", "Test"]);
+    git(path, &["add", "."]);
+    git(path, &["commit", "-q", "-m", "init"]);
+}
+
+/// Outputs of commands that need the network, a server, or an extension,
+/// built from the same types the commands serialize.
+fn constructed_outputs() -> Vec<(&'static str, Value)> {
+    use homeboy::extension::{PhaseReport, PhaseStatus, VerificationPhase};
+
+    let phase = |phase| PhaseReport {
+        phase,
+        status: PhaseStatus::Passed,
+        exit_code: Some(0),
+        summary: "passed".to_string(),
+    };
+    vec![
+        (
+            "api",
+            to_value(homeboy::server::api::ApiOutput {
+                project_id: "site".to_string(),
+                method: "GET".to_string(),
+                endpoint: "/wp/v2/posts".to_string(),
+                pointer: None,
+                response: json!([]),
+            }),
+        ),
+        (
+            "bench",
+            to_value(crate::commands::bench::BenchOutput::List(
+                homeboy::extension::bench::BenchListWorkflowResult {
+                    component: "demo".to_string(),
+                    component_id: "demo".to_string(),
+                    scenarios: Vec::new(),
+                    count: 0,
+                },
+            )),
+        ),
+        (
+            "build",
+            to_value(homeboy::build::BuildResult::Single(
+                homeboy::build::BuildOutput {
+                    command: "build".to_string(),
+                    component_id: "demo".to_string(),
+                    build_command: "cargo build".to_string(),
+                    output: homeboy::engine::command::CapturedOutput::new(
+                        String::new(),
+                        String::new(),
+                    ),
+                    success: true,
+                    package: None,
+                },
+            )),
+        ),
+        (
+            "deploy",
+            to_value(crate::commands::deploy::DeployCommandOutput::Plan(
+                crate::commands::deploy::DeployPlanOutput {
+                    command: "deploy.plan".to_string(),
+                    plans: Vec::new(),
+                },
+            )),
+        ),
+        (
+            "http",
+            to_value(homeboy::http_request::HttpRequestOutput {
+                method: "GET".to_string(),
+                url: "https://example.com".to_string(),
+                status: 200,
+                headers: BTreeMap::new(),
+                body: json!("ok"),
+            }),
+        ),
+        (
+            "lint",
+            to_value(homeboy::extension::lint::LintCommandOutput {
+                passed: true,
+                status: "passed".to_string(),
+                component: "demo".to_string(),
+                exit_code: 0,
+                phase: phase(VerificationPhase::Lint),
+                failure: None,
+                autofix: None,
+                hints: None,
+                baseline_comparison: None,
+                lint_findings: None,
+                summary: None,
+            }),
+        ),
+        (
+            "report",
+            to_value(crate::commands::report::ReportOutput {
+                command: "report.failure-digest".to_string(),
+                markdown: "# Digest".to_string(),
+            }),
+        ),
+        (
+            "self",
+            to_value(homeboy::self_status::collect_status_with(
+                None,
+                || Err("offline".to_string()),
+                |_, _| Err("offline".to_string()),
+            )),
+        ),
+        (
+            "test",
+            to_value(homeboy::extension::test::TestCommandOutput {
+                passed: true,
+                status: "passed".to_string(),
+                component: "demo".to_string(),
+                exit_code: 0,
+                phase: Some(phase(VerificationPhase::Test)),
+                failure: None,
+                test_counts: None,
+                failed_tests: None,
+                coverage: None,
+                baseline_comparison: None,
+                analysis: None,
+                autofix: None,
+                hints: None,
+                drift: None,
+                auto_fix_drift: None,
+                test_scope: None,
+                summary: None,
+                raw_output: None,
+            }),
+        ),
+        (
+            "upgrade",
+            to_value(homeboy::upgrade::VersionCheck {
+                command: "upgrade.check".to_string(),
+                channel: homeboy::upgrade::ReleaseChannel::Stable,
+                current_version: "1.0.0".to_string(),
+                latest_version: None,
+                update_available: false,
+                install_method: homeboy::upgrade::InstallMethod::Source,
+            }),
+        ),
+    ]
+}
+
+#[test]
+fn every_command_output_conforms_to_its_schema() {
+    let outputs = with_isolated_home(|home| {
+        let checkout = tempfile::tempdir().expect("checkout tempdir");
+        demo_checkout(checkout.path());
+        let repo = checkout.path().to_str().unwrap();
+
+        let project_dir = home.path().join(".config/homeboy/projects/local");
+        std::fs::create_dir_all(&project_dir).unwrap();
+        std::fs::write(
+            project_dir.join("local.json"),
+            json!({
+                "base_path": repo,
+                "components": [{ "id": "demo", "local_path": repo }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let log = home.path().join("app.log");
+        std::fs::write(&log, "").unwrap();
+        let log = log.to_str().unwrap();
+
+        let audit = run(&["audit", "demo", "--path", repo, "--conventions"]);
+        let audit_output = home.path().join("audit.json");
+        std::fs::write(
+            &audit_output,
+            json!({ "success": true, "data": audit }).to_string(),
+        )
+        .unwrap();
+        let findings_arg = format!("audit={}", audit_output.display());
+
+        let mut outputs = vec![
+            ("audit", audit),
+            ("auth", run(&["auth", "status", "--all"])),
+            ("changelog", run(&["changelog", "show"])),
+            ("changes", run(&["changes", "--path", repo])),
+            ("component", run(&["component", "list"])),
+            ("config", run(&["config", "path"])),
+            ("daemon", run(&["daemon", "status"])),
+            ("db", run(&["db", "status"])),
+            ("deps", run(&["deps", "stack", "status"])),
+            ("docs", run(&["docs", "map", "demo"])),
+            ("doctor", run(&["doctor", "resources"])),
+            ("extension", run(&["extension", "list"])),
+            ("file", run(&["file", "list", "local", "."])),
+            ("fleet", run(&["fleet", "list"])),
+            ("git", run(&["git", "status", "--path", repo])),
+            ("git", run(&["git", "stale-branches", "--path", repo])),
+            (
+                "issues",
+                run(&["issues", "build-findings", "--from-output", &findings_arg]),
+            ),
+            ("logs", run(&["logs", "list", "local"])),
+            (
+                "observe",
+                run(&[
+                    "observe",
+                    "demo",
+                    "--path",
+                    repo,
+                    "--duration",
+                    "1s",
+                    "--tail-log",
+                    log,
+                ]),
+            ),
+            ("project", run(&["project", "list"])),
+            (
+                "refactor",
+                run(&[
+                    "refactor", "rename", "--from", "init", "--to", "start", "--path", repo,
+                ]),
+            ),
+            ("release", run(&["release", "artifacts", "demo"])),
+            (
+                "review",
+                run(&["review", "demo", "--path", repo, "--changed-since", "HEAD"]),
+            ),
+            ("rig", run(&["rig", "list"])),
+            ("runner", run(&["runner", "list"])),
+            ("runs", run(&["runs", "list"])),
+            ("server", run(&["server", "list"])),
+            ("ssh", run(&["ssh", "list"])),
+            ("stack", run(&["stack", "list"])),
+            ("status", run(&["status", "--path", repo])),
+            ("trace", run(&["trace", "demo", "locks", "--path", repo])),
+            ("triage", run(&["triage", "workspace"])),
+            ("undo", run(&["undo", "list"])),
+            ("version", run(&["version", "show", "--path", repo])),
+        ];
+        outputs.extend(constructed_outputs());
+        outputs
+    });
+
+    for schema in COMMAND_SCHEMAS {
+        assert!(
+            outputs
+                .iter()
+                .any(|(command, _)| *command == schema.command),
+            "no sample output for '{}'; add one so its schema is checked",
+            schema.command
+        );
+    }
+    for (command, value) in &outputs {
+        assert!(
+            conforms(&data_schema_for(command), value),
+            "{} output does not match its schema: {}",
+            command,
+            value
+        );
+    }
+}
+
+#[test]
+fn test_data_schema_rejects_other_shapes() {
+    let undo = data_schema_for("undo");
+    assert!(!conforms(
+        &undo,
+        &json!({ "command": "undo.delete", "id": "snap-1" })
+    ));
+    assert!(!conforms(
+        &undo,
+        &json!({ "command": "undo.purge", "id": "snap-1", "deleted": true })
+    ));
+
+    let daemon = data_schema_for("daemon");
+    assert!(!conforms(
+        &daemon,
+        &json!({ "action": "stop", "stopped": "yes", "state_path": "/tmp/daemon.json" })
+    ));
+}
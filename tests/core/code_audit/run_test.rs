@@ -3,8 +3,9 @@
 //! Wired into `src/core/code_audit/run.rs` via `#[cfg(test)] #[path = ...] mod run_test`.
 
 use super::{
-    apply_finding_filters, build_comparison_output, compute_fixability_if_requested,
-    scope_convention_outliers_to_findings, AuditRunWorkflowArgs,
+    apply_exit_policy, apply_finding_filters, build_comparison_output,
+    compute_fixability_if_requested, scope_convention_outliers_to_findings,
+    scope_findings_to_changed_files, AuditRunWorkflowArgs,
 };
 use crate::code_audit::checks::CheckStatus;
 use crate::code_audit::conventions::{Deviation, Outlier};
@@ -13,6 +14,7 @@ use crate::code_audit::{
     AuditAnalysisContext, AuditExecutionPlan, AuditFinding, AuditSummary, CodeAuditResult,
     ConventionReport,
 };
+use crate::output::{ExitPolicy, FailOn};
 
 fn make_finding(kind: AuditFinding, file: &str) -> Finding {
     Finding {
@@ -90,14 +92,22 @@ fn make_args(include_fixability: bool) -> AuditRunWorkflowArgs {
         exclude_kinds: vec![],
         only_labels: vec![],
         exclude_labels: vec![],
+        docs_include: vec![],
+        docs_exclude: vec![],
+        docs_min_confidence: None,
+        docs_context_lines: None,
+        convention_threshold: None,
         baseline_flags: crate::engine::baseline::BaselineFlags {
             baseline: false,
             ignore_baseline: false,
             ratchet: false,
         },
         changed_since: None,
+        only_changed: false,
         json_summary: false,
         include_fixability,
+        exit_policy: None,
+        no_cache: true,
     }
 }
 
@@ -384,3 +394,60 @@ fn fixability_flag_allows_planning_path() {
     // existing compute_fixability() guard.
     assert!(fixability.is_none());
 }
+
+#[test]
+fn fail_on_maps_finding_severities_to_exit_code() {
+    let mut info = make_finding(AuditFinding::TodoMarker, "a.rs");
+    info.severity = Severity::Info;
+    let result = make_result(vec![info]);
+
+    let mut args = make_args(false);
+    assert_eq!(apply_exit_policy(&args, &result, 1), 1);
+
+    args.exit_policy = Some(ExitPolicy {
+        fail_on: FailOn::Warnings,
+        exit_code: 4,
+    });
+    assert_eq!(apply_exit_policy(&args, &result, 1), 0);
+
+    args.exit_policy = Some(ExitPolicy {
+        fail_on: FailOn::Findings,
+        exit_code: 4,
+    });
+    assert_eq!(apply_exit_policy(&args, &result, 0), 4);
+}
+
+#[test]
+fn only_changed_keeps_findings_in_changed_files_and_all_conventions() {
+    let mut result = make_result(vec![
+        make_finding(AuditFinding::MissingMethod, "src/changed.rs"),
+        make_finding(AuditFinding::MissingMethod, "src/untouched.rs"),
+        make_finding(AuditFinding::TodoMarker, "src/new/mod.rs"),
+    ]);
+    result.conventions = vec![
+        make_convention_report(
+            "test",
+            vec![
+                make_outlier("src/changed.rs", vec![AuditFinding::MissingMethod]),
+                make_outlier("src/untouched.rs", vec![AuditFinding::MissingMethod]),
+            ],
+        ),
+        make_convention_report("other", vec![]),
+    ];
+
+    scope_findings_to_changed_files(
+        &mut result,
+        &["src/changed.rs".to_string(), "src/new/".to_string()],
+    );
+
+    let files: Vec<&str> = result.findings.iter().map(|f| f.file.as_str()).collect();
+    assert_eq!(files, vec!["src/changed.rs", "src/new/mod.rs"]);
+    assert_eq!(result.summary.outliers_found, 2);
+    assert_eq!(result.conventions.len(), 2);
+    let outliers: Vec<&str> = result.conventions[0]
+        .outliers
+        .iter()
+        .map(|o| o.file.as_str())
+        .collect();
+    assert_eq!(outliers, vec!["src/changed.rs"]);
+}
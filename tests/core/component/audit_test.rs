@@ -50,3 +50,23 @@ fn test_merge() {
         vec!["generated/**", "fixtures/**"]
     );
 }
+
+#[test]
+fn test_convention_threshold() {
+    let with = |threshold| AuditConfig {
+        convention_threshold: threshold,
+        ..Default::default()
+    };
+
+    assert_eq!(with(None).convention_threshold(), 0.6);
+    assert_eq!(with(Some(0.8)).convention_threshold(), 0.8);
+    assert_eq!(with(Some(0.1)).convention_threshold(), 0.5);
+    assert_eq!(with(Some(1.5)).convention_threshold(), 1.0);
+    assert_eq!(with(Some(f32::NAN)).convention_threshold(), 0.6);
+
+    let mut merged = with(Some(0.7));
+    merged.merge(&with(None));
+    assert_eq!(merged.convention_threshold, Some(0.7));
+    merged.merge(&with(Some(0.9)));
+    assert_eq!(merged.convention_threshold, Some(0.9));
+}
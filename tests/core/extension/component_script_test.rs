@@ -4,7 +4,8 @@ use std::path::Path;
 
 use crate::commands::test::{run as run_test, TestArgs};
 use crate::commands::utils::args::{
-    BaselineArgs, ExtensionOverrideArgs, HiddenJsonArgs, PositionalComponentArgs, SettingArgs,
+    BaselineArgs, ExtensionOverrideArgs, HiddenJsonArgs, PositionalComponentArgs, ResultFormat,
+    SettingArgs,
 };
 use crate::commands::GlobalArgs;
 use crate::component::{Component, ComponentScriptsConfig};
@@ -40,6 +41,7 @@ fn test_command_args(root: &Path) -> TestArgs {
         args: Vec::new(),
         _json: HiddenJsonArgs::default(),
         json_summary: false,
+        format: ResultFormat::Json,
     }
 }
 
@@ -0,0 +1,129 @@
+//! `--dry-run` must leave the filesystem untouched: run real commands against
+//! a throwaway HOME and component checkout and compare snapshots. Update
+//! checks are off because their caches are written even in a dry run.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Every file under `root` with its contents. Git's index is skipped because
+/// read-only git commands refresh its stat cache.
+fn snapshot(root: &Path) -> BTreeMap<String, Vec<u8>> {
+    fn walk(root: &Path, dir: &Path, files: &mut BTreeMap<String, Vec<u8>>) {
+        for entry in fs::read_dir(dir).expect("read dir").flatten() {
+            let path = entry.path();
+            let relative = path
+                .strip_prefix(root)
+                .unwrap()
+                .to_string_lossy()
+                .to_string();
+            if relative.ends_with(".git/index") {
+                continue;
+            }
+            if path.is_dir() {
+                files.insert(format!("{}/", relative), Vec::new());
+                walk(root, &path, files);
+            } else {
+                files.insert(relative, fs::read(&path).expect("read file"));
+            }
+        }
+    }
+
+    let mut files = BTreeMap::new();
+    walk(root, root, &mut files);
+    files
+}
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=Homeboy",
+            "-c",
+            "user.email=homeboy@example.test",
+        ])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .expect("run git");
+    assert!(status.success(), "git {:?} failed", args);
+}
+
+#[test]
+fn version_sync_dry_run_writes_nothing() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let home = temp.path().join("home");
+    let component = temp.path().join("widget");
+    fs::create_dir_all(&home).expect("mkdir home");
+    fs::create_dir_all(&component).expect("mkdir component");
+    fs::write(
+        component.join("homeboy.json"),
+        r#"{"id":"widget","version_targets":[{"file":"VERSION","pattern":"(.*)"}],"changelog_target":"CHANGELOG.md"}"#,
+    )
+    .expect("write homeboy.json");
+    fs::write(component.join("VERSION"), "0.1.0\n").expect("write VERSION");
+    fs::write(
+        component.join("CHANGELOG.md"),
+        "# Changelog\n\n## Unreleased\n\n- Added a thing\n",
+    )
+    .expect("write changelog");
+    git(&component, &["init", "-q"]);
+    git(&component, &["add", "-A"]);
+    git(&component, &["commit", "-qm", "init"]);
+
+    let before = snapshot(temp.path());
+    let output = Command::new(env!("CARGO_BIN_EXE_homeboy"))
+        .args(["version", "sync", "widget", "0.2.0", "--tag", "--dry-run"])
+        .arg("--path")
+        .arg(&component)
+        .env("HOME", &home)
+        .env("HOMEBOY_QUIET", "1")
+        .env("HOMEBOY_NO_UPDATE_CHECK", "1")
+        .current_dir(&component)
+        .output()
+        .expect("run homeboy");
+
+    assert!(
+        output.status.success(),
+        "homeboy failed: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert_eq!(snapshot(temp.path()), before);
+}
+
+#[test]
+fn server_rename_dry_run_writes_nothing() {
+    let temp = tempfile::tempdir().expect("tempdir");
+    let home = temp.path().join("home");
+    fs::create_dir_all(&home).expect("mkdir home");
+    let homeboy = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_homeboy"))
+            .args(args)
+            .env("HOME", &home)
+            .env("HOMEBOY_QUIET", "1")
+            .env("HOMEBOY_NO_UPDATE_CHECK", "1")
+            .current_dir(temp.path())
+            .output()
+            .expect("run homeboy");
+        assert!(
+            output.status.success(),
+            "homeboy {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stdout)
+        );
+    };
+    homeboy(&[
+        "server",
+        "create",
+        "web",
+        "--host",
+        "web.example.test",
+        "--user",
+        "deploy",
+    ]);
+
+    let before = snapshot(temp.path());
+    homeboy(&["server", "rename", "web", "web-01", "--dry-run"]);
+    assert_eq!(snapshot(temp.path()), before);
+}
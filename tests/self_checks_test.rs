@@ -1,7 +1,8 @@
 use homeboy::commands::lint::{run as run_lint, LintArgs};
 use homeboy::commands::test::{run as run_test, TestArgs};
 use homeboy::commands::utils::args::{
-    BaselineArgs, ExtensionOverrideArgs, HiddenJsonArgs, PositionalComponentArgs, SettingArgs,
+    BaselineArgs, ExtensionOverrideArgs, FailOnArgs, HiddenJsonArgs, PositionalComponentArgs,
+    ResultFormat, SettingArgs,
 };
 use homeboy::commands::GlobalArgs;
 use std::fs;
@@ -44,6 +45,7 @@ fn lint_args(root: &Path) -> LintArgs {
         changed_only: false,
         changed_since: None,
         errors_only: false,
+        fail_on_warnings: false,
         sniffs: None,
         exclude_sniffs: None,
         category: None,
@@ -51,8 +53,10 @@ fn lint_args(root: &Path) -> LintArgs {
         force: false,
         setting_args: SettingArgs::default(),
         baseline_args: BaselineArgs::default(),
+        fail_on_args: FailOnArgs::default(),
         _json: HiddenJsonArgs::default(),
         json_summary: false,
+        format: ResultFormat::Json,
     }
 }
 
@@ -73,6 +77,7 @@ fn test_args(root: &Path) -> TestArgs {
         args: Vec::new(),
         _json: HiddenJsonArgs::default(),
         json_summary: false,
+        format: ResultFormat::Json,
     }
 }
 